@@ -19,6 +19,8 @@ config_outfile = "config.json"
 latin_index_outfile = "src_data/latin_index.json"
 latin_alias_outfile = "src_data/latin_aliases.txt"
 icon_index_outfile = "src_data/icon_index.json"
+kana_index_infile = "src_data/kana_index.txt"
+kana_alias_outfile = "src_data/kana_aliases.txt"
 
 config = {
   comment: [
@@ -45,8 +47,32 @@ config = {
       indexType: "txt-row-major",
       aliases: "",
       glyphTrim: "CJK",
+      compress: true,
       rustout: "../src/fonts/hanzi.rs",
     },
+    {
+      name: "Kana",
+      m3Seed: 0,
+      sprites: "src_data/kana.png", size: 32, cols: 16, gutter: 2, border: 2,
+      legal: "src_data/kana_legal.txt",
+      index: kana_index_infile,
+      indexType: "txt-row-major",
+      aliases: kana_alias_outfile,
+      glyphTrim: "CJK",
+      compress: true,
+      rustout: "../src/fonts/kana.rs",
+    },
+    {
+      name: "Jamo",
+      m3Seed: 0,
+      sprites: "src_data/hangul_jamo.png", size: 32, cols: 16, gutter: 2, border: 2,
+      legal: "src_data/hangul_jamo_legal.txt",
+      index: "src_data/hangul_jamo_index.txt",
+      indexType: "txt-row-major",
+      aliases: "",
+      glyphTrim: "vertical",
+      rustout: "../src/fonts/jamo.rs",
+    },
     {
       name: "Bold",
       m3Seed: 0,
@@ -79,18 +105,29 @@ config = {
       aliases: "src_data/latin_aliases.txt",
       glyphTrim: "proportional",
       rustout: "../src/fonts/small.rs",
+    },
+    {
+      name: "Icons",
+      m3Seed: 0,
+      sprites: "src_data/regular.png", size: 30, cols: 16, gutter: 2, border: 2,
+      legal: "src_data/icons_legal.txt",
+      index: icon_index_outfile,
+      indexType: "json-grid-coord",
+      aliases: "",
+      glyphTrim: "proportional",
+      rustout: "../src/fonts/icons.rs",
+    },
+    {
+      name: "Numeric",
+      m3Seed: 0,
+      sprites: "src_data/numeric.png", size: 64, cols: 8, gutter: 2, border: 2,
+      legal: "src_data/numeric_legal.txt",
+      index: "src_data/numeric_index.txt",
+      indexType: "txt-row-major",
+      aliases: "src_data/numeric_aliases.txt",
+      glyphTrim: "proportional",
+      rustout: "../src/fonts/numeric.rs",
     }
-    # ,{
-    #   name: "Icons",
-    #   m3seed: 0,
-    #   sprites: "src_data/regular.png", size: 30, cols: 16, gutter: 2, border: 2,
-    #   legal: "",
-    #   index: icon_index_outfile,
-    #   indexType: "json-grid-coord",
-    #   aliases: "",
-    #   glyphTrim: "proportional",
-    #   rustout: "../src/fonts/icons.rs",
-    # }
   ]
 }
 
@@ -298,6 +335,98 @@ latin_index = {
     {hex: "152", row: 14, col: 12, label: "Œ"},
     {hex: "153", row: 15, col: 12, label: "œ"},
 
+    # Unicode Greek and Coptic block
+    {hex: "393", row: 16, col: 0, label: "Γ"},
+    {hex: "394", row: 16, col: 1, label: "Δ"},
+    {hex: "398", row: 16, col: 2, label: "Θ"},
+    {hex: "39B", row: 16, col: 3, label: "Λ"},
+    {hex: "39E", row: 16, col: 4, label: "Ξ"},
+    {hex: "3A0", row: 16, col: 5, label: "Π"},
+    {hex: "3A3", row: 16, col: 6, label: "Σ"},
+    {hex: "3A6", row: 16, col: 7, label: "Φ"},
+    {hex: "3A8", row: 16, col: 8, label: "Ψ"},
+    {hex: "3A9", row: 16, col: 9, label: "Ω"},
+    {hex: "3B1", row: 16, col: 10, label: "α"},
+    {hex: "3B2", row: 16, col: 11, label: "β"},
+    {hex: "3B3", row: 16, col: 12, label: "γ"},
+    {hex: "3B4", row: 16, col: 13, label: "δ"},
+    {hex: "3B5", row: 16, col: 14, label: "ε"},
+    {hex: "3B6", row: 16, col: 15, label: "ζ"},
+    {hex: "3B7", row: 17, col: 0, label: "η"},
+    {hex: "3B8", row: 17, col: 1, label: "θ"},
+    {hex: "3B9", row: 17, col: 2, label: "ι"},
+    {hex: "3BA", row: 17, col: 3, label: "κ"},
+    {hex: "3BB", row: 17, col: 4, label: "λ"},
+    {hex: "3BE", row: 17, col: 5, label: "ξ"},
+    {hex: "3C0", row: 17, col: 6, label: "π"},
+    {hex: "3C1", row: 17, col: 7, label: "ρ"},
+    {hex: "3C2", row: 17, col: 8, label: "ς"},
+    {hex: "3C3", row: 17, col: 9, label: "σ"},
+    {hex: "3C4", row: 17, col: 10, label: "τ"},
+    {hex: "3C5", row: 17, col: 11, label: "υ"},
+    {hex: "3C6", row: 17, col: 12, label: "φ"},
+    {hex: "3C7", row: 17, col: 13, label: "χ"},
+    {hex: "3C8", row: 17, col: 14, label: "ψ"},
+    {hex: "3C9", row: 17, col: 15, label: "ω"},
+
+    # Unicode Cyrillic block
+    {hex: "402", row: 18, col: 0, label: "Ђ"},
+    {hex: "404", row: 18, col: 1, label: "Є"},
+    {hex: "409", row: 18, col: 2, label: "Љ"},
+    {hex: "40A", row: 18, col: 3, label: "Њ"},
+    {hex: "40B", row: 18, col: 4, label: "Ћ"},
+    {hex: "40F", row: 18, col: 5, label: "Џ"},
+    {hex: "411", row: 18, col: 6, label: "Б"},
+    {hex: "414", row: 18, col: 7, label: "Д"},
+    {hex: "416", row: 18, col: 8, label: "Ж"},
+    {hex: "417", row: 18, col: 9, label: "З"},
+    {hex: "418", row: 18, col: 10, label: "И"},
+    {hex: "419", row: 18, col: 11, label: "Й"},
+    {hex: "41B", row: 18, col: 12, label: "Л"},
+    {hex: "423", row: 18, col: 13, label: "У"},
+    {hex: "426", row: 18, col: 14, label: "Ц"},
+    {hex: "427", row: 18, col: 15, label: "Ч"},
+    {hex: "428", row: 19, col: 0, label: "Ш"},
+    {hex: "429", row: 19, col: 1, label: "Щ"},
+    {hex: "42A", row: 19, col: 2, label: "Ъ"},
+    {hex: "42B", row: 19, col: 3, label: "Ы"},
+    {hex: "42C", row: 19, col: 4, label: "Ь"},
+    {hex: "42D", row: 19, col: 5, label: "Э"},
+    {hex: "42E", row: 19, col: 6, label: "Ю"},
+    {hex: "42F", row: 19, col: 7, label: "Я"},
+    {hex: "431", row: 19, col: 8, label: "б"},
+    {hex: "432", row: 19, col: 9, label: "в"},
+    {hex: "433", row: 19, col: 10, label: "г"},
+    {hex: "434", row: 19, col: 11, label: "д"},
+    {hex: "436", row: 19, col: 12, label: "ж"},
+    {hex: "437", row: 19, col: 13, label: "з"},
+    {hex: "438", row: 19, col: 14, label: "и"},
+    {hex: "439", row: 19, col: 15, label: "й"},
+    {hex: "43B", row: 20, col: 0, label: "л"},
+    {hex: "43C", row: 20, col: 1, label: "м"},
+    {hex: "43D", row: 20, col: 2, label: "н"},
+    {hex: "43F", row: 20, col: 3, label: "п"},
+    {hex: "442", row: 20, col: 4, label: "т"},
+    {hex: "444", row: 20, col: 5, label: "ф"},
+    {hex: "446", row: 20, col: 6, label: "ц"},
+    {hex: "447", row: 20, col: 7, label: "ч"},
+    {hex: "448", row: 20, col: 8, label: "ш"},
+    {hex: "449", row: 20, col: 9, label: "щ"},
+    {hex: "44A", row: 20, col: 10, label: "ъ"},
+    {hex: "44B", row: 20, col: 11, label: "ы"},
+    {hex: "44C", row: 20, col: 12, label: "ь"},
+    {hex: "44D", row: 20, col: 13, label: "э"},
+    {hex: "44E", row: 20, col: 14, label: "ю"},
+    {hex: "44F", row: 20, col: 15, label: "я"},
+    {hex: "452", row: 21, col: 0, label: "ђ"},
+    {hex: "454", row: 21, col: 1, label: "є"},
+    {hex: "459", row: 21, col: 2, label: "љ"},
+    {hex: "45A", row: 21, col: 3, label: "њ"},
+    {hex: "45B", row: 21, col: 4, label: "ћ"},
+    {hex: "45F", row: 21, col: 5, label: "џ"},
+    {hex: "490", row: 21, col: 6, label: "Ґ"},
+    {hex: "491", row: 21, col: 7, label: "ґ"},
+
     # Unicode General Punctuation block
     {hex: "2018", row: 4, col: 13, label: "‘ Left Single Quotation Mark"},
     {hex: "2019", row: 5, col: 13, label: "’ Right Single Quotation Mark"},
@@ -319,6 +448,65 @@ latin_index = {
   ]
 }
 
+# Greek and Cyrillic letters that look the same as glyphs in the latin index.
+# These get aliases to the existing glyph instead of their own sprite.
+latin_homoglyphs = [
+    {hex: "41", alias: "391", label: "Α"},
+    {hex: "42", alias: "392", label: "Β"},
+    {hex: "45", alias: "395", label: "Ε"},
+    {hex: "5A", alias: "396", label: "Ζ"},
+    {hex: "48", alias: "397", label: "Η"},
+    {hex: "49", alias: "399", label: "Ι"},
+    {hex: "4B", alias: "39A", label: "Κ"},
+    {hex: "4D", alias: "39C", label: "Μ"},
+    {hex: "4E", alias: "39D", label: "Ν"},
+    {hex: "4F", alias: "39F", label: "Ο"},
+    {hex: "50", alias: "3A1", label: "Ρ"},
+    {hex: "54", alias: "3A4", label: "Τ"},
+    {hex: "59", alias: "3A5", label: "Υ"},
+    {hex: "58", alias: "3A7", label: "Χ"},
+    {hex: "B5", alias: "3BC", label: "μ"},
+    {hex: "76", alias: "3BD", label: "ν"},
+    {hex: "6F", alias: "3BF", label: "ο"},
+    {hex: "3B", alias: "37E", label: ";"},
+    {hex: "B7", alias: "387", label: "·"},
+    {hex: "B4", alias: "384", label: "΄"},
+    {hex: "C8", alias: "400", label: "Ѐ"},
+    {hex: "CB", alias: "401", label: "Ё"},
+    {hex: "53", alias: "405", label: "Ѕ"},
+    {hex: "49", alias: "406", label: "І"},
+    {hex: "CF", alias: "407", label: "Ї"},
+    {hex: "4A", alias: "408", label: "Ј"},
+    {hex: "41", alias: "410", label: "А"},
+    {hex: "42", alias: "412", label: "В"},
+    {hex: "393", alias: "413", label: "Г"},
+    {hex: "45", alias: "415", label: "Е"},
+    {hex: "4B", alias: "41A", label: "К"},
+    {hex: "4D", alias: "41C", label: "М"},
+    {hex: "48", alias: "41D", label: "Н"},
+    {hex: "4F", alias: "41E", label: "О"},
+    {hex: "3A0", alias: "41F", label: "П"},
+    {hex: "50", alias: "420", label: "Р"},
+    {hex: "43", alias: "421", label: "С"},
+    {hex: "54", alias: "422", label: "Т"},
+    {hex: "58", alias: "425", label: "Х"},
+    {hex: "3A6", alias: "424", label: "Ф"},
+    {hex: "61", alias: "430", label: "а"},
+    {hex: "3BA", alias: "43A", label: "к"},
+    {hex: "65", alias: "435", label: "е"},
+    {hex: "6F", alias: "43E", label: "о"},
+    {hex: "70", alias: "440", label: "р"},
+    {hex: "63", alias: "441", label: "с"},
+    {hex: "79", alias: "443", label: "у"},
+    {hex: "78", alias: "445", label: "х"},
+    {hex: "E8", alias: "450", label: "ѐ"},
+    {hex: "EB", alias: "451", label: "ё"},
+    {hex: "73", alias: "455", label: "ѕ"},
+    {hex: "69", alias: "456", label: "і"},
+    {hex: "EF", alias: "457", label: "ї"},
+    {hex: "6A", alias: "458", label: "ј"},
+]
+
 icon_index = {
   comment: [
     "Before making changes here, see ../config_editor.rb.",
@@ -346,6 +534,7 @@ puts "  #{config_outfile}"
 puts "  #{latin_index_outfile}"
 puts "  #{icon_index_outfile}"
 puts "  #{latin_alias_outfile}"
+puts "  #{kana_alias_outfile}"
 print "Do you want to proceed? [y/N]: "
 abort "Operation canceled" if !["y", "Y"].include? gets.chomp
 
@@ -366,6 +555,23 @@ File.open(latin_alias_outfile, "w") {|f|
     if cluster_C == cluster_D then next end
     f.puts "#{hex_C} #{hex_D}   # nfc: [#{hex_C}, #{cluster_C}],  nfd: [#{hex_D}, #{cluster_D}]"
   end
+  # Loop through the homoglyphs that share a glyph from the latin index
+  for h in latin_homoglyphs
+    glyph = h[:hex].split("-").map {|scalar| scalar.to_i(16).chr(Encoding::UTF_8)}.join()
+    f.puts "#{h[:hex]} #{h[:alias]}   # glyph: [#{h[:hex]}, #{glyph}],  homoglyph: [#{h[:alias]}, #{h[:label]}]"
+  end
+}
+puts "writing #{kana_alias_outfile}"
+File.open(kana_alias_outfile, "w") {|f|
+  # Loop through the hex codepoints of the kana index, skipping comments
+  for hex_C in File.readlines(kana_index_infile).map {|x| x.split("#")[0].strip}.reject(&:empty?)
+    cluster_C = hex_C.split("-").map {|scalar| scalar.to_i(16).chr(Encoding::UTF_8)}.join()
+    # Kana with dakuten or handakuten decompose to base kana + combining mark
+    cluster_D = cluster_C.unicode_normalize(:nfd)
+    hex_D = cluster_D.codepoints.map {|c| c.to_s(16).upcase}.join("-")
+    if cluster_C == cluster_D then next end
+    f.puts "#{hex_C} #{hex_D}   # nfc: [#{hex_C}, #{cluster_C}],  nfd: [#{hex_D}, #{cluster_D}]"
+  end
 }
 puts "writing #{icon_index_outfile}"
 File.open(icon_index_outfile, "w") {|f|
@@ -15,6 +15,7 @@ type BlockIndex []clusterOffsetEntry
 // An index entry for translating from grapheme cluster to blit pattern
 type clusterOffsetEntry struct {
 	M3Hash     uint32
+	Key        uint32 // Verification key to rule out hash collisions
 	Cluster    string // Parsed UTF-8 form (not hex codepoints)
 	DataOffset int
 }
@@ -24,6 +25,7 @@ type clusterOffsetEntry struct {
 func (b BlockIndex) Insert(graphemeCluster string, m3Seed uint32, dataOffset int) BlockIndex {
 	indexEntry := clusterOffsetEntry{
 		Murmur3(graphemeCluster, m3Seed),
+		KeyForCluster(graphemeCluster, m3Seed),
 		graphemeCluster,
 		dataOffset,
 	}
@@ -44,6 +46,17 @@ func (b BlockIndex) RustCodeForClusterHashes() string {
 	return strings.Join(rustCode, "\n    ")
 }
 
+// Format the inner elements of a [u32; n] verification key table for one block
+func (b BlockIndex) RustCodeForClusterKeys() string {
+	var rustCode []string
+	for _, entry := range b {
+		key := fmt.Sprintf("0x%08X", entry.Key)
+		label := LabelForCluster(entry.Cluster)
+		rustCode = append(rustCode, fmt.Sprintf("%s,  // %s", key, label))
+	}
+	return strings.Join(rustCode, "\n    ")
+}
+
 // Format the inner elements of a [u32; n] blit pattern offset table for one block
 func (b BlockIndex) RustCodeForOffsets() string {
 	var rustCode []string
@@ -55,6 +68,91 @@ func (b BlockIndex) RustCodeForOffsets() string {
 	return strings.Join(rustCode, "\n    ")
 }
 
+// Return the range of codepoints for the single codepoint clusters in the index,
+// and whether they should get a table indexed by codepoint. They get a table
+// when it would take no more words than their hash index entries (hash, key,
+// and offset), which is true unless the codepoints are sparse.
+func (b BlockIndex) codepointRange() (low int, high int, ok bool) {
+	count := 0
+	for _, entry := range b {
+		codepoints := []rune(entry.Cluster)
+		if len(codepoints) != 1 {
+			continue
+		}
+		cp := int(codepoints[0])
+		if count == 0 || cp < low {
+			low = cp
+		}
+		if count == 0 || cp > high {
+			high = cp
+		}
+		count += 1
+	}
+	return low, high, count > 0 && high-low+1 <= 3*count
+}
+
+// Return true if single codepoint clusters get looked up in a codepoint table
+func (b BlockIndex) HasCodepointTable() bool {
+	_, _, ok := b.codepointRange()
+	return ok
+}
+
+// First codepoint of the codepoint table
+func (b BlockIndex) CodepointTableLow() int {
+	low, _, _ := b.codepointRange()
+	return low
+}
+
+// Last codepoint of the codepoint table
+func (b BlockIndex) CodepointTableHigh() int {
+	_, high, _ := b.codepointRange()
+	return high
+}
+
+// Number of entries in the codepoint table
+func (b BlockIndex) CodepointTableLen() int {
+	low, high, _ := b.codepointRange()
+	return high - low + 1
+}
+
+// Format the inner elements of a [u32; n] codepoint table for one block, with
+// blit pattern offsets for codepoints that have glyphs, and NO_GLYPH for gaps
+func (b BlockIndex) RustCodeForCodepointTable() string {
+	low, high, _ := b.codepointRange()
+	offsets := map[int]int{}
+	for _, entry := range b {
+		codepoints := []rune(entry.Cluster)
+		if len(codepoints) == 1 {
+			offsets[int(codepoints[0])] = entry.DataOffset
+		}
+	}
+	var rustCode []string
+	for cp := low; cp <= high; cp++ {
+		if offset, found := offsets[cp]; found {
+			label := LabelForCluster(string(rune(cp)))
+			rustCode = append(rustCode, fmt.Sprintf("%-6s // %s", fmt.Sprintf("%d,", offset), label))
+		} else {
+			rustCode = append(rustCode, fmt.Sprintf("super::NO_GLYPH,  // U+%04X", cp))
+		}
+	}
+	return strings.Join(rustCode, "\n    ")
+}
+
+// Return the entries that go in the hash index, which leaves out the single
+// codepoint clusters when they have a codepoint table
+func (b BlockIndex) HashEntries() BlockIndex {
+	if !b.HasCodepointTable() {
+		return b
+	}
+	hashed := BlockIndex{}
+	for _, entry := range b {
+		if len([]rune(entry.Cluster)) > 1 {
+			hashed = append(hashed, entry)
+		}
+	}
+	return hashed
+}
+
 // Make a grapheme cluster length list for a BlockIndex. The point of this is to
 // facilitate efficient greedy matching. For example, when the index for a block
 // has grapheme clusters of length 1 or 5 codepoints long, the grapheme cluster
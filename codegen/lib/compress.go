@@ -0,0 +1,82 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+package lib
+
+// Compress a packed blit pattern with row masks and 4-bit run lengths. Patterns
+// that would not get smaller, or that are too big for the row masks, are
+// returned unchanged. See src/fonts/compress.rs for how blitstr decodes them.
+//
+// Header: .Words[0] = (number of compressed words n) << 24 | packed header
+// Blank rows: .Words[1] bit y is set if row y has no set pixels
+// Repeated rows: .Words[2] bit y is set if row y is the same as row y-1
+// Runs: .Words[3:1+n] hold 4-bit run lengths for the pixels of the remaining
+//       rows, starting at the MSB of .Words[3]. Runs alternate between clear
+//       and set pixels, starting with clear. A run of r pixels is coded as
+//       r/15 nibbles of 15 followed by a nibble of r%15.
+func (p BlitPattern) Compress() BlitPattern {
+	w := (p.Words[0] >> 16) & 0xff
+	h := (p.Words[0] >> 8) & 0xff
+	packed := uint32(len(p.Words) - 1)
+	if h > 32 {
+		return p
+	}
+	// Get the pixels of row y, in the same order that they are packed
+	row := func(y uint32) []uint32 {
+		var px []uint32
+		for i := y * w; i < (y+1)*w; i++ {
+			px = append(px, (p.Words[1+i/32]>>(31-i%32))&1)
+		}
+		return px
+	}
+	var nibbles []uint32
+	emit := func(run uint32) {
+		for ; run >= 15; run -= 15 {
+			nibbles = append(nibbles, 15)
+		}
+		nibbles = append(nibbles, run)
+	}
+	blank := uint32(0)
+	repeat := uint32(0)
+	run := uint32(0)
+	runSet := uint32(0)
+	var prev []uint32
+	for y := uint32(0); y < h; y++ {
+		px := row(y)
+		isBlank := true
+		isRepeat := y > 0
+		for x := range px {
+			isBlank = isBlank && px[x] == 0
+			isRepeat = isRepeat && px[x] == prev[x]
+		}
+		prev = px
+		if isBlank {
+			blank |= 1 << y
+			continue
+		} else if isRepeat {
+			repeat |= 1 << y
+			continue
+		}
+		for _, bit := range px {
+			if bit != runSet {
+				emit(run)
+				runSet ^= 1
+				run = 0
+			}
+			run++
+		}
+	}
+	emit(run)
+	n := 2 + (uint32(len(nibbles))+7)/8
+	if n >= packed {
+		return p
+	}
+	words := make([]uint32, 1+n)
+	words[0] = (n << 24) | p.Words[0]
+	words[1] = blank
+	words[2] = repeat
+	for i, nibble := range nibbles {
+		words[3+i/8] |= nibble << uint(28-4*(i%8))
+	}
+	return BlitPattern{words, p.CS}
+}
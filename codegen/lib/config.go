@@ -30,6 +30,7 @@ type ConfigGlyphSet struct {
 	IndexType string
 	Aliases   string
 	GlyphTrim string
+	Compress  bool
 	RustOut   string
 }
 
@@ -61,7 +62,7 @@ func (c Config) Fonts() []FontSpec {
 			gs.Name, gs.Sprites, gs.Size, gs.Cols, gs.Gutter, gs.Border,
 			gs.readLegal(),
 			gs.graphemeClusterMap(), gs.graphemeClusterAliases(),
-			gs.RustOut, gs.GlyphTrim, gs.M3Seed,
+			gs.RustOut, gs.GlyphTrim, gs.M3Seed, gs.Compress,
 		}
 		list = append(list, fs)
 	}
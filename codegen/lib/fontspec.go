@@ -15,8 +15,9 @@ type FontSpec struct {
 	CSList    []CharSpec // Map of grapheme clusters to glyph grid coordinates
 	AliasList []GCAlias  // Map of grapheme cluster aliases (alternate names for glyphs)
 	RustOut   string     // Where should the generated rust source code go?
-	GlyphTrim string     // How should bitmap glyphs be trimmed (proportional or CJK)?
+	GlyphTrim string     // How should bitmap glyphs be trimmed (proportional, CJK, or vertical)?
 	M3Seed    uint32     // Hash seed (change value in config.json in case of hash collisions
+	Compress  bool       // Should glyphs be stored compressed when that takes fewer words?
 }
 
 // Look up trim limits based on row & column in glyph grid
@@ -31,6 +32,10 @@ func (f FontSpec) TrimLimits(row int, col int) [4]int {
 	} else if f.GlyphTrim == "CJK" {
 		// No trim for CJK
 		return [4]int{0, 0, 0, 0}
+	} else if f.GlyphTrim == "vertical" {
+		// Only trim top and bottom, so glyphs keep their x position in the
+		// cell (hangul jamo get composed into syllables by overlaying them)
+		return [4]int{f.Size, 0, f.Size, 0}
 	}
 	// Everything else gets max trim
 	return [4]int{f.Size, f.Size, f.Size, f.Size}
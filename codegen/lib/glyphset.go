@@ -77,3 +77,14 @@ func (g GlyphSet) IndexKeys() []UBlock {
 	sort.Slice(blocks, func(i, j int) bool { return blocks[i].Low < blocks[j].Low })
 	return blocks
 }
+
+// Return true if any block in the GlyphSet index has hash index entries (this
+// is called from templates)
+func (g GlyphSet) HasHashEntries() bool {
+	for _, dex := range g.Index {
+		if len(dex.HashEntries()) > 0 {
+			return true
+		}
+	}
+	return false
+}
@@ -7,6 +7,17 @@ import (
 	"math/bits"
 )
 
+// Return the key that blitstr checks after a hash index match to rule out hash
+// collisions (see cluster_key() in src/fonts.rs). Single codepoints are their
+// own key. Longer clusters get a second murmur3 hash, with the MSB set.
+func KeyForCluster(cluster string, seed uint32) uint32 {
+	codepoints := []rune(cluster)
+	if len(codepoints) == 1 {
+		return uint32(codepoints[0])
+	}
+	return Murmur3(cluster, ^seed) | 0x80000000
+}
+
 // Return Murmur3 hash function of a string using each character as a u32 block
 func Murmur3(key string, seed uint32) uint32 {
 	h := seed
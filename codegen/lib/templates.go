@@ -91,17 +91,30 @@ pub const M3_SEED: u32 = {{.M3Seed}};
 ///
 /// Returns: Result<(blit pattern offset into DATA, bytes of cluster used by match)>
 pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGlyphErr> {
-    let first_char: u32;
-    match cluster.chars().next() {
-        Some(c) => first_char = c as u32,
+    let first_char: u32 = match cluster.chars().next() {
+        Some(c) => c as u32,
         None => return Err(NoGlyphErr),
+    };
+    {{- if eq .Font.Name "Emoji"}}
+    // Regional indicator symbols only form a flag when they come in pairs, so
+    // don't let a pair with no flag glyph fall back to two single letters
+    if super::regional_indicator_pair(cluster).is_some() {
+        return match find_enclosed_alphanumeric_supplement(cluster, 2) {
+            Some((offset, bytes_used)) => Ok((GlyphData::Emoji(offset), bytes_used)),
+            None => Err(NoGlyphErr),
+        };
     }
-    return match first_char {
+    {{- end}}
+    match first_char {
         {{ range $_, $k := .GS.IndexKeys -}}
         {{- with $dex := index $.GS.Index $k -}}
         0x{{printf "%X" $k.Low}}..=0x{{printf "%X" $k.High}} => {
             {{ range $_, $gcLen := $dex.ClusterLengthList -}}
+            {{ if and (eq $gcLen 1) $dex.HasCodepointTable -}}
+            if let Some((offset, bytes_used)) = find_{{ToLower $k.Name}}_codepoint(cluster) {
+            {{- else -}}
             if let Some((offset, bytes_used)) = find_{{ToLower $k.Name}}(cluster, {{$gcLen}}) {
+            {{- end}}
                 Ok((GlyphData::{{$.Font.Name}}(offset), bytes_used))
             } else {{ end }}{
                 Err(NoGlyphErr)
@@ -110,33 +123,91 @@ pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGl
         {{ end -}}
         {{- end -}}
         _ => Err(super::NoGlyphErr),
-    };
+    }
 }
 
 {{ range $_, $k := .GS.IndexKeys -}}
 {{- with $dex := index $.GS.Index $k -}}
+{{- if $dex.HasCodepointTable -}}
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_{{ToLower $k.Name}}_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_{{$k.Name}}, 0x{{printf "%X" $dex.CodepointTableLow}}, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x{{printf "%X" $dex.CodepointTableLow}}..=0x{{printf "%X" $dex.CodepointTableHigh}}, or NO_GLYPH
+static CODEPOINT_{{$k.Name}}: [u32; {{$dex.CodepointTableLen}}] = [
+    {{$dex.RustCodeForCodepointTable}}
+];
+
+{{ end -}}
+{{- with $hdex := $dex.HashEntries -}}
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_{{ToLower $k.Name}}(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_{{$k.Name}}.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_{{$k.Name}}[index], bytes_hashed)),
+    {{- if gt (index $hdex.ClusterLengthList 0) 1}}
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    {{- end}}
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_{{$k.Name}}.binary_search(&hash) {
+        Ok(index) if KEY_{{$k.Name}}[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_{{$k.Name}}[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_{{$k.Name}}
-const HASH_{{$k.Name}}: [u32; {{len $dex}}] = [
-    {{$dex.RustCodeForClusterHashes}}
+static HASH_{{$k.Name}}: [u32; {{len $hdex}}] = [
+    {{$hdex.RustCodeForClusterHashes}}
+];
+
+/// Verification keys to rule out hash collisions; sort matches HASH_{{$k.Name}}
+static KEY_{{$k.Name}}: [u32; {{len $hdex}}] = [
+    {{$hdex.RustCodeForClusterKeys}}
 ];
 
 /// Lookup table of blit pattern offsets; sort matches HASH_{{$k.Name}}
-const OFFSET_{{$k.Name}}: [usize; {{len $dex}}] = [
-    {{$dex.RustCodeForOffsets}}
+static OFFSET_{{$k.Name}}: [usize; {{len $hdex}}] = [
+    {{$hdex.RustCodeForOffsets}}
 ];
 
 {{ end -}}
 {{- end -}}
+{{- end -}}
+
+/// Index blocks in the order that get_blit_pattern_offset() checks them
+pub static BLOCKS: [super::Block; {{len .GS.IndexKeys}}] = [
+{{- range $_, $k := .GS.IndexKeys }}
+{{- with $dex := index $.GS.Index $k }}
+    super::Block {
+        low: 0x{{printf "%X" $k.Low}},
+        high: 0x{{printf "%X" $k.High}},
+        cluster_lengths: &[{{range $i, $n := $dex.ClusterLengthList}}{{if $i}}, {{end}}{{$n}}{{end}}],
+        {{- if $dex.HasCodepointTable}}
+        codepoints_low: 0x{{printf "%X" $dex.CodepointTableLow}},
+        codepoints: &CODEPOINT_{{$k.Name}},
+        {{- else}}
+        codepoints_low: 0x{{printf "%X" $k.Low}},
+        codepoints: &[],
+        {{- end}}
+        {{- if $dex.HashEntries}}
+        hashes: &HASH_{{$k.Name}},
+        keys: &KEY_{{$k.Name}},
+        offsets: &OFFSET_{{$k.Name}},
+        {{- else}}
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+        {{- end}}
+    },
+{{- end}}
+{{- end}}
+];
 
 /// Packed glyph pattern data.
 /// Record format:
@@ -148,8 +219,13 @@ const OFFSET_{{$k.Name}}: [usize; {{len $dex}}] = [
 ///  h: Height of pattern in pixels
 ///  yOffset: Vertical offset (pixels downward from top of line) to position
 ///     glyph pattern properly relative to text baseline
-pub const DATA: [u32; {{.GS.DataLen}}] = [
+{{- if .Font.Compress}}
+/// Glyphs that take fewer words compressed have ((n as u8) << 24) in the
+/// header, followed by n words of compressed pattern data (see compress.rs).
+{{- end}}
+pub static DATA: [u32; {{.GS.DataLen}}] = [
 {{.GS.Code}}];
+{{- if .GS.HasHashEntries}}
 
 #[cfg(test)]
 mod tests {
@@ -159,9 +235,11 @@ mod tests {
     // If this fails, there's probably a hash collision, so change the seed.
     fn test_hashes_unique_and_sorted() {
 {{- range $_, $k := .GS.IndexKeys }}
+{{- if (index $.GS.Index $k).HashEntries }}
         for i in 0..HASH_{{$k.Name}}.len()-1 {
             assert!(HASH_{{$k.Name}}[i] < HASH_{{$k.Name}}[i+1]);
-        }{{ end }}
+        }{{ end }}{{ end }}
     }
 }
+{{- end}}
 `
@@ -22,10 +22,14 @@ func knownBlocks() []UBlock {
 		UBlock{0x0000, 0x007F, "BASIC_LATIN"},                             // Latin, Emoji
 		UBlock{0x0080, 0x00FF, "LATIN_1_SUPPLEMENT"},                      // Latin, Emoji
 		UBlock{0x0100, 0x017F, "LATIN_EXTENDED_A"},                        // Latin
+		UBlock{0x0370, 0x03FF, "GREEK_AND_COPTIC"},                        // Latin
+		UBlock{0x0400, 0x04FF, "CYRILLIC"},                                // Latin
+		UBlock{0x1100, 0x11FF, "HANGUL_JAMO"},                             // Jamo
 		UBlock{0x2000, 0x206F, "GENERAL_PUNCTUATION"},                     // Latin, Emoji
 		UBlock{0x20A0, 0x20CF, "CURRENCY_SYMBOLS"},                        // Latin
 		UBlock{0x2100, 0x214F, "LETTERLIKE_SYMBOLS"},                      // Emoji
 		UBlock{0x2190, 0x21FF, "ARROWS"},                                  // Emoji
+		UBlock{0x2200, 0x22FF, "MATHEMATICAL_OPERATORS"},                  // Numeric
 		UBlock{0x2300, 0x23FF, "MISCELLANEOUS_TECHNICAL"},                 // Emoji
 		UBlock{0x2460, 0x24FF, "ENCLOSED_ALPHANUMERICS"},                  // Emoji
 		UBlock{0x25A0, 0x25FF, "GEOMETRIC_SHAPES"},                        // Emoji
@@ -34,6 +38,8 @@ func knownBlocks() []UBlock {
 		UBlock{0x2900, 0x297F, "SUPPLEMENTAL_ARROWS_B"},                   // Emoji
 		UBlock{0x2B00, 0x2BFF, "MISCELLANEOUS_SYMBOLS_AND_ARROWS"},        // Emoji
 		UBlock{0x3000, 0x303F, "CJK_SYMBOLS_AND_PUNCTUATION"},             // Emoji
+		UBlock{0x3040, 0x309F, "HIRAGANA"},                                // Kana
+		UBlock{0x30A0, 0x30FF, "KATAKANA"},                                // Kana
 		UBlock{0x3200, 0x32FF, "ENCLOSED_CJK_LETTERS_AND_MONTHS"},         // Emoji
 		UBlock{0x3400, 0x4DBF, "CJK_UNIFIED_IDEOGRAPHS_EXTENSION_A"},
 		UBlock{0x4E00, 0x9FFF, "CJK_UNIFIED_IDEOGRAPHS"},
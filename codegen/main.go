@@ -51,6 +51,9 @@ func patternListFromSpriteSheet(fs FontSpec) []BlitPattern {
 	var patternList []BlitPattern
 	for _, cs := range fs.CSList {
 		blitPattern := NewBlitPattern(img, fs, cs, enableDebug)
+		if fs.Compress {
+			blitPattern = blitPattern.Compress()
+		}
 		patternList = append(patternList, blitPattern)
 	}
 	return patternList
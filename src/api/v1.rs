@@ -8,13 +8,32 @@
 //! refactor without breaking the public api.
 
 // Re-export names from modules into the v1 namespace
-pub use crate::blit::{clear_region, paint_str};
+pub use crate::blit::{
+    clear_region, paint_icon, paint_str, paint_str_with_cache, paint_str_with_fallback,
+    paint_str_with_options,
+};
+pub use crate::cache::{CachedGlyph, GlyphCache};
 pub use crate::cliprect::ClipRect;
+pub use crate::coverage::{coverage, coverage_in, has_glyph, has_glyph_in, Coverage};
 pub use crate::cursor::Cursor;
+pub use crate::custom::{CustomGlyph, CustomGlyphErr, CustomGlyphs};
+// The crate root's own `pub mod demo` shadows this in its glob import, but
+// keep it so that the v1 namespace has every public name
+#[allow(unused_imports)]
 pub use crate::demo;
+pub use crate::fallback::{FallbackChain, GlyphSource, MissingGlyph};
+pub use crate::font::{BuiltinFont, Font};
+pub use crate::fontfile::{serialize, serialized_len, FontFile, FontFileErr};
+#[doc(hidden)]
+pub use crate::fonts::set_hash_all_clusters;
+pub use crate::fonts::GlyphHeader;
 pub use crate::framebuffer::{new_fr_buf, FrBuf, FRAME_BUF_SIZE, LINES, WIDTH, WORDS_PER_LINE};
 pub use crate::glyphstyle::{glyph_to_height_hint, GlyphStyle};
+pub use crate::icon::Icon;
+pub use crate::options::PaintOptions;
 pub use crate::pt::Pt;
+pub use crate::scale::Scale;
+pub use crate::synthetic::Synthetic;
 
 /// These tests aim to cover all names exported in the v1 api
 #[cfg(test)]
@@ -23,6 +42,7 @@ mod tests {
     use crate::m3hash;
 
     #[test]
+    #[cfg(feature = "latin-regular")]
     fn test_api_v1_blit() {
         let fb = &mut new_fr_buf();
         let clip = ClipRect::full_screen();
@@ -32,6 +52,67 @@ mod tests {
         assert_eq!(m3hash::frame_buffer(fb, 0), 0x529828DB);
     }
 
+    #[test]
+    #[cfg(feature = "latin-regular")]
+    fn test_api_v1_scale() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        let options = PaintOptions::new(FallbackChain::EMOJI_FIRST).with_scale(Scale::X2);
+        assert_eq!(options, PaintOptions::default().with_scale(Scale::X2));
+        paint_str_with_options(fb, clip, cursor, GlyphStyle::Regular, options, None, "abc");
+        assert_eq!(m3hash::frame_buffer(fb, 0), 0xA8B47D52);
+        assert_eq!(Scale::X4.factor(), 4);
+        assert_eq!(Scale::default(), Scale::X1);
+    }
+
+    #[test]
+    #[cfg(feature = "latin-regular")]
+    fn test_api_v1_synthetic() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        let synthetic = Synthetic {
+            bold: true,
+            oblique: false,
+        };
+        assert_eq!(synthetic, Synthetic::BOLD);
+        let options = PaintOptions::default().with_synthetic(synthetic);
+        paint_str_with_options(fb, clip, cursor, GlyphStyle::Regular, options, None, "abc");
+        assert_eq!(m3hash::frame_buffer(fb, 0), 0xC51AEDC2);
+        assert_eq!(Synthetic::default(), Synthetic::NONE);
+    }
+
+    #[test]
+    #[cfg(all(feature = "emoji", feature = "latin-regular"))]
+    fn test_api_v1_fallback() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        let chain = FallbackChain::new(&[GlyphSource::Latin, GlyphSource::Emoji]);
+        paint_str_with_fallback(fb, clip, cursor, GlyphStyle::Regular, chain, "abc");
+        assert_eq!(m3hash::frame_buffer(fb, 0), 0x529828DB);
+        assert_eq!(FallbackChain::default(), FallbackChain::EMOJI_FIRST);
+        assert_ne!(FallbackChain::TEXT_FIRST, FallbackChain::EMOJI_FIRST);
+        let missing = FallbackChain::TEXT_FIRST.with_hex_codes().missing;
+        assert_eq!(missing, MissingGlyph::HexCode);
+    }
+
+    #[test]
+    fn test_api_v1_coverage() {
+        assert_eq!(has_glyph("a", GlyphStyle::Bold), Some(GlyphSource::Latin));
+        let mut missing: Coverage = coverage("a\u{E000}", GlyphStyle::Bold);
+        assert_eq!(missing.next(), Some(1..4));
+        assert_eq!(missing.next(), None);
+        let (st, chain) = (GlyphStyle::Bold, FallbackChain::TEXT_FIRST);
+        assert_eq!(has_glyph_in("a", st, chain), Some(GlyphSource::Latin));
+        let mut missing: Coverage = coverage_in("a\u{E000}", st, chain);
+        assert_eq!(missing.next(), Some(1..4));
+    }
+
     #[test]
     fn test_api_v1_cliprect() {
         let cr1 = ClipRect {
@@ -55,6 +136,49 @@ mod tests {
     }
 
     #[test]
+    fn test_api_v1_custom_glyphs() {
+        static BAR: [u32; 2] = [0xFF00_0000, 0xFF00_0000];
+        let mut slots: [Option<CustomGlyph>; 1] = [None];
+        let mut glyphs = CustomGlyphs::new(&mut slots);
+        assert_eq!(glyphs.register('\u{E000}', 8, &BAR), Ok(()));
+        let err: CustomGlyphErr = glyphs.register('\u{E001}', 8, &BAR).unwrap_err();
+        assert_eq!(err, CustomGlyphErr::Full);
+        let chain = FallbackChain::TEXT_FIRST.with_custom_glyphs(&glyphs);
+        assert_eq!(chain.custom.map(|custom| custom.len()), Some(1));
+    }
+
+    #[test]
+    #[cfg(feature = "latin-small")]
+    fn test_api_v1_font() {
+        let font = BuiltinFont::Small;
+        let (glyph, bytes_used) = font.lookup("abc").unwrap();
+        assert_eq!(bytes_used, 1);
+        let header: GlyphHeader = font.header(glyph);
+        assert!(header.h + header.y_offset <= font.max_height());
+        assert_ne!(font.nth_word(glyph, 1), 0);
+        let chain = FallbackChain::TEXT_FIRST.with_font(&font);
+        assert!(chain.font.is_some());
+    }
+
+    #[test]
+    fn test_api_v1_font_file() {
+        let buf = &mut [0; 8_000];
+        assert!(serialized_len(BuiltinFont::Kana) <= buf.len());
+        let len = serialize(BuiltinFont::Kana, buf).unwrap();
+        let file: FontFile = FontFile::from_words(&buf[..len]).unwrap();
+        assert!(file.lookup("カ").is_some());
+        let err: FontFileErr = FontFile::from_bytes(&[0; 4]).unwrap_err();
+        assert_eq!(err, FontFileErr::Truncated);
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "emoji",
+        feature = "hanzi",
+        feature = "latin-small",
+        feature = "latin-regular",
+        feature = "latin-bold"
+    ))]
     fn test_api_v1_demo() {
         let fb = &mut new_fr_buf();
         demo::sample_text(fb);
@@ -66,12 +190,31 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::assertions_on_constants)]
     fn test_api_v1_framebuffer() {
         assert_eq!(LINES * WORDS_PER_LINE, FRAME_BUF_SIZE);
         assert!(LINES > 0);
         assert!(WIDTH > 0);
         let fb: FrBuf = new_fr_buf();
-        assert!(fb.len() > 0);
+        assert!(!fb.is_empty());
+    }
+
+    #[test]
+    fn test_api_v1_glyph_cache() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let st = GlyphStyle::Small;
+        let slots: &mut [Option<CachedGlyph>] = &mut [None; 8];
+        let mut cache: GlyphCache = GlyphCache::new(slots);
+        for _ in 0..2 {
+            let cursor = &mut Cursor::from_top_left_of(clip);
+            paint_str_with_cache(fb, clip, cursor, st, &mut cache, "\u{E700}");
+        }
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+        assert_eq!(cache.len(), 1);
+        cache.clear();
+        cache.reset_stats();
+        assert!(cache.is_empty());
     }
 
     #[test]
@@ -79,12 +222,38 @@ mod tests {
         let s: usize = GlyphStyle::Small.into();
         let r: usize = GlyphStyle::Regular.into();
         let b: usize = GlyphStyle::Bold.into();
+        let n: usize = GlyphStyle::Numeric.into();
         assert_eq!(GlyphStyle::Small, GlyphStyle::from(s));
         assert_eq!(GlyphStyle::Regular, GlyphStyle::from(r));
         assert_eq!(GlyphStyle::Bold, GlyphStyle::from(b));
+        assert_eq!(GlyphStyle::Numeric, GlyphStyle::from(n));
+        #[cfg(feature = "latin-small")]
         assert_eq!(24, glyph_to_height_hint(GlyphStyle::Small));
+        #[cfg(feature = "latin-regular")]
         assert_eq!(30, glyph_to_height_hint(GlyphStyle::Regular));
+        #[cfg(feature = "latin-bold")]
         assert_eq!(30, glyph_to_height_hint(GlyphStyle::Bold));
+        #[cfg(feature = "numeric")]
+        assert_eq!(64, glyph_to_height_hint(GlyphStyle::Numeric));
+    }
+
+    #[test]
+    fn test_api_v1_icon() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_icon(fb, clip, cursor, Icon::battery(50));
+        paint_icon(fb, clip, cursor, Icon::radio(Some(2)));
+        let hash = m3hash::frame_buffer(fb, 0);
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str(fb, clip, cursor, GlyphStyle::Small, "\u{E702}\u{E706}");
+        assert_eq!(m3hash::frame_buffer(fb, 0), hash);
+        assert_eq!(
+            has_glyph("\u{E70C}", GlyphStyle::Bold),
+            Some(GlyphSource::Icons)
+        );
     }
 
     #[test]
@@ -0,0 +1,71 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+use blitstr::{coverage, demo, set_hash_all_clusters, FrBuf, GlyphStyle};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// This measures how many times per second the demo screens can get painted,
+/// and how many times per second the glyphs for their text can get looked up
+/// without painting. Lookups are also measured with font files hashing every
+/// cluster, as they did before they checked whether the next char can continue
+/// the cluster, to show what skipping the hash for single codepoints saves.
+/// For meaningful numbers, build with optimizations:
+/// `cargo run --release --bin bench`
+fn main() {
+    let fb = &mut blitstr::new_fr_buf();
+    report("sample_text: paint", bench(fb, demo::sample_text));
+    report("goose_poem: paint", bench(fb, demo::goose_poem));
+    compare("sample_text: lookups", fb, |_| {
+        lookups(demo::NOTE);
+        lookups(demo::EMOJI_CAPTIONS);
+        lookups(demo::PANGRAMS);
+    });
+    compare("goose_poem: lookups", fb, |_| lookups(demo::GOOSE_POEM));
+}
+
+/// Look up glyphs for all of text the same way paint_str() does
+fn lookups(text: &str) {
+    black_box(coverage(black_box(text), GlyphStyle::Regular).count());
+}
+
+/// Print runs per second
+fn report(name: &str, per_sec: f64) {
+    println!("{:<32} {:>10.0} /s", name, per_sec);
+}
+
+/// Print runs per second of f with font files hashing every cluster, then
+/// with them skipping the hash for single codepoints, and the speedup
+fn compare(name: &str, fb: &mut FrBuf, f: fn(&mut FrBuf)) {
+    set_hash_all_clusters(true);
+    let hash_all = bench(fb, f);
+    set_hash_all_clusters(false);
+    let skip_hash = bench(fb, f);
+    report(&format!("{} (hash all)", name), hash_all);
+    report(&format!("{} (skip hash)", name), skip_hash);
+    println!("{:<32} {:>10.2}x", "  speedup", skip_hash / hash_all);
+}
+
+/// Run f over and over in several rounds, and return how many runs per second
+/// the fastest round got, which is the one least disturbed by other processes
+fn bench(fb: &mut FrBuf, f: fn(&mut FrBuf)) -> f64 {
+    // Warm up caches before timing
+    f(fb);
+    let mut best = 0.0;
+    for _ in 0..10 {
+        let mut runs = 0;
+        let start = Instant::now();
+        while start.elapsed() < Duration::from_millis(200) {
+            for _ in 0..50 {
+                f(black_box(&mut *fb));
+            }
+            runs += 50;
+        }
+        let per_sec = runs as f64 / start.elapsed().as_secs_f64();
+        if per_sec > best {
+            best = per_sec;
+        }
+    }
+    best
+}
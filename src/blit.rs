@@ -3,12 +3,23 @@
 //
 #![forbid(unsafe_code)]
 
+use crate::cache::GlyphCache;
 use crate::cliprect::ClipRect;
 use crate::cursor::Cursor;
+use crate::custom::{self, CustomGlyphs};
+use crate::fallback::{ChainKey, FallbackChain, GlyphSource, MissingGlyph};
+use crate::font::Font;
 use crate::fonts;
-use crate::fonts::{GlyphSet, NoGlyphErr};
+use crate::fonts::compress::{Row, Rows, MAX_WIDTH, ROW_WORDS};
+use crate::fonts::marks::{self, MarkGlyph, Placement};
+use crate::fonts::{GlyphData, GlyphHeader, GlyphSet, NoGlyphErr};
 use crate::framebuffer::{FrBuf, LINES, WIDTH, WORDS_PER_LINE};
 use crate::glyphstyle::GlyphStyle;
+use crate::icon::Icon;
+use crate::normalize;
+use crate::options::PaintOptions;
+use crate::scale::Scale;
+use crate::synthetic::Synthetic;
 
 /// Clear a screen region bounded by (clip.min.x,clip.min.y)..(clip.min.x,clip.max.y)
 pub fn clear_region(fb: &mut FrBuf, clip: ClipRect) {
@@ -37,61 +48,467 @@ pub fn clear_region(fb: &mut FrBuf, clip: ClipRect) {
     }
 }
 
-/// XOR blit a string with specified style, clip rect, starting at cursor
+/// XOR blit a string with specified style, clip rect, starting at cursor.
+/// Glyphs are looked up in the FallbackChain::EMOJI_FIRST order.
 pub fn paint_str(fb: &mut FrBuf, clip: ClipRect, c: &mut Cursor, st: GlyphStyle, s: &str) {
-    // Look up the latin GlyphSet for the requested GlyphStyle (emoji & hanzi are always included)
-    let gs_latin = match st {
-        GlyphStyle::Bold => GlyphSet::Bold,
-        GlyphStyle::Regular => GlyphSet::Regular,
-        GlyphStyle::Small => GlyphSet::Small,
-    };
+    paint_str_with_fallback(fb, clip, c, st, FallbackChain::EMOJI_FIRST, s);
+}
+
+/// XOR blit a string the same way as paint_str(), but check cache for each
+/// grapheme cluster's glyph before looking it up, and add the glyphs that get
+/// looked up to cache
+pub fn paint_str_with_cache(
+    fb: &mut FrBuf,
+    clip: ClipRect,
+    c: &mut Cursor,
+    st: GlyphStyle,
+    cache: &mut GlyphCache,
+    s: &str,
+) {
+    paint(fb, clip, c, st, PaintOptions::default(), Some(cache), s);
+}
+
+/// XOR blit a string with specified style, clip rect, starting at cursor,
+/// looking up glyphs for each grapheme cluster in the order given by chain
+pub fn paint_str_with_fallback(
+    fb: &mut FrBuf,
+    clip: ClipRect,
+    c: &mut Cursor,
+    st: GlyphStyle,
+    chain: FallbackChain,
+    s: &str,
+) {
+    paint(fb, clip, c, st, PaintOptions::new(chain), None, s);
+}
+
+/// XOR blit a string with specified style, clip rect, starting at cursor,
+/// looking up and painting glyphs as set by options. If there is a cache, it
+/// gets checked for each grapheme cluster's glyph before looking it up, and
+/// the glyphs that get looked up are added to it.
+pub fn paint_str_with_options(
+    fb: &mut FrBuf,
+    clip: ClipRect,
+    c: &mut Cursor,
+    st: GlyphStyle,
+    options: PaintOptions,
+    cache: Option<&mut GlyphCache>,
+    s: &str,
+) {
+    paint(fb, clip, c, st, options, cache, s);
+}
+
+/// XOR blit a string, looking up glyphs in the order given by options.chain,
+/// and checking cache first if there is one. Glyph pixels get painted as blocks
+/// of options.scale pixels, with options.synthetic applied to each glyph.
+fn paint(
+    fb: &mut FrBuf,
+    clip: ClipRect,
+    c: &mut Cursor,
+    st: GlyphStyle,
+    options: PaintOptions,
+    mut cache: Option<&mut GlyphCache>,
+    s: &str,
+) {
+    let fonts = Fonts::new(st, options.chain);
+    let scale = options.scale.factor();
+    let synthetic = options.synthetic;
     // Parse the string, consuming one grapheme cluster for each iteration of
     // the for loop. Since grapheme cluster length varies, s.len() is just an
     // upper bound that's only exact for pure ASCII strings.
     let mut cluster = s;
     for _ in 0..s.len() {
-        if cluster.len() < 1 {
+        if cluster.is_empty() {
             break; // All grapheme clusters have been consumed
         }
-        if Some('\n') == cluster.chars().next() {
+        if cluster.starts_with('\n') {
             // Handle whitespace, note that '\n' uses 1 byte
-            newline(clip, c);
+            newline(clip, c, scale);
             cluster = &cluster[1..];
-        } else if let Ok(bytes_used) = xor_char(fb, clip, c, cluster, GlyphSet::Emoji) {
-            cluster = &cluster[bytes_used..];
-        } else if let Ok(bytes_used) = xor_char(fb, clip, c, cluster, gs_latin) {
+        } else if starts_with_selector(cluster) {
+            // Variation selectors left over after a glyph lookup, or with no
+            // char to select, are invisible. Both use 3 bytes.
+            cluster = &cluster[3..];
+        } else if let Some(glyph) = fonts.find_glyph_cached(cache.as_deref_mut(), cluster) {
+            cluster = &cluster[xor_char(fb, clip, c, glyph, scale, synthetic)..];
+        } else if let Some(bytes_used) =
+            xor_equivalent(fb, clip, c, cluster, &fonts, scale, synthetic)
+        {
             cluster = &cluster[bytes_used..];
-        } else if let Ok(bytes_used) = xor_char(fb, clip, c, cluster, GlyphSet::Hanzi) {
+        } else if let Ok(bytes_used) = xor_region_code(fb, clip, c, cluster, scale) {
             cluster = &cluster[bytes_used..];
         } else {
-            // Fallback: use replacement character
-            if let Ok(_) = xor_char(fb, clip, c, &"\u{FFFD}", gs_latin) {
-                // Advance string slice position by consuming one UTF-8 character
-                if let Some((i, _)) = cluster.char_indices().nth(1) {
-                    cluster = &cluster[i..];
-                } else {
-                    //cluster = &cluster[1..];
-                    //break; // That was the last char, so stop now
+            // Fallback: use replacement character or hex code box
+            match fonts.chain.missing {
+                MissingGlyph::Replacement => {
+                    if let Some(glyph) = lookup("\u{FFFD}", fonts.gs_latin) {
+                        xor_char(fb, clip, c, glyph, scale, synthetic);
+                    }
+                }
+                MissingGlyph::HexCode => xor_hex_code(fb, clip, c, cluster, fonts.gs_latin, scale),
+            }
+            // Advance string slice position by consuming one UTF-8 character
+            if let Some((i, _)) = cluster.char_indices().nth(1) {
+                cluster = &cluster[i..];
+            } else {
+                break; // That was the last char, so stop now
+            }
+        }
+    }
+}
+
+/// XOR blit a Precursor UI icon at the cursor, the same way paint_str() would
+/// paint its Private Use Area char
+pub fn paint_icon(fb: &mut FrBuf, clip: ClipRect, c: &mut Cursor, icon: Icon) {
+    let ch = char::from(icon);
+    if let Some(glyph) = lookup(ch.encode_utf8(&mut [0; 4]), GlyphSet::Icons) {
+        xor_char(fb, clip, c, glyph, 1, Synthetic::NONE);
+    }
+}
+
+/// Blit pattern for a glyph from a built-in glyph set or a Font object
+#[derive(Copy, Clone)]
+enum Pattern<'a> {
+    Builtin(GlyphData),
+    Font(&'a dyn Font, usize),
+}
+
+impl Pattern<'_> {
+    /// Unpack the glyph header
+    fn header(self) -> GlyphHeader {
+        match self {
+            Pattern::Builtin(glyph_data) => glyph_data.header(),
+            Pattern::Font(font, glyph) => font.header(glyph),
+        }
+    }
+
+    /// Unpack the nth pattern data word following the header
+    fn nth_word(self, n: usize) -> u32 {
+        match self {
+            Pattern::Builtin(glyph_data) => glyph_data.nth_word(n),
+            Pattern::Font(font, glyph) => font.nth_word(glyph, n),
+        }
+    }
+
+    /// Words of compressed pattern data, or 0 for packed pixels
+    fn compressed_len(self) -> usize {
+        match self {
+            Pattern::Builtin(glyph_data) => glyph_data.compressed_len(),
+            Pattern::Font(font, glyph) => font.compressed_len(glyph),
+        }
+    }
+}
+
+/// Glyph found for the start of a string by a glyph set lookup
+#[derive(Copy, Clone)]
+struct Glyph<'a> {
+    gs: GlyphSet,
+    pattern: Pattern<'a>,
+    /// Bytes of the string matched by pattern
+    bytes_used: usize,
+    /// Combining marks following the match that get overlaid on the glyph
+    marks: &'a str,
+}
+
+impl Glyph<'_> {
+    /// Glyph source that the glyph set belongs to
+    fn source(&self) -> GlyphSource {
+        match self.gs {
+            #[cfg(feature = "emoji")]
+            GlyphSet::Emoji => GlyphSource::Emoji,
+            #[cfg(feature = "hanzi")]
+            GlyphSet::Hanzi => GlyphSource::Hanzi,
+            GlyphSet::Kana => GlyphSource::Kana,
+            GlyphSet::Hangul => GlyphSource::Hangul,
+            GlyphSet::BoxDrawing(_) => GlyphSource::BoxDrawing,
+            GlyphSet::Braille(_) => GlyphSource::Braille,
+            GlyphSet::Icons => GlyphSource::Icons,
+            GlyphSet::Custom(_) => GlyphSource::Custom,
+            GlyphSet::Font(_) => GlyphSource::Font,
+            #[cfg(feature = "latin-bold")]
+            GlyphSet::Bold => GlyphSource::Latin,
+            #[cfg(feature = "latin-regular")]
+            GlyphSet::Regular => GlyphSource::Latin,
+            #[cfg(feature = "latin-small")]
+            GlyphSet::Small => GlyphSource::Latin,
+            #[cfg(feature = "numeric")]
+            GlyphSet::Numeric => GlyphSource::Latin,
+        }
+    }
+
+    /// Bytes of the string used by the glyph plus its overlaid marks
+    fn len(&self) -> usize {
+        self.bytes_used + self.marks.len()
+    }
+}
+
+/// Look up the glyph for the start of cluster in glyph set gs
+fn lookup(cluster: &str, gs: GlyphSet) -> Option<Glyph<'_>> {
+    let (glyph_data, bytes_used) = gs.get_blit_pattern_offset(cluster).ok()?;
+    // Combining marks that the latin font has no precomposed glyph for get
+    // overlaid on the base glyph rather than being left for U+FFFD
+    let marks = match gs.is_latin() {
+        true => supported_marks(&cluster[bytes_used..]),
+        false => "",
+    };
+    Some(Glyph {
+        gs,
+        pattern: Pattern::Builtin(glyph_data),
+        bytes_used,
+        marks,
+    })
+}
+
+/// Look up the custom glyph for the start of cluster in a registry
+fn lookup_custom<'s>(cluster: &'s str, custom: &CustomGlyphs) -> Option<Glyph<'s>> {
+    let (glyph_data, bytes_used) = custom.find(cluster)?;
+    let h = glyph_data.header().h;
+    Some(Glyph {
+        gs: GlyphSet::Custom(h as u8),
+        pattern: Pattern::Builtin(glyph_data),
+        bytes_used,
+        marks: "",
+    })
+}
+
+/// Look up the glyph for the start of cluster in a Font object
+fn lookup_font<'s>(cluster: &'s str, font: &'s dyn Font) -> Option<Glyph<'s>> {
+    let (glyph, bytes_used) = font.lookup(cluster)?;
+    // Rows get unpacked into buffers sized for the widest built-in glyph, so
+    // wider glyphs can't be painted
+    if font.header(glyph).w > MAX_WIDTH {
+        return None;
+    }
+    Some(Glyph {
+        gs: GlyphSet::Font(font.max_height().min(255) as u8),
+        pattern: Pattern::Font(font, glyph),
+        bytes_used,
+        marks: "",
+    })
+}
+
+/// Glyph sets to search when painting a string
+pub struct Fonts<'a> {
+    st: GlyphStyle,
+    /// Latin glyph set for the GlyphStyle being painted
+    gs_latin: GlyphSet,
+    chain: FallbackChain<'a>,
+    /// Key for the chain's glyphs in a GlyphCache
+    cache_key: Option<ChainKey>,
+}
+
+/// Variation selector 15 requests text presentation of the preceding char
+const TEXT_PRESENTATION: char = '\u{FE0E}';
+
+/// Variation selector 16 requests emoji presentation of the preceding char
+const EMOJI_PRESENTATION: char = '\u{FE0F}';
+
+/// Return true if s starts with a presentation selector
+pub fn starts_with_selector(s: &str) -> bool {
+    s.starts_with(TEXT_PRESENTATION) || s.starts_with(EMOJI_PRESENTATION)
+}
+
+impl<'a> Fonts<'a> {
+    /// Make glyph sets for painting with style st, searched in chain order
+    pub fn new(st: GlyphStyle, chain: FallbackChain<'a>) -> Self {
+        // Look up the latin GlyphSet for the requested GlyphStyle
+        let gs_latin = GlyphSet::latin(st);
+        Fonts {
+            st,
+            gs_latin,
+            chain,
+            cache_key: chain.cache_key(),
+        }
+    }
+
+    /// Find the glyph source that paint_str would use for the start of
+    /// cluster, trying the same glyph lookups, Unicode equivalents, and region
+    /// code boxes as paint_str (but not newlines or presentation selectors).
+    /// Return: (glyph source, bytes of cluster used), or None for U+FFFD
+    pub fn find_source(&self, cluster: &str) -> Option<(GlyphSource, usize)> {
+        if let Some(glyph) = self.find_glyph(cluster) {
+            Some((glyph.source(), glyph.len()))
+        } else if let Some((form, bytes_used)) = self.equivalent(cluster) {
+            let glyph = self.find_glyph(form.as_str())?;
+            Some((glyph.source(), bytes_used))
+        } else if let Some((_, _, bytes_used)) = fonts::regional_indicator_pair(cluster) {
+            Some((GlyphSource::Latin, bytes_used))
+        } else {
+            None
+        }
+    }
+
+    /// Find a Unicode equivalent of the first base char + combining marks of
+    /// cluster. Candidates are its canonical decomposition, canonical
+    /// composition, and compatibility fold, in that order. The first candidate
+    /// with glyphs for all of its chars wins.
+    /// Return: (equivalent, bytes of cluster used), or None if no candidate had glyphs
+    fn equivalent(&self, cluster: &str) -> Option<(normalize::ClusterBuf, usize)> {
+        let unit = &cluster[..normalize::base_and_marks_len(cluster)];
+        let candidates = [
+            normalize::nfd(unit),
+            normalize::nfc(unit),
+            normalize::compatibility_fold(unit),
+        ];
+        for buf in candidates.iter().flatten() {
+            let form = buf.as_str();
+            if form != unit && self.has_glyphs(form) {
+                return Some((*buf, unit.len()));
+            }
+        }
+        None
+    }
+
+    /// Look up the glyph for the start of cluster. A presentation selector
+    /// after the first char picks the latin (U+FE0E) or emoji (U+FE0F) glyph
+    /// set when it has a glyph. Otherwise, glyph sources are tried in the order
+    /// of the fallback chain.
+    fn find_glyph<'s>(&self, cluster: &'s str) -> Option<Glyph<'s>>
+    where
+        'a: 's,
+    {
+        let mut chars = cluster.chars();
+        chars.next()?;
+        let selected = match chars.next() {
+            Some(TEXT_PRESENTATION) => self.lookup_latin(cluster),
+            #[cfg(feature = "emoji")]
+            Some(EMOJI_PRESENTATION) => lookup(cluster, GlyphSet::Emoji),
+            _ => None,
+        };
+        selected.or_else(|| self.find_in_chain(cluster))
+    }
+
+    /// Look up the latin glyph for the start of cluster. In Numeric style,
+    /// chars with no combining marks try the numeric glyph set first.
+    fn lookup_latin<'s>(&self, cluster: &'s str) -> Option<Glyph<'s>> {
+        #[cfg(feature = "numeric")]
+        if self.st == GlyphStyle::Numeric {
+            let first_len = cluster.chars().next().map_or(0, char::len_utf8);
+            if supported_marks(&cluster[first_len..]).is_empty() {
+                if let Some(glyph) = lookup(cluster, GlyphSet::Numeric) {
+                    return Some(glyph);
                 }
             }
         }
+        lookup(cluster, self.gs_latin)
+    }
+
+    /// Look up the glyph for the start of cluster the same way as find_glyph(),
+    /// but check cache first, and add the glyph to cache if it had to be
+    /// looked up
+    fn find_glyph_cached<'s>(
+        &self,
+        cache: Option<&mut GlyphCache>,
+        cluster: &'s str,
+    ) -> Option<Glyph<'s>>
+    where
+        'a: 's,
+    {
+        let (cache, key) = match (cache, self.cache_key) {
+            (Some(cache), Some(key)) => (cache, key),
+            _ => return self.find_glyph(cluster),
+        };
+        // A CustomGlyphs registry can change between paints, so its chars
+        // have to be looked up every time
+        let first = cluster.chars().next()?;
+        if self.chain.custom.is_some() && custom::is_private_use(first) {
+            return self.find_glyph(cluster);
+        }
+        if let Some((gs, glyph_data, bytes_used)) = cache.get(self.st, key, cluster) {
+            return Some(Glyph {
+                gs,
+                pattern: Pattern::Builtin(glyph_data),
+                bytes_used,
+                marks: "",
+            });
+        }
+        let glyph = self.find_glyph(cluster)?;
+        // Font object glyphs can't be cached, since they aren't GlyphData
+        if let (Pattern::Builtin(glyph_data), "") = (glyph.pattern, glyph.marks) {
+            let bytes_used = glyph.bytes_used;
+            cache.insert(self.st, key, cluster, glyph.gs, glyph_data, bytes_used);
+        }
+        Some(glyph)
+    }
+
+    /// Look up the glyph for the start of cluster, trying each glyph source in
+    /// the order of the fallback chain
+    fn find_in_chain<'s>(&self, cluster: &'s str) -> Option<Glyph<'s>>
+    where
+        'a: 's,
+    {
+        self.chain.sources.iter().find_map(|src| {
+            let gs = match src {
+                GlyphSource::Custom => return lookup_custom(cluster, self.chain.custom?),
+                GlyphSource::Font => return lookup_font(cluster, self.chain.font?),
+                #[cfg(feature = "emoji")]
+                GlyphSource::Emoji => GlyphSet::Emoji,
+                GlyphSource::Latin => return self.lookup_latin(cluster),
+                #[cfg(feature = "hanzi")]
+                GlyphSource::Hanzi => GlyphSet::Hanzi,
+                GlyphSource::Kana => GlyphSet::Kana,
+                GlyphSource::Hangul => GlyphSet::Hangul,
+                GlyphSource::BoxDrawing => GlyphSet::BoxDrawing(self.gs_latin.max_height() as u8),
+                GlyphSource::Braille => GlyphSet::Braille(self.gs_latin.max_height() as u8),
+                GlyphSource::Icons => GlyphSet::Icons,
+            };
+            lookup(cluster, gs)
+        })
+    }
+
+    /// Return true if find_glyph() has glyphs for all of s
+    fn has_glyphs(&self, s: &str) -> bool {
+        let mut rest = s;
+        while !rest.is_empty() {
+            match self.find_glyph(rest) {
+                Some(glyph) => rest = &rest[glyph.len()..],
+                None => return false,
+            }
+        }
+        true
     }
 }
 
-/// Advance the cursor to the start of a new line within the clip rect
-fn newline(clip: ClipRect, c: &mut Cursor) {
+/// Blit the Unicode equivalent of the first base char + combining marks of
+/// cluster found by Fonts::equivalent()
+/// Return: bytes of cluster used, or None if no equivalent had glyphs
+fn xor_equivalent(
+    fb: &mut FrBuf,
+    clip: ClipRect,
+    c: &mut Cursor,
+    cluster: &str,
+    fonts: &Fonts,
+    scale: usize,
+    synthetic: Synthetic,
+) -> Option<usize> {
+    let (form, bytes_used) = fonts.equivalent(cluster)?;
+    let mut rest = form.as_str();
+    while let Some(glyph) = fonts.find_glyph(rest) {
+        xor_char(fb, clip, c, glyph, scale, synthetic);
+        rest = &rest[glyph.len()..];
+    }
+    Some(bytes_used)
+}
+
+/// Advance the cursor to the start of a new line within the clip rect, with
+/// the minimum line height and line gap scaled up by scale
+fn newline(clip: ClipRect, c: &mut Cursor, scale: usize) {
     c.pt.x = clip.min.x;
-    if c.line_height < fonts::small::MAX_HEIGHT as usize {
-        c.line_height = fonts::small::MAX_HEIGHT as usize;
+    let min_line_height = GlyphSet::latin(GlyphStyle::Small).max_height() * scale;
+    if c.line_height < min_line_height {
+        c.line_height = min_line_height;
     }
-    c.pt.y += c.line_height + 1;
+    c.pt.y += c.line_height + scale;
     c.line_height = 0;
 }
 
-/// Blit a char with: XOR, align left:xr.0 top:yr.0, pad L:1px R:2px
-/// Return: width in pixels of character + padding that were blitted (0 if won't fit in clip region)
+/// Blit a char with: XOR, align left:xr.0 top:yr.0, pad L:1px R:2px, with
+/// glyph pixels and padding scaled up by scale. Synthetic styles apply to all
+/// glyphs but box drawing and braille cells, which have to join up with their
+/// neighbors.
+/// Return: bytes of string used by glyph and its marks (0 if it won't fit in clip region)
 ///
 /// Examples of word alignment for source data (rows of glpyh pixels)
+/// ```text
 /// 1. Fits in one word:
 ///    row_width:8, row:1 => (data[0].bit_27)->(data[0].bit_24), mask:0x0f00_0000
 ///    | data[0]                                 |
@@ -110,97 +527,535 @@ fn newline(clip: ClipRect, c: &mut Cursor) {
 /// Examples of word alignment for destination frame buffer:
 /// 1. Fits in word: xr:1..7   => (data[0].bit_30)->(data[0].bit_26), mask:0x7c00_0000
 /// 2. Spans words:  xr:30..36 => (data[0].bit_01)->(data[1].bit_29), mask:[0x0000_0003,0xe000_000]
-///
+/// ```
 fn xor_char(
     fb: &mut FrBuf,
     clip: ClipRect,
     c: &mut Cursor,
-    cluster: &str,
+    glyph: Glyph,
+    scale: usize,
+    synthetic: Synthetic,
+) -> usize {
+    if clip.max.y > LINES || clip.max.x > WIDTH || clip.min.x >= clip.max.x {
+        return 0;
+    }
+    // Unpack the glyph header
+    let pattern = glyph.pattern;
+    let gh = pattern.header();
+    if let Pattern::Builtin(glyph_data @ (GlyphData::BoxDrawing(_) | GlyphData::Braille(_))) =
+        pattern
+    {
+        return match xor_cell(fb, clip, c, glyph_data, glyph.gs.max_height(), scale) {
+            true => glyph.bytes_used,
+            false => 0,
+        };
+    }
+    if !glyph.marks.is_empty() {
+        let marks = glyph.marks;
+        return match xor_marked_char(fb, clip, c, glyph.gs, pattern, marks, scale, synthetic) {
+            true => glyph.len(),
+            false => 0,
+        };
+    }
+    let line_height = glyph.gs.max_height();
+    let strokes = Strokes::new(synthetic, line_height, gh.y_offset, gh.y_offset + gh.h);
+    let w = gh.w + strokes.extra_w();
+    let x0 = glyph_x0(clip, c, w, scale);
+    let y0 = c.pt.y + gh.y_offset * scale;
+    if y0 > clip.max.y {
+        return 0; // Entire glyph is outside clip rect, so clip it
+    }
+    xor_pattern(fb, clip, x0, y0, pattern, &gh, scale, strokes);
+    advance(c, w, line_height, scale);
+    glyph.bytes_used
+}
+
+/// Vertical gap in pixels between a base glyph and an overlaid combining mark
+const MARK_GAP: usize = 2;
+
+/// Return the leading run of combining marks in s that have mark glyphs
+fn supported_marks(s: &str) -> &str {
+    for (i, m) in s.char_indices() {
+        if marks::find_mark(m).is_none() {
+            return &s[..i];
+        }
+    }
+    s
+}
+
+/// Look up the blit pattern and placement for a combining mark in latin glyph set gs
+fn mark_glyph(gs: GlyphSet, m: char) -> Option<(GlyphData, Placement)> {
+    let (mark, placement) = marks::find_mark(m)?;
+    let glyph_data = match mark {
+        MarkGlyph::Spacing(ch) => {
+            let (glyph_data, _) = gs
+                .get_blit_pattern_offset(ch.encode_utf8(&mut [0; 4]))
+                .ok()?;
+            glyph_data
+        }
+        MarkGlyph::Pattern(offset) => GlyphData::Marks(offset),
+    };
+    Some((glyph_data, placement))
+}
+
+/// Blit a base glyph with a run of combining marks overlaid above, below, or
+/// to the right of it. Marks stack outward from the base in string order.
+/// Layout happens in glyph pixels, which get scaled up by scale for blitting.
+/// Return: false if the glyph was entirely outside the clip rect
+#[allow(clippy::too_many_arguments)]
+fn xor_marked_char(
+    fb: &mut FrBuf,
+    clip: ClipRect,
+    c: &mut Cursor,
     gs: GlyphSet,
+    base: Pattern,
+    marks: &str,
+    scale: usize,
+    synthetic: Synthetic,
+) -> bool {
+    let gh = base.header();
+    // Measure the cell: marks above or below can be wider than the base, and
+    // marks on the right extend past it
+    let mut center_w = gh.w;
+    let mut right_w = 0;
+    for m in marks.chars() {
+        if let Some((glyph_data, placement)) = mark_glyph(gs, m) {
+            let w = glyph_data.header().w;
+            match placement {
+                Placement::Right => right_w = right_w.max(w),
+                _ => center_w = center_w.max(w),
+            }
+        }
+    }
+    let bx = (center_w - gh.w) / 2;
+    // Stack marks outward from the top and bottom edges of the base glyph.
+    // Positions are measured from the top left corner of the cell's 1px left
+    // pad, so that marks can overhang into it.
+    let (line_top, line_bottom) = (0, gs.max_height());
+    let (base_x, base_top) = (1 + bx, gh.y_offset);
+    let axis = base_x + gh.w / 2;
+    let stack = |m: char, top: &mut usize, bottom: &mut usize| {
+        let (glyph_data, placement) = mark_glyph(gs, m)?;
+        let mh = glyph_data.header();
+        let (mx, my) = match placement {
+            Placement::Above => {
+                *top = top.saturating_sub(MARK_GAP + mh.h).max(line_top);
+                (axis - mh.w / 2, *top)
+            }
+            Placement::Below => {
+                let my = (*bottom + MARK_GAP).min(line_bottom.saturating_sub(mh.h));
+                *bottom = my + mh.h;
+                (axis - mh.w / 2, my)
+            }
+            Placement::Right => {
+                let my = (base_top + MARK_GAP).saturating_sub(mh.h).max(line_top);
+                (base_x + gh.w, my)
+            }
+        };
+        Some((mx, my, glyph_data, mh))
+    };
+    // Find the rows that the marks reach, since oblique shears the whole cell
+    let (mut top, mut bottom) = (base_top, base_top + gh.h);
+    let (mut cell_top, mut cell_bottom) = (top, bottom);
+    for m in marks.chars() {
+        if let Some((_, my, _, mh)) = stack(m, &mut top, &mut bottom) {
+            cell_top = cell_top.min(my);
+            cell_bottom = cell_bottom.max(my + mh.h);
+        }
+    }
+    let strokes = Strokes::new(synthetic, line_bottom, cell_top, cell_bottom);
+    let cell_w = center_w.max(bx + gh.w + right_w) + strokes.extra_w();
+    let pad_x = glyph_x0(clip, c, cell_w, scale) - scale;
+    let line_y = c.pt.y;
+    if line_y + base_top * scale > clip.max.y {
+        return false; // Entire glyph is outside clip rect, so clip it
+    }
+    let blit = |fb: &mut FrBuf, x: usize, y: usize, pattern: Pattern, gh: &GlyphHeader| {
+        let (sx, sy) = (pad_x + x * scale, line_y + y * scale);
+        xor_pattern(fb, clip, sx, sy, pattern, gh, scale, strokes.at_row(y));
+    };
+    blit(fb, base_x, base_top, base, &gh);
+    let (mut top, mut bottom) = (base_top, base_top + gh.h);
+    for m in marks.chars() {
+        if let Some((mx, my, glyph_data, mh)) = stack(m, &mut top, &mut bottom) {
+            blit(fb, mx, my, Pattern::Builtin(glyph_data), &mh);
+        }
+    }
+    advance(c, cell_w, line_bottom, scale);
+    true
+}
+
+/// Blit a box drawing, block element, or braille cell with no padding, so
+/// that lines, blocks, and dot grids join up with the cells next to them. Box
+/// drawing cells get stretched to reach the next line when the line is taller
+/// than the cell. Cell pixels get scaled up by scale.
+/// Return: false if the cell was entirely outside the clip rect
+fn xor_cell(
+    fb: &mut FrBuf,
+    clip: ClipRect,
+    c: &mut Cursor,
+    glyph_data: GlyphData,
+    line_height: usize,
+    scale: usize,
+) -> bool {
+    if c.pt.x < clip.min.x {
+        c.pt.x = clip.min.x;
+    }
+    let w = glyph_data.header().w * scale;
+    if c.pt.x + w > clip.max.x {
+        newline(clip, c, scale);
+    }
+    if c.pt.y > clip.max.y {
+        return false; // Entire cell is outside clip rect, so clip it
+    }
+    let line_height = line_height * scale;
+    let glyph_data = match glyph_data {
+        GlyphData::BoxDrawing(cell) => {
+            // Fit the cell in glyph pixels, rounding up so it still reaches
+            // the next line
+            let (x, y) = (c.pt.x / scale, c.pt.y / scale);
+            let h = c.line_height.max(line_height).div_ceil(scale);
+            GlyphData::BoxDrawing(cell.fit(x, y, h))
+        }
+        _ => glyph_data,
+    };
+    let (pattern, gh) = (Pattern::Builtin(glyph_data), glyph_data.header());
+    xor_pattern(fb, clip, c.pt.x, c.pt.y, pattern, &gh, scale, Strokes::NONE);
+    c.pt.x += w;
+    if line_height > c.line_height {
+        c.line_height = line_height;
+    }
+    true
+}
+
+/// Blit a boxed two letter region code for a pair of regional indicator
+/// symbols that has no flag glyph, for example "🇽🇽" => [XX]. Unpaired regional
+/// indicators are left for the emoji glyph set to handle.
+/// Return: bytes of cluster used by the pair
+fn xor_region_code(
+    fb: &mut FrBuf,
+    clip: ClipRect,
+    c: &mut Cursor,
+    cluster: &str,
+    scale: usize,
 ) -> Result<usize, NoGlyphErr> {
+    let (a, b, bytes_used) = match fonts::regional_indicator_pair(cluster) {
+        Some(pair) => pair,
+        None => return Err(NoGlyphErr),
+    };
     if clip.max.y > LINES || clip.max.x > WIDTH || clip.min.x >= clip.max.x {
         return Ok(0);
     }
-    // Look up glyph for grapheme cluster and unpack its header
-    let (glyph_data, bytes_used) = match gs {
-        GlyphSet::Emoji => fonts::emoji::get_blit_pattern_offset(cluster)?,
-        GlyphSet::Bold => fonts::bold::get_blit_pattern_offset(cluster)?,
-        GlyphSet::Regular => fonts::regular::get_blit_pattern_offset(cluster)?,
-        GlyphSet::Small => fonts::small::get_blit_pattern_offset(cluster)?,
-        GlyphSet::Hanzi => fonts::hanzi::get_blit_pattern_offset(cluster)?,
+    // Letters come from the small latin font so the box fits on any line
+    let gs = GlyphSet::latin(GlyphStyle::Small);
+    let (ga, _) = gs.get_blit_pattern_offset(a.encode_utf8(&mut [0; 4]))?;
+    let (gb, _) = gs.get_blit_pattern_offset(b.encode_utf8(&mut [0; 4]))?;
+    let (gha, ghb) = (ga.header(), gb.header());
+    // Box layout: 1px border, 2px pad, letter, 2px gap, letter, 2px pad, 1px border
+    let w = gha.w + ghb.w + 10;
+    let h = gs.max_height() - 5;
+    let x0 = glyph_x0(clip, c, w, scale);
+    let y0 = c.pt.y + 3 * scale;
+    if y0 > clip.max.y {
+        return Ok(0); // Entire box is outside clip rect, so clip it
+    }
+    xor_box_outline(fb, clip, x0, y0, w, h, scale);
+    let (pa, pb) = (Pattern::Builtin(ga), Pattern::Builtin(gb));
+    let (xa, xb) = (x0 + 3 * scale, x0 + (5 + gha.w) * scale);
+    let (ya, yb) = (c.pt.y + gha.y_offset * scale, c.pt.y + ghb.y_offset * scale);
+    xor_pattern(fb, clip, xa, ya, pa, &gha, scale, Strokes::NONE);
+    xor_pattern(fb, clip, xb, yb, pb, &ghb, scale, Strokes::NONE);
+    advance(c, w, gs.max_height(), scale);
+    Ok(bytes_used)
+}
+
+/// Blit a box containing the hex codepoint of the first char of cluster, for
+/// example U+E000 => [E0/00] with the digits in two rows. Codepoints above
+/// U+FFFF use three digits per row. Digits get scaled up to fill the line
+/// height of the latin glyph set gs_latin, then the whole box gets scaled up by
+/// scale.
+fn xor_hex_code(
+    fb: &mut FrBuf,
+    clip: ClipRect,
+    c: &mut Cursor,
+    cluster: &str,
+    gs_latin: GlyphSet,
+    scale: usize,
+) {
+    let cp = match cluster.chars().next() {
+        Some(ch) => ch as u32,
+        None => return,
     };
-    let gh = glyph_data.header();
-    if gh.w > 32 {
-        return Ok(0);
+    if clip.max.y > LINES || clip.max.x > WIDTH || clip.min.x >= clip.max.x {
+        return;
+    }
+    let line_height = gs_latin.max_height();
+    let cols = if cp > 0xFFFF { 3 } else { 2 };
+    // Box layout: 1px border, 2px pad, digit rows with 1 digit px gaps, 2px pad, 1px border
+    let h = line_height - 4;
+    let zoom = ((h - 6) / (2 * fonts::hex::HEIGHT + 1)).max(1);
+    let w = cols * (fonts::hex::WIDTH + 1) * zoom - zoom + 6;
+    let x0 = glyph_x0(clip, c, w, scale);
+    let y0 = c.pt.y + 2 * scale;
+    if y0 > clip.max.y {
+        return; // Entire box is outside clip rect, so clip it
+    }
+    xor_box_outline(fb, clip, x0, y0, w, h, scale);
+    // Center the digit rows vertically inside the box
+    let digits_h = (2 * fonts::hex::HEIGHT + 1) * zoom;
+    let top = (h - digits_h) / 2;
+    for i in 0..2 * cols {
+        let digit = cp >> (4 * (2 * cols - 1 - i));
+        let dx = 3 + (i % cols) * (fonts::hex::WIDTH + 1) * zoom;
+        let dy = top + (i / cols) * (fonts::hex::HEIGHT + 1) * zoom;
+        for y in 0..fonts::hex::HEIGHT * zoom {
+            for x in 0..fonts::hex::WIDTH * zoom {
+                if fonts::hex::pixel(digit, x / zoom, y / zoom) {
+                    let (px, py) = (x0 + (dx + x) * scale, y0 + (dy + y) * scale);
+                    xor_px(fb, clip, px, py, scale);
+                }
+            }
+        }
     }
+    advance(c, w, line_height, scale);
+}
+
+/// Prepare the cursor for blitting a glyph of width w, wrapping to a new line
+/// if needed. Width and padding are in glyph pixels, scaled up by scale.
+/// Return: x coordinate for the glyph's left edge (after 1px pad)
+fn glyph_x0(clip: ClipRect, c: &mut Cursor, w: usize, scale: usize) -> usize {
     // Don't clip if cursor is left of clip rect; instead, advance the cursor
     if c.pt.x < clip.min.x {
         c.pt.x = clip.min.x;
     }
     // Add 1px pad to left
-    let mut x0 = c.pt.x + 1;
+    let mut x0 = c.pt.x + scale;
     // Adjust for word wrapping
-    if x0 + gh.w + 2 >= clip.max.x {
-        newline(clip, c);
-        x0 = c.pt.x + 1;
+    if x0 + (w + 2) * scale >= clip.max.x {
+        newline(clip, c, scale);
+        x0 = c.pt.x + scale;
     }
-    // Calculate word alignment for destination buffer
-    let x1 = x0 + gh.w;
-    let dest_low_word = x0 >> 5;
-    let dest_high_word = x1 >> 5;
-    let px_in_dest_low_word = 32 - (x0 & 0x1f);
-    // Blit it
-    let y0 = c.pt.y + gh.y_offset;
+    x0
+}
+
+/// Advance the cursor past a glyph of width w plus padding, and grow the line
+/// height to fit the glyph's font. Width, padding, and line height are in
+/// glyph pixels, scaled up by scale.
+fn advance(c: &mut Cursor, w: usize, font_line_height: usize, scale: usize) {
+    let width_of_blitted_pixels = (w + 3) * scale;
+    c.pt.x += width_of_blitted_pixels;
+    if font_line_height * scale > c.line_height {
+        c.line_height = font_line_height * scale;
+    }
+}
+
+/// XOR blit a glyph pattern with its top left corner at (x0, y0), with each
+/// glyph pixel scaled up to a block of scale x scale pixels, clipping the parts
+/// of rows that fall outside the clip rect. Rows get synthetic bold and oblique
+/// strokes before they are scaled.
+#[allow(clippy::too_many_arguments)]
+fn xor_pattern(
+    fb: &mut FrBuf,
+    clip: ClipRect,
+    x0: usize,
+    y0: usize,
+    glyph_pattern: Pattern,
+    gh: &GlyphHeader,
+    scale: usize,
+    strokes: Strokes,
+) {
     if y0 > clip.max.y {
-        return Ok(0); // Entire glyph is outside clip rect, so clip it
+        return; // Entire glyph is outside clip rect, so clip it
     }
-    let y_max = if (y0 + gh.h) <= clip.max.y {
-        gh.h
+    let row_w = gh.w + strokes.bold as usize;
+    let (w, h) = (row_w * scale, gh.h * scale);
+    let y_max = if (y0 + h) <= clip.max.y {
+        h
     } else {
         clip.max.y - y0 // Clip bottom of glyph
     };
-    for y in 0..y_max {
-        // Skip rows that are above the clip region
-        if y0 + y < clip.min.y {
-            continue; // Clip top of glyph
-        }
-        // Unpack pixels for this glyph row.
-        // px_in_low_word can include some or all of the pixels for this row of
-        // the pattern. It may also include pixels for the next row, or, in the
-        // case of the last row, it may include padding bits.
-        let px_offset = y * gh.w;
-        let low_word = 1 + (px_offset >> 5);
-        let px_in_low_word = 32 - (px_offset & 0x1f);
-        let mut pattern = glyph_data.nth_word(low_word);
-        // Mask and align pixels from low word of glyph data array
-        pattern <<= 32 - px_in_low_word;
-        pattern >>= 32 - gh.w;
-        if gh.w > px_in_low_word {
-            // When pixels for this row span two words in the glyph data array,
-            // get pixels from the high word too
-            let px_in_high_word = gh.w - px_in_low_word;
-            let mut pattern_h = glyph_data.nth_word(low_word + 1);
-            pattern_h >>= 32 - px_in_high_word;
-            pattern |= pattern_h;
-        }
-        // XOR glyph pixels onto destination buffer
-        let base = (y0 + y) * WORDS_PER_LINE;
+    let rows = Rows::new(gh, glyph_pattern.compressed_len(), |n| {
+        glyph_pattern.nth_word(n)
+    });
+    let mut wide_row;
+    for (y, pattern) in rows.take(y_max.div_ceil(scale)).enumerate() {
+        let pattern = match strokes.bold {
+            true => embolden(&pattern),
+            false => pattern,
+        };
+        let row = match scale {
+            1 => &pattern[..],
+            _ => {
+                wide_row = scale_row(&pattern, row_w, scale);
+                &wide_row[..]
+            }
+        };
+        let x = x0 + strokes.shift(y) * scale;
+        for sy in y0 + y * scale..(y0 + (y + 1) * scale).min(y0 + y_max) {
+            // Skip rows that are above the clip region
+            if sy < clip.min.y {
+                continue; // Clip top of glyph
+            }
+            xor_row(fb, clip, x, sy, w, row);
+        }
+    }
+}
+
+/// Rows of glyph pixels for each 1px step of synthetic oblique shear
+const OBLIQUE_STEP: usize = 4;
+
+/// Synthetic bold and oblique strokes for the patterns of a glyph cell, in
+/// glyph pixels. Oblique shifts each row of the line right by 1px for every
+/// OBLIQUE_STEP rows above the bottom of the line, less the shift of the
+/// cell's bottom row, so that the cell still starts at its left edge.
+#[derive(Copy, Clone)]
+struct Strokes {
+    /// OR each row with itself shifted 1px right
+    bold: bool,
+    /// Shear rows of a line that is line_height rows high
+    oblique: bool,
+    line_height: usize,
+    /// Shift of the cell's top and bottom rows before subtracting bottom_shear
+    top_shear: usize,
+    bottom_shear: usize,
+    /// Line row of row 0 of the pattern being blitted
+    row0: usize,
+}
+
+impl Strokes {
+    /// Patterns get blitted as they are
+    const NONE: Strokes = Strokes {
+        bold: false,
+        oblique: false,
+        line_height: 0,
+        top_shear: 0,
+        bottom_shear: 0,
+        row0: 0,
+    };
+
+    /// Strokes for a cell that covers rows top..bottom of a line that is
+    /// line_height rows high
+    fn new(synthetic: Synthetic, line_height: usize, top: usize, bottom: usize) -> Self {
+        let mut strokes = Strokes {
+            bold: synthetic.bold,
+            oblique: synthetic.oblique,
+            line_height,
+            row0: top,
+            ..Strokes::NONE
+        };
+        strokes.top_shear = strokes.shear(top);
+        strokes.bottom_shear = strokes.shear(bottom.max(top + 1) - 1);
+        strokes
+    }
+
+    /// Shift for row r of the line, before subtracting bottom_shear
+    fn shear(self, r: usize) -> usize {
+        match self.oblique {
+            true => self.line_height.saturating_sub(r + 1) / OBLIQUE_STEP,
+            false => 0,
+        }
+    }
+
+    /// Width the strokes add to the cell: 1px for bold, plus the shift of the
+    /// cell's top row for oblique
+    fn extra_w(self) -> usize {
+        self.bold as usize + self.top_shear - self.bottom_shear
+    }
+
+    /// Strokes for a pattern of the cell with its row 0 on line row row0
+    fn at_row(self, row0: usize) -> Self {
+        Strokes { row0, ..self }
+    }
+
+    /// Distance to shift row y of the pattern right
+    fn shift(self, y: usize) -> usize {
+        self.shear(self.row0 + y).saturating_sub(self.bottom_shear)
+    }
+}
+
+/// OR a row with itself shifted 1px right, making the row 1px wider
+fn embolden(row: &Row) -> Row {
+    let mut bold = *row;
+    for k in 0..ROW_WORDS {
+        let carry = if k > 0 { row[k - 1] >> 31 } else { 0 };
+        bold[k] |= row[k] << 1 | carry;
+    }
+    bold
+}
+
+/// Largest scale factor that paint_str_with_options() can paint glyphs at
+const MAX_SCALE: usize = Scale::X4 as usize;
+
+/// Stretch a row of w pixels to w * scale pixels by repeating each pixel scale
+/// times
+fn scale_row(row: &Row, w: usize, scale: usize) -> [u32; ROW_WORDS * MAX_SCALE] {
+    let mut wide_row = [0; ROW_WORDS * MAX_SCALE];
+    for x in 0..w {
+        if row[x >> 5] & (1 << (x & 0x1f)) != 0 {
+            for sx in x * scale..(x + 1) * scale {
+                wide_row[sx >> 5] |= 1 << (sx & 0x1f);
+            }
+        }
+    }
+    wide_row
+}
+
+/// XOR a row of w pixels onto the frame buffer starting at (x0, y), one word
+/// of the row at a time, leaving out pixels left or right of the clip rect.
+/// Bit 0 of row[0] is the leftmost pixel.
+fn xor_row(fb: &mut FrBuf, clip: ClipRect, x0: usize, y: usize, w: usize, row: &[u32]) {
+    let base = y * WORDS_PER_LINE;
+    for (k, &word) in row.iter().enumerate().take(w.div_ceil(32)) {
+        let x = x0 + 32 * k;
+        // Mask off pixels outside of clip.min.x..clip.max.x
+        let lo = clip.min.x.saturating_sub(x);
+        let hi = (w - 32 * k).min(32).min(clip.max.x.saturating_sub(x));
+        if lo >= hi {
+            continue;
+        }
+        let pattern = word & ((u32::MAX >> (32 - (hi - lo))) << lo);
+        // Calculate word alignment for destination buffer
+        let dest_low_word = x >> 5;
+        let px_in_dest_low_word = 32 - (x & 0x1f);
+        // XOR pixels onto destination buffer
         fb[base + dest_low_word] ^= pattern << (32 - px_in_dest_low_word);
-        if px_in_dest_low_word < gh.w {
-            fb[base + dest_high_word] ^= pattern >> px_in_dest_low_word;
+        if px_in_dest_low_word < hi {
+            fb[base + dest_low_word + 1] ^= pattern >> px_in_dest_low_word;
         }
     }
-    let width_of_blitted_pixels = gh.w + 3;
-    c.pt.x += width_of_blitted_pixels;
-    let font_line_height = match gs {
-        GlyphSet::Bold => fonts::bold::MAX_HEIGHT,
-        GlyphSet::Regular => fonts::regular::MAX_HEIGHT,
-        GlyphSet::Small => fonts::small::MAX_HEIGHT,
-        GlyphSet::Emoji => fonts::emoji::MAX_HEIGHT,
-        GlyphSet::Hanzi => fonts::hanzi::MAX_HEIGHT,
-    } as usize;
-    if font_line_height > c.line_height {
-        c.line_height = font_line_height;
-    }
-    return Ok(bytes_used);
+}
+
+/// XOR a block of scale x scale pixels with its top left corner at (x, y),
+/// leaving out pixels that fall outside the clip rect
+fn xor_px(fb: &mut FrBuf, clip: ClipRect, x: usize, y: usize, scale: usize) {
+    for py in y.max(clip.min.y)..(y + scale).min(clip.max.y) {
+        for px in x.max(clip.min.x)..(x + scale).min(clip.max.x) {
+            fb[py * WORDS_PER_LINE + (px >> 5)] ^= 1 << (px & 0x1f);
+        }
+    }
+}
+
+/// XOR the 1px outline of a w x h box with its top left corner at (x0, y0),
+/// with the size and outline in glyph pixels scaled up by scale
+fn xor_box_outline(
+    fb: &mut FrBuf,
+    clip: ClipRect,
+    x0: usize,
+    y0: usize,
+    w: usize,
+    h: usize,
+    scale: usize,
+) {
+    if w < 2 || h < 2 {
+        return;
+    }
+    let (right, bottom) = (x0 + (w - 1) * scale, y0 + (h - 1) * scale);
+    for x in 0..w {
+        xor_px(fb, clip, x0 + x * scale, y0, scale);
+        xor_px(fb, clip, x0 + x * scale, bottom, scale);
+    }
+    for y in 1..h - 1 {
+        xor_px(fb, clip, x0, y0 + y * scale, scale);
+        xor_px(fb, clip, right, y0 + y * scale, scale);
+    }
 }
@@ -0,0 +1,212 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+use crate::fallback::ChainKey;
+use crate::fonts::{self, GlyphData, GlyphSet};
+use crate::glyphstyle::GlyphStyle;
+
+/// Glyph lookup result for a style, fallback chain, and char, stored in a
+/// GlyphCache slot
+#[derive(Copy, Clone, Debug)]
+pub struct CachedGlyph {
+    st: GlyphStyle,
+    chain: ChainKey,
+    ch: char,
+    gs: GlyphSet,
+    glyph_data: GlyphData,
+    bytes_used: u8,
+}
+
+/// Cache of recent glyph lookups, backed by storage slots that the caller
+/// provides. UIs that redraw the same labels every frame can pass one to
+/// paint_str_with_cache() to skip repeating the fallback chain lookups for
+/// each grapheme cluster.
+///
+/// Each (style, char) pair has one slot that it can go in, and a new lookup
+/// replaces whatever was in the slot before. Only clusters of one char get
+/// cached, since the glyph for a longer cluster depends on what follows it.
+/// Use hits() and misses() to pick a size: more slots means fewer lookups
+/// that miss because another char took their slot.
+///
+/// One cache can be used with several fallback chains. Each cached glyph
+/// remembers the order of glyph sources, and the Font object, of the chain
+/// that found it, and only counts as a hit for chains that match. Custom
+/// glyphs never get cached, and neither do Private Use Area chars painted
+/// with a CustomGlyphs registry, since the registry can change between paints.
+#[derive(Debug)]
+pub struct GlyphCache<'a> {
+    slots: &'a mut [Option<CachedGlyph>],
+    hits: u32,
+    misses: u32,
+}
+
+impl<'a> GlyphCache<'a> {
+    /// Make an empty cache that holds up to slots.len() glyphs
+    pub fn new(slots: &'a mut [Option<CachedGlyph>]) -> Self {
+        let mut cache = GlyphCache {
+            slots,
+            hits: 0,
+            misses: 0,
+        };
+        cache.clear();
+        cache
+    }
+
+    /// Remove all the cached glyphs, but keep the hit and miss counts
+    pub fn clear(&mut self) {
+        for slot in self.slots.iter_mut() {
+            *slot = None;
+        }
+    }
+
+    /// Number of lookups that were found in the cache
+    pub fn hits(&self) -> u32 {
+        self.hits
+    }
+
+    /// Number of lookups that had to search the glyph sets
+    pub fn misses(&self) -> u32 {
+        self.misses
+    }
+
+    /// Set the hit and miss counts back to zero
+    pub fn reset_stats(&mut self) {
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    /// Number of cached glyphs
+    pub fn len(&self) -> usize {
+        self.slots.iter().filter(|slot| slot.is_some()).count()
+    }
+
+    /// Return true if no glyphs are cached
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Slot for the glyph of ch in style st
+    fn slot(&self, st: GlyphStyle, ch: char) -> Option<usize> {
+        if self.slots.is_empty() {
+            return None;
+        }
+        let key = (ch as u32) ^ ((usize::from(st) as u32) << 21);
+        // Fibonacci hashing spreads nearby codepoints across the slots, and
+        // taking the high bits of hash * len maps the hash onto a slot index
+        let hash = key.wrapping_mul(0x9E37_79B9) as u64;
+        Some(((hash * self.slots.len() as u64) >> 32) as usize)
+    }
+
+    /// Look up the cached glyph for the start of cluster in style st, found
+    /// with a chain that has key chain. This counts a hit or a miss unless
+    /// cluster can't be cached.
+    /// Return: (glyph set, glyph data, bytes of cluster used)
+    pub(crate) fn get(
+        &mut self,
+        st: GlyphStyle,
+        chain: ChainKey,
+        cluster: &str,
+    ) -> Option<(GlyphSet, GlyphData, usize)> {
+        let ch = cacheable(cluster)?;
+        let cached = match self.slot(st, ch).and_then(|i| self.slots[i]) {
+            Some(g) if g.st == st && g.ch == ch && g.chain == chain => Some(g),
+            _ => None,
+        };
+        match cached {
+            Some(g) => {
+                self.hits = self.hits.saturating_add(1);
+                Some((g.gs, g.glyph_data, g.bytes_used as usize))
+            }
+            None => {
+                self.misses = self.misses.saturating_add(1);
+                None
+            }
+        }
+    }
+
+    /// Cache the glyph that a lookup with a chain that has key chain found for
+    /// the start of cluster in style st. Custom glyphs don't get cached.
+    pub(crate) fn insert(
+        &mut self,
+        st: GlyphStyle,
+        chain: ChainKey,
+        cluster: &str,
+        gs: GlyphSet,
+        glyph_data: GlyphData,
+        bytes_used: usize,
+    ) {
+        if let GlyphSet::Custom(_) = gs {
+            return;
+        }
+        let ch = match cacheable(cluster) {
+            Some(ch) if bytes_used == ch.len_utf8() => ch,
+            _ => return,
+        };
+        if let Some(i) = self.slot(st, ch) {
+            self.slots[i] = Some(CachedGlyph {
+                st,
+                chain,
+                ch,
+                gs,
+                glyph_data,
+                bytes_used: bytes_used as u8,
+            });
+        }
+    }
+}
+
+/// Return the first char of cluster if the glyph lookup for cluster only
+/// depends on that char, which is true unless the next char can continue a
+/// grapheme cluster
+fn cacheable(cluster: &str) -> Option<char> {
+    match fonts::continues_cluster(cluster) {
+        true => None,
+        false => cluster.chars().next(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fallback::{FallbackChain, GlyphSource};
+
+    #[test]
+    fn test_glyph_cache_hits_and_misses() {
+        let slots = &mut [None; 4];
+        let mut cache = GlyphCache::new(slots);
+        let (gs, st) = (GlyphSet::Icons, GlyphStyle::Regular);
+        let k = FallbackChain::EMOJI_FIRST.cache_key().unwrap();
+        let (glyph_data, _) = gs.get_blit_pattern_offset("\u{E700}").unwrap();
+        assert!(cache.get(st, k, "\u{E700}").is_none());
+        cache.insert(st, k, "\u{E700}", gs, glyph_data, 3);
+        assert_eq!(cache.len(), 1);
+        let (_, cached, bytes_used) = cache.get(st, k, "\u{E700}x").unwrap();
+        assert_eq!(cached.header(), glyph_data.header());
+        assert_eq!(bytes_used, 3);
+        // Other styles have their own entries
+        assert!(cache.get(GlyphStyle::Bold, k, "\u{E700}").is_none());
+        // So do other chains
+        let icons_only = [GlyphSource::Icons];
+        let other = FallbackChain::new(&icons_only).cache_key().unwrap();
+        assert!(cache.get(st, other, "\u{E700}").is_none());
+        assert_eq!((cache.hits(), cache.misses()), (1, 3));
+        // Chars followed by combining marks don't use the cache
+        assert!(cache.get(st, k, "\u{E700}\u{0301}").is_none());
+        cache.insert(st, k, "e\u{0301}", gs, glyph_data, 3);
+        assert_eq!((cache.hits(), cache.misses(), cache.len()), (1, 3, 1));
+        // Custom glyphs don't get cached
+        cache.insert(st, k, "\u{E000}", GlyphSet::Custom(20), glyph_data, 3);
+        assert_eq!(cache.len(), 1);
+        cache.reset_stats();
+        cache.clear();
+        assert_eq!((cache.hits(), cache.misses()), (0, 0));
+        assert!(cache.is_empty());
+        // A cache with no slots misses every time
+        let mut none = GlyphCache::new(&mut []);
+        none.insert(st, k, "\u{E700}", gs, glyph_data, 3);
+        assert!(none.get(st, k, "\u{E700}").is_none());
+        assert_eq!(none.misses(), 1);
+    }
+}
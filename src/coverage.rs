@@ -0,0 +1,167 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+use core::ops::Range;
+
+use crate::blit::{starts_with_selector, Fonts};
+use crate::fallback::{FallbackChain, GlyphSource};
+use crate::glyphstyle::GlyphStyle;
+
+/// Find the glyph source that paint_str() would use to paint all of cluster
+/// in style st. Return None if cluster would be painted with U+FFFD, or if it
+/// is more than one grapheme cluster.
+pub fn has_glyph(cluster: &str, st: GlyphStyle) -> Option<GlyphSource> {
+    has_glyph_in(cluster, st, FallbackChain::EMOJI_FIRST)
+}
+
+/// Find the glyph source that paint_str_with_fallback() would use to paint
+/// all of cluster in style st with chain. Return None if cluster has no glyph
+/// in chain, or if it is more than one grapheme cluster.
+pub fn has_glyph_in(cluster: &str, st: GlyphStyle, chain: FallbackChain) -> Option<GlyphSource> {
+    let fonts = Fonts::new(st, chain);
+    let (source, bytes_used) = fonts.find_source(cluster)?;
+    // Trailing presentation selectors are never painted, so they're covered
+    let mut rest = &cluster[bytes_used..];
+    while starts_with_selector(rest) {
+        rest = &rest[3..];
+    }
+    match rest.is_empty() {
+        true => Some(source),
+        false => None,
+    }
+}
+
+/// List the parts of s that paint_str() would paint as U+FFFD in style st.
+/// Return: iterator over byte ranges of s, one for each U+FFFD
+pub fn coverage(s: &str, st: GlyphStyle) -> Coverage<'_> {
+    coverage_in(s, st, FallbackChain::EMOJI_FIRST)
+}
+
+/// List the parts of s that have no glyph when paint_str_with_fallback()
+/// paints them in style st with chain. Depending on chain.missing, those parts
+/// get painted as U+FFFD or as hex code boxes.
+/// Return: iterator over byte ranges of s, one for each missing char
+pub fn coverage_in<'a>(s: &'a str, st: GlyphStyle, chain: FallbackChain<'a>) -> Coverage<'a> {
+    Coverage {
+        fonts: Fonts::new(st, chain),
+        s,
+        pos: 0,
+    }
+}
+
+/// Iterator over byte ranges of a string that have no glyphs
+pub struct Coverage<'a> {
+    fonts: Fonts<'a>,
+    s: &'a str,
+    pos: usize,
+}
+
+impl Iterator for Coverage<'_> {
+    type Item = Range<usize>;
+
+    /// Skip ahead to the next char with no glyph, the same way paint_str()
+    /// consumes grapheme clusters
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pos < self.s.len() {
+            let cluster = &self.s[self.pos..];
+            if cluster.starts_with('\n') {
+                self.pos += 1;
+            } else if starts_with_selector(cluster) {
+                self.pos += 3;
+            } else if let Some((_, bytes_used)) = self.fonts.find_source(cluster) {
+                self.pos += bytes_used;
+            } else {
+                // One missing glyph per char, like paint_str()
+                let start = self.pos;
+                self.pos += cluster.chars().next().map_or(1, char::len_utf8);
+                return Some(start..self.pos);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom::CustomGlyphs;
+    use crate::font::BuiltinFont;
+
+    #[test]
+    fn test_has_glyph() {
+        let st = GlyphStyle::Regular;
+        assert_eq!(has_glyph("a", st), Some(GlyphSource::Latin));
+        #[cfg(feature = "emoji")]
+        assert_eq!(has_glyph("😸", st), Some(GlyphSource::Emoji));
+        #[cfg(feature = "hanzi")]
+        assert_eq!(has_glyph("鹅", st), Some(GlyphSource::Hanzi));
+        assert_eq!(has_glyph("か", st), Some(GlyphSource::Kana));
+        assert_eq!(has_glyph("か\u{3099}", st), Some(GlyphSource::Kana));
+        assert_eq!(has_glyph("한", st), Some(GlyphSource::Hangul));
+        assert_eq!(has_glyph("\u{1100}\u{1161}", st), Some(GlyphSource::Hangul));
+        assert_eq!(has_glyph("╬", st), Some(GlyphSource::BoxDrawing));
+        assert_eq!(has_glyph("▒", st), Some(GlyphSource::BoxDrawing));
+        assert_eq!(has_glyph("⣿", st), Some(GlyphSource::Braille));
+        assert_eq!(has_glyph("\u{E704}", st), Some(GlyphSource::Icons));
+        assert_eq!(has_glyph("©\u{FE0E}", st), Some(GlyphSource::Latin));
+        assert_eq!(has_glyph("a\u{30C}", st), Some(GlyphSource::Latin));
+        assert_eq!(has_glyph("\u{FB01}", st), Some(GlyphSource::Latin));
+        assert_eq!(has_glyph("🇦🇦", st), Some(GlyphSource::Latin));
+        assert_eq!(has_glyph("\u{353}", st), None);
+        assert_eq!(has_glyph("ab", st), None);
+        assert_eq!(has_glyph("", st), None);
+    }
+
+    #[test]
+    #[cfg(feature = "numeric")]
+    fn test_has_glyph_numeric() {
+        let st = GlyphStyle::Numeric;
+        assert_eq!(has_glyph("7", st), Some(GlyphSource::Latin));
+        assert_eq!(has_glyph("\u{2212}", st), Some(GlyphSource::Latin));
+        assert_eq!(has_glyph("h", st), Some(GlyphSource::Latin));
+        #[cfg(feature = "emoji")]
+        assert_eq!(has_glyph("7\u{20E3}", st), Some(GlyphSource::Emoji));
+        assert_eq!(has_glyph("7\u{FE0E}", st), Some(GlyphSource::Latin));
+    }
+
+    #[test]
+    #[cfg(feature = "emoji")]
+    fn test_coverage() {
+        let st = GlyphStyle::Regular;
+        let s = "ok\n😸 x\u{353} \u{E000}\u{E001}";
+        let mut missing = coverage(s, st);
+        assert_eq!(missing.next(), Some(9..11));
+        assert_eq!(missing.next(), Some(12..15));
+        assert_eq!(missing.next(), Some(15..18));
+        assert_eq!(missing.next(), None);
+        assert_eq!(coverage("Hello, 😸!\n", st).next(), None);
+    }
+
+    #[test]
+    fn test_has_glyph_in_chain() {
+        let st = GlyphStyle::Regular;
+        let text_first = FallbackChain::TEXT_FIRST;
+        assert_eq!(has_glyph_in("©", st, text_first), Some(GlyphSource::Latin));
+        #[cfg(feature = "emoji")]
+        assert_eq!(has_glyph("©", st), Some(GlyphSource::Emoji));
+        // Custom glyphs only count when the chain has the registry
+        let mut slots = [None; 1];
+        let mut custom = CustomGlyphs::new(&mut slots);
+        assert_eq!(custom.register('\u{E000}', 2, &[0xC000_0000; 2]), Ok(()));
+        let chain = text_first.with_custom_glyphs(&custom);
+        let custom_source = Some(GlyphSource::Custom);
+        assert_eq!(has_glyph_in("\u{E000}", st, chain), custom_source);
+        assert_eq!(has_glyph("\u{E000}", st), None);
+        let mut missing = coverage_in("\u{E000}\u{E001}", st, chain);
+        assert_eq!(missing.next(), Some(3..6));
+        assert_eq!(missing.next(), None);
+        // Font objects only count when the chain has the font
+        let sources = [GlyphSource::Font];
+        let chain = FallbackChain::new(&sources).with_font(&BuiltinFont::Kana);
+        assert_eq!(has_glyph_in("か", st, chain), Some(GlyphSource::Font));
+        assert_eq!(has_glyph_in("a", st, chain), None);
+        assert_eq!(coverage_in("aか", st, chain).next(), Some(0..1));
+    }
+}
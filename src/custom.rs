@@ -0,0 +1,215 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+use core::fmt;
+
+use crate::fonts::{pack_pixels, GlyphData};
+
+/// Custom 1-bit bitmap glyph for a Private Use Area char. Rows are one word
+/// each, with the MSB as the leftmost pixel, and the top row at the top of the
+/// line.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CustomGlyph {
+    ch: char,
+    w: u8,
+    rows: &'static [u32],
+}
+
+impl CustomGlyph {
+    /// Packed header word for the glyph, in the same format as the DATA
+    /// arrays of the generated fonts
+    pub(crate) fn header(self) -> u32 {
+        ((self.w as u32) << 16) | ((self.rows.len() as u32) << 8)
+    }
+
+    /// Pixel word n of the glyph, packed the same way as the DATA arrays of
+    /// the generated fonts
+    pub(crate) fn nth_word(self, n: usize) -> u32 {
+        let (w, h) = (self.w as usize, self.rows.len());
+        pack_pixels(n, w, h, |x, y| (self.rows[y] << x) & 0x8000_0000 != 0)
+    }
+}
+
+/// Reasons that CustomGlyphs::register() can refuse a glyph
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CustomGlyphErr {
+    /// Custom glyphs are only allowed for Private Use Area chars
+    NotPrivateUse,
+    /// Width must be 1..=32px, and there must be 1..=255 rows
+    BadSize,
+    /// All the storage slots are taken
+    Full,
+}
+impl fmt::Display for CustomGlyphErr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CustomGlyphErr::NotPrivateUse => {
+                write!(f, "Custom glyph char is not in a Private Use Area")
+            }
+            CustomGlyphErr::BadSize => write!(f, "Custom glyph size is out of range"),
+            CustomGlyphErr::Full => write!(f, "No room left for custom glyphs"),
+        }
+    }
+}
+
+/// Registry of custom glyphs for Private Use Area chars, backed by storage
+/// slots that the caller provides. To paint strings with custom glyphs, use
+/// paint_str_with_fallback() with a chain made by FallbackChain::with_custom_glyphs().
+#[derive(Debug, PartialEq)]
+pub struct CustomGlyphs<'a> {
+    slots: &'a mut [Option<CustomGlyph>],
+}
+
+/// Return true if ch is in the BMP Private Use Area or in the supplementary
+/// Private Use Areas of planes 15 and 16
+pub(crate) fn is_private_use(ch: char) -> bool {
+    matches!(ch as u32, 0xE000..=0xF8FF | 0xF0000..=0xFFFFD | 0x100000..=0x10FFFD)
+}
+
+impl<'a> CustomGlyphs<'a> {
+    /// Make an empty registry that holds up to slots.len() glyphs
+    pub fn new(slots: &'a mut [Option<CustomGlyph>]) -> Self {
+        for slot in slots.iter_mut() {
+            *slot = None;
+        }
+        CustomGlyphs { slots }
+    }
+
+    /// Register a glyph of width w for Private Use Area char ch, replacing any
+    /// glyph ch already had. Each word of rows holds one row of pixels, with
+    /// the MSB as the leftmost pixel.
+    pub fn register(
+        &mut self,
+        ch: char,
+        w: usize,
+        rows: &'static [u32],
+    ) -> Result<(), CustomGlyphErr> {
+        if !is_private_use(ch) {
+            return Err(CustomGlyphErr::NotPrivateUse);
+        }
+        if w == 0 || w > 32 || rows.is_empty() || rows.len() > 255 {
+            return Err(CustomGlyphErr::BadSize);
+        }
+        let glyph = CustomGlyph {
+            ch,
+            w: w as u8,
+            rows,
+        };
+        // Replace the old glyph for ch, or else take the first free slot
+        let mut free = None;
+        for (i, slot) in self.slots.iter().enumerate() {
+            match slot {
+                Some(old) if old.ch == ch => {
+                    free = Some(i);
+                    break;
+                }
+                None if free.is_none() => free = Some(i),
+                _ => (),
+            }
+        }
+        match free {
+            Some(i) => {
+                self.slots[i] = Some(glyph);
+                Ok(())
+            }
+            None => Err(CustomGlyphErr::Full),
+        }
+    }
+
+    /// Remove the glyph for ch. Return: true if ch had a glyph.
+    pub fn unregister(&mut self, ch: char) -> bool {
+        for slot in self.slots.iter_mut() {
+            if matches!(slot, Some(glyph) if glyph.ch == ch) {
+                *slot = None;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Number of registered glyphs
+    pub fn len(&self) -> usize {
+        self.slots.iter().filter(|slot| slot.is_some()).count()
+    }
+
+    /// Return true if no glyphs are registered
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Look up the custom glyph for the first char of cluster.
+    /// Returns: Some((glyph data, bytes of cluster used by match))
+    pub(crate) fn find(&self, cluster: &str) -> Option<(GlyphData, usize)> {
+        let ch = cluster.chars().next()?;
+        let glyph = self.slots.iter().flatten().find(|glyph| glyph.ch == ch)?;
+        Some((GlyphData::Custom(*glyph), ch.len_utf8()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static DOT: [u32; 2] = [0xC000_0000, 0xC000_0000];
+
+    #[test]
+    fn test_register() {
+        let mut slots = [None; 2];
+        let mut glyphs = CustomGlyphs::new(&mut slots);
+        assert!(glyphs.is_empty());
+        assert_eq!(
+            glyphs.register('a', 2, &DOT),
+            Err(CustomGlyphErr::NotPrivateUse)
+        );
+        assert_eq!(
+            glyphs.register('\u{E000}', 0, &DOT),
+            Err(CustomGlyphErr::BadSize)
+        );
+        assert_eq!(
+            glyphs.register('\u{E000}', 33, &DOT),
+            Err(CustomGlyphErr::BadSize)
+        );
+        assert_eq!(
+            glyphs.register('\u{E000}', 2, &[]),
+            Err(CustomGlyphErr::BadSize)
+        );
+        assert_eq!(glyphs.register('\u{E000}', 2, &DOT), Ok(()));
+        assert_eq!(glyphs.register('\u{10FFFD}', 2, &DOT), Ok(()));
+        // Registering the same char again replaces its glyph
+        assert_eq!(glyphs.register('\u{E000}', 1, &DOT), Ok(()));
+        assert_eq!(
+            glyphs.register('\u{F0000}', 2, &DOT),
+            Err(CustomGlyphErr::Full)
+        );
+        assert_eq!(glyphs.len(), 2);
+        assert!(glyphs.unregister('\u{10FFFD}'));
+        assert!(!glyphs.unregister('\u{10FFFD}'));
+        assert_eq!(glyphs.register('\u{F0000}', 2, &DOT), Ok(()));
+    }
+
+    #[test]
+    fn test_find() {
+        let mut slots = [None; 1];
+        let mut glyphs = CustomGlyphs::new(&mut slots);
+        assert!(glyphs.find("\u{E000}").is_none());
+        glyphs.register('\u{E000}', 2, &DOT).unwrap();
+        match glyphs.find("\u{E000}x") {
+            Some((GlyphData::Custom(glyph), 3)) => {
+                let header = GlyphData::Custom(glyph).header();
+                assert_eq!((header.w, header.h, header.y_offset), (2, 2, 0));
+                assert_eq!(glyph.nth_word(1), 0xF000_0000);
+            }
+            _ => panic!("custom glyph not found"),
+        }
+        assert!(glyphs.find("\u{E001}").is_none());
+        // Packed rows start from their rightmost pixel, like the generated fonts
+        static LEFT_PX: [u32; 1] = [0x8000_0000];
+        glyphs.register('\u{E000}', 3, &LEFT_PX).unwrap();
+        match glyphs.find("\u{E000}") {
+            Some((glyph_data, _)) => assert_eq!(glyph_data.nth_word(1), 0x2000_0000),
+            None => panic!("custom glyph not found"),
+        }
+    }
+}
@@ -4,29 +4,43 @@
 #![forbid(unsafe_code)]
 use super::{clear_region, paint_str, ClipRect, Cursor, FrBuf, GlyphStyle};
 
+/// Text that sample_text() paints in each latin style. This has Unicode NFC
+/// and NFD.
+pub const NOTE: &str = "Hello, world! ää 🀄🃏\u{1F170}\u{1F170}\u{FE0F}\n";
+
+/// Emoji with captions that sample_text() paints in Regular style
+pub const EMOJI_CAPTIONS: &str = concat!(
+    "\n   🍎       🎸       🕶        🍎\n",
+    " apple  guitar  glasses  apple\n\n",
+    "           😸     🎩    🔑\n",
+    "           cat    hat    key\n\n",
+);
+
+/// Pangrams that sample_text() paints in Regular style, then again in Small
+/// style to wrap within a narrower clip rect
+pub const PANGRAMS: &str = concat!(
+    "The quick brown fox jumps over the lazy dog. ",
+    "Zwölf Boxkämpfer jagen Viktor quer über den großen Sylter Deich.\n"
+);
+
+/// Poem that goose_poem() paints
+pub const GOOSE_POEM: &str = concat!(
+    "鹅、鹅、鹅，\n",
+    "曲项向天歌。\n",
+    "白毛浮绿水，\n",
+    "红掌拨清波\n",
+);
+
 /// Demonstrate available fonts
 pub fn sample_text(fb: &mut FrBuf) {
-    let note = &"Hello, world! ää 🀄🃏\u{1F170}\u{1F170}\u{FE0F}\n"; // This has Unicode NFC and NFD
-    let sas1 = &"\n   🍎       🎸       🕶        🍎\n";
-    let sas2 = &" apple  guitar  glasses  apple\n\n";
-    let sas3 = &"           😸     🎩    🔑\n";
-    let sas4 = &"           cat    hat    key\n\n";
-    let wrap = &concat!(
-        "The quick brown fox jumps over the lazy dog. ",
-        "Zwölf Boxkämpfer jagen Viktor quer über den großen Sylter Deich.\n"
-    );
-
     clear_region(fb, ClipRect::full_screen());
     let mut clip = ClipRect::padded_screen();
     let c = &mut Cursor::from_top_left_of(clip);
-    paint_str(fb, clip, c, GlyphStyle::Bold, note);
-    paint_str(fb, clip, c, GlyphStyle::Regular, note);
-    paint_str(fb, clip, c, GlyphStyle::Small, note);
-    paint_str(fb, clip, c, GlyphStyle::Regular, sas1);
-    paint_str(fb, clip, c, GlyphStyle::Regular, sas2);
-    paint_str(fb, clip, c, GlyphStyle::Regular, sas3);
-    paint_str(fb, clip, c, GlyphStyle::Regular, sas4);
-    paint_str(fb, clip, c, GlyphStyle::Regular, wrap);
+    paint_str(fb, clip, c, GlyphStyle::Bold, NOTE);
+    paint_str(fb, clip, c, GlyphStyle::Regular, NOTE);
+    paint_str(fb, clip, c, GlyphStyle::Small, NOTE);
+    paint_str(fb, clip, c, GlyphStyle::Regular, EMOJI_CAPTIONS);
+    paint_str(fb, clip, c, GlyphStyle::Regular, PANGRAMS);
     // Demonstrate messing with the clip region and cursor:
     // 1. Convenience function to make a new cursor
     let c = &mut Cursor::new(c.pt.x, c.pt.y, c.line_height);
@@ -40,7 +54,7 @@ pub fn sample_text(fb: &mut FrBuf) {
     //    Note: fn def is `new(min_x: usize, min_y: usize, max_x: usize, max_y: usize)`
     let clip = ClipRect::new(clip.max.x, clip.min.y, clip.min.x, clip.max.y);
     // Blit the string
-    paint_str(fb, clip, c, GlyphStyle::Small, wrap);
+    paint_str(fb, clip, c, GlyphStyle::Small, PANGRAMS);
 }
 
 /// Short example to greet world + cat
@@ -68,11 +82,5 @@ pub fn goose_poem(fb: &mut FrBuf) {
     // Paint poem
     let clip = ClipRect::padded_screen();
     let c = &mut Cursor::from_top_left_of(clip);
-    let poem = &concat!(
-        "鹅、鹅、鹅，\n",
-        "曲项向天歌。\n",
-        "白毛浮绿水，\n",
-        "红掌拨清波\n",
-    );
-    paint_str(fb, clip, c, GlyphStyle::Regular, poem);
+    paint_str(fb, clip, c, GlyphStyle::Regular, GOOSE_POEM);
 }
@@ -0,0 +1,261 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+use core::fmt;
+
+use crate::custom::CustomGlyphs;
+use crate::font::Font;
+
+/// Glyph sets that paint_str can draw grapheme clusters from. Emoji and Hanzi
+/// depend on cargo features, so matches on GlyphSource from outside the crate
+/// need a wildcard arm.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum GlyphSource {
+    /// Emoji glyphs, same for all styles
+    #[cfg(feature = "emoji")]
+    Emoji,
+    /// Latin script glyphs for the GlyphStyle being painted
+    Latin,
+    /// Hanzi glyphs, same for all styles
+    #[cfg(feature = "hanzi")]
+    Hanzi,
+    /// Japanese kana glyphs, same for all styles
+    Kana,
+    /// Korean hangul syllables composed from jamo glyphs, same for all styles
+    Hangul,
+    /// Box drawing and block elements sized to fill the line height of the
+    /// GlyphStyle being painted, so they join up with their neighbors
+    BoxDrawing,
+    /// Braille patterns with dots spaced to fit the line height of the
+    /// GlyphStyle being painted
+    Braille,
+    /// Precursor UI icons in the Private Use Area (U+E700..U+E70C), same for
+    /// all styles
+    Icons,
+    /// Custom glyphs for Private Use Area chars from the chain's CustomGlyphs
+    /// registry, if it has one
+    Custom,
+    /// Glyphs from the chain's Font object, if it has one
+    Font,
+}
+
+/// How to paint chars that have no glyph in any of the glyph sources
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum MissingGlyph {
+    /// U+FFFD from the latin glyph set, the same for every missing char
+    Replacement,
+    /// Box containing the char's codepoint in tiny hex digits
+    HexCode,
+}
+
+/// Ordered list of glyph sources to try for each grapheme cluster. The first
+/// source with a glyph wins. Chars with no glyph in any of the sources get
+/// painted according to missing. GlyphSource::Custom looks up glyphs in custom,
+/// and GlyphSource::Font looks up glyphs in font.
+#[derive(Copy, Clone)]
+pub struct FallbackChain<'a> {
+    pub sources: &'a [GlyphSource],
+    pub missing: MissingGlyph,
+    pub custom: Option<&'a CustomGlyphs<'a>>,
+    pub font: Option<&'a dyn Font>,
+}
+
+impl<'a> FallbackChain<'a> {
+    /// Make a chain that tries sources in order, then falls back to U+FFFD
+    pub const fn new(sources: &'a [GlyphSource]) -> Self {
+        FallbackChain {
+            sources,
+            missing: MissingGlyph::Replacement,
+            custom: None,
+            font: None,
+        }
+    }
+
+    /// Copy of the chain that paints missing chars as hex code boxes
+    pub const fn with_hex_codes(self) -> Self {
+        FallbackChain {
+            missing: MissingGlyph::HexCode,
+            ..self
+        }
+    }
+
+    /// Copy of the chain that paints Private Use Area chars with glyphs from
+    /// custom, for sources that include GlyphSource::Custom
+    pub const fn with_custom_glyphs(self, custom: &'a CustomGlyphs<'a>) -> Self {
+        FallbackChain {
+            custom: Some(custom),
+            ..self
+        }
+    }
+
+    /// Copy of the chain that paints glyphs from font, for sources that
+    /// include GlyphSource::Font
+    pub const fn with_font(self, font: &'a dyn Font) -> Self {
+        FallbackChain {
+            font: Some(font),
+            ..self
+        }
+    }
+}
+
+/// Identity of a chain's lookup order, so a GlyphCache can tell apart glyphs
+/// that were looked up with different chains
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct ChainKey {
+    /// Sources in order, 4 bits each, leaving out Custom and Font sources
+    /// with nothing attached since lookups skip them
+    sources: u64,
+    /// Address of the Font object, or 0 if there isn't one
+    font: usize,
+}
+
+impl FallbackChain<'_> {
+    /// Key for caching glyphs looked up with this chain, or None if the chain
+    /// has too many sources to fit in a key
+    pub(crate) fn cache_key(&self) -> Option<ChainKey> {
+        let mut sources = 0;
+        let mut n = 0;
+        for &src in self.sources.iter() {
+            let attached = match src {
+                GlyphSource::Custom => self.custom.is_some(),
+                GlyphSource::Font => self.font.is_some(),
+                _ => true,
+            };
+            if !attached {
+                continue;
+            }
+            if n == 16 {
+                return None;
+            }
+            sources |= (src as u64 + 1) << (4 * n);
+            n += 1;
+        }
+        // Same Font object identity as PartialEq uses
+        let font = self
+            .font
+            .map_or(0, |font| font as *const dyn Font as *const u8 as usize);
+        Some(ChainKey { sources, font })
+    }
+}
+
+/// Chains are equal when they use the same Font object, rather than fonts
+/// with equal glyphs
+impl PartialEq for FallbackChain<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        let same_font = match (self.font, other.font) {
+            (Some(a), Some(b)) => core::ptr::eq(
+                a as *const dyn Font as *const u8,
+                b as *const dyn Font as *const u8,
+            ),
+            (a, b) => a.is_none() && b.is_none(),
+        };
+        self.sources == other.sources
+            && self.missing == other.missing
+            && self.custom == other.custom
+            && same_font
+    }
+}
+
+impl fmt::Debug for FallbackChain<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FallbackChain")
+            .field("sources", &self.sources)
+            .field("missing", &self.missing)
+            .field("custom", &self.custom)
+            .field("font", &self.font.map(|font| font.max_height()))
+            .finish()
+    }
+}
+
+impl FallbackChain<'static> {
+    /// Prefer emoji glyphs, so "©" or "1️⃣" get their emoji glyphs.
+    /// This is the order used by paint_str().
+    pub const EMOJI_FIRST: FallbackChain<'static> = FallbackChain::new(&[
+        GlyphSource::Custom,
+        GlyphSource::Font,
+        #[cfg(feature = "emoji")]
+        GlyphSource::Emoji,
+        GlyphSource::Latin,
+        GlyphSource::BoxDrawing,
+        GlyphSource::Braille,
+        GlyphSource::Icons,
+        #[cfg(feature = "hanzi")]
+        GlyphSource::Hanzi,
+        GlyphSource::Kana,
+        GlyphSource::Hangul,
+    ]);
+
+    /// Prefer latin glyphs, so "©" and digits match the surrounding text
+    pub const TEXT_FIRST: FallbackChain<'static> = FallbackChain::new(&[
+        GlyphSource::Custom,
+        GlyphSource::Font,
+        GlyphSource::Latin,
+        GlyphSource::BoxDrawing,
+        GlyphSource::Braille,
+        GlyphSource::Icons,
+        #[cfg(feature = "hanzi")]
+        GlyphSource::Hanzi,
+        GlyphSource::Kana,
+        GlyphSource::Hangul,
+        #[cfg(feature = "emoji")]
+        GlyphSource::Emoji,
+    ]);
+}
+
+impl Default for FallbackChain<'static> {
+    fn default() -> Self {
+        FallbackChain::EMOJI_FIRST
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::font::BuiltinFont;
+
+    #[test]
+    fn test_fallback_chain_presets_cover_all_sources() {
+        for chain in [FallbackChain::EMOJI_FIRST, FallbackChain::TEXT_FIRST].iter() {
+            let sources = [
+                #[cfg(feature = "emoji")]
+                GlyphSource::Emoji,
+                GlyphSource::Latin,
+                #[cfg(feature = "hanzi")]
+                GlyphSource::Hanzi,
+                GlyphSource::Kana,
+                GlyphSource::Hangul,
+                GlyphSource::BoxDrawing,
+                GlyphSource::Braille,
+                GlyphSource::Icons,
+                GlyphSource::Custom,
+                GlyphSource::Font,
+            ];
+            for gs in sources.iter() {
+                assert_eq!(chain.sources.iter().filter(|s| *s == gs).count(), 1);
+            }
+            assert_eq!(chain.missing, MissingGlyph::Replacement);
+        }
+        assert_eq!(FallbackChain::default(), FallbackChain::EMOJI_FIRST);
+    }
+
+    #[test]
+    fn test_fallback_chain_with_hex_codes() {
+        let chain = FallbackChain::TEXT_FIRST.with_hex_codes();
+        assert_eq!(chain.sources, FallbackChain::TEXT_FIRST.sources);
+        assert_eq!(chain.missing, MissingGlyph::HexCode);
+        assert_eq!(chain.custom, None);
+        assert!(chain.font.is_none());
+    }
+
+    #[test]
+    fn test_fallback_chain_with_font() {
+        let (a, b) = (BuiltinFont::Kana, BuiltinFont::Kana);
+        let chain = FallbackChain::TEXT_FIRST.with_font(&a);
+        assert_eq!(chain, FallbackChain::TEXT_FIRST.with_font(&a));
+        assert_ne!(chain, FallbackChain::TEXT_FIRST.with_font(&b));
+        assert_ne!(chain, FallbackChain::TEXT_FIRST);
+    }
+}
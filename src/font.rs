@@ -0,0 +1,275 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+use crate::fonts::{self, Block, GlyphData, GlyphHeader, GlyphSet};
+
+/// Source of bitmap glyphs that paint_str_with_fallback() can paint from, for
+/// fonts that are not built in. Glyph patterns use the same packing as the
+/// DATA arrays of the built-in fonts: rows of w pixels, top to bottom, packed
+/// into 32-bit words starting at the MSB of word 1, with each row starting
+/// from its rightmost pixel. Glyphs can also use the compressed encoding of
+/// the built-in CJK fonts (see fonts/compress.rs).
+pub trait Font {
+    /// Look up the glyph for the start of cluster. Glyph ids are whatever the
+    /// font finds useful, for example offsets into its pattern data.
+    /// Return: Some((glyph id, bytes of cluster used by match))
+    fn lookup(&self, cluster: &str) -> Option<(usize, usize)>;
+
+    /// Metrics of a glyph: width, height, and offset down from the top of the
+    /// line, in pixels. Widths can be up to 255px, the same as for built-in
+    /// glyphs. Wider glyphs can't be painted, so they get treated as missing.
+    fn header(&self, glyph: usize) -> GlyphHeader;
+
+    /// Pattern word n of a glyph, where n counts up from 1
+    fn nth_word(&self, glyph: usize, n: usize) -> u32;
+
+    /// Words of compressed pattern data for a glyph, or 0 if its pattern is
+    /// packed pixels
+    fn compressed_len(&self, _glyph: usize) -> usize {
+        0
+    }
+
+    /// Line height for the font. This will be true: h + y_offset <= max_height
+    fn max_height(&self) -> usize;
+}
+
+/// Built-in fonts, for use as a Font. Glyph ids are offsets into the font's
+/// DATA array. Variants depend on which glyph set features are enabled, so
+/// matches on BuiltinFont from outside the crate need a wildcard arm.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum BuiltinFont {
+    #[cfg(feature = "emoji")]
+    Emoji,
+    #[cfg(feature = "latin-bold")]
+    Bold,
+    #[cfg(feature = "latin-regular")]
+    Regular,
+    #[cfg(feature = "latin-small")]
+    Small,
+    #[cfg(feature = "hanzi")]
+    Hanzi,
+    Kana,
+    Icons,
+    #[cfg(feature = "numeric")]
+    Numeric,
+}
+
+impl BuiltinFont {
+    /// Glyph set with the font's DATA array
+    fn glyph_set(self) -> GlyphSet {
+        match self {
+            #[cfg(feature = "emoji")]
+            BuiltinFont::Emoji => GlyphSet::Emoji,
+            #[cfg(feature = "latin-bold")]
+            BuiltinFont::Bold => GlyphSet::Bold,
+            #[cfg(feature = "latin-regular")]
+            BuiltinFont::Regular => GlyphSet::Regular,
+            #[cfg(feature = "latin-small")]
+            BuiltinFont::Small => GlyphSet::Small,
+            #[cfg(feature = "hanzi")]
+            BuiltinFont::Hanzi => GlyphSet::Hanzi,
+            BuiltinFont::Kana => GlyphSet::Kana,
+            BuiltinFont::Icons => GlyphSet::Icons,
+            #[cfg(feature = "numeric")]
+            BuiltinFont::Numeric => GlyphSet::Numeric,
+        }
+    }
+
+    /// Hash index blocks of the font
+    pub(crate) fn blocks(self) -> &'static [Block] {
+        match self {
+            #[cfg(feature = "emoji")]
+            BuiltinFont::Emoji => &fonts::emoji::BLOCKS,
+            #[cfg(feature = "latin-bold")]
+            BuiltinFont::Bold => &fonts::bold::BLOCKS,
+            #[cfg(feature = "latin-regular")]
+            BuiltinFont::Regular => &fonts::regular::BLOCKS,
+            #[cfg(feature = "latin-small")]
+            BuiltinFont::Small => &fonts::small::BLOCKS,
+            #[cfg(feature = "hanzi")]
+            BuiltinFont::Hanzi => &fonts::hanzi::BLOCKS,
+            BuiltinFont::Kana => &fonts::kana::BLOCKS,
+            BuiltinFont::Icons => &fonts::icons::BLOCKS,
+            #[cfg(feature = "numeric")]
+            BuiltinFont::Numeric => &fonts::numeric::BLOCKS,
+        }
+    }
+
+    /// Packed glyph pattern data of the font
+    pub(crate) fn data(self) -> &'static [u32] {
+        match self {
+            #[cfg(feature = "emoji")]
+            BuiltinFont::Emoji => &fonts::emoji::DATA,
+            #[cfg(feature = "latin-bold")]
+            BuiltinFont::Bold => &fonts::bold::DATA,
+            #[cfg(feature = "latin-regular")]
+            BuiltinFont::Regular => &fonts::regular::DATA,
+            #[cfg(feature = "latin-small")]
+            BuiltinFont::Small => &fonts::small::DATA,
+            #[cfg(feature = "hanzi")]
+            BuiltinFont::Hanzi => &fonts::hanzi::DATA,
+            BuiltinFont::Kana => &fonts::kana::DATA,
+            BuiltinFont::Icons => &fonts::icons::DATA,
+            #[cfg(feature = "numeric")]
+            BuiltinFont::Numeric => &fonts::numeric::DATA,
+        }
+    }
+
+    /// Seed for the murmur3 hashes in the font's hash index
+    pub(crate) fn m3_seed(self) -> u32 {
+        match self {
+            #[cfg(feature = "emoji")]
+            BuiltinFont::Emoji => fonts::emoji::M3_SEED,
+            #[cfg(feature = "latin-bold")]
+            BuiltinFont::Bold => fonts::bold::M3_SEED,
+            #[cfg(feature = "latin-regular")]
+            BuiltinFont::Regular => fonts::regular::M3_SEED,
+            #[cfg(feature = "latin-small")]
+            BuiltinFont::Small => fonts::small::M3_SEED,
+            #[cfg(feature = "hanzi")]
+            BuiltinFont::Hanzi => fonts::hanzi::M3_SEED,
+            BuiltinFont::Kana => fonts::kana::M3_SEED,
+            BuiltinFont::Icons => fonts::icons::M3_SEED,
+            #[cfg(feature = "numeric")]
+            BuiltinFont::Numeric => fonts::numeric::M3_SEED,
+        }
+    }
+
+    /// Glyph data for an offset into the font's DATA array
+    fn glyph_data(self, offset: usize) -> GlyphData {
+        match self {
+            #[cfg(feature = "emoji")]
+            BuiltinFont::Emoji => GlyphData::Emoji(offset),
+            #[cfg(feature = "latin-bold")]
+            BuiltinFont::Bold => GlyphData::Bold(offset),
+            #[cfg(feature = "latin-regular")]
+            BuiltinFont::Regular => GlyphData::Regular(offset),
+            #[cfg(feature = "latin-small")]
+            BuiltinFont::Small => GlyphData::Small(offset),
+            #[cfg(feature = "hanzi")]
+            BuiltinFont::Hanzi => GlyphData::Hanzi(offset),
+            BuiltinFont::Kana => GlyphData::Kana(offset),
+            BuiltinFont::Icons => GlyphData::Icons(offset),
+            #[cfg(feature = "numeric")]
+            BuiltinFont::Numeric => GlyphData::Numeric(offset),
+        }
+    }
+}
+
+impl Font for BuiltinFont {
+    fn lookup(&self, cluster: &str) -> Option<(usize, usize)> {
+        let (glyph_data, bytes_used) = self.glyph_set().get_blit_pattern_offset(cluster).ok()?;
+        let offset = match glyph_data {
+            #[cfg(feature = "emoji")]
+            GlyphData::Emoji(offset) => offset,
+            #[cfg(feature = "latin-bold")]
+            GlyphData::Bold(offset) => offset,
+            #[cfg(feature = "latin-regular")]
+            GlyphData::Regular(offset) => offset,
+            #[cfg(feature = "latin-small")]
+            GlyphData::Small(offset) => offset,
+            #[cfg(feature = "hanzi")]
+            GlyphData::Hanzi(offset) => offset,
+            GlyphData::Kana(offset) | GlyphData::Icons(offset) => offset,
+            #[cfg(feature = "numeric")]
+            GlyphData::Numeric(offset) => offset,
+            _ => return None,
+        };
+        Some((offset, bytes_used))
+    }
+
+    fn header(&self, glyph: usize) -> GlyphHeader {
+        self.glyph_data(glyph).header()
+    }
+
+    fn nth_word(&self, glyph: usize, n: usize) -> u32 {
+        self.glyph_data(glyph).nth_word(n)
+    }
+
+    fn compressed_len(&self, glyph: usize) -> usize {
+        self.glyph_data(glyph).compressed_len()
+    }
+
+    fn max_height(&self) -> usize {
+        self.glyph_set().max_height()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_font_lookup() {
+        let font: &dyn Font = &BuiltinFont::Kana;
+        let (glyph, bytes_used) = font.lookup("カa").unwrap();
+        assert_eq!(bytes_used, 3);
+        let header = font.header(glyph);
+        assert!(header.w > 0 && header.h + header.y_offset <= font.max_height());
+        assert!(font.lookup("鹅").is_none());
+        #[cfg(feature = "hanzi")]
+        assert!(BuiltinFont::Hanzi.lookup("鹅").is_some());
+        #[cfg(feature = "emoji")]
+        assert_eq!(BuiltinFont::Emoji.max_height(), 32);
+    }
+
+    #[test]
+    fn test_no_false_matches_for_single_codepoints() {
+        let fonts = [
+            #[cfg(feature = "emoji")]
+            BuiltinFont::Emoji,
+            #[cfg(feature = "latin-bold")]
+            BuiltinFont::Bold,
+            #[cfg(feature = "latin-regular")]
+            BuiltinFont::Regular,
+            #[cfg(feature = "latin-small")]
+            BuiltinFont::Small,
+            #[cfg(feature = "hanzi")]
+            BuiltinFont::Hanzi,
+            BuiltinFont::Kana,
+            BuiltinFont::Icons,
+            #[cfg(feature = "numeric")]
+            BuiltinFont::Numeric,
+        ];
+        for font in fonts.iter() {
+            let blocks = font.blocks();
+            let mut matches = 0;
+            for c in (0..=0x10FFFF).filter_map(char::from_u32) {
+                let buf = &mut [0; 4];
+                let cluster = c.encode_utf8(buf);
+                let (offset, bytes_used) = match font.lookup(cluster) {
+                    Some(m) => m,
+                    None => continue,
+                };
+                // Every match must be for c's codepoints table entry, or for an
+                // index entry whose key is exactly c
+                let in_block = |b: &&Block| (b.low..=b.high).contains(&(c as u32));
+                let b = blocks.iter().find(in_block).unwrap();
+                let i = (c as u32).wrapping_sub(b.codepoints_low) as usize;
+                let entry = match b.codepoints.get(i) {
+                    Some(&entry) => entry as usize,
+                    None => {
+                        let (hash, _) = fonts::murmur3(cluster, font.m3_seed(), 1);
+                        let i = b.hashes.binary_search(&hash).unwrap();
+                        assert_eq!(b.keys[i], c as u32);
+                        b.offsets[i]
+                    }
+                };
+                assert_eq!((entry, bytes_used), (offset, cluster.len()));
+                matches += 1;
+            }
+            // And every single codepoint entry must get matched
+            let entries: usize = blocks
+                .iter()
+                .map(|b| {
+                    let keys = b.keys.iter().filter(|key| **key < 0x8000_0000).count();
+                    keys + b.codepoint_entries().count()
+                })
+                .sum();
+            assert_eq!(matches, entries, "{:?}", font);
+        }
+    }
+}
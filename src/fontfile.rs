@@ -0,0 +1,564 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Font File Format
+//!
+//! Binary container for bitmap fonts that get loaded at runtime, for example
+//! from a flash partition, rather than being compiled in. Files are a sequence
+//! of u32 words, stored little-endian when they are bytes:
+//! ```text
+//! Header:
+//!   [0]: MAGIC
+//!   [1]: VERSION
+//!   [2]: max_height (line height in pixels, 1..=255)
+//!   [3]: m3_seed (seed for the murmur3 hashes in the index)
+//!   [4]: block count
+//!   [5]: glyph count (entries in the hash index)
+//!   [6]: pattern word count
+//! Block table (5 words per block, sorted by low, with no overlaps):
+//!   low, high: first and last codepoint of the block
+//!   cluster lengths: bit n is set if the block has clusters of n codepoints
+//!   index start, index count: range of the hash index used by the block
+//! Hash index: murmur3(cluster), sorted within each block's range
+//! Keys (since version 3): verification key for each hash index entry, which
+//!   gets checked after a hash match to rule out collisions (see cluster_key()
+//!   in fonts.rs)
+//! Offsets: pattern word offset for each hash index entry
+//! Pattern words: glyph records in the same format as the built-in fonts'
+//!   DATA arrays, a header word followed by packed pixel words, or, since
+//!   version 2, by compressed pattern words (see fonts/compress.rs)
+//! ```
+//! Parsing does not copy the file. All the offsets and glyph records get
+//! checked when the file is parsed, so lookups in a parsed file can't go out
+//! of bounds.
+#![forbid(unsafe_code)]
+
+use core::fmt;
+
+use crate::font::{BuiltinFont, Font};
+use crate::fonts::{self, compress, GlyphHeader};
+
+/// First word of a font file ("BSTF" as little-endian bytes)
+pub const MAGIC: u32 = 0x4654_5342;
+
+/// Newest version of the font file format that this parser understands
+pub const VERSION: u32 = 3;
+
+/// Size in words of the file header
+const HEADER_WORDS: usize = 7;
+
+/// Size in words of a block table entry
+const BLOCK_WORDS: usize = 5;
+
+/// Reasons that a font file can fail to parse or serialize
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FontFileErr {
+    /// Input is shorter than the sizes in its header
+    Truncated,
+    /// Input does not start with MAGIC
+    BadMagic,
+    /// Input uses a version of the format this parser doesn't understand
+    BadVersion,
+    /// Header has a line height outside 1..=255
+    BadHeight,
+    /// Block table entry has a bad codepoint range or index range
+    BadBlock,
+    /// Hash index is not sorted, or has an offset past the pattern words
+    BadIndex,
+    /// Glyph record is too wide, too tall, or runs past the pattern words
+    BadGlyph,
+    /// Output buffer is too small for the serialized font
+    NoRoom,
+}
+impl fmt::Display for FontFileErr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FontFileErr::Truncated => write!(f, "Font file is truncated"),
+            FontFileErr::BadMagic => write!(f, "Font file has bad magic number"),
+            FontFileErr::BadVersion => write!(f, "Font file version is not supported"),
+            FontFileErr::BadHeight => write!(f, "Font file line height is out of range"),
+            FontFileErr::BadBlock => write!(f, "Font file has a bad block table entry"),
+            FontFileErr::BadIndex => write!(f, "Font file has a bad hash index"),
+            FontFileErr::BadGlyph => write!(f, "Font file has a bad glyph record"),
+            FontFileErr::NoRoom => write!(f, "Buffer is too small for font file"),
+        }
+    }
+}
+
+/// Words of a font file, either as u32 words or as little-endian bytes
+#[derive(Copy, Clone, Debug)]
+enum Words<'a> {
+    Native(&'a [u32]),
+    LeBytes(&'a [u8]),
+}
+
+impl<'a> Words<'a> {
+    /// Number of whole words
+    fn len(self) -> usize {
+        match self {
+            Words::Native(words) => words.len(),
+            Words::LeBytes(bytes) => bytes.len() / 4,
+        }
+    }
+
+    /// Word i, or 0 if i is out of range
+    fn get(self, i: usize) -> u32 {
+        match self {
+            Words::Native(words) => words.get(i).copied().unwrap_or(0),
+            Words::LeBytes(bytes) => match bytes.get(i * 4..i * 4 + 4) {
+                Some(b) => u32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+                None => 0,
+            },
+        }
+    }
+
+    /// Words start..start + len. Caller must ensure the range is in bounds.
+    fn slice(self, start: usize, len: usize) -> Words<'a> {
+        match self {
+            Words::Native(words) => Words::Native(&words[start..start + len]),
+            Words::LeBytes(bytes) => Words::LeBytes(&bytes[start * 4..(start + len) * 4]),
+        }
+    }
+}
+
+/// Block table entry
+struct FileBlock {
+    low: u32,
+    high: u32,
+    cluster_lengths: u32,
+    start: usize,
+    count: usize,
+}
+
+/// Font parsed from a font file, for use as a Font
+#[derive(Copy, Clone, Debug)]
+pub struct FontFile<'a> {
+    max_height: usize,
+    m3_seed: u32,
+    blocks: Words<'a>,
+    hashes: Words<'a>,
+    /// Verification keys, which files older than version 3 don't have
+    keys: Option<Words<'a>>,
+    offsets: Words<'a>,
+    data: Words<'a>,
+}
+
+impl<'a> FontFile<'a> {
+    /// Parse and check a font file stored as u32 words. Words past the end of
+    /// the font, like erased flash, are ignored.
+    pub fn from_words(words: &'a [u32]) -> Result<Self, FontFileErr> {
+        FontFile::parse(Words::Native(words))
+    }
+
+    /// Parse and check a font file stored as bytes, with little-endian words.
+    /// Bytes past the end of the font, like erased flash, are ignored.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, FontFileErr> {
+        FontFile::parse(Words::LeBytes(bytes))
+    }
+
+    fn parse(words: Words<'a>) -> Result<Self, FontFileErr> {
+        if words.len() < HEADER_WORDS {
+            return Err(FontFileErr::Truncated);
+        }
+        if words.get(0) != MAGIC {
+            return Err(FontFileErr::BadMagic);
+        }
+        let version = words.get(1);
+        if !(1..=VERSION).contains(&version) {
+            return Err(FontFileErr::BadVersion);
+        }
+        let max_height = words.get(2) as usize;
+        if max_height == 0 || max_height > 255 {
+            return Err(FontFileErr::BadHeight);
+        }
+        let block_count = words.get(4) as usize;
+        let glyph_count = words.get(5) as usize;
+        let data_len = words.get(6) as usize;
+        let key_count = match version {
+            1 | 2 => 0,
+            _ => glyph_count,
+        };
+        // Check sizes with overflow checks, since they come from the input
+        let sizes = [
+            block_count.checked_mul(BLOCK_WORDS),
+            Some(glyph_count),
+            Some(key_count),
+            Some(glyph_count),
+            Some(data_len),
+        ];
+        let mut end = HEADER_WORDS;
+        for size in sizes.iter() {
+            end = match size.and_then(|size| end.checked_add(size)) {
+                Some(end) => end,
+                None => return Err(FontFileErr::Truncated),
+            };
+        }
+        if end > words.len() {
+            return Err(FontFileErr::Truncated);
+        }
+        let blocks_start = HEADER_WORDS;
+        let hashes_start = blocks_start + block_count * BLOCK_WORDS;
+        let keys_start = hashes_start + glyph_count;
+        let offsets_start = keys_start + key_count;
+        let data_start = offsets_start + glyph_count;
+        let font = FontFile {
+            max_height,
+            m3_seed: words.get(3),
+            blocks: words.slice(blocks_start, block_count * BLOCK_WORDS),
+            hashes: words.slice(hashes_start, glyph_count),
+            keys: match key_count {
+                0 => None,
+                _ => Some(words.slice(keys_start, key_count)),
+            },
+            offsets: words.slice(offsets_start, glyph_count),
+            data: words.slice(data_start, data_len),
+        };
+        font.check_blocks()?;
+        font.check_glyphs()?;
+        Ok(font)
+    }
+
+    /// Number of blocks in the block table
+    fn block_count(&self) -> usize {
+        self.blocks.len() / BLOCK_WORDS
+    }
+
+    /// Block table entry i
+    fn block(&self, i: usize) -> FileBlock {
+        let base = i * BLOCK_WORDS;
+        FileBlock {
+            low: self.blocks.get(base),
+            high: self.blocks.get(base + 1),
+            cluster_lengths: self.blocks.get(base + 2),
+            start: self.blocks.get(base + 3) as usize,
+            count: self.blocks.get(base + 4) as usize,
+        }
+    }
+
+    /// Check that blocks are sorted and in range, and that their hashes are
+    /// sorted
+    fn check_blocks(&self) -> Result<(), FontFileErr> {
+        let mut next_low = 0;
+        for i in 0..self.block_count() {
+            let b = self.block(i);
+            let in_index = match b.start.checked_add(b.count) {
+                Some(end) => end <= self.hashes.len(),
+                None => false,
+            };
+            if b.low < next_low
+                || b.high < b.low
+                || b.high > 0x10_FFFF
+                || b.cluster_lengths & 1 != 0
+                || !in_index
+            {
+                return Err(FontFileErr::BadBlock);
+            }
+            next_low = b.high + 1;
+            for j in b.start + 1..b.start + b.count {
+                if self.hashes.get(j - 1) >= self.hashes.get(j) {
+                    return Err(FontFileErr::BadIndex);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that every offset in the index points at a glyph record that
+    /// fits in the pattern words and in the line height
+    fn check_glyphs(&self) -> Result<(), FontFileErr> {
+        for i in 0..self.offsets.len() {
+            let offset = self.offsets.get(i) as usize;
+            if offset >= self.data.len() {
+                return Err(FontFileErr::BadIndex);
+            }
+            let gh = self.header(offset);
+            let compressed_len = self.compressed_len(offset);
+            let words = compress::pattern_len(&gh, compressed_len);
+            if gh.h + gh.y_offset > self.max_height
+                || (compressed_len > 0 && gh.h > compress::MAX_ROWS)
+                || offset + words >= self.data.len()
+            {
+                return Err(FontFileErr::BadGlyph);
+            }
+        }
+        Ok(())
+    }
+
+    /// Look up the first n chars of cluster in the hash index range of block b
+    fn find(&self, b: &FileBlock, cluster: &str, n: u32) -> Option<(usize, usize)> {
+        let (target, bytes_hashed) = fonts::murmur3(cluster, self.m3_seed, n);
+        // Binary search of hashes in start..start + count
+        let (mut lo, mut hi) = (b.start, b.start + b.count);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let hash = self.hashes.get(mid);
+            if hash == target {
+                // Rule out a hash collision with a cluster the font doesn't have
+                if let Some(keys) = self.keys {
+                    if keys.get(mid) != fonts::cluster_key(cluster, self.m3_seed, n) {
+                        return None;
+                    }
+                }
+                return Some((self.offsets.get(mid) as usize, bytes_hashed));
+            } else if hash < target {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        None
+    }
+}
+
+impl Font for FontFile<'_> {
+    fn lookup(&self, cluster: &str) -> Option<(usize, usize)> {
+        let first_char = cluster.chars().next()? as u32;
+        let b = (0..self.block_count())
+            .map(|i| self.block(i))
+            .find(|b| b.low <= first_char && first_char <= b.high)?;
+        // Regional indicator symbols only form a flag when they come in pairs,
+        // so don't let a pair with no flag glyph fall back to single letters
+        if fonts::regional_indicator_pair(cluster).is_some() {
+            return self.find(&b, cluster, 2);
+        }
+        (1..32)
+            .rev()
+            .filter(|n| b.cluster_lengths & (1 << n) != 0)
+            .find_map(|n| self.find(&b, cluster, n))
+    }
+
+    fn header(&self, glyph: usize) -> GlyphHeader {
+        let header = self.data.get(glyph);
+        let w = ((header << 8) >> 24) as usize;
+        let h = ((header << 16) >> 24) as usize;
+        let y_offset = (header & 0x000000ff) as usize;
+        GlyphHeader { w, h, y_offset }
+    }
+
+    fn nth_word(&self, glyph: usize, n: usize) -> u32 {
+        self.data.get(glyph + n)
+    }
+
+    fn compressed_len(&self, glyph: usize) -> usize {
+        (self.data.get(glyph) >> 24) as usize
+    }
+
+    fn max_height(&self) -> usize {
+        self.max_height
+    }
+}
+
+/// Number of words that serialize() writes for font
+pub fn serialized_len(font: BuiltinFont) -> usize {
+    let blocks = font.blocks();
+    let glyph_count: usize = blocks.iter().map(index_len).sum();
+    HEADER_WORDS + blocks.len() * BLOCK_WORDS + 3 * glyph_count + font.data().len()
+}
+
+/// Number of hash index entries that serialize() writes for block b. Font files
+/// have no codepoints tables, so those entries go in the hash index too.
+fn index_len(b: &fonts::Block) -> usize {
+    b.hashes.len() + b.codepoint_entries().count()
+}
+
+/// Write a built-in font to out in the font file format. To store the file as
+/// bytes, write each word with u32::to_le_bytes().
+/// Return: number of words written
+pub fn serialize(font: BuiltinFont, out: &mut [u32]) -> Result<usize, FontFileErr> {
+    let len = serialized_len(font);
+    if out.len() < len {
+        return Err(FontFileErr::NoRoom);
+    }
+    let (blocks, data, m3_seed) = (font.blocks(), font.data(), font.m3_seed());
+    let glyph_count: usize = blocks.iter().map(index_len).sum();
+    let header = [
+        MAGIC,
+        VERSION,
+        font.max_height() as u32,
+        m3_seed,
+        blocks.len() as u32,
+        glyph_count as u32,
+        data.len() as u32,
+    ];
+    out[..HEADER_WORDS].copy_from_slice(&header);
+    let mut block_pos = HEADER_WORDS;
+    let hashes_start = block_pos + blocks.len() * BLOCK_WORDS;
+    let mut start = 0;
+    let hash = |c: char| fonts::murmur3(c.encode_utf8(&mut [0; 4]), m3_seed, 1).0;
+    for b in blocks.iter() {
+        let cluster_lengths = b.cluster_lengths.iter().fold(0, |mask, n| mask | (1 << n));
+        let count = index_len(b);
+        let entry = [b.low, b.high, cluster_lengths, start as u32, count as u32];
+        out[block_pos..block_pos + BLOCK_WORDS].copy_from_slice(&entry);
+        block_pos += BLOCK_WORDS;
+        // Merge the hash index with the codepoints table, sort the hashes, then
+        // put each key and offset in the same position as its hash
+        let entries = || {
+            let hashed = (0..b.hashes.len()).map(|i| (b.hashes[i], b.keys[i], b.offsets[i]));
+            let codepoints = b
+                .codepoint_entries()
+                .map(|(c, offset)| (hash(c), c as u32, offset));
+            hashed.chain(codepoints)
+        };
+        let pos = hashes_start + start;
+        let hashes = &mut out[pos..pos + count];
+        for (word, (hash, _, _)) in hashes.iter_mut().zip(entries()) {
+            *word = hash;
+        }
+        hashes.sort_unstable();
+        for (hash, key, offset) in entries() {
+            let i = match out[pos..pos + count].binary_search(&hash) {
+                Ok(i) | Err(i) => pos + i,
+            };
+            out[i + glyph_count] = key;
+            out[i + 2 * glyph_count] = offset as u32;
+        }
+        start += count;
+    }
+    let data_start = hashes_start + 3 * glyph_count;
+    out[data_start..len].copy_from_slice(data);
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Serialize font into buf and parse it
+    fn round_trip(font: BuiltinFont, buf: &mut [u32]) -> FontFile<'_> {
+        let len = serialize(font, buf).unwrap();
+        FontFile::from_words(&buf[..len]).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip_lookups_match() {
+        let buf = &mut [0; 12_000];
+        let fonts = [
+            #[cfg(feature = "latin-small")]
+            BuiltinFont::Small,
+            BuiltinFont::Kana,
+            BuiltinFont::Icons,
+            #[cfg(feature = "numeric")]
+            BuiltinFont::Numeric,
+        ];
+        let clusters = [
+            "a",
+            "é",
+            "\u{30CF}\u{309A}",
+            "ぴ",
+            "\u{E704}",
+            "€",
+            "鹅",
+            "",
+        ];
+        for font in fonts.iter() {
+            let file = round_trip(*font, buf);
+            assert_eq!(file.max_height(), font.max_height());
+            for cluster in clusters.iter() {
+                match (font.lookup(cluster), file.lookup(cluster)) {
+                    (Some((a, used_a)), Some((b, used_b))) => {
+                        assert_eq!(used_a, used_b);
+                        assert_eq!(font.header(a), file.header(b));
+                        assert_eq!(font.nth_word(a, 1), file.nth_word(b, 1));
+                    }
+                    (None, None) => (),
+                    _ => panic!("lookups differ for {:?}", cluster),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let words = &mut [0; 12_000];
+        let len = serialize(BuiltinFont::Kana, words).unwrap();
+        let bytes = &mut [0xFF; 48_100];
+        for (i, word) in words[..len].iter().enumerate() {
+            bytes[i * 4..i * 4 + 4].copy_from_slice(&word.to_le_bytes());
+        }
+        // Trailing bytes (erased flash) get ignored
+        let file = FontFile::from_bytes(&bytes[..]).unwrap();
+        let (glyph, bytes_used) = file.lookup("カ").unwrap();
+        assert_eq!(bytes_used, 3);
+        let (builtin, _) = BuiltinFont::Kana.lookup("カ").unwrap();
+        assert_eq!(
+            file.nth_word(glyph, 2),
+            BuiltinFont::Kana.nth_word(builtin, 2)
+        );
+    }
+
+    #[test]
+    fn test_verification_keys() {
+        let buf = &mut [0; 6_000];
+        let len = serialize(BuiltinFont::Icons, buf).unwrap();
+        let glyph_count = buf[5] as usize;
+        let keys_start = HEADER_WORDS + BLOCK_WORDS + glyph_count;
+        let file = FontFile::from_words(&buf[..len]).unwrap();
+        assert!(file.lookup("\u{E700}").is_some());
+        // A hash match with the wrong key counts as a collision
+        let bad = &mut [0; 6_000];
+        bad[..len].copy_from_slice(&buf[..len]);
+        for key in bad[keys_start..keys_start + glyph_count].iter_mut() {
+            *key ^= 1;
+        }
+        let file = FontFile::from_words(&bad[..len]).unwrap();
+        assert!(file.lookup("\u{E700}").is_none());
+        // Version 2 files have no keys, so their hash matches don't get checked
+        let v2 = &mut [0; 6_000];
+        let v2_len = len - glyph_count;
+        v2[..keys_start].copy_from_slice(&buf[..keys_start]);
+        v2[keys_start..v2_len].copy_from_slice(&buf[keys_start + glyph_count..len]);
+        v2[1] = 2;
+        let file = FontFile::from_words(&v2[..v2_len]).unwrap();
+        assert!(file.lookup("\u{E700}").is_some());
+    }
+
+    #[test]
+    fn test_bad_input() {
+        let buf = &mut [0; 6_000];
+        let len = serialize(BuiltinFont::Icons, buf).unwrap();
+        assert_eq!(
+            serialize(BuiltinFont::Icons, &mut buf[..len - 1]),
+            Err(FontFileErr::NoRoom)
+        );
+        let good = FontFile::from_words(&buf[..len]);
+        assert!(good.is_ok());
+        assert_eq!(
+            FontFile::from_words(&buf[..len - 1]).err(),
+            Some(FontFileErr::Truncated)
+        );
+        assert_eq!(
+            FontFile::from_words(&buf[..3]).err(),
+            Some(FontFileErr::Truncated)
+        );
+        // Corrupt a copy of the file one field at a time
+        let check = |i: usize, word: u32, err: FontFileErr| {
+            let bad = &mut [0; 6_000];
+            bad[..len].copy_from_slice(&buf[..len]);
+            bad[i] = word;
+            assert_eq!(FontFile::from_words(&bad[..len]).err(), Some(err));
+        };
+        check(0, 0, FontFileErr::BadMagic);
+        check(1, VERSION + 1, FontFileErr::BadVersion);
+        check(2, 0, FontFileErr::BadHeight);
+        check(4, 0xFFFF_FFFF, FontFileErr::Truncated);
+        check(5, 0xFFFF_FFFF, FontFileErr::Truncated);
+        // Block range ends before it starts
+        check(HEADER_WORDS + 1, 0, FontFileErr::BadBlock);
+        // Block uses more index entries than there are
+        check(HEADER_WORDS + 4, 0xFFFF, FontFileErr::BadBlock);
+        // Hash index out of order
+        let hashes_start = HEADER_WORDS + BLOCK_WORDS;
+        check(hashes_start, 0xFFFF_FFFF, FontFileErr::BadIndex);
+        // Offset past the end of the pattern words
+        let glyph_count = buf[5] as usize;
+        let offsets_start = hashes_start + 2 * glyph_count;
+        check(offsets_start, 0xFFFF, FontFileErr::BadIndex);
+        // Glyph header taller than the line height
+        let data_start = hashes_start + 3 * glyph_count;
+        check(data_start, 8 << 16 | 255 << 8, FontFileErr::BadGlyph);
+        // Compressed glyph record longer than the pattern words
+        let long_header = (127 << 24) | (8 << 16) | (8 << 8);
+        check(data_start, long_header, FontFileErr::BadGlyph);
+    }
+}
@@ -2,67 +2,360 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 //
 #![forbid(unsafe_code)]
+#[cfg(feature = "latin-bold")]
 pub mod bold;
+pub mod boxdraw;
+pub mod braille;
+pub mod compress;
+#[cfg(feature = "emoji")]
 pub mod emoji;
+pub mod hangul;
+#[cfg(feature = "hanzi")]
 pub mod hanzi;
+pub mod hex;
+pub mod icons;
+pub mod jamo;
+pub mod kana;
+pub mod marks;
+#[cfg(feature = "numeric")]
+pub mod numeric;
+#[cfg(feature = "latin-regular")]
 pub mod regular;
+#[cfg(feature = "latin-small")]
 pub mod small;
 
 use super::m3hash;
+use crate::custom::CustomGlyph;
+use crate::glyphstyle::GlyphStyle;
 use core::fmt;
+use core::sync::atomic::{AtomicBool, Ordering};
 
 /// Holds an offset into the glyph data array of a particular glyph set
 #[derive(Copy, Clone, Debug)]
 pub enum GlyphData {
+    #[cfg(feature = "emoji")]
     Emoji(usize),
+    #[cfg(feature = "latin-bold")]
     Bold(usize),
+    #[cfg(feature = "latin-regular")]
     Regular(usize),
+    #[cfg(feature = "latin-small")]
     Small(usize),
+    #[cfg(feature = "hanzi")]
     Hanzi(usize),
+    Kana(usize),
+    Jamo(usize),
+    #[cfg(feature = "numeric")]
+    Numeric(usize),
+    Hangul(hangul::Syllable),
+    BoxDrawing(boxdraw::Cell),
+    Braille(braille::Dots),
+    Icons(usize),
+    Custom(CustomGlyph),
+    Marks(usize),
 }
 impl GlyphData {
     /// Unpack glyph header of format: (w:u8)<<16 | (h:u8)<<8 | yOffset:u8
     pub fn header(self) -> GlyphHeader {
-        let header = match self {
+        let header = self.header_word();
+        let w = ((header << 8) >> 24) as usize;
+        let h = ((header << 16) >> 24) as usize;
+        let y_offset = (header & 0x000000ff) as usize;
+        GlyphHeader { w, h, y_offset }
+    }
+
+    /// Words of compressed pattern data following the header, or 0 if the
+    /// pattern is packed pixels (see compress.rs)
+    pub fn compressed_len(self) -> usize {
+        (self.header_word() >> 24) as usize
+    }
+
+    /// Packed header word for the glyph
+    fn header_word(self) -> u32 {
+        match self {
+            #[cfg(feature = "emoji")]
             GlyphData::Emoji(offset) => emoji::DATA[offset],
+            #[cfg(feature = "latin-bold")]
             GlyphData::Bold(offset) => bold::DATA[offset],
+            #[cfg(feature = "latin-regular")]
             GlyphData::Regular(offset) => regular::DATA[offset],
+            #[cfg(feature = "latin-small")]
             GlyphData::Small(offset) => small::DATA[offset],
+            #[cfg(feature = "hanzi")]
             GlyphData::Hanzi(offset) => hanzi::DATA[offset],
-        };
-        let w = ((header << 8) >> 24) as usize;
-        let h = ((header << 16) >> 24) as usize;
-        let y_offset = (header & 0x000000ff) as usize;
-        GlyphHeader { w, h, y_offset }
+            GlyphData::Kana(offset) => kana::DATA[offset],
+            GlyphData::Jamo(offset) => jamo::DATA[offset],
+            #[cfg(feature = "numeric")]
+            GlyphData::Numeric(offset) => numeric::DATA[offset],
+            GlyphData::Hangul(syllable) => syllable.header(),
+            GlyphData::BoxDrawing(cell) => cell.header(),
+            GlyphData::Braille(dots) => dots.header(),
+            GlyphData::Icons(offset) => icons::DATA[offset],
+            GlyphData::Custom(glyph) => glyph.header(),
+            GlyphData::Marks(offset) => marks::DATA[offset],
+        }
     }
 
-    /// Unpack the nth pixel data word following the header
+    /// Unpack the nth pattern data word following the header
     pub fn nth_word(self, n: usize) -> u32 {
         match self {
+            #[cfg(feature = "emoji")]
             GlyphData::Emoji(offset) => emoji::DATA[offset + n],
+            #[cfg(feature = "latin-bold")]
             GlyphData::Bold(offset) => bold::DATA[offset + n],
+            #[cfg(feature = "latin-regular")]
             GlyphData::Regular(offset) => regular::DATA[offset + n],
+            #[cfg(feature = "latin-small")]
             GlyphData::Small(offset) => small::DATA[offset + n],
+            #[cfg(feature = "hanzi")]
             GlyphData::Hanzi(offset) => hanzi::DATA[offset + n],
+            GlyphData::Kana(offset) => kana::DATA[offset + n],
+            GlyphData::Jamo(offset) => jamo::DATA[offset + n],
+            #[cfg(feature = "numeric")]
+            GlyphData::Numeric(offset) => numeric::DATA[offset + n],
+            GlyphData::Hangul(syllable) => syllable.nth_word(n),
+            GlyphData::BoxDrawing(cell) => cell.nth_word(n),
+            GlyphData::Braille(dots) => dots.nth_word(n),
+            GlyphData::Icons(offset) => icons::DATA[offset + n],
+            GlyphData::Custom(glyph) => glyph.nth_word(n),
+            GlyphData::Marks(offset) => marks::DATA[offset + n],
         }
     }
 }
 
 /// Holds header data for a font glyph
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct GlyphHeader {
     pub w: usize,
     pub h: usize,
     pub y_offset: usize,
 }
 
+/// Index for the glyphs of one Unicode block in a generated font. The first n
+/// chars of clusters starting with a char in low..=high get looked up, trying
+/// each n of cluster_lengths in order. Single codepoints get looked up in the
+/// codepoints table when the block has one, and everything else in hashes.
+pub struct Block {
+    pub low: u32,
+    pub high: u32,
+    pub cluster_lengths: &'static [u32],
+    /// Codepoint of codepoints[0]
+    pub codepoints_low: u32,
+    /// Offsets into DATA indexed by codepoint - codepoints_low, or NO_GLYPH.
+    /// Codegen leaves this empty when the block's single codepoints are too
+    /// sparse for a table to be smaller than their hash index entries.
+    pub codepoints: &'static [u32],
+    /// Sorted murmur3 hashes of clusters
+    pub hashes: &'static [u32],
+    /// Verification keys for the clusters in hashes (see cluster_key())
+    pub keys: &'static [u32],
+    /// Offsets into DATA for the clusters in hashes
+    pub offsets: &'static [usize],
+}
+impl Block {
+    /// Iterate over the chars in the codepoints table that have glyphs
+    /// Returns: (char, offset into DATA)
+    pub fn codepoint_entries(&self) -> impl Iterator<Item = (char, usize)> + '_ {
+        let low = self.codepoints_low;
+        self.codepoints
+            .iter()
+            .zip(low..)
+            .filter(|(offset, _)| **offset != NO_GLYPH)
+            .filter_map(|(offset, cp)| Some((char::from_u32(cp)?, *offset as usize)))
+    }
+}
+
 /// Available typeface glyph sets
 #[derive(Copy, Clone, Debug)]
 pub enum GlyphSet {
+    #[cfg(feature = "emoji")]
     Emoji,
+    #[cfg(feature = "latin-bold")]
     Bold,
+    #[cfg(feature = "latin-regular")]
     Regular,
+    #[cfg(feature = "latin-small")]
     Small,
+    #[cfg(feature = "hanzi")]
     Hanzi,
+    Kana,
+    Hangul,
+    /// Large digits and numeric symbols for GlyphStyle::Numeric
+    #[cfg(feature = "numeric")]
+    Numeric,
+    /// Box drawing and block element cells sized for lines of this height
+    BoxDrawing(u8),
+    /// Braille patterns with dots spaced to fit lines of this height
+    Braille(u8),
+    Icons,
+    /// Custom glyph of this height from a CustomGlyphs registry
+    Custom(u8),
+    /// Glyph from a Font object with this line height
+    Font(u8),
+}
+impl GlyphSet {
+    /// Look up the blit pattern for a grapheme cluster in this glyph set
+    /// Returns: Result<(blit pattern offset into DATA, bytes of cluster used by match)>
+    pub fn get_blit_pattern_offset(self, cluster: &str) -> Result<(GlyphData, usize), NoGlyphErr> {
+        match self {
+            #[cfg(feature = "emoji")]
+            GlyphSet::Emoji => emoji::get_blit_pattern_offset(cluster),
+            #[cfg(feature = "latin-bold")]
+            GlyphSet::Bold => bold::get_blit_pattern_offset(cluster),
+            #[cfg(feature = "latin-regular")]
+            GlyphSet::Regular => regular::get_blit_pattern_offset(cluster),
+            #[cfg(feature = "latin-small")]
+            GlyphSet::Small => small::get_blit_pattern_offset(cluster),
+            #[cfg(feature = "hanzi")]
+            GlyphSet::Hanzi => hanzi::get_blit_pattern_offset(cluster),
+            GlyphSet::Kana => kana::get_blit_pattern_offset(cluster),
+            GlyphSet::Hangul => hangul::get_blit_pattern_offset(cluster),
+            #[cfg(feature = "numeric")]
+            GlyphSet::Numeric => numeric::get_blit_pattern_offset(cluster),
+            GlyphSet::BoxDrawing(line_height) => {
+                boxdraw::get_blit_pattern_offset(cluster, line_height as usize)
+            }
+            GlyphSet::Braille(line_height) => {
+                braille::get_blit_pattern_offset(cluster, line_height as usize)
+            }
+            GlyphSet::Icons => icons::get_blit_pattern_offset(cluster),
+            // Custom glyphs and Font objects do their own lookups
+            GlyphSet::Custom(_) | GlyphSet::Font(_) => Err(NoGlyphErr),
+        }
+    }
+
+    /// Latin glyph set for style st. When the cargo feature for the style's
+    /// font is off, the closest font that is included gets used instead.
+    pub fn latin(st: GlyphStyle) -> GlyphSet {
+        let closest: &[GlyphSet] = match st {
+            GlyphStyle::Small => &[
+                #[cfg(feature = "latin-small")]
+                GlyphSet::Small,
+                #[cfg(feature = "latin-regular")]
+                GlyphSet::Regular,
+                #[cfg(feature = "latin-bold")]
+                GlyphSet::Bold,
+            ],
+            GlyphStyle::Regular => &[
+                #[cfg(feature = "latin-regular")]
+                GlyphSet::Regular,
+                #[cfg(feature = "latin-bold")]
+                GlyphSet::Bold,
+                #[cfg(feature = "latin-small")]
+                GlyphSet::Small,
+            ],
+            // Numeric glyphs only cover digits, so text around them is bold
+            GlyphStyle::Bold | GlyphStyle::Numeric => &[
+                #[cfg(feature = "latin-bold")]
+                GlyphSet::Bold,
+                #[cfg(feature = "latin-regular")]
+                GlyphSet::Regular,
+                #[cfg(feature = "latin-small")]
+                GlyphSet::Small,
+            ],
+        };
+        // lib.rs makes sure at least one latin font is included
+        closest[0]
+    }
+
+    /// Return true for the latin glyph sets
+    pub fn is_latin(self) -> bool {
+        match self {
+            #[cfg(feature = "latin-bold")]
+            GlyphSet::Bold => true,
+            #[cfg(feature = "latin-regular")]
+            GlyphSet::Regular => true,
+            #[cfg(feature = "latin-small")]
+            GlyphSet::Small => true,
+            _ => false,
+        }
+    }
+
+    /// Maximum height of glyph patterns in this glyph set
+    pub fn max_height(self) -> usize {
+        let max_height = match self {
+            #[cfg(feature = "latin-bold")]
+            GlyphSet::Bold => bold::MAX_HEIGHT,
+            #[cfg(feature = "latin-regular")]
+            GlyphSet::Regular => regular::MAX_HEIGHT,
+            #[cfg(feature = "latin-small")]
+            GlyphSet::Small => small::MAX_HEIGHT,
+            #[cfg(feature = "emoji")]
+            GlyphSet::Emoji => emoji::MAX_HEIGHT,
+            #[cfg(feature = "hanzi")]
+            GlyphSet::Hanzi => hanzi::MAX_HEIGHT,
+            GlyphSet::Kana => kana::MAX_HEIGHT,
+            GlyphSet::Hangul => hangul::MAX_HEIGHT,
+            #[cfg(feature = "numeric")]
+            GlyphSet::Numeric => numeric::MAX_HEIGHT,
+            GlyphSet::BoxDrawing(line_height) | GlyphSet::Braille(line_height) => line_height,
+            GlyphSet::Icons => icons::MAX_HEIGHT,
+            GlyphSet::Custom(h) | GlyphSet::Font(h) => h,
+        };
+        max_height as usize
+    }
+}
+
+/// Entry in a Block's codepoints table for a codepoint with no glyph
+pub const NO_GLYPH: u32 = u32::MAX;
+
+/// Look up the first char of cluster in a table of DATA offsets indexed by
+/// codepoint - low, with NO_GLYPH for codepoints that have no glyph
+/// Returns: Some((offset into DATA, bytes of cluster used by match))
+pub fn find_codepoint(table: &[u32], low: u32, cluster: &str) -> Option<(usize, usize)> {
+    let c = cluster.chars().next()?;
+    let i = (c as u32).checked_sub(low)? as usize;
+    match table.get(i) {
+        Some(&offset) if offset != NO_GLYPH => Some((offset as usize, c.len_utf8())),
+        _ => None,
+    }
+}
+
+/// When set, the font files hash every cluster, like they did before they
+/// checked continues_cluster() first. See set_hash_all_clusters().
+static HASH_ALL_CLUSTERS: AtomicBool = AtomicBool::new(false);
+
+/// Make glyph lookups hash every cluster, even when the next char can't
+/// continue it, so that src/bin/bench.rs can measure what skipping the hash
+/// saves. This is only for benchmarks.
+#[doc(hidden)]
+pub fn set_hash_all_clusters(on: bool) {
+    HASH_ALL_CLUSTERS.store(on, Ordering::Relaxed);
+}
+
+/// Return true if a font file's hash lookup for cluster can be skipped, since
+/// only single codepoints, which are in the codepoint tables, can match
+pub fn skip_hash(cluster: &str) -> bool {
+    !continues_cluster(cluster) && !HASH_ALL_CLUSTERS.load(Ordering::Relaxed)
+}
+
+/// Return true if the char after the first char of cluster can continue a
+/// grapheme cluster. Only then can a glyph for a multi-codepoint cluster match.
+pub fn continues_cluster(cluster: &str) -> bool {
+    match cluster.chars().nth(1) {
+        Some(next) => is_continuation(next),
+        None => false,
+    }
+}
+
+/// Return true if c can continue a grapheme cluster that a glyph set has a
+/// glyph or a composition rule for. These are combining diacritical marks,
+/// hangul vowel and final jamo, zero width joiner, combining enclosing keycap,
+/// kana voiced sound marks, variation selectors, regional indicators, emoji
+/// skin tone modifiers, and tags.
+fn is_continuation(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x0300..=0x036F
+            | 0x1160..=0x11FF
+            | 0x200D
+            | 0x20E3
+            | 0x3099..=0x309A
+            | 0xFE00..=0xFE0F
+            | 0x1F1E6..=0x1F1FF
+            | 0x1F3FB..=0x1F3FF
+            | 0xE0020..=0xE007F
+    )
 }
 
 /// Error type for when a font has no glyph to match a grapheme cluster query
@@ -74,6 +367,54 @@ impl fmt::Display for NoGlyphErr {
     }
 }
 
+/// Pack pixel word n of a w x h procedural glyph pattern, in the same format as
+/// the DATA arrays of the generated fonts. pixel(x, y) says which pixels are set.
+pub fn pack_pixels(n: usize, w: usize, h: usize, pixel: impl Fn(usize, usize) -> bool) -> u32 {
+    let mut word = 0;
+    for bit in 0..32 {
+        let i = (n - 1) * 32 + bit;
+        if i >= w * h {
+            break;
+        }
+        // Rows are packed starting from their rightmost pixel
+        if pixel(w - 1 - i % w, i / w) {
+            word |= 1 << (31 - bit);
+        }
+    }
+    word
+}
+
+/// Return the key that gets checked after a hash index match for the first
+/// limit codepoints of cluster, to rule out murmur3 hash collisions. A single
+/// codepoint is its own key, so single codepoints can't get false matches.
+/// Longer clusters get a second murmur3 hash, with seed !m3_seed and the MSB
+/// set so it can't be mistaken for a codepoint.
+pub fn cluster_key(cluster: &str, m3_seed: u32, limit: u32) -> u32 {
+    let mut chars = cluster.chars().take(limit as usize);
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => c as u32,
+        _ => murmur3(cluster, !m3_seed, limit).0 | 0x8000_0000,
+    }
+}
+
+/// Regional indicator symbols A..Z, which pair up to spell out flag emoji
+const REGIONAL_INDICATORS: core::ops::RangeInclusive<u32> = 0x1F1E6..=0x1F1FF;
+
+/// If cluster starts with a pair of regional indicator symbols, return the
+/// pair as uppercase ASCII letters (e.g. "🇺🇸" => 'U', 'S').
+/// Returns: Some((first letter, second letter, bytes of cluster used by pair))
+pub fn regional_indicator_pair(cluster: &str) -> Option<(char, char, usize)> {
+    let mut chars = cluster.chars();
+    let a = chars.next()? as u32;
+    let b = chars.next()? as u32;
+    if !REGIONAL_INDICATORS.contains(&a) || !REGIONAL_INDICATORS.contains(&b) {
+        return None;
+    }
+    let letter = |ri: u32| (b'A' + (ri - REGIONAL_INDICATORS.start()) as u8) as char;
+    // Regional indicators are 4 bytes each in UTF-8
+    Some((letter(a), letter(b), 8))
+}
+
 /// Compute Murmur3 hash function of the first limit codepoints of a string,
 /// using each char as a u32 block. This wrapper function exists to provide a
 /// stable font::murmur3(...) internal API that the font codegen system can use
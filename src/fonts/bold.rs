@@ -33,684 +33,882 @@ pub const M3_SEED: u32 = 0;
 ///
 /// Returns: Result<(blit pattern offset into DATA, bytes of cluster used by match)>
 pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGlyphErr> {
-    let first_char: u32;
-    match cluster.chars().next() {
-        Some(c) => first_char = c as u32,
+    let first_char: u32 = match cluster.chars().next() {
+        Some(c) => c as u32,
         None => return Err(NoGlyphErr),
-    }
-    return match first_char {
+    };
+    match first_char {
         0x0..=0x7F => {
             if let Some((offset, bytes_used)) = find_basic_latin(cluster, 2) {
                 Ok((GlyphData::Bold(offset), bytes_used))
-            } else if let Some((offset, bytes_used)) = find_basic_latin(cluster, 1) {
+            } else if let Some((offset, bytes_used)) = find_basic_latin_codepoint(cluster) {
                 Ok((GlyphData::Bold(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
             }
         }
         0x80..=0xFF => {
-            if let Some((offset, bytes_used)) = find_latin_1_supplement(cluster, 1) {
+            if let Some((offset, bytes_used)) = find_latin_1_supplement_codepoint(cluster) {
                 Ok((GlyphData::Bold(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
             }
         }
         0x100..=0x17F => {
-            if let Some((offset, bytes_used)) = find_latin_extended_a(cluster, 1) {
+            if let Some((offset, bytes_used)) = find_latin_extended_a_codepoint(cluster) {
+                Ok((GlyphData::Bold(offset), bytes_used))
+            } else {
+                Err(NoGlyphErr)
+            }
+        }
+        0x370..=0x3FF => {
+            if let Some((offset, bytes_used)) = find_greek_and_coptic_codepoint(cluster) {
+                Ok((GlyphData::Bold(offset), bytes_used))
+            } else {
+                Err(NoGlyphErr)
+            }
+        }
+        0x400..=0x4FF => {
+            if let Some((offset, bytes_used)) = find_cyrillic(cluster, 2) {
+                Ok((GlyphData::Bold(offset), bytes_used))
+            } else if let Some((offset, bytes_used)) = find_cyrillic_codepoint(cluster) {
                 Ok((GlyphData::Bold(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
             }
         }
         0x2000..=0x206F => {
-            if let Some((offset, bytes_used)) = find_general_punctuation(cluster, 1) {
+            if let Some((offset, bytes_used)) = find_general_punctuation_codepoint(cluster) {
                 Ok((GlyphData::Bold(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
             }
         }
         0x20A0..=0x20CF => {
-            if let Some((offset, bytes_used)) = find_currency_symbols(cluster, 1) {
+            if let Some((offset, bytes_used)) = find_currency_symbols_codepoint(cluster) {
                 Ok((GlyphData::Bold(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
             }
         }
         0xFFF0..=0xFFFF => {
-            if let Some((offset, bytes_used)) = find_specials(cluster, 1) {
+            if let Some((offset, bytes_used)) = find_specials_codepoint(cluster) {
                 Ok((GlyphData::Bold(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
             }
         }
         _ => Err(super::NoGlyphErr),
-    };
+    }
+}
+
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_basic_latin_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_BASIC_LATIN, 0x20, cluster)
 }
 
+/// Blit pattern offsets for codepoints 0x20..=0x7E, or NO_GLYPH
+static CODEPOINT_BASIC_LATIN: [u32; 95] = [
+    0,     // " "
+    2,     // "!"
+    6,     // "\""
+    9,     // "#"
+    18,    // "$"
+    26,    // "%"
+    39,    // "&"
+    49,    // "'"
+    51,    // "("
+    57,    // ")"
+    63,    // "*"
+    68,    // "+"
+    73,    // ","
+    75,    // "-"
+    77,    // "."
+    79,    // "/"
+    87,    // "0"
+    95,    // "1"
+    101,   // "2"
+    109,   // "3"
+    117,   // "4"
+    126,   // "5"
+    134,   // "6"
+    142,   // "7"
+    150,   // "8"
+    158,   // "9"
+    166,   // ":"
+    169,   // ";"
+    173,   // "<"
+    179,   // "="
+    183,   // ">"
+    189,   // "?"
+    197,   // "@"
+    207,   // "A"
+    215,   // "B"
+    223,   // "C"
+    231,   // "D"
+    239,   // "E"
+    246,   // "F"
+    253,   // "G"
+    261,   // "H"
+    269,   // "I"
+    275,   // "J"
+    283,   // "K"
+    292,   // "L"
+    299,   // "M"
+    312,   // "N"
+    321,   // "O"
+    329,   // "P"
+    337,   // "Q"
+    347,   // "R"
+    355,   // "S"
+    362,   // "T"
+    370,   // "U"
+    378,   // "V"
+    386,   // "W"
+    399,   // "X"
+    407,   // "Y"
+    415,   // "Z"
+    423,   // "["
+    429,   // "\\"
+    437,   // "]"
+    443,   // "^"
+    446,   // "_"
+    448,   // "`"
+    451,   // "a"
+    458,   // "b"
+    466,   // "c"
+    472,   // "d"
+    480,   // "e"
+    487,   // "f"
+    494,   // "g"
+    503,   // "h"
+    511,   // "i"
+    515,   // "j"
+    524,   // "k"
+    532,   // "l"
+    536,   // "m"
+    546,   // "n"
+    553,   // "o"
+    560,   // "p"
+    568,   // "q"
+    576,   // "r"
+    582,   // "s"
+    588,   // "t"
+    594,   // "u"
+    601,   // "v"
+    608,   // "w"
+    618,   // "x"
+    625,   // "y"
+    634,   // "z"
+    641,   // "{"
+    647,   // "|"
+    650,   // "}"
+    656,   // "~"
+];
+
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_basic_latin(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_BASIC_LATIN.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_BASIC_LATIN[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_BASIC_LATIN.binary_search(&hash) {
+        Ok(index) if KEY_BASIC_LATIN[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_BASIC_LATIN[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_BASIC_LATIN
-const HASH_BASIC_LATIN: [u32; 148] = [
+static HASH_BASIC_LATIN: [u32; 53] = [
     0x0323CD4F,  // "ë" 65-308
-    0x049BF55E,  // "}"
     0x0537C05E,  // "È" 45-300
-    0x0B9EA876,  // "8"
     0x0D4E2B64,  // "è" 65-300
     0x11DDB5BC,  // "Ù" 55-300
     0x129F2A7F,  // "ã" 61-303
-    0x13156136,  // "F"
-    0x131AB870,  // "O"
     0x1403A883,  // "Ê" 45-302
-    0x1586C0AA,  // "j"
-    0x1672836B,  // "`"
-    0x16F9A05F,  // "#"
-    0x18D53BB6,  // "("
     0x198B90FC,  // "ù" 75-300
-    0x1BF82D35,  // "y"
     0x1C40E42F,  // "Ö" 4F-308
-    0x1CFCD293,  // ")"
     0x1F2B17F3,  // "Î" 49-302
-    0x1F79A4D0,  // "$"
-    0x21840E11,  // "q"
-    0x22ABE92F,  // "K"
     0x2379B646,  // "Õ" 4F-303
     0x24189EC6,  // "ä" 61-308
-    0x259E68D3,  // "6"
-    0x26FF6E36,  // "3"
-    0x2A3184F1,  // "U"
-    0x2B038801,  // "a"
-    0x2C17F13B,  // "r"
-    0x2D2269F8,  // "m"
     0x30F856B5,  // "Â" 41-302
-    0x31099644,  // "t"
-    0x315F5687,  // "I"
-    0x3673CB35,  // "{"
-    0x39BC06BB,  // "L"
     0x435F26F6,  // "Ñ" 4E-303
-    0x43603571,  // "C"
-    0x44C216BA,  // "R"
     0x455E0578,  // "Ü" 55-308
-    0x45EF573F,  // ";"
     0x465D3B9D,  // "í" 69-301
     0x47EF8034,  // "ÿ" 79-308
-    0x4938EA00,  // "7"
-    0x4AB0FC13,  // "4"
-    0x4BCD3197,  // "A"
-    0x4CC5A899,  // "|"
-    0x524C4F7E,  // "B"
-    0x52D3CB36,  // "%"
     0x55C7F2AE,  // "ý" 79-301
-    0x56DEE618,  // "v"
-    0x5A352E5E,  // "c"
-    0x5B06F60A,  // "1"
-    0x5C904E6C,  // "_"
-    0x5CA0F32F,  // "&"
-    0x5FF80125,  // "S"
-    0x61901224,  // "5"
     0x67BD451E,  // "ñ" 6E-303
     0x681839B8,  // "Ò" 4F-300
-    0x68E75BE7,  // "@"
     0x6C058218,  // "Û" 55-302
-    0x6E85A379,  // "x"
-    0x72DE1EFB,  // "*"
-    0x73688484,  // "p"
-    0x7627CA6C,  // "D"
-    0x779CDA2E,  // "Y"
     0x7834A19C,  // "ó" 6F-301
-    0x78FD8C32,  // "u"
     0x7A435EEE,  // "Ï" 49-308
-    0x7B6B1369,  // "9"
-    0x7BA23029,  // "J"
     0x7F4EBF76,  // "Ç" 43-327
-    0x803AF153,  // "["
-    0x804744C4,  // "e"
-    0x84510F7D,  // "o"
     0x8499B628,  // "å" 61-30A
-    0x86A8B043,  // ","
-    0x8B86BA97,  // "="
-    0x8C1A54EE,  // "g"
-    0x8C69C315,  // " "
     0x8C94654B,  // "ê" 65-302
-    0x8DB20A6B,  // "!"
     0x933CEC52,  // "Ä" 41-308
-    0x9500C437,  // "<"
-    0x9582C02E,  // "G"
-    0x98453FD6,  // "?"
     0x98C6B546,  // "ü" 75-308
-    0x99933C47,  // "w"
     0x9B44CABE,  // "á" 61-301
     0x9DD6D1DF,  // "â" 61-302
     0x9F99932F,  // "À" 41-300
     0xA03D6D22,  // "Å" 41-30A
-    0xA13B361A,  // "/"
-    0xA1A410C7,  // "2"
     0xA26A0E29,  // "Ã" 41-303
     0xA30CAD6B,  // "û" 75-302
-    0xA5872342,  // "k"
-    0xA891E88A,  // "0"
-    0xABE208D0,  // "P"
-    0xADABEA12,  // "d"
     0xADC91A24,  // "ç" 63-327
     0xAE495307,  // "õ" 6F-303
-    0xAF41DB71,  // "]"
-    0xB12BF2EE,  // "V"
-    0xB35F1351,  // "E"
     0xB454A383,  // "ú" 75-301
     0xB5097BAB,  // "ö" 6F-308
-    0xB8ED6BE2,  // "X"
     0xBE856396,  // "ì" 69-300
-    0xBFE47D0D,  // "Q"
-    0xC06E932F,  // "T"
     0xC0AFD8ED,  // "Ô" 4F-302
-    0xC1209A73,  // "f"
-    0xC2DDC575,  // "\""
     0xC3AB3492,  // "ò" 6F-300
     0xC57A2C66,  // "Ó" 4F-301
     0xC5E4D96B,  // "î" 69-302
-    0xC890E3DD,  // ">"
-    0xC99309A2,  // "-"
-    0xC9BEA311,  // "Z"
-    0xCAECCF17,  // "s"
-    0xCAF83468,  // "H"
-    0xCB07FD3E,  // "'"
-    0xCB7242E0,  // "+"
-    0xCD3FDBE3,  // "M"
-    0xCE94AE25,  // "b"
     0xD363E17B,  // "à" 61-300
-    0xD4FFA898,  // ":"
-    0xD7B03F23,  // "z"
     0xD822B857,  // "Ì" 49-300
-    0xDA2B151D,  // "\\"
-    0xDB01AF22,  // "n"
-    0xDC135ABD,  // "N"
-    0xE2AA1EBB,  // "."
-    0xE5CA55BF,  // "i"
-    0xE6556D01,  // "h"
     0xE7C19BA9,  // "é" 65-301
     0xED67FA54,  // "Ý" 59-301
-    0xEF026B52,  // "l"
     0xEF302693,  // "Í" 49-301
     0xF04B9422,  // "Ú" 55-301
-    0xF0F19A38,  // "~"
-    0xF29AB82D,  // "W"
     0xF31D8BFB,  // "ï" 69-308
     0xF3A5A351,  // "Á" 41-301
     0xF3BF2609,  // "É" 45-301
     0xF85223EB,  // "Ë" 45-308
-    0xFC2C2430,  // "^"
     0xFFF1D70C,  // "ô" 6F-302
 ];
 
+/// Verification keys to rule out hash collisions; sort matches HASH_BASIC_LATIN
+static KEY_BASIC_LATIN: [u32; 53] = [
+    0x80D44E97,  // "ë" 65-308
+    0xDB1ED85C,  // "È" 45-300
+    0xE04C4E1C,  // "è" 65-300
+    0xA28AC6EA,  // "Ù" 55-300
+    0xE2E050C3,  // "ã" 61-303
+    0xD9FAA663,  // "Ê" 45-302
+    0xABC0AB4B,  // "ù" 75-300
+    0xE42C33D7,  // "Ö" 4F-308
+    0xB175A1B9,  // "Î" 49-302
+    0xD74A4220,  // "Õ" 4F-303
+    0x80943004,  // "ä" 61-308
+    0xE2AB7B52,  // "Â" 41-302
+    0xACDB8AB2,  // "Ñ" 4E-303
+    0xA77793A4,  // "Ü" 55-308
+    0xA360CABE,  // "í" 69-301
+    0xE210549A,  // "ÿ" 79-308
+    0xBE05027D,  // "ý" 79-301
+    0xE75C9E2F,  // "ñ" 6E-303
+    0xA89AE289,  // "Ò" 4F-300
+    0x8D2F33EB,  // "Û" 55-302
+    0xF203F879,  // "ó" 6F-301
+    0xB3605562,  // "Ï" 49-308
+    0xB0D4E0C2,  // "Ç" 43-327
+    0xA0CF5C31,  // "å" 61-30A
+    0xD1BFE014,  // "ê" 65-302
+    0xCFEE06C2,  // "Ä" 41-308
+    0xD1F64E74,  // "ü" 75-308
+    0xBCA67AFB,  // "á" 61-301
+    0xCB130ED9,  // "â" 61-302
+    0xD9283FBB,  // "À" 41-300
+    0xB0326F4E,  // "Å" 41-30A
+    0x97B109FE,  // "Ã" 41-303
+    0xFBEBF095,  // "û" 75-302
+    0xCA6BBFA7,  // "ç" 63-327
+    0x8C3671BD,  // "õ" 6F-303
+    0xF5F21CDB,  // "ú" 75-301
+    0xC894E903,  // "ö" 6F-308
+    0xB66FD0FD,  // "ì" 69-300
+    0xF8F41517,  // "Ô" 4F-302
+    0xB440ABE0,  // "ò" 6F-300
+    0xC2A3381F,  // "Ó" 4F-301
+    0xE25FA6D2,  // "î" 69-302
+    0x9523889F,  // "à" 61-300
+    0xCF2F30E0,  // "Ì" 49-300
+    0xAAEB4531,  // "é" 65-301
+    0xBE8AF7BB,  // "Ý" 59-301
+    0xAF5882A5,  // "Í" 49-301
+    0x91A3D630,  // "Ú" 55-301
+    0xB1E3AEA7,  // "ï" 69-308
+    0x9CC2135C,  // "Á" 41-301
+    0xBD3D9CD7,  // "É" 45-301
+    0xB64E013C,  // "Ë" 45-308
+    0xEBB3788F,  // "ô" 6F-302
+];
+
 /// Lookup table of blit pattern offsets; sort matches HASH_BASIC_LATIN
-const OFFSET_BASIC_LATIN: [usize; 148] = [
+static OFFSET_BASIC_LATIN: [usize; 53] = [
     1253, // "ë" 65-308
-    650,  // "}"
     940,  // "È" 45-300
-    150,  // "8"
     1226, // "è" 65-300
     1085, // "Ù" 55-300
     1180, // "ã" 61-303
-    246,  // "F"
-    321,  // "O"
     958,  // "Ê" 45-302
-    515,  // "j"
-    448,  // "`"
-    9,    // "#"
-    51,   // "("
     1355, // "ù" 75-300
-    625,  // "y"
     1058, // "Ö" 4F-308
-    57,   // ")"
     983,  // "Î" 49-302
-    18,   // "$"
-    568,  // "q"
-    283,  // "K"
     1048, // "Õ" 4F-303
     1189, // "ä" 61-308
-    134,  // "6"
-    109,  // "3"
-    370,  // "U"
-    451,  // "a"
-    576,  // "r"
-    536,  // "m"
     878,  // "Â" 41-302
-    588,  // "t"
-    269,  // "I"
-    641,  // "{"
-    292,  // "L"
     1006, // "Ñ" 4E-303
-    223,  // "C"
-    347,  // "R"
     1115, // "Ü" 55-308
-    169,  // ";"
     1265, // "í" 69-301
     1411, // "ÿ" 79-308
-    142,  // "7"
-    117,  // "4"
-    207,  // "A"
-    647,  // "|"
-    215,  // "B"
-    26,   // "%"
     1390, // "ý" 79-301
-    601,  // "v"
-    466,  // "c"
-    95,   // "1"
-    446,  // "_"
-    39,   // "&"
-    355,  // "S"
-    126,  // "5"
     1289, // "ñ" 6E-303
     1018, // "Ò" 4F-300
-    197,  // "@"
     1105, // "Û" 55-302
-    618,  // "x"
-    63,   // "*"
-    560,  // "p"
-    231,  // "D"
-    407,  // "Y"
     1307, // "ó" 6F-301
-    594,  // "u"
     990,  // "Ï" 49-308
-    158,  // "9"
-    275,  // "J"
     930,  // "Ç" 43-327
-    423,  // "["
-    480,  // "e"
-    553,  // "o"
     1197, // "å" 61-30A
-    73,   // ","
-    179,  // "="
-    494,  // "g"
-    0,    // " "
     1244, // "ê" 65-302
-    2,    // "!"
     898,  // "Ä" 41-308
-    173,  // "<"
-    253,  // "G"
-    189,  // "?"
     1382, // "ü" 75-308
-    608,  // "w"
     1162, // "á" 61-301
     1171, // "â" 61-302
     858,  // "À" 41-300
     908,  // "Å" 41-30A
-    79,   // "/"
-    101,  // "2"
     888,  // "Ã" 41-303
     1373, // "û" 75-302
-    524,  // "k"
-    87,   // "0"
-    329,  // "P"
-    472,  // "d"
     1217, // "ç" 63-327
     1325, // "õ" 6F-303
-    437,  // "]"
-    378,  // "V"
-    239,  // "E"
     1364, // "ú" 75-301
     1334, // "ö" 6F-308
-    399,  // "X"
     1261, // "ì" 69-300
-    337,  // "Q"
-    362,  // "T"
     1038, // "Ô" 4F-302
-    487,  // "f"
-    6,    // "\""
     1298, // "ò" 6F-300
     1028, // "Ó" 4F-301
     1269, // "î" 69-302
-    183,  // ">"
-    75,   // "-"
-    415,  // "Z"
-    582,  // "s"
-    261,  // "H"
-    49,   // "'"
-    68,   // "+"
-    299,  // "M"
-    458,  // "b"
     1153, // "à" 61-300
-    166,  // ":"
-    634,  // "z"
     975,  // "Ì" 49-300
-    429,  // "\\"
-    546,  // "n"
-    312,  // "N"
-    77,   // "."
-    511,  // "i"
-    503,  // "h"
     1235, // "é" 65-301
     1125, // "Ý" 59-301
-    532,  // "l"
     979,  // "Í" 49-301
     1095, // "Ú" 55-301
-    656,  // "~"
-    386,  // "W"
     1275, // "ï" 69-308
     868,  // "Á" 41-301
     949,  // "É" 45-301
     967,  // "Ë" 45-308
-    443,  // "^"
     1316, // "ô" 6F-302
 ];
 
-/// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
-/// Only attempt to match grapheme clusters of length limit codepoints.
-fn find_latin_1_supplement(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_LATIN_1_SUPPLEMENT.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_LATIN_1_SUPPLEMENT[index], bytes_hashed)),
-        _ => None,
-    }
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_latin_1_supplement_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_LATIN_1_SUPPLEMENT, 0xA0, cluster)
 }
 
-/// Index of murmur3(grapheme cluster); sort matches OFFSET_LATIN_1_SUPPLEMENT
-const HASH_LATIN_1_SUPPLEMENT: [u32; 96] = [
-    0x00EAC56E,  // "°"
-    0x0254FD66,  // "®"
-    0x056DE9E6,  // "Î"
-    0x06FE368D,  // "Ö"
-    0x137E3259,  // "Õ"
-    0x18E6F281,  // "à"
-    0x1A5A66CD,  // "ø"
-    0x1B0DE252,  // "¢"
-    0x1B43C661,  // "¤"
-    0x1C505947,  // "í"
-    0x252158D3,  // "¥"
-    0x25872B22,  // "ë"
-    0x259437AC,  // "§"
-    0x268B196D,  // "ä"
-    0x275E5021,  // "¨"
-    0x2766361E,  // "Ú"
-    0x278A377A,  // "Ì"
-    0x28CA6AD7,  // "Ø"
-    0x291E971E,  // "Ï"
-    0x3280724D,  // "»"
-    0x36953E7B,  // "õ"
-    0x388FB155,  // "ï"
-    0x39056A43,  // "É"
-    0x3A073FBE,  // "È"
-    0x3AE00F65,  // "ü"
-    0x3E168B4D,  // "ó"
-    0x3E82329E,  // "û"
-    0x402DCFF6,  // "×"
-    0x4236CDD1,  // "¾"
-    0x44D0C2C4,  // "ê"
-    0x450DB83D,  // "Û"
-    0x4F92356C,  // "µ"
-    0x511BCA1D,  // "á"
-    0x554A5349,  // "Æ"
-    0x5E39BCEE,  // "´"
-    0x5EB9D2A0,  // "ç"
-    0x5EE10367,  // "ò"
-    0x62D494F4,  // "Ý"
-    0x6984AA2D,  // "Í"
-    0x6BA96CC3,  // "Ü"
-    0x6E3C05CF,  // "ý"
-    0x6EF8ED06,  // "\u00AD" Soft Hyphen
-    0x6FA3C127,  // "ã"
-    0x71EE15F2,  // "Ò"
-    0x7619D892,  // "ª"
-    0x76554D10,  // "Ç"
-    0x79026F8E,  // "Å"
-    0x7E2A203C,  // "÷"
-    0x839D40CB,  // "£"
-    0x84FF8F94,  // "Ô"
-    0x8AE0A2B9,  // "å"
-    0x8D741045,  // "Þ"
-    0x8EA3F31F,  // "³"
-    0x9194CCB5,  // "¬"
-    0x92979158,  // "ô"
-    0x95C4DC63,  // "Ã"
-    0x97A9C1DA,  // "¶"
-    0x97C63B05,  // "\u00A0" No-Break Space
-    0xA10C8120,  // "·"
-    0xA5F3368B,  // "¹"
-    0xA7883CA5,  // "¸"
-    0xA7B0FE42,  // "©"
-    0xABF561B2,  // "«"
-    0xAD97621D,  // "Ð"
-    0xB1A64F1E,  // "Â"
-    0xB1BF38B8,  // "º"
-    0xB55DA0FA,  // "î"
-    0xB5CA05F5,  // "¦"
-    0xB7001103,  // "¡"
-    0xB9229669,  // "¿"
-    0xBA8E9829,  // "Ä"
-    0xBB608964,  // "ú"
-    0xC0837C42,  // "¼"
-    0xC0E7AB63,  // "¯"
-    0xC20FE7B7,  // "þ"
-    0xC6EF143E,  // "ß"
-    0xC98BE718,  // "Á"
-    0xCAD0511F,  // "é"
-    0xCAF24984,  // "Ù"
-    0xD040D3E3,  // "è"
-    0xD4D6097A,  // "Ë"
-    0xD83534E2,  // "Ñ"
-    0xDD061C7A,  // "²"
-    0xDE3FB757,  // "ì"
-    0xE14F2E81,  // "â"
-    0xE2532EDC,  // "ð"
-    0xE51FF77C,  // "½"
-    0xE5DA3FED,  // "±"
-    0xE9131AA1,  // "ù"
-    0xEBC8143D,  // "ö"
-    0xEC99A516,  // "À"
-    0xF446CD1A,  // "Ó"
-    0xF520EF41,  // "æ"
-    0xF64ED921,  // "ñ"
-    0xFB89D3F4,  // "ÿ"
-    0xFBFECC1C,  // "Ê"
+/// Blit pattern offsets for codepoints 0xA0..=0xFF, or NO_GLYPH
+static CODEPOINT_LATIN_1_SUPPLEMENT: [u32; 96] = [
+    659,   // "\u00A0" No-Break Space
+    661,   // "¡"
+    665,   // "¢"
+    671,   // "£"
+    680,   // "¤"
+    688,   // "¥"
+    698,   // "¦"
+    701,   // "§"
+    710,   // "¨"
+    712,   // "©"
+    722,   // "ª"
+    728,   // "«"
+    736,   // "¬"
+    739,   // "\u00AD" Soft Hyphen
+    741,   // "®"
+    751,   // "¯"
+    753,   // "°"
+    756,   // "±"
+    762,   // "²"
+    765,   // "³"
+    768,   // "´"
+    771,   // "µ"
+    781,   // "¶"
+    790,   // "·"
+    792,   // "¸"
+    794,   // "¹"
+    797,   // "º"
+    803,   // "»"
+    811,   // "¼"
+    824,   // "½"
+    837,   // "¾"
+    850,   // "¿"
+    858,   // "À"
+    868,   // "Á"
+    878,   // "Â"
+    888,   // "Ã"
+    898,   // "Ä"
+    908,   // "Å"
+    918,   // "Æ"
+    930,   // "Ç"
+    940,   // "È"
+    949,   // "É"
+    958,   // "Ê"
+    967,   // "Ë"
+    975,   // "Ì"
+    979,   // "Í"
+    983,   // "Î"
+    990,   // "Ï"
+    997,   // "Ð"
+    1006,  // "Ñ"
+    1018,  // "Ò"
+    1028,  // "Ó"
+    1038,  // "Ô"
+    1048,  // "Õ"
+    1058,  // "Ö"
+    1068,  // "×"
+    1073,  // "Ø"
+    1085,  // "Ù"
+    1095,  // "Ú"
+    1105,  // "Û"
+    1115,  // "Ü"
+    1125,  // "Ý"
+    1135,  // "Þ"
+    1144,  // "ß"
+    1153,  // "à"
+    1162,  // "á"
+    1171,  // "â"
+    1180,  // "ã"
+    1189,  // "ä"
+    1197,  // "å"
+    1207,  // "æ"
+    1217,  // "ç"
+    1226,  // "è"
+    1235,  // "é"
+    1244,  // "ê"
+    1253,  // "ë"
+    1261,  // "ì"
+    1265,  // "í"
+    1269,  // "î"
+    1275,  // "ï"
+    1281,  // "ð"
+    1289,  // "ñ"
+    1298,  // "ò"
+    1307,  // "ó"
+    1316,  // "ô"
+    1325,  // "õ"
+    1334,  // "ö"
+    1342,  // "÷"
+    1347,  // "ø"
+    1355,  // "ù"
+    1364,  // "ú"
+    1373,  // "û"
+    1382,  // "ü"
+    1390,  // "ý"
+    1401,  // "þ"
+    1411,  // "ÿ"
 ];
 
-/// Lookup table of blit pattern offsets; sort matches HASH_LATIN_1_SUPPLEMENT
-const OFFSET_LATIN_1_SUPPLEMENT: [usize; 96] = [
-    753,  // "°"
-    741,  // "®"
-    983,  // "Î"
-    1058, // "Ö"
-    1048, // "Õ"
-    1153, // "à"
-    1347, // "ø"
-    665,  // "¢"
-    680,  // "¤"
-    1265, // "í"
-    688,  // "¥"
-    1253, // "ë"
-    701,  // "§"
-    1189, // "ä"
-    710,  // "¨"
-    1095, // "Ú"
-    975,  // "Ì"
-    1073, // "Ø"
-    990,  // "Ï"
-    803,  // "»"
-    1325, // "õ"
-    1275, // "ï"
-    949,  // "É"
-    940,  // "È"
-    1382, // "ü"
-    1307, // "ó"
-    1373, // "û"
-    1068, // "×"
-    837,  // "¾"
-    1244, // "ê"
-    1105, // "Û"
-    771,  // "µ"
-    1162, // "á"
-    918,  // "Æ"
-    768,  // "´"
-    1217, // "ç"
-    1298, // "ò"
-    1125, // "Ý"
-    979,  // "Í"
-    1115, // "Ü"
-    1390, // "ý"
-    739,  // "\u00AD" Soft Hyphen
-    1180, // "ã"
-    1018, // "Ò"
-    722,  // "ª"
-    930,  // "Ç"
-    908,  // "Å"
-    1342, // "÷"
-    671,  // "£"
-    1038, // "Ô"
-    1197, // "å"
-    1135, // "Þ"
-    765,  // "³"
-    736,  // "¬"
-    1316, // "ô"
-    888,  // "Ã"
-    781,  // "¶"
-    659,  // "\u00A0" No-Break Space
-    790,  // "·"
-    794,  // "¹"
-    792,  // "¸"
-    712,  // "©"
-    728,  // "«"
-    997,  // "Ð"
-    878,  // "Â"
-    797,  // "º"
-    1269, // "î"
-    698,  // "¦"
-    661,  // "¡"
-    850,  // "¿"
-    898,  // "Ä"
-    1364, // "ú"
-    811,  // "¼"
-    751,  // "¯"
-    1401, // "þ"
-    1144, // "ß"
-    868,  // "Á"
-    1235, // "é"
-    1085, // "Ù"
-    1226, // "è"
-    967,  // "Ë"
-    1006, // "Ñ"
-    762,  // "²"
-    1261, // "ì"
-    1171, // "â"
-    1281, // "ð"
-    824,  // "½"
-    756,  // "±"
-    1355, // "ù"
-    1334, // "ö"
-    858,  // "À"
-    1028, // "Ó"
-    1207, // "æ"
-    1289, // "ñ"
-    1411, // "ÿ"
-    958,  // "Ê"
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_latin_extended_a_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_LATIN_EXTENDED_A, 0x152, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x152..=0x153, or NO_GLYPH
+static CODEPOINT_LATIN_EXTENDED_A: [u32; 2] = [
+    1421,  // "Œ"
+    1433,  // "œ"
 ];
 
-/// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
-/// Only attempt to match grapheme clusters of length limit codepoints.
-fn find_latin_extended_a(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_LATIN_EXTENDED_A.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_LATIN_EXTENDED_A[index], bytes_hashed)),
-        _ => None,
-    }
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_greek_and_coptic_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_GREEK_AND_COPTIC, 0x37E, cluster)
 }
 
-/// Index of murmur3(grapheme cluster); sort matches OFFSET_LATIN_EXTENDED_A
-const HASH_LATIN_EXTENDED_A: [u32; 2] = [
-    0x1A01594C,  // "Œ"
-    0x8C60DA30,  // "œ"
+/// Blit pattern offsets for codepoints 0x37E..=0x3C9, or NO_GLYPH
+static CODEPOINT_GREEK_AND_COPTIC: [u32; 76] = [
+    169,   // ";"
+    super::NO_GLYPH,  // U+037F
+    super::NO_GLYPH,  // U+0380
+    super::NO_GLYPH,  // U+0381
+    super::NO_GLYPH,  // U+0382
+    super::NO_GLYPH,  // U+0383
+    768,   // "΄"
+    super::NO_GLYPH,  // U+0385
+    super::NO_GLYPH,  // U+0386
+    790,   // "·"
+    super::NO_GLYPH,  // U+0388
+    super::NO_GLYPH,  // U+0389
+    super::NO_GLYPH,  // U+038A
+    super::NO_GLYPH,  // U+038B
+    super::NO_GLYPH,  // U+038C
+    super::NO_GLYPH,  // U+038D
+    super::NO_GLYPH,  // U+038E
+    super::NO_GLYPH,  // U+038F
+    super::NO_GLYPH,  // U+0390
+    207,   // "Α"
+    215,   // "Β"
+    1443,  // "Γ"
+    1451,  // "Δ"
+    239,   // "Ε"
+    415,   // "Ζ"
+    261,   // "Η"
+    1461,  // "Θ"
+    269,   // "Ι"
+    283,   // "Κ"
+    1470,  // "Λ"
+    299,   // "Μ"
+    312,   // "Ν"
+    1480,  // "Ξ"
+    321,   // "Ο"
+    1489,  // "Π"
+    329,   // "Ρ"
+    super::NO_GLYPH,  // U+03A2
+    1498,  // "Σ"
+    362,   // "Τ"
+    407,   // "Υ"
+    1507,  // "Φ"
+    399,   // "Χ"
+    1517,  // "Ψ"
+    1527,  // "Ω"
+    super::NO_GLYPH,  // U+03AA
+    super::NO_GLYPH,  // U+03AB
+    super::NO_GLYPH,  // U+03AC
+    super::NO_GLYPH,  // U+03AD
+    super::NO_GLYPH,  // U+03AE
+    super::NO_GLYPH,  // U+03AF
+    super::NO_GLYPH,  // U+03B0
+    1537,  // "α"
+    1545,  // "β"
+    1555,  // "γ"
+    1564,  // "δ"
+    1572,  // "ε"
+    1578,  // "ζ"
+    1588,  // "η"
+    1597,  // "θ"
+    1605,  // "ι"
+    1609,  // "κ"
+    1616,  // "λ"
+    771,   // "μ"
+    601,   // "ν"
+    1624,  // "ξ"
+    553,   // "ο"
+    1634,  // "π"
+    1642,  // "ρ"
+    1650,  // "ς"
+    1656,  // "σ"
+    1664,  // "τ"
+    1671,  // "υ"
+    1678,  // "φ"
+    1688,  // "χ"
+    1696,  // "ψ"
+    1708,  // "ω"
 ];
 
-/// Lookup table of blit pattern offsets; sort matches HASH_LATIN_EXTENDED_A
-const OFFSET_LATIN_EXTENDED_A: [usize; 2] = [
-    1421, // "Œ"
-    1433, // "œ"
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_cyrillic_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_CYRILLIC, 0x400, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x400..=0x491, or NO_GLYPH
+static CODEPOINT_CYRILLIC: [u32; 146] = [
+    940,   // "Ѐ"
+    967,   // "Ё"
+    1716,  // "Ђ"
+    super::NO_GLYPH,  // U+0403
+    1727,  // "Є"
+    355,   // "Ѕ"
+    269,   // "І"
+    990,   // "Ї"
+    275,   // "Ј"
+    1736,  // "Љ"
+    1749,  // "Њ"
+    1762,  // "Ћ"
+    super::NO_GLYPH,  // U+040C
+    super::NO_GLYPH,  // U+040D
+    super::NO_GLYPH,  // U+040E
+    1772,  // "Џ"
+    207,   // "А"
+    1782,  // "Б"
+    215,   // "В"
+    1443,  // "Г"
+    1791,  // "Д"
+    239,   // "Е"
+    1803,  // "Ж"
+    1816,  // "З"
+    1825,  // "И"
+    1834,  // "Й"
+    283,   // "К"
+    1846,  // "Л"
+    299,   // "М"
+    261,   // "Н"
+    321,   // "О"
+    1489,  // "П"
+    329,   // "Р"
+    223,   // "С"
+    362,   // "Т"
+    1855,  // "У"
+    1507,  // "Ф"
+    399,   // "Х"
+    1864,  // "Ц"
+    1875,  // "Ч"
+    1884,  // "Ш"
+    1894,  // "Щ"
+    1907,  // "Ъ"
+    1916,  // "Ы"
+    1926,  // "Ь"
+    1934,  // "Э"
+    1943,  // "Ю"
+    1955,  // "Я"
+    451,   // "а"
+    1964,  // "б"
+    1972,  // "в"
+    1979,  // "г"
+    1985,  // "д"
+    480,   // "е"
+    1993,  // "ж"
+    2001,  // "з"
+    2008,  // "и"
+    2015,  // "й"
+    1609,  // "к"
+    2024,  // "л"
+    2031,  // "м"
+    2039,  // "н"
+    553,   // "о"
+    2046,  // "п"
+    560,   // "р"
+    466,   // "с"
+    2053,  // "т"
+    625,   // "у"
+    2060,  // "ф"
+    618,   // "х"
+    2072,  // "ц"
+    2080,  // "ч"
+    2087,  // "ш"
+    2095,  // "щ"
+    2105,  // "ъ"
+    2112,  // "ы"
+    2120,  // "ь"
+    2126,  // "э"
+    2133,  // "ю"
+    2141,  // "я"
+    1226,  // "ѐ"
+    1253,  // "ё"
+    2148,  // "ђ"
+    super::NO_GLYPH,  // U+0453
+    2160,  // "є"
+    582,   // "ѕ"
+    511,   // "і"
+    1275,  // "ї"
+    515,   // "ј"
+    2167,  // "љ"
+    2176,  // "њ"
+    2184,  // "ћ"
+    super::NO_GLYPH,  // U+045C
+    super::NO_GLYPH,  // U+045D
+    super::NO_GLYPH,  // U+045E
+    2193,  // "џ"
+    super::NO_GLYPH,  // U+0460
+    super::NO_GLYPH,  // U+0461
+    super::NO_GLYPH,  // U+0462
+    super::NO_GLYPH,  // U+0463
+    super::NO_GLYPH,  // U+0464
+    super::NO_GLYPH,  // U+0465
+    super::NO_GLYPH,  // U+0466
+    super::NO_GLYPH,  // U+0467
+    super::NO_GLYPH,  // U+0468
+    super::NO_GLYPH,  // U+0469
+    super::NO_GLYPH,  // U+046A
+    super::NO_GLYPH,  // U+046B
+    super::NO_GLYPH,  // U+046C
+    super::NO_GLYPH,  // U+046D
+    super::NO_GLYPH,  // U+046E
+    super::NO_GLYPH,  // U+046F
+    super::NO_GLYPH,  // U+0470
+    super::NO_GLYPH,  // U+0471
+    super::NO_GLYPH,  // U+0472
+    super::NO_GLYPH,  // U+0473
+    super::NO_GLYPH,  // U+0474
+    super::NO_GLYPH,  // U+0475
+    super::NO_GLYPH,  // U+0476
+    super::NO_GLYPH,  // U+0477
+    super::NO_GLYPH,  // U+0478
+    super::NO_GLYPH,  // U+0479
+    super::NO_GLYPH,  // U+047A
+    super::NO_GLYPH,  // U+047B
+    super::NO_GLYPH,  // U+047C
+    super::NO_GLYPH,  // U+047D
+    super::NO_GLYPH,  // U+047E
+    super::NO_GLYPH,  // U+047F
+    super::NO_GLYPH,  // U+0480
+    super::NO_GLYPH,  // U+0481
+    super::NO_GLYPH,  // U+0482
+    super::NO_GLYPH,  // U+0483
+    super::NO_GLYPH,  // U+0484
+    super::NO_GLYPH,  // U+0485
+    super::NO_GLYPH,  // U+0486
+    super::NO_GLYPH,  // U+0487
+    super::NO_GLYPH,  // U+0488
+    super::NO_GLYPH,  // U+0489
+    super::NO_GLYPH,  // U+048A
+    super::NO_GLYPH,  // U+048B
+    super::NO_GLYPH,  // U+048C
+    super::NO_GLYPH,  // U+048D
+    super::NO_GLYPH,  // U+048E
+    super::NO_GLYPH,  // U+048F
+    2200,  // "Ґ"
+    2211,  // "ґ"
 ];
 
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
-fn find_general_punctuation(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_GENERAL_PUNCTUATION.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_GENERAL_PUNCTUATION[index], bytes_hashed)),
+fn find_cyrillic(cluster: &str, limit: u32) -> Option<(usize, usize)> {
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_CYRILLIC.binary_search(&hash) {
+        Ok(index) if KEY_CYRILLIC[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_CYRILLIC[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
-/// Index of murmur3(grapheme cluster); sort matches OFFSET_GENERAL_PUNCTUATION
-const HASH_GENERAL_PUNCTUATION: [u32; 11] = [
-    0x0D0042B1,  // "•"
-    0x18E68A7D,  // "‚"
-    0x372ED469,  // "“"
-    0x3A3C25C2,  // "”"
-    0x80E4E277,  // "‡"
-    0x885E576B,  // "‟"
-    0x8B80C01D,  // "‛"
-    0x8F0BE1DB,  // "„"
-    0x8F56D335,  // "†"
-    0xA6C64F29,  // "‘"
-    0xE29813B0,  // "’"
+/// Index of murmur3(grapheme cluster); sort matches OFFSET_CYRILLIC
+static HASH_CYRILLIC: [u32; 2] = [
+    0x96A19F32,  // "й" 438-306
+    0xFDAF4D81,  // "Й" 418-306
 ];
 
-/// Lookup table of blit pattern offsets; sort matches HASH_GENERAL_PUNCTUATION
-const OFFSET_GENERAL_PUNCTUATION: [usize; 11] = [
-    1474, // "•"
-    1447, // "‚"
-    1451, // "“"
-    1455, // "”"
-    1470, // "‡"
-    1463, // "‟"
-    1449, // "‛"
-    1459, // "„"
-    1467, // "†"
-    1443, // "‘"
-    1445, // "’"
+/// Verification keys to rule out hash collisions; sort matches HASH_CYRILLIC
+static KEY_CYRILLIC: [u32; 2] = [
+    0xE507E6DB,  // "й" 438-306
+    0xE62754ED,  // "Й" 418-306
 ];
 
-/// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
-/// Only attempt to match grapheme clusters of length limit codepoints.
-fn find_currency_symbols(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_CURRENCY_SYMBOLS.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_CURRENCY_SYMBOLS[index], bytes_hashed)),
-        _ => None,
-    }
+/// Lookup table of blit pattern offsets; sort matches HASH_CYRILLIC
+static OFFSET_CYRILLIC: [usize; 2] = [
+    2015, // "й" 438-306
+    1834, // "Й" 418-306
+];
+
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_general_punctuation_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_GENERAL_PUNCTUATION, 0x2018, cluster)
 }
 
-/// Index of murmur3(grapheme cluster); sort matches OFFSET_CURRENCY_SYMBOLS
-const HASH_CURRENCY_SYMBOLS: [u32; 1] = [
-    0x1ACA36BB,  // "€"
+/// Blit pattern offsets for codepoints 0x2018..=0x2022, or NO_GLYPH
+static CODEPOINT_GENERAL_PUNCTUATION: [u32; 11] = [
+    2217,  // "‘"
+    2219,  // "’"
+    2221,  // "‚"
+    2223,  // "‛"
+    2225,  // "“"
+    2229,  // "”"
+    2233,  // "„"
+    2237,  // "‟"
+    2241,  // "†"
+    2244,  // "‡"
+    2248,  // "•"
 ];
 
-/// Lookup table of blit pattern offsets; sort matches HASH_CURRENCY_SYMBOLS
-const OFFSET_CURRENCY_SYMBOLS: [usize; 1] = [
-    1479, // "€"
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_currency_symbols_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_CURRENCY_SYMBOLS, 0x20AC, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x20AC..=0x20AC, or NO_GLYPH
+static CODEPOINT_CURRENCY_SYMBOLS: [u32; 1] = [
+    2253,  // "€"
 ];
 
-/// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
-/// Only attempt to match grapheme clusters of length limit codepoints.
-fn find_specials(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_SPECIALS.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_SPECIALS[index], bytes_hashed)),
-        _ => None,
-    }
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_specials_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_SPECIALS, 0xFFFD, cluster)
 }
 
-/// Index of murmur3(grapheme cluster); sort matches OFFSET_SPECIALS
-const HASH_SPECIALS: [u32; 1] = [
-    0x58A5DA35,  // "�"
+/// Blit pattern offsets for codepoints 0xFFFD..=0xFFFD, or NO_GLYPH
+static CODEPOINT_SPECIALS: [u32; 1] = [
+    2262,  // "�"
 ];
 
-/// Lookup table of blit pattern offsets; sort matches HASH_SPECIALS
-const OFFSET_SPECIALS: [usize; 1] = [
-    1488, // "�"
+/// Index blocks in the order that get_blit_pattern_offset() checks them
+pub static BLOCKS: [super::Block; 8] = [
+    super::Block {
+        low: 0x0,
+        high: 0x7F,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x20,
+        codepoints: &CODEPOINT_BASIC_LATIN,
+        hashes: &HASH_BASIC_LATIN,
+        keys: &KEY_BASIC_LATIN,
+        offsets: &OFFSET_BASIC_LATIN,
+    },
+    super::Block {
+        low: 0x80,
+        high: 0xFF,
+        cluster_lengths: &[1],
+        codepoints_low: 0xA0,
+        codepoints: &CODEPOINT_LATIN_1_SUPPLEMENT,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
+    super::Block {
+        low: 0x100,
+        high: 0x17F,
+        cluster_lengths: &[1],
+        codepoints_low: 0x152,
+        codepoints: &CODEPOINT_LATIN_EXTENDED_A,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
+    super::Block {
+        low: 0x370,
+        high: 0x3FF,
+        cluster_lengths: &[1],
+        codepoints_low: 0x37E,
+        codepoints: &CODEPOINT_GREEK_AND_COPTIC,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
+    super::Block {
+        low: 0x400,
+        high: 0x4FF,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x400,
+        codepoints: &CODEPOINT_CYRILLIC,
+        hashes: &HASH_CYRILLIC,
+        keys: &KEY_CYRILLIC,
+        offsets: &OFFSET_CYRILLIC,
+    },
+    super::Block {
+        low: 0x2000,
+        high: 0x206F,
+        cluster_lengths: &[1],
+        codepoints_low: 0x2018,
+        codepoints: &CODEPOINT_GENERAL_PUNCTUATION,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
+    super::Block {
+        low: 0x20A0,
+        high: 0x20CF,
+        cluster_lengths: &[1],
+        codepoints_low: 0x20AC,
+        codepoints: &CODEPOINT_CURRENCY_SYMBOLS,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
+    super::Block {
+        low: 0xFFF0,
+        high: 0xFFFF,
+        cluster_lengths: &[1],
+        codepoints_low: 0xFFFD,
+        codepoints: &CODEPOINT_SPECIALS,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
 ];
 
 /// Packed glyph pattern data.
@@ -723,7 +921,7 @@ const OFFSET_SPECIALS: [usize; 1] = [
 ///  h: Height of pattern in pixels
 ///  yOffset: Vertical offset (pixels downward from top of line) to position
 ///     glyph pattern properly relative to text baseline
-pub const DATA: [u32; 1501] = [
+pub static DATA: [u32; 2275] = [
     // [0]: 20 " "
     0x0004020e, 0x00000000,
     // [2]: 21 "!"
@@ -1184,32 +1382,254 @@ pub const DATA: [u32; 1501] = [
     // [1433]: 153 "œ"
     0x00140e0a, 0x3fffc3ff, 0xfcf0f0ff, 0x0f0ff0f0, 0xff0f0fff, 0xf0ffff0f, 0x00f0f00f, 0x0fc0f0fc,
     0x0f0f3fff, 0xc3fffc00,
-    // [1443]: 2018 "‘"
+    // [1443]: 393 "Γ"
+    0x000c1206, 0xffffff00, 0xf00f00f0, 0x0f00f00f, 0x00f00f00, 0xf00f00f0, 0x0f00f00f, 0x00f00f00,
+    // [1451]: 394 "Δ"
+    0x00101206, 0x03c003c0, 0x03c003c0, 0x0ff00ff0, 0x0ff00ff0, 0x3c3c3c3c, 0x3c3c3c3c, 0xf00ff00f,
+    0xf00ff00f, 0xffffffff,
+    // [1461]: 398 "Θ"
+    0x000e1206, 0x3ff0ffcf, 0x03fc0ff0, 0x3fc0ff03, 0xfc0fffff, 0xffff03fc, 0x0ff03fc0, 0xff03fc0f,
+    0x3ff0ffc0,
+    // [1470]: 39B "Λ"
+    0x00101206, 0x03c003c0, 0x03c003c0, 0x0ff00ff0, 0x0ff00ff0, 0x3c3c3c3c, 0x3c3c3c3c, 0xf00ff00f,
+    0xf00ff00f, 0xf00ff00f,
+    // [1480]: 39E "Ξ"
+    0x000e1206, 0xfffffff0, 0x00000000, 0x00000000, 0x00003ff0, 0xffc00000, 0x00000000, 0x00000000,
+    0xfffffff0,
+    // [1489]: 3A0 "Π"
+    0x000e1206, 0xffffffff, 0x03fc0ff0, 0x3fc0ff03, 0xfc0ff03f, 0xc0ff03fc, 0x0ff03fc0, 0xff03fc0f,
+    0xf03fc0f0,
+    // [1498]: 3A3 "Σ"
+    0x000e1206, 0xfffffff0, 0x03c00f00, 0xf003c03c, 0x00f00f00, 0x3c003c00, 0xf000f003, 0xc003c00f,
+    0xfffffff0,
+    // [1507]: 3A6 "Φ"
+    0x00101206, 0x03c003c0, 0x3ffc3ffc, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf, 0x3ffc3ffc,
+    0x03c003c0, 0x03c003c0,
+    // [1517]: 3A8 "Ψ"
+    0x00101206, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf, 0x3ffc3ffc, 0x0ff00ff0, 0x03c003c0,
+    0x03c003c0, 0x03c003c0,
+    // [1527]: 3A9 "Ω"
+    0x00101206, 0x0ff00ff0, 0x3c3c3c3c, 0xf00ff00f, 0xf00ff00f, 0xf00ff00f, 0x3c3c3c3c, 0x0ff00ff0,
+    0x0ff00ff0, 0xffffffff,
+    // [1537]: 3B1 "α"
+    0x000e0e0a, 0xfff3ffcf, 0xc3ff0f3c, 0x3cf0f3c3, 0xcf0f3c3c, 0xf0ffc3ff, 0x0ffff3ff, 0xc0000000,
+    // [1545]: 3B2 "β"
+    0x000c1806, 0x3fc3fcf0, 0xff0ff0ff, 0x0f3cf3cf, 0x3ff3fff0, 0xff0ff0ff, 0x0ff0ff0f, 0x3ff3ff00,
+    0xf00f00f0, 0x0f00f00f,
+    // [1555]: 3B3 "γ"
+    0x000c140a, 0xf0ff0ff0, 0xff0f3fc3, 0xfc3fc3fc, 0x0f00f00f, 0x00f00f00, 0xf00f00f0, 0x0f00f00f,
+    0x00f00000,
+    // [1564]: 3B4 "δ"
+    0x000c1206, 0xffcffc00, 0xf00f03c0, 0x3c3f03f0, 0xf3cf3cf0, 0xff0ff0ff, 0x0ff0ff0f, 0x3fc3fc00,
+    // [1572]: 3B5 "ε"
+    0x000a0e0a, 0xff3fc03c, 0x0f03c0f3, 0xf0fc03c0, 0xf03c0fff, 0x3fc00000,
+    // [1578]: 3B6 "ζ"
+    0x000c1806, 0xffffff3c, 0x03c00f00, 0xf003c03c, 0x00f00f00, 0xf00f00f0, 0x0f00f00f, 0x3fc3fcf0,
+    0x0f00f00f, 0x003f03f0,
+    // [1588]: 3B7 "η"
+    0x000c140a, 0x3ff3fff3, 0xff3ff0ff, 0x0ff0ff0f, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff00f00, 0xf00f00f0,
+    0x0f000000,
+    // [1597]: 3B8 "θ"
+    0x000c1206, 0x3fc3fcf0, 0xff0ff0ff, 0x0ff0ff0f, 0xfffffff0, 0xff0ff0ff, 0x0ff0ff0f, 0x3fc3fc00,
+    // [1605]: 3B9 "ι"
+    0x00060e0a, 0x3cf3cf3c, 0xf3cf3cf3, 0xcff3c000,
+    // [1609]: 3BA "κ"
+    0x000c0e0a, 0xf0ff0f3c, 0xf3cf0ff0, 0xff03f03f, 0x0ff0ff3c, 0xf3cff0ff, 0x0f000000,
+    // [1616]: 3BB "λ"
+    0x000c1206, 0x00f00f03, 0xc03c03c0, 0x3c0f00f0, 0x0f00f03f, 0xc3fc3fc3, 0xfcf0ff0f, 0xf0ff0f00,
+    // [1624]: 3BE "ξ"
+    0x000c1806, 0xffcffc00, 0xf00f00f0, 0x0f3fc3fc, 0x00f00f00, 0xf00f00f0, 0x0f00f00f, 0x3fc3fcf0,
+    0x0f00f00f, 0x003f03f0,
+    // [1634]: 3C0 "π"
+    0x00100e0a, 0xffffffff, 0x3c3c3c3c, 0x3c3c3c3c, 0x3c3c3c3c, 0x3c3c3c3c, 0x3c3c3c3c, 0x3c3c3c3c,
+    // [1642]: 3C1 "ρ"
+    0x000c120a, 0x3fc3fcf0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0ff0, 0xff0f3ff3, 0xff00f00f, 0x00f00f00,
+    // [1650]: 3C2 "ς"
+    0x000a100a, 0xff3fc03c, 0x0f03c0f0, 0x3c0f3f0f, 0xcf03c0f0, 0x3c03f0fc,
+    // [1656]: 3C3 "σ"
+    0x000e0e0a, 0xfff3ffc3, 0xc3cf0f3c, 0x3cf0f3c3, 0xcf0f3c3c, 0xf0f3c3cf, 0x0f0ff03f, 0xc0000000,
+    // [1664]: 3C4 "τ"
+    0x000c0e0a, 0xffffff0f, 0x00f00f00, 0xf00f00f0, 0x0f00f00f, 0x00f0fc0f, 0xc0000000,
+    // [1671]: 3C5 "υ"
+    0x000c0e0a, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0ff0, 0xff0f3fc3, 0xfc000000,
+    // [1678]: 3C6 "φ"
+    0x0010120a, 0x03c003c0, 0x3ffc3ffc, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf, 0x3ffc3ffc,
+    0x03c003c0, 0x03c003c0,
+    // [1688]: 3C7 "χ"
+    0x000c120a, 0xf0ff0ff0, 0xff0f3fc3, 0xfc3fc3fc, 0x0f00f03f, 0xc3fc3fc3, 0xfcf0ff0f, 0xf0ff0f00,
+    // [1696]: 3C8 "ψ"
+    0x00101606, 0x03c003c0, 0x03c003c0, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf,
+    0x3ffc3ffc, 0x0ff00ff0, 0x03c003c0, 0x03c003c0,
+    // [1708]: 3C9 "ω"
+    0x00100e0a, 0x3c3c3c3c, 0xf00ff00f, 0xf00ff00f, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf, 0x3ffc3ffc,
+    // [1716]: 402 "Ђ"
+    0x00101406, 0x0fff0fff, 0x00f000f0, 0x00f000f0, 0x3ff03ff0, 0xf0f0f0f0, 0xf0f0f0f0, 0xf0f0f0f0,
+    0xf0f0f0f0, 0xf0f0f0f0, 0x3c003c00,
+    // [1727]: 404 "Є"
+    0x000e1206, 0x3ff0ffcf, 0x03fc0f00, 0x3c00f003, 0xc00f0ffc, 0x3ff003c0, 0x0f003c00, 0xff03fc0f,
+    0x3ff0ffc0,
+    // [1736]: 409 "Љ"
+    0x00141206, 0x00ff000f, 0xf000ff00, 0x0ff000ff, 0x000ff000, 0xff000ff0, 0x3fff03ff, 0xf0f0ff0f,
+    0x0ff0f0ff, 0x0f0ff0f0, 0xf3cf0f3c, 0x3ff0f3ff, 0x0f000000,
+    // [1749]: 40A "Њ"
+    0x00141206, 0x00f0f00f, 0x0f00f0f0, 0x0f0f00f0, 0xf00f0f00, 0xf0f00f0f, 0x3ffff3ff, 0xfff0f0ff,
+    0x0f0ff0f0, 0xff0f0ff0, 0xf0ff0f0f, 0x3ff0f3ff, 0x0f000000,
+    // [1762]: 40B "Ћ"
+    0x00101206, 0x0fff0fff, 0x00f000f0, 0x00f000f0, 0x3ff03ff0, 0xf0f0f0f0, 0xf0f0f0f0, 0xf0f0f0f0,
+    0xf0f0f0f0, 0xf0f0f0f0,
+    // [1772]: 40F "Џ"
+    0x000e1406, 0xf03fc0ff, 0x03fc0ff0, 0x3fc0ff03, 0xfc0ff03f, 0xc0ff03fc, 0x0ff03fc0, 0xff03fc0f,
+    0xfffffff0, 0xfc03f000,
+    // [1782]: 411 "Б"
+    0x000e1206, 0xfffffff0, 0x03c00f00, 0x3c00f003, 0xc00f3ffc, 0xffff03fc, 0x0ff03fc0, 0xff03fc0f,
+    0x3ffcfff0,
+    // [1791]: 414 "Д"
+    0x00101606, 0x3ff03ff0, 0x3cf03cf0, 0x3cf03cf0, 0x3cf03cf0, 0x3cf03cf0, 0x3c3c3c3c, 0x3c3c3c3c,
+    0x3c3c3c3c, 0xffffffff, 0xf00ff00f, 0xf00ff00f,
+    // [1803]: 416 "Ж"
+    0x00141206, 0xf0f0ff0f, 0x0ff0f0ff, 0x0f0f3cf3, 0xc3cf3c0f, 0xff00fff0, 0x03fc003f, 0xc00fff00,
+    0xfff03cf3, 0xc3cf3cf0, 0xf0ff0f0f, 0xf0f0ff0f, 0x0f000000,
+    // [1816]: 417 "З"
+    0x000e1206, 0x3ff0ffcf, 0x03fc0ff0, 0x03c00f00, 0x3c003fc0, 0xff0f003c, 0x00f003c0, 0x0f03fc0f,
+    0x3ff0ffc0,
+    // [1825]: 418 "И"
+    0x000e1206, 0xfc3ff0ff, 0xc3ff0fff, 0x3ffcfff3, 0xffcff3ff, 0xcfff3ffc, 0xfff0ffc3, 0xff0ffc3f,
+    0xf03fc0f0,
+    // [1834]: 419 "Й"
+    0x000e1800, 0x3cf0f3c0, 0xfc03f000, 0x00000fc3, 0xff0ffc3f, 0xf0fff3ff, 0xcfff3ffc, 0xff3ffcff,
+    0xf3ffcfff, 0x0ffc3ff0, 0xffc3ff03, 0xfc0f0000,
+    // [1846]: 41B "Л"
+    0x000e1206, 0xffc3ff0f, 0x3c3cf0f3, 0xc3cf0f3c, 0x3cf0f3c3, 0xcf0f3c3c, 0xf0f0f3c3, 0xcf0f3c3c,
+    0xf03fc0f0,
+    // [1855]: 423 "У"
+    0x000e1206, 0xf03fc0ff, 0x03fc0ff0, 0x3fc0ff03, 0xfc0ff03f, 0xc0ffff3f, 0xfcf003c0, 0x0f003c00,
+    0x3ff0ffc0,
+    // [1864]: 426 "Ц"
+    0x00101406, 0x3c0f3c0f, 0x3c0f3c0f, 0x3c0f3c0f, 0x3c0f3c0f, 0x3c0f3c0f, 0x3c0f3c0f, 0x3c0f3c0f,
+    0x3c0f3c0f, 0xffffffff, 0xf000f000,
+    // [1875]: 427 "Ч"
+    0x000e1206, 0xf03fc0ff, 0x03fc0ff0, 0x3fc0ff03, 0xfc0ffff3, 0xffcf003c, 0x00f003c0, 0x0f003c00,
+    0xf003c000,
+    // [1884]: 428 "Ш"
+    0x00101206, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf,
+    0xf3cff3cf, 0xffffffff,
+    // [1894]: 429 "Щ"
+    0x00121406, 0x3cf3cf3c, 0xf3cf3cf3, 0xcf3cf3cf, 0x3cf3cf3c, 0xf3cf3cf3, 0xcf3cf3cf, 0x3cf3cf3c,
+    0xf3cf3cf3, 0xcf3cf3cf, 0xffffffff, 0xff0003c0, 0x00000000,
+    // [1907]: 42A "Ъ"
+    0x000e1206, 0x00fc03f0, 0x0f003c00, 0xf003c00f, 0x003c3ff0, 0xffcf0f3c, 0x3cf0f3c3, 0xcf0f3c3c,
+    0x3ff0ffc0,
+    // [1916]: 42B "Ы"
+    0x00101206, 0xf00ff00f, 0xf00ff00f, 0xf00ff00f, 0xf00ff00f, 0xf3fff3ff, 0xff0fff0f, 0xff0fff0f,
+    0xff0fff0f, 0xf3fff3ff,
+    // [1926]: 42C "Ь"
+    0x000c1206, 0x00f00f00, 0xf00f00f0, 0x0f00f00f, 0x3ff3fff0, 0xff0ff0ff, 0x0ff0ff0f, 0x3ff3ff00,
+    // [1934]: 42D "Э"
+    0x000e1206, 0x3ff0ffcf, 0x03fc0ff0, 0x03c00f00, 0x3c00ffc3, 0xff0f003c, 0x00f003c0, 0x0f03fc0f,
+    0x3ff0ffc0,
+    // [1943]: 42E "Ю"
+    0x00121206, 0x3fc3cff0, 0xff0f3fc3, 0xcff0f3fc, 0x3cff0f3f, 0xc3cff0ff, 0xfc3fff0f, 0x3fc3cff0,
+    0xf3fc3cff, 0x0f3fc3cf, 0x3fc3cff0, 0xf0000000,
+    // [1955]: 42F "Я"
+    0x000e1206, 0xfff3ffcf, 0x03fc0ff0, 0x3fc0ff03, 0xfc0ffff3, 0xffcf3c3c, 0xf0f0f3c3, 0xcf03fc0f,
+    0xf03fc0f0,
+    // [1964]: 431 "б"
+    0x000c1206, 0xffcffc00, 0xf00f00f0, 0x0f3ff3ff, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0x3fc3fc00,
+    // [1972]: 432 "в"
+    0x000c0e0a, 0x3ff3fff0, 0xff0ff0ff, 0x0f3ff3ff, 0xf0ff0ff0, 0xff0f3ff3, 0xff000000,
+    // [1979]: 433 "г"
+    0x000a0e0a, 0xfffff03c, 0x0f03c0f0, 0x3c0f03c0, 0xf03c0f03, 0xc0f00000,
+    // [1985]: 434 "д"
+    0x000e100a, 0x3fc0ff03, 0xfc0ff03f, 0xc0ff03cf, 0x0f3c3cf0, 0xf3cfffff, 0xfff03fc0, 0xff03fc0f,
+    // [1993]: 436 "ж"
+    0x00100e0a, 0xf3cff3cf, 0xf3cff3cf, 0x3ffc3ffc, 0x0ff00ff0, 0x3ffc3ffc, 0xf3cff3cf, 0xf3cff3cf,
+    // [2001]: 437 "з"
+    0x000c0e0a, 0x3fc3fcf0, 0xff0ff00f, 0x003f03f0, 0xf00f00f0, 0xff0f3fc3, 0xfc000000,
+    // [2008]: 438 "и"
+    0x000c0e0a, 0xf0ff0ff0, 0xff0ffcff, 0xcfffffff, 0xf3ff3ff0, 0xff0ff0ff, 0x0f000000,
+    // [2015]: 439 "й"
+    0x000c1404, 0xf0ff0f3f, 0xc3fc0000, 0x00f0ff0f, 0xf0ff0ffc, 0xffcfffff, 0xfff3ff3f, 0xf0ff0ff0,
+    0xff0f0000,
+    // [2024]: 43B "л"
+    0x000c0e0a, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0ff0, 0xff0ff0f3, 0xcf3cf0ff, 0x0f000000,
+    // [2031]: 43C "м"
+    0x00100e0a, 0xf00ff00f, 0xfc3ffc3f, 0xffffffff, 0xf3cff3cf, 0xf00ff00f, 0xf00ff00f, 0xf00ff00f,
+    // [2039]: 43D "н"
+    0x000c0e0a, 0xf0ff0ff0, 0xff0ff0ff, 0x0fffffff, 0xf0ff0ff0, 0xff0ff0ff, 0x0f000000,
+    // [2046]: 43F "п"
+    0x000c0e0a, 0xfffffff0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0ff0, 0xff0ff0ff, 0x0f000000,
+    // [2053]: 442 "т"
+    0x000c0e0a, 0xffffff0f, 0x00f00f00, 0xf00f00f0, 0x0f00f00f, 0x00f00f00, 0xf0000000,
+    // [2060]: 444 "ф"
+    0x00101606, 0x03c003c0, 0x03c003c0, 0x3ffc3ffc, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf,
+    0xf3cff3cf, 0x3ffc3ffc, 0x03c003c0, 0x03c003c0,
+    // [2072]: 446 "ц"
+    0x000e100a, 0x3c3cf0f3, 0xc3cf0f3c, 0x3cf0f3c3, 0xcf0f3c3c, 0xf0f3c3cf, 0x0fffffff, 0xff003c00,
+    // [2080]: 447 "ч"
+    0x000c0e0a, 0xf0ff0ff0, 0xff0ff0ff, 0x0fffcffc, 0xf00f00f0, 0x0f00f00f, 0x00000000,
+    // [2087]: 448 "ш"
+    0x00100e0a, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf, 0xf3cff3cf, 0xffffffff,
+    // [2095]: 449 "щ"
+    0x0012100a, 0x3cf3cf3c, 0xf3cf3cf3, 0xcf3cf3cf, 0x3cf3cf3c, 0xf3cf3cf3, 0xcf3cf3cf, 0x3cf3cfff,
+    0xffffffff, 0x0003c000,
+    // [2105]: 44A "ъ"
+    0x000c0e0a, 0x03f03f03, 0xc03c03c0, 0x3c3fc3fc, 0xf3cf3cf3, 0xcf3c3fc3, 0xfc000000,
+    // [2112]: 44B "ы"
+    0x00100e0a, 0xf00ff00f, 0xf00ff00f, 0xf00ff00f, 0xf0fff0ff, 0xf3cff3cf, 0xf3cff3cf, 0xf0fff0ff,
+    // [2120]: 44C "ь"
+    0x000a0e0a, 0x03c0f03c, 0x0f03c0f3, 0xfcfff3fc, 0xff3fcf3f, 0xcff00000,
+    // [2126]: 44D "э"
+    0x000c0e0a, 0x3fc3fcf0, 0xff0ff00f, 0x00ff0ff0, 0xf00f00f0, 0xff0f3fc3, 0xfc000000,
+    // [2133]: 44E "ю"
+    0x00100e0a, 0x3fcf3fcf, 0xf0fff0ff, 0xf0fff0ff, 0xf0fff0ff, 0xf0fff0ff, 0xf0fff0ff, 0x3fcf3fcf,
+    // [2141]: 44F "я"
+    0x000c0e0a, 0xffcffcf0, 0xff0ff0ff, 0x0fffcffc, 0xff0ff0f3, 0xcf3cf0ff, 0x0f000000,
+    // [2148]: 452 "ђ"
+    0x000e1806, 0x00f003c0, 0xffc3ff00, 0xf003c3ff, 0x0ffcf3f3, 0xcfcf0f3c, 0x3cf0f3c3, 0xcf0f3c3c,
+    0xf0f3c3cf, 0x003c00f0, 0x03c003f0, 0x0fc00000,
+    // [2160]: 454 "є"
+    0x000c0e0a, 0x3fc3fcf0, 0xff0f00f0, 0x0f3ff3ff, 0x00f00ff0, 0xff0f3fc3, 0xfc000000,
+    // [2167]: 459 "љ"
+    0x00120e0a, 0x03fc00ff, 0x003fc00f, 0xf003fc00, 0xff03ffc0, 0xfff0f3fc, 0x3cff0f3c, 0xf3cf3c3f,
+    0xc3cff0f0,
+    // [2176]: 45A "њ"
+    0x00100e0a, 0x03cf03cf, 0x03cf03cf, 0x03cf03cf, 0x3fff3fff, 0xf3cff3cf, 0xf3cff3cf, 0x3fcf3fcf,
+    // [2184]: 45B "ћ"
+    0x000e1206, 0x00f003c0, 0xffc3ff00, 0xf003c3ff, 0x0ffcf3f3, 0xcfcf0f3c, 0x3cf0f3c3, 0xcf0f3c3c,
+    0xf0f3c3c0,
+    // [2193]: 45F "џ"
+    0x000c100a, 0xf0ff0ff0, 0xff0ff0ff, 0x0ff0ff0f, 0xf0ff0ff0, 0xff0fffff, 0xff0f00f0,
+    // [2200]: 490 "Ґ"
+    0x000e1602, 0xf003c00f, 0x003c00ff, 0xfffff003, 0xc00f003c, 0x00f003c0, 0x0f003c00, 0xf003c00f,
+    0x003c00f0, 0x03c00f00, 0x3c00f000,
+    // [2211]: 491 "ґ"
+    0x000a1008, 0xf03c0fff, 0xff03c0f0, 0x3c0f03c0, 0xf03c0f03, 0xc0f03c0f,
+    // [2217]: 2018 "‘"
     0x00040806, 0xcc33ffff,
-    // [1445]: 2019 "’"
+    // [2219]: 2019 "’"
     0x00040806, 0xffffcc33,
-    // [1447]: 201A "‚"
+    // [2221]: 201A "‚"
     0x00040814, 0xffffcc33,
-    // [1449]: 201B "‛"
+    // [2223]: 201B "‛"
     0x00040806, 0xffff33cc,
-    // [1451]: 201C "“"
+    // [2225]: 201C "“"
     0x000a0806, 0xc330c30c, 0xc3f3fcff, 0x3fcf0000,
-    // [1455]: 201D "”"
+    // [2229]: 201D "”"
     0x000a0806, 0xf3fcff3f, 0xcfc330c3, 0x0cc30000,
-    // [1459]: 201E "„"
+    // [2233]: 201E "„"
     0x000a0814, 0xf3fcff3f, 0xcfc330c3, 0x0cc30000,
-    // [1463]: 201F "‟"
+    // [2237]: 201F "‟"
     0x000a0806, 0xf3fcff3f, 0xcf30cc3c, 0x330c0000,
-    // [1467]: 2020 "†"
+    // [2241]: 2020 "†"
     0x00060a06, 0x30cfff30, 0xc30c30c0,
-    // [1470]: 2021 "‡"
+    // [2244]: 2021 "‡"
     0x00060c06, 0x30cfff30, 0xc30cfff3, 0x0c000000,
-    // [1474]: 2022 "•"
+    // [2248]: 2022 "•"
     0x000a0a0a, 0x3f0fcfff, 0xffffffff, 0xffff3f0f, 0xc0000000,
-    // [1479]: 20AC "€"
+    // [2253]: 20AC "€"
     0x00101008, 0x3fc03fc0, 0xc030c030, 0x000c000c, 0x3fff3fff, 0x000c000c, 0x0fff0fff, 0xc030c030,
     0x3fc03fc0,
-    // [1488]: FFFD "�"
+    // [2262]: FFFD "�"
     0x00121404, 0x00c00030, 0x003f000f, 0xc00f3c03, 0xcf03ccf0, 0xf33cfcff, 0xff3ffff3, 0xfffcff3f,
     0xff0fffc0, 0xf3c03cf0, 0x03f000fc, 0x000c0003, 0x00000000,
 ];
@@ -1224,20 +1644,8 @@ mod tests {
         for i in 0..HASH_BASIC_LATIN.len()-1 {
             assert!(HASH_BASIC_LATIN[i] < HASH_BASIC_LATIN[i+1]);
         }
-        for i in 0..HASH_LATIN_1_SUPPLEMENT.len()-1 {
-            assert!(HASH_LATIN_1_SUPPLEMENT[i] < HASH_LATIN_1_SUPPLEMENT[i+1]);
-        }
-        for i in 0..HASH_LATIN_EXTENDED_A.len()-1 {
-            assert!(HASH_LATIN_EXTENDED_A[i] < HASH_LATIN_EXTENDED_A[i+1]);
-        }
-        for i in 0..HASH_GENERAL_PUNCTUATION.len()-1 {
-            assert!(HASH_GENERAL_PUNCTUATION[i] < HASH_GENERAL_PUNCTUATION[i+1]);
-        }
-        for i in 0..HASH_CURRENCY_SYMBOLS.len()-1 {
-            assert!(HASH_CURRENCY_SYMBOLS[i] < HASH_CURRENCY_SYMBOLS[i+1]);
-        }
-        for i in 0..HASH_SPECIALS.len()-1 {
-            assert!(HASH_SPECIALS[i] < HASH_SPECIALS[i+1]);
+        for i in 0..HASH_CYRILLIC.len()-1 {
+            assert!(HASH_CYRILLIC[i] < HASH_CYRILLIC[i+1]);
         }
     }
 }
@@ -0,0 +1,382 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Box Drawing and Block Elements
+//!
+//! Procedural glyphs for box drawing (U+2500..U+257F) and block elements
+//! (U+2580..U+259F). Rather than storing bitmaps with padding around them,
+//! patterns get generated at blit time to fill a whole cell, sized to fit the
+//! line height. Lines and blocks in neighboring cells join up with no gaps.
+//!
+//! Light lines are 2px wide, heavy lines are 4px, and double lines are two 2px
+//! lines with a 2px gap, matching the 2x pixels of the latin fonts.
+#![forbid(unsafe_code)]
+
+use core::ops::Range;
+
+use super::{pack_pixels, GlyphData, NoGlyphErr};
+
+/// First char of the box drawing block
+const BASE: u32 = 0x2500;
+
+/// Number of chars in the box drawing and block elements blocks
+const COUNT: u32 = 0xA0;
+
+/// Tallest cell, which is the most that fits in the height byte of the header
+/// word
+const MAX_HEIGHT: usize = u8::MAX as usize;
+
+/// Height of a cell that reaches past a line of height line_height to the top
+/// of the next line, clamped to MAX_HEIGHT
+fn cell_height(line_height: usize) -> u8 {
+    (line_height + 1).min(MAX_HEIGHT) as u8
+}
+
+/// Box drawing or block element char with the size of its cell
+#[derive(Copy, Clone, Debug)]
+pub struct Cell {
+    /// Offset of the char from U+2500
+    index: u8,
+    w: u8,
+    h: u8,
+    /// Parity of the cell's screen position, so shade patterns line up:
+    /// bit 0 for x, bit 1 for y
+    phase: u8,
+}
+
+/// Return Okay(cell) for a box drawing or block element char at the start of
+/// cluster. The cell is half as wide as line_height, and 1px taller, so that
+/// it also covers the gap between lines.
+///
+/// Returns: Result<(cell, bytes of cluster used by match)>
+pub fn get_blit_pattern_offset(
+    cluster: &str,
+    line_height: usize,
+) -> Result<(GlyphData, usize), NoGlyphErr> {
+    let index = match cluster.chars().next() {
+        Some(c) if (BASE..BASE + COUNT).contains(&(c as u32)) => (c as u32 - BASE) as u8,
+        _ => return Err(NoGlyphErr),
+    };
+    let cell = Cell {
+        index,
+        w: (line_height / 2) as u8,
+        h: cell_height(line_height),
+        phase: 0,
+    };
+    // Chars in U+2500..U+259F are 3 bytes in UTF-8
+    Ok((GlyphData::BoxDrawing(cell), 3))
+}
+
+impl Cell {
+    /// Copy of the cell placed at (x, y) on a line of height line_height,
+    /// stretched to reach the top of the next line if the line is taller
+    /// than the cell
+    pub fn fit(self, x: usize, y: usize, line_height: usize) -> Self {
+        Cell {
+            h: self.h.max(cell_height(line_height)),
+            phase: ((x & 1) | ((y & 1) << 1)) as u8,
+            ..self
+        }
+    }
+
+    /// Packed header word for the cell's pattern, in the same format as the
+    /// DATA arrays of the generated fonts
+    pub fn header(self) -> u32 {
+        ((self.w as u32) << 16) | ((self.h as u32) << 8)
+    }
+
+    /// Generate pixel word n of the cell's pattern, packed the same way as
+    /// the DATA arrays of the generated fonts
+    pub fn nth_word(self, n: usize) -> u32 {
+        pack_pixels(n, self.w as usize, self.h as usize, |x, y| self.pixel(x, y))
+    }
+
+    /// Return true if the pixel at (x, y) of the cell is set
+    fn pixel(self, x: usize, y: usize) -> bool {
+        let (w, h) = (self.w as usize, self.h as usize);
+        match BASE + self.index as u32 {
+            0x2580..=0x259F => block(self.index - 0x80, x, y, w, h, self.phase),
+            0x256D..=0x2570 => arc(self.index - 0x6D, x, y, w, h),
+            0x2571..=0x2573 => diagonal(self.index - 0x71, x, y, w, h),
+            c => {
+                let [up, right, down, left] = ARMS[self.index as usize];
+                let (horizontal, vertical) = ((x, w), (y, h));
+                let on_line = on_arm((left, right), false, horizontal, vertical, (up, down))
+                    || on_arm((right, left), true, horizontal, vertical, (up, down))
+                    || on_arm((up, down), false, vertical, horizontal, (left, right))
+                    || on_arm((down, up), true, vertical, horizontal, (left, right));
+                match dashes(c) {
+                    Some(n) if left != O => on_line && on_dash(x, w, n),
+                    Some(n) => on_line && on_dash(y, h, n),
+                    None => on_line,
+                }
+            }
+        }
+    }
+}
+
+/// Line weights for the arms of box drawing chars
+const O: u8 = 0;
+const L: u8 = 1;
+const H: u8 = 2;
+const D: u8 = 3;
+
+/// Range of pixels across a line of weight wt, centered in a cell of size n.
+/// For double lines, this spans the outer edges of both lines.
+fn band(wt: u8, n: usize) -> Range<usize> {
+    let thickness = match wt {
+        H => 4,
+        D => 6,
+        _ => 2,
+    };
+    let start = n.saturating_sub(thickness) / 2;
+    start..start + thickness
+}
+
+/// Return true if a pixel is on an arm that runs from the middle of the cell
+/// to its start (to_end: false) or its end (to_end: true). The arm's weight
+/// comes first in weights, followed by the weight of the arm opposite it.
+/// Coordinates are (position, cell size) along and across the arm, and perp
+/// holds the weights of the perpendicular arms on the low and high sides of
+/// the across axis.
+fn on_arm(
+    weights: (u8, u8),
+    to_end: bool,
+    along: (usize, usize),
+    across: (usize, usize),
+    perp: (u8, u8),
+) -> bool {
+    let (wt, opposite) = weights;
+    let lines = band(wt, across.1);
+    if wt == O || !lines.contains(&across.0) {
+        return false;
+    }
+    // Arms reach across the perpendicular lines, so corners get filled in
+    let widest = perp.0.max(perp.1);
+    let reach = band(if widest == O { wt } else { widest }, along.1);
+    let (mut low, mut high) = (reach.start, reach.end);
+    // Inner lines stop at the near line of a perpendicular double line
+    let inner = if wt == D {
+        let first_line = across.0 < lines.start + 2;
+        if !first_line && across.0 < lines.end - 2 {
+            return false; // Gap between the two lines of a double line
+        }
+        // Where double lines join, the inner line of each pair stops at the
+        // near line of the other pair
+        if first_line {
+            perp.0 == D
+        } else {
+            perp.1 == D
+        }
+    } else {
+        // Single lines ending at a double line don't cross into its gap
+        widest == D && opposite == O
+    };
+    if inner {
+        low = reach.end - 2;
+        high = reach.start + 2;
+    }
+    if to_end {
+        along.0 >= low
+    } else {
+        along.0 < high
+    }
+}
+
+/// Number of dashes per cell for dashed line chars
+fn dashes(c: u32) -> Option<usize> {
+    match c {
+        0x2504..=0x2507 => Some(3),
+        0x2508..=0x250B => Some(4),
+        0x254C..=0x254F => Some(2),
+        _ => None,
+    }
+}
+
+/// Return true if pos falls on one of n evenly spaced dashes along a cell of size
+fn on_dash(pos: usize, size: usize, n: usize) -> bool {
+    (pos * n) % size * 2 < size
+}
+
+/// Return true if (x, y) is on light arc i of ╭╮╯╰. Coordinates are doubled
+/// so that the arc can be centered on the middle of a pixel.
+fn arc(i: u8, x: usize, y: usize, w: usize, h: usize) -> bool {
+    let (vx, hy) = (band(L, w).start, band(L, h).start);
+    // Center lines of the vertical and horizontal arms
+    let (cx, cy) = (2 * vx as i32 + 2, 2 * hy as i32 + 2);
+    let r = cx.min(2 * w as i32 - cx);
+    let (right, down) = match i {
+        0 => (true, true),
+        1 => (false, true),
+        2 => (false, false),
+        _ => (true, false),
+    };
+    // Center of the arc's circle
+    let ox = if right { cx + r } else { cx - r };
+    let oy = if down { cy + r } else { cy - r };
+    let (px, py) = (2 * x as i32 + 1, 2 * y as i32 + 1);
+    let in_quadrant = (px <= ox) == right && (py <= oy) == down;
+    let d2 = (px - ox).pow(2) + (py - oy).pow(2);
+    if in_quadrant {
+        return (r - 2).pow(2) <= d2 && d2 <= (r + 2).pow(2);
+    }
+    // Straight parts of the arms, past the ends of the arc
+    let on_h = band(L, h).contains(&y) && if right { px > ox } else { px < ox };
+    let on_v = band(L, w).contains(&x) && if down { py > oy } else { py < oy };
+    on_h || on_v
+}
+
+/// Return true if (x, y) is on diagonal i of ╱╲╳, from corner to corner
+fn diagonal(i: u8, x: usize, y: usize, w: usize, h: usize) -> bool {
+    let (w, h) = (w as i64, h as i64);
+    let (px, py) = (2 * x as i64 + 1, 2 * y as i64 + 1);
+    // Twice the distance from the line times its length, for light lines
+    let limit = 4 * (w * w + h * h);
+    let rising = (h * px + w * py - 2 * w * h).pow(2) <= limit;
+    let falling = (h * px - w * py).pow(2) <= limit;
+    match i {
+        0 => rising,
+        1 => falling,
+        _ => rising || falling,
+    }
+}
+
+/// Return true if (x, y) is set in block element i of U+2580..U+259F. Shades
+/// use phase to line up their patterns between cells.
+fn block(i: u8, x: usize, y: usize, w: usize, h: usize, phase: u8) -> bool {
+    let lower = |eighths: usize| y >= h - h * eighths / 8;
+    let left = |eighths: usize| x < w * eighths / 8;
+    let (top, west) = (!lower(4), left(4));
+    let (sx, sy) = (x + (phase & 1) as usize, y + (phase >> 1) as usize);
+    match i {
+        0x00 => top,
+        0x01..=0x08 => lower(i as usize),
+        0x09..=0x0F => left(16 - i as usize),
+        0x10 => !west,
+        0x11 => sx % 2 == 0 && sy % 2 == 0,
+        0x12 => (sx + sy) % 2 == 0,
+        0x13 => sx % 2 == 0 || sy % 2 == 0,
+        0x14 => y < h / 8,
+        0x15 => !left(7),
+        0x16 => !top && west,
+        0x17 => !top && !west,
+        0x18 => top && west,
+        0x19 => !top || west,
+        0x1A => top == west,
+        0x1B => top || west,
+        0x1C => top || !west,
+        0x1D => top && !west,
+        0x1E => top != west,
+        _ => !(top && west),
+    }
+}
+
+/// Weights of the [up, right, down, left] arms of U+2500..U+257F. Dashed
+/// lines, arcs, and diagonals have extra handling in Cell::pixel().
+#[rustfmt::skip]
+static ARMS: [[u8; 4]; 0x80] = [
+    [O, L, O, L], [O, H, O, H], [L, O, L, O], [H, O, H, O], // ─━│┃
+    [O, L, O, L], [O, H, O, H], [L, O, L, O], [H, O, H, O], // ┄┅┆┇
+    [O, L, O, L], [O, H, O, H], [L, O, L, O], [H, O, H, O], // ┈┉┊┋
+    [O, L, L, O], [O, H, L, O], [O, L, H, O], [O, H, H, O], // ┌┍┎┏
+    [O, O, L, L], [O, O, L, H], [O, O, H, L], [O, O, H, H], // ┐┑┒┓
+    [L, L, O, O], [L, H, O, O], [H, L, O, O], [H, H, O, O], // └┕┖┗
+    [L, O, O, L], [L, O, O, H], [H, O, O, L], [H, O, O, H], // ┘┙┚┛
+    [L, L, L, O], [L, H, L, O], [H, L, L, O], [L, L, H, O], // ├┝┞┟
+    [H, L, H, O], [H, H, L, O], [L, H, H, O], [H, H, H, O], // ┠┡┢┣
+    [L, O, L, L], [L, O, L, H], [H, O, L, L], [L, O, H, L], // ┤┥┦┧
+    [H, O, H, L], [H, O, L, H], [L, O, H, H], [H, O, H, H], // ┨┩┪┫
+    [O, L, L, L], [O, L, L, H], [O, H, L, L], [O, H, L, H], // ┬┭┮┯
+    [O, L, H, L], [O, L, H, H], [O, H, H, L], [O, H, H, H], // ┰┱┲┳
+    [L, L, O, L], [L, L, O, H], [L, H, O, L], [L, H, O, H], // ┴┵┶┷
+    [H, L, O, L], [H, L, O, H], [H, H, O, L], [H, H, O, H], // ┸┹┺┻
+    [L, L, L, L], [L, L, L, H], [L, H, L, L], [L, H, L, H], // ┼┽┾┿
+    [H, L, L, L], [L, L, H, L], [H, L, H, L], [H, L, L, H], // ╀╁╂╃
+    [H, H, L, L], [L, L, H, H], [L, H, H, L], [H, H, L, H], // ╄╅╆╇
+    [L, H, H, H], [H, L, H, H], [H, H, H, L], [H, H, H, H], // ╈╉╊╋
+    [O, L, O, L], [O, H, O, H], [L, O, L, O], [H, O, H, O], // ╌╍╎╏
+    [O, D, O, D], [D, O, D, O], [O, D, L, O], [O, L, D, O], // ═║╒╓
+    [O, D, D, O], [O, O, L, D], [O, O, D, L], [O, O, D, D], // ╔╕╖╗
+    [L, D, O, O], [D, L, O, O], [D, D, O, O], [L, O, O, D], // ╘╙╚╛
+    [D, O, O, L], [D, O, O, D], [L, D, L, O], [D, L, D, O], // ╜╝╞╟
+    [D, D, D, O], [L, O, L, D], [D, O, D, L], [D, O, D, D], // ╠╡╢╣
+    [O, D, L, D], [O, L, D, L], [O, D, D, D], [L, D, O, D], // ╤╥╦╧
+    [D, L, O, L], [D, D, O, D], [L, D, L, D], [D, L, D, L], // ╨╩╪╫
+    [D, D, D, D], [O, L, L, O], [O, O, L, L], [L, O, O, L], // ╬╭╮╯
+    [L, L, O, O], [O, O, O, O], [O, O, O, O], [O, O, O, O], // ╰╱╲╳
+    [O, O, O, L], [L, O, O, O], [O, L, O, O], [O, O, L, O], // ╴╵╶╷
+    [O, O, O, H], [H, O, O, O], [O, H, O, O], [O, O, H, O], // ╸╹╺╻
+    [O, H, O, L], [L, O, H, O], [O, L, O, H], [H, O, L, O], // ╼╽╾╿
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Make a cell for char c, sized for a line of height 30
+    fn cell(c: char) -> Cell {
+        match get_blit_pattern_offset(c.encode_utf8(&mut [0; 4]), 30) {
+            Ok((GlyphData::BoxDrawing(cell), 3)) => cell,
+            _ => panic!("no cell for {:?}", c),
+        }
+    }
+
+    /// Return row y of the cell for char c, with # for set pixels
+    fn row(c: char, y: usize) -> [u8; 15] {
+        let mut s = [b'.'; 15];
+        for (x, px) in s.iter_mut().enumerate() {
+            if cell(c).pixel(x, y) {
+                *px = b'#';
+            }
+        }
+        s
+    }
+
+    #[test]
+    fn test_cell_size() {
+        let c = cell('─');
+        assert_eq!((c.w, c.h), (15, 31));
+        let c = c.fit(1, 0, 32);
+        assert_eq!((c.w, c.h, c.phase), (15, 33, 1));
+        assert!(get_blit_pattern_offset("a", 30).is_err());
+        assert!(get_blit_pattern_offset("\u{25A0}", 30).is_err());
+    }
+
+    #[test]
+    fn test_lines_reach_cell_edges() {
+        // Horizontal lines span the full width; vertical lines the full height
+        assert_eq!(&row('─', 14), b"###############");
+        assert_eq!(&row('│', 0), b"......##.......");
+        assert_eq!(&row('│', 30), b"......##.......");
+        assert_eq!(&row('┃', 30), b".....####......");
+        assert_eq!(&row('║', 30), b"....##..##.....");
+        assert_eq!(&row('┌', 14), b"......#########");
+        assert_eq!(&row('┌', 0), b"...............");
+        assert_eq!(&row('╴', 16), b"...............");
+        assert_eq!(&row('╴', 14), b"########.......");
+    }
+
+    #[test]
+    fn test_double_line_joins() {
+        // ╔: outer lines meet at the outer corner, inner lines at the inner one
+        assert_eq!(&row('╔', 12), b"....###########");
+        assert_eq!(&row('╔', 14), b"....##.........");
+        assert_eq!(&row('╔', 16), b"....##..#######");
+        assert_eq!(&row('╔', 18), b"....##..##.....");
+        // ╬: four corners with nothing crossing the middle
+        assert_eq!(&row('╬', 12), b"######..#######");
+        assert_eq!(&row('╬', 14), b"...............");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let c = cell('█');
+        assert!((0..15).all(|x| (0..31).all(|y| c.pixel(x, y))));
+        assert_eq!(&row('▌', 0), b"#######........");
+        assert_eq!(&row('▐', 0), b".......########");
+        assert!(!cell('▄').pixel(0, 15) && cell('▄').pixel(0, 16));
+        assert!(cell('▀').pixel(0, 15) && !cell('▀').pixel(0, 16));
+        assert_eq!(&row('▒', 0), b"#.#.#.#.#.#.#.#");
+        assert_eq!(&row('▒', 1), b".#.#.#.#.#.#.#.");
+    }
+}
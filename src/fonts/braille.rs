@@ -0,0 +1,138 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Braille Patterns
+//!
+//! Procedural glyphs for Unicode braille patterns (U+2800..U+28FF). Each
+//! pattern is a 2x4 grid of dots, where bit n of (char - U+2800) raises dot
+//! n+1 in the standard braille numbering:
+//! ```text
+//!   1 4
+//!   2 5
+//!   3 6
+//!   7 8
+//! ```
+//! Dots are square and get scaled to fit the line height. The grid spacing is
+//! the same within a cell and between neighboring cells, so strings of braille
+//! patterns work as sparklines and dot plots.
+#![forbid(unsafe_code)]
+
+use super::{pack_pixels, GlyphData, NoGlyphErr};
+
+/// First char of the braille patterns block
+const BASE: u32 = 0x2800;
+
+/// Number of chars in the braille patterns block
+const COUNT: u32 = 0x100;
+
+/// Braille pattern dots with the spacing of their grid
+#[derive(Copy, Clone, Debug)]
+pub struct Dots {
+    /// Bit n is set for raised dot n+1
+    dots: u8,
+    /// Distance in pixels between the centers of neighboring dots
+    pitch: u8,
+}
+
+/// Return Okay(dots) for a braille pattern char at the start of cluster. Four
+/// rows of dots span the line height, plus the 1px gap between lines.
+///
+/// Returns: Result<(dots, bytes of cluster used by match)>
+pub fn get_blit_pattern_offset(
+    cluster: &str,
+    line_height: usize,
+) -> Result<(GlyphData, usize), NoGlyphErr> {
+    let dots = match cluster.chars().next() {
+        Some(c) if (BASE..BASE + COUNT).contains(&(c as u32)) => (c as u32 - BASE) as u8,
+        _ => return Err(NoGlyphErr),
+    };
+    let pitch = ((line_height + 1) / 4) as u8;
+    // Braille patterns are 3 bytes in UTF-8
+    Ok((GlyphData::Braille(Dots { dots, pitch }), 3))
+}
+
+impl Dots {
+    /// Packed header word for the pattern, in the same format as the DATA
+    /// arrays of the generated fonts
+    pub fn header(self) -> u32 {
+        let pitch = self.pitch as u32;
+        ((2 * pitch) << 16) | ((4 * pitch) << 8)
+    }
+
+    /// Generate pixel word n of the pattern, packed the same way as the DATA
+    /// arrays of the generated fonts
+    pub fn nth_word(self, n: usize) -> u32 {
+        let pitch = self.pitch as usize;
+        pack_pixels(n, 2 * pitch, 4 * pitch, |x, y| self.pixel(x, y))
+    }
+
+    /// Return true if the pixel at (x, y) is part of a raised dot
+    fn pixel(self, x: usize, y: usize) -> bool {
+        let pitch = self.pitch as usize;
+        // Dots are half the pitch wide, centered in their grid square
+        let size = (pitch / 2).max(1);
+        let margin = (pitch - size) / 2;
+        let (col, row) = (x / pitch, y / pitch);
+        let (dx, dy) = (x % pitch, y % pitch);
+        if dx < margin || dx >= margin + size || dy < margin || dy >= margin + size {
+            return false;
+        }
+        let bit = match (col, row) {
+            (0, 3) => 6,
+            (1, 3) => 7,
+            (col, row) => col * 3 + row,
+        };
+        self.dots & (1 << bit) != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Make the dots for char c, sized for a line of height 30
+    fn dots(c: char) -> Dots {
+        match get_blit_pattern_offset(c.encode_utf8(&mut [0; 4]), 30) {
+            Ok((GlyphData::Braille(dots), 3)) => dots,
+            _ => panic!("no braille pattern for {:?}", c),
+        }
+    }
+
+    /// Return which dots of c are raised, checking the center of each dot
+    fn raised(c: char) -> [[bool; 2]; 4] {
+        let d = dots(c);
+        let pitch = d.pitch as usize;
+        let mut grid = [[false; 2]; 4];
+        for (row, cols) in grid.iter_mut().enumerate() {
+            for (col, dot) in cols.iter_mut().enumerate() {
+                *dot = d.pixel(col * pitch + pitch / 2, row * pitch + pitch / 2);
+            }
+        }
+        grid
+    }
+
+    #[test]
+    fn test_dot_size() {
+        let d = dots('⣿');
+        assert_eq!(d.pitch, 7);
+        let header = GlyphData::Braille(d).header();
+        assert_eq!((header.w, header.h, header.y_offset), (14, 28, 0));
+        // Dots are 3x3 with a 2px margin
+        assert!((0..14).all(|x| !d.pixel(x, 0)));
+        assert!((0..14).all(|x| d.pixel(x, 2) == [2, 3, 4, 9, 10, 11].contains(&x)));
+        assert!(get_blit_pattern_offset("a", 30).is_err());
+        assert!(get_blit_pattern_offset("\u{2900}", 30).is_err());
+    }
+
+    #[test]
+    fn test_dot_numbering() {
+        let (o, x) = (false, true);
+        assert_eq!(raised('⠀'), [[o, o], [o, o], [o, o], [o, o]]);
+        assert_eq!(raised('⠁'), [[x, o], [o, o], [o, o], [o, o]]);
+        assert_eq!(raised('⠇'), [[x, o], [x, o], [x, o], [o, o]]);
+        assert_eq!(raised('⠸'), [[o, x], [o, x], [o, x], [o, o]]);
+        assert_eq!(raised('⡀'), [[o, o], [o, o], [o, o], [x, o]]);
+        assert_eq!(raised('⢀'), [[o, o], [o, o], [o, o], [o, x]]);
+        assert_eq!(raised('⣿'), [[x, x], [x, x], [x, x], [x, x]]);
+    }
+}
@@ -0,0 +1,372 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Compressed Glyph Patterns
+//!
+//! Optional encoding for glyph patterns with blank rows, repeated rows, and
+//! long runs of set or clear pixels, like the strokes of CJK ideograms. Codegen
+//! stores a glyph compressed when that takes fewer words than packed pixels,
+//! and then puts the number of compressed words, n, in the top byte of the
+//! glyph's header word. The n words following the header are:
+//! ```text
+//!   [1]: blank rows: bit y is set if row y has no set pixels
+//!   [2]: repeated rows: bit y is set if row y is the same as row y-1
+//!   [3..=n]: 4-bit run lengths for the pixels of the remaining rows
+//! ```
+//! Pixels of the remaining rows are in the same order as packed pixels. Runs
+//! alternate between clear and set pixels, starting with clear, and run length
+//! nibbles start at the MSB of word 3. A run of r pixels is coded as r/15
+//! nibbles of 15 followed by a nibble of r%15.
+//!
+//! Rows get decoded in order, top to bottom, with no scratch buffer. Compressed
+//! glyphs can be at most 32px high, so the row masks fit in a word.
+#![forbid(unsafe_code)]
+
+use super::GlyphHeader;
+
+/// Maximum height of a compressed glyph pattern
+pub const MAX_ROWS: usize = 32;
+
+/// Maximum width of a glyph pattern, which is the most that fits in the width
+/// byte of the header word
+pub const MAX_WIDTH: usize = 255;
+
+/// Words needed for a row of the widest glyph pattern
+pub const ROW_WORDS: usize = MAX_WIDTH.div_ceil(32);
+
+/// Pixels of one glyph row, 32 per word, with bit 0 of word 0 as the leftmost
+/// pixel. Words past the width of the glyph are 0.
+pub type Row = [u32; ROW_WORDS];
+
+/// Number of pattern words following the header of a glyph, where
+/// compressed_len is the top byte of the header word (0 for packed pixels)
+pub fn pattern_len(gh: &GlyphHeader, compressed_len: usize) -> usize {
+    match compressed_len {
+        0 => (gh.w * gh.h).div_ceil(32),
+        n => n,
+    }
+}
+
+/// Iterator over the rows of a packed or compressed glyph pattern, from the
+/// top down. Rows can span several words, for glyphs wider than 32px.
+pub struct Rows<W: Fn(usize) -> u32> {
+    /// Pattern word n following the header, where n counts up from 1
+    word: W,
+    w: usize,
+    h: usize,
+    /// Next row to unpack
+    y: usize,
+    /// Words of compressed pattern data, or 0 for packed pixels
+    compressed_len: usize,
+    blank: u32,
+    repeat: u32,
+    /// Next run length nibble to read, counting from the MSB of word 3
+    nibble: usize,
+    /// Pixels left in the current run, and whether they are set
+    run_left: usize,
+    run_set: bool,
+    prev: Row,
+}
+
+impl<W: Fn(usize) -> u32> Rows<W> {
+    /// Unpack the rows of a glyph with header gh, reading pattern words from
+    /// word(1), word(2), etc.
+    pub fn new(gh: &GlyphHeader, compressed_len: usize, word: W) -> Self {
+        let (blank, repeat) = match compressed_len {
+            0 => (0, 0),
+            _ => (word(1), word(2)),
+        };
+        Rows {
+            word,
+            w: gh.w,
+            h: gh.h,
+            y: 0,
+            compressed_len,
+            blank,
+            repeat,
+            nibble: 0,
+            run_left: 0,
+            // The first run is clear pixels, so this gets flipped before use
+            run_set: true,
+            prev: [0; ROW_WORDS],
+        }
+    }
+
+    /// Unpack row y of packed pixels. Each row starts from its rightmost
+    /// pixel, so word k of the row comes from the end of the row's bits.
+    fn packed_row(&self, y: usize) -> Row {
+        let mut row = [0; ROW_WORDS];
+        for (k, word) in row.iter_mut().enumerate().take(self.w.div_ceil(32)) {
+            let w = (self.w - 32 * k).min(32);
+            *word = self.packed_bits((y + 1) * self.w - 32 * k - w, w);
+        }
+        row
+    }
+
+    /// Unpack w <= 32 bits of packed pixels, starting px_offset bits after the
+    /// MSB of word 1.
+    /// px_in_low_word can include some or all of the requested pixels. It may
+    /// also include pixels for the next row, or, in the case of the last row,
+    /// it may include padding bits.
+    fn packed_bits(&self, px_offset: usize, w: usize) -> u32 {
+        let low_word = 1 + (px_offset >> 5);
+        let px_in_low_word = 32 - (px_offset & 0x1f);
+        let mut pattern = (self.word)(low_word);
+        // Mask and align pixels from low word of glyph data array
+        pattern <<= 32 - px_in_low_word;
+        pattern >>= 32 - w;
+        if w > px_in_low_word {
+            // When pixels for this row span two words in the glyph data array,
+            // get pixels from the high word too
+            let px_in_high_word = w - px_in_low_word;
+            let mut pattern_h = (self.word)(low_word + 1);
+            pattern_h >>= 32 - px_in_high_word;
+            pattern |= pattern_h;
+        }
+        pattern
+    }
+
+    /// Decode the next row that is stored as runs. Runs start from the
+    /// rightmost pixel of the row.
+    fn decoded_row(&mut self) -> Row {
+        let mut row = [0; ROW_WORDS];
+        let mut px = self.w;
+        while px > 0 {
+            if self.run_left == 0 {
+                self.run_left = self.next_run();
+                self.run_set = !self.run_set;
+                continue;
+            }
+            // Fill in the run one word at a time
+            let n = px.min(self.run_left).min(((px - 1) & 0x1f) + 1);
+            if self.run_set {
+                let run = u32::MAX >> (32 - n);
+                row[(px - 1) >> 5] |= run << ((px - n) & 0x1f);
+            }
+            px -= n;
+            self.run_left -= n;
+        }
+        row
+    }
+
+    /// Read the length of the next run. For a pattern that ends too soon, the
+    /// run goes on past the end of the glyph.
+    fn next_run(&mut self) -> usize {
+        let mut run = 0;
+        loop {
+            let n = 3 + self.nibble / 8;
+            if n > self.compressed_len {
+                return usize::MAX;
+            }
+            let nibble = ((self.word)(n) >> (28 - 4 * (self.nibble % 8))) & 0xf;
+            self.nibble += 1;
+            run += nibble as usize;
+            if nibble < 15 {
+                return run;
+            }
+        }
+    }
+}
+
+impl<W: Fn(usize) -> u32> Iterator for Rows<W> {
+    type Item = Row;
+
+    fn next(&mut self) -> Option<Row> {
+        if self.y >= self.h {
+            return None;
+        }
+        let y = self.y;
+        self.y += 1;
+        let is_set = |mask: u32| y < MAX_ROWS && (mask >> y) & 1 == 1;
+        let row = if self.compressed_len == 0 {
+            self.packed_row(y)
+        } else if is_set(self.blank) {
+            [0; ROW_WORDS]
+        } else if is_set(self.repeat) {
+            self.prev
+        } else {
+            self.decoded_row()
+        };
+        self.prev = row;
+        Some(row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use crate::font::{BuiltinFont, Font};
+    use std::println;
+
+    /// Compress the rows of a glyph into out[1..], the way codegen does.
+    /// Return: Some(words of compressed data) if that is less than packed pixels
+    fn compress(gh: &GlyphHeader, rows: &[Row], out: &mut [u32]) -> Option<usize> {
+        if gh.h > MAX_ROWS {
+            return None;
+        }
+        let limit = pattern_len(gh, 0).min(out.len() - 1);
+        let (mut blank, mut repeat) = (0, 0);
+        let mut nibbles = 0;
+        let mut push = |nibble: u32, out: &mut [u32]| {
+            let n = 3 + nibbles / 8;
+            if n <= limit {
+                out[n] |= nibble << (28 - 4 * (nibbles % 8));
+            }
+            nibbles += 1;
+        };
+        out[1..=limit].fill(0);
+        let (mut run, mut run_set) = (0, false);
+        for (y, row) in rows.iter().enumerate() {
+            if row.iter().all(|&word| word == 0) {
+                blank |= 1 << y;
+                continue;
+            }
+            if y > 0 && *row == rows[y - 1] {
+                repeat |= 1 << y;
+                continue;
+            }
+            for x in (0..gh.w).rev() {
+                if ((row[x >> 5] >> (x & 0x1f)) & 1 == 1) != run_set {
+                    for _ in 0..run / 15 {
+                        push(15, out);
+                    }
+                    push(run as u32 % 15, out);
+                    run_set = !run_set;
+                    run = 0;
+                }
+                run += 1;
+            }
+        }
+        for _ in 0..run / 15 {
+            push(15, out);
+        }
+        push(run as u32 % 15, out);
+        let len = 2 + nibbles.div_ceil(8);
+        if len >= pattern_len(gh, 0) {
+            return None;
+        }
+        out[1] = blank;
+        out[2] = repeat;
+        Some(len)
+    }
+
+    /// Pack the rows of a glyph into out[1..], starting each row from its
+    /// rightmost pixel
+    fn pack(gh: &GlyphHeader, rows: &[Row], out: &mut [u32]) {
+        let mut i = 0;
+        for row in rows.iter() {
+            for x in (0..gh.w).rev() {
+                let px = (row[x >> 5] >> (x & 0x1f)) & 1;
+                out[1 + i / 32] |= px << (31 - i % 32);
+                i += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn test_compressed_rows_match() {
+        let gh = GlyphHeader {
+            w: 20,
+            h: 8,
+            y_offset: 0,
+        };
+        let rows = [0, 0xF_FFFF, 0xF_FFFF, 0, 0x8_0001, 0x8_0001, 0x1_F0F0, 0];
+        let rows: [Row; 8] = rows.map(|row| [row, 0, 0, 0, 0, 0, 0, 0]);
+        let mut words = [0; 8];
+        let n = compress(&gh, &rows, &mut words).unwrap();
+        assert!(n < pattern_len(&gh, 0));
+        let decoded = Rows::new(&gh, n, |i| words[i]);
+        assert!(decoded.eq(rows.iter().copied()));
+        // A truncated pattern decodes without panicking
+        assert_eq!(Rows::new(&gh, 2, |i| words[i]).count(), gh.h);
+    }
+
+    #[test]
+    fn test_wide_rows_match() {
+        let gh = GlyphHeader {
+            w: 40,
+            h: 6,
+            y_offset: 0,
+        };
+        let rows: [u64; 6] = [
+            0xFF_FFFF_FFFF,
+            0x80_0000_0001,
+            0x80_0000_0001,
+            0,
+            0x0F_F000_0FF0,
+            0xC3_C3C3_C3C3,
+        ];
+        let rows = rows.map(|row| [row as u32, (row >> 32) as u32, 0, 0, 0, 0, 0, 0]);
+        let words = &mut [0; 16];
+        pack(&gh, &rows, words);
+        let packed = Rows::new(&gh, 0, |i| words[i]);
+        assert!(packed.eq(rows.iter().copied()));
+        let compressed = &mut [0; 16];
+        let n = compress(&gh, &rows, compressed).unwrap();
+        let decoded = Rows::new(&gh, n, |i| compressed[i]);
+        assert!(decoded.eq(rows.iter().copied()));
+    }
+
+    #[test]
+    fn test_report_flash_savings() {
+        let fonts = [
+            #[cfg(feature = "emoji")]
+            BuiltinFont::Emoji,
+            #[cfg(feature = "latin-bold")]
+            BuiltinFont::Bold,
+            #[cfg(feature = "latin-regular")]
+            BuiltinFont::Regular,
+            #[cfg(feature = "latin-small")]
+            BuiltinFont::Small,
+            #[cfg(feature = "hanzi")]
+            BuiltinFont::Hanzi,
+            BuiltinFont::Kana,
+            BuiltinFont::Icons,
+            #[cfg(feature = "numeric")]
+            BuiltinFont::Numeric,
+        ];
+        for font in fonts.iter() {
+            let data = font.data();
+            let (mut packed, mut compressible) = (0, 0);
+            let mut offset = 0;
+            while offset < data.len() {
+                let gh = font.header(offset);
+                let compressed_len = font.compressed_len(offset);
+                let mut buf = [[0; ROW_WORDS]; 256];
+                let rows = &mut buf[..gh.h];
+                for (row, px) in rows
+                    .iter_mut()
+                    .zip(Rows::new(&gh, compressed_len, |n| data[offset + n]))
+                {
+                    *row = px;
+                }
+                // Glyphs that codegen compressed must match the reference encoder
+                let words = &mut [0; 1 + MAX_WIDTH * MAX_ROWS / 32];
+                let len = compress(&gh, rows, words);
+                if compressed_len > 0 {
+                    let stored = &data[offset + 1..][..compressed_len];
+                    assert_eq!(len, Some(compressed_len), "{:?}[{}]", font, offset);
+                    assert_eq!(&words[1..=compressed_len], stored);
+                }
+                packed += 1 + pattern_len(&gh, 0);
+                compressible += 1 + len.unwrap_or(pattern_len(&gh, 0));
+                offset += 1 + pattern_len(&gh, compressed_len);
+            }
+            let saved = |words: usize| (packed - words) * 1000 / packed;
+            println!(
+                "{:?}: {} words packed, {} stored ({}.{}% saved), {} if compressed ({}.{}% saved)",
+                font,
+                packed,
+                data.len(),
+                saved(data.len()) / 10,
+                saved(data.len()) % 10,
+                compressible,
+                saved(compressible) / 10,
+                saved(compressible) % 10,
+            );
+            // Fonts are stored either packed, or compressed wherever that is smaller
+            assert!(data.len() == packed || data.len() == compressible);
+        }
+    }
+}
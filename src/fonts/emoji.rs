@@ -43,12 +43,19 @@ pub const M3_SEED: u32 = 0;
 ///
 /// Returns: Result<(blit pattern offset into DATA, bytes of cluster used by match)>
 pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGlyphErr> {
-    let first_char: u32;
-    match cluster.chars().next() {
-        Some(c) => first_char = c as u32,
+    let first_char: u32 = match cluster.chars().next() {
+        Some(c) => c as u32,
         None => return Err(NoGlyphErr),
+    };
+    // Regional indicator symbols only form a flag when they come in pairs, so
+    // don't let a pair with no flag glyph fall back to two single letters
+    if super::regional_indicator_pair(cluster).is_some() {
+        return match find_enclosed_alphanumeric_supplement(cluster, 2) {
+            Some((offset, bytes_used)) => Ok((GlyphData::Emoji(offset), bytes_used)),
+            None => Err(NoGlyphErr),
+        };
     }
-    return match first_char {
+    match first_char {
         0x0..=0x7F => {
             if let Some((offset, bytes_used)) = find_basic_latin(cluster, 3) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
@@ -61,7 +68,7 @@ pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGl
         0x80..=0xFF => {
             if let Some((offset, bytes_used)) = find_latin_1_supplement(cluster, 2) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
-            } else if let Some((offset, bytes_used)) = find_latin_1_supplement(cluster, 1) {
+            } else if let Some((offset, bytes_used)) = find_latin_1_supplement_codepoint(cluster) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
@@ -88,7 +95,7 @@ pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGl
         0x2190..=0x21FF => {
             if let Some((offset, bytes_used)) = find_arrows(cluster, 2) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
-            } else if let Some((offset, bytes_used)) = find_arrows(cluster, 1) {
+            } else if let Some((offset, bytes_used)) = find_arrows_codepoint(cluster) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
@@ -106,7 +113,7 @@ pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGl
         0x2460..=0x24FF => {
             if let Some((offset, bytes_used)) = find_enclosed_alphanumerics(cluster, 2) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
-            } else if let Some((offset, bytes_used)) = find_enclosed_alphanumerics(cluster, 1) {
+            } else if let Some((offset, bytes_used)) = find_enclosed_alphanumerics_codepoint(cluster) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
@@ -144,7 +151,7 @@ pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGl
         0x2900..=0x297F => {
             if let Some((offset, bytes_used)) = find_supplemental_arrows_b(cluster, 2) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
-            } else if let Some((offset, bytes_used)) = find_supplemental_arrows_b(cluster, 1) {
+            } else if let Some((offset, bytes_used)) = find_supplemental_arrows_b_codepoint(cluster) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
@@ -171,14 +178,14 @@ pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGl
         0x3200..=0x32FF => {
             if let Some((offset, bytes_used)) = find_enclosed_cjk_letters_and_months(cluster, 2) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
-            } else if let Some((offset, bytes_used)) = find_enclosed_cjk_letters_and_months(cluster, 1) {
+            } else if let Some((offset, bytes_used)) = find_enclosed_cjk_letters_and_months_codepoint(cluster) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
             }
         }
         0xE000..=0xF8FF => {
-            if let Some((offset, bytes_used)) = find_private_use_area(cluster, 1) {
+            if let Some((offset, bytes_used)) = find_private_use_area_codepoint(cluster) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
@@ -187,14 +194,14 @@ pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGl
         0x1F000..=0x1F02F => {
             if let Some((offset, bytes_used)) = find_mahjong_tiles(cluster, 2) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
-            } else if let Some((offset, bytes_used)) = find_mahjong_tiles(cluster, 1) {
+            } else if let Some((offset, bytes_used)) = find_mahjong_tiles_codepoint(cluster) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
             }
         }
         0x1F0A0..=0x1F0FF => {
-            if let Some((offset, bytes_used)) = find_playing_cards(cluster, 1) {
+            if let Some((offset, bytes_used)) = find_playing_cards_codepoint(cluster) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
@@ -233,7 +240,7 @@ pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGl
                 Ok((GlyphData::Emoji(offset), bytes_used))
             } else if let Some((offset, bytes_used)) = find_miscellaneous_symbols_and_pictographs(cluster, 2) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
-            } else if let Some((offset, bytes_used)) = find_miscellaneous_symbols_and_pictographs(cluster, 1) {
+            } else if let Some((offset, bytes_used)) = find_miscellaneous_symbols_and_pictographs_codepoint(cluster) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
@@ -246,7 +253,7 @@ pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGl
                 Ok((GlyphData::Emoji(offset), bytes_used))
             } else if let Some((offset, bytes_used)) = find_emoticons(cluster, 2) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
-            } else if let Some((offset, bytes_used)) = find_emoticons(cluster, 1) {
+            } else if let Some((offset, bytes_used)) = find_emoticons_codepoint(cluster) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
@@ -259,14 +266,14 @@ pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGl
                 Ok((GlyphData::Emoji(offset), bytes_used))
             } else if let Some((offset, bytes_used)) = find_transport_and_map_symbols(cluster, 2) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
-            } else if let Some((offset, bytes_used)) = find_transport_and_map_symbols(cluster, 1) {
+            } else if let Some((offset, bytes_used)) = find_transport_and_map_symbols_codepoint(cluster) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
             }
         }
         0x1F780..=0x1F7FF => {
-            if let Some((offset, bytes_used)) = find_geometric_shapes_extended(cluster, 1) {
+            if let Some((offset, bytes_used)) = find_geometric_shapes_extended_codepoint(cluster) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
@@ -283,35 +290,43 @@ pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGl
                 Ok((GlyphData::Emoji(offset), bytes_used))
             } else if let Some((offset, bytes_used)) = find_supplemental_symbols_and_pictographs(cluster, 2) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
-            } else if let Some((offset, bytes_used)) = find_supplemental_symbols_and_pictographs(cluster, 1) {
+            } else if let Some((offset, bytes_used)) = find_supplemental_symbols_and_pictographs_codepoint(cluster) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
             }
         }
         0x1FA70..=0x1FAFF => {
-            if let Some((offset, bytes_used)) = find_symbols_and_pictographs_extended_a(cluster, 1) {
+            if let Some((offset, bytes_used)) = find_symbols_and_pictographs_extended_a_codepoint(cluster) {
                 Ok((GlyphData::Emoji(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
             }
         }
         _ => Err(super::NoGlyphErr),
-    };
+    }
 }
 
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_basic_latin(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_BASIC_LATIN.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_BASIC_LATIN[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_BASIC_LATIN.binary_search(&hash) {
+        Ok(index) if KEY_BASIC_LATIN[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_BASIC_LATIN[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_BASIC_LATIN
-const HASH_BASIC_LATIN: [u32; 24] = [
+static HASH_BASIC_LATIN: [u32; 24] = [
     0x21A25536,  // "1⃣" 31-20E3
     0x2C953D7D,  // "6⃣" 36-20E3
     0x2E5CDDE9,  // "2⃣" 32-20E3
@@ -338,8 +353,36 @@ const HASH_BASIC_LATIN: [u32; 24] = [
     0xE2AF0060,  // "3⃣" 33-20E3
 ];
 
+/// Verification keys to rule out hash collisions; sort matches HASH_BASIC_LATIN
+static KEY_BASIC_LATIN: [u32; 24] = [
+    0xA8232D97,  // "1⃣" 31-20E3
+    0xBA8F562B,  // "6⃣" 36-20E3
+    0xA7FB4E67,  // "2⃣" 32-20E3
+    0x9D292C3C,  // "8⃣" 38-20E3
+    0xC706B6F0,  // "9️⃣" 39-FE0F-20E3
+    0x85EED8C6,  // "5⃣" 35-20E3
+    0xDEEA43C2,  // "*⃣" 2A-20E3
+    0xBFAB384D,  // "#⃣" 23-20E3
+    0xA834892C,  // "3️⃣" 33-FE0F-20E3
+    0x95E2DCF5,  // "6️⃣" 36-FE0F-20E3
+    0xFE45E155,  // "*️⃣" 2A-FE0F-20E3
+    0xD53C853F,  // "7⃣" 37-20E3
+    0xB577B164,  // "8️⃣" 38-FE0F-20E3
+    0xA8DFF0B3,  // "0️⃣" 30-FE0F-20E3
+    0xFFAC1007,  // "2️⃣" 32-FE0F-20E3
+    0xB9073335,  // "4️⃣" 34-FE0F-20E3
+    0xE1C776DF,  // "0⃣" 30-20E3
+    0x9F8CF860,  // "1️⃣" 31-FE0F-20E3
+    0xAB3FB650,  // "7️⃣" 37-FE0F-20E3
+    0xB2950F00,  // "#️⃣" 23-FE0F-20E3
+    0xBF9E64D0,  // "4⃣" 34-20E3
+    0xE20D785C,  // "9⃣" 39-20E3
+    0x9AE1E3B7,  // "5️⃣" 35-FE0F-20E3
+    0xA1ED693E,  // "3⃣" 33-20E3
+];
+
 /// Lookup table of blit pattern offsets; sort matches HASH_BASIC_LATIN
-const OFFSET_BASIC_LATIN: [usize; 24] = [
+static OFFSET_BASIC_LATIN: [usize; 24] = [
     90774, // "1⃣" 31-20E3
     91001, // "6⃣" 36-20E3
     90807, // "2⃣" 32-20E3
@@ -366,52 +409,93 @@ const OFFSET_BASIC_LATIN: [usize; 24] = [
     90904, // "3⃣" 33-20E3
 ];
 
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_latin_1_supplement_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_LATIN_1_SUPPLEMENT, 0xA9, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0xA9..=0xAE, or NO_GLYPH
+static CODEPOINT_LATIN_1_SUPPLEMENT: [u32; 6] = [
+    91130, // "©"
+    super::NO_GLYPH,  // U+00AA
+    super::NO_GLYPH,  // U+00AB
+    super::NO_GLYPH,  // U+00AC
+    super::NO_GLYPH,  // U+00AD
+    91163, // "®"
+];
+
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_latin_1_supplement(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_LATIN_1_SUPPLEMENT.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_LATIN_1_SUPPLEMENT[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_LATIN_1_SUPPLEMENT.binary_search(&hash) {
+        Ok(index) if KEY_LATIN_1_SUPPLEMENT[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_LATIN_1_SUPPLEMENT[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_LATIN_1_SUPPLEMENT
-const HASH_LATIN_1_SUPPLEMENT: [u32; 4] = [
-    0x0254FD66,  // "®"
+static HASH_LATIN_1_SUPPLEMENT: [u32; 2] = [
     0x58292DAE,  // "®️" AE-FE0F
-    0xA7B0FE42,  // "©"
     0xAF8BA02B,  // "©️" A9-FE0F
 ];
 
+/// Verification keys to rule out hash collisions; sort matches HASH_LATIN_1_SUPPLEMENT
+static KEY_LATIN_1_SUPPLEMENT: [u32; 2] = [
+    0xD39D4896,  // "®️" AE-FE0F
+    0xF9C8B645,  // "©️" A9-FE0F
+];
+
 /// Lookup table of blit pattern offsets; sort matches HASH_LATIN_1_SUPPLEMENT
-const OFFSET_LATIN_1_SUPPLEMENT: [usize; 4] = [
-    91163, // "®"
+static OFFSET_LATIN_1_SUPPLEMENT: [usize; 2] = [
     91163, // "®️" AE-FE0F
-    91130, // "©"
     91130, // "©️" A9-FE0F
 ];
 
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_general_punctuation(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_GENERAL_PUNCTUATION.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_GENERAL_PUNCTUATION[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_GENERAL_PUNCTUATION.binary_search(&hash) {
+        Ok(index) if KEY_GENERAL_PUNCTUATION[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_GENERAL_PUNCTUATION[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_GENERAL_PUNCTUATION
-const HASH_GENERAL_PUNCTUATION: [u32; 4] = [
+static HASH_GENERAL_PUNCTUATION: [u32; 4] = [
     0x24F1EBAF,  // "‼"
     0x552BC889,  // "‼️" 203C-FE0F
     0xD5F4B15F,  // "⁉️" 2049-FE0F
     0xF1679268,  // "⁉"
 ];
 
+/// Verification keys to rule out hash collisions; sort matches HASH_GENERAL_PUNCTUATION
+static KEY_GENERAL_PUNCTUATION: [u32; 4] = [
+    0x0000203C,  // "‼"
+    0xBE97BBDE,  // "‼️" 203C-FE0F
+    0xC6BC67B3,  // "⁉️" 2049-FE0F
+    0x00002049,  // "⁉"
+];
+
 /// Lookup table of blit pattern offsets; sort matches HASH_GENERAL_PUNCTUATION
-const OFFSET_GENERAL_PUNCTUATION: [usize; 4] = [
+static OFFSET_GENERAL_PUNCTUATION: [usize; 4] = [
     84771, // "‼"
     84771, // "‼️" 203C-FE0F
     84789, // "⁉️" 2049-FE0F
@@ -421,91 +505,151 @@ const OFFSET_GENERAL_PUNCTUATION: [usize; 4] = [
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_letterlike_symbols(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_LETTERLIKE_SYMBOLS.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_LETTERLIKE_SYMBOLS[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_LETTERLIKE_SYMBOLS.binary_search(&hash) {
+        Ok(index) if KEY_LETTERLIKE_SYMBOLS[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_LETTERLIKE_SYMBOLS[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_LETTERLIKE_SYMBOLS
-const HASH_LETTERLIKE_SYMBOLS: [u32; 4] = [
+static HASH_LETTERLIKE_SYMBOLS: [u32; 4] = [
     0x3576E554,  // "™️" 2122-FE0F
     0x7BC3FAC8,  // "ℹ"
     0xA9620193,  // "ℹ️" 2139-FE0F
     0xB6F492EA,  // "™"
 ];
 
+/// Verification keys to rule out hash collisions; sort matches HASH_LETTERLIKE_SYMBOLS
+static KEY_LETTERLIKE_SYMBOLS: [u32; 4] = [
+    0x9DD3E0B1,  // "™️" 2122-FE0F
+    0x00002139,  // "ℹ"
+    0xD18E0485,  // "ℹ️" 2139-FE0F
+    0x00002122,  // "™"
+];
+
 /// Lookup table of blit pattern offsets; sort matches HASH_LETTERLIKE_SYMBOLS
-const OFFSET_LETTERLIKE_SYMBOLS: [usize; 4] = [
+static OFFSET_LETTERLIKE_SYMBOLS: [usize; 4] = [
     84814, // "™️" 2122-FE0F
     84831, // "ℹ"
     84831, // "ℹ️" 2139-FE0F
     84814, // "™"
 ];
 
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_arrows_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_ARROWS, 0x2194, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x2194..=0x21AA, or NO_GLYPH
+static CODEPOINT_ARROWS: [u32; 23] = [
+    84863, // "↔"
+    84896, // "↕"
+    84928, // "↖"
+    84960, // "↗"
+    84992, // "↘"
+    85025, // "↙"
+    super::NO_GLYPH,  // U+219A
+    super::NO_GLYPH,  // U+219B
+    super::NO_GLYPH,  // U+219C
+    super::NO_GLYPH,  // U+219D
+    super::NO_GLYPH,  // U+219E
+    super::NO_GLYPH,  // U+219F
+    super::NO_GLYPH,  // U+21A0
+    super::NO_GLYPH,  // U+21A1
+    super::NO_GLYPH,  // U+21A2
+    super::NO_GLYPH,  // U+21A3
+    super::NO_GLYPH,  // U+21A4
+    super::NO_GLYPH,  // U+21A5
+    super::NO_GLYPH,  // U+21A6
+    super::NO_GLYPH,  // U+21A7
+    super::NO_GLYPH,  // U+21A8
+    85057, // "↩"
+    85089, // "↪"
+];
+
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_arrows(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_ARROWS.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_ARROWS[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_ARROWS.binary_search(&hash) {
+        Ok(index) if KEY_ARROWS[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_ARROWS[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_ARROWS
-const HASH_ARROWS: [u32; 16] = [
+static HASH_ARROWS: [u32; 8] = [
     0x03C35D48,  // "↗️" 2197-FE0F
-    0x2419F05C,  // "↗"
-    0x2F4DA40C,  // "↙"
-    0x3A987DC6,  // "↩"
-    0x43829E86,  // "↘"
     0x4AE933E9,  // "↔️" 2194-FE0F
-    0x657AD9C6,  // "↕"
-    0x6D5E0CB5,  // "↪"
     0x74DE1FE4,  // "↩️" 21A9-FE0F
-    0x8EF51186,  // "↔"
     0x979C67D2,  // "↖️" 2196-FE0F
     0x98B45CCE,  // "↕️" 2195-FE0F
     0xB4AFA17D,  // "↪️" 21AA-FE0F
     0xE1AF7CF5,  // "↘️" 2198-FE0F
     0xF5A0FD62,  // "↙️" 2199-FE0F
-    0xF96FB401,  // "↖"
+];
+
+/// Verification keys to rule out hash collisions; sort matches HASH_ARROWS
+static KEY_ARROWS: [u32; 8] = [
+    0xF411C702,  // "↗️" 2197-FE0F
+    0x8C219EC4,  // "↔️" 2194-FE0F
+    0xE5583128,  // "↩️" 21A9-FE0F
+    0xDEFD4AB1,  // "↖️" 2196-FE0F
+    0xFF711B97,  // "↕️" 2195-FE0F
+    0xD64E7418,  // "↪️" 21AA-FE0F
+    0xD7975F13,  // "↘️" 2198-FE0F
+    0x8DEDE10F,  // "↙️" 2199-FE0F
 ];
 
 /// Lookup table of blit pattern offsets; sort matches HASH_ARROWS
-const OFFSET_ARROWS: [usize; 16] = [
+static OFFSET_ARROWS: [usize; 8] = [
     84960, // "↗️" 2197-FE0F
-    84960, // "↗"
-    85025, // "↙"
-    85057, // "↩"
-    84992, // "↘"
     84863, // "↔️" 2194-FE0F
-    84896, // "↕"
-    85089, // "↪"
     85057, // "↩️" 21A9-FE0F
-    84863, // "↔"
     84928, // "↖️" 2196-FE0F
     84896, // "↕️" 2195-FE0F
     85089, // "↪️" 21AA-FE0F
     84992, // "↘️" 2198-FE0F
     85025, // "↙️" 2199-FE0F
-    84928, // "↖"
 ];
 
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_miscellaneous_technical(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_MISCELLANEOUS_TECHNICAL.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_MISCELLANEOUS_TECHNICAL[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_MISCELLANEOUS_TECHNICAL.binary_search(&hash) {
+        Ok(index) if KEY_MISCELLANEOUS_TECHNICAL[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_MISCELLANEOUS_TECHNICAL[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_MISCELLANEOUS_TECHNICAL
-const HASH_MISCELLANEOUS_TECHNICAL: [u32; 33] = [
+static HASH_MISCELLANEOUS_TECHNICAL: [u32; 33] = [
     0x05FFBB41,  // "⏪"
     0x0742DC77,  // "⏩"
     0x107CEC26,  // "⏸"
@@ -541,8 +685,45 @@ const HASH_MISCELLANEOUS_TECHNICAL: [u32; 33] = [
     0xFBD75DFF,  // "⏹"
 ];
 
+/// Verification keys to rule out hash collisions; sort matches HASH_MISCELLANEOUS_TECHNICAL
+static KEY_MISCELLANEOUS_TECHNICAL: [u32; 33] = [
+    0x000023EA,  // "⏪"
+    0x000023E9,  // "⏩"
+    0x000023F8,  // "⏸"
+    0x000023EF,  // "⏯"
+    0xEB20964B,  // "⏭️" 23ED-FE0F
+    0x000023EB,  // "⏫"
+    0x000023EC,  // "⏬"
+    0x000023ED,  // "⏭"
+    0x972D5975,  // "⏱️" 23F1-FE0F
+    0xCB7CD49B,  // "⏲️" 23F2-FE0F
+    0x96B1659E,  // "⌛️" 231B-FE0F
+    0xBAAE3759,  // "⌚️" 231A-FE0F
+    0xC7C71C5E,  // "⏳️" 23F3-FE0F
+    0x9A82DB52,  // "⏹️" 23F9-FE0F
+    0x95034542,  // "⌨️" 2328-FE0F
+    0x000023F1,  // "⏱"
+    0x0000231A,  // "⌚"
+    0x00002328,  // "⌨"
+    0xB76B8D94,  // "⏯️" 23EF-FE0F
+    0x000023FA,  // "⏺"
+    0x000023F0,  // "⏰"
+    0x9CE98E60,  // "⏮️" 23EE-FE0F
+    0x000023F2,  // "⏲"
+    0xBA8353FD,  // "⏸️" 23F8-FE0F
+    0x000023CF,  // "⏏"
+    0x000023EE,  // "⏮"
+    0x84D20761,  // "⏺️" 23FA-FE0F
+    0xB1BC50FC,  // "⏏️" 23CF-FE0F
+    0x0000231B,  // "⌛"
+    0xB6F3626F,  // "⏪️" 23EA-FE0F
+    0xDCF03336,  // "⏩️" 23E9-FE0F
+    0x000023F3,  // "⏳"
+    0x000023F9,  // "⏹"
+];
+
 /// Lookup table of blit pattern offsets; sort matches HASH_MISCELLANEOUS_TECHNICAL
-const OFFSET_MISCELLANEOUS_TECHNICAL: [usize; 33] = [
+static OFFSET_MISCELLANEOUS_TECHNICAL: [usize; 33] = [
     85283, // "⏪"
     85250, // "⏩"
     85583, // "⏸"
@@ -578,40 +759,69 @@ const OFFSET_MISCELLANEOUS_TECHNICAL: [usize; 33] = [
     85615, // "⏹"
 ];
 
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_enclosed_alphanumerics_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_ENCLOSED_ALPHANUMERICS, 0x24C2, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x24C2..=0x24C2, or NO_GLYPH
+static CODEPOINT_ENCLOSED_ALPHANUMERICS: [u32; 1] = [
+    85680, // "Ⓜ"
+];
+
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_enclosed_alphanumerics(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_ENCLOSED_ALPHANUMERICS.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_ENCLOSED_ALPHANUMERICS[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_ENCLOSED_ALPHANUMERICS.binary_search(&hash) {
+        Ok(index) if KEY_ENCLOSED_ALPHANUMERICS[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_ENCLOSED_ALPHANUMERICS[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_ENCLOSED_ALPHANUMERICS
-const HASH_ENCLOSED_ALPHANUMERICS: [u32; 2] = [
+static HASH_ENCLOSED_ALPHANUMERICS: [u32; 1] = [
     0xA932CDE6,  // "Ⓜ️" 24C2-FE0F
-    0xD30E07EB,  // "Ⓜ"
+];
+
+/// Verification keys to rule out hash collisions; sort matches HASH_ENCLOSED_ALPHANUMERICS
+static KEY_ENCLOSED_ALPHANUMERICS: [u32; 1] = [
+    0xEEE5306C,  // "Ⓜ️" 24C2-FE0F
 ];
 
 /// Lookup table of blit pattern offsets; sort matches HASH_ENCLOSED_ALPHANUMERICS
-const OFFSET_ENCLOSED_ALPHANUMERICS: [usize; 2] = [
+static OFFSET_ENCLOSED_ALPHANUMERICS: [usize; 1] = [
     85680, // "Ⓜ️" 24C2-FE0F
-    85680, // "Ⓜ"
 ];
 
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_geometric_shapes(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_GEOMETRIC_SHAPES.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_GEOMETRIC_SHAPES[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_GEOMETRIC_SHAPES.binary_search(&hash) {
+        Ok(index) if KEY_GEOMETRIC_SHAPES[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_GEOMETRIC_SHAPES[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_GEOMETRIC_SHAPES
-const HASH_GEOMETRIC_SHAPES: [u32; 16] = [
+static HASH_GEOMETRIC_SHAPES: [u32; 16] = [
     0x0A290CDE,  // "◼️" 25FC-FE0F
     0x22FE882B,  // "▪"
     0x2613B7FD,  // "◽"
@@ -630,8 +840,28 @@ const HASH_GEOMETRIC_SHAPES: [u32; 16] = [
     0xF7DA77D8,  // "▶️" 25B6-FE0F
 ];
 
+/// Verification keys to rule out hash collisions; sort matches HASH_GEOMETRIC_SHAPES
+static KEY_GEOMETRIC_SHAPES: [u32; 16] = [
+    0xEDF040C8,  // "◼️" 25FC-FE0F
+    0x000025AA,  // "▪"
+    0x000025FD,  // "◽"
+    0xBC899E2A,  // "▪️" 25AA-FE0F
+    0x000025B6,  // "▶"
+    0x000025FC,  // "◼"
+    0x8948BA0A,  // "◾️" 25FE-FE0F
+    0x000025FB,  // "◻"
+    0xCA7ABBAD,  // "◀️" 25C0-FE0F
+    0x000025FE,  // "◾"
+    0x9374954E,  // "◽️" 25FD-FE0F
+    0x9BF9C647,  // "▫️" 25AB-FE0F
+    0x000025AB,  // "▫"
+    0xE5101181,  // "◻️" 25FB-FE0F
+    0x000025C0,  // "◀"
+    0x9FF2517B,  // "▶️" 25B6-FE0F
+];
+
 /// Lookup table of blit pattern offsets; sort matches HASH_GEOMETRIC_SHAPES
-const OFFSET_GEOMETRIC_SHAPES: [usize; 16] = [
+static OFFSET_GEOMETRIC_SHAPES: [usize; 16] = [
     85812, // "◼️" 25FC-FE0F
     85712, // "▪"
     85835, // "◽"
@@ -653,15 +883,23 @@ const OFFSET_GEOMETRIC_SHAPES: [usize; 16] = [
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_miscellaneous_symbols(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_MISCELLANEOUS_SYMBOLS.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_MISCELLANEOUS_SYMBOLS[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_MISCELLANEOUS_SYMBOLS.binary_search(&hash) {
+        Ok(index) if KEY_MISCELLANEOUS_SYMBOLS[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_MISCELLANEOUS_SYMBOLS[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_MISCELLANEOUS_SYMBOLS
-const HASH_MISCELLANEOUS_SYMBOLS: [u32; 192] = [
+static HASH_MISCELLANEOUS_SYMBOLS: [u32; 192] = [
     0x00DABFFB,  // "♐️" 2650-FE0F
     0x019074A9,  // "⛹🏿\u200d♂️" 26F9-1F3FF-200D-2642-FE0F
     0x0453680C,  // "♂️" 2642-FE0F
@@ -856,8 +1094,204 @@ const HASH_MISCELLANEOUS_SYMBOLS: [u32; 192] = [
     0xFD27913A,  // "☮"
 ];
 
+/// Verification keys to rule out hash collisions; sort matches HASH_MISCELLANEOUS_SYMBOLS
+static KEY_MISCELLANEOUS_SYMBOLS: [u32; 192] = [
+    0xB5ED24FA,  // "♐️" 2650-FE0F
+    0x88EC8711,  // "⛹🏿\u200d♂️" 26F9-1F3FF-200D-2642-FE0F
+    0xF71DA6E9,  // "♂️" 2642-FE0F
+    0x00002618,  // "☘"
+    0x00002622,  // "☢"
+    0xB75F60C7,  // "⛓️" 26D3-FE0F
+    0xEBE51D0E,  // "♀️" 2640-FE0F
+    0x00002640,  // "♀"
+    0x00002692,  // "⚒"
+    0x000026A1,  // "⚡"
+    0x000026E9,  // "⛩"
+    0xF925FE06,  // "⛲️" 26F2-FE0F
+    0x952AAFDD,  // "♣️" 2663-FE0F
+    0x0000262F,  // "☯"
+    0x9A9326EA,  // "♨️" 2668-FE0F
+    0xFA6C6367,  // "⛹🏻\u200d♀️" 26F9-1F3FB-200D-2640-FE0F
+    0x00002696,  // "⚖"
+    0xDCA25232,  // "♑️" 2651-FE0F
+    0x000026F5,  // "⛵"
+    0xA75C2425,  // "⚧️" 26A7-FE0F
+    0xB346E7C9,  // "⛔️" 26D4-FE0F
+    0xFF23FC2E,  // "⚙️" 2699-FE0F
+    0xDAFBF0E3,  // "⛈️" 26C8-FE0F
+    0x00002653,  // "♓"
+    0x00002663,  // "♣"
+    0x000026F9,  // "⛹"
+    0xBA56810D,  // "☪️" 262A-FE0F
+    0x0000260E,  // "☎"
+    0xB711F104,  // "⛹️\u200d♂️" 26F9-FE0F-200D-2642-FE0F
+    0xF5E08AA5,  // "⛴️" 26F4-FE0F
+    0x00002666,  // "♦"
+    0xEF7340F1,  // "⚖️" 2696-FE0F
+    0x00002649,  // "♉"
+    0xF9244945,  // "⛹🏽\u200d♀️" 26F9-1F3FD-200D-2640-FE0F
+    0xC4D7421C,  // "☀️" 2600-FE0F
+    0xCED41EAF,  // "♻️" 267B-FE0F
+    0x0000264A,  // "♊"
+    0x000026F0,  // "⛰"
+    0x000026C4,  // "⛄"
+    0x93D2BC1C,  // "☝🏻" 261D-1F3FB
+    0x000026BD,  // "⚽"
+    0x9CDF35CF,  // "⛹🏿" 26F9-1F3FF
+    0xF65B37B0,  // "♾️" 267E-FE0F
+    0xE5E87324,  // "⛹🏻\u200d♂️" 26F9-1F3FB-200D-2642-FE0F
+    0x000026C8,  // "⛈"
+    0x0000269B,  // "⚛"
+    0x8F1BF83E,  // "⛹🏼\u200d♀️" 26F9-1F3FC-200D-2640-FE0F
+    0x9DBF0A6E,  // "⚗️" 2697-FE0F
+    0x959C2B7D,  // "♊️" 264A-FE0F
+    0x944CFCB0,  // "♉️" 2649-FE0F
+    0x00002620,  // "☠"
+    0x8A199B3F,  // "☄️" 2604-FE0F
+    0x000026B0,  // "⚰"
+    0x84635408,  // "⚡️" 26A1-FE0F
+    0xB1C076DC,  // "☂️" 2602-FE0F
+    0xC1C9BAF5,  // "☣️" 2623-FE0F
+    0x0000267F,  // "♿"
+    0xABAC4197,  // "⛹🏼\u200d♂️" 26F9-1F3FC-200D-2642-FE0F
+    0x000026F2,  // "⛲"
+    0xF4242F1D,  // "⛽️" 26FD-FE0F
+    0xDA2BA253,  // "⛸️" 26F8-FE0F
+    0x8BB5336D,  // "⛹️\u200d♀️" 26F9-FE0F-200D-2640-FE0F
+    0x9696B1E3,  // "⚱️" 26B1-FE0F
+    0x00002615,  // "☕"
+    0x0000267B,  // "♻"
+    0x000026A0,  // "⚠"
+    0x85F4B0EF,  // "⛄️" 26C4-FE0F
+    0x000026FA,  // "⛺"
+    0x0000262A,  // "☪"
+    0x0000263A,  // "☺"
+    0x00002652,  // "♒"
+    0x8DE4A484,  // "⚠️" 26A0-FE0F
+    0xB26BC804,  // "⛺️" 26FA-FE0F
+    0xFFBC1E88,  // "⛵️" 26F5-FE0F
+    0x000026BE,  // "⚾"
+    0xA54A333E,  // "⚜️" 269C-FE0F
+    0xF7ADF172,  // "♌️" 264C-FE0F
+    0x00002600,  // "☀"
+    0xCCD74B8C,  // "♥️" 2665-FE0F
+    0xC0E6B838,  // "⚽️" 26BD-FE0F
+    0x00002642,  // "♂"
+    0xC78016D4,  // "☦️" 2626-FE0F
+    0xF72B8947,  // "♎️" 264E-FE0F
+    0xC9A0100E,  // "☸️" 2638-FE0F
+    0xA84D99B8,  // "⚓️" 2693-FE0F
+    0x0000265F,  // "♟"
+    0x97CC4614,  // "☝️" 261D-FE0F
+    0x0000264D,  // "♍"
+    0x000026D1,  // "⛑"
+    0xD2794985,  // "☝🏽" 261D-1F3FD
+    0xA06C4BFD,  // "♈️" 2648-FE0F
+    0xB131FEF8,  // "☘️" 2618-FE0F
+    0x000026D4,  // "⛔"
+    0x000026F8,  // "⛸"
+    0x000026F3,  // "⛳"
+    0x00002626,  // "☦"
+    0x9D57E789,  // "⛪️" 26EA-FE0F
+    0x00002638,  // "☸"
+    0x00002697,  // "⚗"
+    0x00002668,  // "♨"
+    0x8343DECF,  // "⚒️" 2692-FE0F
+    0xF861D51D,  // "☺️" 263A-FE0F
+    0x9BAF6634,  // "☔️" 2614-FE0F
+    0x0000267E,  // "♾"
+    0xC012B3F3,  // "⚔️" 2694-FE0F
+    0x8A730508,  // "⛷🏿" 26F7-1F3FF
+    0x85334C70,  // "☎️" 260E-FE0F
+    0x00002611,  // "☑"
+    0xA77083FB,  // "♋️" 264B-FE0F
+    0x8141D733,  // "⛹🏾" 26F9-1F3FE
+    0x85246FB3,  // "☢️" 2622-FE0F
+    0x93C38530,  // "⛹🏽" 26F9-1F3FD
+    0xC7D12A68,  // "☝🏿" 261D-1F3FF
+    0xA4B99536,  // "⛹🏿\u200d♀️" 26F9-1F3FF-200D-2640-FE0F
+    0xFF05D735,  // "⚾️" 26BE-FE0F
+    0x00002648,  // "♈"
+    0x000026F1,  // "⛱"
+    0xD25F705B,  // "♏️" 264F-FE0F
+    0x00002602,  // "☂"
+    0xF13352DD,  // "☝🏼" 261D-1F3FC
+    0x00002650,  // "♐"
+    0xE891FF0D,  // "♒️" 2652-FE0F
+    0xC733C8D4,  // "⛹🏼" 26F9-1F3FC
+    0x0000264B,  // "♋"
+    0x9E9D432E,  // "⛹🏽\u200d♂️" 26F9-1F3FD-200D-2642-FE0F
+    0xBF48B685,  // "⛅️" 26C5-FE0F
+    0xAD332241,  // "⛳️" 26F3-FE0F
+    0x00002695,  // "⚕"
+    0x0000264E,  // "♎"
+    0xE4B4A1AF,  // "⚫️" 26AB-FE0F
+    0xD96444B2,  // "⚰️" 26B0-FE0F
+    0x0000261D,  // "☝"
+    0xEED4AE96,  // "⛷🏼" 26F7-1F3FC
+    0x90BE0FAE,  // "☹️" 2639-FE0F
+    0x000026AB,  // "⚫"
+    0x9E5762CD,  // "☠️" 2620-FE0F
+    0x00002693,  // "⚓"
+    0x000026EA,  // "⛪"
+    0x00002639,  // "☹"
+    0xA9CE3ED4,  // "⛷🏾" 26F7-1F3FE
+    0xE7F44541,  // "♦️" 2666-FE0F
+    0x00002694,  // "⚔"
+    0x0000264C,  // "♌"
+    0xB5AC4DCB,  // "♠️" 2660-FE0F
+    0x00002623,  // "☣"
+    0xB4DEE30C,  // "☃️" 2603-FE0F
+    0x00002614,  // "☔"
+    0xA08E6B68,  // "☮️" 262E-FE0F
+    0x000026F7,  // "⛷"
+    0xD83CD761,  // "⛩️" 26E9-FE0F
+    0x00002651,  // "♑"
+    0x00002603,  // "☃"
+    0x97DB6241,  // "♍️" 264D-FE0F
+    0x84E2E969,  // "⚛️" 269B-FE0F
+    0x98553F62,  // "⛱️" 26F1-FE0F
+    0xC8B0E2B9,  // "♟️" 265F-FE0F
+    0x000026CE,  // "⛎"
+    0x000026B1,  // "⚱"
+    0x80EFA7A0,  // "⛷️" 26F7-FE0F
+    0x00002699,  // "⚙"
+    0xA1345C50,  // "⛹️" 26F9-FE0F
+    0x000026C5,  // "⛅"
+    0xF5069D03,  // "⛑️" 26D1-FE0F
+    0x000026CF,  // "⛏"
+    0x0000269C,  // "⚜"
+    0x00002665,  // "♥"
+    0x000026FD,  // "⛽"
+    0xA222547C,  // "☝🏾" 261D-1F3FE
+    0xE7D3F653,  // "⚕️" 2695-FE0F
+    0xF48F30EE,  // "⛹🏾\u200d♀️" 26F9-1F3FE-200D-2640-FE0F
+    0x00002601,  // "☁"
+    0xA33CD4E4,  // "♿️" 267F-FE0F
+    0xCC796DDB,  // "⛹🏾\u200d♂️" 26F9-1F3FE-200D-2642-FE0F
+    0xCF09379A,  // "⛷🏽" 26F7-1F3FD
+    0x8C66D270,  // "☯️" 262F-FE0F
+    0x00002660,  // "♠"
+    0xBFAB4829,  // "⚪️" 26AA-FE0F
+    0x95C8E0E7,  // "☕️" 2615-FE0F
+    0xB21581DB,  // "⛏️" 26CF-FE0F
+    0xCE73F3E1,  // "⛷🏻" 26F7-1F3FB
+    0xF6032501,  // "♓️" 2653-FE0F
+    0xB2DD84E7,  // "☑️" 2611-FE0F
+    0xBD4326B9,  // "⛰️" 26F0-FE0F
+    0x000026F4,  // "⛴"
+    0x000026AA,  // "⚪"
+    0x000026D3,  // "⛓"
+    0xA73C9AFA,  // "⛹🏻" 26F9-1F3FB
+    0x8FE9094A,  // "☁️" 2601-FE0F
+    0x000026A7,  // "⚧"
+    0x00002604,  // "☄"
+    0x0000264F,  // "♏"
+    0x0000262E,  // "☮"
+];
+
 /// Lookup table of blit pattern offsets; sort matches HASH_MISCELLANEOUS_SYMBOLS
-const OFFSET_MISCELLANEOUS_SYMBOLS: [usize; 192] = [
+static OFFSET_MISCELLANEOUS_SYMBOLS: [usize; 192] = [
     86897, // "♐️" 2650-FE0F
     88865, // "⛹🏿\u200d♂️" 26F9-1F3FF-200D-2642-FE0F
     86606, // "♂️" 2642-FE0F
@@ -1055,15 +1489,23 @@ const OFFSET_MISCELLANEOUS_SYMBOLS: [usize; 192] = [
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_dingbats(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_DINGBATS.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_DINGBATS[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_DINGBATS.binary_search(&hash) {
+        Ok(index) if KEY_DINGBATS[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_DINGBATS[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_DINGBATS
-const HASH_DINGBATS: [u32; 73] = [
+static HASH_DINGBATS: [u32; 73] = [
     0x04181FFC,  // "➰"
     0x0E875CC7,  // "✍🏻" 270D-1F3FB
     0x109EBC40,  // "✋🏾" 270B-1F3FE
@@ -1139,8 +1581,85 @@ const HASH_DINGBATS: [u32; 73] = [
     0xFE6F3735,  // "❣"
 ];
 
+/// Verification keys to rule out hash collisions; sort matches HASH_DINGBATS
+static KEY_DINGBATS: [u32; 73] = [
+    0x000027B0,  // "➰"
+    0xCF6452BE,  // "✍🏻" 270D-1F3FB
+    0xC82975AB,  // "✋🏾" 270B-1F3FE
+    0x0000270C,  // "✌"
+    0xF531B642,  // "✌🏽" 270C-1F3FD
+    0x00002795,  // "➕"
+    0x00002733,  // "✳"
+    0x8FBD71E0,  // "✌🏼" 270C-1F3FC
+    0xD27D6D3E,  // "✌🏾" 270C-1F3FE
+    0xFC967224,  // "✊🏼" 270A-1F3FC
+    0xE5EAE0BF,  // "✌️" 270C-FE0F
+    0x00002716,  // "✖"
+    0x00002755,  // "❕"
+    0x9A5C8B19,  // "✈️" 2708-FE0F
+    0x00002744,  // "❄"
+    0x00002714,  // "✔"
+    0xF463D344,  // "✉️" 2709-FE0F
+    0x00002728,  // "✨"
+    0xCEBF0529,  // "➡️" 27A1-FE0F
+    0x00002764,  // "❤"
+    0x9245571E,  // "✋🏽" 270B-1F3FD
+    0x0000270B,  // "✋"
+    0xF5FDC40E,  // "✌🏻" 270C-1F3FB
+    0x959C3413,  // "✍️" 270D-FE0F
+    0x00002747,  // "❇"
+    0x00002709,  // "✉"
+    0xFE8269D8,  // "✋🏻" 270B-1F3FB
+    0x97BDECD2,  // "❓️" 2753-FE0F
+    0xD0597A12,  // "✍🏽" 270D-1F3FD
+    0xB0074664,  // "✊🏾" 270A-1F3FE
+    0x8201DDDA,  // "✍🏼" 270D-1F3FC
+    0x0000270F,  // "✏"
+    0x00002702,  // "✂"
+    0x99DF665A,  // "✌🏿" 270C-1F3FF
+    0xACABD214,  // "❤️" 2764-FE0F
+    0x00002796,  // "➖"
+    0x0000270D,  // "✍"
+    0x840ED052,  // "✂️" 2702-FE0F
+    0xA0FF4858,  // "✴️" 2734-FE0F
+    0x00002753,  // "❓"
+    0x0000274C,  // "❌"
+    0x00002712,  // "✒"
+    0x0000274E,  // "❎"
+    0x00002797,  // "➗"
+    0x00002757,  // "❗"
+    0xFEC8F54C,  // "❄️" 2744-FE0F
+    0xF51E995F,  // "✊🏿" 270A-1F3FF
+    0x86053C6D,  // "✋🏼" 270B-1F3FC
+    0x00002721,  // "✡"
+    0xA65BD3D9,  // "❇️" 2747-FE0F
+    0xFD4B7BF7,  // "✍🏿" 270D-1F3FF
+    0x00002734,  // "✴"
+    0xFFF19A48,  // "✡️" 2721-FE0F
+    0x00002705,  // "✅"
+    0x0000271D,  // "✝"
+    0x000027A1,  // "➡"
+    0x000027BF,  // "➿"
+    0xF133EE53,  // "✔️" 2714-FE0F
+    0x00002754,  // "❔"
+    0xED838F90,  // "✏️" 270F-FE0F
+    0xFFE9F7F5,  // "✖️" 2716-FE0F
+    0x00002708,  // "✈"
+    0x8B5B5E39,  // "✋🏿" 270B-1F3FF
+    0xACDA3CA2,  // "✒️" 2712-FE0F
+    0xB5E80092,  // "❗️" 2757-FE0F
+    0x0000270A,  // "✊"
+    0xF064FF4C,  // "✊🏻" 270A-1F3FB
+    0xFB37F066,  // "✊🏽" 270A-1F3FD
+    0xFB0FF300,  // "❣️" 2763-FE0F
+    0xAEC1A8C6,  // "✝️" 271D-FE0F
+    0x82D33409,  // "✳️" 2733-FE0F
+    0xC240AB26,  // "✍🏾" 270D-1F3FE
+    0x00002763,  // "❣"
+];
+
 /// Lookup table of blit pattern offsets; sort matches HASH_DINGBATS
-const OFFSET_DINGBATS: [usize; 73] = [
+static OFFSET_DINGBATS: [usize; 73] = [
     90345, // "➰"
     89596, // "✍🏻" 270D-1F3FB
     89411, // "✋🏾" 270B-1F3FE
@@ -1216,44 +1735,73 @@ const OFFSET_DINGBATS: [usize; 73] = [
     90203, // "❣"
 ];
 
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_supplemental_arrows_b_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_SUPPLEMENTAL_ARROWS_B, 0x2934, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x2934..=0x2935, or NO_GLYPH
+static CODEPOINT_SUPPLEMENTAL_ARROWS_B: [u32; 2] = [
+    90395, // "⤴"
+    90428, // "⤵"
+];
+
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_supplemental_arrows_b(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_SUPPLEMENTAL_ARROWS_B.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_SUPPLEMENTAL_ARROWS_B[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_SUPPLEMENTAL_ARROWS_B.binary_search(&hash) {
+        Ok(index) if KEY_SUPPLEMENTAL_ARROWS_B[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_SUPPLEMENTAL_ARROWS_B[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_SUPPLEMENTAL_ARROWS_B
-const HASH_SUPPLEMENTAL_ARROWS_B: [u32; 4] = [
+static HASH_SUPPLEMENTAL_ARROWS_B: [u32; 2] = [
     0x9A705AEA,  // "⤵️" 2935-FE0F
-    0xAA137D17,  // "⤴"
-    0xBAA3370F,  // "⤵"
     0xCE796779,  // "⤴️" 2934-FE0F
 ];
 
+/// Verification keys to rule out hash collisions; sort matches HASH_SUPPLEMENTAL_ARROWS_B
+static KEY_SUPPLEMENTAL_ARROWS_B: [u32; 2] = [
+    0xC302CCD5,  // "⤵️" 2935-FE0F
+    0xC1DBE0AD,  // "⤴️" 2934-FE0F
+];
+
 /// Lookup table of blit pattern offsets; sort matches HASH_SUPPLEMENTAL_ARROWS_B
-const OFFSET_SUPPLEMENTAL_ARROWS_B: [usize; 4] = [
+static OFFSET_SUPPLEMENTAL_ARROWS_B: [usize; 2] = [
     90428, // "⤵️" 2935-FE0F
-    90395, // "⤴"
-    90428, // "⤵"
     90395, // "⤴️" 2934-FE0F
 ];
 
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_miscellaneous_symbols_and_arrows(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_MISCELLANEOUS_SYMBOLS_AND_ARROWS.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_MISCELLANEOUS_SYMBOLS_AND_ARROWS[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_MISCELLANEOUS_SYMBOLS_AND_ARROWS.binary_search(&hash) {
+        Ok(index) if KEY_MISCELLANEOUS_SYMBOLS_AND_ARROWS[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_MISCELLANEOUS_SYMBOLS_AND_ARROWS[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_MISCELLANEOUS_SYMBOLS_AND_ARROWS
-const HASH_MISCELLANEOUS_SYMBOLS_AND_ARROWS: [u32; 14] = [
+static HASH_MISCELLANEOUS_SYMBOLS_AND_ARROWS: [u32; 14] = [
     0x03354678,  // "⬆"
     0x67B8E222,  // "⬛"
     0x6A74875D,  // "⬇"
@@ -1270,8 +1818,26 @@ const HASH_MISCELLANEOUS_SYMBOLS_AND_ARROWS: [u32; 14] = [
     0xFEDA4295,  // "⭐"
 ];
 
+/// Verification keys to rule out hash collisions; sort matches HASH_MISCELLANEOUS_SYMBOLS_AND_ARROWS
+static KEY_MISCELLANEOUS_SYMBOLS_AND_ARROWS: [u32; 14] = [
+    0x00002B06,  // "⬆"
+    0x00002B1B,  // "⬛"
+    0x00002B07,  // "⬇"
+    0xD0F0C61D,  // "⭕️" 2B55-FE0F
+    0xA7C74C4D,  // "⬆️" 2B06-FE0F
+    0x00002B05,  // "⬅"
+    0xCC943679,  // "⬛️" 2B1B-FE0F
+    0xCCA72617,  // "⬇️" 2B07-FE0F
+    0x00002B55,  // "⭕"
+    0xD781B6EE,  // "⬜️" 2B1C-FE0F
+    0x00002B1C,  // "⬜"
+    0xC9AF18E6,  // "⬅️" 2B05-FE0F
+    0xDAD291B1,  // "⭐️" 2B50-FE0F
+    0x00002B50,  // "⭐"
+];
+
 /// Lookup table of blit pattern offsets; sort matches HASH_MISCELLANEOUS_SYMBOLS_AND_ARROWS
-const OFFSET_MISCELLANEOUS_SYMBOLS_AND_ARROWS: [usize; 14] = [
+static OFFSET_MISCELLANEOUS_SYMBOLS_AND_ARROWS: [usize; 14] = [
     90525, // "⬆"
     90589, // "⬛"
     90557, // "⬇"
@@ -1291,129 +1857,176 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_ARROWS: [usize; 14] = [
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_cjk_symbols_and_punctuation(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_CJK_SYMBOLS_AND_PUNCTUATION.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_CJK_SYMBOLS_AND_PUNCTUATION[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_CJK_SYMBOLS_AND_PUNCTUATION.binary_search(&hash) {
+        Ok(index) if KEY_CJK_SYMBOLS_AND_PUNCTUATION[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_CJK_SYMBOLS_AND_PUNCTUATION[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_CJK_SYMBOLS_AND_PUNCTUATION
-const HASH_CJK_SYMBOLS_AND_PUNCTUATION: [u32; 4] = [
+static HASH_CJK_SYMBOLS_AND_PUNCTUATION: [u32; 4] = [
     0x8237E8AC,  // "〰"
     0xB2CC2921,  // "〰️" 3030-FE0F
     0xB69C75CA,  // "〽️" 303D-FE0F
     0xCD2BC3CF,  // "〽"
 ];
 
+/// Verification keys to rule out hash collisions; sort matches HASH_CJK_SYMBOLS_AND_PUNCTUATION
+static KEY_CJK_SYMBOLS_AND_PUNCTUATION: [u32; 4] = [
+    0x00003030,  // "〰"
+    0xC2E666B1,  // "〰️" 3030-FE0F
+    0xDAB3C492,  // "〽️" 303D-FE0F
+    0x0000303D,  // "〽"
+];
+
 /// Lookup table of blit pattern offsets; sort matches HASH_CJK_SYMBOLS_AND_PUNCTUATION
-const OFFSET_CJK_SYMBOLS_AND_PUNCTUATION: [usize; 4] = [
+static OFFSET_CJK_SYMBOLS_AND_PUNCTUATION: [usize; 4] = [
     90742, // "〰"
     90742, // "〰️" 3030-FE0F
     90752, // "〽️" 303D-FE0F
     90752, // "〽"
 ];
 
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_enclosed_cjk_letters_and_months_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_ENCLOSED_CJK_LETTERS_AND_MONTHS, 0x3297, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x3297..=0x3299, or NO_GLYPH
+static CODEPOINT_ENCLOSED_CJK_LETTERS_AND_MONTHS: [u32; 3] = [
+    90839, // "㊗"
+    super::NO_GLYPH,  // U+3298
+    90871, // "㊙"
+];
+
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_enclosed_cjk_letters_and_months(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_ENCLOSED_CJK_LETTERS_AND_MONTHS.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_ENCLOSED_CJK_LETTERS_AND_MONTHS[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_ENCLOSED_CJK_LETTERS_AND_MONTHS.binary_search(&hash) {
+        Ok(index) if KEY_ENCLOSED_CJK_LETTERS_AND_MONTHS[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_ENCLOSED_CJK_LETTERS_AND_MONTHS[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_ENCLOSED_CJK_LETTERS_AND_MONTHS
-const HASH_ENCLOSED_CJK_LETTERS_AND_MONTHS: [u32; 4] = [
-    0x47B35CE1,  // "㊗"
+static HASH_ENCLOSED_CJK_LETTERS_AND_MONTHS: [u32; 2] = [
     0x764C4E31,  // "㊗️" 3297-FE0F
     0x76EE2EB1,  // "㊙️" 3299-FE0F
-    0xFD600E5E,  // "㊙"
+];
+
+/// Verification keys to rule out hash collisions; sort matches HASH_ENCLOSED_CJK_LETTERS_AND_MONTHS
+static KEY_ENCLOSED_CJK_LETTERS_AND_MONTHS: [u32; 2] = [
+    0xC8ED4933,  // "㊗️" 3297-FE0F
+    0xE86E4E98,  // "㊙️" 3299-FE0F
 ];
 
 /// Lookup table of blit pattern offsets; sort matches HASH_ENCLOSED_CJK_LETTERS_AND_MONTHS
-const OFFSET_ENCLOSED_CJK_LETTERS_AND_MONTHS: [usize; 4] = [
-    90839, // "㊗"
+static OFFSET_ENCLOSED_CJK_LETTERS_AND_MONTHS: [usize; 2] = [
     90839, // "㊗️" 3297-FE0F
     90871, // "㊙️" 3299-FE0F
-    90871, // "㊙"
 ];
 
-/// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
-/// Only attempt to match grapheme clusters of length limit codepoints.
-fn find_private_use_area(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_PRIVATE_USE_AREA.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_PRIVATE_USE_AREA[index], bytes_hashed)),
-        _ => None,
-    }
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_private_use_area_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_PRIVATE_USE_AREA, 0xE50A, cluster)
 }
 
-/// Index of murmur3(grapheme cluster); sort matches OFFSET_PRIVATE_USE_AREA
-const HASH_PRIVATE_USE_AREA: [u32; 1] = [
-    0xD1A64133,  // "\ue50a"
+/// Blit pattern offsets for codepoints 0xE50A..=0xE50A, or NO_GLYPH
+static CODEPOINT_PRIVATE_USE_AREA: [u32; 1] = [
+    91196, // "\ue50a"
 ];
 
-/// Lookup table of blit pattern offsets; sort matches HASH_PRIVATE_USE_AREA
-const OFFSET_PRIVATE_USE_AREA: [usize; 1] = [
-    91196, // "\ue50a"
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_mahjong_tiles_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_MAHJONG_TILES, 0x1F004, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x1F004..=0x1F004, or NO_GLYPH
+static CODEPOINT_MAHJONG_TILES: [u32; 1] = [
+    0,     // "🀄"
 ];
 
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_mahjong_tiles(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_MAHJONG_TILES.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_MAHJONG_TILES[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_MAHJONG_TILES.binary_search(&hash) {
+        Ok(index) if KEY_MAHJONG_TILES[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_MAHJONG_TILES[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_MAHJONG_TILES
-const HASH_MAHJONG_TILES: [u32; 2] = [
+static HASH_MAHJONG_TILES: [u32; 1] = [
     0x3A370578,  // "🀄️" 1F004-FE0F
-    0x81CB1E6B,  // "🀄"
+];
+
+/// Verification keys to rule out hash collisions; sort matches HASH_MAHJONG_TILES
+static KEY_MAHJONG_TILES: [u32; 1] = [
+    0xA086078C,  // "🀄️" 1F004-FE0F
 ];
 
 /// Lookup table of blit pattern offsets; sort matches HASH_MAHJONG_TILES
-const OFFSET_MAHJONG_TILES: [usize; 2] = [
+static OFFSET_MAHJONG_TILES: [usize; 1] = [
     0,    // "🀄️" 1F004-FE0F
-    0,    // "🀄"
 ];
 
-/// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
-/// Only attempt to match grapheme clusters of length limit codepoints.
-fn find_playing_cards(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_PLAYING_CARDS.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_PLAYING_CARDS[index], bytes_hashed)),
-        _ => None,
-    }
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_playing_cards_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_PLAYING_CARDS, 0x1F0CF, cluster)
 }
 
-/// Index of murmur3(grapheme cluster); sort matches OFFSET_PLAYING_CARDS
-const HASH_PLAYING_CARDS: [u32; 1] = [
-    0x77DD1D57,  // "🃏"
-];
-
-/// Lookup table of blit pattern offsets; sort matches HASH_PLAYING_CARDS
-const OFFSET_PLAYING_CARDS: [usize; 1] = [
-    24,   // "🃏"
+/// Blit pattern offsets for codepoints 0x1F0CF..=0x1F0CF, or NO_GLYPH
+static CODEPOINT_PLAYING_CARDS: [u32; 1] = [
+    24,    // "🃏"
 ];
 
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_enclosed_alphanumeric_supplement(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_ENCLOSED_ALPHANUMERIC_SUPPLEMENT.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_ENCLOSED_ALPHANUMERIC_SUPPLEMENT[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_ENCLOSED_ALPHANUMERIC_SUPPLEMENT.binary_search(&hash) {
+        Ok(index) if KEY_ENCLOSED_ALPHANUMERIC_SUPPLEMENT[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_ENCLOSED_ALPHANUMERIC_SUPPLEMENT[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_ENCLOSED_ALPHANUMERIC_SUPPLEMENT
-const HASH_ENCLOSED_ALPHANUMERIC_SUPPLEMENT: [u32; 303] = [
+static HASH_ENCLOSED_ALPHANUMERIC_SUPPLEMENT: [u32; 303] = [
     0x00FBB95E,  // "🇲🇱" 1F1F2-1F1F1
     0x02ADA3E8,  // "🇪🇷" 1F1EA-1F1F7
     0x035FAE53,  // "🇹🇨" 1F1F9-1F1E8
@@ -1719,8 +2332,315 @@ const HASH_ENCLOSED_ALPHANUMERIC_SUPPLEMENT: [u32; 303] = [
     0xFD5F5383,  // "🇦🇽" 1F1E6-1F1FD
 ];
 
+/// Verification keys to rule out hash collisions; sort matches HASH_ENCLOSED_ALPHANUMERIC_SUPPLEMENT
+static KEY_ENCLOSED_ALPHANUMERIC_SUPPLEMENT: [u32; 303] = [
+    0xFAA20606,  // "🇲🇱" 1F1F2-1F1F1
+    0xC860FDA9,  // "🇪🇷" 1F1EA-1F1F7
+    0xBF83BF1E,  // "🇹🇨" 1F1F9-1F1E8
+    0xF71BA0F1,  // "🇧🇶" 1F1E7-1F1F6
+    0xFF1BBC4B,  // "🇵🇪" 1F1F5-1F1EA
+    0xD7BE7B9C,  // "🇳🇿" 1F1F3-1F1FF
+    0x8A9B57C0,  // "🇰🇪" 1F1F0-1F1EA
+    0x8C357F1D,  // "🇲🇺" 1F1F2-1F1FA
+    0xB68D44FA,  // "🇲🇵" 1F1F2-1F1F5
+    0xDF66118C,  // "🇸🇬" 1F1F8-1F1EC
+    0xCA461D6A,  // "🇦🇴" 1F1E6-1F1F4
+    0x93D8578C,  // "🇧🇿" 1F1E7-1F1FF
+    0xDF271EB2,  // "🇸🇧" 1F1F8-1F1E7
+    0x935DB85D,  // "🇦🇹" 1F1E6-1F1F9
+    0xA4981B0A,  // "🇲🇲" 1F1F2-1F1F2
+    0xC3019303,  // "🇹🇯" 1F1F9-1F1EF
+    0x9775427A,  // "🇦🇼" 1F1E6-1F1FC
+    0x93D7024C,  // "🇲🇭" 1F1F2-1F1ED
+    0xC9445FD0,  // "🇲🇶" 1F1F2-1F1F6
+    0x9C20C293,  // "🇺🇿" 1F1FA-1F1FF
+    0xD1F826A0,  // "🇧🇹" 1F1E7-1F1F9
+    0xD6A75B36,  // "🇱🇧" 1F1F1-1F1E7
+    0xDAE4D72A,  // "🇰🇵" 1F1F0-1F1F5
+    0x9B5418B7,  // "🇳🇮" 1F1F3-1F1EE
+    0x848151B3,  // "🇹🇲" 1F1F9-1F1F2
+    0xF3343E8B,  // "🇨🇰" 1F1E8-1F1F0
+    0xE33793DD,  // "🇵🇭" 1F1F5-1F1ED
+    0xB7723816,  // "🇧🇸" 1F1E7-1F1F8
+    0x89D8E82E,  // "🇧🇪" 1F1E7-1F1EA
+    0xF874A0E5,  // "🇨🇾" 1F1E8-1F1FE
+    0x9B93103D,  // "🇸🇾" 1F1F8-1F1FE
+    0xC77742CF,  // "🇦🇨" 1F1E6-1F1E8
+    0xD034BE23,  // "🇹🇻" 1F1F9-1F1FB
+    0xE9486130,  // "🇳🇬" 1F1F3-1F1EC
+    0x0001F18E,  // "🆎"
+    0xE23A3558,  // "🇱🇾" 1F1F1-1F1FE
+    0x9E724C81,  // "🇭🇷" 1F1ED-1F1F7
+    0xC57A4F76,  // "🇬🇶" 1F1EC-1F1F6
+    0xDAA7D623,  // "🇧🇮" 1F1E7-1F1EE
+    0xAE99C7B4,  // "🇨🇲" 1F1E8-1F1F2
+    0x8CB21EFF,  // "🇬🇷" 1F1EC-1F1F7
+    0xA054817C,  // "🇲🇷" 1F1F2-1F1F7
+    0x926FB176,  // "🅰️" 1F170-FE0F
+    0xACF4E430,  // "🇪🇹" 1F1EA-1F1F9
+    0xC30CA4BF,  // "🇱🇰" 1F1F1-1F1F0
+    0xF271E292,  // "🇧🇯" 1F1E7-1F1EF
+    0x9FF76EC6,  // "🇫🇴" 1F1EB-1F1F4
+    0xB152E991,  // "🇲🇿" 1F1F2-1F1FF
+    0x0001F1FC,  // "🇼"
+    0x90F21C0A,  // "🇬🇲" 1F1EC-1F1F2
+    0x8C67DEE3,  // "🇷🇺" 1F1F7-1F1FA
+    0xC10F45BE,  // "🇭🇰" 1F1ED-1F1F0
+    0x0001F1F0,  // "🇰"
+    0xA1ED36F7,  // "🇲🇰" 1F1F2-1F1F0
+    0x0001F1FB,  // "🇻"
+    0x0001F192,  // "🆒"
+    0x0001F196,  // "🆖"
+    0x99231206,  // "🇧🇾" 1F1E7-1F1FE
+    0xAF23C3EE,  // "🇧🇭" 1F1E7-1F1ED
+    0x0001F17F,  // "🅿"
+    0xB7511C1E,  // "🇬🇪" 1F1EC-1F1EA
+    0xA3E8AFE5,  // "🇺🇸" 1F1FA-1F1F8
+    0xAEFA1088,  // "🇬🇬" 1F1EC-1F1EC
+    0x0001F1F9,  // "🇹"
+    0xCAD26130,  // "🇬🇭" 1F1EC-1F1ED
+    0xB8A4E6F5,  // "🇵🇼" 1F1F5-1F1FC
+    0xE53BE33D,  // "🇵🇾" 1F1F5-1F1FE
+    0x94A2511C,  // "🇧🇼" 1F1E7-1F1FC
+    0xE96D38F4,  // "🇴🇲" 1F1F4-1F1F2
+    0xAB0DFCDC,  // "🇸🇲" 1F1F8-1F1F2
+    0xF59B727A,  // "🇨🇻" 1F1E8-1F1FB
+    0xD015D37F,  // "🇧🇳" 1F1E7-1F1F3
+    0xFB8622EC,  // "🇹🇷" 1F1F9-1F1F7
+    0x0001F170,  // "🅰"
+    0xDCAA07D3,  // "🇻🇬" 1F1FB-1F1EC
+    0x90375147,  // "🅿️" 1F17F-FE0F
+    0x9AC22E0D,  // "🇸🇻" 1F1F8-1F1FB
+    0xFF356DFA,  // "🇻🇮" 1F1FB-1F1EE
+    0x0001F1FD,  // "🇽"
+    0x0001F195,  // "🆕"
+    0xD2AFBF63,  // "🇷🇴" 1F1F7-1F1F4
+    0x0001F1FF,  // "🇿"
+    0xCEE164BC,  // "🇸🇰" 1F1F8-1F1F0
+    0x0001F1F6,  // "🇶"
+    0xDAAA304F,  // "🇪🇭" 1F1EA-1F1ED
+    0x0001F199,  // "🆙"
+    0xF9E1B8C0,  // "🇵🇳" 1F1F5-1F1F3
+    0xAF72F5FE,  // "🇳🇷" 1F1F3-1F1F7
+    0xE1CB150C,  // "🇮🇸" 1F1EE-1F1F8
+    0xE6750592,  // "🇱🇦" 1F1F1-1F1E6
+    0xBCE1A1F1,  // "🇧🇲" 1F1E7-1F1F2
+    0xBD876619,  // "🇸🇳" 1F1F8-1F1F3
+    0xC9840781,  // "🇯🇵" 1F1EF-1F1F5
+    0xFD212713,  // "🇺🇳" 1F1FA-1F1F3
+    0xB59215D7,  // "🇧🇷" 1F1E7-1F1F7
+    0x91ABDD26,  // "🇸🇽" 1F1F8-1F1FD
+    0xE340E04C,  // "🇿🇲" 1F1FF-1F1F2
+    0xFBE128B2,  // "🇦🇫" 1F1E6-1F1EB
+    0xB7EDFB0A,  // "🇹🇫" 1F1F9-1F1EB
+    0x9AA7A658,  // "🇮🇹" 1F1EE-1F1F9
+    0xE4596BEF,  // "🇬🇫" 1F1EC-1F1EB
+    0xA0D3A4EC,  // "🇵🇸" 1F1F5-1F1F8
+    0xE656454F,  // "🇯🇴" 1F1EF-1F1F4
+    0xD0172FD4,  // "🇨🇺" 1F1E8-1F1FA
+    0xCFEA0662,  // "🇾🇹" 1F1FE-1F1F9
+    0xD71A1F61,  // "🇧🇬" 1F1E7-1F1EC
+    0x0001F1F3,  // "🇳"
+    0x9460CF99,  // "🇸🇯" 1F1F8-1F1EF
+    0x9B963AEB,  // "🇹🇩" 1F1F9-1F1E9
+    0x936B17EF,  // "🇮🇱" 1F1EE-1F1F1
+    0xDD070231,  // "🇪🇬" 1F1EA-1F1EC
+    0x8EA38D79,  // "🇪🇨" 1F1EA-1F1E8
+    0xE22915A7,  // "🇧🇧" 1F1E7-1F1E7
+    0x9466E97B,  // "🇨🇷" 1F1E8-1F1F7
+    0xA8E53AD3,  // "🇩🇪" 1F1E9-1F1EA
+    0xC0785946,  // "🇲🇨" 1F1F2-1F1E8
+    0xD10B6AFE,  // "🇯🇲" 1F1EF-1F1F2
+    0xC055285C,  // "🇱🇷" 1F1F1-1F1F7
+    0xBB09321B,  // "🇮🇷" 1F1EE-1F1F7
+    0xEAC55233,  // "🇨🇵" 1F1E8-1F1F5
+    0xDBFB5F8A,  // "🇭🇲" 1F1ED-1F1F2
+    0xB68531B4,  // "🇵🇹" 1F1F5-1F1F9
+    0xE24DDF8D,  // "🇧🇫" 1F1E7-1F1EB
+    0x0001F19A,  // "🆚"
+    0xCC31F74A,  // "🇬🇱" 1F1EC-1F1F1
+    0x0001F1EB,  // "🇫"
+    0x84ABF1F5,  // "🇸🇮" 1F1F8-1F1EE
+    0xF103B0D8,  // "🇳🇫" 1F1F3-1F1EB
+    0xB40228A7,  // "🇳🇱" 1F1F3-1F1F1
+    0x0001F1F8,  // "🇸"
+    0xF64C453C,  // "🇨🇴" 1F1E8-1F1F4
+    0xCEAC4F02,  // "🇨🇼" 1F1E8-1F1FC
+    0xB65C9E4D,  // "🇻🇺" 1F1FB-1F1FA
+    0x0001F1FA,  // "🇺"
+    0x0001F17E,  // "🅾"
+    0x8B8EF203,  // "🇧🇩" 1F1E7-1F1E9
+    0x0001F1F2,  // "🇲"
+    0xAAF4395C,  // "🇩🇬" 1F1E9-1F1EC
+    0xB941E949,  // "🇲🇻" 1F1F2-1F1FB
+    0xEDB98900,  // "🇺🇬" 1F1FA-1F1EC
+    0x9867B0B2,  // "🇬🇵" 1F1EC-1F1F5
+    0xAC912670,  // "🇹🇭" 1F1F9-1F1ED
+    0xB5C4A866,  // "🇳🇺" 1F1F3-1F1FA
+    0xC77DD3F2,  // "🇻🇪" 1F1FB-1F1EA
+    0x8F567ADE,  // "🇳🇴" 1F1F3-1F1F4
+    0x96AB933E,  // "🇮🇶" 1F1EE-1F1F6
+    0xF31A0AD7,  // "🇸🇦" 1F1F8-1F1E6
+    0xBC2F35AA,  // "🇦🇱" 1F1E6-1F1F1
+    0xCA7A7410,  // "🇲🇬" 1F1F2-1F1EC
+    0x97C96BE1,  // "🇫🇲" 1F1EB-1F1F2
+    0xA3E50616,  // "🇼🇫" 1F1FC-1F1EB
+    0xD01D46CD,  // "🇲🇸" 1F1F2-1F1F8
+    0x0001F193,  // "🆓"
+    0xA425C0E1,  // "🇺🇲" 1F1FA-1F1F2
+    0x9E880915,  // "🇩🇲" 1F1E9-1F1F2
+    0x9309C8F8,  // "🇰🇼" 1F1F0-1F1FC
+    0x96159400,  // "🇦🇺" 1F1E6-1F1FA
+    0x9CB8E347,  // "🇳🇦" 1F1F3-1F1E6
+    0xE0E5ADE0,  // "🇧🇦" 1F1E7-1F1E6
+    0x98AC7D6D,  // "🇧🇻" 1F1E7-1F1FB
+    0xA540F182,  // "🇨🇫" 1F1E8-1F1EB
+    0x9181023C,  // "🇫🇯" 1F1EB-1F1EF
+    0x9720AAB4,  // "🇼🇸" 1F1FC-1F1F8
+    0x9F2BD149,  // "🇪🇦" 1F1EA-1F1E6
+    0x9245E3B3,  // "🇦🇮" 1F1E6-1F1EE
+    0xCF660ECD,  // "🇵🇰" 1F1F5-1F1F0
+    0x94F5159D,  // "🇬🇩" 1F1EC-1F1E9
+    0x8F781BCC,  // "🇵🇲" 1F1F5-1F1F2
+    0xCBD1B0FB,  // "🇱🇹" 1F1F1-1F1F9
+    0xD92376C1,  // "🇱🇺" 1F1F1-1F1FA
+    0x0001F1EF,  // "🇯"
+    0x9E91667E,  // "🇻🇳" 1F1FB-1F1F3
+    0xBC1058A4,  // "🇿🇼" 1F1FF-1F1FC
+    0x986F9BD1,  // "🇹🇿" 1F1F9-1F1FF
+    0x0001F198,  // "🆘"
+    0xBCE5771A,  // "🇸🇱" 1F1F8-1F1F1
+    0x85D26814,  // "🇧🇱" 1F1E7-1F1F1
+    0xCF1363E7,  // "🇬🇮" 1F1EC-1F1EE
+    0xE02F28C9,  // "🇧🇴" 1F1E7-1F1F4
+    0xA09BD541,  // "🇫🇮" 1F1EB-1F1EE
+    0x8125C562,  // "🇭🇺" 1F1ED-1F1FA
+    0xDDA7BF81,  // "🇹🇬" 1F1F9-1F1EC
+    0x8B47A229,  // "🇾🇪" 1F1FE-1F1EA
+    0xDD830DA0,  // "🇸🇷" 1F1F8-1F1F7
+    0xFB64B14E,  // "🇳🇵" 1F1F3-1F1F5
+    0x8E02EFD3,  // "🇹🇹" 1F1F9-1F1F9
+    0xB73B959B,  // "🇨🇿" 1F1E8-1F1FF
+    0xF361215D,  // "🇦🇸" 1F1E6-1F1F8
+    0x82366F30,  // "🇻🇨" 1F1FB-1F1E8
+    0x831B274B,  // "🇹🇳" 1F1F9-1F1F3
+    0xAAD88957,  // "🇬🇦" 1F1EC-1F1E6
+    0xC3C02F4C,  // "🇸🇩" 1F1F8-1F1E9
+    0xCD23D041,  // "🇲🇴" 1F1F2-1F1F4
+    0xBB94F9B4,  // "🇹🇦" 1F1F9-1F1E6
+    0x98AE6711,  // "🇬🇾" 1F1EC-1F1FE
+    0xB466FD07,  // "🇦🇩" 1F1E6-1F1E9
+    0xFCF988A6,  // "🇮🇳" 1F1EE-1F1F3
+    0xB275F45B,  // "🇦🇪" 1F1E6-1F1EA
+    0xA67AEB21,  // "🇨🇱" 1F1E8-1F1F1
+    0xA9229783,  // "🅾️" 1F17E-FE0F
+    0x0001F1ED,  // "🇭"
+    0xEC5894C4,  // "🇳🇨" 1F1F3-1F1E8
+    0xCEC0904F,  // "🇿🇦" 1F1FF-1F1E6
+    0xD6BA333C,  // "🇪🇸" 1F1EA-1F1F8
+    0x0001F171,  // "🅱"
+    0xBB47060B,  // "🇲🇫" 1F1F2-1F1EB
+    0x9F53F805,  // "🇹🇴" 1F1F9-1F1F4
+    0x0001F1FE,  // "🇾"
+    0xB3D30FC9,  // "🇸🇸" 1F1F8-1F1F8
+    0xE30CA289,  // "🇲🇦" 1F1F2-1F1E6
+    0x9954E0B9,  // "🇸🇹" 1F1F8-1F1F9
+    0x98AA019E,  // "🇻🇦" 1F1FB-1F1E6
+    0x9FF3B08C,  // "🇲🇳" 1F1F2-1F1F3
+    0xDE3D7213,  // "🇶🇦" 1F1F6-1F1E6
+    0xACCE33D3,  // "🇰🇳" 1F1F0-1F1F3
+    0xB6E617F6,  // "🇰🇾" 1F1F0-1F1FE
+    0xA8939597,  // "🇲🇩" 1F1F2-1F1E9
+    0x0001F1EA,  // "🇪"
+    0xD0A9003D,  // "🇬🇧" 1F1EC-1F1E7
+    0xE46D8B43,  // "🇺🇾" 1F1FA-1F1FE
+    0x0001F1F7,  // "🇷"
+    0xFDA05DA3,  // "🇸🇭" 1F1F8-1F1ED
+    0x0001F1F5,  // "🇵"
+    0xB1FAAC24,  // "🇷🇸" 1F1F7-1F1F8
+    0xB3C1F01B,  // "🇦🇷" 1F1E6-1F1F7
+    0xDFD76865,  // "🇲🇾" 1F1F2-1F1FE
+    0xD33014C6,  // "🇩🇿" 1F1E9-1F1FF
+    0x0001F1F4,  // "🇴"
+    0xE5EE59E0,  // "🇸🇴" 1F1F8-1F1F4
+    0x8DFBB2D3,  // "🇯🇪" 1F1EF-1F1EA
+    0xE62DD222,  // "🇬🇺" 1F1EC-1F1FA
+    0x8FB71FA7,  // "🇮🇪" 1F1EE-1F1EA
+    0xC06251E3,  // "🇱🇻" 1F1F1-1F1FB
+    0x86689CBF,  // "🇰🇬" 1F1F0-1F1EC
+    0xB93D82BF,  // "🇱🇨" 1F1F1-1F1E8
+    0xF3A214E0,  // "🇨🇨" 1F1E8-1F1E8
+    0xA5536FA3,  // "🇰🇭" 1F1F0-1F1ED
+    0x87308E27,  // "🅱️" 1F171-FE0F
+    0x850589B3,  // "🇳🇪" 1F1F3-1F1EA
+    0xEA963D56,  // "🇵🇦" 1F1F5-1F1E6
+    0xD90E8EA3,  // "🇰🇮" 1F1F0-1F1EE
+    0xA7CCD626,  // "🇰🇿" 1F1F0-1F1FF
+    0x9F3570E2,  // "🇽🇰" 1F1FD-1F1F0
+    0xF7A2BA1B,  // "🇩🇴" 1F1E9-1F1F4
+    0x0001F1E6,  // "🇦"
+    0x98F63C6E,  // "🇵🇬" 1F1F5-1F1EC
+    0x0001F194,  // "🆔"
+    0x9DDB5BAD,  // "🇦🇬" 1F1E6-1F1EC
+    0xD15A204D,  // "🇮🇩" 1F1EE-1F1E9
+    0xE7AF5AE3,  // "🇱🇮" 1F1F1-1F1EE
+    0x82582F56,  // "🇰🇷" 1F1F0-1F1F7
+    0xA7068B86,  // "🇲🇪" 1F1F2-1F1EA
+    0xB7B2278D,  // "🇬🇹" 1F1EC-1F1F9
+    0xB4889667,  // "🇷🇼" 1F1F7-1F1FC
+    0xF38BB6EE,  // "🇮🇲" 1F1EE-1F1F2
+    0xCD9F0582,  // "🇦🇲" 1F1E6-1F1F2
+    0x0001F1E8,  // "🇨"
+    0xB5C9DDD4,  // "🇨🇬" 1F1E8-1F1EC
+    0x96A4F1E6,  // "🇫🇰" 1F1EB-1F1F0
+    0xBC149A32,  // "🇨🇩" 1F1E8-1F1E9
+    0x9419BFF1,  // "🇵🇱" 1F1F5-1F1F1
+    0xDD4541B9,  // "🇸🇿" 1F1F8-1F1FF
+    0x0001F1E9,  // "🇩"
+    0xFE64B8EE,  // "🇹🇱" 1F1F9-1F1F1
+    0x0001F1EE,  // "🇮"
+    0xFF757213,  // "🇸🇨" 1F1F8-1F1E8
+    0x0001F1E7,  // "🇧"
+    0x9E333951,  // "🇦🇿" 1F1E6-1F1FF
+    0xE312CA00,  // "🇹🇰" 1F1F9-1F1F0
+    0xBD4A8A84,  // "🇬🇸" 1F1EC-1F1F8
+    0xBA5C6E82,  // "🇩🇰" 1F1E9-1F1F0
+    0xDECBED7A,  // "🇫🇷" 1F1EB-1F1F7
+    0xEF3580B2,  // "🇭🇳" 1F1ED-1F1F3
+    0x9CE916F6,  // "🇨🇳" 1F1E8-1F1F3
+    0xF61BD967,  // "🇲🇽" 1F1F2-1F1FD
+    0xBB715F50,  // "🇬🇼" 1F1EC-1F1FC
+    0x9EC97DD7,  // "🇩🇯" 1F1E9-1F1EF
+    0xC8B45610,  // "🇸🇪" 1F1F8-1F1EA
+    0xF4AB0BBA,  // "🇲🇹" 1F1F2-1F1F9
+    0x0001F191,  // "🆑"
+    0xB3E15A0C,  // "🇭🇹" 1F1ED-1F1F9
+    0x8AA215D4,  // "🇦🇶" 1F1E6-1F1F6
+    0x8E1FD71A,  // "🇲🇼" 1F1F2-1F1FC
+    0xC80DAF09,  // "🇪🇪" 1F1EA-1F1EA
+    0xF85ECAF3,  // "🇮🇴" 1F1EE-1F1F4
+    0xFAEFA8FB,  // "🇨🇮" 1F1E8-1F1EE
+    0xDF8071EA,  // "🇮🇨" 1F1EE-1F1E8
+    0x8BB38ED4,  // "🇪🇺" 1F1EA-1F1FA
+    0xB28F8460,  // "🇱🇸" 1F1F1-1F1F8
+    0x86D7EAD5,  // "🇵🇫" 1F1F5-1F1EB
+    0xAB779130,  // "🇹🇼" 1F1F9-1F1FC
+    0x962F5039,  // "🇰🇲" 1F1F0-1F1F2
+    0xB90C7479,  // "🇨🇦" 1F1E8-1F1E6
+    0x0001F1F1,  // "🇱"
+    0x0001F197,  // "🆗"
+    0x0001F1EC,  // "🇬"
+    0xB09597BF,  // "🇵🇷" 1F1F5-1F1F7
+    0xB373B9B8,  // "🇨🇽" 1F1E8-1F1FD
+    0xD74E5E48,  // "🇨🇭" 1F1E8-1F1ED
+    0xEB23E8DC,  // "🇷🇪" 1F1F7-1F1EA
+    0xF47BAA70,  // "🇬🇳" 1F1EC-1F1F3
+    0xA3EE89BF,  // "🇺🇦" 1F1FA-1F1E6
+    0xE2BC28DA,  // "🇦🇽" 1F1E6-1F1FD
+];
+
 /// Lookup table of blit pattern offsets; sort matches HASH_ENCLOSED_ALPHANUMERIC_SUPPLEMENT
-const OFFSET_ENCLOSED_ALPHANUMERIC_SUPPLEMENT: [usize; 303] = [
+static OFFSET_ENCLOSED_ALPHANUMERIC_SUPPLEMENT: [usize; 303] = [
     4498, // "🇲🇱" 1F1F2-1F1F1
     2334, // "🇪🇷" 1F1EA-1F1F7
     6402, // "🇹🇨" 1F1F9-1F1E8
@@ -2029,15 +2949,23 @@ const OFFSET_ENCLOSED_ALPHANUMERIC_SUPPLEMENT: [usize; 303] = [
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_enclosed_ideographic_supplement(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT.binary_search(&hash) {
+        Ok(index) if KEY_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT
-const HASH_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT: [u32; 19] = [
+static HASH_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT: [u32; 19] = [
     0x0BC20A8B,  // "🈚️" 1F21A-FE0F
     0x192420BE,  // "🈂️" 1F202-FE0F
     0x1EB97748,  // "🈶"
@@ -2059,8 +2987,31 @@ const HASH_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT: [u32; 19] = [
     0xF9906F07,  // "🈳"
 ];
 
+/// Verification keys to rule out hash collisions; sort matches HASH_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT
+static KEY_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT: [u32; 19] = [
+    0x8CD8A866,  // "🈚️" 1F21A-FE0F
+    0xDE048C09,  // "🈂️" 1F202-FE0F
+    0x0001F236,  // "🈶"
+    0x0001F22F,  // "🈯"
+    0x0001F235,  // "🈵"
+    0x0001F21A,  // "🈚"
+    0x0001F250,  // "🉐"
+    0x0001F251,  // "🉑"
+    0x0001F23A,  // "🈺"
+    0xF6FE9A8B,  // "🈷️" 1F237-FE0F
+    0x0001F234,  // "🈴"
+    0x0001F237,  // "🈷"
+    0x0001F238,  // "🈸"
+    0x0001F201,  // "🈁"
+    0xEFF3B9C1,  // "🈯️" 1F22F-FE0F
+    0x0001F202,  // "🈂"
+    0x0001F239,  // "🈹"
+    0x0001F232,  // "🈲"
+    0x0001F233,  // "🈳"
+];
+
 /// Lookup table of blit pattern offsets; sort matches HASH_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT
-const OFFSET_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT: [usize; 19] = [
+static OFFSET_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT: [usize; 19] = [
     7598, // "🈚️" 1F21A-FE0F
     7565, // "🈂️" 1F202-FE0F
     7791, // "🈶"
@@ -2082,260 +3033,951 @@ const OFFSET_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT: [usize; 19] = [
     7694, // "🈳"
 ];
 
-/// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
-/// Only attempt to match grapheme clusters of length limit codepoints.
-fn find_miscellaneous_symbols_and_pictographs(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS[index], bytes_hashed)),
-        _ => None,
-    }
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_miscellaneous_symbols_and_pictographs_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS, 0x1F300, cluster)
 }
 
-/// Index of murmur3(grapheme cluster); sort matches OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS
-const HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1648] = [
-    0x006F2752,  // "🕵️\u200d♀️" 1F575-FE0F-200D-2640-FE0F
-    0x00C3C87B,  // "🕗"
-    0x010EE9BE,  // "👨🏼\u200d🤝\u200d👨🏿" 1F468-1F3FC-200D-1F91D-200D-1F468-1F3FF
-    0x01153BE8,  // "📇"
-    0x0145B999,  // "🕴🏼\u200d♀️" 1F574-1F3FC-200D-2640-FE0F
-    0x016D38C5,  // "🐛"
-    0x01AC2B76,  // "🔫"
-    0x024A9758,  // "👞"
-    0x02A46644,  // "🏬"
-    0x02AB7A02,  // "👩🏽\u200d🎤" 1F469-1F3FD-200D-1F3A4
-    0x02B67308,  // "🎬️" 1F3AC-FE0F
-    0x02BEA08A,  // "👩🏾\u200d🦳" 1F469-1F3FE-200D-1F9B3
-    0x02D0DA94,  // "👨🏽\u200d🚀" 1F468-1F3FD-200D-1F680
-    0x02D5A37E,  // "🕐️" 1F550-FE0F
-    0x02E3E6AB,  // "🗝️" 1F5DD-FE0F
-    0x02F2EAE5,  // "👃🏾" 1F443-1F3FE
-    0x032782C6,  // "🌮"
-    0x032A5FA7,  // "🌎️" 1F30E-FE0F
-    0x034D295B,  // "👩🏼\u200d🎨" 1F469-1F3FC-200D-1F3A8
-    0x035278DA,  // "💪🏽" 1F4AA-1F3FD
-    0x036D95A6,  // "👁️" 1F441-FE0F
-    0x03F11C8A,  // "👨🏼\u200d⚕️" 1F468-1F3FC-200D-2695-FE0F
-    0x03F761B4,  // "🎧"
-    0x03FECB9B,  // "🎹"
-    0x04808C7F,  // "👨🏽\u200d💻" 1F468-1F3FD-200D-1F4BB
-    0x04B2A4F4,  // "🌉"
-    0x04D6F257,  // "🕺🏻" 1F57A-1F3FB
-    0x050870A8,  // "💕"
-    0x050A3B44,  // "💆🏿\u200d♀️" 1F486-1F3FF-200D-2640-FE0F
-    0x053BF508,  // "🖖🏽" 1F596-1F3FD
-    0x05538282,  // "🏋️\u200d♂️" 1F3CB-FE0F-200D-2642-FE0F
-    0x05A810A1,  // "👨\u200d❤️\u200d💋\u200d👨" 1F468-200D-2764-FE0F-200D-1F48B-200D-1F468
-    0x05AD34BB,  // "🎆"
-    0x05D238E2,  // "🖐"
-    0x05FB9B2A,  // "🐡"
-    0x060C7A3E,  // "🎼"
-    0x062BC481,  // "👱🏿\u200d♂️" 1F471-1F3FF-200D-2642-FE0F
-    0x0637A6BC,  // "👵🏿" 1F475-1F3FF
-    0x06405BBC,  // "👨🏿\u200d🦯" 1F468-1F3FF-200D-1F9AF
-    0x0646A493,  // "👩🏿\u200d🤝\u200d👨🏾" 1F469-1F3FF-200D-1F91D-200D-1F468-1F3FE
-    0x0687018C,  // "👱\u200d♂️" 1F471-200D-2642-FE0F
-    0x069F7203,  // "🏯"
-    0x06C3BFE1,  // "💻"
-    0x06D7FE65,  // "📙"
-    0x06FF4610,  // "🕖"
-    0x07445E97,  // "🎙️" 1F399-FE0F
-    0x0745ABEE,  // "👩\u200d🦰" 1F469-200D-1F9B0
-    0x075180B7,  // "💮"
-    0x0773DA7B,  // "💣️" 1F4A3-FE0F
-    0x07B49DF9,  // "📅"
-    0x07C5E300,  // "📺️" 1F4FA-FE0F
-    0x07CD7F78,  // "📛"
-    0x07F21F63,  // "🎥"
-    0x081648B1,  // "👱🏿" 1F471-1F3FF
-    0x0834453C,  // "👩🏾\u200d🦼" 1F469-1F3FE-200D-1F9BC
-    0x085581F3,  // "👨🏻\u200d🔬" 1F468-1F3FB-200D-1F52C
-    0x0869B5CC,  // "🍿"
-    0x08CF9624,  // "👷🏾\u200d♂️" 1F477-1F3FE-200D-2642-FE0F
-    0x08D91534,  // "👩🏻\u200d🤝\u200d👩🏽" 1F469-1F3FB-200D-1F91D-200D-1F469-1F3FD
-    0x08DD22D1,  // "🐀"
-    0x0912DB2F,  // "👳🏼" 1F473-1F3FC
-    0x095787D4,  // "💂🏿\u200d♀️" 1F482-1F3FF-200D-2640-FE0F
-    0x095A54D9,  // "🍔"
-    0x096BA949,  // "🕊"
-    0x0976AC78,  // "🏄\u200d♀️" 1F3C4-200D-2640-FE0F
-    0x0979E625,  // "🔅"
-    0x09B7F2BA,  // "👩🏾\u200d🤝\u200d👩🏼" 1F469-1F3FE-200D-1F91D-200D-1F469-1F3FC
-    0x09CDC7BB,  // "🔀"
-    0x09DBB848,  // "🔪"
-    0x09FD18BF,  // "📤"
-    0x0A107C3A,  // "💇🏽\u200d♀️" 1F487-1F3FD-200D-2640-FE0F
-    0x0A5A2B48,  // "🍹"
-    0x0A69E0F7,  // "📁"
-    0x0A8ED127,  // "💅🏽" 1F485-1F3FD
-    0x0A9E0875,  // "🏄🏼" 1F3C4-1F3FC
-    0x0AA45491,  // "🐎"
-    0x0B0BE3BE,  // "💑"
-    0x0B1CB585,  // "👨🏽\u200d🤝\u200d👨🏾" 1F468-1F3FD-200D-1F91D-200D-1F468-1F3FE
-    0x0B4E1949,  // "🕵🏿\u200d♂️" 1F575-1F3FF-200D-2642-FE0F
-    0x0B7D0ACE,  // "🏋🏼" 1F3CB-1F3FC
-    0x0BD0BF23,  // "👐🏾" 1F450-1F3FE
-    0x0BF99526,  // "👨🏼\u200d🤝\u200d👨🏾" 1F468-1F3FC-200D-1F91D-200D-1F468-1F3FE
-    0x0BFA6384,  // "💰️" 1F4B0-FE0F
-    0x0C025D01,  // "💁🏾\u200d♂️" 1F481-1F3FE-200D-2642-FE0F
-    0x0C09803E,  // "🏵️" 1F3F5-FE0F
-    0x0C1D7718,  // "👩🏻" 1F469-1F3FB
-    0x0C29BE00,  // "👩🏿\u200d🦲" 1F469-1F3FF-200D-1F9B2
-    0x0CB9DAD4,  // "👵"
-    0x0CE71C77,  // "👇"
-    0x0D27FF2B,  // "👨🏼\u200d🔧" 1F468-1F3FC-200D-1F527
-    0x0D3CADB4,  // "👨\u200d👧\u200d👦" 1F468-200D-1F467-200D-1F466
-    0x0D85EC24,  // "🍬"
-    0x0D96A9DD,  // "🗞️" 1F5DE-FE0F
-    0x0DDC8C14,  // "💆🏻" 1F486-1F3FB
-    0x0DF9B2B6,  // "👱🏻" 1F471-1F3FB
-    0x0E1AC7D7,  // "🔇"
-    0x0E2EF45D,  // "🐕\u200d🦺" 1F415-200D-1F9BA
-    0x0E379971,  // "👨🏾\u200d🍼" 1F468-1F3FE-200D-1F37C
-    0x0E408BC2,  // "🌄"
-    0x0E409BB1,  // "🕺🏼" 1F57A-1F3FC
-    0x0E727EB8,  // "💔"
-    0x0E7641B6,  // "👨\u200d🔧" 1F468-200D-1F527
-    0x0EB4D997,  // "💪🏼" 1F4AA-1F3FC
-    0x0EDE6E47,  // "💭"
-    0x0F36E9BA,  // "🏋🏾" 1F3CB-1F3FE
-    0x0F5AF62A,  // "🏴\U000e0067\U000e0062\U000e0065\U000e006e\U000e0067\U000e007f" 1F3F4-E0067-E0062-E0065-E006E-E0067-E007F
-    0x0F748659,  // "🏡"
-    0x0FC2575A,  // "🕕"
-    0x105D99E0,  // "👩🏼\u200d🦰" 1F469-1F3FC-200D-1F9B0
-    0x107B5B3A,  // "👊🏽" 1F44A-1F3FD
-    0x109026D4,  // "👮🏽\u200d♂️" 1F46E-1F3FD-200D-2642-FE0F
-    0x10FEFEE2,  // "🌈"
-    0x1109456B,  // "👩🏽\u200d🍳" 1F469-1F3FD-200D-1F373
-    0x1125BD4D,  // "📯"
-    0x1140C9D7,  // "🏌🏽\u200d♀️" 1F3CC-1F3FD-200D-2640-FE0F
-    0x114A9770,  // "👩🏿\u200d⚖️" 1F469-1F3FF-200D-2696-FE0F
-    0x11BE7D85,  // "🎸"
-    0x11D6DBE6,  // "🕘️" 1F558-FE0F
-    0x11FCEB06,  // "🐙"
-    0x121A1C9D,  // "🌯"
-    0x121EC9E9,  // "👨🏽\u200d🍼" 1F468-1F3FD-200D-1F37C
-    0x12291DD0,  // "📶"
-    0x1234CD81,  // "🕞️" 1F55E-FE0F
-    0x12433895,  // "🎳"
-    0x126EF4BF,  // "👩🏻\u200d🦼" 1F469-1F3FB-200D-1F9BC
-    0x126F620C,  // "👩🏼\u200d🤝\u200d👩🏻" 1F469-1F3FC-200D-1F91D-200D-1F469-1F3FB
-    0x12DD10EA,  // "👨🏾\u200d🚀" 1F468-1F3FE-200D-1F680
-    0x12F673A6,  // "👩\u200d🍳" 1F469-200D-1F373
-    0x12FDDC36,  // "👨🏻\u200d🤝\u200d👨🏼" 1F468-1F3FB-200D-1F91D-200D-1F468-1F3FC
-    0x13520FDF,  // "🎗️" 1F397-FE0F
-    0x135B2A91,  // "🎩"
-    0x1385F386,  // "🕗️" 1F557-FE0F
-    0x138F792C,  // "📫️" 1F4EB-FE0F
-    0x139CE612,  // "🍓"
-    0x13C0A884,  // "👩\u200d👧" 1F469-200D-1F467
-    0x14130F22,  // "👨🏽\u200d⚕️" 1F468-1F3FD-200D-2695-FE0F
-    0x1453C757,  // "💃🏾" 1F483-1F3FE
-    0x1474AA1B,  // "💂🏼\u200d♂️" 1F482-1F3FC-200D-2642-FE0F
-    0x148A2717,  // "💃🏿" 1F483-1F3FF
-    0x148F225F,  // "👩🏻\u200d✈️" 1F469-1F3FB-200D-2708-FE0F
-    0x14C4A6F5,  // "🏎️" 1F3CE-FE0F
-    0x14E5E623,  // "📘"
-    0x14FA6B13,  // "🌠"
-    0x15116AA4,  // "👨🏼\u200d🚒" 1F468-1F3FC-200D-1F692
-    0x152FA47E,  // "👩🏾\u200d🦯" 1F469-1F3FE-200D-1F9AF
-    0x155D0CC8,  // "👨\u200d🎓" 1F468-200D-1F393
-    0x156996D1,  // "🎞️" 1F39E-FE0F
-    0x158D8511,  // "👰🏿\u200d♀️" 1F470-1F3FF-200D-2640-FE0F
-    0x1599CE77,  // "👨🏾\u200d⚕️" 1F468-1F3FE-200D-2695-FE0F
-    0x15CA5093,  // "🏻"
-    0x15F22037,  // "👩🏾\u200d🎓" 1F469-1F3FE-200D-1F393
-    0x1633A5DD,  // "🕑️" 1F551-FE0F
-    0x1638330B,  // "📠"
-    0x16416C17,  // "🏜️" 1F3DC-FE0F
-    0x16A4C7B1,  // "🌧"
-    0x16F209CC,  // "👩🏽\u200d🤝\u200d👨🏼" 1F469-1F3FD-200D-1F91D-200D-1F468-1F3FC
-    0x17780DFA,  // "💹"
-    0x179F0394,  // "🏍"
-    0x17B0FB1C,  // "🎇"
-    0x17B6EF8C,  // "👨🏾\u200d💻" 1F468-1F3FE-200D-1F4BB
-    0x17BC8048,  // "👦🏻" 1F466-1F3FB
-    0x17FD1FF7,  // "👨🏾\u200d🍳" 1F468-1F3FE-200D-1F373
-    0x1807183A,  // "👩\u200d❤️\u200d💋\u200d👨" 1F469-200D-2764-FE0F-200D-1F48B-200D-1F468
-    0x18421379,  // "📱"
-    0x1871ED0C,  // "👺"
-    0x18BC72A8,  // "🍟"
-    0x18D4ABB6,  // "👂🏽" 1F442-1F3FD
-    0x18E48985,  // "🌏️" 1F30F-FE0F
-    0x1911C57E,  // "📬️" 1F4EC-FE0F
-    0x193F3226,  // "🍡"
-    0x19D34663,  // "👍🏾" 1F44D-1F3FE
-    0x1A3A29DC,  // "👨🏻\u200d🎨" 1F468-1F3FB-200D-1F3A8
-    0x1A43FC08,  // "👩🏼\u200d✈️" 1F469-1F3FC-200D-2708-FE0F
-    0x1A54D2F8,  // "🎮"
-    0x1A695053,  // "🏄🏿\u200d♀️" 1F3C4-1F3FF-200D-2640-FE0F
-    0x1A6BBBE8,  // "👊🏻" 1F44A-1F3FB
-    0x1A7F3D36,  // "🕵️" 1F575-FE0F
-    0x1AB32F25,  // "👦🏼" 1F466-1F3FC
-    0x1ABA6A06,  // "🍽️" 1F37D-FE0F
-    0x1AC7C5F5,  // "👩🏿\u200d🤝\u200d👨🏽" 1F469-1F3FF-200D-1F91D-200D-1F468-1F3FD
-    0x1B44223A,  // "👬🏻" 1F46C-1F3FB
-    0x1B649E5C,  // "🌂"
-    0x1B84097D,  // "🐠"
+/// Blit pattern offsets for codepoints 0x1F300..=0x1F5FF, or NO_GLYPH
+static CODEPOINT_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 768] = [
+    8016,  // "🌀"
+    8045,  // "🌁"
+    8076,  // "🌂"
+    8102,  // "🌃"
+    8135,  // "🌄"
+    8168,  // "🌅"
+    8200,  // "🌆"
+    8233,  // "🌇"
+    8266,  // "🌈"
+    8298,  // "🌉"
+    8331,  // "🌊"
+    8361,  // "🌋"
+    8394,  // "🌌"
+    8427,  // "🌍"
+    8459,  // "🌎"
+    8491,  // "🌏"
+    8524,  // "🌐"
+    8556,  // "🌑"
+    8588,  // "🌒"
+    8621,  // "🌓"
+    8651,  // "🌔"
+    8681,  // "🌕"
+    8713,  // "🌖"
+    8745,  // "🌗"
+    8777,  // "🌘"
+    8809,  // "🌙"
+    8837,  // "🌚"
+    8869,  // "🌛"
+    8892,  // "🌜"
+    8915,  // "🌝"
+    8945,  // "🌞"
+    8977,  // "🌟"
+    9009,  // "🌠"
+    9041,  // "🌡"
+    super::NO_GLYPH,  // U+1F322
+    super::NO_GLYPH,  // U+1F323
+    9069,  // "🌤"
+    9101,  // "🌥"
+    9123,  // "🌦"
+    9153,  // "🌧"
+    9183,  // "🌨"
+    9213,  // "🌩"
+    9241,  // "🌪"
+    9268,  // "🌫"
+    9298,  // "🌬"
+    9319,  // "🌭"
+    9345,  // "🌮"
+    9365,  // "🌯"
+    9393,  // "🌰"
+    9425,  // "🌱"
+    9455,  // "🌲"
+    9481,  // "🌳"
+    9510,  // "🌴"
+    9537,  // "🌵"
+    9567,  // "🌶"
+    9596,  // "🌷"
+    9625,  // "🌸"
+    9656,  // "🌹"
+    9676,  // "🌺"
+    9706,  // "🌻"
+    9737,  // "🌼"
+    9759,  // "🌽"
+    9786,  // "🌾"
+    9808,  // "🌿"
+    9836,  // "🍀"
+    9866,  // "🍁"
+    9898,  // "🍂"
+    9929,  // "🍃"
+    9958,  // "🍄"
+    9991,  // "🍅"
+    10023, // "🍆"
+    10053, // "🍇"
+    10083, // "🍈"
+    10108, // "🍉"
+    10128, // "🍊"
+    10160, // "🍋"
+    10192, // "🍌"
+    10217, // "🍍"
+    10241, // "🍎"
+    10268, // "🍏"
+    10295, // "🍐"
+    10322, // "🍑"
+    10354, // "🍒"
+    10382, // "🍓"
+    10409, // "🍔"
+    10438, // "🍕"
+    10463, // "🍖"
+    10491, // "🍗"
+    10519, // "🍘"
+    10549, // "🍙"
+    10576, // "🍚"
+    10603, // "🍛"
+    10624, // "🍜"
+    10656, // "🍝"
+    10685, // "🍞"
+    10712, // "🍟"
+    10737, // "🍠"
+    10767, // "🍡"
+    10794, // "🍢"
+    10820, // "🍣"
+    10850, // "🍤"
+    10876, // "🍥"
+    10904, // "🍦"
+    10920, // "🍧"
+    10949, // "🍨"
+    10976, // "🍩"
+    11001, // "🍪"
+    11030, // "🍫"
+    11062, // "🍬"
+    11094, // "🍭"
+    11126, // "🍮"
+    11150, // "🍯"
+    11174, // "🍰"
+    11205, // "🍱"
+    11236, // "🍲"
+    11260, // "🍳"
+    11293, // "🍴"
+    11314, // "🍵"
+    11346, // "🍶"
+    11375, // "🍷"
+    11396, // "🍸"
+    11419, // "🍹"
+    11445, // "🍺"
+    11475, // "🍻"
+    11507, // "🍼"
+    11535, // "🍽"
+    11567, // "🍾"
+    11599, // "🍿"
+    11627, // "🎀"
+    11655, // "🎁"
+    11686, // "🎂"
+    11718, // "🎃"
+    11750, // "🎄"
+    11928, // "🎅"
+    11960, // "🎆"
+    11993, // "🎇"
+    12025, // "🎈"
+    12041, // "🎉"
+    12072, // "🎊"
+    12101, // "🎋"
+    12129, // "🎌"
+    12158, // "🎍"
+    12187, // "🎎"
+    12217, // "🎏"
+    12246, // "🎐"
+    12265, // "🎑"
+    12298, // "🎒"
+    12330, // "🎓"
+    super::NO_GLYPH,  // U+1F394
+    super::NO_GLYPH,  // U+1F395
+    12360, // "🎖"
+    12377, // "🎗"
+    super::NO_GLYPH,  // U+1F398
+    12394, // "🎙"
+    12418, // "🎚"
+    12444, // "🎛"
+    super::NO_GLYPH,  // U+1F39C
+    super::NO_GLYPH,  // U+1F39D
+    12476, // "🎞"
+    12498, // "🎟"
+    12529, // "🎠"
+    12558, // "🎡"
+    12589, // "🎢"
+    12619, // "🎣"
+    12650, // "🎤"
+    12680, // "🎥"
+    12706, // "🎦"
+    12738, // "🎧"
+    12771, // "🎨"
+    12799, // "🎩"
+    12831, // "🎪"
+    12857, // "🎫"
+    12886, // "🎬"
+    12918, // "🎭"
+    12950, // "🎮"
+    12974, // "🎯"
+    13005, // "🎰"
+    13029, // "🎱"
+    13062, // "🎲"
+    13086, // "🎳"
+    13118, // "🎴"
+    13144, // "🎵"
+    13175, // "🎶"
+    13206, // "🎷"
+    13233, // "🎸"
+    13265, // "🎹"
+    13298, // "🎺"
+    13325, // "🎻"
+    13356, // "🎼"
+    13388, // "🎽"
+    13417, // "🎾"
+    13449, // "🎿"
+    13478, // "🏀"
+    13510, // "🏁"
+    13689, // "🏂"
+    14094, // "🏃"
+    14659, // "🏄"
+    14691, // "🏅"
+    14721, // "🏆"
+    14911, // "🏇"
+    14943, // "🏈"
+    14974, // "🏉"
+    15524, // "🏊"
+    16082, // "🏋"
+    16426, // "🏌"
+    16445, // "🏍"
+    16468, // "🏎"
+    16487, // "🏏"
+    16519, // "🏐"
+    16549, // "🏑"
+    16577, // "🏒"
+    16605, // "🏓"
+    16635, // "🏔"
+    16665, // "🏕"
+    16696, // "🏖"
+    16728, // "🏗"
+    16758, // "🏘"
+    16786, // "🏙"
+    16818, // "🏚"
+    16841, // "🏛"
+    16869, // "🏜"
+    16900, // "🏝"
+    16932, // "🏞"
+    16964, // "🏟"
+    16993, // "🏠"
+    17020, // "🏡"
+    17049, // "🏢"
+    17078, // "🏣"
+    17110, // "🏤"
+    17141, // "🏥"
+    17173, // "🏦"
+    17203, // "🏧"
+    17235, // "🏨"
+    17267, // "🏩"
+    17299, // "🏪"
+    17330, // "🏫"
+    17362, // "🏬"
+    17394, // "🏭"
+    17424, // "🏮"
+    17455, // "🏯"
+    17485, // "🏰"
+    super::NO_GLYPH,  // U+1F3F1
+    super::NO_GLYPH,  // U+1F3F2
+    17558, // "🏳"
+    17676, // "🏴"
+    17703, // "🏵"
+    super::NO_GLYPH,  // U+1F3F6
+    17734, // "🏷"
+    17762, // "🏸"
+    17795, // "🏹"
+    17826, // "🏺"
+    17849, // "🏻"
+    17871, // "🏼"
+    17894, // "🏽"
+    17916, // "🏾"
+    17939, // "🏿"
+    17962, // "🐀"
+    17986, // "🐁"
+    18005, // "🐂"
+    18029, // "🐃"
+    18057, // "🐄"
+    18081, // "🐅"
+    18103, // "🐆"
+    18125, // "🐇"
+    18186, // "🐈"
+    18216, // "🐉"
+    18246, // "🐊"
+    18267, // "🐋"
+    18293, // "🐌"
+    18319, // "🐍"
+    18351, // "🐎"
+    18380, // "🐏"
+    18401, // "🐐"
+    18431, // "🐑"
+    18459, // "🐒"
+    18491, // "🐓"
+    18520, // "🐔"
+    18568, // "🐕"
+    18597, // "🐖"
+    18619, // "🐗"
+    18649, // "🐘"
+    18678, // "🐙"
+    18710, // "🐚"
+    18735, // "🐛"
+    18765, // "🐜"
+    18796, // "🐝"
+    18827, // "🐞"
+    18859, // "🐟"
+    18883, // "🐠"
+    18914, // "🐡"
+    18935, // "🐢"
+    18954, // "🐣"
+    18979, // "🐤"
+    19005, // "🐥"
+    19033, // "🐦"
+    19058, // "🐧"
+    19088, // "🐨"
+    19111, // "🐩"
+    19140, // "🐪"
+    19169, // "🐫"
+    19197, // "🐬"
+    19227, // "🐭"
+    19258, // "🐮"
+    19288, // "🐯"
+    19318, // "🐰"
+    19345, // "🐱"
+    19371, // "🐲"
+    19402, // "🐳"
+    19432, // "🐴"
+    19464, // "🐵"
+    19494, // "🐶"
+    19525, // "🐷"
+    19555, // "🐸"
+    19584, // "🐹"
+    19616, // "🐺"
+    19671, // "🐻"
+    19702, // "🐼"
+    19733, // "🐽"
+    19752, // "🐾"
+    19779, // "🐿"
+    19811, // "👀"
+    19863, // "👁"
+    19993, // "👂"
+    20139, // "👃"
+    20163, // "👄"
+    20186, // "👅"
+    20330, // "👆"
+    20473, // "👇"
+    20612, // "👈"
+    20754, // "👉"
+    20893, // "👊"
+    21074, // "👋"
+    21225, // "👌"
+    21384, // "👍"
+    21551, // "👎"
+    21737, // "👏"
+    21862, // "👐"
+    21882, // "👑"
+    21911, // "👒"
+    21928, // "👓"
+    21941, // "👔"
+    21974, // "👕"
+    22003, // "👖"
+    22029, // "👗"
+    22059, // "👘"
+    22088, // "👙"
+    22111, // "👚"
+    22139, // "👛"
+    22171, // "👜"
+    22202, // "👝"
+    22222, // "👞"
+    22251, // "👟"
+    22281, // "👠"
+    22310, // "👡"
+    22326, // "👢"
+    22358, // "👣"
+    22387, // "👤"
+    22417, // "👥"
+    22592, // "👦"
+    22751, // "👧"
+    28209, // "👨"
+    34105, // "👩"
+    34130, // "👪"
+    34302, // "👫"
+    34471, // "👬"
+    34635, // "👭"
+    35086, // "👮"
+    35173, // "👯"
+    35718, // "👰"
+    36163, // "👱"
+    36319, // "👲"
+    36729, // "👳"
+    36895, // "👴"
+    37065, // "👵"
+    37241, // "👶"
+    37691, // "👷"
+    37862, // "👸"
+    37891, // "👹"
+    37922, // "👺"
+    37952, // "👻"
+    38132, // "👼"
+    38162, // "👽"
+    38192, // "👾"
+    38222, // "👿"
+    38254, // "💀"
+    38810, // "💁"
+    39188, // "💂"
+    39352, // "💃"
+    39378, // "💄"
+    39560, // "💅"
+    40004, // "💆"
+    40556, // "💇"
+    40588, // "💈"
+    40605, // "💉"
+    40634, // "💊"
+    40666, // "💋"
+    40691, // "💌"
+    40714, // "💍"
+    40735, // "💎"
+    40760, // "💏"
+    40788, // "💐"
+    40820, // "💑"
+    40852, // "💒"
+    40883, // "💓"
+    40911, // "💔"
+    40939, // "💕"
+    40970, // "💖"
+    40999, // "💗"
+    41028, // "💘"
+    41057, // "💙"
+    41086, // "💚"
+    41115, // "💛"
+    41142, // "💜"
+    41171, // "💝"
+    41200, // "💞"
+    41231, // "💟"
+    41264, // "💠"
+    41294, // "💡"
+    41310, // "💢"
+    41338, // "💣"
+    41370, // "💤"
+    41396, // "💥"
+    41425, // "💦"
+    41457, // "💧"
+    41476, // "💨"
+    41505, // "💩"
+    41686, // "💪"
+    41716, // "💫"
+    41744, // "💬"
+    41771, // "💭"
+    41799, // "💮"
+    41828, // "💯"
+    41859, // "💰"
+    41888, // "💱"
+    41915, // "💲"
+    41934, // "💳"
+    41958, // "💴"
+    41985, // "💵"
+    42012, // "💶"
+    42039, // "💷"
+    42066, // "💸"
+    42096, // "💹"
+    42128, // "💺"
+    42154, // "💻"
+    42179, // "💼"
+    42212, // "💽"
+    42245, // "💾"
+    42278, // "💿"
+    42310, // "📀"
+    42338, // "📁"
+    42364, // "📂"
+    42391, // "📃"
+    42419, // "📄"
+    42444, // "📅"
+    42476, // "📆"
+    42507, // "📇"
+    42538, // "📈"
+    42564, // "📉"
+    42590, // "📊"
+    42617, // "📋"
+    42643, // "📌"
+    42675, // "📍"
+    42690, // "📎"
+    42717, // "📏"
+    42747, // "📐"
+    42776, // "📑"
+    42806, // "📒"
+    42839, // "📓"
+    42870, // "📔"
+    42901, // "📕"
+    42932, // "📖"
+    42956, // "📗"
+    42987, // "📘"
+    43018, // "📙"
+    43048, // "📚"
+    43078, // "📛"
+    43110, // "📜"
+    43140, // "📝"
+    43170, // "📞"
+    43202, // "📟"
+    43226, // "📠"
+    43258, // "📡"
+    43283, // "📢"
+    43312, // "📣"
+    43334, // "📤"
+    43366, // "📥"
+    43398, // "📦"
+    43422, // "📧"
+    43445, // "📨"
+    43462, // "📩"
+    43493, // "📪"
+    43520, // "📫"
+    43545, // "📬"
+    43573, // "📭"
+    43601, // "📮"
+    43625, // "📯"
+    43657, // "📰"
+    43687, // "📱"
+    43708, // "📲"
+    43741, // "📳"
+    43773, // "📴"
+    43805, // "📵"
+    43838, // "📶"
+    43870, // "📷"
+    43895, // "📸"
+    43922, // "📹"
+    43949, // "📺"
+    43978, // "📻"
+    44009, // "📼"
+    44031, // "📽"
+    super::NO_GLYPH,  // U+1F4FE
+    44061, // "📿"
+    44093, // "🔀"
+    44126, // "🔁"
+    44158, // "🔂"
+    44190, // "🔃"
+    44223, // "🔄"
+    44255, // "🔅"
+    44276, // "🔆"
+    44308, // "🔇"
+    44334, // "🔈"
+    44351, // "🔉"
+    44374, // "🔊"
+    44405, // "🔋"
+    44426, // "🔌"
+    44454, // "🔍"
+    44486, // "🔎"
+    44518, // "🔏"
+    44548, // "🔐"
+    44580, // "🔑"
+    44612, // "🔒"
+    44634, // "🔓"
+    44657, // "🔔"
+    44683, // "🔕"
+    44710, // "🔖"
+    44741, // "🔗"
+    44772, // "🔘"
+    44804, // "🔙"
+    44836, // "🔚"
+    44869, // "🔛"
+    44901, // "🔜"
+    44934, // "🔝"
+    44966, // "🔞"
+    44999, // "🔟"
+    45031, // "🔠"
+    45064, // "🔡"
+    45096, // "🔢"
+    45128, // "🔣"
+    45161, // "🔤"
+    45193, // "🔥"
+    45221, // "🔦"
+    45250, // "🔧"
+    45282, // "🔨"
+    45309, // "🔩"
+    45338, // "🔪"
+    45368, // "🔫"
+    45394, // "🔬"
+    45420, // "🔭"
+    45450, // "🔮"
+    45479, // "🔯"
+    45511, // "🔰"
+    45534, // "🔱"
+    45562, // "🔲"
+    45595, // "🔳"
+    45627, // "🔴"
+    45659, // "🔵"
+    45691, // "🔶"
+    45721, // "🔷"
+    45750, // "🔸"
+    45761, // "🔹"
+    45773, // "🔺"
+    45785, // "🔻"
+    45797, // "🔼"
+    45830, // "🔽"
+    super::NO_GLYPH,  // U+1F53E
+    super::NO_GLYPH,  // U+1F53F
+    super::NO_GLYPH,  // U+1F540
+    super::NO_GLYPH,  // U+1F541
+    super::NO_GLYPH,  // U+1F542
+    super::NO_GLYPH,  // U+1F543
+    super::NO_GLYPH,  // U+1F544
+    super::NO_GLYPH,  // U+1F545
+    super::NO_GLYPH,  // U+1F546
+    super::NO_GLYPH,  // U+1F547
+    super::NO_GLYPH,  // U+1F548
+    45862, // "🕉"
+    45894, // "🕊"
+    45921, // "🕋"
+    45954, // "🕌"
+    45983, // "🕍"
+    46014, // "🕎"
+    super::NO_GLYPH,  // U+1F54F
+    46047, // "🕐"
+    46079, // "🕑"
+    46111, // "🕒"
+    46143, // "🕓"
+    46175, // "🕔"
+    46207, // "🕕"
+    46239, // "🕖"
+    46271, // "🕗"
+    46303, // "🕘"
+    46335, // "🕙"
+    46367, // "🕚"
+    46399, // "🕛"
+    46431, // "🕜"
+    46463, // "🕝"
+    46495, // "🕞"
+    46527, // "🕟"
+    46559, // "🕠"
+    46591, // "🕡"
+    46623, // "🕢"
+    46655, // "🕣"
+    46687, // "🕤"
+    46719, // "🕥"
+    46751, // "🕦"
+    46783, // "🕧"
+    super::NO_GLYPH,  // U+1F568
+    super::NO_GLYPH,  // U+1F569
+    super::NO_GLYPH,  // U+1F56A
+    super::NO_GLYPH,  // U+1F56B
+    super::NO_GLYPH,  // U+1F56C
+    super::NO_GLYPH,  // U+1F56D
+    super::NO_GLYPH,  // U+1F56E
+    46815, // "🕯"
+    46829, // "🕰"
+    super::NO_GLYPH,  // U+1F571
+    super::NO_GLYPH,  // U+1F572
+    46855, // "🕳"
+    47069, // "🕴"
+    47532, // "🕵"
+    47559, // "🕶"
+    47570, // "🕷"
+    47597, // "🕸"
+    47623, // "🕹"
+    47747, // "🕺"
+    super::NO_GLYPH,  // U+1F57B
+    super::NO_GLYPH,  // U+1F57C
+    super::NO_GLYPH,  // U+1F57D
+    super::NO_GLYPH,  // U+1F57E
+    super::NO_GLYPH,  // U+1F57F
+    super::NO_GLYPH,  // U+1F580
+    super::NO_GLYPH,  // U+1F581
+    super::NO_GLYPH,  // U+1F582
+    super::NO_GLYPH,  // U+1F583
+    super::NO_GLYPH,  // U+1F584
+    super::NO_GLYPH,  // U+1F585
+    super::NO_GLYPH,  // U+1F586
+    47766, // "🖇"
+    super::NO_GLYPH,  // U+1F588
+    super::NO_GLYPH,  // U+1F589
+    47794, // "🖊"
+    47824, // "🖋"
+    47856, // "🖌"
+    47886, // "🖍"
+    super::NO_GLYPH,  // U+1F58E
+    super::NO_GLYPH,  // U+1F58F
+    48050, // "🖐"
+    super::NO_GLYPH,  // U+1F591
+    super::NO_GLYPH,  // U+1F592
+    super::NO_GLYPH,  // U+1F593
+    super::NO_GLYPH,  // U+1F594
+    48183, // "🖕"
+    48338, // "🖖"
+    super::NO_GLYPH,  // U+1F597
+    super::NO_GLYPH,  // U+1F598
+    super::NO_GLYPH,  // U+1F599
+    super::NO_GLYPH,  // U+1F59A
+    super::NO_GLYPH,  // U+1F59B
+    super::NO_GLYPH,  // U+1F59C
+    super::NO_GLYPH,  // U+1F59D
+    super::NO_GLYPH,  // U+1F59E
+    super::NO_GLYPH,  // U+1F59F
+    super::NO_GLYPH,  // U+1F5A0
+    super::NO_GLYPH,  // U+1F5A1
+    super::NO_GLYPH,  // U+1F5A2
+    super::NO_GLYPH,  // U+1F5A3
+    48364, // "🖤"
+    48393, // "🖥"
+    super::NO_GLYPH,  // U+1F5A6
+    super::NO_GLYPH,  // U+1F5A7
+    48425, // "🖨"
+    super::NO_GLYPH,  // U+1F5A9
+    super::NO_GLYPH,  // U+1F5AA
+    super::NO_GLYPH,  // U+1F5AB
+    super::NO_GLYPH,  // U+1F5AC
+    super::NO_GLYPH,  // U+1F5AD
+    super::NO_GLYPH,  // U+1F5AE
+    super::NO_GLYPH,  // U+1F5AF
+    super::NO_GLYPH,  // U+1F5B0
+    48452, // "🖱"
+    48475, // "🖲"
+    super::NO_GLYPH,  // U+1F5B3
+    super::NO_GLYPH,  // U+1F5B4
+    super::NO_GLYPH,  // U+1F5B5
+    super::NO_GLYPH,  // U+1F5B6
+    super::NO_GLYPH,  // U+1F5B7
+    super::NO_GLYPH,  // U+1F5B8
+    super::NO_GLYPH,  // U+1F5B9
+    super::NO_GLYPH,  // U+1F5BA
+    super::NO_GLYPH,  // U+1F5BB
+    48502, // "🖼"
+    super::NO_GLYPH,  // U+1F5BD
+    super::NO_GLYPH,  // U+1F5BE
+    super::NO_GLYPH,  // U+1F5BF
+    super::NO_GLYPH,  // U+1F5C0
+    super::NO_GLYPH,  // U+1F5C1
+    48526, // "🗂"
+    48551, // "🗃"
+    48580, // "🗄"
+    super::NO_GLYPH,  // U+1F5C5
+    super::NO_GLYPH,  // U+1F5C6
+    super::NO_GLYPH,  // U+1F5C7
+    super::NO_GLYPH,  // U+1F5C8
+    super::NO_GLYPH,  // U+1F5C9
+    super::NO_GLYPH,  // U+1F5CA
+    super::NO_GLYPH,  // U+1F5CB
+    super::NO_GLYPH,  // U+1F5CC
+    super::NO_GLYPH,  // U+1F5CD
+    super::NO_GLYPH,  // U+1F5CE
+    super::NO_GLYPH,  // U+1F5CF
+    super::NO_GLYPH,  // U+1F5D0
+    48601, // "🗑"
+    48633, // "🗒"
+    48659, // "🗓"
+    super::NO_GLYPH,  // U+1F5D4
+    super::NO_GLYPH,  // U+1F5D5
+    super::NO_GLYPH,  // U+1F5D6
+    super::NO_GLYPH,  // U+1F5D7
+    super::NO_GLYPH,  // U+1F5D8
+    super::NO_GLYPH,  // U+1F5D9
+    super::NO_GLYPH,  // U+1F5DA
+    super::NO_GLYPH,  // U+1F5DB
+    48688, // "🗜"
+    48717, // "🗝"
+    48748, // "🗞"
+    super::NO_GLYPH,  // U+1F5DF
+    super::NO_GLYPH,  // U+1F5E0
+    48778, // "🗡"
+    super::NO_GLYPH,  // U+1F5E2
+    48809, // "🗣"
+    super::NO_GLYPH,  // U+1F5E4
+    super::NO_GLYPH,  // U+1F5E5
+    super::NO_GLYPH,  // U+1F5E6
+    super::NO_GLYPH,  // U+1F5E7
+    48838, // "🗨"
+    super::NO_GLYPH,  // U+1F5E9
+    super::NO_GLYPH,  // U+1F5EA
+    super::NO_GLYPH,  // U+1F5EB
+    super::NO_GLYPH,  // U+1F5EC
+    super::NO_GLYPH,  // U+1F5ED
+    super::NO_GLYPH,  // U+1F5EE
+    48867, // "🗯"
+    super::NO_GLYPH,  // U+1F5F0
+    super::NO_GLYPH,  // U+1F5F1
+    super::NO_GLYPH,  // U+1F5F2
+    48892, // "🗳"
+    super::NO_GLYPH,  // U+1F5F4
+    super::NO_GLYPH,  // U+1F5F5
+    super::NO_GLYPH,  // U+1F5F6
+    super::NO_GLYPH,  // U+1F5F7
+    super::NO_GLYPH,  // U+1F5F8
+    super::NO_GLYPH,  // U+1F5F9
+    48919, // "🗺"
+    48943, // "🗻"
+    48967, // "🗼"
+    48990, // "🗽"
+    49022, // "🗾"
+    49054, // "🗿"
+];
+
+/// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
+/// Only attempt to match grapheme clusters of length limit codepoints.
+fn find_miscellaneous_symbols_and_pictographs(cluster: &str, limit: u32) -> Option<(usize, usize)> {
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS.binary_search(&hash) {
+        Ok(index) if KEY_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS[index], bytes_hashed))
+        }
+        _ => None,
+    }
+}
+
+/// Index of murmur3(grapheme cluster); sort matches OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS
+static HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1011] = [
+    0x006F2752,  // "🕵️\u200d♀️" 1F575-FE0F-200D-2640-FE0F
+    0x010EE9BE,  // "👨🏼\u200d🤝\u200d👨🏿" 1F468-1F3FC-200D-1F91D-200D-1F468-1F3FF
+    0x0145B999,  // "🕴🏼\u200d♀️" 1F574-1F3FC-200D-2640-FE0F
+    0x02AB7A02,  // "👩🏽\u200d🎤" 1F469-1F3FD-200D-1F3A4
+    0x02B67308,  // "🎬️" 1F3AC-FE0F
+    0x02BEA08A,  // "👩🏾\u200d🦳" 1F469-1F3FE-200D-1F9B3
+    0x02D0DA94,  // "👨🏽\u200d🚀" 1F468-1F3FD-200D-1F680
+    0x02D5A37E,  // "🕐️" 1F550-FE0F
+    0x02E3E6AB,  // "🗝️" 1F5DD-FE0F
+    0x02F2EAE5,  // "👃🏾" 1F443-1F3FE
+    0x032A5FA7,  // "🌎️" 1F30E-FE0F
+    0x034D295B,  // "👩🏼\u200d🎨" 1F469-1F3FC-200D-1F3A8
+    0x035278DA,  // "💪🏽" 1F4AA-1F3FD
+    0x036D95A6,  // "👁️" 1F441-FE0F
+    0x03F11C8A,  // "👨🏼\u200d⚕️" 1F468-1F3FC-200D-2695-FE0F
+    0x04808C7F,  // "👨🏽\u200d💻" 1F468-1F3FD-200D-1F4BB
+    0x04D6F257,  // "🕺🏻" 1F57A-1F3FB
+    0x050A3B44,  // "💆🏿\u200d♀️" 1F486-1F3FF-200D-2640-FE0F
+    0x053BF508,  // "🖖🏽" 1F596-1F3FD
+    0x05538282,  // "🏋️\u200d♂️" 1F3CB-FE0F-200D-2642-FE0F
+    0x05A810A1,  // "👨\u200d❤️\u200d💋\u200d👨" 1F468-200D-2764-FE0F-200D-1F48B-200D-1F468
+    0x062BC481,  // "👱🏿\u200d♂️" 1F471-1F3FF-200D-2642-FE0F
+    0x0637A6BC,  // "👵🏿" 1F475-1F3FF
+    0x06405BBC,  // "👨🏿\u200d🦯" 1F468-1F3FF-200D-1F9AF
+    0x0646A493,  // "👩🏿\u200d🤝\u200d👨🏾" 1F469-1F3FF-200D-1F91D-200D-1F468-1F3FE
+    0x0687018C,  // "👱\u200d♂️" 1F471-200D-2642-FE0F
+    0x07445E97,  // "🎙️" 1F399-FE0F
+    0x0745ABEE,  // "👩\u200d🦰" 1F469-200D-1F9B0
+    0x0773DA7B,  // "💣️" 1F4A3-FE0F
+    0x07C5E300,  // "📺️" 1F4FA-FE0F
+    0x081648B1,  // "👱🏿" 1F471-1F3FF
+    0x0834453C,  // "👩🏾\u200d🦼" 1F469-1F3FE-200D-1F9BC
+    0x085581F3,  // "👨🏻\u200d🔬" 1F468-1F3FB-200D-1F52C
+    0x08CF9624,  // "👷🏾\u200d♂️" 1F477-1F3FE-200D-2642-FE0F
+    0x08D91534,  // "👩🏻\u200d🤝\u200d👩🏽" 1F469-1F3FB-200D-1F91D-200D-1F469-1F3FD
+    0x0912DB2F,  // "👳🏼" 1F473-1F3FC
+    0x095787D4,  // "💂🏿\u200d♀️" 1F482-1F3FF-200D-2640-FE0F
+    0x0976AC78,  // "🏄\u200d♀️" 1F3C4-200D-2640-FE0F
+    0x09B7F2BA,  // "👩🏾\u200d🤝\u200d👩🏼" 1F469-1F3FE-200D-1F91D-200D-1F469-1F3FC
+    0x0A107C3A,  // "💇🏽\u200d♀️" 1F487-1F3FD-200D-2640-FE0F
+    0x0A8ED127,  // "💅🏽" 1F485-1F3FD
+    0x0A9E0875,  // "🏄🏼" 1F3C4-1F3FC
+    0x0B1CB585,  // "👨🏽\u200d🤝\u200d👨🏾" 1F468-1F3FD-200D-1F91D-200D-1F468-1F3FE
+    0x0B4E1949,  // "🕵🏿\u200d♂️" 1F575-1F3FF-200D-2642-FE0F
+    0x0B7D0ACE,  // "🏋🏼" 1F3CB-1F3FC
+    0x0BD0BF23,  // "👐🏾" 1F450-1F3FE
+    0x0BF99526,  // "👨🏼\u200d🤝\u200d👨🏾" 1F468-1F3FC-200D-1F91D-200D-1F468-1F3FE
+    0x0BFA6384,  // "💰️" 1F4B0-FE0F
+    0x0C025D01,  // "💁🏾\u200d♂️" 1F481-1F3FE-200D-2642-FE0F
+    0x0C09803E,  // "🏵️" 1F3F5-FE0F
+    0x0C1D7718,  // "👩🏻" 1F469-1F3FB
+    0x0C29BE00,  // "👩🏿\u200d🦲" 1F469-1F3FF-200D-1F9B2
+    0x0D27FF2B,  // "👨🏼\u200d🔧" 1F468-1F3FC-200D-1F527
+    0x0D3CADB4,  // "👨\u200d👧\u200d👦" 1F468-200D-1F467-200D-1F466
+    0x0D96A9DD,  // "🗞️" 1F5DE-FE0F
+    0x0DDC8C14,  // "💆🏻" 1F486-1F3FB
+    0x0DF9B2B6,  // "👱🏻" 1F471-1F3FB
+    0x0E2EF45D,  // "🐕\u200d🦺" 1F415-200D-1F9BA
+    0x0E379971,  // "👨🏾\u200d🍼" 1F468-1F3FE-200D-1F37C
+    0x0E409BB1,  // "🕺🏼" 1F57A-1F3FC
+    0x0E7641B6,  // "👨\u200d🔧" 1F468-200D-1F527
+    0x0EB4D997,  // "💪🏼" 1F4AA-1F3FC
+    0x0F36E9BA,  // "🏋🏾" 1F3CB-1F3FE
+    0x0F5AF62A,  // "🏴\U000e0067\U000e0062\U000e0065\U000e006e\U000e0067\U000e007f" 1F3F4-E0067-E0062-E0065-E006E-E0067-E007F
+    0x105D99E0,  // "👩🏼\u200d🦰" 1F469-1F3FC-200D-1F9B0
+    0x107B5B3A,  // "👊🏽" 1F44A-1F3FD
+    0x109026D4,  // "👮🏽\u200d♂️" 1F46E-1F3FD-200D-2642-FE0F
+    0x1109456B,  // "👩🏽\u200d🍳" 1F469-1F3FD-200D-1F373
+    0x1140C9D7,  // "🏌🏽\u200d♀️" 1F3CC-1F3FD-200D-2640-FE0F
+    0x114A9770,  // "👩🏿\u200d⚖️" 1F469-1F3FF-200D-2696-FE0F
+    0x11D6DBE6,  // "🕘️" 1F558-FE0F
+    0x121EC9E9,  // "👨🏽\u200d🍼" 1F468-1F3FD-200D-1F37C
+    0x1234CD81,  // "🕞️" 1F55E-FE0F
+    0x126EF4BF,  // "👩🏻\u200d🦼" 1F469-1F3FB-200D-1F9BC
+    0x126F620C,  // "👩🏼\u200d🤝\u200d👩🏻" 1F469-1F3FC-200D-1F91D-200D-1F469-1F3FB
+    0x12DD10EA,  // "👨🏾\u200d🚀" 1F468-1F3FE-200D-1F680
+    0x12F673A6,  // "👩\u200d🍳" 1F469-200D-1F373
+    0x12FDDC36,  // "👨🏻\u200d🤝\u200d👨🏼" 1F468-1F3FB-200D-1F91D-200D-1F468-1F3FC
+    0x13520FDF,  // "🎗️" 1F397-FE0F
+    0x1385F386,  // "🕗️" 1F557-FE0F
+    0x138F792C,  // "📫️" 1F4EB-FE0F
+    0x13C0A884,  // "👩\u200d👧" 1F469-200D-1F467
+    0x14130F22,  // "👨🏽\u200d⚕️" 1F468-1F3FD-200D-2695-FE0F
+    0x1453C757,  // "💃🏾" 1F483-1F3FE
+    0x1474AA1B,  // "💂🏼\u200d♂️" 1F482-1F3FC-200D-2642-FE0F
+    0x148A2717,  // "💃🏿" 1F483-1F3FF
+    0x148F225F,  // "👩🏻\u200d✈️" 1F469-1F3FB-200D-2708-FE0F
+    0x14C4A6F5,  // "🏎️" 1F3CE-FE0F
+    0x15116AA4,  // "👨🏼\u200d🚒" 1F468-1F3FC-200D-1F692
+    0x152FA47E,  // "👩🏾\u200d🦯" 1F469-1F3FE-200D-1F9AF
+    0x155D0CC8,  // "👨\u200d🎓" 1F468-200D-1F393
+    0x156996D1,  // "🎞️" 1F39E-FE0F
+    0x158D8511,  // "👰🏿\u200d♀️" 1F470-1F3FF-200D-2640-FE0F
+    0x1599CE77,  // "👨🏾\u200d⚕️" 1F468-1F3FE-200D-2695-FE0F
+    0x15F22037,  // "👩🏾\u200d🎓" 1F469-1F3FE-200D-1F393
+    0x1633A5DD,  // "🕑️" 1F551-FE0F
+    0x16416C17,  // "🏜️" 1F3DC-FE0F
+    0x16F209CC,  // "👩🏽\u200d🤝\u200d👨🏼" 1F469-1F3FD-200D-1F91D-200D-1F468-1F3FC
+    0x17B6EF8C,  // "👨🏾\u200d💻" 1F468-1F3FE-200D-1F4BB
+    0x17BC8048,  // "👦🏻" 1F466-1F3FB
+    0x17FD1FF7,  // "👨🏾\u200d🍳" 1F468-1F3FE-200D-1F373
+    0x1807183A,  // "👩\u200d❤️\u200d💋\u200d👨" 1F469-200D-2764-FE0F-200D-1F48B-200D-1F468
+    0x18D4ABB6,  // "👂🏽" 1F442-1F3FD
+    0x18E48985,  // "🌏️" 1F30F-FE0F
+    0x1911C57E,  // "📬️" 1F4EC-FE0F
+    0x19D34663,  // "👍🏾" 1F44D-1F3FE
+    0x1A3A29DC,  // "👨🏻\u200d🎨" 1F468-1F3FB-200D-1F3A8
+    0x1A43FC08,  // "👩🏼\u200d✈️" 1F469-1F3FC-200D-2708-FE0F
+    0x1A695053,  // "🏄🏿\u200d♀️" 1F3C4-1F3FF-200D-2640-FE0F
+    0x1A6BBBE8,  // "👊🏻" 1F44A-1F3FB
+    0x1A7F3D36,  // "🕵️" 1F575-FE0F
+    0x1AB32F25,  // "👦🏼" 1F466-1F3FC
+    0x1ABA6A06,  // "🍽️" 1F37D-FE0F
+    0x1AC7C5F5,  // "👩🏿\u200d🤝\u200d👨🏽" 1F469-1F3FF-200D-1F91D-200D-1F468-1F3FD
+    0x1B44223A,  // "👬🏻" 1F46C-1F3FB
     0x1B97D4D3,  // "👰🏻\u200d♀️" 1F470-1F3FB-200D-2640-FE0F
     0x1B9FA314,  // "👨\u200d👩\u200d👧" 1F468-200D-1F469-200D-1F467
     0x1BA2EBE1,  // "👩\u200d🦱" 1F469-200D-1F9B1
-    0x1BAA3573,  // "🏹"
     0x1BC2A154,  // "👈🏼" 1F448-1F3FC
-    0x1BC323CC,  // "📂"
     0x1BEABDB6,  // "👴🏿" 1F474-1F3FF
     0x1C1603ED,  // "💪🏿" 1F4AA-1F3FF
-    0x1C1AE208,  // "📉"
     0x1C2C4E30,  // "👩🏿\u200d🦽" 1F469-1F3FF-200D-1F9BD
     0x1C8A66D9,  // "👨\u200d⚖️" 1F468-200D-2696-FE0F
     0x1C99E7BE,  // "💂🏿" 1F482-1F3FF
     0x1CB2F060,  // "🏇🏽" 1F3C7-1F3FD
     0x1CC08EC7,  // "👩🏽\u200d🎨" 1F469-1F3FD-200D-1F3A8
     0x1CDE8273,  // "🗒️" 1F5D2-FE0F
-    0x1CE611F5,  // "💍"
     0x1D63F0B4,  // "🏝️" 1F3DD-FE0F
     0x1DA54475,  // "👩🏾\u200d✈️" 1F469-1F3FE-200D-2708-FE0F
     0x1DBFAD7B,  // "👨🏼\u200d🦽" 1F468-1F3FC-200D-1F9BD
-    0x1DCDFA8C,  // "🏘"
     0x1E6F86B0,  // "🎅🏾" 1F385-1F3FE
-    0x1EA33E89,  // "🎺"
-    0x1EA69E6D,  // "👫"
     0x1EA7C208,  // "👩🏼\u200d🤝\u200d👨🏿" 1F469-1F3FC-200D-1F91D-200D-1F468-1F3FF
     0x1EBA85B7,  // "👩🏻\u200d🦽" 1F469-1F3FB-200D-1F9BD
     0x1EF7C13A,  // "🕡️" 1F561-FE0F
     0x1F075F25,  // "👨🏿\u200d🚀" 1F468-1F3FF-200D-1F680
-    0x1F131CCD,  // "🐃"
-    0x1F1B2FAB,  // "🔟"
-    0x1F28E41B,  // "🐘"
-    0x1F3DB05F,  // "🌹"
     0x1F4FDF22,  // "👨\u200d👩\u200d👧\u200d👧" 1F468-200D-1F469-200D-1F467-200D-1F467
     0x1F6765C2,  // "👮🏼" 1F46E-1F3FC
-    0x1F724509,  // "🗃"
     0x1F88E31F,  // "👷🏽\u200d♀️" 1F477-1F3FD-200D-2640-FE0F
-    0x1FF7F561,  // "🍎"
     0x201CB05D,  // "🏕️" 1F3D5-FE0F
-    0x203C0D09,  // "💎"
-    0x2042217D,  // "🔚"
-    0x2045D7A2,  // "👢"
     0x20512A6E,  // "👸🏿" 1F478-1F3FF
-    0x2079F43B,  // "🍐"
-    0x20837B06,  // "🕰"
     0x208F083F,  // "👲🏾" 1F472-1F3FE
     0x20AB8346,  // "👨🏾\u200d🦯" 1F468-1F3FE-200D-1F9AF
-    0x20BD6857,  // "🏀"
-    0x20E22B10,  // "🐍"
-    0x218EA315,  // "🖱"
-    0x21E2665C,  // "💱"
-    0x21F07B5F,  // "🖍"
-    0x220C36A7,  // "🌰"
     0x2241E54F,  // "👨🏼\u200d🤝\u200d👨🏻" 1F468-1F3FC-200D-1F91D-200D-1F468-1F3FB
     0x224A4937,  // "💳️" 1F4B3-FE0F
     0x225BD0AF,  // "💂🏽" 1F482-1F3FD
     0x225CE3FE,  // "👩🏽\u200d🦼" 1F469-1F3FD-200D-1F9BC
     0x22725657,  // "👩🏻\u200d🔧" 1F469-1F3FB-200D-1F527
-    0x228BEA4D,  // "🌪"
     0x22B8196A,  // "💁\u200d♀️" 1F481-200D-2640-FE0F
-    0x22C0F1C6,  // "🗺"
     0x22C84D0C,  // "👎🏻" 1F44E-1F3FB
     0x2349C1C1,  // "👨🏽\u200d🏫" 1F468-1F3FD-200D-1F3EB
     0x235E36F8,  // "👩🏻\u200d🎤" 1F469-1F3FB-200D-1F3A4
@@ -2344,33 +3986,16 @@ const HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1648] = [
     0x238AEE1B,  // "👰🏾\u200d♂️" 1F470-1F3FE-200D-2642-FE0F
     0x23B20675,  // "👭🏿" 1F46D-1F3FF
     0x23D74FF5,  // "🏟️" 1F3DF-FE0F
-    0x2406EBCC,  // "💌"
-    0x245C6410,  // "👰"
-    0x24A5DF1D,  // "🍲"
     0x24BDC400,  // "🏊🏽" 1F3CA-1F3FD
     0x24C8D44C,  // "👩🏾\u200d🚒" 1F469-1F3FE-200D-1F692
-    0x24EACF48,  // "🎾"
-    0x2506707E,  // "🖕"
     0x25699409,  // "👨🏿\u200d🌾" 1F468-1F3FF-200D-1F33E
     0x257299CE,  // "👷🏼\u200d♂️" 1F477-1F3FC-200D-2642-FE0F
     0x257B3C23,  // "👶🏿" 1F476-1F3FF
-    0x25A12E93,  // "🏓"
-    0x266C8DAB,  // "🎉"
-    0x26718A6B,  // "👯"
-    0x26ED817C,  // "👬"
     0x26EDB6C8,  // "👨🏼\u200d🚀" 1F468-1F3FC-200D-1F680
-    0x2706FF16,  // "🎂"
-    0x27956B93,  // "💚"
     0x28065BB6,  // "👆🏼" 1F446-1F3FC
-    0x281AA90B,  // "👒"
     0x28376EFD,  // "👩🏿\u200d💼" 1F469-1F3FF-200D-1F4BC
     0x285ADA3F,  // "👨🏾\u200d🎄" 1F468-1F3FE-200D-1F384
-    0x286729BB,  // "💏"
     0x28B53963,  // "🏳️\u200d⚧️" 1F3F3-FE0F-200D-26A7-FE0F
-    0x28D2E73F,  // "🌐"
-    0x290D486B,  // "🕍"
-    0x2921C841,  // "📵"
-    0x2923325E,  // "🐕"
     0x29736454,  // "📻️" 1F4FB-FE0F
     0x297ED329,  // "💇🏾\u200d♀️" 1F487-1F3FE-200D-2640-FE0F
     0x29A72643,  // "👨🏽\u200d🤝\u200d👨🏼" 1F468-1F3FD-200D-1F91D-200D-1F468-1F3FC
@@ -2379,104 +4004,65 @@ const HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1648] = [
     0x29D00126,  // "👩🏽\u200d🤝\u200d👩🏼" 1F469-1F3FD-200D-1F91D-200D-1F469-1F3FC
     0x2A3CF978,  // "👩🏽\u200d🔧" 1F469-1F3FD-200D-1F527
     0x2A544168,  // "🏖️" 1F3D6-FE0F
-    0x2A72FAEB,  // "🌎"
     0x2A8443F7,  // "🐿️" 1F43F-FE0F
     0x2AB245F4,  // "📋️" 1F4CB-FE0F
-    0x2AC6C788,  // "🐼"
-    0x2B1AD89F,  // "👜"
-    0x2B1F5D9D,  // "🔠"
     0x2B33A95C,  // "👩🏽\u200d🦲" 1F469-1F3FD-200D-1F9B2
     0x2B673868,  // "🎓️" 1F393-FE0F
-    0x2B730543,  // "👆"
     0x2B8846C6,  // "🕵🏻" 1F575-1F3FB
-    0x2BE7A655,  // "🗾"
-    0x2C1AF39D,  // "🕉"
     0x2C221F4B,  // "👨🏿\u200d🦰" 1F468-1F3FF-200D-1F9B0
     0x2C5AC143,  // "👐🏿" 1F450-1F3FF
     0x2C80FCE2,  // "👮\u200d♂️" 1F46E-200D-2642-FE0F
     0x2C8A57BF,  // "🏆️" 1F3C6-FE0F
-    0x2C8C17C5,  // "🔆"
     0x2CAA8E4F,  // "🏇🏾" 1F3C7-1F3FE
     0x2D1E41CE,  // "👸🏻" 1F478-1F3FB
     0x2D32D9A2,  // "🏄🏿\u200d♂️" 1F3C4-1F3FF-200D-2642-FE0F
     0x2D344D27,  // "👮\u200d♀️" 1F46E-200D-2640-FE0F
     0x2D5AFFBD,  // "👨🏽\u200d🎨" 1F468-1F3FD-200D-1F3A8
-    0x2E1A28F7,  // "📃"
-    0x2E446665,  // "🍇"
     0x2E5DD8C9,  // "💃🏼" 1F483-1F3FC
     0x2E6FB323,  // "🕵️\u200d♂️" 1F575-FE0F-200D-2642-FE0F
     0x2E7F74E3,  // "👋🏾" 1F44B-1F3FE
     0x2E9DFA85,  // "👨\u200d👨\u200d👧\u200d👧" 1F468-200D-1F468-200D-1F467-200D-1F467
     0x2EBC8273,  // "🏂🏽" 1F3C2-1F3FD
-    0x2ED60680,  // "🐌"
-    0x2F49D7CC,  // "💸"
-    0x2F9BFCCE,  // "🔐"
-    0x30251F19,  // "🌒"
     0x305C6D51,  // "👩🏻\u200d🏫" 1F469-1F3FB-200D-1F3EB
     0x308CE13B,  // "👨\u200d👩\u200d👦\u200d👦" 1F468-200D-1F469-200D-1F466-200D-1F466
     0x3097DB82,  // "👩🏼\u200d🤝\u200d👨🏻" 1F469-1F3FC-200D-1F91D-200D-1F468-1F3FB
-    0x30A58D63,  // "🎁"
-    0x30BD149E,  // "🍺"
     0x312BCBD8,  // "💂🏻\u200d♀️" 1F482-1F3FB-200D-2640-FE0F
-    0x312D1B5D,  // "🐄"
     0x31322A1E,  // "👨🏾\u200d🏭" 1F468-1F3FE-200D-1F3ED
-    0x313B6E54,  // "👟"
     0x31657E84,  // "🏊🏽\u200d♀️" 1F3CA-1F3FD-200D-2640-FE0F
-    0x3191E391,  // "🏥"
     0x31A19781,  // "🏃\u200d♂️" 1F3C3-200D-2642-FE0F
     0x31B0EA3E,  // "🏃🏿\u200d♂️" 1F3C3-1F3FF-200D-2642-FE0F
     0x31C3FF97,  // "👪️" 1F46A-FE0F
-    0x31C45890,  // "📹"
     0x3223464B,  // "🕣️" 1F563-FE0F
     0x329BC076,  // "👨\u200d🚒" 1F468-200D-1F692
-    0x32A1CD90,  // "🌨"
-    0x32AF87C2,  // "📢"
-    0x32C59FA2,  // "🌕"
     0x32CEA7F5,  // "👩🏾\u200d🌾" 1F469-1F3FE-200D-1F33E
-    0x330AE8B8,  // "🌴"
     0x3316B569,  // "👷🏽\u200d♂️" 1F477-1F3FD-200D-2642-FE0F
-    0x33C3D284,  // "🌋"
     0x344D57E1,  // "🏌🏻\u200d♀️" 1F3CC-1F3FB-200D-2640-FE0F
-    0x34A210FD,  // "👦"
     0x34AE08D5,  // "👩🏾\u200d🤝\u200d👩🏻" 1F469-1F3FE-200D-1F91D-200D-1F469-1F3FB
     0x351EE6E6,  // "🕴️\u200d♂️" 1F574-FE0F-200D-2642-FE0F
-    0x354CEEFF,  // "🗡"
     0x3553E91E,  // "👩\u200d🦯" 1F469-200D-1F9AF
-    0x3632BFC7,  // "🏖"
     0x3639B7C1,  // "👨🏽\u200d🤝\u200d👨🏿" 1F468-1F3FD-200D-1F91D-200D-1F468-1F3FF
     0x366C11BF,  // "👨🏽\u200d🦲" 1F468-1F3FD-200D-1F9B2
-    0x36864986,  // "📜"
-    0x36A8C572,  // "👀"
     0x36CC16D3,  // "👸🏼" 1F478-1F3FC
     0x36DE7D96,  // "👳🏻\u200d♀️" 1F473-1F3FB-200D-2640-FE0F
     0x374F73D7,  // "👩🏼\u200d🎄" 1F469-1F3FC-200D-1F384
     0x376F32D8,  // "🖐🏼" 1F590-1F3FC
     0x37A33F6C,  // "👩🏼\u200d🤝\u200d👩🏾" 1F469-1F3FC-200D-1F91D-200D-1F469-1F3FE
-    0x37E2D379,  // "🍑"
     0x37E50C9D,  // "🕒️" 1F552-FE0F
     0x385A676B,  // "👨🏻\u200d🎓" 1F468-1F3FB-200D-1F393
     0x3888A6CA,  // "👷🏻\u200d♂️" 1F477-1F3FB-200D-2642-FE0F
-    0x394E7D19,  // "📪"
-    0x39601D09,  // "🐲"
     0x39DBF5A5,  // "👸🏾" 1F478-1F3FE
-    0x3A0CCA46,  // "📕"
     0x3A13104E,  // "🌨️" 1F328-FE0F
     0x3A4E584B,  // "👇️" 1F447-FE0F
     0x3A509F6B,  // "👰🏼\u200d♂️" 1F470-1F3FC-200D-2642-FE0F
     0x3AABB7B3,  // "🐻\u200d❄️" 1F43B-200D-2744-FE0F
-    0x3AC69857,  // "🎑"
     0x3B539297,  // "👊🏾" 1F44A-1F3FE
-    0x3B620672,  // "👄"
     0x3B7A1D9C,  // "👩🏿\u200d🎓" 1F469-1F3FF-200D-1F393
     0x3B996C6B,  // "💆🏿" 1F486-1F3FF
     0x3B9B8761,  // "👨🏻\u200d🏭" 1F468-1F3FB-200D-1F3ED
     0x3BAAD888,  // "🏭️" 1F3ED-FE0F
     0x3BB89950,  // "👩🏾\u200d🍼" 1F469-1F3FE-200D-1F37C
     0x3BD7E03A,  // "👵🏼" 1F475-1F3FC
-    0x3BFFFC1E,  // "🏳"
-    0x3C63DA72,  // "🌁"
     0x3C7238FF,  // "👨\u200d🎤" 1F468-200D-1F3A4
-    0x3C834934,  // "🌲"
     0x3C9570FE,  // "🖖🏼" 1F596-1F3FC
     0x3C9E9C0D,  // "👨🏾\u200d💼" 1F468-1F3FE-200D-1F4BC
     0x3CA5C2A0,  // "👇🏿" 1F447-1F3FF
@@ -2489,157 +4075,85 @@ const HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1648] = [
     0x3D6B02ED,  // "👩🏿\u200d🏫" 1F469-1F3FF-200D-1F3EB
     0x3D88F2BB,  // "👩🏽\u200d🤝\u200d👨🏾" 1F469-1F3FD-200D-1F91D-200D-1F468-1F3FE
     0x3D972DCA,  // "🍸️" 1F378-FE0F
-    0x3DB3B992,  // "📚"
     0x3DD4D19E,  // "🕴🏻" 1F574-1F3FB
-    0x3E1E8EDC,  // "🏧"
-    0x3ED3A2F9,  // "🔑"
     0x3ED3F415,  // "🌥️" 1F325-FE0F
-    0x3EFF95D2,  // "🐤"
     0x3F109C76,  // "🌶️" 1F336-FE0F
-    0x3F26BC30,  // "📦"
     0x3FD18FC5,  // "👨🏻\u200d🦱" 1F468-1F3FB-200D-1F9B1
-    0x3FDC0EF1,  // "📳"
     0x3FFC208F,  // "👨🏿\u200d🔬" 1F468-1F3FF-200D-1F52C
-    0x400652A3,  // "🐖"
-    0x4083D9DD,  // "🌳"
     0x4088F0E3,  // "👎🏾" 1F44E-1F3FE
     0x40B329EC,  // "👧🏿" 1F467-1F3FF
     0x40D0EDA0,  // "👩🏼" 1F469-1F3FC
     0x410019FF,  // "👨🏾\u200d🤝\u200d👨🏼" 1F468-1F3FE-200D-1F91D-200D-1F468-1F3FC
-    0x411C6AB6,  // "🕦"
     0x4240AAA2,  // "🏊🏿\u200d♀️" 1F3CA-1F3FF-200D-2640-FE0F
     0x425FD789,  // "🗡️" 1F5E1-FE0F
     0x42838F3B,  // "💇🏽" 1F487-1F3FD
-    0x429CF05A,  // "🎬"
-    0x42B47299,  // "🎵"
     0x42E5FB08,  // "👶🏾" 1F476-1F3FE
     0x42EBA7B5,  // "👨🏾\u200d🎓" 1F468-1F3FE-200D-1F393
-    0x4326AE44,  // "🔭"
     0x434955E8,  // "👩\u200d🚀" 1F469-200D-1F680
     0x435194D7,  // "👨\u200d👦" 1F468-200D-1F466
-    0x4353E59A,  // "👁"
     0x436660A2,  // "👩🏿\u200d🎨" 1F469-1F3FF-200D-1F3A8
     0x43805DB8,  // "🕦️" 1F566-FE0F
     0x43C29BB6,  // "🕖️" 1F556-FE0F
     0x43EDD463,  // "🖕🏻" 1F595-1F3FB
     0x43F795AA,  // "👊🏼" 1F44A-1F3FC
-    0x444E747D,  // "🍘"
     0x44632F1B,  // "👨🏻\u200d🚀" 1F468-1F3FB-200D-1F680
-    0x447E33F9,  // "🔵"
-    0x4483D259,  // "💁"
-    0x44910FBE,  // "🎋"
-    0x44A37EF2,  // "🔥"
-    0x4505012F,  // "💾"
-    0x45236216,  // "🔏"
-    0x4584D508,  // "🐝"
-    0x45EF395C,  // "🐫"
-    0x45F33D87,  // "📭"
     0x45F5DC59,  // "👶🏼" 1F476-1F3FC
-    0x45FED4CE,  // "🔮"
-    0x4607FD38,  // "🌌"
-    0x462781CE,  // "🕎"
-    0x4677CDB7,  // "🎎"
     0x46F96104,  // "👩\u200d👩\u200d👧\u200d👧" 1F469-200D-1F469-200D-1F467-200D-1F467
-    0x4707474F,  // "💘"
-    0x4730010D,  // "🐵"
     0x4733374E,  // "🖊️" 1F58A-FE0F
-    0x473CDA5D,  // "🍗"
     0x47519BBA,  // "💁🏽\u200d♂️" 1F481-1F3FD-200D-2642-FE0F
     0x47A69603,  // "👨\u200d💼" 1F468-200D-1F4BC
     0x47A8EEB9,  // "🏌️" 1F3CC-FE0F
     0x481C52F8,  // "👨🏾\u200d🦳" 1F468-1F3FE-200D-1F9B3
     0x4851A95E,  // "👩🏽\u200d🦳" 1F469-1F3FD-200D-1F9B3
     0x48532DA3,  // "👩\u200d🏫" 1F469-200D-1F3EB
-    0x48844286,  // "🏤"
-    0x489AED87,  // "🐿"
-    0x48AFF9A9,  // "🖖"
     0x48CB6817,  // "👷🏿\u200d♀️" 1F477-1F3FF-200D-2640-FE0F
-    0x48E22126,  // "🌗"
     0x4921C875,  // "👩🏼\u200d🤝\u200d👩🏿" 1F469-1F3FC-200D-1F91D-200D-1F469-1F3FF
-    0x4932E325,  // "🏈"
     0x4962EEE7,  // "👩🏽\u200d✈️" 1F469-1F3FD-200D-2708-FE0F
-    0x499002C7,  // "🏒"
     0x4998B3DD,  // "👩🏽\u200d💻" 1F469-1F3FD-200D-1F4BB
     0x4999FDC0,  // "👩🏻\u200d🎄" 1F469-1F3FB-200D-1F384
-    0x49AD3E68,  // "🔣"
     0x49DE00E7,  // "👫🏾" 1F46B-1F3FE
     0x49E88260,  // "🗣️" 1F5E3-FE0F
     0x49EBC818,  // "👩\u200d⚖️" 1F469-200D-2696-FE0F
-    0x49F2012D,  // "🌛"
-    0x4A07B1DF,  // "📲"
-    0x4A1F2703,  // "🗻"
-    0x4A27A22B,  // "💋"
     0x4A6C07D8,  // "👩\u200d❤️\u200d👨" 1F469-200D-2764-FE0F-200D-1F468
     0x4A6F91ED,  // "👨🏼\u200d⚖️" 1F468-1F3FC-200D-2696-FE0F
     0x4A9BE8E5,  // "🗺️" 1F5FA-FE0F
     0x4A9F6657,  // "💁🏽" 1F481-1F3FD
     0x4AB9CBB7,  // "🏇🏻" 1F3C7-1F3FB
     0x4B33C9A9,  // "🕳️" 1F573-FE0F
-    0x4B343E76,  // "🌊"
     0x4B60BFE6,  // "💂🏾\u200d♀️" 1F482-1F3FE-200D-2640-FE0F
     0x4BA36B7F,  // "🏃\u200d♀️" 1F3C3-200D-2640-FE0F
     0x4C29A833,  // "🕶️" 1F576-FE0F
     0x4C7B471A,  // "👨🏾\u200d🎨" 1F468-1F3FE-200D-1F3A8
-    0x4CA15B05,  // "🏦"
-    0x4CAEF25D,  // "🔺"
     0x4CCCC36B,  // "👨🏼\u200d💼" 1F468-1F3FC-200D-1F4BC
-    0x4CD98885,  // "🏅"
     0x4CDED7CD,  // "💃🏽" 1F483-1F3FD
     0x4CE60757,  // "👫🏼" 1F46B-1F3FC
     0x4D21A222,  // "👩\u200d👩\u200d👦\u200d👦" 1F469-200D-1F469-200D-1F466-200D-1F466
     0x4D6A12F6,  // "🕸️" 1F578-FE0F
-    0x4D73FAE9,  // "💄"
-    0x4E06CD0F,  // "🌿"
     0x4E5DE601,  // "📚️" 1F4DA-FE0F
-    0x4E99E7F0,  // "🌞"
-    0x4EAF2F60,  // "🍦"
     0x4EB274DE,  // "🏞️" 1F3DE-FE0F
     0x4F254111,  // "👉️" 1F449-FE0F
-    0x4F2D9915,  // "🌾"
     0x4F2E693D,  // "📤️" 1F4E4-FE0F
     0x4F4B337A,  // "👂🏿" 1F442-1F3FF
     0x4F61359B,  // "🏃🏽" 1F3C3-1F3FD
     0x4F8FEBCE,  // "👩🏽\u200d🔬" 1F469-1F3FD-200D-1F52C
     0x4F9347D1,  // "🕉️" 1F549-FE0F
-    0x4F9B44B9,  // "🔄"
     0x4FBA357E,  // "👩🏿\u200d🤝\u200d👩🏾" 1F469-1F3FF-200D-1F91D-200D-1F469-1F3FE
     0x50168005,  // "👩\u200d💻" 1F469-200D-1F4BB
-    0x502020F7,  // "🏜"
-    0x509B3EFD,  // "👪"
     0x50C21928,  // "👨🏼\u200d🏭" 1F468-1F3FC-200D-1F3ED
-    0x50D164C0,  // "🕠"
-    0x50D1B527,  // "🐶"
-    0x511CDE79,  // "💵"
-    0x5147787D,  // "🍴"
     0x51575488,  // "🕺🏾" 1F57A-1F3FE
-    0x517154A4,  // "👔"
     0x517F7219,  // "👩🏿\u200d🚀" 1F469-1F3FF-200D-1F680
     0x51E3D7D6,  // "👨🏾\u200d🦱" 1F468-1F3FE-200D-1F9B1
     0x52189FF0,  // "👨\u200d🚀" 1F468-200D-1F680
     0x522C2710,  // "🐟️" 1F41F-FE0F
-    0x522C9A7B,  // "🍧"
     0x523847D2,  // "👩🏻\u200d⚕️" 1F469-1F3FB-200D-2695-FE0F
-    0x52428A18,  // "🔈"
-    0x52452060,  // "🐯"
     0x52671D9E,  // "👮🏻\u200d♂️" 1F46E-1F3FB-200D-2642-FE0F
     0x527B5AA6,  // "🕴️\u200d♀️" 1F574-FE0F-200D-2640-FE0F
     0x52AF6FF9,  // "👨\u200d👨\u200d👧\u200d👦" 1F468-200D-1F468-200D-1F467-200D-1F466
     0x52B8FC2D,  // "👩🏿\u200d🚒" 1F469-1F3FF-200D-1F692
     0x52C4E133,  // "👨\u200d💻" 1F468-200D-1F4BB
-    0x52C5E02A,  // "🔔"
     0x52CE25F0,  // "👏🏽" 1F44F-1F3FD
-    0x532B3539,  // "🕑"
-    0x536087B8,  // "👉"
-    0x5365DA29,  // "🕟"
-    0x536DF385,  // "📨"
-    0x537B5F0B,  // "🐆"
     0x5384D793,  // "👨🏽\u200d🦼" 1F468-1F3FD-200D-1F9BC
-    0x5435133A,  // "🏩"
-    0x548AD4A4,  // "👨"
-    0x54F387E4,  // "🍵"
-    0x5511789F,  // "📄"
     0x552D7E1D,  // "🏌🏻" 1F3CC-1F3FB
-    0x553227BA,  // "🌑"
     0x555D350E,  // "💅🏻" 1F485-1F3FB
     0x55662BFA,  // "👨🏾\u200d🦼" 1F468-1F3FE-200D-1F9BC
     0x556A10CA,  // "🖕🏽" 1F595-1F3FD
@@ -2648,113 +4162,70 @@ const HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1648] = [
     0x55EF37CB,  // "👨🏾\u200d🔧" 1F468-1F3FE-200D-1F527
     0x5615E985,  // "👂️" 1F442-FE0F
     0x561C7BB1,  // "👨\u200d👨\u200d👦" 1F468-200D-1F468-200D-1F466
-    0x568DC07C,  // "🍍"
     0x568E62E8,  // "🕵🏼\u200d♀️" 1F575-1F3FC-200D-2640-FE0F
     0x56B1CEAE,  // "👨🏿\u200d🏫" 1F468-1F3FF-200D-1F3EB
-    0x56CD0454,  // "📝"
-    0x5708788C,  // "🕧"
-    0x571E1F41,  // "👓"
     0x5731D21E,  // "🌫️" 1F32B-FE0F
     0x5766B00A,  // "👷🏼\u200d♀️" 1F477-1F3FC-200D-2640-FE0F
     0x577AE286,  // "👨\u200d👨\u200d👧" 1F468-200D-1F468-200D-1F467
     0x57819FBA,  // "👩🏻\u200d💻" 1F469-1F3FB-200D-1F4BB
     0x57B3CF02,  // "👨\u200d👦\u200d👦" 1F468-200D-1F466-200D-1F466
-    0x57CACDB2,  // "🔂"
     0x57F379D5,  // "👱🏽\u200d♀️" 1F471-1F3FD-200D-2640-FE0F
-    0x580E90F6,  // "🌩"
     0x581885E6,  // "👭🏽" 1F46D-1F3FD
     0x586D09F4,  // "👨🏼\u200d🔬" 1F468-1F3FC-200D-1F52C
-    0x58808B06,  // "🔧"
-    0x5891FEB1,  // "🌸"
     0x58CB3876,  // "👨🏾\u200d🦲" 1F468-1F3FE-200D-1F9B2
     0x58FC4350,  // "👨🏻\u200d✈️" 1F468-1F3FB-200D-2708-FE0F
     0x590598F8,  // "🎚️" 1F39A-FE0F
-    0x591AC8C3,  // "💼"
     0x591CCAB0,  // "👐🏻" 1F450-1F3FB
-    0x5955E6BF,  // "🏆"
-    0x599CC1E0,  // "📟"
-    0x59B6C3EF,  // "🔩"
-    0x5A008312,  // "🕹"
-    0x5A3753B9,  // "💃"
-    0x5A5B9CCE,  // "🐇"
     0x5AEA2009,  // "👩🏽\u200d🎄" 1F469-1F3FD-200D-1F384
     0x5B18F4B6,  // "👩🏾\u200d🤝\u200d👨🏽" 1F469-1F3FE-200D-1F91D-200D-1F468-1F3FD
     0x5B37C5D5,  // "👳🏼\u200d♀️" 1F473-1F3FC-200D-2640-FE0F
     0x5C1C512B,  // "💂🏼" 1F482-1F3FC
-    0x5C4A9EB0,  // "🐉"
     0x5C88D744,  // "👇🏻" 1F447-1F3FB
     0x5C97BCC0,  // "👳\u200d♂️" 1F473-200D-2642-FE0F
-    0x5CC64810,  // "🌭"
     0x5CD03927,  // "👆🏽" 1F446-1F3FD
     0x5CD1F455,  // "👨🏼\u200d🎨" 1F468-1F3FC-200D-1F3A8
     0x5CF5C72B,  // "👩🏽\u200d🌾" 1F469-1F3FD-200D-1F33E
     0x5CFF026E,  // "🌩️" 1F329-FE0F
     0x5D1B0C0B,  // "🏄🏻\u200d♀️" 1F3C4-1F3FB-200D-2640-FE0F
-    0x5D8A8B57,  // "💓"
     0x5D940C78,  // "👨\u200d🍼" 1F468-200D-1F37C
-    0x5D956116,  // "🌍"
-    0x5DAFBD8E,  // "🌺"
     0x5DBE5184,  // "👨🏾\u200d🔬" 1F468-1F3FE-200D-1F52C
     0x5DD7E523,  // "🕵🏻\u200d♂️" 1F575-1F3FB-200D-2642-FE0F
     0x5DFC7489,  // "👭🏾" 1F46D-1F3FE
-    0x5E0EA225,  // "🗯"
-    0x5E130852,  // "💳"
     0x5E3B4246,  // "💅🏾" 1F485-1F3FE
     0x5E4C2A85,  // "👨🏻\u200d🍼" 1F468-1F3FB-200D-1F37C
-    0x5E79E673,  // "🎠"
     0x5E9B1ECA,  // "🕴🏼\u200d♂️" 1F574-1F3FC-200D-2642-FE0F
     0x5ED4E79B,  // "👩🏻\u200d🚒" 1F469-1F3FB-200D-1F692
     0x5EF49069,  // "👩🏻\u200d🎨" 1F469-1F3FB-200D-1F3A8
     0x5F2F7865,  // "👮🏽" 1F46E-1F3FD
     0x5F3B26D7,  // "👍🏿" 1F44D-1F3FF
     0x5F46A472,  // "👩🏼\u200d🍼" 1F469-1F3FC-200D-1F37C
-    0x5F6F4B02,  // "🕷"
     0x5F724687,  // "👩🏿" 1F469-1F3FF
-    0x5F846932,  // "🌅"
     0x5F90A7CE,  // "👨\u200d🌾" 1F468-200D-1F33E
     0x5F94B790,  // "👩\u200d🦲" 1F469-200D-1F9B2
     0x5F9C9078,  // "👎️" 1F44E-FE0F
-    0x5FB24B5F,  // "🍱"
-    0x5FC72A95,  // "🏽"
     0x5FD02DC4,  // "🏄🏻" 1F3C4-1F3FB
     0x60160DD6,  // "👨🏿\u200d🎨" 1F468-1F3FF-200D-1F3A8
     0x6028D968,  // "👩\u200d🌾" 1F469-200D-1F33E
     0x6043EF14,  // "👩\u200d✈️" 1F469-200D-2708-FE0F
-    0x6071E1A8,  // "💯"
     0x60BC4C83,  // "👳🏽\u200d♂️" 1F473-1F3FD-200D-2642-FE0F
-    0x61132908,  // "💙"
-    0x6114970E,  // "💒"
     0x61158060,  // "👼🏿" 1F47C-1F3FF
     0x611604D6,  // "🏌🏽" 1F3CC-1F3FD
-    0x6156DF7F,  // "🌓"
     0x616981F0,  // "👱🏿\u200d♀️" 1F471-1F3FF-200D-2640-FE0F
-    0x61A360A8,  // "🐮"
     0x61C0C5AB,  // "🏋🏻" 1F3CB-1F3FB
-    0x61EB21D3,  // "🔷"
     0x61FCDAB0,  // "👩🏻\u200d🦳" 1F469-1F3FB-200D-1F9B3
     0x624A85F2,  // "🏌🏿\u200d♂️" 1F3CC-1F3FF-200D-2642-FE0F
     0x629E32ED,  // "👩🏻\u200d🦰" 1F469-1F3FB-200D-1F9B0
-    0x62B695CF,  // "🔊"
     0x62B85BF0,  // "👩🏻\u200d🎓" 1F469-1F3FB-200D-1F393
     0x62BA37E8,  // "👯\u200d♀️" 1F46F-200D-2640-FE0F
-    0x62BE039E,  // "📋"
     0x62C8AFA8,  // "💃🏻" 1F483-1F3FB
     0x6326E194,  // "🏋🏾\u200d♂️" 1F3CB-1F3FE-200D-2642-FE0F
-    0x6331534E,  // "📏"
     0x633E5774,  // "👩🏿\u200d🦯" 1F469-1F3FF-200D-1F9AF
     0x63D6BFA7,  // "🗯️" 1F5EF-FE0F
     0x63EC664E,  // "💆🏻\u200d♂️" 1F486-1F3FB-200D-2642-FE0F
-    0x6410C099,  // "💐"
     0x6416046D,  // "🕧️" 1F567-FE0F
-    0x645545C1,  // "👳"
     0x6467FF9C,  // "👩🏾" 1F469-1F3FE
     0x646C9260,  // "🏌️\u200d♀️" 1F3CC-FE0F-200D-2640-FE0F
-    0x6482B385,  // "🍂"
-    0x649CA150,  // "🗨"
-    0x64AF77C1,  // "💛"
     0x64B519F4,  // "👳🏻\u200d♂️" 1F473-1F3FB-200D-2642-FE0F
-    0x64C4B920,  // "🏏"
-    0x64D04BEC,  // "🏠"
     0x64EAA1CD,  // "👆️" 1F446-FE0F
     0x64F5DD10,  // "🌬️" 1F32C-FE0F
     0x64FC3521,  // "💂🏾" 1F482-1F3FE
@@ -2764,50 +4235,34 @@ const HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1648] = [
     0x6679F41E,  // "🌍️" 1F30D-FE0F
     0x66CCB18C,  // "👨🏾\u200d🚒" 1F468-1F3FE-200D-1F692
     0x66CEFC04,  // "👌🏻" 1F44C-1F3FB
-    0x66D3A315,  // "📰"
     0x67056BFC,  // "👩🏻\u200d🦲" 1F469-1F3FB-200D-1F9B2
-    0x67EB803D,  // "🌘"
-    0x67EC7147,  // "💥"
     0x67F6E206,  // "👨🏽\u200d✈️" 1F468-1F3FD-200D-2708-FE0F
     0x680306D2,  // "👩🏾\u200d🎤" 1F469-1F3FE-200D-1F3A4
-    0x6818145E,  // "🐅"
     0x68227EF0,  // "👩\u200d👦\u200d👦" 1F469-200D-1F466-200D-1F466
     0x6879C431,  // "👩🏾\u200d🤝\u200d👨🏿" 1F469-1F3FE-200D-1F91D-200D-1F468-1F3FF
     0x68B58BF0,  // "🖕🏾" 1F595-1F3FE
     0x68C4B77E,  // "👨🏻\u200d🦳" 1F468-1F3FB-200D-1F9B3
     0x68FBB44D,  // "👨🏽\u200d🌾" 1F468-1F3FD-200D-1F33E
-    0x68FD923C,  // "👠"
-    0x696942C7,  // "🔒"
     0x699675AB,  // "🕤️" 1F564-FE0F
     0x69B30183,  // "👮🏼\u200d♀️" 1F46E-1F3FC-200D-2640-FE0F
-    0x69EEA78A,  // "👾"
-    0x69F44257,  // "🌶"
     0x6A3C8FC2,  // "👩🏾\u200d💼" 1F469-1F3FE-200D-1F4BC
     0x6A40B944,  // "👴🏾" 1F474-1F3FE
     0x6ACCDBC9,  // "🕵🏽\u200d♀️" 1F575-1F3FD-200D-2640-FE0F
     0x6AFC7924,  // "👨🏿\u200d🤝\u200d👨🏻" 1F468-1F3FF-200D-1F91D-200D-1F468-1F3FB
-    0x6B0BF181,  // "🏫"
     0x6B1E149E,  // "🏋🏿" 1F3CB-1F3FF
     0x6B60A1E8,  // "👨🏼\u200d🎄" 1F468-1F3FC-200D-1F384
     0x6B6A3499,  // "🕔️" 1F554-FE0F
     0x6B81899A,  // "💇🏼" 1F487-1F3FC
     0x6B8C8B8C,  // "🕵🏼" 1F575-1F3FC
-    0x6B9B273F,  // "🏛"
     0x6BA9ABEE,  // "👨🏻\u200d🦰" 1F468-1F3FB-200D-1F9B0
-    0x6BB93823,  // "🕣"
     0x6BBD156D,  // "👩🏾\u200d🏭" 1F469-1F3FE-200D-1F3ED
-    0x6BBDB762,  // "🐸"
-    0x6BC7C518,  // "🔉"
     0x6BCCFB6F,  // "👨🏻\u200d🍳" 1F468-1F3FB-200D-1F373
     0x6BCD5F9F,  // "👨🏼\u200d🏫" 1F468-1F3FC-200D-1F3EB
     0x6BD64778,  // "👨\u200d🦱" 1F468-200D-1F9B1
     0x6C0164B4,  // "🖨️" 1F5A8-FE0F
-    0x6C1A185F,  // "💰"
-    0x6C21E173,  // "👎"
     0x6C3346D6,  // "👮🏿\u200d♂️" 1F46E-1F3FF-200D-2642-FE0F
     0x6C69C700,  // "👭🏻" 1F46D-1F3FB
     0x6CB7BD10,  // "👩🏿\u200d✈️" 1F469-1F3FF-200D-2708-FE0F
-    0x6D57F7AA,  // "💂"
     0x6D5B747C,  // "👃🏿" 1F443-1F3FF
     0x6D71E490,  // "💆🏾" 1F486-1F3FE
     0x6D724D7D,  // "👮🏼\u200d♂️" 1F46E-1F3FC-200D-2642-FE0F
@@ -2815,10 +4270,7 @@ const HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1648] = [
     0x6DBA39DB,  // "🖇️" 1F587-FE0F
     0x6DCE077D,  // "💂🏽\u200d♂️" 1F482-1F3FD-200D-2642-FE0F
     0x6E0759D1,  // "👉🏿" 1F449-1F3FF
-    0x6E3D55B7,  // "💀"
-    0x6EA5828A,  // "🐊"
     0x6EA646B5,  // "👱🏻\u200d♀️" 1F471-1F3FB-200D-2640-FE0F
-    0x6EF5F228,  // "🕜"
     0x6EFE2192,  // "👩🏾\u200d🤝\u200d👨🏼" 1F469-1F3FE-200D-1F91D-200D-1F468-1F3FC
     0x6F609A5F,  // "👩🏿\u200d💻" 1F469-1F3FF-200D-1F4BB
     0x6F6A595E,  // "👨🏾\u200d🏫" 1F468-1F3FE-200D-1F3EB
@@ -2829,7 +4281,6 @@ const HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1648] = [
     0x7020162F,  // "👨\u200d🍳" 1F468-200D-1F373
     0x70552A66,  // "👷\u200d♂️" 1F477-200D-2642-FE0F
     0x70629768,  // "🖋️" 1F58B-FE0F
-    0x706776FC,  // "🏝"
     0x7076D0C9,  // "🕴🏾\u200d♂️" 1F574-1F3FE-200D-2642-FE0F
     0x70D05A0D,  // "💂🏻\u200d♂️" 1F482-1F3FB-200D-2642-FE0F
     0x70E2223D,  // "🏊🏿\u200d♂️" 1F3CA-1F3FF-200D-2642-FE0F
@@ -2837,169 +4288,104 @@ const HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1648] = [
     0x7104B0F8,  // "👩🏿\u200d🦱" 1F469-1F3FF-200D-1F9B1
     0x71116E77,  // "👲🏿" 1F472-1F3FF
     0x711EE63E,  // "👯\u200d♂️" 1F46F-200D-2642-FE0F
-    0x713B31F4,  // "🕶"
-    0x714D1F2E,  // "🎡"
-    0x7151A328,  // "🔲"
-    0x71A2CD15,  // "💟"
     0x71AC9FA3,  // "🏚️" 1F3DA-FE0F
-    0x71AEFE27,  // "🐩"
     0x71B7A7EE,  // "👷\u200d♀️" 1F477-200D-2640-FE0F
-    0x71D3674F,  // "👈"
-    0x71DA2DB3,  // "🏣"
     0x71E0681E,  // "👌🏿" 1F44C-1F3FF
     0x71F55B97,  // "🌜️" 1F31C-FE0F
-    0x7207A29D,  // "🌙"
-    0x720F7DC8,  // "🏰"
     0x72518A74,  // "👩🏽" 1F469-1F3FD
     0x728E55E0,  // "👩🏾\u200d🦽" 1F469-1F3FE-200D-1F9BD
     0x729C27B3,  // "👨\u200d🔬" 1F468-200D-1F52C
-    0x72B9E678,  // "🔡"
     0x730501B9,  // "👨🏻\u200d🎄" 1F468-1F3FB-200D-1F384
     0x732A8AB9,  // "👳🏾" 1F473-1F3FE
-    0x73368E33,  // "🏾"
-    0x7347F158,  // "💡"
-    0x73592561,  // "👴"
-    0x7363DEE0,  // "🌻"
-    0x7370EB21,  // "🏐"
-    0x73972841,  // "💉"
     0x73A4131E,  // "🕯️" 1F56F-FE0F
     0x73DBEB0C,  // "👨🏿\u200d🤝\u200d👨🏽" 1F468-1F3FF-200D-1F91D-200D-1F468-1F3FD
     0x748FA531,  // "👩🏽\u200d🍼" 1F469-1F3FD-200D-1F37C
-    0x74A9DC44,  // "📫"
     0x74C0E8D9,  // "👩🏼\u200d⚕️" 1F469-1F3FC-200D-2695-FE0F
-    0x74CCB1D5,  // "🗣"
     0x7511ADA8,  // "💁🏿\u200d♀️" 1F481-1F3FF-200D-2640-FE0F
     0x7515EDA0,  // "👨🏻\u200d🚒" 1F468-1F3FB-200D-1F692
-    0x751C55E5,  // "🎏"
     0x752A80D2,  // "👈🏽" 1F448-1F3FD
-    0x75AB3612,  // "🏊"
-    0x75FE4F97,  // "💅"
-    0x762CEA0D,  // "🐴"
     0x763D1A2D,  // "🏂🏼" 1F3C2-1F3FC
     0x7647796E,  // "🕴🏽\u200d♀️" 1F574-1F3FD-200D-2640-FE0F
-    0x76623D97,  // "🌏"
     0x76647773,  // "🕴🏾" 1F574-1F3FE
     0x766FE273,  // "💁🏿" 1F481-1F3FF
     0x769C82BF,  // "👨🏿\u200d🎤" 1F468-1F3FF-200D-1F3A4
-    0x76A0CF24,  // "💆"
     0x76A95E56,  // "👩🏿\u200d🤝\u200d👨🏼" 1F469-1F3FF-200D-1F91D-200D-1F468-1F3FC
     0x76BACCD5,  // "🏌🏿" 1F3CC-1F3FF
     0x76D5125C,  // "👨🏽" 1F468-1F3FD
-    0x7738E9F0,  // "🔽"
     0x7751F147,  // "👩🏼\u200d🦼" 1F469-1F3FC-200D-1F9BC
     0x7759DD0F,  // "🎛️" 1F39B-FE0F
     0x7794CC9B,  // "💪🏾" 1F4AA-1F3FE
     0x77E04203,  // "👮🏿" 1F46E-1F3FF
-    0x77F1BD91,  // "👙"
-    0x783C7028,  // "🎌"
     0x788B7DF9,  // "🏊️" 1F3CA-FE0F
     0x78BCF250,  // "💆🏼\u200d♂️" 1F486-1F3FC-200D-2642-FE0F
-    0x78C54943,  // "🌱"
     0x78D82237,  // "👉🏻" 1F449-1F3FB
     0x78E16BC0,  // "🏂🏻" 1F3C2-1F3FB
-    0x797EC497,  // "🍁"
     0x79B780BE,  // "👨\u200d👧\u200d👧" 1F468-200D-1F467-200D-1F467
-    0x79C377EE,  // "🎒"
     0x7A056B52,  // "👨🏽\u200d🔬" 1F468-1F3FD-200D-1F52C
-    0x7A2CC0FD,  // "📑"
     0x7A2E383B,  // "🏋🏻\u200d♂️" 1F3CB-1F3FB-200D-2642-FE0F
-    0x7AC61030,  // "🍉"
     0x7AF641C7,  // "💁🏼" 1F481-1F3FC
-    0x7B08BDD8,  // "📍"
     0x7B186B31,  // "🎧️" 1F3A7-FE0F
     0x7BA7806D,  // "👰🏾" 1F470-1F3FE
-    0x7BE93468,  // "🏮"
-    0x7BFCF391,  // "🏉"
     0x7C301051,  // "👩\u200d🎤" 1F469-200D-1F3A4
     0x7C3C6DAF,  // "🔈️" 1F508-FE0F
     0x7C755CA8,  // "👨🏽\u200d🚒" 1F468-1F3FD-200D-1F692
     0x7CA229FC,  // "🗂️" 1F5C2-FE0F
     0x7CFFCF22,  // "🏄\u200d♂️" 1F3C4-200D-2642-FE0F
-    0x7D3D0F2B,  // "👧"
     0x7D518B62,  // "💇🏻\u200d♀️" 1F487-1F3FB-200D-2640-FE0F
-    0x7D8E21C9,  // "🐱"
     0x7D9A6EA3,  // "🕴️" 1F574-FE0F
     0x7DB771A9,  // "💂🏻" 1F482-1F3FB
     0x7DBD655E,  // "👨🏽\u200d🔧" 1F468-1F3FD-200D-1F527
     0x7DDFF6EF,  // "👨🏼\u200d🍳" 1F468-1F3FC-200D-1F373
-    0x7E04D335,  // "🕓"
     0x7E23FEC7,  // "👍🏽" 1F44D-1F3FD
     0x7E2F79DB,  // "🖕🏿" 1F595-1F3FF
-    0x7E30A3E6,  // "🕤"
     0x7E3E4927,  // "🏛️" 1F3DB-FE0F
     0x7E4295CB,  // "🏌🏾\u200d♂️" 1F3CC-1F3FE-200D-2642-FE0F
     0x7E7DA586,  // "🏊\u200d♂️" 1F3CA-200D-2642-FE0F
     0x7E859ADF,  // "👩🏼\u200d🦲" 1F469-1F3FC-200D-1F9B2
-    0x7EADF38E,  // "🌬"
     0x7EE101D8,  // "👨🏻\u200d🤝\u200d👨🏿" 1F468-1F3FB-200D-1F91D-200D-1F468-1F3FF
     0x7F3DA3CA,  // "💇🏻\u200d♂️" 1F487-1F3FB-200D-2642-FE0F
-    0x7F400D7D,  // "🏂"
-    0x7FEE0C52,  // "🕙"
     0x80238726,  // "💅🏼" 1F485-1F3FC
     0x8042856D,  // "👩🏾\u200d🦲" 1F469-1F3FE-200D-1F9B2
-    0x8045558E,  // "📆"
     0x8046FDDF,  // "👩🏻\u200d🤝\u200d👨🏾" 1F469-1F3FB-200D-1F91D-200D-1F468-1F3FE
     0x80557309,  // "🏴\U000e0067\U000e0062\U000e0077\U000e006c\U000e0073\U000e007f" 1F3F4-E0067-E0062-E0077-E006C-E0073-E007F
     0x80652737,  // "🕕️" 1F555-FE0F
     0x80839C9C,  // "👨\u200d👨\u200d👦\u200d👦" 1F468-200D-1F468-200D-1F466-200D-1F466
     0x80DADD5E,  // "💆🏽\u200d♂️" 1F486-1F3FD-200D-2642-FE0F
     0x80DF468A,  // "💁🏻\u200d♀️" 1F481-1F3FB-200D-2640-FE0F
-    0x81556B8A,  // "🔞"
     0x81B90295,  // "🐈\u200d⬛" 1F408-200D-2B1B
     0x81C7E398,  // "👨\u200d👩\u200d👧\u200d👦" 1F468-200D-1F469-200D-1F467-200D-1F466
     0x81E67814,  // "👩🏿\u200d🤝\u200d👨🏻" 1F469-1F3FF-200D-1F91D-200D-1F468-1F3FB
     0x81F19432,  // "🏷️" 1F3F7-FE0F
-    0x8202DF00,  // "🍈"
     0x821E15CE,  // "🏄🏽\u200d♀️" 1F3C4-1F3FD-200D-2640-FE0F
     0x823B8D9D,  // "👳🏿\u200d♀️" 1F473-1F3FF-200D-2640-FE0F
     0x82711E32,  // "👨🏾\u200d🦽" 1F468-1F3FE-200D-1F9BD
-    0x827B4E33,  // "👤"
     0x82D0D12A,  // "👩🏿\u200d🦰" 1F469-1F3FF-200D-1F9B0
     0x83051E75,  // "🏄🏻\u200d♂️" 1F3C4-1F3FB-200D-2642-FE0F
-    0x8352352C,  // "🏵"
     0x835D82D9,  // "👴🏼" 1F474-1F3FC
-    0x837D8C4A,  // "📔"
-    0x83C66620,  // "📈"
     0x83D0400C,  // "🎭️" 1F3AD-FE0F
-    0x83D7A17C,  // "🎦"
-    0x83F246A3,  // "💽"
-    0x8441E70F,  // "👹"
-    0x849FADF6,  // "💪"
     0x84BA13E3,  // "🏊🏻\u200d♀️" 1F3CA-1F3FB-200D-2640-FE0F
-    0x84CB5AF9,  // "📀"
     0x84FC04FF,  // "💂\u200d♀️" 1F482-200D-2640-FE0F
     0x8513CF43,  // "🕟️" 1F55F-FE0F
-    0x85158BB9,  // "🕝"
     0x854182B7,  // "👉🏾" 1F449-1F3FE
     0x855F2BFD,  // "🏄🏿" 1F3C4-1F3FF
-    0x856B0750,  // "📞"
-    0x857E13E8,  // "🎯"
     0x85E070BA,  // "💁🏾\u200d♀️" 1F481-1F3FE-200D-2640-FE0F
     0x85FC9B87,  // "👰🏽\u200d♂️" 1F470-1F3FD-200D-2642-FE0F
     0x861DBBF1,  // "👉🏼" 1F449-1F3FC
     0x86263CDF,  // "🕴🏼" 1F574-1F3FC
     0x8640ABE4,  // "👩\u200d👩\u200d👧\u200d👦" 1F469-200D-1F469-200D-1F467-200D-1F466
-    0x8695C62D,  // "👅"
     0x86980868,  // "👨🏽\u200d🦯" 1F468-1F3FD-200D-1F9AF
-    0x869BEC4D,  // "🍙"
-    0x86D558CE,  // "💨"
     0x87127437,  // "🏳️\u200d🌈" 1F3F3-FE0F-200D-1F308
     0x871B3275,  // "💇🏿\u200d♂️" 1F487-1F3FF-200D-2642-FE0F
     0x8761D45D,  // "🏇🏼" 1F3C7-1F3FC
-    0x87E3296E,  // "🍊"
     0x885CDFD1,  // "👩\u200d🎓" 1F469-200D-1F393
     0x885FD396,  // "👩\u200d🔬" 1F469-200D-1F52C
-    0x887723EB,  // "🌝"
     0x887C6F70,  // "🏃🏼\u200d♂️" 1F3C3-1F3FC-200D-2642-FE0F
     0x8892F046,  // "👷🏻\u200d♀️" 1F477-1F3FB-200D-2640-FE0F
-    0x88B31517,  // "📧"
-    0x88B980C6,  // "📒"
     0x88E1159C,  // "👵🏻" 1F475-1F3FB
     0x88F6BF81,  // "💆\u200d♂️" 1F486-200D-2642-FE0F
     0x890A6AF8,  // "👩🏻\u200d🚀" 1F469-1F3FB-200D-1F680
     0x894109F7,  // "👩🏻\u200d🌾" 1F469-1F3FB-200D-1F33E
     0x8958EE27,  // "👋🏼" 1F44B-1F3FC
-    0x89879229,  // "🌫"
     0x89C07684,  // "👳🏽" 1F473-1F3FD
     0x89D06043,  // "🏌🏾" 1F3CC-1F3FE
     0x89E89FCA,  // "👩🏼\u200d🎓" 1F469-1F3FC-200D-1F393
@@ -3009,476 +4395,279 @@ const HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1648] = [
     0x8A390650,  // "👦🏽" 1F466-1F3FD
     0x8A8AA6D6,  // "🌦️" 1F326-FE0F
     0x8ABD8F09,  // "👩🏽\u200d🏫" 1F469-1F3FD-200D-1F3EB
-    0x8AE92552,  // "🍣"
     0x8AFE525A,  // "🕵🏾" 1F575-1F3FE
     0x8B6ACDF3,  // "👧🏻" 1F467-1F3FB
     0x8B76815B,  // "👳🏻" 1F473-1F3FB
     0x8B78B998,  // "💇\u200d♂️" 1F487-200D-2642-FE0F
     0x8B90AAAE,  // "🏊🏻" 1F3CA-1F3FB
     0x8B97A907,  // "👩🏽\u200d🦽" 1F469-1F3FD-200D-1F9BD
-    0x8B9944EB,  // "📩"
     0x8BC1CCA0,  // "👩🏼\u200d🚀" 1F469-1F3FC-200D-1F680
     0x8BF6A60B,  // "👷🏿" 1F477-1F3FF
-    0x8BF7CD82,  // "🔤"
     0x8C2E9621,  // "👨🏾\u200d🦰" 1F468-1F3FE-200D-1F9B0
-    0x8C5B2814,  // "🐣"
-    0x8C641347,  // "🗼"
     0x8C65A6DD,  // "🏊🏻\u200d♂️" 1F3CA-1F3FB-200D-2642-FE0F
     0x8C8C5807,  // "👩🏽\u200d⚕️" 1F469-1F3FD-200D-2695-FE0F
     0x8C921F26,  // "👱\u200d♀️" 1F471-200D-2640-FE0F
     0x8CD39D7E,  // "👩🏽\u200d🤝\u200d👨🏻" 1F469-1F3FD-200D-1F91D-200D-1F468-1F3FB
-    0x8D07727E,  // "🖲"
-    0x8D4B6F65,  // "🍯"
-    0x8D99B353,  // "🔙"
     0x8DC3B268,  // "👩🏿\u200d🍳" 1F469-1F3FF-200D-1F373
-    0x8DE15711,  // "👛"
-    0x8E68CAB1,  // "📡"
     0x8E8FE81D,  // "👁\u200d🗨" 1F441-200D-1F5E8
-    0x8EBA8908,  // "🔨"
-    0x8F63AD2D,  // "🕌"
-    0x8F802069,  // "🎄"
     0x8F85CEB5,  // "🏄🏽\u200d♂️" 1F3C4-1F3FD-200D-2642-FE0F
-    0x8FD62DBB,  // "🏞"
-    0x8FEBA2C8,  // "🌀"
-    0x8FFA4DCB,  // "🎣"
     0x9012BE95,  // "👼🏼" 1F47C-1F3FC
     0x9030FB54,  // "👨🏿\u200d💻" 1F468-1F3FF-200D-1F4BB
-    0x90714FCE,  // "🔗"
     0x90C77BFB,  // "👩\u200d❤️\u200d👩" 1F469-200D-2764-FE0F-200D-1F469
-    0x90EC47EA,  // "🐺"
     0x90F47102,  // "👱🏽" 1F471-1F3FD
-    0x90F7E910,  // "📷"
-    0x91347B94,  // "🗑"
-    0x9149F115,  // "🏺"
-    0x9156B388,  // "👗"
-    0x916E75F9,  // "🎊"
     0x917225D5,  // "👼🏽" 1F47C-1F3FD
     0x91AB5019,  // "💁🏾" 1F481-1F3FE
     0x91C827D9,  // "🎮️" 1F3AE-FE0F
     0x91F2E7E0,  // "👱🏼" 1F471-1F3FC
     0x91FEF9F9,  // "👩🏾\u200d🔧" 1F469-1F3FE-200D-1F527
-    0x92558C3D,  // "👘"
     0x92576339,  // "👩🏻\u200d🍼" 1F469-1F3FB-200D-1F37C
-    0x92642202,  // "🔎"
-    0x929E395B,  // "🍏"
     0x92C6E067,  // "👩🏾\u200d💻" 1F469-1F3FE-200D-1F4BB
     0x92D743B4,  // "👷🏻" 1F477-1F3FB
-    0x92D9E276,  // "🌷"
     0x934538EC,  // "👱🏻\u200d♂️" 1F471-1F3FB-200D-2642-FE0F
     0x935D5E1D,  // "👨🏽\u200d🦳" 1F468-1F3FD-200D-1F9B3
-    0x93CD4416,  // "🏋"
     0x93E9062C,  // "👩🏿\u200d🍼" 1F469-1F3FF-200D-1F37C
     0x9412B04B,  // "👈🏻" 1F448-1F3FB
-    0x94A91196,  // "📺"
     0x94DC2270,  // "🕴🏾\u200d♀️" 1F574-1F3FE-200D-2640-FE0F
-    0x94E16568,  // "🗝"
-    0x94F2DEC4,  // "🕔"
-    0x950186BA,  // "🍀"
     0x9502B602,  // "👨🏼\u200d🦳" 1F468-1F3FC-200D-1F9B3
     0x95102328,  // "👩\u200d🚒" 1F469-200D-1F692
     0x952CF3C5,  // "👨🏿\u200d🎄" 1F468-1F3FF-200D-1F384
-    0x95A881F8,  // "🐰"
-    0x95D3710D,  // "🐹"
     0x963457E0,  // "👩🏾\u200d🦰" 1F469-1F3FE-200D-1F9B0
     0x96487207,  // "👨🏾\u200d🌾" 1F468-1F3FE-200D-1F33E
     0x96DC8AFE,  // "👂🏻" 1F442-1F3FB
     0x977276BE,  // "👷🏽" 1F477-1F3FD
     0x97FA41EC,  // "👨🏾\u200d⚖️" 1F468-1F3FE-200D-2696-FE0F
-    0x982FFEAE,  // "🎟"
     0x986DA93C,  // "👨🏼\u200d✈️" 1F468-1F3FC-200D-2708-FE0F
-    0x98848772,  // "📣"
-    0x98AB042B,  // "🌆"
     0x98CA0138,  // "🗓️" 1F5D3-FE0F
     0x98E27D90,  // "👩🏾\u200d🍳" 1F469-1F3FE-200D-1F373
     0x98EEB01B,  // "🏌🏼\u200d♂️" 1F3CC-1F3FC-200D-2642-FE0F
-    0x98F341C5,  // "🎻"
     0x9909EE43,  // "👩🏽\u200d🤝\u200d👩🏾" 1F469-1F3FD-200D-1F91D-200D-1F469-1F3FE
-    0x990F0668,  // "👽"
-    0x992AAD83,  // "🏙"
-    0x995710CA,  // "🐂"
     0x9970C1B4,  // "💁\u200d♂️" 1F481-200D-2642-FE0F
-    0x9990A894,  // "👍"
     0x999BB12D,  // "👨🏼\u200d💻" 1F468-1F3FC-200D-1F4BB
-    0x99A8BD83,  // "🐻"
     0x99B5C275,  // "👩\u200d🎨" 1F469-200D-1F3A8
-    0x99DF9CF0,  // "💶"
     0x9A027AC6,  // "👨🏿\u200d🏭" 1F468-1F3FF-200D-1F3ED
     0x9A0A0145,  // "👨🏻\u200d🌾" 1F468-1F3FB-200D-1F33E
-    0x9A174469,  // "🏃"
     0x9A1C150A,  // "🐦️" 1F426-FE0F
     0x9A227302,  // "🏄🏼\u200d♀️" 1F3C4-1F3FC-200D-2640-FE0F
     0x9A9D7CBA,  // "💂🏼\u200d♀️" 1F482-1F3FC-200D-2640-FE0F
-    0x9A9EC59F,  // "🔳"
     0x9AC105B1,  // "📥️" 1F4E5-FE0F
-    0x9AC874E4,  // "🏢"
     0x9B1E6B66,  // "📭️" 1F4ED-FE0F
-    0x9B3B421C,  // "💢"
-    0x9B71316A,  // "👡"
     0x9B9D4B6B,  // "👰🏻\u200d♂️" 1F470-1F3FB-200D-2642-FE0F
     0x9BC0B4E2,  // "🏋🏽" 1F3CB-1F3FD
-    0x9BF721A2,  // "💈"
-    0x9BF95620,  // "🍨"
     0x9C0BF95F,  // "👨🏻\u200d🦽" 1F468-1F3FB-200D-1F9BD
     0x9C3D25F5,  // "👏🏼" 1F44F-1F3FC
-    0x9CBF1CB9,  // "👣"
-    0x9D15C4AE,  // "🐧"
-    0x9D2DE6E1,  // "🎤"
-    0x9D69035D,  // "🔘"
-    0x9DABEB44,  // "🍥"
     0x9DD68E60,  // "👋🏿" 1F44B-1F3FF
     0x9E2C53E4,  // "👩\u200d⚕️" 1F469-200D-2695-FE0F
     0x9E4C77BE,  // "👨🏼\u200d🦼" 1F468-1F3FC-200D-1F9BC
-    0x9E4D3F18,  // "🎅"
     0x9ED3887E,  // "👰🏽\u200d♀️" 1F470-1F3FD-200D-2640-FE0F
     0x9ED7673D,  // "💆🏽\u200d♀️" 1F486-1F3FD-200D-2640-FE0F
-    0x9EE5225B,  // "👖"
-    0x9EFB27F6,  // "👝"
     0x9F05A446,  // "📽️" 1F4FD-FE0F
     0x9F4E87BC,  // "🏃🏼" 1F3C3-1F3FC
     0x9F5A02B9,  // "💇🏽\u200d♂️" 1F487-1F3FD-200D-2642-FE0F
-    0x9F6D8E81,  // "🏔"
-    0x9F76D9F8,  // "🍸"
-    0x9F7949E5,  // "💿"
     0xA05F42A9,  // "🏊🏼\u200d♂️" 1F3CA-1F3FC-200D-2642-FE0F
     0xA08995B3,  // "👨🏽\u200d🎓" 1F468-1F3FD-200D-1F393
     0xA0AF4890,  // "👩\u200d🦼" 1F469-200D-1F9BC
     0xA0B9C310,  // "👏🏿" 1F44F-1F3FF
-    0xA0C6DF19,  // "🔓"
     0xA0F7CA94,  // "👶🏽" 1F476-1F3FD
     0xA0FE9033,  // "🏋🏿\u200d♂️" 1F3CB-1F3FF-200D-2642-FE0F
-    0xA0FFB2F0,  // "🐭"
     0xA10B374A,  // "🏃🏾\u200d♀️" 1F3C3-1F3FE-200D-2640-FE0F
     0xA11046A0,  // "👨🏿\u200d🤝\u200d👨🏾" 1F468-1F3FF-200D-1F91D-200D-1F468-1F3FE
-    0xA181CC6D,  // "🐜"
     0xA18EED98,  // "👨🏿\u200d💼" 1F468-1F3FF-200D-1F4BC
     0xA1924194,  // "🏌🏼" 1F3CC-1F3FC
     0xA22B4F1A,  // "🔍️" 1F50D-FE0F
-    0xA2AFEFB7,  // "🔖"
     0xA2CCAC06,  // "👂🏾" 1F442-1F3FE
-    0xA2E24FDB,  // "👿"
     0xA30662A8,  // "👨🏼\u200d🎓" 1F468-1F3FC-200D-1F393
     0xA368C111,  // "👈️" 1F448-FE0F
     0xA3C43A08,  // "👍🏻" 1F44D-1F3FB
     0xA40880AA,  // "💆🏿\u200d♂️" 1F486-1F3FF-200D-2642-FE0F
     0xA4105139,  // "🕺🏽" 1F57A-1F3FD
     0xA4645621,  // "🕢️" 1F562-FE0F
-    0xA467C6AE,  // "🎱"
     0xA4702CF4,  // "👩🏻\u200d🤝\u200d👩🏼" 1F469-1F3FB-200D-1F91D-200D-1F469-1F3FC
     0xA474C70B,  // "👏🏻" 1F44F-1F3FB
     0xA48DEB1F,  // "👨🏻\u200d🦲" 1F468-1F3FB-200D-1F9B2
     0xA4A3F7B3,  // "👩🏿\u200d🤝\u200d👩🏽" 1F469-1F3FF-200D-1F91D-200D-1F469-1F3FD
     0xA4C64911,  // "👨\u200d⚕️" 1F468-200D-2695-FE0F
     0xA4D5EED7,  // "👨\u200d✈️" 1F468-200D-2708-FE0F
-    0xA4E9DAA0,  // "🐷"
     0xA5186EB2,  // "🔒️" 1F512-FE0F
-    0xA51CBB93,  // "📖"
-    0xA5973C48,  // "🌼"
-    0xA5D66095,  // "🍻"
     0xA617E715,  // "👨\u200d👩\u200d👦" 1F468-200D-1F469-200D-1F466
     0xA61B5ECF,  // "🌡️" 1F321-FE0F
-    0xA622F380,  // "🎰"
     0xA62535D4,  // "🏗️" 1F3D7-FE0F
-    0xA6873C75,  // "🍋"
-    0xA69BEBE5,  // "🌇"
     0xA7360C11,  // "👮🏻\u200d♀️" 1F46E-1F3FB-200D-2640-FE0F
-    0xA741ED84,  // "🍜"
-    0xA7C9B0F4,  // "🌽"
     0xA80435C9,  // "👨\u200d🏭" 1F468-200D-1F3ED
     0xA807B8F8,  // "💇🏿" 1F487-1F3FF
-    0xA817114D,  // "🌵"
     0xA8237229,  // "👫🏿" 1F46B-1F3FF
     0xA8CB6B2D,  // "🕴🏿\u200d♂️" 1F574-1F3FF-200D-2642-FE0F
     0xA8DA2480,  // "👭🏼" 1F46D-1F3FC
-    0xA8F2D34C,  // "🎀"
     0xA9186B72,  // "🖱️" 1F5B1-FE0F
     0xA92C3302,  // "👨🏿\u200d🍳" 1F468-1F3FF-200D-1F373
     0xA93AA30B,  // "👩🏻\u200d🍳" 1F469-1F3FB-200D-1F373
     0xA9602728,  // "🌕️" 1F315-FE0F
-    0xA9719A8C,  // "🎲"
     0xA9B738F9,  // "👍🏼" 1F44D-1F3FC
     0xA9DCC385,  // "💇🏼\u200d♀️" 1F487-1F3FC-200D-2640-FE0F
-    0xAA1BB216,  // "🍚"
     0xAA218F4E,  // "👩🏻\u200d🏭" 1F469-1F3FB-200D-1F3ED
     0xAA2EC8B8,  // "🏃🏾" 1F3C3-1F3FE
     0xAA3AB064,  // "🏋️" 1F3CB-FE0F
     0xAA6515F8,  // "🗄️" 1F5C4-FE0F
     0xAA99077E,  // "👰🏼\u200d♀️" 1F470-1F3FC-200D-2640-FE0F
-    0xAB28F9AB,  // "🍳"
-    0xAB4B00F4,  // "🎚"
     0xAB4ED301,  // "🕹️" 1F579-FE0F
     0xAB51784D,  // "💆🏾\u200d♂️" 1F486-1F3FE-200D-2642-FE0F
-    0xAB8613B1,  // "🔕"
     0xABA60E7A,  // "📟️" 1F4DF-FE0F
-    0xABEE5426,  // "🐳"
     0xAC14BFB7,  // "👨🏿\u200d🎓" 1F468-1F3FF-200D-1F393
     0xAC24FD44,  // "👴🏻" 1F474-1F3FB
-    0xAC34B794,  // "🕵"
     0xAC508CDD,  // "🏌🏾\u200d♀️" 1F3CC-1F3FE-200D-2640-FE0F
     0xAC723FC5,  // "👨🏻\u200d🦯" 1F468-1F3FB-200D-1F9AF
     0xAC93A7FB,  // "🔓️" 1F513-FE0F
-    0xAC9BC56C,  // "🎨"
     0xACA9C591,  // "👴🏽" 1F474-1F3FD
-    0xACBB7CC3,  // "🐨"
     0xACCDCDA6,  // "👳🏽\u200d♀️" 1F473-1F3FD-200D-2640-FE0F
-    0xACFFC666,  // "🏸"
-    0xAD348546,  // "🐽"
-    0xAE5F0FC2,  // "🍛"
     0xAE82B15F,  // "👨🏿\u200d⚕️" 1F468-1F3FF-200D-2695-FE0F
     0xAEB0C5E2,  // "👱🏼\u200d♂️" 1F471-1F3FC-200D-2642-FE0F
-    0xAEC1677E,  // "🍕"
-    0xAEC7F68F,  // "🌔"
     0xAF00A723,  // "🕚️" 1F55A-FE0F
-    0xAF05C500,  // "👲"
     0xAF4AE9A4,  // "👆🏾" 1F446-1F3FE
     0xAF9850A5,  // "🏄🏾\u200d♂️" 1F3C4-1F3FE-200D-2642-FE0F
     0xAFAE378B,  // "👩🏻\u200d🦯" 1F469-1F3FB-200D-1F9AF
     0xAFCFB09E,  // "👏🏾" 1F44F-1F3FE
     0xAFD07981,  // "🏌🏽\u200d♂️" 1F3CC-1F3FD-200D-2642-FE0F
     0xAFE1AC1E,  // "👩🏾\u200d🎨" 1F469-1F3FE-200D-1F3A8
-    0xB0051843,  // "🗿"
     0xB0298A1E,  // "💇🏻" 1F487-1F3FB
     0xB05070D2,  // "🏄️" 1F3C4-FE0F
-    0xB05A2D59,  // "👭"
     0xB05B35F4,  // "🎅🏻" 1F385-1F3FB
     0xB0D1CEB7,  // "🕝️" 1F55D-FE0F
     0xB0D3BFBA,  // "🏃🏾\u200d♂️" 1F3C3-1F3FE-200D-2642-FE0F
     0xB0E60C12,  // "👩🏿\u200d🔧" 1F469-1F3FF-200D-1F527
     0xB0FBB14D,  // "🕵🏾\u200d♂️" 1F575-1F3FE-200D-2642-FE0F
     0xB1003F49,  // "📪️" 1F4EA-FE0F
-    0xB12973E4,  // "🐏"
     0xB1953E08,  // "🖌️" 1F58C-FE0F
-    0xB1A67058,  // "📐"
     0xB1E69BA5,  // "👨🏾\u200d✈️" 1F468-1F3FE-200D-2708-FE0F
     0xB213A72B,  // "👩\u200d👧\u200d👦" 1F469-200D-1F467-200D-1F466
-    0xB2635DE8,  // "👕"
-    0xB29D58BD,  // "🍷"
     0xB2A9A2BF,  // "🏊🏿" 1F3CA-1F3FF
-    0xB2ABDF65,  // "🍽"
-    0xB2B4634C,  // "🏨"
-    0xB2C9E99A,  // "📌"
     0xB3050C5F,  // "👨🏽\u200d🤝\u200d👨🏻" 1F468-1F3FD-200D-1F91D-200D-1F468-1F3FB
-    0xB3304314,  // "🏷"
     0xB3449F99,  // "👩🏼\u200d🌾" 1F469-1F3FC-200D-1F33E
-    0xB35ACCCF,  // "🎿"
-    0xB38710FB,  // "📥"
     0xB3E60E63,  // "👳🏾\u200d♂️" 1F473-1F3FE-200D-2642-FE0F
-    0xB40D0E1A,  // "💬"
     0xB41C7D68,  // "👓️" 1F453-FE0F
     0xB4756EF3,  // "👨\u200d🦽" 1F468-200D-1F9BD
-    0xB499776E,  // "🎞"
-    0xB4E85092,  // "👏"
-    0xB5D9EBD2,  // "🕸"
-    0xB615B95E,  // "🗓"
     0xB64BBAEE,  // "📦️" 1F4E6-FE0F
     0xB6853153,  // "👨🏿\u200d🚒" 1F468-1F3FF-200D-1F692
-    0xB68FFB1D,  // "🍝"
     0xB6A702C9,  // "👩🏻\u200d🤝\u200d👨🏿" 1F469-1F3FB-200D-1F91D-200D-1F468-1F3FF
-    0xB6DBFB7B,  // "💦"
-    0xB71C659C,  // "📽"
     0xB74EA892,  // "👩🏻\u200d⚖️" 1F469-1F3FB-200D-2696-FE0F
     0xB7757A00,  // "👵🏽" 1F475-1F3FD
     0xB7D335F5,  // "👩\u200d🦽" 1F469-200D-1F9BD
     0xB829B63C,  // "🏃🏻\u200d♀️" 1F3C3-1F3FB-200D-2640-FE0F
-    0xB83052E1,  // "🎙"
     0xB853AB66,  // "🏃🏽\u200d♀️" 1F3C3-1F3FD-200D-2640-FE0F
     0xB86AFC21,  // "👲🏻" 1F472-1F3FB
     0xB86ED5F7,  // "👨🏿\u200d✈️" 1F468-1F3FF-200D-2708-FE0F
     0xB9033460,  // "👩🏼\u200d🤝\u200d👨🏾" 1F469-1F3FC-200D-1F91D-200D-1F468-1F3FE
     0xB926A698,  // "🏃🏿" 1F3C3-1F3FF
-    0xB95733A5,  // "🕺"
     0xB9A50A78,  // "🏄🏼\u200d♂️" 1F3C4-1F3FC-200D-2642-FE0F
-    0xB9A9207D,  // "🌥"
-    0xB9DD9973,  // "🏕"
     0xB9DE5CA4,  // "👨🏻\u200d⚖️" 1F468-1F3FB-200D-2696-FE0F
-    0xB9FD3A0A,  // "🗳"
     0xBA0CC644,  // "👨\u200d🦲" 1F468-200D-1F9B2
     0xBA31E8CE,  // "👩🏼\u200d🚒" 1F469-1F3FC-200D-1F692
     0xBA5A3B55,  // "👱🏾\u200d♂️" 1F471-1F3FE-200D-2642-FE0F
-    0xBA5F8536,  // "💧"
     0xBA653580,  // "🏋🏽\u200d♀️" 1F3CB-1F3FD-200D-2640-FE0F
     0xBAAB6161,  // "💁🏽\u200d♀️" 1F481-1F3FD-200D-2640-FE0F
-    0xBAC0B686,  // "🖥"
-    0xBADAD7A1,  // "🗞"
     0xBAFEDAC0,  // "🏃🏻\u200d♂️" 1F3C3-1F3FB-200D-2642-FE0F
     0xBB0D4C7E,  // "🖖🏾" 1F596-1F3FE
-    0xBB17599B,  // "🎛"
     0xBB29383A,  // "🏊🏼" 1F3CA-1F3FC
-    0xBC4E5A4A,  // "👋"
-    0xBC9D1E21,  // "🍩"
     0xBD0303D7,  // "👨🏾\u200d🤝\u200d👨🏻" 1F468-1F3FE-200D-1F91D-200D-1F468-1F3FB
     0xBD0BB876,  // "👳🏾\u200d♀️" 1F473-1F3FE-200D-2640-FE0F
     0xBD76BC96,  // "👩🏼\u200d💻" 1F469-1F3FC-200D-1F4BB
     0xBDB8B902,  // "👨🏼\u200d🦱" 1F468-1F3FC-200D-1F9B1
-    0xBDE6E072,  // "🔸"
     0xBE42FF6E,  // "💂🏾\u200d♂️" 1F482-1F3FE-200D-2642-FE0F
-    0xBE66809B,  // "🐁"
     0xBEBCD7EB,  // "👷🏼" 1F477-1F3FC
     0xBF0D116C,  // "🕊️" 1F54A-FE0F
     0xBF180441,  // "🏌🏼\u200d♀️" 1F3CC-1F3FC-200D-2640-FE0F
     0xBF1FC8E4,  // "🕵🏽\u200d♂️" 1F575-1F3FD-200D-2642-FE0F
-    0xBF3122C1,  // "🐔"
     0xBF48CB51,  // "🖖🏿" 1F596-1F3FF
-    0xBF50C35C,  // "📬"
-    0xBF5E39FB,  // "💴"
-    0xBFAD8304,  // "🕐"
     0xC065855F,  // "👊🏿" 1F44A-1F3FF
     0xC083D0C3,  // "👋🏻" 1F44B-1F3FB
     0xC0C821B4,  // "🎅🏿" 1F385-1F3FF
     0xC0EE2541,  // "👳🏼\u200d♂️" 1F473-1F3FC-200D-2642-FE0F
     0xC0F488D4,  // "🏂🏿" 1F3C2-1F3FF
     0xC0F95A9A,  // "🕵🏿" 1F575-1F3FF
-    0xC16E0150,  // "🔰"
-    0xC1731064,  // "🔯"
     0xC1FBCB74,  // "🕴🏿" 1F574-1F3FF
     0xC20AE217,  // "🎖️" 1F396-FE0F
-    0xC233C397,  // "🌦"
-    0xC24A8664,  // "👩"
-    0xC25FDD5A,  // "🍾"
     0xC2B0B11C,  // "💇🏾" 1F487-1F3FE
-    0xC2B21D03,  // "🖊"
     0xC2CD894A,  // "👨🏿\u200d🦲" 1F468-1F3FF-200D-1F9B2
     0xC2DCDA01,  // "👨\u200d🦳" 1F468-200D-1F9B3
     0xC2F9E962,  // "🏌️\u200d♂️" 1F3CC-FE0F-200D-2642-FE0F
-    0xC30D0B7B,  // "🐓"
     0xC32D2A23,  // "👨🏻\u200d🤝\u200d👨🏾" 1F468-1F3FB-200D-1F91D-200D-1F468-1F3FE
-    0xC33E86DA,  // "🗽"
-    0xC345C25A,  // "🎭"
     0xC3583866,  // "👨🏽\u200d⚖️" 1F468-1F3FD-200D-2696-FE0F
     0xC35D5DAC,  // "👎🏽" 1F44E-1F3FD
     0xC3B6E4C7,  // "👳🏿" 1F473-1F3FF
     0xC3D93C39,  // "👨🏻\u200d🏫" 1F468-1F3FB-200D-1F3EB
-    0xC44A0E1C,  // "🐦"
-    0xC44EF1A6,  // "🐪"
     0xC457BEBC,  // "👂🏼" 1F442-1F3FC
-    0xC4B5D326,  // "🍢"
-    0xC4F6D45C,  // "💷"
-    0xC5095592,  // "🍭"
     0xC5441E6A,  // "👧🏽" 1F467-1F3FD
     0xC553D1FB,  // "👩🏼\u200d🦯" 1F469-1F3FC-200D-1F9AF
     0xC57B0E35,  // "👩🏼\u200d🍳" 1F469-1F3FC-200D-1F373
     0xC5A74F47,  // "🕴🏽" 1F574-1F3FD
-    0xC5C76576,  // "🍌"
     0xC6023978,  // "🏊🏾\u200d♂️" 1F3CA-1F3FE-200D-2642-FE0F
     0xC60FC39D,  // "👃🏻" 1F443-1F3FB
-    0xC6352A35,  // "💺"
     0xC67EB8BC,  // "👩🏼\u200d🤝\u200d👩🏽" 1F469-1F3FC-200D-1F91D-200D-1F469-1F3FD
     0xC67F6376,  // "🖐️" 1F590-FE0F
     0xC72082A9,  // "👨🏿\u200d🦱" 1F468-1F3FF-200D-1F9B1
-    0xC72323E8,  // "🌤"
-    0xC729E30A,  // "🏎"
-    0xC72F5067,  // "💤"
-    0xC74DC032,  // "👼"
     0xC7903208,  // "👨🏼\u200d🤝\u200d👨🏽" 1F468-1F3FC-200D-1F91D-200D-1F468-1F3FD
-    0xC7D8A1E9,  // "🕢"
     0xC804CAC7,  // "🕺🏿" 1F57A-1F3FF
     0xC816B9F2,  // "💿️" 1F4BF-FE0F
     0xC8556542,  // "👨🏾" 1F468-1F3FE
     0xC8D0E81D,  // "👩🏿\u200d🎄" 1F469-1F3FF-200D-1F384
     0xC8DEA28A,  // "👨🏾\u200d🤝\u200d👨🏽" 1F468-1F3FE-200D-1F91D-200D-1F468-1F3FD
     0xC8E09BD6,  // "💻️" 1F4BB-FE0F
-    0xC970411F,  // "🏁"
     0xCA2E0320,  // "👩🏼\u200d🦽" 1F469-1F3FC-200D-1F9BD
     0xCA5F4D8F,  // "🎟️" 1F39F-FE0F
     0xCA7F3B14,  // "👩\u200d👩\u200d👦" 1F469-200D-1F469-200D-1F466
     0xCA8B73F9,  // "💇🏿\u200d♀️" 1F487-1F3FF-200D-2640-FE0F
-    0xCA8C74AA,  // "📗"
-    0xCAB37D00,  // "💜"
     0xCAD5B370,  // "👩\u200d🍼" 1F469-200D-1F37C
-    0xCB11DBE6,  // "🐗"
     0xCB2815FC,  // "👩🏻\u200d🤝\u200d👨🏽" 1F469-1F3FB-200D-1F91D-200D-1F468-1F3FD
-    0xCB2FDA62,  // "🍒"
-    0xCB944C28,  // "🎪"
     0xCB9C0A80,  // "🏊🏽\u200d♂️" 1F3CA-1F3FD-200D-2642-FE0F
     0xCB9C7FA4,  // "👌🏽" 1F44C-1F3FD
-    0xCBA072AB,  // "💖"
-    0xCBAB2138,  // "🔼"
-    0xCC2787DA,  // "📮"
     0xCC763C42,  // "📹️" 1F4F9-FE0F
-    0xCC787177,  // "🕥"
     0xCC7C05E2,  // "👇🏾" 1F447-1F3FE
     0xCCAA040A,  // "👨🏿\u200d🍼" 1F468-1F3FF-200D-1F37C
-    0xCCB0C104,  // "🐾"
     0xCD0F3C1D,  // "🖍️" 1F58D-FE0F
     0xCD21FEDE,  // "👍️" 1F44D-FE0F
     0xCD4C45A0,  // "👨🏿\u200d🔧" 1F468-1F3FF-200D-1F527
-    0xCD5CA985,  // "🍮"
-    0xCD64519C,  // "💞"
     0xCD671C4B,  // "👨\u200d🦼" 1F468-200D-1F9BC
     0xCD820003,  // "🌧️" 1F327-FE0F
-    0xCD8618F9,  // "🏭"
-    0xCD98DF72,  // "🎢"
-    0xCD9BF298,  // "📊"
     0xCDB607F1,  // "👳🏿\u200d♂️" 1F473-1F3FF-200D-2642-FE0F
     0xCDF57874,  // "👩🏾\u200d🔬" 1F469-1F3FE-200D-1F52C
     0xCDFCD059,  // "🕵🏼\u200d♂️" 1F575-1F3FC-200D-2642-FE0F
-    0xCE061F06,  // "👻"
     0xCE1197EC,  // "🕷️" 1F577-FE0F
     0xCE30CF73,  // "🏄🏽" 1F3C4-1F3FD
-    0xCE456DEC,  // "🐋"
     0xCE9963A7,  // "🏇🏿" 1F3C7-1F3FF
-    0xCEC33AC4,  // "🐐"
-    0xCEC51CB3,  // "🕘"
     0xCEF3D26E,  // "👨🏻\u200d⚕️" 1F468-1F3FB-200D-2695-FE0F
     0xCF018177,  // "👨\u200d🦯" 1F468-200D-1F9AF
-    0xCF11DDA2,  // "🎃"
-    0xCF196C1C,  // "🏌"
     0xCF301A7E,  // "👩🏽\u200d🦯" 1F469-1F3FD-200D-1F9AF
     0xCF3CE8D1,  // "🕓️" 1F553-FE0F
     0xCF7A27F8,  // "👰🏾\u200d♀️" 1F470-1F3FE-200D-2640-FE0F
-    0xCF9336DE,  // "🌃"
     0xCFB3DB63,  // "🏔️" 1F3D4-FE0F
-    0xCFB6BC20,  // "🐢"
     0xCFD4E1AC,  // "🕵🏽" 1F575-1F3FD
     0xD01AAC5C,  // "🏄🏾\u200d♀️" 1F3C4-1F3FE-200D-2640-FE0F
-    0xD05FEFFB,  // "🎶"
     0xD0BC02D1,  // "🏴\U000e0067\U000e0062\U000e0073\U000e0063\U000e0074\U000e007f" 1F3F4-E0067-E0062-E0073-E0063-E0074-E007F
     0xD0C10012,  // "👮🏾\u200d♀️" 1F46E-1F3FE-200D-2640-FE0F
     0xD10827DA,  // "🏋🏼\u200d♀️" 1F3CB-1F3FC-200D-2640-FE0F
     0xD10BE133,  // "🎅🏼" 1F385-1F3FC
-    0xD1502696,  // "🔃"
-    0xD15935CE,  // "🐈"
-    0xD17682D3,  // "🔍"
     0xD1AFA267,  // "💆🏼\u200d♀️" 1F486-1F3FC-200D-2640-FE0F
     0xD1B5B85F,  // "👧🏾" 1F467-1F3FE
-    0xD1B5D607,  // "🏇"
-    0xD1B8436E,  // "🏑"
-    0xD1E4C02B,  // "🏄"
     0xD1ED34BB,  // "👩🏽\u200d🤝\u200d👨🏿" 1F469-1F3FD-200D-1F91D-200D-1F468-1F3FF
     0xD1EFF4CB,  // "👎🏼" 1F44E-1F3FC
-    0xD233F0D5,  // "💇"
     0xD259DD9B,  // "👬🏿" 1F46C-1F3FF
-    0xD26D27C4,  // "📴"
-    0xD2B9FA17,  // "📻"
     0xD2BF8676,  // "💂🏽\u200d♀️" 1F482-1F3FD-200D-2640-FE0F
     0xD2CCF84A,  // "👩🏽\u200d🦱" 1F469-1F3FD-200D-1F9B1
     0xD2D2EC64,  // "👩🏻\u200d🤝\u200d👩🏾" 1F469-1F3FB-200D-1F91D-200D-1F469-1F3FE
-    0xD2FAA0E7,  // "🍅"
     0xD30419B0,  // "💇🏼\u200d♂️" 1F487-1F3FC-200D-2642-FE0F
     0xD35B3C17,  // "🏘️" 1F3D8-FE0F
     0xD35BEDF8,  // "👩🏾\u200d⚕️" 1F469-1F3FE-200D-2695-FE0F
-    0xD35E089D,  // "💊"
     0xD38D11CC,  // "👦🏿" 1F466-1F3FF
-    0xD3CCED15,  // "🔌"
-    0xD3CEDD2D,  // "🗂"
     0xD3FE933B,  // "🕴🏻\u200d♂️" 1F574-1F3FB-200D-2642-FE0F
-    0xD417DA00,  // "🖌"
-    0xD4296970,  // "💝"
     0xD47F41C4,  // "🖲️" 1F5B2-FE0F
     0xD4E582D1,  // "👩\u200d🎄" 1F469-200D-1F384
     0xD500A6FE,  // "👩\u200d🦳" 1F469-200D-1F9B3
     0xD537273C,  // "👩🏼\u200d🏫" 1F469-1F3FC-200D-1F3EB
     0xD548DD6C,  // "💆🏼" 1F486-1F3FC
-    0xD573B87D,  // "🔻"
     0xD57DDB14,  // "👇🏽" 1F447-1F3FD
     0xD5AA1439,  // "🕴🏿\u200d♀️" 1F574-1F3FF-200D-2640-FE0F
     0xD5BE1F6D,  // "🏋🏽\u200d♂️" 1F3CB-1F3FD-200D-2642-FE0F
     0xD5FD7728,  // "🏃🏽\u200d♂️" 1F3C3-1F3FD-200D-2642-FE0F
-    0xD627FC9B,  // "🍄"
     0xD68C9F9F,  // "👲🏽" 1F472-1F3FD
     0xD6B56183,  // "👩🏾\u200d🦱" 1F469-1F3FE-200D-1F9B1
-    0xD6C3C90B,  // "👂"
-    0xD6C3DB27,  // "👥"
-    0xD6D79BBA,  // "🍖"
     0xD6DFE7A6,  // "👩🏼\u200d🤝\u200d👨🏽" 1F469-1F3FC-200D-1F91D-200D-1F468-1F3FD
     0xD72F9228,  // "📷️" 1F4F7-FE0F
     0xD745E3C2,  // "👩🏽\u200d🎓" 1F469-1F3FD-200D-1F393
@@ -3486,34 +4675,15 @@ const HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1648] = [
     0xD75EA380,  // "🏋🏻\u200d♀️" 1F3CB-1F3FB-200D-2640-FE0F
     0xD7A5B156,  // "👩\u200d🏭" 1F469-200D-1F3ED
     0xD7E9F0B2,  // "👰\u200d♂️" 1F470-200D-2642-FE0F
-    0xD843F2D0,  // "🎖"
-    0xD8451E92,  // "🍠"
     0xD84B1C95,  // "🏂️" 1F3C2-FE0F
-    0xD84D54FE,  // "🍃"
-    0xD85C97F7,  // "👑"
-    0xD87D9AB8,  // "🎓"
-    0xD8AC6275,  // "🍶"
     0xD8E945D5,  // "🌪️" 1F32A-FE0F
-    0xD9054C93,  // "🔬"
-    0xD92501C5,  // "🏟"
-    0xD96FFF53,  // "👷"
     0xD9824FA7,  // "🖐🏽" 1F590-1F3FD
-    0xD9A3298D,  // "🖇"
-    0xD9BF11F4,  // "💗"
     0xD9C645F9,  // "🖖🏻" 1F596-1F3FB
-    0xDA0986C3,  // "🐥"
-    0xDA3CFC7C,  // "🔶"
-    0xDAF4476B,  // "🔦"
-    0xDB03AAB2,  // "🗄"
     0xDB5F5D2D,  // "👰🏽" 1F470-1F3FD
-    0xDB8296A3,  // "🖨"
     0xDBA57DB4,  // "👱🏾" 1F471-1F3FE
-    0xDBA7E143,  // "👶"
-    0xDBC4F9D3,  // "📸"
     0xDBE64D3A,  // "🖐🏾" 1F590-1F3FE
     0xDBEBAC1C,  // "👨🏽\u200d🦱" 1F468-1F3FD-200D-1F9B1
     0xDC068A42,  // "🕵🏿\u200d♀️" 1F575-1F3FF-200D-2640-FE0F
-    0xDC14948D,  // "🍰"
     0xDC399C4E,  // "👩🏼\u200d🏭" 1F469-1F3FC-200D-1F3ED
     0xDC63EB99,  // "👶🏻" 1F476-1F3FB
     0xDCA5D0D6,  // "👨🏿\u200d🦼" 1F468-1F3FF-200D-1F9BC
@@ -3522,47 +4692,31 @@ const HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1648] = [
     0xDCF0A781,  // "🏍️" 1F3CD-FE0F
     0xDD17AD14,  // "👩🏻\u200d🤝\u200d👨🏼" 1F469-1F3FB-200D-1F91D-200D-1F468-1F3FC
     0xDD2C78F6,  // "💇🏾\u200d♂️" 1F487-1F3FE-200D-2642-FE0F
-    0xDD30F7D7,  // "🕋"
     0xDD37CF5F,  // "👐🏽" 1F450-1F3FD
     0xDD52833A,  // "🗜️" 1F5DC-FE0F
     0xDD6D901C,  // "👨\u200d🎨" 1F468-200D-1F3A8
     0xDDA4EAE6,  // "👩\u200d💼" 1F469-200D-1F4BC
     0xDDC4B82F,  // "🏃🏼\u200d♀️" 1F3C3-1F3FC-200D-2640-FE0F
     0xDDD344BF,  // "👨🏻\u200d🔧" 1F468-1F3FB-200D-1F527
-    0xDE2A59FF,  // "🏴"
-    0xDE5FB585,  // "📎"
-    0xDE756950,  // "🔱"
-    0xDE9A23BA,  // "🍼"
-    0xDEA6E585,  // "🏿"
     0xDF145AB6,  // "👨🏽\u200d🎤" 1F468-1F3FD-200D-1F3A4
     0xDF30948B,  // "👰🏻" 1F470-1F3FB
     0xDF8A09A8,  // "👨🏼\u200d🎤" 1F468-1F3FC-200D-1F3A4
     0xDF918BDC,  // "👨🏾\u200d🤝\u200d👨🏿" 1F468-1F3FE-200D-1F91D-200D-1F468-1F3FF
     0xDFD99071,  // "👬🏼" 1F46C-1F3FC
     0xE02DF33C,  // "👩🏼\u200d🦱" 1F469-1F3FC-200D-1F9B1
-    0xE02E2514,  // "🐬"
     0xE03370F6,  // "👐🏼" 1F450-1F3FC
     0xE04C7C10,  // "👨🏼\u200d🦯" 1F468-1F3FC-200D-1F9AF
     0xE0D410F2,  // "🕜️" 1F55C-FE0F
     0xE0DE5AE1,  // "👩🏾\u200d🚀" 1F469-1F3FE-200D-1F680
     0xE114EC0F,  // "🕴🏽\u200d♂️" 1F574-1F3FD-200D-2642-FE0F
-    0xE1227F53,  // "🌚"
     0xE1394D7B,  // "👩\u200d👩\u200d👧" 1F469-200D-1F469-200D-1F467
-    0xE154B173,  // "🍤"
     0xE15C418C,  // "👩🏾\u200d🤝\u200d👩🏽" 1F469-1F3FE-200D-1F91D-200D-1F469-1F3FD
     0xE1B678BA,  // "👨\u200d🦰" 1F468-200D-1F9B0
     0xE1F756F5,  // "👫🏻" 1F46B-1F3FB
-    0xE1FE36C5,  // "🐞"
     0xE2556F14,  // "👋🏽" 1F44B-1F3FD
     0xE277BC54,  // "🖥️" 1F5A5-FE0F
     0xE2820F64,  // "👃🏽" 1F443-1F3FD
-    0xE2D2799C,  // "🎴"
-    0xE2FDF061,  // "🔛"
-    0xE302A660,  // "💠"
-    0xE30A6D0E,  // "🌟"
     0xE320410F,  // "👨🏻\u200d🦼" 1F468-1F3FB-200D-1F9BC
-    0xE3307FC1,  // "👚"
-    0xE336DDDE,  // "🕚"
     0xE3680D23,  // "👬🏽" 1F46C-1F3FD
     0xE381AEF1,  // "🐈️" 1F408-FE0F
     0xE3A10D53,  // "💆\u200d♀️" 1F486-200D-2640-FE0F
@@ -3572,16 +4726,10 @@ const HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1648] = [
     0xE3EBFC54,  // "👩🏽\u200d🤝\u200d👩🏻" 1F469-1F3FD-200D-1F91D-200D-1F469-1F3FB
     0xE3F24D02,  // "👩🏽\u200d🤝\u200d👩🏿" 1F469-1F3FD-200D-1F91D-200D-1F469-1F3FF
     0xE447EE42,  // "🕠️" 1F560-FE0F
-    0xE44B1E99,  // "🏪"
     0xE44FACF3,  // "👨🏼\u200d🦰" 1F468-1F3FC-200D-1F9B0
     0xE4882423,  // "👩🏾\u200d🤝\u200d👨🏻" 1F469-1F3FE-200D-1F91D-200D-1F468-1F3FB
-    0xE4C4A1EA,  // "🔜"
-    0xE5002B30,  // "🎷"
-    0xE5109370,  // "🐟"
     0xE563C42C,  // "🗑️" 1F5D1-FE0F
     0xE58EEC1C,  // "👨🏽\u200d🍳" 1F468-1F3FD-200D-1F373
-    0xE5E692E9,  // "🐑"
-    0xE5F42F82,  // "🐚"
     0xE6653BB7,  // "👌🏾" 1F44C-1F3FE
     0xE68FAA85,  // "👩🏿\u200d⚕️" 1F469-1F3FF-200D-2695-FE0F
     0xE6E4407B,  // "🖐🏻" 1F590-1F3FB
@@ -3596,59 +4744,39 @@ const HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1648] = [
     0xE79DAC3D,  // "🕙️" 1F559-FE0F
     0xE7A0B906,  // "🗳️" 1F5F3-FE0F
     0xE7B3B5B8,  // "👨🏽\u200d💼" 1F468-1F3FD-200D-1F4BC
-    0xE7DC4DB8,  // "🖤"
-    0xE7DC9CBD,  // "🎗"
     0xE82E4D73,  // "👨🏾\u200d🎤" 1F468-1F3FE-200D-1F3A4
-    0xE871D6EA,  // "📿"
-    0xE87438A8,  // "💲"
     0xE8D29BDD,  // "👩🏾\u200d⚖️" 1F469-1F3FE-200D-2696-FE0F
     0xE916B3CC,  // "👱🏾\u200d♀️" 1F471-1F3FE-200D-2640-FE0F
     0xE9221A42,  // "👩\u200d❤️\u200d💋\u200d👩" 1F469-200D-2764-FE0F-200D-1F48B-200D-1F469
-    0xE929958F,  // "🔢"
     0xE96742A9,  // "👰\u200d♀️" 1F470-200D-2640-FE0F
     0xE9C063DC,  // "🏌🏻\u200d♂️" 1F3CC-1F3FB-200D-2642-FE0F
     0xEA19ABB6,  // "👳\u200d♀️" 1F473-200D-2640-FE0F
     0xEA454559,  // "👩🏼\u200d🎤" 1F469-1F3FC-200D-1F3A4
     0xEA85EBC4,  // "👮🏾" 1F46E-1F3FE
-    0xEA861A81,  // "🏼"
     0xEA9902D6,  // "👩🏽\u200d🏭" 1F469-1F3FD-200D-1F3ED
     0xEAB54B8D,  // "👩🏼\u200d🔧" 1F469-1F3FC-200D-1F527
     0xEAFFD4DA,  // "💁🏻\u200d♂️" 1F481-1F3FB-200D-2642-FE0F
     0xEB12714A,  // "🗃️" 1F5C3-FE0F
     0xEB3F2A9C,  // "👫🏽" 1F46B-1F3FD
-    0xEB795375,  // "🎽"
     0xEB979B50,  // "👨🏼\u200d🌾" 1F468-1F3FC-200D-1F33E
-    0xEBA42C21,  // "🍫"
     0xEBDF663C,  // "💁🏼\u200d♂️" 1F481-1F3FC-200D-2642-FE0F
     0xEC288CC3,  // "🏂🏾" 1F3C2-1F3FE
-    0xEC48E141,  // "🕒"
     0xEC7659FE,  // "👩🏽\u200d🦰" 1F469-1F3FD-200D-1F9B0
     0xEC8FF279,  // "👩🏼\u200d🦳" 1F469-1F3FC-200D-1F9B3
     0xECB1CD3D,  // "🏋🏿\u200d♀️" 1F3CB-1F3FF-200D-2640-FE0F
-    0xECDB3A59,  // "👃"
     0xED0E8302,  // "👩🏿\u200d🎤" 1F469-1F3FF-200D-1F3A4
-    0xED40DB04,  // "🗒"
     0xED5B091F,  // "🏌🏿\u200d♀️" 1F3CC-1F3FF-200D-2640-FE0F
-    0xED7F71CD,  // "🕯"
-    0xEDBB4D5E,  // "💩"
     0xEDCC2AB6,  // "👨🏿\u200d🦽" 1F468-1F3FF-200D-1F9BD
     0xEDEB811D,  // "🌤️" 1F324-FE0F
-    0xEDED1E13,  // "🕞"
-    0xEDED4812,  // "🏚"
     0xEE0746EE,  // "👩\u200d👦" 1F469-200D-1F466
     0xEE0F9ECA,  // "💪🏻" 1F4AA-1F3FB
     0xEE296DEC,  // "🗨️" 1F5E8-FE0F
     0xEE3741B6,  // "👉🏽" 1F449-1F3FD
     0xEE9A6F26,  // "👨🏽\u200d🎄" 1F468-1F3FD-200D-1F384
     0xEF2A183C,  // "💆🏽" 1F486-1F3FD
-    0xEF30FCFF,  // "🔁"
-    0xEF36659A,  // "🔋"
-    0xEF48D39F,  // "🍞"
     0xEF9B8054,  // "💁🏻" 1F481-1F3FB
-    0xEFBE2CF3,  // "👐"
     0xF005E2A8,  // "🕵🏻\u200d♀️" 1F575-1F3FB-200D-2640-FE0F
     0xF04AAE16,  // "👩🏿\u200d🦳" 1F469-1F3FF-200D-1F9B3
-    0xF059D284,  // "👸"
     0xF08F1B19,  // "👱🏽\u200d♂️" 1F471-1F3FD-200D-2642-FE0F
     0xF0936C3A,  // "🏊🏼\u200d♀️" 1F3CA-1F3FC-200D-2640-FE0F
     0xF09C6248,  // "👩🏽\u200d⚖️" 1F469-1F3FD-200D-2696-FE0F
@@ -3656,47 +4784,33 @@ const HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1648] = [
     0xF0AE54C0,  // "👷🏾" 1F477-1F3FE
     0xF0B53D6D,  // "👩🏽\u200d🚀" 1F469-1F3FD-200D-1F680
     0xF0C551DD,  // "👨\u200d❤️\u200d👨" 1F468-200D-2764-FE0F-200D-1F468
-    0xF0E02F0C,  // "🕴"
     0xF0E090C9,  // "🏊🏾\u200d♀️" 1F3CA-1F3FE-200D-2640-FE0F
     0xF0EFFD83,  // "🕛️" 1F55B-FE0F
-    0xF104B5C8,  // "🗜"
-    0xF15B8088,  // "🔹"
     0xF160A612,  // "🏄🏾" 1F3C4-1F3FE
-    0xF1C7B136,  // "📓"
     0xF1E0C163,  // "👨🏻\u200d💼" 1F468-1F3FB-200D-1F4BC
     0xF1EC6388,  // "👩🏿\u200d🤝\u200d👩🏻" 1F469-1F3FF-200D-1F91D-200D-1F469-1F3FB
     0xF2578A74,  // "👮🏽\u200d♀️" 1F46E-1F3FD-200D-2640-FE0F
     0xF271146F,  // "👩🏾\u200d🏫" 1F469-1F3FE-200D-1F3EB
     0xF27C923F,  // "👩\u200d🔧" 1F469-200D-1F527
     0xF290BC60,  // "👌🏼" 1F44C-1F3FC
-    0xF2B61BCF,  // "👌"
     0xF2CF0667,  // "👮🏻" 1F46E-1F3FB
-    0xF2E35940,  // "👊"
-    0xF34482C9,  // "🌖"
     0xF36509ED,  // "👵🏾" 1F475-1F3FE
-    0xF374F7F9,  // "👱"
     0xF3779FD9,  // "💁🏼\u200d♀️" 1F481-1F3FC-200D-2640-FE0F
     0xF3852875,  // "👩\u200d👧\u200d👧" 1F469-200D-1F467-200D-1F467
     0xF407C92A,  // "👩🏻\u200d🦱" 1F469-1F3FB-200D-1F9B1
     0xF41168DE,  // "👨🏻\u200d🎤" 1F468-1F3FB-200D-1F3A4
-    0xF4367E20,  // "🌜"
-    0xF439F51A,  // "🖋"
     0xF448212E,  // "👨🏿\u200d⚖️" 1F468-1F3FF-200D-2696-FE0F
     0xF4905F05,  // "👩🏿\u200d🦼" 1F469-1F3FF-200D-1F9BC
     0xF49EAB66,  // "💆🏻\u200d♀️" 1F486-1F3FB-200D-2640-FE0F
     0xF4A92395,  // "👨🏻\u200d💻" 1F468-1F3FB-200D-1F4BB
     0xF4BB54B7,  // "🖐🏿" 1F590-1F3FF
-    0xF4C3FD50,  // "🔝"
     0xF4C4ACCB,  // "👩🏼\u200d⚖️" 1F469-1F3FC-200D-2696-FE0F
     0xF4D0ABD3,  // "👼🏻" 1F47C-1F3FB
     0xF4FF953D,  // "🏃🏿\u200d♀️" 1F3C3-1F3FF-200D-2640-FE0F
     0xF51E3C35,  // "👮🏿\u200d♀️" 1F46E-1F3FF-200D-2640-FE0F
     0xF52BE8F4,  // "👨🏼" 1F468-1F3FC
     0xF5B1A9E8,  // "👨🏼\u200d🍼" 1F468-1F3FC-200D-1F37C
-    0xF5EE3392,  // "🎍"
     0xF625384C,  // "🕥️" 1F565-FE0F
-    0xF62E267E,  // "🕛"
-    0xF63BE1C9,  // "🎫"
     0xF65FCAE3,  // "🏊\u200d♀️" 1F3CA-200D-2640-FE0F
     0xF705B58C,  // "👬🏾" 1F46C-1F3FE
     0xF7238398,  // "👩🏿\u200d🌾" 1F469-1F3FF-200D-1F33E
@@ -3705,9 +4819,7 @@ const HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1648] = [
     0xF7E47B87,  // "💆🏾\u200d♀️" 1F486-1F3FE-200D-2640-FE0F
     0xF80A20BD,  // "👎🏿" 1F44E-1F3FF
     0xF8105890,  // "👩🏻\u200d🤝\u200d👩🏿" 1F469-1F3FB-200D-1F91D-200D-1F469-1F3FF
-    0xF827E9EB,  // "📼"
     0xF868749C,  // "👧🏼" 1F467-1F3FC
-    0xF8DCF4DC,  // "🍆"
     0xF98ABC65,  // "👨🏿\u200d🤝\u200d👨🏼" 1F468-1F3FF-200D-1F91D-200D-1F468-1F3FC
     0xF9D1BAAB,  // "🐕️" 1F415-FE0F
     0xF9D81B2D,  // "🕵🏾\u200d♀️" 1F575-1F3FE-200D-2640-FE0F
@@ -3715,46 +4827,1042 @@ const HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1648] = [
     0xFA8F38BB,  // "💁🏿\u200d♂️" 1F481-1F3FF-200D-2642-FE0F
     0xFAAAE1D5,  // "🏳️" 1F3F3-FE0F
     0xFAC8A91B,  // "🏋🏾\u200d♀️" 1F3CB-1F3FE-200D-2640-FE0F
-    0xFADA1D6F,  // "🌡"
     0xFB21D885,  // "👱🏼\u200d♀️" 1F471-1F3FC-200D-2640-FE0F
-    0xFB37A465,  // "🎐"
-    0xFB3B22F5,  // "🏗"
-    0xFB5DB9A8,  // "🎈"
     0xFB9A0ECE,  // "👨\u200d👧" 1F468-200D-1F467
     0xFBB0372E,  // "💇\u200d♀️" 1F487-200D-2640-FE0F
-    0xFC2A8478,  // "🕳"
-    0xFCE780CB,  // "🍪"
-    0xFD39B0DB,  // "🐒"
     0xFD584B5D,  // "👩🏽\u200d🚒" 1F469-1F3FD-200D-1F692
     0xFD833B6E,  // "👷🏾\u200d♀️" 1F477-1F3FE-200D-2640-FE0F
     0xFD97FF53,  // "💂\u200d♂️" 1F482-200D-2642-FE0F
     0xFDD5054E,  // "👩🏾\u200d🤝\u200d👩🏿" 1F469-1F3FE-200D-1F91D-200D-1F469-1F3FF
-    0xFDDA4DD7,  // "🖼"
-    0xFE4423C5,  // "🔴"
-    0xFE474D4E,  // "💣"
     0xFE4788A4,  // "👨🏽\u200d🦰" 1F468-1F3FD-200D-1F9B0
-    0xFE60DEB9,  // "💫"
     0xFE6980B0,  // "👩🏿\u200d🔬" 1F469-1F3FF-200D-1F52C
     0xFEDA997C,  // "👨🏼\u200d🦲" 1F468-1F3FC-200D-1F9B2
-    0xFEFFB8E9,  // "👮"
     0xFF07D2EC,  // "👃🏼" 1F443-1F3FC
     0xFF664B2E,  // "👆🏿" 1F446-1F3FF
     0xFF97C63F,  // "👩🏾\u200d🎄" 1F469-1F3FE-200D-1F384
     0xFFB0CDC0,  // "🏃🏻" 1F3C3-1F3FB
-    0xFFE88987,  // "🕡"
+];
+
+/// Verification keys to rule out hash collisions; sort matches HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS
+static KEY_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [u32; 1011] = [
+    0xDD380319,  // "🕵️\u200d♀️" 1F575-FE0F-200D-2640-FE0F
+    0xA3E70D55,  // "👨🏼\u200d🤝\u200d👨🏿" 1F468-1F3FC-200D-1F91D-200D-1F468-1F3FF
+    0x84C43914,  // "🕴🏼\u200d♀️" 1F574-1F3FC-200D-2640-FE0F
+    0xB6A83A60,  // "👩🏽\u200d🎤" 1F469-1F3FD-200D-1F3A4
+    0xCCE95E20,  // "🎬️" 1F3AC-FE0F
+    0xC44855AC,  // "👩🏾\u200d🦳" 1F469-1F3FE-200D-1F9B3
+    0xCBC03849,  // "👨🏽\u200d🚀" 1F468-1F3FD-200D-1F680
+    0xA7C39CB6,  // "🕐️" 1F550-FE0F
+    0xBDD76EF0,  // "🗝️" 1F5DD-FE0F
+    0xA94291FD,  // "👃🏾" 1F443-1F3FE
+    0xBA25EB71,  // "🌎️" 1F30E-FE0F
+    0x81D52359,  // "👩🏼\u200d🎨" 1F469-1F3FC-200D-1F3A8
+    0x92D731C2,  // "💪🏽" 1F4AA-1F3FD
+    0xC01E75F5,  // "👁️" 1F441-FE0F
+    0xDFB2B3AB,  // "👨🏼\u200d⚕️" 1F468-1F3FC-200D-2695-FE0F
+    0xC1F20D53,  // "👨🏽\u200d💻" 1F468-1F3FD-200D-1F4BB
+    0xB08896E2,  // "🕺🏻" 1F57A-1F3FB
+    0x8E269DED,  // "💆🏿\u200d♀️" 1F486-1F3FF-200D-2640-FE0F
+    0x85F4C02F,  // "🖖🏽" 1F596-1F3FD
+    0xF00CFAB4,  // "🏋️\u200d♂️" 1F3CB-FE0F-200D-2642-FE0F
+    0xDECD962F,  // "👨\u200d❤️\u200d💋\u200d👨" 1F468-200D-2764-FE0F-200D-1F48B-200D-1F468
+    0xBEBA27C5,  // "👱🏿\u200d♂️" 1F471-1F3FF-200D-2642-FE0F
+    0x8458D0B3,  // "👵🏿" 1F475-1F3FF
+    0x964A4C8F,  // "👨🏿\u200d🦯" 1F468-1F3FF-200D-1F9AF
+    0xEDBEA561,  // "👩🏿\u200d🤝\u200d👨🏾" 1F469-1F3FF-200D-1F91D-200D-1F468-1F3FE
+    0xD96CF6A6,  // "👱\u200d♂️" 1F471-200D-2642-FE0F
+    0xF77E675B,  // "🎙️" 1F399-FE0F
+    0x9110B794,  // "👩\u200d🦰" 1F469-200D-1F9B0
+    0xEC4464CB,  // "💣️" 1F4A3-FE0F
+    0x8E5A7A02,  // "📺️" 1F4FA-FE0F
+    0x965E6378,  // "👱🏿" 1F471-1F3FF
+    0xD2092A54,  // "👩🏾\u200d🦼" 1F469-1F3FE-200D-1F9BC
+    0xAB3DFD42,  // "👨🏻\u200d🔬" 1F468-1F3FB-200D-1F52C
+    0x876D785C,  // "👷🏾\u200d♂️" 1F477-1F3FE-200D-2642-FE0F
+    0xE397A6ED,  // "👩🏻\u200d🤝\u200d👩🏽" 1F469-1F3FB-200D-1F91D-200D-1F469-1F3FD
+    0xC9160669,  // "👳🏼" 1F473-1F3FC
+    0xFDADA835,  // "💂🏿\u200d♀️" 1F482-1F3FF-200D-2640-FE0F
+    0xB0CC100F,  // "🏄\u200d♀️" 1F3C4-200D-2640-FE0F
+    0xA3507DE5,  // "👩🏾\u200d🤝\u200d👩🏼" 1F469-1F3FE-200D-1F91D-200D-1F469-1F3FC
+    0xF26FB8D6,  // "💇🏽\u200d♀️" 1F487-1F3FD-200D-2640-FE0F
+    0xA3F2C446,  // "💅🏽" 1F485-1F3FD
+    0xBCBE8A6B,  // "🏄🏼" 1F3C4-1F3FC
+    0xD00B5E4C,  // "👨🏽\u200d🤝\u200d👨🏾" 1F468-1F3FD-200D-1F91D-200D-1F468-1F3FE
+    0xB6186099,  // "🕵🏿\u200d♂️" 1F575-1F3FF-200D-2642-FE0F
+    0xD27318EF,  // "🏋🏼" 1F3CB-1F3FC
+    0xA58E44B4,  // "👐🏾" 1F450-1F3FE
+    0xF596AD22,  // "👨🏼\u200d🤝\u200d👨🏾" 1F468-1F3FC-200D-1F91D-200D-1F468-1F3FE
+    0xBE49E9BE,  // "💰️" 1F4B0-FE0F
+    0xBA61FB05,  // "💁🏾\u200d♂️" 1F481-1F3FE-200D-2642-FE0F
+    0xE35183B5,  // "🏵️" 1F3F5-FE0F
+    0xF25F30A4,  // "👩🏻" 1F469-1F3FB
+    0xBD690A38,  // "👩🏿\u200d🦲" 1F469-1F3FF-200D-1F9B2
+    0xA0700ED3,  // "👨🏼\u200d🔧" 1F468-1F3FC-200D-1F527
+    0xDE39271C,  // "👨\u200d👧\u200d👦" 1F468-200D-1F467-200D-1F466
+    0xD81C9D5A,  // "🗞️" 1F5DE-FE0F
+    0xAA7AE624,  // "💆🏻" 1F486-1F3FB
+    0xB8110AB0,  // "👱🏻" 1F471-1F3FB
+    0xAF3E6126,  // "🐕\u200d🦺" 1F415-200D-1F9BA
+    0xD07E641F,  // "👨🏾\u200d🍼" 1F468-1F3FE-200D-1F37C
+    0xB6FAC486,  // "🕺🏼" 1F57A-1F3FC
+    0xFCD8A91A,  // "👨\u200d🔧" 1F468-200D-1F527
+    0xCCB9A03D,  // "💪🏼" 1F4AA-1F3FC
+    0xC55CA881,  // "🏋🏾" 1F3CB-1F3FE
+    0xB1992299,  // "🏴\U000e0067\U000e0062\U000e0065\U000e006e\U000e0067\U000e007f" 1F3F4-E0067-E0062-E0065-E006E-E0067-E007F
+    0xD08C85E0,  // "👩🏼\u200d🦰" 1F469-1F3FC-200D-1F9B0
+    0xE98331B0,  // "👊🏽" 1F44A-1F3FD
+    0x8EA389EA,  // "👮🏽\u200d♂️" 1F46E-1F3FD-200D-2642-FE0F
+    0xF193C686,  // "👩🏽\u200d🍳" 1F469-1F3FD-200D-1F373
+    0xEDA7BCD1,  // "🏌🏽\u200d♀️" 1F3CC-1F3FD-200D-2640-FE0F
+    0xE5D1F5AD,  // "👩🏿\u200d⚖️" 1F469-1F3FF-200D-2696-FE0F
+    0xA15DA3B2,  // "🕘️" 1F558-FE0F
+    0xF6E48C91,  // "👨🏽\u200d🍼" 1F468-1F3FD-200D-1F37C
+    0x9D80072A,  // "🕞️" 1F55E-FE0F
+    0xD67585B3,  // "👩🏻\u200d🦼" 1F469-1F3FB-200D-1F9BC
+    0xBFE5F18E,  // "👩🏼\u200d🤝\u200d👩🏻" 1F469-1F3FC-200D-1F91D-200D-1F469-1F3FB
+    0xFA31C76D,  // "👨🏾\u200d🚀" 1F468-1F3FE-200D-1F680
+    0x9E7BF2A2,  // "👩\u200d🍳" 1F469-200D-1F373
+    0xB0F44753,  // "👨🏻\u200d🤝\u200d👨🏼" 1F468-1F3FB-200D-1F91D-200D-1F468-1F3FC
+    0xE9678770,  // "🎗️" 1F397-FE0F
+    0xAF7BAC2D,  // "🕗️" 1F557-FE0F
+    0xE70722F1,  // "📫️" 1F4EB-FE0F
+    0x9B915EBE,  // "👩\u200d👧" 1F469-200D-1F467
+    0xFFD931D8,  // "👨🏽\u200d⚕️" 1F468-1F3FD-200D-2695-FE0F
+    0xACD103FF,  // "💃🏾" 1F483-1F3FE
+    0xE1E4D880,  // "💂🏼\u200d♂️" 1F482-1F3FC-200D-2642-FE0F
+    0xB6B802BC,  // "💃🏿" 1F483-1F3FF
+    0xF3D82A6B,  // "👩🏻\u200d✈️" 1F469-1F3FB-200D-2708-FE0F
+    0x82F4A20B,  // "🏎️" 1F3CE-FE0F
+    0x991ADBC4,  // "👨🏼\u200d🚒" 1F468-1F3FC-200D-1F692
+    0x9EF1384B,  // "👩🏾\u200d🦯" 1F469-1F3FE-200D-1F9AF
+    0xBDC7E600,  // "👨\u200d🎓" 1F468-200D-1F393
+    0xE8BD4A68,  // "🎞️" 1F39E-FE0F
+    0xAD219386,  // "👰🏿\u200d♀️" 1F470-1F3FF-200D-2640-FE0F
+    0xAE261D68,  // "👨🏾\u200d⚕️" 1F468-1F3FE-200D-2695-FE0F
+    0xF6B5E008,  // "👩🏾\u200d🎓" 1F469-1F3FE-200D-1F393
+    0xC7F7206C,  // "🕑️" 1F551-FE0F
+    0xCE007CF4,  // "🏜️" 1F3DC-FE0F
+    0x80D18AC2,  // "👩🏽\u200d🤝\u200d👨🏼" 1F469-1F3FD-200D-1F91D-200D-1F468-1F3FC
+    0xB0E38C05,  // "👨🏾\u200d💻" 1F468-1F3FE-200D-1F4BB
+    0x9691DC76,  // "👦🏻" 1F466-1F3FB
+    0x9B5B2001,  // "👨🏾\u200d🍳" 1F468-1F3FE-200D-1F373
+    0xBDCDB53E,  // "👩\u200d❤️\u200d💋\u200d👨" 1F469-200D-2764-FE0F-200D-1F48B-200D-1F468
+    0x8D6B02A9,  // "👂🏽" 1F442-1F3FD
+    0xD2149C82,  // "🌏️" 1F30F-FE0F
+    0xF5813C2D,  // "📬️" 1F4EC-FE0F
+    0xB885018D,  // "👍🏾" 1F44D-1F3FE
+    0xE7440B1A,  // "👨🏻\u200d🎨" 1F468-1F3FB-200D-1F3A8
+    0xA65922D3,  // "👩🏼\u200d✈️" 1F469-1F3FC-200D-2708-FE0F
+    0xE3018781,  // "🏄🏿\u200d♀️" 1F3C4-1F3FF-200D-2640-FE0F
+    0x9324D8E8,  // "👊🏻" 1F44A-1F3FB
+    0x9ADD5FFE,  // "🕵️" 1F575-FE0F
+    0xB2DBEAE3,  // "👦🏼" 1F466-1F3FC
+    0xD631603C,  // "🍽️" 1F37D-FE0F
+    0xDBC26731,  // "👩🏿\u200d🤝\u200d👨🏽" 1F469-1F3FF-200D-1F91D-200D-1F468-1F3FD
+    0x808EAA7B,  // "👬🏻" 1F46C-1F3FB
+    0xA4EF879B,  // "👰🏻\u200d♀️" 1F470-1F3FB-200D-2640-FE0F
+    0xC0429951,  // "👨\u200d👩\u200d👧" 1F468-200D-1F469-200D-1F467
+    0xF79A0049,  // "👩\u200d🦱" 1F469-200D-1F9B1
+    0xA3024F20,  // "👈🏼" 1F448-1F3FC
+    0xDC32A45F,  // "👴🏿" 1F474-1F3FF
+    0xD72BAA0C,  // "💪🏿" 1F4AA-1F3FF
+    0xA22CC579,  // "👩🏿\u200d🦽" 1F469-1F3FF-200D-1F9BD
+    0xD00A2C4F,  // "👨\u200d⚖️" 1F468-200D-2696-FE0F
+    0xC318DFA8,  // "💂🏿" 1F482-1F3FF
+    0xE2A5CB7F,  // "🏇🏽" 1F3C7-1F3FD
+    0x8EF2E4E2,  // "👩🏽\u200d🎨" 1F469-1F3FD-200D-1F3A8
+    0x9BE4835B,  // "🗒️" 1F5D2-FE0F
+    0xBFAAB22D,  // "🏝️" 1F3DD-FE0F
+    0xED06AD34,  // "👩🏾\u200d✈️" 1F469-1F3FE-200D-2708-FE0F
+    0x8A510254,  // "👨🏼\u200d🦽" 1F468-1F3FC-200D-1F9BD
+    0xACF676DF,  // "🎅🏾" 1F385-1F3FE
+    0x9870C52A,  // "👩🏼\u200d🤝\u200d👨🏿" 1F469-1F3FC-200D-1F91D-200D-1F468-1F3FF
+    0xA15F3FE9,  // "👩🏻\u200d🦽" 1F469-1F3FB-200D-1F9BD
+    0xEE14C89B,  // "🕡️" 1F561-FE0F
+    0xF6A1F2A8,  // "👨🏿\u200d🚀" 1F468-1F3FF-200D-1F680
+    0x82FA73A6,  // "👨\u200d👩\u200d👧\u200d👧" 1F468-200D-1F469-200D-1F467-200D-1F467
+    0xF813805C,  // "👮🏼" 1F46E-1F3FC
+    0xD5C9C376,  // "👷🏽\u200d♀️" 1F477-1F3FD-200D-2640-FE0F
+    0xF32B2559,  // "🏕️" 1F3D5-FE0F
+    0x90D1A2A5,  // "👸🏿" 1F478-1F3FF
+    0x9711D5B6,  // "👲🏾" 1F472-1F3FE
+    0xE059DD34,  // "👨🏾\u200d🦯" 1F468-1F3FE-200D-1F9AF
+    0xCDDA8682,  // "👨🏼\u200d🤝\u200d👨🏻" 1F468-1F3FC-200D-1F91D-200D-1F468-1F3FB
+    0xA0680D1B,  // "💳️" 1F4B3-FE0F
+    0xBD95BF4C,  // "💂🏽" 1F482-1F3FD
+    0xF7ED089C,  // "👩🏽\u200d🦼" 1F469-1F3FD-200D-1F9BC
+    0xBD1D06F2,  // "👩🏻\u200d🔧" 1F469-1F3FB-200D-1F527
+    0x86407187,  // "💁\u200d♀️" 1F481-200D-2640-FE0F
+    0xDB49BE75,  // "👎🏻" 1F44E-1F3FB
+    0xF96D8FB2,  // "👨🏽\u200d🏫" 1F468-1F3FD-200D-1F3EB
+    0xE7A66C56,  // "👩🏻\u200d🎤" 1F469-1F3FB-200D-1F3A4
+    0xA0452A85,  // "🏋🏼\u200d♂️" 1F3CB-1F3FC-200D-2642-FE0F
+    0xA98059F6,  // "👩🏻\u200d💼" 1F469-1F3FB-200D-1F4BC
+    0xEB631289,  // "👰🏾\u200d♂️" 1F470-1F3FE-200D-2642-FE0F
+    0xCE6CF409,  // "👭🏿" 1F46D-1F3FF
+    0x9CF878DB,  // "🏟️" 1F3DF-FE0F
+    0x8BAFFF81,  // "🏊🏽" 1F3CA-1F3FD
+    0xCC04B540,  // "👩🏾\u200d🚒" 1F469-1F3FE-200D-1F692
+    0xA0B1E44C,  // "👨🏿\u200d🌾" 1F468-1F3FF-200D-1F33E
+    0xE47B7639,  // "👷🏼\u200d♂️" 1F477-1F3FC-200D-2642-FE0F
+    0xC335D05D,  // "👶🏿" 1F476-1F3FF
+    0x84026D6E,  // "👨🏼\u200d🚀" 1F468-1F3FC-200D-1F680
+    0xA49BAF84,  // "👆🏼" 1F446-1F3FC
+    0xA3089B98,  // "👩🏿\u200d💼" 1F469-1F3FF-200D-1F4BC
+    0x8489D910,  // "👨🏾\u200d🎄" 1F468-1F3FE-200D-1F384
+    0xA0AFEB51,  // "🏳️\u200d⚧️" 1F3F3-FE0F-200D-26A7-FE0F
+    0x864BCAC9,  // "📻️" 1F4FB-FE0F
+    0xCC62811F,  // "💇🏾\u200d♀️" 1F487-1F3FE-200D-2640-FE0F
+    0xC3D5CC58,  // "👨🏽\u200d🤝\u200d👨🏼" 1F468-1F3FD-200D-1F91D-200D-1F468-1F3FC
+    0xD6428FE4,  // "👽️" 1F47D-FE0F
+    0xDA70B557,  // "👇🏼" 1F447-1F3FC
+    0xC2886819,  // "👩🏽\u200d🤝\u200d👩🏼" 1F469-1F3FD-200D-1F91D-200D-1F469-1F3FC
+    0x8E586899,  // "👩🏽\u200d🔧" 1F469-1F3FD-200D-1F527
+    0xDD28387B,  // "🏖️" 1F3D6-FE0F
+    0xCF264865,  // "🐿️" 1F43F-FE0F
+    0x8D1D1FBA,  // "📋️" 1F4CB-FE0F
+    0xB760BA5C,  // "👩🏽\u200d🦲" 1F469-1F3FD-200D-1F9B2
+    0x9005B3B1,  // "🎓️" 1F393-FE0F
+    0x9D5FDC86,  // "🕵🏻" 1F575-1F3FB
+    0x8F13B030,  // "👨🏿\u200d🦰" 1F468-1F3FF-200D-1F9B0
+    0xC6892335,  // "👐🏿" 1F450-1F3FF
+    0x942D914C,  // "👮\u200d♂️" 1F46E-200D-2642-FE0F
+    0xA74B27F5,  // "🏆️" 1F3C6-FE0F
+    0xB4781869,  // "🏇🏾" 1F3C7-1F3FE
+    0x913BB7AD,  // "👸🏻" 1F478-1F3FB
+    0xDF286D6B,  // "🏄🏿\u200d♂️" 1F3C4-1F3FF-200D-2642-FE0F
+    0xDF602ED0,  // "👮\u200d♀️" 1F46E-200D-2640-FE0F
+    0xB9ED28AD,  // "👨🏽\u200d🎨" 1F468-1F3FD-200D-1F3A8
+    0xAE10B82C,  // "💃🏼" 1F483-1F3FC
+    0xF24B6922,  // "🕵️\u200d♂️" 1F575-FE0F-200D-2642-FE0F
+    0xF1126BFF,  // "👋🏾" 1F44B-1F3FE
+    0xCEB99579,  // "👨\u200d👨\u200d👧\u200d👧" 1F468-200D-1F468-200D-1F467-200D-1F467
+    0xBF9EFF19,  // "🏂🏽" 1F3C2-1F3FD
+    0x828606BA,  // "👩🏻\u200d🏫" 1F469-1F3FB-200D-1F3EB
+    0x98B11F5A,  // "👨\u200d👩\u200d👦\u200d👦" 1F468-200D-1F469-200D-1F466-200D-1F466
+    0xA9903D6F,  // "👩🏼\u200d🤝\u200d👨🏻" 1F469-1F3FC-200D-1F91D-200D-1F468-1F3FB
+    0xC1F42361,  // "💂🏻\u200d♀️" 1F482-1F3FB-200D-2640-FE0F
+    0xAEB5DB55,  // "👨🏾\u200d🏭" 1F468-1F3FE-200D-1F3ED
+    0xCFD0766D,  // "🏊🏽\u200d♀️" 1F3CA-1F3FD-200D-2640-FE0F
+    0xE062220E,  // "🏃\u200d♂️" 1F3C3-200D-2642-FE0F
+    0xEDE48D07,  // "🏃🏿\u200d♂️" 1F3C3-1F3FF-200D-2642-FE0F
+    0x96CE8ED8,  // "👪️" 1F46A-FE0F
+    0x930C4A58,  // "🕣️" 1F563-FE0F
+    0x98CBE630,  // "👨\u200d🚒" 1F468-200D-1F692
+    0xA9173843,  // "👩🏾\u200d🌾" 1F469-1F3FE-200D-1F33E
+    0xD51ACA43,  // "👷🏽\u200d♂️" 1F477-1F3FD-200D-2642-FE0F
+    0xB23FDACE,  // "🏌🏻\u200d♀️" 1F3CC-1F3FB-200D-2640-FE0F
+    0xCDB0B9D2,  // "👩🏾\u200d🤝\u200d👩🏻" 1F469-1F3FE-200D-1F91D-200D-1F469-1F3FB
+    0x85A58A9A,  // "🕴️\u200d♂️" 1F574-FE0F-200D-2642-FE0F
+    0xD783F5E9,  // "👩\u200d🦯" 1F469-200D-1F9AF
+    0xB4EF47DE,  // "👨🏽\u200d🤝\u200d👨🏿" 1F468-1F3FD-200D-1F91D-200D-1F468-1F3FF
+    0xDC86C6F0,  // "👨🏽\u200d🦲" 1F468-1F3FD-200D-1F9B2
+    0xA89C4C76,  // "👸🏼" 1F478-1F3FC
+    0xD4AEE81A,  // "👳🏻\u200d♀️" 1F473-1F3FB-200D-2640-FE0F
+    0xCFEDDA5C,  // "👩🏼\u200d🎄" 1F469-1F3FC-200D-1F384
+    0x82B142B2,  // "🖐🏼" 1F590-1F3FC
+    0xA2AA2B08,  // "👩🏼\u200d🤝\u200d👩🏾" 1F469-1F3FC-200D-1F91D-200D-1F469-1F3FE
+    0xD0202BD7,  // "🕒️" 1F552-FE0F
+    0xB85331AC,  // "👨🏻\u200d🎓" 1F468-1F3FB-200D-1F393
+    0xC4BCB84E,  // "👷🏻\u200d♂️" 1F477-1F3FB-200D-2642-FE0F
+    0x927F6F3E,  // "👸🏾" 1F478-1F3FE
+    0xCE5209DA,  // "🌨️" 1F328-FE0F
+    0x8FD8FF61,  // "👇️" 1F447-FE0F
+    0xD444A211,  // "👰🏼\u200d♂️" 1F470-1F3FC-200D-2642-FE0F
+    0xB79E0734,  // "🐻\u200d❄️" 1F43B-200D-2744-FE0F
+    0xE91DC723,  // "👊🏾" 1F44A-1F3FE
+    0xF931A8D8,  // "👩🏿\u200d🎓" 1F469-1F3FF-200D-1F393
+    0xC45F1EBD,  // "💆🏿" 1F486-1F3FF
+    0x9C71991A,  // "👨🏻\u200d🏭" 1F468-1F3FB-200D-1F3ED
+    0xCA4E9669,  // "🏭️" 1F3ED-FE0F
+    0xF0E4507F,  // "👩🏾\u200d🍼" 1F469-1F3FE-200D-1F37C
+    0xE1AD9266,  // "👵🏼" 1F475-1F3FC
+    0xA6032F2D,  // "👨\u200d🎤" 1F468-200D-1F3A4
+    0xE6F91550,  // "🖖🏼" 1F596-1F3FC
+    0x93BDBA94,  // "👨🏾\u200d💼" 1F468-1F3FE-200D-1F4BC
+    0xA111513E,  // "👇🏿" 1F447-1F3FF
+    0xF3A9A40D,  // "👨🏻" 1F468-1F3FB
+    0xA7DAC5B2,  // "👮🏾\u200d♂️" 1F46E-1F3FE-200D-2642-FE0F
+    0x87AB71C0,  // "💂🏿\u200d♂️" 1F482-1F3FF-200D-2642-FE0F
+    0xCC4D509B,  // "👨🏿\u200d🦳" 1F468-1F3FF-200D-1F9B3
+    0xE74D7452,  // "👨🏽\u200d🦽" 1F468-1F3FD-200D-1F9BD
+    0xEA50B6E6,  // "👲🏼" 1F472-1F3FC
+    0x8899BDB2,  // "👩🏿\u200d🏫" 1F469-1F3FF-200D-1F3EB
+    0x98960E74,  // "👩🏽\u200d🤝\u200d👨🏾" 1F469-1F3FD-200D-1F91D-200D-1F468-1F3FE
+    0xA3B2574C,  // "🍸️" 1F378-FE0F
+    0x8ABA221E,  // "🕴🏻" 1F574-1F3FB
+    0x99EBEEAC,  // "🌥️" 1F325-FE0F
+    0xFD44CF0A,  // "🌶️" 1F336-FE0F
+    0xBDE01C1E,  // "👨🏻\u200d🦱" 1F468-1F3FB-200D-1F9B1
+    0xB04291E0,  // "👨🏿\u200d🔬" 1F468-1F3FF-200D-1F52C
+    0xDC260CF7,  // "👎🏾" 1F44E-1F3FE
+    0x8DCEEC75,  // "👧🏿" 1F467-1F3FF
+    0xE3209DB9,  // "👩🏼" 1F469-1F3FC
+    0xA535ED75,  // "👨🏾\u200d🤝\u200d👨🏼" 1F468-1F3FE-200D-1F91D-200D-1F468-1F3FC
+    0xC8D2E294,  // "🏊🏿\u200d♀️" 1F3CA-1F3FF-200D-2640-FE0F
+    0xDE5438AE,  // "🗡️" 1F5E1-FE0F
+    0xA8409547,  // "💇🏽" 1F487-1F3FD
+    0x8C521876,  // "👶🏾" 1F476-1F3FE
+    0x975E44F0,  // "👨🏾\u200d🎓" 1F468-1F3FE-200D-1F393
+    0xB6C59CDB,  // "👩\u200d🚀" 1F469-200D-1F680
+    0x8C2B3E29,  // "👨\u200d👦" 1F468-200D-1F466
+    0x9D2DC9F9,  // "👩🏿\u200d🎨" 1F469-1F3FF-200D-1F3A8
+    0xAE3624AF,  // "🕦️" 1F566-FE0F
+    0x8E082B05,  // "🕖️" 1F556-FE0F
+    0xCB0A0A3D,  // "🖕🏻" 1F595-1F3FB
+    0x8CDBCD48,  // "👊🏼" 1F44A-1F3FC
+    0xD17BB2C9,  // "👨🏻\u200d🚀" 1F468-1F3FB-200D-1F680
+    0x8ECE74AE,  // "👶🏼" 1F476-1F3FC
+    0x800A1C33,  // "👩\u200d👩\u200d👧\u200d👧" 1F469-200D-1F469-200D-1F467-200D-1F467
+    0xCE08CBD7,  // "🖊️" 1F58A-FE0F
+    0xC99265A5,  // "💁🏽\u200d♂️" 1F481-1F3FD-200D-2642-FE0F
+    0xA0570617,  // "👨\u200d💼" 1F468-200D-1F4BC
+    0xAB716CF4,  // "🏌️" 1F3CC-FE0F
+    0x9F08A744,  // "👨🏾\u200d🦳" 1F468-1F3FE-200D-1F9B3
+    0xFD2A6E78,  // "👩🏽\u200d🦳" 1F469-1F3FD-200D-1F9B3
+    0xB88E2428,  // "👩\u200d🏫" 1F469-200D-1F3EB
+    0xA22C786B,  // "👷🏿\u200d♀️" 1F477-1F3FF-200D-2640-FE0F
+    0x896907A1,  // "👩🏼\u200d🤝\u200d👩🏿" 1F469-1F3FC-200D-1F91D-200D-1F469-1F3FF
+    0xC1C2358E,  // "👩🏽\u200d✈️" 1F469-1F3FD-200D-2708-FE0F
+    0xEA338A93,  // "👩🏽\u200d💻" 1F469-1F3FD-200D-1F4BB
+    0xBB042400,  // "👩🏻\u200d🎄" 1F469-1F3FB-200D-1F384
+    0x8345A1D0,  // "👫🏾" 1F46B-1F3FE
+    0x83CD0030,  // "🗣️" 1F5E3-FE0F
+    0xF3BDC610,  // "👩\u200d⚖️" 1F469-200D-2696-FE0F
+    0xB881610B,  // "👩\u200d❤️\u200d👨" 1F469-200D-2764-FE0F-200D-1F468
+    0xEC5E4D3C,  // "👨🏼\u200d⚖️" 1F468-1F3FC-200D-2696-FE0F
+    0xA5D1AB16,  // "🗺️" 1F5FA-FE0F
+    0x94ED4E20,  // "💁🏽" 1F481-1F3FD
+    0xDE4491B1,  // "🏇🏻" 1F3C7-1F3FB
+    0x9F60F0E4,  // "🕳️" 1F573-FE0F
+    0xE36F5C16,  // "💂🏾\u200d♀️" 1F482-1F3FE-200D-2640-FE0F
+    0x8FDCDA4C,  // "🏃\u200d♀️" 1F3C3-200D-2640-FE0F
+    0xAB94FD04,  // "🕶️" 1F576-FE0F
+    0xB1BC588E,  // "👨🏾\u200d🎨" 1F468-1F3FE-200D-1F3A8
+    0xE14FEEE1,  // "👨🏼\u200d💼" 1F468-1F3FC-200D-1F4BC
+    0x80C3569A,  // "💃🏽" 1F483-1F3FD
+    0xDFF98046,  // "👫🏼" 1F46B-1F3FC
+    0xEB8C74F8,  // "👩\u200d👩\u200d👦\u200d👦" 1F469-200D-1F469-200D-1F466-200D-1F466
+    0xE442A9BD,  // "🕸️" 1F578-FE0F
+    0xDDCC7052,  // "📚️" 1F4DA-FE0F
+    0xC8E93E21,  // "🏞️" 1F3DE-FE0F
+    0xE9926FCF,  // "👉️" 1F449-FE0F
+    0xEB017DBB,  // "📤️" 1F4E4-FE0F
+    0x942F7DF9,  // "👂🏿" 1F442-1F3FF
+    0xD591683B,  // "🏃🏽" 1F3C3-1F3FD
+    0x87E558B4,  // "👩🏽\u200d🔬" 1F469-1F3FD-200D-1F52C
+    0xF3A288CB,  // "🕉️" 1F549-FE0F
+    0xD87F6114,  // "👩🏿\u200d🤝\u200d👩🏾" 1F469-1F3FF-200D-1F91D-200D-1F469-1F3FE
+    0xE93362B0,  // "👩\u200d💻" 1F469-200D-1F4BB
+    0xCA568D43,  // "👨🏼\u200d🏭" 1F468-1F3FC-200D-1F3ED
+    0xB64E7727,  // "🕺🏾" 1F57A-1F3FE
+    0xB32A067B,  // "👩🏿\u200d🚀" 1F469-1F3FF-200D-1F680
+    0xE7B653F8,  // "👨🏾\u200d🦱" 1F468-1F3FE-200D-1F9B1
+    0xF54DBDBE,  // "👨\u200d🚀" 1F468-200D-1F680
+    0xF7A01382,  // "🐟️" 1F41F-FE0F
+    0xB2A47AC8,  // "👩🏻\u200d⚕️" 1F469-1F3FB-200D-2695-FE0F
+    0xACE67D97,  // "👮🏻\u200d♂️" 1F46E-1F3FB-200D-2642-FE0F
+    0xBC2C2D34,  // "🕴️\u200d♀️" 1F574-FE0F-200D-2640-FE0F
+    0xD06176AF,  // "👨\u200d👨\u200d👧\u200d👦" 1F468-200D-1F468-200D-1F467-200D-1F466
+    0xD8C496E1,  // "👩🏿\u200d🚒" 1F469-1F3FF-200D-1F692
+    0xDE0C2833,  // "👨\u200d💻" 1F468-200D-1F4BB
+    0xB59B2DD9,  // "👏🏽" 1F44F-1F3FD
+    0x9FF459CD,  // "👨🏽\u200d🦼" 1F468-1F3FD-200D-1F9BC
+    0xEC54B3D1,  // "🏌🏻" 1F3CC-1F3FB
+    0xED2C4796,  // "💅🏻" 1F485-1F3FB
+    0xC6E06C46,  // "👨🏾\u200d🦼" 1F468-1F3FE-200D-1F9BC
+    0xA1CAB26D,  // "🖕🏽" 1F595-1F3FD
+    0xD53288D5,  // "👦🏾" 1F466-1F3FE
+    0xDC05B14A,  // "👼🏾" 1F47C-1F3FE
+    0xB6A1A47C,  // "👨🏾\u200d🔧" 1F468-1F3FE-200D-1F527
+    0xCE854230,  // "👂️" 1F442-FE0F
+    0xE88801DB,  // "👨\u200d👨\u200d👦" 1F468-200D-1F468-200D-1F466
+    0xC96916FD,  // "🕵🏼\u200d♀️" 1F575-1F3FC-200D-2640-FE0F
+    0x9F2224BE,  // "👨🏿\u200d🏫" 1F468-1F3FF-200D-1F3EB
+    0xBA8F8644,  // "🌫️" 1F32B-FE0F
+    0xD2852CB1,  // "👷🏼\u200d♀️" 1F477-1F3FC-200D-2640-FE0F
+    0xD7A3509D,  // "👨\u200d👨\u200d👧" 1F468-200D-1F468-200D-1F467
+    0x9DC520D5,  // "👩🏻\u200d💻" 1F469-1F3FB-200D-1F4BB
+    0xB9C8EBC1,  // "👨\u200d👦\u200d👦" 1F468-200D-1F466-200D-1F466
+    0xEB929575,  // "👱🏽\u200d♀️" 1F471-1F3FD-200D-2640-FE0F
+    0xF6FED1BC,  // "👭🏽" 1F46D-1F3FD
+    0xF250EC55,  // "👨🏼\u200d🔬" 1F468-1F3FC-200D-1F52C
+    0xE4C66F8B,  // "👨🏾\u200d🦲" 1F468-1F3FE-200D-1F9B2
+    0x9319BD38,  // "👨🏻\u200d✈️" 1F468-1F3FB-200D-2708-FE0F
+    0x8E363DAD,  // "🎚️" 1F39A-FE0F
+    0x9E6412BC,  // "👐🏻" 1F450-1F3FB
+    0xDDCD4CE3,  // "👩🏽\u200d🎄" 1F469-1F3FD-200D-1F384
+    0xD26C9289,  // "👩🏾\u200d🤝\u200d👨🏽" 1F469-1F3FE-200D-1F91D-200D-1F468-1F3FD
+    0xD1F42FF7,  // "👳🏼\u200d♀️" 1F473-1F3FC-200D-2640-FE0F
+    0xD3299823,  // "💂🏼" 1F482-1F3FC
+    0xAC95C2A7,  // "👇🏻" 1F447-1F3FB
+    0x823BA9CE,  // "👳\u200d♂️" 1F473-200D-2642-FE0F
+    0x994F9FFE,  // "👆🏽" 1F446-1F3FD
+    0xCD6EF872,  // "👨🏼\u200d🎨" 1F468-1F3FC-200D-1F3A8
+    0xA4856762,  // "👩🏽\u200d🌾" 1F469-1F3FD-200D-1F33E
+    0x826B0553,  // "🌩️" 1F329-FE0F
+    0x962A37E0,  // "🏄🏻\u200d♀️" 1F3C4-1F3FB-200D-2640-FE0F
+    0xAB21E39A,  // "👨\u200d🍼" 1F468-200D-1F37C
+    0xBCF5C482,  // "👨🏾\u200d🔬" 1F468-1F3FE-200D-1F52C
+    0x9179BCA4,  // "🕵🏻\u200d♂️" 1F575-1F3FB-200D-2642-FE0F
+    0xC34C6652,  // "👭🏾" 1F46D-1F3FE
+    0xED99AB6C,  // "💅🏾" 1F485-1F3FE
+    0xE2170994,  // "👨🏻\u200d🍼" 1F468-1F3FB-200D-1F37C
+    0xB4616EC4,  // "🕴🏼\u200d♂️" 1F574-1F3FC-200D-2642-FE0F
+    0xBCAD8F65,  // "👩🏻\u200d🚒" 1F469-1F3FB-200D-1F692
+    0xD45268C0,  // "👩🏻\u200d🎨" 1F469-1F3FB-200D-1F3A8
+    0xF30FEFD5,  // "👮🏽" 1F46E-1F3FD
+    0xA7FB0EB3,  // "👍🏿" 1F44D-1F3FF
+    0xD2B5523E,  // "👩🏼\u200d🍼" 1F469-1F3FC-200D-1F37C
+    0xFDC36B8C,  // "👩🏿" 1F469-1F3FF
+    0xFFD68DE6,  // "👨\u200d🌾" 1F468-200D-1F33E
+    0x94073226,  // "👩\u200d🦲" 1F469-200D-1F9B2
+    0xD7D8BBD2,  // "👎️" 1F44E-FE0F
+    0xB42450AA,  // "🏄🏻" 1F3C4-1F3FB
+    0xA011D813,  // "👨🏿\u200d🎨" 1F468-1F3FF-200D-1F3A8
+    0xDE495D7C,  // "👩\u200d🌾" 1F469-200D-1F33E
+    0x87FBBF89,  // "👩\u200d✈️" 1F469-200D-2708-FE0F
+    0xC60CC64A,  // "👳🏽\u200d♂️" 1F473-1F3FD-200D-2642-FE0F
+    0xF1F41609,  // "👼🏿" 1F47C-1F3FF
+    0xBFAC3083,  // "🏌🏽" 1F3CC-1F3FD
+    0xF9F4F447,  // "👱🏿\u200d♀️" 1F471-1F3FF-200D-2640-FE0F
+    0xCDF92968,  // "🏋🏻" 1F3CB-1F3FB
+    0x84027859,  // "👩🏻\u200d🦳" 1F469-1F3FB-200D-1F9B3
+    0xEB6094EB,  // "🏌🏿\u200d♂️" 1F3CC-1F3FF-200D-2642-FE0F
+    0x9391E97F,  // "👩🏻\u200d🦰" 1F469-1F3FB-200D-1F9B0
+    0xB6D61837,  // "👩🏻\u200d🎓" 1F469-1F3FB-200D-1F393
+    0xE9E626E4,  // "👯\u200d♀️" 1F46F-200D-2640-FE0F
+    0xAA69F798,  // "💃🏻" 1F483-1F3FB
+    0x9A89791C,  // "🏋🏾\u200d♂️" 1F3CB-1F3FE-200D-2642-FE0F
+    0xF560F108,  // "👩🏿\u200d🦯" 1F469-1F3FF-200D-1F9AF
+    0xE9B105CB,  // "🗯️" 1F5EF-FE0F
+    0xD197D612,  // "💆🏻\u200d♂️" 1F486-1F3FB-200D-2642-FE0F
+    0xCA100A2C,  // "🕧️" 1F567-FE0F
+    0x844D4C5D,  // "👩🏾" 1F469-1F3FE
+    0xBE940784,  // "🏌️\u200d♀️" 1F3CC-FE0F-200D-2640-FE0F
+    0xD1809899,  // "👳🏻\u200d♂️" 1F473-1F3FB-200D-2642-FE0F
+    0x8E2DEAD9,  // "👆️" 1F446-FE0F
+    0xE6DD309C,  // "🌬️" 1F32C-FE0F
+    0x99B69747,  // "💂🏾" 1F482-1F3FE
+    0xE4A8C9BF,  // "🖼️" 1F5BC-FE0F
+    0xBC1F543B,  // "🏠️" 1F3E0-FE0F
+    0xB87AB41E,  // "🖕🏼" 1F595-1F3FC
+    0xBAC2C54E,  // "🌍️" 1F30D-FE0F
+    0xE4D53ABE,  // "👨🏾\u200d🚒" 1F468-1F3FE-200D-1F692
+    0xBDAC5780,  // "👌🏻" 1F44C-1F3FB
+    0xE3828CCB,  // "👩🏻\u200d🦲" 1F469-1F3FB-200D-1F9B2
+    0xEF7E2467,  // "👨🏽\u200d✈️" 1F468-1F3FD-200D-2708-FE0F
+    0xCF669124,  // "👩🏾\u200d🎤" 1F469-1F3FE-200D-1F3A4
+    0xEA96C3DC,  // "👩\u200d👦\u200d👦" 1F469-200D-1F466-200D-1F466
+    0xD7E465DF,  // "👩🏾\u200d🤝\u200d👨🏿" 1F469-1F3FE-200D-1F91D-200D-1F468-1F3FF
+    0xC81CBD66,  // "🖕🏾" 1F595-1F3FE
+    0xC42D8DF7,  // "👨🏻\u200d🦳" 1F468-1F3FB-200D-1F9B3
+    0x86A3F024,  // "👨🏽\u200d🌾" 1F468-1F3FD-200D-1F33E
+    0xA2A66CB5,  // "🕤️" 1F564-FE0F
+    0xFDC19CA6,  // "👮🏼\u200d♀️" 1F46E-1F3FC-200D-2640-FE0F
+    0xCCB90A29,  // "👩🏾\u200d💼" 1F469-1F3FE-200D-1F4BC
+    0x92931664,  // "👴🏾" 1F474-1F3FE
+    0xF0E38EBA,  // "🕵🏽\u200d♀️" 1F575-1F3FD-200D-2640-FE0F
+    0xC6F5269A,  // "👨🏿\u200d🤝\u200d👨🏻" 1F468-1F3FF-200D-1F91D-200D-1F468-1F3FB
+    0x8CA16B9D,  // "🏋🏿" 1F3CB-1F3FF
+    0x974CAA5E,  // "👨🏼\u200d🎄" 1F468-1F3FC-200D-1F384
+    0xAB5C4B53,  // "🕔️" 1F554-FE0F
+    0xDB0FD5C8,  // "💇🏼" 1F487-1F3FC
+    0x89BF32F1,  // "🕵🏼" 1F575-1F3FC
+    0xCB7E03A2,  // "👨🏻\u200d🦰" 1F468-1F3FB-200D-1F9B0
+    0xD3F4E059,  // "👩🏾\u200d🏭" 1F469-1F3FE-200D-1F3ED
+    0xE9FF7426,  // "👨🏻\u200d🍳" 1F468-1F3FB-200D-1F373
+    0xBDC865F2,  // "👨🏼\u200d🏫" 1F468-1F3FC-200D-1F3EB
+    0x91E46957,  // "👨\u200d🦱" 1F468-200D-1F9B1
+    0x8C42C4F5,  // "🖨️" 1F5A8-FE0F
+    0xDB6BE5B0,  // "👮🏿\u200d♂️" 1F46E-1F3FF-200D-2642-FE0F
+    0xFB312447,  // "👭🏻" 1F46D-1F3FB
+    0xD1E2BA44,  // "👩🏿\u200d✈️" 1F469-1F3FF-200D-2708-FE0F
+    0xD7223D6B,  // "👃🏿" 1F443-1F3FF
+    0xC3C653BC,  // "💆🏾" 1F486-1F3FE
+    0xDD67E664,  // "👮🏼\u200d♂️" 1F46E-1F3FC-200D-2642-FE0F
+    0xF18DF19B,  // "🕴🏻\u200d♀️" 1F574-1F3FB-200D-2640-FE0F
+    0x922FE267,  // "🖇️" 1F587-FE0F
+    0xC700C1B0,  // "💂🏽\u200d♂️" 1F482-1F3FD-200D-2642-FE0F
+    0xD1364FE0,  // "👉🏿" 1F449-1F3FF
+    0xF9ED4F4A,  // "👱🏻\u200d♀️" 1F471-1F3FB-200D-2640-FE0F
+    0xE6D2B9AD,  // "👩🏾\u200d🤝\u200d👨🏼" 1F469-1F3FE-200D-1F91D-200D-1F468-1F3FC
+    0x833A1522,  // "👩🏿\u200d💻" 1F469-1F3FF-200D-1F4BB
+    0xA4DEDB9F,  // "👨🏾\u200d🏫" 1F468-1F3FE-200D-1F3EB
+    0xD88C9C06,  // "👈🏿" 1F448-1F3FF
+    0x9ED1ACCD,  // "🏙️" 1F3D9-FE0F
+    0xAE7EAA6D,  // "👆🏻" 1F446-1F3FB
+    0x80ACEEE0,  // "👩🏻\u200d🔬" 1F469-1F3FB-200D-1F52C
+    0xEEFC21F3,  // "👨\u200d🍳" 1F468-200D-1F373
+    0xEB55D2B9,  // "👷\u200d♂️" 1F477-200D-2642-FE0F
+    0xBA6EED7F,  // "🖋️" 1F58B-FE0F
+    0xBE7F4CF0,  // "🕴🏾\u200d♂️" 1F574-1F3FE-200D-2642-FE0F
+    0xBC205A8B,  // "💂🏻\u200d♂️" 1F482-1F3FB-200D-2642-FE0F
+    0xB7AE3112,  // "🏊🏿\u200d♂️" 1F3CA-1F3FF-200D-2642-FE0F
+    0xEBB9F2BF,  // "🏴\u200d☠️" 1F3F4-200D-2620-FE0F
+    0xC5F054FA,  // "👩🏿\u200d🦱" 1F469-1F3FF-200D-1F9B1
+    0xEB9A1E2A,  // "👲🏿" 1F472-1F3FF
+    0x8DAF2838,  // "👯\u200d♂️" 1F46F-200D-2642-FE0F
+    0xA2F05D2F,  // "🏚️" 1F3DA-FE0F
+    0xDEAFB441,  // "👷\u200d♀️" 1F477-200D-2640-FE0F
+    0xCF78696A,  // "👌🏿" 1F44C-1F3FF
+    0x9CFAB5DE,  // "🌜️" 1F31C-FE0F
+    0xF56AC457,  // "👩🏽" 1F469-1F3FD
+    0xEC92CC3D,  // "👩🏾\u200d🦽" 1F469-1F3FE-200D-1F9BD
+    0xE37372F3,  // "👨\u200d🔬" 1F468-200D-1F52C
+    0x96027848,  // "👨🏻\u200d🎄" 1F468-1F3FB-200D-1F384
+    0x8292E456,  // "👳🏾" 1F473-1F3FE
+    0xC49E83D4,  // "🕯️" 1F56F-FE0F
+    0xD35B18A4,  // "👨🏿\u200d🤝\u200d👨🏽" 1F468-1F3FF-200D-1F91D-200D-1F468-1F3FD
+    0xB555E14E,  // "👩🏽\u200d🍼" 1F469-1F3FD-200D-1F37C
+    0xCD79383F,  // "👩🏼\u200d⚕️" 1F469-1F3FC-200D-2695-FE0F
+    0xC7C00375,  // "💁🏿\u200d♀️" 1F481-1F3FF-200D-2640-FE0F
+    0x8AB8F3C8,  // "👨🏻\u200d🚒" 1F468-1F3FB-200D-1F692
+    0xB69C8BD4,  // "👈🏽" 1F448-1F3FD
+    0x93CF2F7E,  // "🏂🏼" 1F3C2-1F3FC
+    0xEA411F64,  // "🕴🏽\u200d♀️" 1F574-1F3FD-200D-2640-FE0F
+    0x8448A1DD,  // "🕴🏾" 1F574-1F3FE
+    0xED67C1A2,  // "💁🏿" 1F481-1F3FF
+    0x9C1459EF,  // "👨🏿\u200d🎤" 1F468-1F3FF-200D-1F3A4
+    0xF76A67C8,  // "👩🏿\u200d🤝\u200d👨🏼" 1F469-1F3FF-200D-1F91D-200D-1F468-1F3FC
+    0x8D85C895,  // "🏌🏿" 1F3CC-1F3FF
+    0xF1D6D4A1,  // "👨🏽" 1F468-1F3FD
+    0xC8D7A004,  // "👩🏼\u200d🦼" 1F469-1F3FC-200D-1F9BC
+    0xDC7EBF94,  // "🎛️" 1F39B-FE0F
+    0xDD3F3AC2,  // "💪🏾" 1F4AA-1F3FE
+    0x952AC064,  // "👮🏿" 1F46E-1F3FF
+    0xE5E155AC,  // "🏊️" 1F3CA-FE0F
+    0xDD2103B5,  // "💆🏼\u200d♂️" 1F486-1F3FC-200D-2642-FE0F
+    0xA4438646,  // "👉🏻" 1F449-1F3FB
+    0xC8D96266,  // "🏂🏻" 1F3C2-1F3FB
+    0x843CC71E,  // "👨\u200d👧\u200d👧" 1F468-200D-1F467-200D-1F467
+    0xCBCC17B7,  // "👨🏽\u200d🔬" 1F468-1F3FD-200D-1F52C
+    0x81F97544,  // "🏋🏻\u200d♂️" 1F3CB-1F3FB-200D-2642-FE0F
+    0x8AB5F865,  // "💁🏼" 1F481-1F3FC
+    0xCEFA6263,  // "🎧️" 1F3A7-FE0F
+    0xC3EA1816,  // "👰🏾" 1F470-1F3FE
+    0xF8965EE3,  // "👩\u200d🎤" 1F469-200D-1F3A4
+    0xD9942779,  // "🔈️" 1F508-FE0F
+    0xD19E8686,  // "👨🏽\u200d🚒" 1F468-1F3FD-200D-1F692
+    0x9096D0BB,  // "🗂️" 1F5C2-FE0F
+    0x8FF0CC4A,  // "🏄\u200d♂️" 1F3C4-200D-2642-FE0F
+    0xE9F3F2F5,  // "💇🏻\u200d♀️" 1F487-1F3FB-200D-2640-FE0F
+    0xA9ACEB0A,  // "🕴️" 1F574-FE0F
+    0xDAA234DA,  // "💂🏻" 1F482-1F3FB
+    0x8DF87EF9,  // "👨🏽\u200d🔧" 1F468-1F3FD-200D-1F527
+    0x9208075C,  // "👨🏼\u200d🍳" 1F468-1F3FC-200D-1F373
+    0xE9CA3A0B,  // "👍🏽" 1F44D-1F3FD
+    0xA58B48AA,  // "🖕🏿" 1F595-1F3FF
+    0xF7F04417,  // "🏛️" 1F3DB-FE0F
+    0xFC3DC876,  // "🏌🏾\u200d♂️" 1F3CC-1F3FE-200D-2642-FE0F
+    0xCA357521,  // "🏊\u200d♂️" 1F3CA-200D-2642-FE0F
+    0xC8C15656,  // "👩🏼\u200d🦲" 1F469-1F3FC-200D-1F9B2
+    0xED1E5B4F,  // "👨🏻\u200d🤝\u200d👨🏿" 1F468-1F3FB-200D-1F91D-200D-1F468-1F3FF
+    0x81A4AABB,  // "💇🏻\u200d♂️" 1F487-1F3FB-200D-2642-FE0F
+    0xBF828E24,  // "💅🏼" 1F485-1F3FC
+    0xC40B93F6,  // "👩🏾\u200d🦲" 1F469-1F3FE-200D-1F9B2
+    0xEFA2F95C,  // "👩🏻\u200d🤝\u200d👨🏾" 1F469-1F3FB-200D-1F91D-200D-1F468-1F3FE
+    0xF1129A24,  // "🏴\U000e0067\U000e0062\U000e0077\U000e006c\U000e0073\U000e007f" 1F3F4-E0067-E0062-E0077-E006C-E0073-E007F
+    0xEF710223,  // "🕕️" 1F555-FE0F
+    0x87C242D2,  // "👨\u200d👨\u200d👦\u200d👦" 1F468-200D-1F468-200D-1F466-200D-1F466
+    0x8065A5E6,  // "💆🏽\u200d♂️" 1F486-1F3FD-200D-2642-FE0F
+    0x84746859,  // "💁🏻\u200d♀️" 1F481-1F3FB-200D-2640-FE0F
+    0xEDFC4E8A,  // "🐈\u200d⬛" 1F408-200D-2B1B
+    0xAEE26398,  // "👨\u200d👩\u200d👧\u200d👦" 1F468-200D-1F469-200D-1F467-200D-1F466
+    0xE4EA8815,  // "👩🏿\u200d🤝\u200d👨🏻" 1F469-1F3FF-200D-1F91D-200D-1F468-1F3FB
+    0xD01EEF8C,  // "🏷️" 1F3F7-FE0F
+    0xCCA55D64,  // "🏄🏽\u200d♀️" 1F3C4-1F3FD-200D-2640-FE0F
+    0xBFDD36AE,  // "👳🏿\u200d♀️" 1F473-1F3FF-200D-2640-FE0F
+    0xAF77B51E,  // "👨🏾\u200d🦽" 1F468-1F3FE-200D-1F9BD
+    0xDBC853CE,  // "👩🏿\u200d🦰" 1F469-1F3FF-200D-1F9B0
+    0x8D6B2565,  // "🏄🏻\u200d♂️" 1F3C4-1F3FB-200D-2642-FE0F
+    0x92E67C92,  // "👴🏼" 1F474-1F3FC
+    0xE3AFE2FA,  // "🎭️" 1F3AD-FE0F
+    0xCAC6BA34,  // "🏊🏻\u200d♀️" 1F3CA-1F3FB-200D-2640-FE0F
+    0xEE826F7A,  // "💂\u200d♀️" 1F482-200D-2640-FE0F
+    0xD0F6DC94,  // "🕟️" 1F55F-FE0F
+    0xF0131E51,  // "👉🏾" 1F449-1F3FE
+    0xA6E5E893,  // "🏄🏿" 1F3C4-1F3FF
+    0xA682EEE2,  // "💁🏾\u200d♀️" 1F481-1F3FE-200D-2640-FE0F
+    0x85F007D6,  // "👰🏽\u200d♂️" 1F470-1F3FD-200D-2642-FE0F
+    0xA273CABD,  // "👉🏼" 1F449-1F3FC
+    0xF28D9CE6,  // "🕴🏼" 1F574-1F3FC
+    0x934C942F,  // "👩\u200d👩\u200d👧\u200d👦" 1F469-200D-1F469-200D-1F467-200D-1F466
+    0xD2DCE6CC,  // "👨🏽\u200d🦯" 1F468-1F3FD-200D-1F9AF
+    0xB38E9353,  // "🏳️\u200d🌈" 1F3F3-FE0F-200D-1F308
+    0xB6D5CB6E,  // "💇🏿\u200d♂️" 1F487-1F3FF-200D-2642-FE0F
+    0xE002145B,  // "🏇🏼" 1F3C7-1F3FC
+    0xD3CA5DE5,  // "👩\u200d🎓" 1F469-200D-1F393
+    0xACC5D371,  // "👩\u200d🔬" 1F469-200D-1F52C
+    0xF31B5BDB,  // "🏃🏼\u200d♂️" 1F3C3-1F3FC-200D-2642-FE0F
+    0xFB2E3FC2,  // "👷🏻\u200d♀️" 1F477-1F3FB-200D-2640-FE0F
+    0xE69E2F49,  // "👵🏻" 1F475-1F3FB
+    0xCC521EA2,  // "💆\u200d♂️" 1F486-200D-2642-FE0F
+    0x9CD54432,  // "👩🏻\u200d🚀" 1F469-1F3FB-200D-1F680
+    0x888DD103,  // "👩🏻\u200d🌾" 1F469-1F3FB-200D-1F33E
+    0xE906C881,  // "👋🏼" 1F44B-1F3FC
+    0x802ADAC2,  // "👳🏽" 1F473-1F3FD
+    0xA578AD51,  // "🏌🏾" 1F3CC-1F3FE
+    0xE202A8CB,  // "👩🏼\u200d🎓" 1F469-1F3FC-200D-1F393
+    0x8AEDEFF6,  // "👷🏿\u200d♂️" 1F477-1F3FF-200D-2642-FE0F
+    0xDF68E01F,  // "👰🏼" 1F470-1F3FC
+    0xD2209D1F,  // "🏋️\u200d♀️" 1F3CB-FE0F-200D-2640-FE0F
+    0x9BB67C8B,  // "👦🏽" 1F466-1F3FD
+    0xAFD58FB6,  // "🌦️" 1F326-FE0F
+    0xFB0B26F5,  // "👩🏽\u200d🏫" 1F469-1F3FD-200D-1F3EB
+    0xF478BEF6,  // "🕵🏾" 1F575-1F3FE
+    0x82608A2C,  // "👧🏻" 1F467-1F3FB
+    0xB1A44788,  // "👳🏻" 1F473-1F3FB
+    0xA46C4FF0,  // "💇\u200d♂️" 1F487-200D-2642-FE0F
+    0xD3FD394E,  // "🏊🏻" 1F3CA-1F3FB
+    0xC7883614,  // "👩🏽\u200d🦽" 1F469-1F3FD-200D-1F9BD
+    0x9377B45C,  // "👩🏼\u200d🚀" 1F469-1F3FC-200D-1F680
+    0xBCF7C93E,  // "👷🏿" 1F477-1F3FF
+    0xB9F44D74,  // "👨🏾\u200d🦰" 1F468-1F3FE-200D-1F9B0
+    0xBAFB75E3,  // "🏊🏻\u200d♂️" 1F3CA-1F3FB-200D-2642-FE0F
+    0xAE62A035,  // "👩🏽\u200d⚕️" 1F469-1F3FD-200D-2695-FE0F
+    0xFC0DC918,  // "👱\u200d♀️" 1F471-200D-2640-FE0F
+    0xE9E9BC32,  // "👩🏽\u200d🤝\u200d👨🏻" 1F469-1F3FD-200D-1F91D-200D-1F468-1F3FB
+    0xDC003C7E,  // "👩🏿\u200d🍳" 1F469-1F3FF-200D-1F373
+    0xFE1E90AB,  // "👁\u200d🗨" 1F441-200D-1F5E8
+    0xCB9C27B4,  // "🏄🏽\u200d♂️" 1F3C4-1F3FD-200D-2642-FE0F
+    0xB29CF17F,  // "👼🏼" 1F47C-1F3FC
+    0xB815081A,  // "👨🏿\u200d💻" 1F468-1F3FF-200D-1F4BB
+    0xEF7D4CEC,  // "👩\u200d❤️\u200d👩" 1F469-200D-2764-FE0F-200D-1F469
+    0x81E6DCA0,  // "👱🏽" 1F471-1F3FD
+    0xB36A9A9E,  // "👼🏽" 1F47C-1F3FD
+    0xAE49EC4E,  // "💁🏾" 1F481-1F3FE
+    0x818CDFB6,  // "🎮️" 1F3AE-FE0F
+    0xE454A56A,  // "👱🏼" 1F471-1F3FC
+    0x8A1EB27A,  // "👩🏾\u200d🔧" 1F469-1F3FE-200D-1F527
+    0xD78873D7,  // "👩🏻\u200d🍼" 1F469-1F3FB-200D-1F37C
+    0xF679E274,  // "👩🏾\u200d💻" 1F469-1F3FE-200D-1F4BB
+    0xC85E4ECC,  // "👷🏻" 1F477-1F3FB
+    0xDCF369DF,  // "👱🏻\u200d♂️" 1F471-1F3FB-200D-2642-FE0F
+    0xC14D1CA5,  // "👨🏽\u200d🦳" 1F468-1F3FD-200D-1F9B3
+    0xD4C287CD,  // "👩🏿\u200d🍼" 1F469-1F3FF-200D-1F37C
+    0x82279AD4,  // "👈🏻" 1F448-1F3FB
+    0xC7B742CC,  // "🕴🏾\u200d♀️" 1F574-1F3FE-200D-2640-FE0F
+    0xFE619E4F,  // "👨🏼\u200d🦳" 1F468-1F3FC-200D-1F9B3
+    0xAD89BC5B,  // "👩\u200d🚒" 1F469-200D-1F692
+    0xA4304CE3,  // "👨🏿\u200d🎄" 1F468-1F3FF-200D-1F384
+    0xA0C68D9C,  // "👩🏾\u200d🦰" 1F469-1F3FE-200D-1F9B0
+    0xDEE7A0A6,  // "👨🏾\u200d🌾" 1F468-1F3FE-200D-1F33E
+    0xF82DC83B,  // "👂🏻" 1F442-1F3FB
+    0xEC911F55,  // "👷🏽" 1F477-1F3FD
+    0xEC242EEE,  // "👨🏾\u200d⚖️" 1F468-1F3FE-200D-2696-FE0F
+    0x970A5BF6,  // "👨🏼\u200d✈️" 1F468-1F3FC-200D-2708-FE0F
+    0x929502B4,  // "🗓️" 1F5D3-FE0F
+    0x82BBF0B0,  // "👩🏾\u200d🍳" 1F469-1F3FE-200D-1F373
+    0xE3EE8356,  // "🏌🏼\u200d♂️" 1F3CC-1F3FC-200D-2642-FE0F
+    0x9124FBDD,  // "👩🏽\u200d🤝\u200d👩🏾" 1F469-1F3FD-200D-1F91D-200D-1F469-1F3FE
+    0xB91F2B3C,  // "💁\u200d♂️" 1F481-200D-2642-FE0F
+    0x8064759E,  // "👨🏼\u200d💻" 1F468-1F3FC-200D-1F4BB
+    0x803EA727,  // "👩\u200d🎨" 1F469-200D-1F3A8
+    0xEBB2E25B,  // "👨🏿\u200d🏭" 1F468-1F3FF-200D-1F3ED
+    0x88B74AA0,  // "👨🏻\u200d🌾" 1F468-1F3FB-200D-1F33E
+    0xDFC35054,  // "🐦️" 1F426-FE0F
+    0xF53BD879,  // "🏄🏼\u200d♀️" 1F3C4-1F3FC-200D-2640-FE0F
+    0x9626D3F9,  // "💂🏼\u200d♀️" 1F482-1F3FC-200D-2640-FE0F
+    0xCE0DB5B9,  // "📥️" 1F4E5-FE0F
+    0x94C731AB,  // "📭️" 1F4ED-FE0F
+    0xBB42BEA2,  // "👰🏻\u200d♂️" 1F470-1F3FB-200D-2642-FE0F
+    0x9ABD205D,  // "🏋🏽" 1F3CB-1F3FD
+    0x9A57556A,  // "👨🏻\u200d🦽" 1F468-1F3FB-200D-1F9BD
+    0xDBE31F21,  // "👏🏼" 1F44F-1F3FC
+    0x8D5FFA0A,  // "👋🏿" 1F44B-1F3FF
+    0x8F802382,  // "👩\u200d⚕️" 1F469-200D-2695-FE0F
+    0xACABF028,  // "👨🏼\u200d🦼" 1F468-1F3FC-200D-1F9BC
+    0x880DC30E,  // "👰🏽\u200d♀️" 1F470-1F3FD-200D-2640-FE0F
+    0xA792AD2D,  // "💆🏽\u200d♀️" 1F486-1F3FD-200D-2640-FE0F
+    0x992BF685,  // "📽️" 1F4FD-FE0F
+    0xC788CCC6,  // "🏃🏼" 1F3C3-1F3FC
+    0x82D81EC0,  // "💇🏽\u200d♂️" 1F487-1F3FD-200D-2642-FE0F
+    0xB3650CE0,  // "🏊🏼\u200d♂️" 1F3CA-1F3FC-200D-2642-FE0F
+    0xAB8A196A,  // "👨🏽\u200d🎓" 1F468-1F3FD-200D-1F393
+    0xD480F2EE,  // "👩\u200d🦼" 1F469-200D-1F9BC
+    0xC6A181C0,  // "👏🏿" 1F44F-1F3FF
+    0xD00ACAF4,  // "👶🏽" 1F476-1F3FD
+    0xD1D11186,  // "🏋🏿\u200d♂️" 1F3CB-1F3FF-200D-2642-FE0F
+    0xCB440695,  // "🏃🏾\u200d♀️" 1F3C3-1F3FE-200D-2640-FE0F
+    0x9BB419E8,  // "👨🏿\u200d🤝\u200d👨🏾" 1F468-1F3FF-200D-1F91D-200D-1F468-1F3FE
+    0xACADDDBF,  // "👨🏿\u200d💼" 1F468-1F3FF-200D-1F4BC
+    0xA3F97315,  // "🏌🏼" 1F3CC-1F3FC
+    0xC2A372AA,  // "🔍️" 1F50D-FE0F
+    0x862E8A5F,  // "👂🏾" 1F442-1F3FE
+    0xB35BDD94,  // "👨🏼\u200d🎓" 1F468-1F3FC-200D-1F393
+    0xE247120D,  // "👈️" 1F448-FE0F
+    0xBA1B32E8,  // "👍🏻" 1F44D-1F3FB
+    0xC2F28AC1,  // "💆🏿\u200d♂️" 1F486-1F3FF-200D-2642-FE0F
+    0xA94C69BB,  // "🕺🏽" 1F57A-1F3FD
+    0xD94B6A0A,  // "🕢️" 1F562-FE0F
+    0xA8A37D4C,  // "👩🏻\u200d🤝\u200d👩🏼" 1F469-1F3FB-200D-1F91D-200D-1F469-1F3FC
+    0xD7311094,  // "👏🏻" 1F44F-1F3FB
+    0xA9D1AD03,  // "👨🏻\u200d🦲" 1F468-1F3FB-200D-1F9B2
+    0xD9FFC09E,  // "👩🏿\u200d🤝\u200d👩🏽" 1F469-1F3FF-200D-1F91D-200D-1F469-1F3FD
+    0xB191045E,  // "👨\u200d⚕️" 1F468-200D-2695-FE0F
+    0x955B4C1E,  // "👨\u200d✈️" 1F468-200D-2708-FE0F
+    0xE23F8404,  // "🔒️" 1F512-FE0F
+    0xF59DEE4F,  // "👨\u200d👩\u200d👦" 1F468-200D-1F469-200D-1F466
+    0xA84485EC,  // "🌡️" 1F321-FE0F
+    0x99CD117A,  // "🏗️" 1F3D7-FE0F
+    0x9C431295,  // "👮🏻\u200d♀️" 1F46E-1F3FB-200D-2640-FE0F
+    0x9B06A4AB,  // "👨\u200d🏭" 1F468-200D-1F3ED
+    0xDA258516,  // "💇🏿" 1F487-1F3FF
+    0xE40530B8,  // "👫🏿" 1F46B-1F3FF
+    0x94BAD6BF,  // "🕴🏿\u200d♂️" 1F574-1F3FF-200D-2642-FE0F
+    0xB9CF797B,  // "👭🏼" 1F46D-1F3FC
+    0xBBD88274,  // "🖱️" 1F5B1-FE0F
+    0x881233A8,  // "👨🏿\u200d🍳" 1F468-1F3FF-200D-1F373
+    0x81389237,  // "👩🏻\u200d🍳" 1F469-1F3FB-200D-1F373
+    0xC4DD55E4,  // "🌕️" 1F315-FE0F
+    0xE12B12A3,  // "👍🏼" 1F44D-1F3FC
+    0xB0CCBA56,  // "💇🏼\u200d♀️" 1F487-1F3FC-200D-2640-FE0F
+    0xE523AB86,  // "👩🏻\u200d🏭" 1F469-1F3FB-200D-1F3ED
+    0xE0BD3B55,  // "🏃🏾" 1F3C3-1F3FE
+    0x9FA76783,  // "🏋️" 1F3CB-FE0F
+    0xABAA027E,  // "🗄️" 1F5C4-FE0F
+    0xD2713216,  // "👰🏼\u200d♀️" 1F470-1F3FC-200D-2640-FE0F
+    0xECF73D91,  // "🕹️" 1F579-FE0F
+    0xB9B5623C,  // "💆🏾\u200d♂️" 1F486-1F3FE-200D-2642-FE0F
+    0xFD007979,  // "📟️" 1F4DF-FE0F
+    0xDB2E6764,  // "👨🏿\u200d🎓" 1F468-1F3FF-200D-1F393
+    0x869EF8C6,  // "👴🏻" 1F474-1F3FB
+    0xAB669D32,  // "🏌🏾\u200d♀️" 1F3CC-1F3FE-200D-2640-FE0F
+    0xB4729D32,  // "👨🏻\u200d🦯" 1F468-1F3FB-200D-1F9AF
+    0xAE113238,  // "🔓️" 1F513-FE0F
+    0x86D5FF04,  // "👴🏽" 1F474-1F3FD
+    0x8075D973,  // "👳🏽\u200d♀️" 1F473-1F3FD-200D-2640-FE0F
+    0x9A7B7BA5,  // "👨🏿\u200d⚕️" 1F468-1F3FF-200D-2695-FE0F
+    0xAB1A1F21,  // "👱🏼\u200d♂️" 1F471-1F3FC-200D-2642-FE0F
+    0x919D840E,  // "🕚️" 1F55A-FE0F
+    0x81F6A9F9,  // "👆🏾" 1F446-1F3FE
+    0xF672E773,  // "🏄🏾\u200d♂️" 1F3C4-1F3FE-200D-2642-FE0F
+    0xE676F58D,  // "👩🏻\u200d🦯" 1F469-1F3FB-200D-1F9AF
+    0xB8444F48,  // "👏🏾" 1F44F-1F3FE
+    0xDBE1C2CF,  // "🏌🏽\u200d♂️" 1F3CC-1F3FD-200D-2642-FE0F
+    0xD2AFCD42,  // "👩🏾\u200d🎨" 1F469-1F3FE-200D-1F3A8
+    0x938BC23C,  // "💇🏻" 1F487-1F3FB
+    0xD216AB26,  // "🏄️" 1F3C4-FE0F
+    0xC1C102FF,  // "🎅🏻" 1F385-1F3FB
+    0xF4A8036A,  // "🕝️" 1F55D-FE0F
+    0xD62F6492,  // "🏃🏾\u200d♂️" 1F3C3-1F3FE-200D-2642-FE0F
+    0xB285721E,  // "👩🏿\u200d🔧" 1F469-1F3FF-200D-1F527
+    0xD0339DAC,  // "🕵🏾\u200d♂️" 1F575-1F3FE-200D-2642-FE0F
+    0xCBB185C8,  // "📪️" 1F4EA-FE0F
+    0xE13997BE,  // "🖌️" 1F58C-FE0F
+    0x87B1EF6C,  // "👨🏾\u200d✈️" 1F468-1F3FE-200D-2708-FE0F
+    0xBFD3CCFA,  // "👩\u200d👧\u200d👦" 1F469-200D-1F467-200D-1F466
+    0x8F0E7716,  // "🏊🏿" 1F3CA-1F3FF
+    0xED3E58D0,  // "👨🏽\u200d🤝\u200d👨🏻" 1F468-1F3FD-200D-1F91D-200D-1F468-1F3FB
+    0xEBA9FCF7,  // "👩🏼\u200d🌾" 1F469-1F3FC-200D-1F33E
+    0xF1543A4C,  // "👳🏾\u200d♂️" 1F473-1F3FE-200D-2642-FE0F
+    0xFA226DB9,  // "👓️" 1F453-FE0F
+    0xD14C757C,  // "👨\u200d🦽" 1F468-200D-1F9BD
+    0xCFFC25D4,  // "📦️" 1F4E6-FE0F
+    0x9D7AE679,  // "👨🏿\u200d🚒" 1F468-1F3FF-200D-1F692
+    0xD2BEF19B,  // "👩🏻\u200d🤝\u200d👨🏿" 1F469-1F3FB-200D-1F91D-200D-1F468-1F3FF
+    0xE0A7283B,  // "👩🏻\u200d⚖️" 1F469-1F3FB-200D-2696-FE0F
+    0xE79BA32C,  // "👵🏽" 1F475-1F3FD
+    0x91B03E93,  // "👩\u200d🦽" 1F469-200D-1F9BD
+    0xA39F821F,  // "🏃🏻\u200d♀️" 1F3C3-1F3FB-200D-2640-FE0F
+    0x8A11E9AA,  // "🏃🏽\u200d♀️" 1F3C3-1F3FD-200D-2640-FE0F
+    0xAC7AF770,  // "👲🏻" 1F472-1F3FB
+    0xEC7BF756,  // "👨🏿\u200d✈️" 1F468-1F3FF-200D-2708-FE0F
+    0xB83DCA48,  // "👩🏼\u200d🤝\u200d👨🏾" 1F469-1F3FC-200D-1F91D-200D-1F468-1F3FE
+    0xA0AC0553,  // "🏃🏿" 1F3C3-1F3FF
+    0x90D9D081,  // "🏄🏼\u200d♂️" 1F3C4-1F3FC-200D-2642-FE0F
+    0xB7EFABE5,  // "👨🏻\u200d⚖️" 1F468-1F3FB-200D-2696-FE0F
+    0xE3F09CC3,  // "👨\u200d🦲" 1F468-200D-1F9B2
+    0xFB52E5F6,  // "👩🏼\u200d🚒" 1F469-1F3FC-200D-1F692
+    0xC0D77556,  // "👱🏾\u200d♂️" 1F471-1F3FE-200D-2642-FE0F
+    0xE8A7C5F2,  // "🏋🏽\u200d♀️" 1F3CB-1F3FD-200D-2640-FE0F
+    0xFF8B1C76,  // "💁🏽\u200d♀️" 1F481-1F3FD-200D-2640-FE0F
+    0x9C7DFB6A,  // "🏃🏻\u200d♂️" 1F3C3-1F3FB-200D-2642-FE0F
+    0xABDE388C,  // "🖖🏾" 1F596-1F3FE
+    0xF24AA56E,  // "🏊🏼" 1F3CA-1F3FC
+    0xAAF1D27A,  // "👨🏾\u200d🤝\u200d👨🏻" 1F468-1F3FE-200D-1F91D-200D-1F468-1F3FB
+    0xB1E900FB,  // "👳🏾\u200d♀️" 1F473-1F3FE-200D-2640-FE0F
+    0x914F1EFF,  // "👩🏼\u200d💻" 1F469-1F3FC-200D-1F4BB
+    0xE466DC1B,  // "👨🏼\u200d🦱" 1F468-1F3FC-200D-1F9B1
+    0xFD030E54,  // "💂🏾\u200d♂️" 1F482-1F3FE-200D-2642-FE0F
+    0xC9C1ACB2,  // "👷🏼" 1F477-1F3FC
+    0x8B6E65D1,  // "🕊️" 1F54A-FE0F
+    0xF139A920,  // "🏌🏼\u200d♀️" 1F3CC-1F3FC-200D-2640-FE0F
+    0xB80E3114,  // "🕵🏽\u200d♂️" 1F575-1F3FD-200D-2642-FE0F
+    0x9C421D3C,  // "🖖🏿" 1F596-1F3FF
+    0xAE45E9D2,  // "👊🏿" 1F44A-1F3FF
+    0xEF5F0C29,  // "👋🏻" 1F44B-1F3FB
+    0xF71BAF27,  // "🎅🏿" 1F385-1F3FF
+    0x84ED5C37,  // "👳🏼\u200d♂️" 1F473-1F3FC-200D-2642-FE0F
+    0x9E7D9AEC,  // "🏂🏿" 1F3C2-1F3FF
+    0xF71E5CD3,  // "🕵🏿" 1F575-1F3FF
+    0xEECF231F,  // "🕴🏿" 1F574-1F3FF
+    0xAAC9A18F,  // "🎖️" 1F396-FE0F
+    0xB9C35FDD,  // "💇🏾" 1F487-1F3FE
+    0xCECB585F,  // "👨🏿\u200d🦲" 1F468-1F3FF-200D-1F9B2
+    0xE219583A,  // "👨\u200d🦳" 1F468-200D-1F9B3
+    0x8CA8CA9C,  // "🏌️\u200d♂️" 1F3CC-FE0F-200D-2642-FE0F
+    0xC954E84B,  // "👨🏻\u200d🤝\u200d👨🏾" 1F468-1F3FB-200D-1F91D-200D-1F468-1F3FE
+    0xEA339F8A,  // "👨🏽\u200d⚖️" 1F468-1F3FD-200D-2696-FE0F
+    0xBC0CFDEA,  // "👎🏽" 1F44E-1F3FD
+    0xC4B176A6,  // "👳🏿" 1F473-1F3FF
+    0xB94AD4B8,  // "👨🏻\u200d🏫" 1F468-1F3FB-200D-1F3EB
+    0xD06B7373,  // "👂🏼" 1F442-1F3FC
+    0xB390E403,  // "👧🏽" 1F467-1F3FD
+    0x86E73B95,  // "👩🏼\u200d🦯" 1F469-1F3FC-200D-1F9AF
+    0xFC96F30D,  // "👩🏼\u200d🍳" 1F469-1F3FC-200D-1F373
+    0xA5AD98CB,  // "🕴🏽" 1F574-1F3FD
+    0xF4850999,  // "🏊🏾\u200d♂️" 1F3CA-1F3FE-200D-2642-FE0F
+    0xC15CC78F,  // "👃🏻" 1F443-1F3FB
+    0xF7224DEA,  // "👩🏼\u200d🤝\u200d👩🏽" 1F469-1F3FC-200D-1F91D-200D-1F469-1F3FD
+    0xB3FECA13,  // "🖐️" 1F590-FE0F
+    0xD57FACD6,  // "👨🏿\u200d🦱" 1F468-1F3FF-200D-1F9B1
+    0xEC60E97E,  // "👨🏼\u200d🤝\u200d👨🏽" 1F468-1F3FC-200D-1F91D-200D-1F468-1F3FD
+    0xA3AA3431,  // "🕺🏿" 1F57A-1F3FF
+    0x9AB653D4,  // "💿️" 1F4BF-FE0F
+    0xC449BDE5,  // "👨🏾" 1F468-1F3FE
+    0xB8DC0A13,  // "👩🏿\u200d🎄" 1F469-1F3FF-200D-1F384
+    0xDD407312,  // "👨🏾\u200d🤝\u200d👨🏽" 1F468-1F3FE-200D-1F91D-200D-1F468-1F3FD
+    0xB07A5D8C,  // "💻️" 1F4BB-FE0F
+    0xCE9D7093,  // "👩🏼\u200d🦽" 1F469-1F3FC-200D-1F9BD
+    0xD840278A,  // "🎟️" 1F39F-FE0F
+    0x9B7916BE,  // "👩\u200d👩\u200d👦" 1F469-200D-1F469-200D-1F466
+    0xFCE09855,  // "💇🏿\u200d♀️" 1F487-1F3FF-200D-2640-FE0F
+    0x80FD11DB,  // "👩\u200d🍼" 1F469-200D-1F37C
+    0xBA31500B,  // "👩🏻\u200d🤝\u200d👨🏽" 1F469-1F3FB-200D-1F91D-200D-1F468-1F3FD
+    0x9A882E05,  // "🏊🏽\u200d♂️" 1F3CA-1F3FD-200D-2642-FE0F
+    0x88D4CBF2,  // "👌🏽" 1F44C-1F3FD
+    0x928DC30F,  // "📹️" 1F4F9-FE0F
+    0xCDBB3F59,  // "👇🏾" 1F447-1F3FE
+    0xECABB4C9,  // "👨🏿\u200d🍼" 1F468-1F3FF-200D-1F37C
+    0xF6F6EFEC,  // "🖍️" 1F58D-FE0F
+    0xE5C375FA,  // "👍️" 1F44D-FE0F
+    0x968709EA,  // "👨🏿\u200d🔧" 1F468-1F3FF-200D-1F527
+    0xC9C11323,  // "👨\u200d🦼" 1F468-200D-1F9BC
+    0x89465884,  // "🌧️" 1F327-FE0F
+    0xE1976EEC,  // "👳🏿\u200d♂️" 1F473-1F3FF-200D-2642-FE0F
+    0x8CD0A356,  // "👩🏾\u200d🔬" 1F469-1F3FE-200D-1F52C
+    0x85592EB0,  // "🕵🏼\u200d♂️" 1F575-1F3FC-200D-2642-FE0F
+    0x873BA9A5,  // "🕷️" 1F577-FE0F
+    0xD94338B7,  // "🏄🏽" 1F3C4-1F3FD
+    0xD6ED66A0,  // "🏇🏿" 1F3C7-1F3FF
+    0xB16BA374,  // "👨🏻\u200d⚕️" 1F468-1F3FB-200D-2695-FE0F
+    0xCC495E0B,  // "👨\u200d🦯" 1F468-200D-1F9AF
+    0xEDA638F7,  // "👩🏽\u200d🦯" 1F469-1F3FD-200D-1F9AF
+    0xE70F0CA6,  // "🕓️" 1F553-FE0F
+    0x98CCB872,  // "👰🏾\u200d♀️" 1F470-1F3FE-200D-2640-FE0F
+    0x9CE404D0,  // "🏔️" 1F3D4-FE0F
+    0xF8B34242,  // "🕵🏽" 1F575-1F3FD
+    0xFB7153B4,  // "🏄🏾\u200d♀️" 1F3C4-1F3FE-200D-2640-FE0F
+    0x889640AD,  // "🏴\U000e0067\U000e0062\U000e0073\U000e0063\U000e0074\U000e007f" 1F3F4-E0067-E0062-E0073-E0063-E0074-E007F
+    0x978DFFF9,  // "👮🏾\u200d♀️" 1F46E-1F3FE-200D-2640-FE0F
+    0xD5199495,  // "🏋🏼\u200d♀️" 1F3CB-1F3FC-200D-2640-FE0F
+    0xB31CA929,  // "🎅🏼" 1F385-1F3FC
+    0xA1B399CA,  // "💆🏼\u200d♀️" 1F486-1F3FC-200D-2640-FE0F
+    0xA08EF221,  // "👧🏾" 1F467-1F3FE
+    0xEFEF66FD,  // "👩🏽\u200d🤝\u200d👨🏿" 1F469-1F3FD-200D-1F91D-200D-1F468-1F3FF
+    0xF8074F65,  // "👎🏼" 1F44E-1F3FC
+    0x96A8912D,  // "👬🏿" 1F46C-1F3FF
+    0xA1D267DE,  // "💂🏽\u200d♀️" 1F482-1F3FD-200D-2640-FE0F
+    0x93A799AB,  // "👩🏽\u200d🦱" 1F469-1F3FD-200D-1F9B1
+    0xB4A5E1B7,  // "👩🏻\u200d🤝\u200d👩🏾" 1F469-1F3FB-200D-1F91D-200D-1F469-1F3FE
+    0xB35B1FAD,  // "💇🏼\u200d♂️" 1F487-1F3FC-200D-2642-FE0F
+    0xB5B40173,  // "🏘️" 1F3D8-FE0F
+    0xDE29CA0B,  // "👩🏾\u200d⚕️" 1F469-1F3FE-200D-2695-FE0F
+    0xCA9F678D,  // "👦🏿" 1F466-1F3FF
+    0xAF36ADA3,  // "🕴🏻\u200d♂️" 1F574-1F3FB-200D-2642-FE0F
+    0xEAC7BF8D,  // "🖲️" 1F5B2-FE0F
+    0x91450BDD,  // "👩\u200d🎄" 1F469-200D-1F384
+    0xC0787941,  // "👩\u200d🦳" 1F469-200D-1F9B3
+    0xFB5105E1,  // "👩🏼\u200d🏫" 1F469-1F3FC-200D-1F3EB
+    0xCC8B121C,  // "💆🏼" 1F486-1F3FC
+    0xFC32C91F,  // "👇🏽" 1F447-1F3FD
+    0x9E51BCC6,  // "🕴🏿\u200d♀️" 1F574-1F3FF-200D-2640-FE0F
+    0xAFB8824E,  // "🏋🏽\u200d♂️" 1F3CB-1F3FD-200D-2642-FE0F
+    0xC70AB2B8,  // "🏃🏽\u200d♂️" 1F3C3-1F3FD-200D-2642-FE0F
+    0xB2711BCF,  // "👲🏽" 1F472-1F3FD
+    0xB3EB23B6,  // "👩🏾\u200d🦱" 1F469-1F3FE-200D-1F9B1
+    0xDCC5C82C,  // "👩🏼\u200d🤝\u200d👨🏽" 1F469-1F3FC-200D-1F91D-200D-1F468-1F3FD
+    0xC9877500,  // "📷️" 1F4F7-FE0F
+    0x83B847C4,  // "👩🏽\u200d🎓" 1F469-1F3FD-200D-1F393
+    0xC473D8AA,  // "💅🏿" 1F485-1F3FF
+    0xF439530D,  // "🏋🏻\u200d♀️" 1F3CB-1F3FB-200D-2640-FE0F
+    0x8099283A,  // "👩\u200d🏭" 1F469-200D-1F3ED
+    0x8B2209FC,  // "👰\u200d♂️" 1F470-200D-2642-FE0F
+    0xD5E7AD8D,  // "🏂️" 1F3C2-FE0F
+    0x858308E6,  // "🌪️" 1F32A-FE0F
+    0xE74E5902,  // "🖐🏽" 1F590-1F3FD
+    0xBBF67579,  // "🖖🏻" 1F596-1F3FB
+    0x87081481,  // "👰🏽" 1F470-1F3FD
+    0x82DC7C28,  // "👱🏾" 1F471-1F3FE
+    0xBE68A0A4,  // "🖐🏾" 1F590-1F3FE
+    0xE5ECEAAE,  // "👨🏽\u200d🦱" 1F468-1F3FD-200D-1F9B1
+    0xF2221799,  // "🕵🏿\u200d♀️" 1F575-1F3FF-200D-2640-FE0F
+    0x83FC845F,  // "👩🏼\u200d🏭" 1F469-1F3FC-200D-1F3ED
+    0xF9BFA7D0,  // "👶🏻" 1F476-1F3FB
+    0xA54B4A10,  // "👨🏿\u200d🦼" 1F468-1F3FF-200D-1F9BC
+    0xA973FFD2,  // "👨🏽\u200d🏭" 1F468-1F3FD-200D-1F3ED
+    0xDB5A5E26,  // "🎅🏽" 1F385-1F3FD
+    0xAD4975B3,  // "🏍️" 1F3CD-FE0F
+    0x8A536AB5,  // "👩🏻\u200d🤝\u200d👨🏼" 1F469-1F3FB-200D-1F91D-200D-1F468-1F3FC
+    0xBC1DF72C,  // "💇🏾\u200d♂️" 1F487-1F3FE-200D-2642-FE0F
+    0xF329113A,  // "👐🏽" 1F450-1F3FD
+    0xE3BCC197,  // "🗜️" 1F5DC-FE0F
+    0xEC351AD1,  // "👨\u200d🎨" 1F468-200D-1F3A8
+    0xA04E8647,  // "👩\u200d💼" 1F469-200D-1F4BC
+    0xFE7E0FE4,  // "🏃🏼\u200d♀️" 1F3C3-1F3FC-200D-2640-FE0F
+    0xB8B081A9,  // "👨🏻\u200d🔧" 1F468-1F3FB-200D-1F527
+    0xEDEAD80A,  // "👨🏽\u200d🎤" 1F468-1F3FD-200D-1F3A4
+    0xD7527FE2,  // "👰🏻" 1F470-1F3FB
+    0xC76717AD,  // "👨🏼\u200d🎤" 1F468-1F3FC-200D-1F3A4
+    0x9F7C375F,  // "👨🏾\u200d🤝\u200d👨🏿" 1F468-1F3FE-200D-1F91D-200D-1F468-1F3FF
+    0xC297E521,  // "👬🏼" 1F46C-1F3FC
+    0x98FF4672,  // "👩🏼\u200d🦱" 1F469-1F3FC-200D-1F9B1
+    0xF796756A,  // "👐🏼" 1F450-1F3FC
+    0xA546993F,  // "👨🏼\u200d🦯" 1F468-1F3FC-200D-1F9AF
+    0xE0AA18E0,  // "🕜️" 1F55C-FE0F
+    0xE8A5D011,  // "👩🏾\u200d🚀" 1F469-1F3FE-200D-1F680
+    0xA60DD2C8,  // "🕴🏽\u200d♂️" 1F574-1F3FD-200D-2642-FE0F
+    0xDD46002E,  // "👩\u200d👩\u200d👧" 1F469-200D-1F469-200D-1F467
+    0xF192CDE4,  // "👩🏾\u200d🤝\u200d👩🏽" 1F469-1F3FE-200D-1F91D-200D-1F469-1F3FD
+    0x8B13F8B8,  // "👨\u200d🦰" 1F468-200D-1F9B0
+    0xEE853BAE,  // "👫🏻" 1F46B-1F3FB
+    0xBE1E939F,  // "👋🏽" 1F44B-1F3FD
+    0xD6F542D3,  // "🖥️" 1F5A5-FE0F
+    0xAAB13042,  // "👃🏽" 1F443-1F3FD
+    0x8F5E7E28,  // "👨🏻\u200d🦼" 1F468-1F3FB-200D-1F9BC
+    0xB9D5EDC2,  // "👬🏽" 1F46C-1F3FD
+    0x83BB1387,  // "🐈️" 1F408-FE0F
+    0xBBC050A5,  // "💆\u200d♀️" 1F486-200D-2640-FE0F
+    0xC14A792E,  // "🏊🏾" 1F3CA-1F3FE
+    0xF2465E3A,  // "👩🏼\u200d💼" 1F469-1F3FC-200D-1F4BC
+    0xDC0AAABE,  // "👸🏽" 1F478-1F3FD
+    0xD9847358,  // "👩🏽\u200d🤝\u200d👩🏻" 1F469-1F3FD-200D-1F91D-200D-1F469-1F3FB
+    0xBC0E7362,  // "👩🏽\u200d🤝\u200d👩🏿" 1F469-1F3FD-200D-1F91D-200D-1F469-1F3FF
+    0xE51A2142,  // "🕠️" 1F560-FE0F
+    0xE2AF8D56,  // "👨🏼\u200d🦰" 1F468-1F3FC-200D-1F9B0
+    0xDDAC136C,  // "👩🏾\u200d🤝\u200d👨🏻" 1F469-1F3FE-200D-1F91D-200D-1F468-1F3FB
+    0x92EDBBA9,  // "🗑️" 1F5D1-FE0F
+    0xCA192E64,  // "👨🏽\u200d🍳" 1F468-1F3FD-200D-1F373
+    0xC5C3AB06,  // "👌🏾" 1F44C-1F3FE
+    0xC8C22294,  // "👩🏿\u200d⚕️" 1F469-1F3FF-200D-2695-FE0F
+    0x831E2A63,  // "🖐🏻" 1F590-1F3FB
+    0xAFADAD53,  // "👩🏿\u200d🤝\u200d👩🏼" 1F469-1F3FF-200D-1F91D-200D-1F469-1F3FC
+    0xC96C3C4E,  // "👩🏽\u200d💼" 1F469-1F3FD-200D-1F4BC
+    0x9635C540,  // "👰🏿\u200d♂️" 1F470-1F3FF-200D-2642-FE0F
+    0xC079F071,  // "👩🏿\u200d🏭" 1F469-1F3FF-200D-1F3ED
+    0xE0E33064,  // "👨🏻\u200d🤝\u200d👨🏽" 1F468-1F3FB-200D-1F91D-200D-1F468-1F3FD
+    0x92E59E8A,  // "👨🏿" 1F468-1F3FF
+    0x83B1DB89,  // "👰🏿" 1F470-1F3FF
+    0xD88498F9,  // "🕰️" 1F570-FE0F
+    0xE2CFA5FE,  // "🕙️" 1F559-FE0F
+    0xEBCCC46B,  // "🗳️" 1F5F3-FE0F
+    0xA76E0BB1,  // "👨🏽\u200d💼" 1F468-1F3FD-200D-1F4BC
+    0xDE16BD73,  // "👨🏾\u200d🎤" 1F468-1F3FE-200D-1F3A4
+    0xF8853402,  // "👩🏾\u200d⚖️" 1F469-1F3FE-200D-2696-FE0F
+    0x9E90538D,  // "👱🏾\u200d♀️" 1F471-1F3FE-200D-2640-FE0F
+    0x91422530,  // "👩\u200d❤️\u200d💋\u200d👩" 1F469-200D-2764-FE0F-200D-1F48B-200D-1F469
+    0xE0A8E924,  // "👰\u200d♀️" 1F470-200D-2640-FE0F
+    0xA0044B22,  // "🏌🏻\u200d♂️" 1F3CC-1F3FB-200D-2642-FE0F
+    0xB2C68F6C,  // "👳\u200d♀️" 1F473-200D-2640-FE0F
+    0xE689FCB2,  // "👩🏼\u200d🎤" 1F469-1F3FC-200D-1F3A4
+    0xF945BCFB,  // "👮🏾" 1F46E-1F3FE
+    0xE3471C15,  // "👩🏽\u200d🏭" 1F469-1F3FD-200D-1F3ED
+    0xA05A8B3D,  // "👩🏼\u200d🔧" 1F469-1F3FC-200D-1F527
+    0x9AB52586,  // "💁🏻\u200d♂️" 1F481-1F3FB-200D-2642-FE0F
+    0x911CFB80,  // "🗃️" 1F5C3-FE0F
+    0xF0E999C3,  // "👫🏽" 1F46B-1F3FD
+    0xC1E5A1DC,  // "👨🏼\u200d🌾" 1F468-1F3FC-200D-1F33E
+    0xDE36C12C,  // "💁🏼\u200d♂️" 1F481-1F3FC-200D-2642-FE0F
+    0xDAE440B5,  // "🏂🏾" 1F3C2-1F3FE
+    0x8E9ADBB9,  // "👩🏽\u200d🦰" 1F469-1F3FD-200D-1F9B0
+    0x8DBEB5E3,  // "👩🏼\u200d🦳" 1F469-1F3FC-200D-1F9B3
+    0xE81A8A2B,  // "🏋🏿\u200d♀️" 1F3CB-1F3FF-200D-2640-FE0F
+    0x9FF92901,  // "👩🏿\u200d🎤" 1F469-1F3FF-200D-1F3A4
+    0xA67AFAE5,  // "🏌🏿\u200d♀️" 1F3CC-1F3FF-200D-2640-FE0F
+    0x9136C210,  // "👨🏿\u200d🦽" 1F468-1F3FF-200D-1F9BD
+    0xAE4324AF,  // "🌤️" 1F324-FE0F
+    0xDB28C228,  // "👩\u200d👦" 1F469-200D-1F466
+    0xE08F4534,  // "💪🏻" 1F4AA-1F3FB
+    0xC5792B80,  // "🗨️" 1F5E8-FE0F
+    0xE18F0392,  // "👉🏽" 1F449-1F3FD
+    0xC7853FEE,  // "👨🏽\u200d🎄" 1F468-1F3FD-200D-1F384
+    0xBB615B39,  // "💆🏽" 1F486-1F3FD
+    0xB9282784,  // "💁🏻" 1F481-1F3FB
+    0xD7FEDB3C,  // "🕵🏻\u200d♀️" 1F575-1F3FB-200D-2640-FE0F
+    0x9F9FA6DE,  // "👩🏿\u200d🦳" 1F469-1F3FF-200D-1F9B3
+    0xB09F229F,  // "👱🏽\u200d♂️" 1F471-1F3FD-200D-2642-FE0F
+    0xB7CA7991,  // "🏊🏼\u200d♀️" 1F3CA-1F3FC-200D-2640-FE0F
+    0x88F0FFBC,  // "👩🏽\u200d⚖️" 1F469-1F3FD-200D-2696-FE0F
+    0x9C548F81,  // "👨\u200d🎄" 1F468-200D-1F384
+    0x90E44334,  // "👷🏾" 1F477-1F3FE
+    0x83543212,  // "👩🏽\u200d🚀" 1F469-1F3FD-200D-1F680
+    0xF22D7E55,  // "👨\u200d❤️\u200d👨" 1F468-200D-2764-FE0F-200D-1F468
+    0xE0950214,  // "🏊🏾\u200d♀️" 1F3CA-1F3FE-200D-2640-FE0F
+    0xAC7DAACE,  // "🕛️" 1F55B-FE0F
+    0xCAF89C7A,  // "🏄🏾" 1F3C4-1F3FE
+    0xF8DAF791,  // "👨🏻\u200d💼" 1F468-1F3FB-200D-1F4BC
+    0xCB607051,  // "👩🏿\u200d🤝\u200d👩🏻" 1F469-1F3FF-200D-1F91D-200D-1F469-1F3FB
+    0xEADF8A09,  // "👮🏽\u200d♀️" 1F46E-1F3FD-200D-2640-FE0F
+    0x88F38E8B,  // "👩🏾\u200d🏫" 1F469-1F3FE-200D-1F3EB
+    0xD64EEA5B,  // "👩\u200d🔧" 1F469-200D-1F527
+    0xE7EF5AD1,  // "👌🏼" 1F44C-1F3FC
+    0x87DE6061,  // "👮🏻" 1F46E-1F3FB
+    0xF939D56A,  // "👵🏾" 1F475-1F3FE
+    0x9A9A2B3F,  // "💁🏼\u200d♀️" 1F481-1F3FC-200D-2640-FE0F
+    0xAE0C046E,  // "👩\u200d👧\u200d👧" 1F469-200D-1F467-200D-1F467
+    0xBAE30E33,  // "👩🏻\u200d🦱" 1F469-1F3FB-200D-1F9B1
+    0x81C1D4A4,  // "👨🏻\u200d🎤" 1F468-1F3FB-200D-1F3A4
+    0xFB639BE0,  // "👨🏿\u200d⚖️" 1F468-1F3FF-200D-2696-FE0F
+    0xA861B703,  // "👩🏿\u200d🦼" 1F469-1F3FF-200D-1F9BC
+    0xE21BE914,  // "💆🏻\u200d♀️" 1F486-1F3FB-200D-2640-FE0F
+    0x9DD22CE1,  // "👨🏻\u200d💻" 1F468-1F3FB-200D-1F4BB
+    0xAE898ED1,  // "🖐🏿" 1F590-1F3FF
+    0xCEBB2F32,  // "👩🏼\u200d⚖️" 1F469-1F3FC-200D-2696-FE0F
+    0xB08082AB,  // "👼🏻" 1F47C-1F3FB
+    0xEDE8423A,  // "🏃🏿\u200d♀️" 1F3C3-1F3FF-200D-2640-FE0F
+    0xDCA06880,  // "👮🏿\u200d♀️" 1F46E-1F3FF-200D-2640-FE0F
+    0xD3E64264,  // "👨🏼" 1F468-1F3FC
+    0xFFE8A78E,  // "👨🏼\u200d🍼" 1F468-1F3FC-200D-1F37C
+    0xB2608A9D,  // "🕥️" 1F565-FE0F
+    0xF93CBEC3,  // "🏊\u200d♀️" 1F3CA-200D-2640-FE0F
+    0xD02B0659,  // "👬🏾" 1F46C-1F3FE
+    0xA89160E8,  // "👩🏿\u200d🌾" 1F469-1F3FF-200D-1F33E
+    0xF0FEFBDD,  // "👩🏼\u200d🔬" 1F469-1F3FC-200D-1F52C
+    0xB25599A3,  // "👨\u200d🏫" 1F468-200D-1F3EB
+    0xA0316416,  // "💆🏾\u200d♀️" 1F486-1F3FE-200D-2640-FE0F
+    0xB7AF59BC,  // "👎🏿" 1F44E-1F3FF
+    0xCEDF6F7C,  // "👩🏻\u200d🤝\u200d👩🏿" 1F469-1F3FB-200D-1F91D-200D-1F469-1F3FF
+    0x84CEFE3F,  // "👧🏼" 1F467-1F3FC
+    0xEEA8DF93,  // "👨🏿\u200d🤝\u200d👨🏼" 1F468-1F3FF-200D-1F91D-200D-1F468-1F3FC
+    0xBA5C44C1,  // "🐕️" 1F415-FE0F
+    0x8C0C7757,  // "🕵🏾\u200d♀️" 1F575-1F3FE-200D-2640-FE0F
+    0xC1F75223,  // "👈🏾" 1F448-1F3FE
+    0xCEC587A3,  // "💁🏿\u200d♂️" 1F481-1F3FF-200D-2642-FE0F
+    0xCEA3BF1B,  // "🏳️" 1F3F3-FE0F
+    0xDB61422E,  // "🏋🏾\u200d♀️" 1F3CB-1F3FE-200D-2640-FE0F
+    0xEFFEBFEF,  // "👱🏼\u200d♀️" 1F471-1F3FC-200D-2640-FE0F
+    0xCEF3233D,  // "👨\u200d👧" 1F468-200D-1F467
+    0x999E2805,  // "💇\u200d♀️" 1F487-200D-2640-FE0F
+    0x8E3FC0B3,  // "👩🏽\u200d🚒" 1F469-1F3FD-200D-1F692
+    0xB7BF2C1A,  // "👷🏾\u200d♀️" 1F477-1F3FE-200D-2640-FE0F
+    0xC6C10588,  // "💂\u200d♂️" 1F482-200D-2642-FE0F
+    0xA883BC3E,  // "👩🏾\u200d🤝\u200d👩🏿" 1F469-1F3FE-200D-1F91D-200D-1F469-1F3FF
+    0x9B725020,  // "👨🏽\u200d🦰" 1F468-1F3FD-200D-1F9B0
+    0xEAFD627F,  // "👩🏿\u200d🔬" 1F469-1F3FF-200D-1F52C
+    0xFEB689C4,  // "👨🏼\u200d🦲" 1F468-1F3FC-200D-1F9B2
+    0xED8BEBAD,  // "👃🏼" 1F443-1F3FC
+    0xFA3CEBD5,  // "👆🏿" 1F446-1F3FF
+    0xB59CB23A,  // "👩🏾\u200d🎄" 1F469-1F3FE-200D-1F384
+    0xCDE375BF,  // "🏃🏻" 1F3C3-1F3FB
 ];
 
 /// Lookup table of blit pattern offsets; sort matches HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS
-const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
+static OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1011] = [
     47478, // "🕵️\u200d♀️" 1F575-FE0F-200D-2640-FE0F
-    46271, // "🕗"
     24136, // "👨🏼\u200d🤝\u200d👨🏿" 1F468-1F3FC-200D-1F91D-200D-1F468-1F3FF
-    42507, // "📇"
     46905, // "🕴🏼\u200d♀️" 1F574-1F3FC-200D-2640-FE0F
-    18735, // "🐛"
-    45368, // "🔫"
-    22222, // "👞"
-    17362, // "🏬"
     30253, // "👩🏽\u200d🎤" 1F469-1F3FD-200D-1F3A4
     12886, // "🎬️" 1F3AC-FE0F
     31839, // "👩🏾\u200d🦳" 1F469-1F3FE-200D-1F9B3
@@ -3762,67 +5870,38 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     46047, // "🕐️" 1F550-FE0F
     48717, // "🗝️" 1F5DD-FE0F
     20085, // "👃🏾" 1F443-1F3FE
-    9345, // "🌮"
     8459, // "🌎️" 1F30E-FE0F
     29345, // "👩🏼\u200d🎨" 1F469-1F3FC-200D-1F3A8
     41594, // "💪🏽" 1F4AA-1F3FD
     19863, // "👁️" 1F441-FE0F
     24338, // "👨🏼\u200d⚕️" 1F468-1F3FC-200D-2695-FE0F
-    12738, // "🎧"
-    13265, // "🎹"
     24710, // "👨🏽\u200d💻" 1F468-1F3FD-200D-1F4BB
-    8298, // "🌉"
     47651, // "🕺🏻" 1F57A-1F3FB
-    40939, // "💕"
     39881, // "💆🏿\u200d♀️" 1F486-1F3FF-200D-2640-FE0F
     48255, // "🖖🏽" 1F596-1F3FD
     16051, // "🏋️\u200d♂️" 1F3CB-FE0F-200D-2642-FE0F
     28180, // "👨\u200d❤️\u200d💋\u200d👨" 1F468-200D-2764-FE0F-200D-1F48B-200D-1F468
-    11960, // "🎆"
-    48050, // "🖐"
-    18914, // "🐡"
-    13356, // "🎼"
     36066, // "👱🏿\u200d♂️" 1F471-1F3FF-200D-2642-FE0F
     37036, // "👵🏿" 1F475-1F3FF
     26692, // "👨🏿\u200d🦯" 1F468-1F3FF-200D-1F9AF
     32544, // "👩🏿\u200d🤝\u200d👨🏾" 1F469-1F3FF-200D-1F91D-200D-1F468-1F3FE
     36141, // "👱\u200d♂️" 1F471-200D-2642-FE0F
-    17455, // "🏯"
-    42154, // "💻"
-    43018, // "📙"
-    46239, // "🕖"
     12394, // "🎙️" 1F399-FE0F
     33745, // "👩\u200d🦰" 1F469-200D-1F9B0
-    41799, // "💮"
     41338, // "💣️" 1F4A3-FE0F
-    42444, // "📅"
     43949, // "📺️" 1F4FA-FE0F
-    43078, // "📛"
-    12680, // "🎥"
     36090, // "👱🏿" 1F471-1F3FF
     31863, // "👩🏾\u200d🦼" 1F469-1F3FE-200D-1F9BC
     23137, // "👨🏻\u200d🔬" 1F468-1F3FB-200D-1F52C
-    11599, // "🍿"
     37516, // "👷🏾\u200d♂️" 1F477-1F3FE-200D-2642-FE0F
     28805, // "👩🏻\u200d🤝\u200d👩🏽" 1F469-1F3FB-200D-1F91D-200D-1F469-1F3FD
-    17962, // "🐀"
     36457, // "👳🏼" 1F473-1F3FC
     39084, // "💂🏿\u200d♀️" 1F482-1F3FF-200D-2640-FE0F
-    10409, // "🍔"
-    45894, // "🕊"
-    14595, // "🏄\u200d♀️" 1F3C4-200D-2640-FE0F
-    44255, // "🔅"
-    31649, // "👩🏾\u200d🤝\u200d👩🏼" 1F469-1F3FE-200D-1F91D-200D-1F469-1F3FC
-    44093, // "🔀"
-    45338, // "🔪"
-    43334, // "📤"
+    14595, // "🏄\u200d♀️" 1F3C4-200D-2640-FE0F
+    31649, // "👩🏾\u200d🤝\u200d👩🏼" 1F469-1F3FE-200D-1F91D-200D-1F469-1F3FC
     40215, // "💇🏽\u200d♀️" 1F487-1F3FD-200D-2640-FE0F
-    11419, // "🍹"
-    42338, // "📁"
     39463, // "💅🏽" 1F485-1F3FD
     14275, // "🏄🏼" 1F3C4-1F3FC
-    18351, // "🐎"
-    40820, // "💑"
     24949, // "👨🏽\u200d🤝\u200d👨🏾" 1F468-1F3FD-200D-1F91D-200D-1F468-1F3FE
     47424, // "🕵🏿\u200d♂️" 1F575-1F3FF-200D-2642-FE0F
     15710, // "🏋🏼" 1F3CB-1F3FC
@@ -3833,53 +5912,35 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     17703, // "🏵️" 1F3F5-FE0F
     29156, // "👩🏻" 1F469-1F3FB
     32770, // "👩🏿\u200d🦲" 1F469-1F3FF-200D-1F9B2
-    37065, // "👵"
-    20473, // "👇"
     23936, // "👨🏼\u200d🔧" 1F468-1F3FC-200D-1F527
     27304, // "👨\u200d👧\u200d👦" 1F468-200D-1F467-200D-1F466
-    11062, // "🍬"
     48748, // "🗞️" 1F5DE-FE0F
     39640, // "💆🏻" 1F486-1F3FB
     35796, // "👱🏻" 1F471-1F3FB
-    44308, // "🔇"
     18537, // "🐕\u200d🦺" 1F415-200D-1F9BA
     25349, // "👨🏾\u200d🍼" 1F468-1F3FE-200D-1F37C
-    8135, // "🌄"
     47669, // "🕺🏼" 1F57A-1F3FC
-    40911, // "💔"
     27782, // "👨\u200d🔧" 1F468-200D-1F527
     41565, // "💪🏼" 1F4AA-1F3FC
-    41771, // "💭"
     15896, // "🏋🏾" 1F3CB-1F3FE
     17606, // "🏴\U000e0067\U000e0062\U000e0065\U000e006e\U000e0067\U000e007f" 1F3F4-E0067-E0062-E0065-E006E-E0067-E007F
-    17020, // "🏡"
-    46207, // "🕕"
     29866, // "👩🏼\u200d🦰" 1F469-1F3FC-200D-1F9B0
     20822, // "👊🏽" 1F44A-1F3FD
     34836, // "👮🏽\u200d♂️" 1F46E-1F3FD-200D-2642-FE0F
-    8266, // "🌈"
     30152, // "👩🏽\u200d🍳" 1F469-1F3FD-200D-1F373
-    43625, // "📯"
     16221, // "🏌🏽\u200d♀️" 1F3CC-1F3FD-200D-2640-FE0F
     32893, // "👩🏿\u200d⚖️" 1F469-1F3FF-200D-2696-FE0F
-    13233, // "🎸"
     46303, // "🕘️" 1F558-FE0F
-    18678, // "🐙"
-    9365, // "🌯"
     24505, // "👨🏽\u200d🍼" 1F468-1F3FD-200D-1F37C
-    43838, // "📶"
     46495, // "🕞️" 1F55E-FE0F
-    13086, // "🎳"
     29022, // "👩🏻\u200d🦼" 1F469-1F3FB-200D-1F9BC
     29725, // "👩🏼\u200d🤝\u200d👩🏻" 1F469-1F3FC-200D-1F91D-200D-1F469-1F3FB
     25679, // "👨🏾\u200d🚀" 1F468-1F3FE-200D-1F680
     33006, // "👩\u200d🍳" 1F469-200D-1F373
     23223, // "👨🏻\u200d🤝\u200d👨🏼" 1F468-1F3FB-200D-1F91D-200D-1F468-1F3FC
     12377, // "🎗️" 1F397-FE0F
-    12799, // "🎩"
     46271, // "🕗️" 1F557-FE0F
     43520, // "📫️" 1F4EB-FE0F
-    10382, // "🍓"
     33346, // "👩\u200d👧" 1F469-200D-1F467
     25182, // "👨🏽\u200d⚕️" 1F468-1F3FD-200D-2695-FE0F
     39291, // "💃🏾" 1F483-1F3FE
@@ -3887,39 +5948,26 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     39320, // "💃🏿" 1F483-1F3FF
     29132, // "👩🏻\u200d✈️" 1F469-1F3FB-200D-2708-FE0F
     16468, // "🏎️" 1F3CE-FE0F
-    42987, // "📘"
-    9009, // "🌠"
     24027, // "👨🏼\u200d🚒" 1F468-1F3FC-200D-1F692
     31734, // "👩🏾\u200d🦯" 1F469-1F3FE-200D-1F9AF
     27095, // "👨\u200d🎓" 1F468-200D-1F393
     12476, // "🎞️" 1F39E-FE0F
     35566, // "👰🏿\u200d♀️" 1F470-1F3FF-200D-2640-FE0F
     26026, // "👨🏾\u200d⚕️" 1F468-1F3FE-200D-2695-FE0F
-    17849, // "🏻"
     31180, // "👩🏾\u200d🎓" 1F469-1F3FE-200D-1F393
     46079, // "🕑️" 1F551-FE0F
-    43226, // "📠"
     16869, // "🏜️" 1F3DC-FE0F
-    9153, // "🌧"
     30580, // "👩🏽\u200d🤝\u200d👨🏼" 1F469-1F3FD-200D-1F91D-200D-1F468-1F3FC
-    42096, // "💹"
-    16445, // "🏍"
-    11993, // "🎇"
     25554, // "👨🏾\u200d💻" 1F468-1F3FE-200D-1F4BB
     22446, // "👦🏻" 1F466-1F3FB
     25319, // "👨🏾\u200d🍳" 1F468-1F3FE-200D-1F373
     34041, // "👩\u200d❤️\u200d💋\u200d👨" 1F469-200D-2764-FE0F-200D-1F48B-200D-1F468
-    43687, // "📱"
-    37922, // "👺"
-    10712, // "🍟"
     19923, // "👂🏽" 1F442-1F3FD
     8491, // "🌏️" 1F30F-FE0F
     43545, // "📬️" 1F4EC-FE0F
-    10767, // "🍡"
     21326, // "👍🏾" 1F44D-1F3FE
     22946, // "👨🏻\u200d🎨" 1F468-1F3FB-200D-1F3A8
     30069, // "👩🏼\u200d✈️" 1F469-1F3FC-200D-2708-FE0F
-    12950, // "🎮"
     14499, // "🏄🏿\u200d♀️" 1F3C4-1F3FF-200D-2640-FE0F
     20778, // "👊🏻" 1F44A-1F3FB
     47532, // "🕵️" 1F575-FE0F
@@ -3927,67 +5975,39 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     11535, // "🍽️" 1F37D-FE0F
     32515, // "👩🏿\u200d🤝\u200d👨🏽" 1F469-1F3FF-200D-1F91D-200D-1F468-1F3FD
     34331, // "👬🏻" 1F46C-1F3FB
-    8076, // "🌂"
-    18883, // "🐠"
     35203, // "👰🏻\u200d♀️" 1F470-1F3FB-200D-2640-FE0F
     27687, // "👨\u200d👩\u200d👧" 1F468-200D-1F469-200D-1F467
     33773, // "👩\u200d🦱" 1F469-200D-1F9B1
-    17795, // "🏹"
     20517, // "👈🏼" 1F448-1F3FC
-    42364, // "📂"
     36865, // "👴🏿" 1F474-1F3FF
     41655, // "💪🏿" 1F4AA-1F3FF
-    42564, // "📉"
     32842, // "👩🏿\u200d🦽" 1F469-1F3FF-200D-1F9BD
     28094, // "👨\u200d⚖️" 1F468-200D-2696-FE0F
     39126, // "💂🏿" 1F482-1F3FF
     14816, // "🏇🏽" 1F3C7-1F3FD
     30283, // "👩🏽\u200d🎨" 1F469-1F3FD-200D-1F3A8
     48633, // "🗒️" 1F5D2-FE0F
-    40714, // "💍"
     16900, // "🏝️" 1F3DD-FE0F
     31973, // "👩🏾\u200d✈️" 1F469-1F3FE-200D-2708-FE0F
     24310, // "👨🏼\u200d🦽" 1F468-1F3FC-200D-1F9BD
-    16758, // "🏘"
     11867, // "🎅🏾" 1F385-1F3FE
-    13298, // "🎺"
-    34302, // "👫"
     29698, // "👩🏼\u200d🤝\u200d👨🏿" 1F469-1F3FC-200D-1F91D-200D-1F468-1F3FF
     29051, // "👩🏻\u200d🦽" 1F469-1F3FB-200D-1F9BD
     46591, // "🕡️" 1F561-FE0F
     26524, // "👨🏿\u200d🚀" 1F468-1F3FF-200D-1F680
-    18029, // "🐃"
-    44999, // "🔟"
-    18649, // "🐘"
-    9656, // "🌹"
     27655, // "👨\u200d👩\u200d👧\u200d👧" 1F468-200D-1F469-200D-1F467-200D-1F467
     34787, // "👮🏼" 1F46E-1F3FC
-    48551, // "🗃"
     37417, // "👷🏽\u200d♀️" 1F477-1F3FD-200D-2640-FE0F
-    10241, // "🍎"
     16665, // "🏕️" 1F3D5-FE0F
-    40735, // "💎"
-    44836, // "🔚"
-    22326, // "👢"
     37832, // "👸🏿" 1F478-1F3FF
-    10295, // "🍐"
-    46829, // "🕰"
     36265, // "👲🏾" 1F472-1F3FE
     25847, // "👨🏾\u200d🦯" 1F468-1F3FE-200D-1F9AF
-    13478, // "🏀"
-    18319, // "🐍"
-    48452, // "🖱"
-    41888, // "💱"
-    47886, // "🖍"
-    9393, // "🌰"
     24052, // "👨🏼\u200d🤝\u200d👨🏻" 1F468-1F3FC-200D-1F91D-200D-1F468-1F3FB
     41934, // "💳️" 1F4B3-FE0F
     39002, // "💂🏽" 1F482-1F3FD
     30910, // "👩🏽\u200d🦼" 1F469-1F3FD-200D-1F9BC
     28552, // "👩🏻\u200d🔧" 1F469-1F3FB-200D-1F527
-    9241, // "🌪"
     38751, // "💁\u200d♀️" 1F481-200D-2640-FE0F
-    48919, // "🗺"
     21409, // "👎🏻" 1F44E-1F3FB
     24648, // "👨🏽\u200d🏫" 1F468-1F3FD-200D-1F3EB
     28368, // "👩🏻\u200d🎤" 1F469-1F3FB-200D-1F3A4
@@ -3996,33 +6016,16 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     35505, // "👰🏾\u200d♂️" 1F470-1F3FE-200D-2642-FE0F
     34606, // "👭🏿" 1F46D-1F3FF
     16964, // "🏟️" 1F3DF-FE0F
-    40691, // "💌"
-    35718, // "👰"
-    11236, // "🍲"
     15246, // "🏊🏽" 1F3CA-1F3FD
     31484, // "👩🏾\u200d🚒" 1F469-1F3FE-200D-1F692
-    13417, // "🎾"
-    48183, // "🖕"
     26133, // "👨🏿\u200d🌾" 1F468-1F3FF-200D-1F33E
     37366, // "👷🏼\u200d♂️" 1F477-1F3FC-200D-2642-FE0F
     37210, // "👶🏿" 1F476-1F3FF
-    16605, // "🏓"
-    12041, // "🎉"
-    35173, // "👯"
-    34471, // "👬"
     23996, // "👨🏼\u200d🚀" 1F468-1F3FC-200D-1F680
-    11686, // "🎂"
-    41086, // "💚"
     20233, // "👆🏼" 1F446-1F3FC
-    21911, // "👒"
     32313, // "👩🏿\u200d💼" 1F469-1F3FF-200D-1F4BC
     25370, // "👨🏾\u200d🎄" 1F468-1F3FE-200D-1F384
-    40760, // "💏"
     17535, // "🏳️\u200d⚧️" 1F3F3-FE0F-200D-26A7-FE0F
-    8524, // "🌐"
-    45983, // "🕍"
-    43805, // "📵"
-    18568, // "🐕"
     43978, // "📻️" 1F4FB-FE0F
     40309, // "💇🏾\u200d♀️" 1F487-1F3FE-200D-2640-FE0F
     24920, // "👨🏽\u200d🤝\u200d👨🏼" 1F468-1F3FD-200D-1F91D-200D-1F468-1F3FC
@@ -4031,104 +6034,65 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     30692, // "👩🏽\u200d🤝\u200d👩🏼" 1F469-1F3FD-200D-1F91D-200D-1F469-1F3FC
     30438, // "👩🏽\u200d🔧" 1F469-1F3FD-200D-1F527
     16696, // "🏖️" 1F3D6-FE0F
-    8459, // "🌎"
     19779, // "🐿️" 1F43F-FE0F
     42617, // "📋️" 1F4CB-FE0F
-    19702, // "🐼"
-    22171, // "👜"
-    45031, // "🔠"
     30862, // "👩🏽\u200d🦲" 1F469-1F3FD-200D-1F9B2
     12330, // "🎓️" 1F393-FE0F
-    20330, // "👆"
     47134, // "🕵🏻" 1F575-1F3FB
-    49022, // "🗾"
-    45862, // "🕉"
     26720, // "👨🏿\u200d🦰" 1F468-1F3FF-200D-1F9B0
     21842, // "👐🏿" 1F450-1F3FF
     35061, // "👮\u200d♂️" 1F46E-200D-2642-FE0F
     14721, // "🏆️" 1F3C6-FE0F
-    44276, // "🔆"
     14848, // "🏇🏾" 1F3C7-1F3FE
     37717, // "👸🏻" 1F478-1F3FB
     14531, // "🏄🏿\u200d♂️" 1F3C4-1F3FF-200D-2642-FE0F
     35037, // "👮\u200d♀️" 1F46E-200D-2640-FE0F
     24616, // "👨🏽\u200d🎨" 1F468-1F3FD-200D-1F3A8
-    42391, // "📃"
-    10053, // "🍇"
     39234, // "💃🏼" 1F483-1F3FC
     47505, // "🕵️\u200d♂️" 1F575-FE0F-200D-2642-FE0F
     21011, // "👋🏾" 1F44B-1F3FE
     27495, // "👨\u200d👨\u200d👧\u200d👧" 1F468-200D-1F468-200D-1F467-200D-1F467
     13597, // "🏂🏽" 1F3C2-1F3FD
-    18293, // "🐌"
-    42066, // "💸"
-    44548, // "🔐"
-    8588, // "🌒"
     28426, // "👩🏻\u200d🏫" 1F469-1F3FB-200D-1F3EB
     27559, // "👨\u200d👩\u200d👦\u200d👦" 1F468-200D-1F469-200D-1F466-200D-1F466
     29614, // "👩🏼\u200d🤝\u200d👨🏻" 1F469-1F3FC-200D-1F91D-200D-1F468-1F3FB
-    11655, // "🎁"
-    11445, // "🍺"
     38840, // "💂🏻\u200d♀️" 1F482-1F3FB-200D-2640-FE0F
-    18057, // "🐄"
     25521, // "👨🏾\u200d🏭" 1F468-1F3FE-200D-1F3ED
-    22251, // "👟"
     15184, // "🏊🏽\u200d♀️" 1F3CA-1F3FD-200D-2640-FE0F
-    17141, // "🏥"
     14073, // "🏃\u200d♂️" 1F3C3-200D-2642-FE0F
     14006, // "🏃🏿\u200d♂️" 1F3C3-1F3FF-200D-2642-FE0F
     34130, // "👪️" 1F46A-FE0F
-    43922, // "📹"
     46655, // "🕣️" 1F563-FE0F
     27873, // "👨\u200d🚒" 1F468-200D-1F692
-    9183, // "🌨"
-    43283, // "📢"
-    8681, // "🌕"
     31071, // "👩🏾\u200d🌾" 1F469-1F3FE-200D-1F33E
-    9510, // "🌴"
     37441, // "👷🏽\u200d♂️" 1F477-1F3FD-200D-2642-FE0F
-    8361, // "🌋"
     16113, // "🏌🏻\u200d♀️" 1F3CC-1F3FB-200D-2640-FE0F
-    22592, // "👦"
     31621, // "👩🏾\u200d🤝\u200d👩🏻" 1F469-1F3FE-200D-1F91D-200D-1F469-1F3FB
     47057, // "🕴️\u200d♂️" 1F574-FE0F-200D-2642-FE0F
-    48778, // "🗡"
     33717, // "👩\u200d🦯" 1F469-200D-1F9AF
-    16696, // "🏖"
     24978, // "👨🏽\u200d🤝\u200d👨🏿" 1F468-1F3FD-200D-1F91D-200D-1F468-1F3FF
     25084, // "👨🏽\u200d🦲" 1F468-1F3FD-200D-1F9B2
-    43110, // "📜"
-    19811, // "👀"
     37746, // "👸🏼" 1F478-1F3FC
     36345, // "👳🏻\u200d♀️" 1F473-1F3FB-200D-2640-FE0F
     29265, // "👩🏼\u200d🎄" 1F469-1F3FC-200D-1F384
     47943, // "🖐🏼" 1F590-1F3FC
     29782, // "👩🏼\u200d🤝\u200d👩🏾" 1F469-1F3FC-200D-1F91D-200D-1F469-1F3FE
-    10322, // "🍑"
     46111, // "🕒️" 1F552-FE0F
     22889, // "👨🏻\u200d🎓" 1F468-1F3FB-200D-1F393
     37294, // "👷🏻\u200d♂️" 1F477-1F3FB-200D-2642-FE0F
-    43493, // "📪"
-    19371, // "🐲"
     37803, // "👸🏾" 1F478-1F3FE
-    42901, // "📕"
     9183, // "🌨️" 1F328-FE0F
     20473, // "👇️" 1F447-FE0F
     35323, // "👰🏼\u200d♂️" 1F470-1F3FC-200D-2642-FE0F
     19643, // "🐻\u200d❄️" 1F43B-200D-2744-FE0F
-    12265, // "🎑"
     20845, // "👊🏾" 1F44A-1F3FE
-    20163, // "👄"
     32133, // "👩🏿\u200d🎓" 1F469-1F3FF-200D-1F393
     39930, // "💆🏿" 1F486-1F3FF
     23009, // "👨🏻\u200d🏭" 1F468-1F3FB-200D-1F3ED
     17394, // "🏭️" 1F3ED-FE0F
     31132, // "👩🏾\u200d🍼" 1F469-1F3FE-200D-1F37C
     36951, // "👵🏼" 1F475-1F3FC
-    17558, // "🏳"
-    8045, // "🌁"
     27121, // "👨\u200d🎤" 1F468-200D-1F3A4
-    9455, // "🌲"
     48229, // "🖖🏼" 1F596-1F3FC
     25584, // "👨🏾\u200d💼" 1F468-1F3FE-200D-1F4BC
     20447, // "👇🏿" 1F447-1F3FF
@@ -4141,157 +6105,85 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     32219, // "👩🏿\u200d🏫" 1F469-1F3FF-200D-1F3EB
     30607, // "👩🏽\u200d🤝\u200d👨🏾" 1F469-1F3FD-200D-1F91D-200D-1F468-1F3FE
     11396, // "🍸️" 1F378-FE0F
-    43048, // "📚"
     46894, // "🕴🏻" 1F574-1F3FB
-    17203, // "🏧"
-    44580, // "🔑"
     9101, // "🌥️" 1F325-FE0F
-    18979, // "🐤"
     9567, // "🌶️" 1F336-FE0F
-    43398, // "📦"
     23384, // "👨🏻\u200d🦱" 1F468-1F3FB-200D-1F9B1
-    43741, // "📳"
     26493, // "👨🏿\u200d🔬" 1F468-1F3FF-200D-1F52C
-    18597, // "🐖"
-    9481, // "🌳"
     21494, // "👎🏾" 1F44E-1F3FE
     22724, // "👧🏿" 1F467-1F3FF
     30092, // "👩🏼" 1F469-1F3FC
     25764, // "👨🏾\u200d🤝\u200d👨🏼" 1F468-1F3FE-200D-1F91D-200D-1F468-1F3FC
-    46751, // "🕦"
     15370, // "🏊🏿\u200d♀️" 1F3CA-1F3FF-200D-2640-FE0F
     48778, // "🗡️" 1F5E1-FE0F
     40277, // "💇🏽" 1F487-1F3FD
-    12886, // "🎬"
-    13144, // "🎵"
     37180, // "👶🏾" 1F476-1F3FE
     25400, // "👨🏾\u200d🎓" 1F468-1F3FE-200D-1F393
-    45420, // "🔭"
     33663, // "👩\u200d🚀" 1F469-200D-1F680
     27274, // "👨\u200d👦" 1F468-200D-1F466
-    19863, // "👁"
     32188, // "👩🏿\u200d🎨" 1F469-1F3FF-200D-1F3A8
     46751, // "🕦️" 1F566-FE0F
     46239, // "🕖️" 1F556-FE0F
     48076, // "🖕🏻" 1F595-1F3FB
     20800, // "👊🏼" 1F44A-1F3FC
-    10519, // "🍘"
     23167, // "👨🏻\u200d🚀" 1F468-1F3FB-200D-1F680
-    45659, // "🔵"
-    38810, // "💁"
-    12101, // "🎋"
-    45193, // "🔥"
-    42245, // "💾"
-    44518, // "🔏"
-    18796, // "🐝"
-    19169, // "🐫"
-    43573, // "📭"
     37120, // "👶🏼" 1F476-1F3FC
-    45450, // "🔮"
-    8394, // "🌌"
-    46014, // "🕎"
-    12187, // "🎎"
     33475, // "👩\u200d👩\u200d👧\u200d👧" 1F469-200D-1F469-200D-1F467-200D-1F467
-    41028, // "💘"
-    19464, // "🐵"
     47794, // "🖊️" 1F58A-FE0F
-    10491, // "🍗"
     38498, // "💁🏽\u200d♂️" 1F481-1F3FD-200D-2642-FE0F
     27751, // "👨\u200d💼" 1F468-200D-1F4BC
     16426, // "🏌️" 1F3CC-FE0F
     25947, // "👨🏾\u200d🦳" 1F468-1F3FE-200D-1F9B3
     30884, // "👩🏽\u200d🦳" 1F469-1F3FD-200D-1F9B3
     33165, // "👩\u200d🏫" 1F469-200D-1F3EB
-    17110, // "🏤"
-    19779, // "🐿"
-    48338, // "🖖"
     37567, // "👷🏿\u200d♀️" 1F477-1F3FF-200D-2640-FE0F
-    8745, // "🌗"
     29809, // "👩🏼\u200d🤝\u200d👩🏿" 1F469-1F3FC-200D-1F91D-200D-1F469-1F3FF
-    14943, // "🏈"
     31018, // "👩🏽\u200d✈️" 1F469-1F3FD-200D-2708-FE0F
-    16577, // "🏒"
     30375, // "👩🏽\u200d💻" 1F469-1F3FD-200D-1F4BB
     28318, // "👩🏻\u200d🎄" 1F469-1F3FB-200D-1F384
-    45128, // "🔣"
     34244, // "👫🏾" 1F46B-1F3FE
     48809, // "🗣️" 1F5E3-FE0F
     33924, // "👩\u200d⚖️" 1F469-200D-2696-FE0F
-    8869, // "🌛"
-    43708, // "📲"
-    48943, // "🗻"
-    40666, // "💋"
     33976, // "👩\u200d❤️\u200d👨" 1F469-200D-2764-FE0F-200D-1F468
     24362, // "👨🏼\u200d⚖️" 1F468-1F3FC-200D-2696-FE0F
     48919, // "🗺️" 1F5FA-FE0F
     38530, // "💁🏽" 1F481-1F3FD
     14753, // "🏇🏻" 1F3C7-1F3FB
     46855, // "🕳️" 1F573-FE0F
-    8331, // "🌊"
     39023, // "💂🏾\u200d♀️" 1F482-1F3FE-200D-2640-FE0F
     14050, // "🏃\u200d♀️" 1F3C3-200D-2640-FE0F
     47559, // "🕶️" 1F576-FE0F
     25457, // "👨🏾\u200d🎨" 1F468-1F3FE-200D-1F3A8
-    17173, // "🏦"
-    45773, // "🔺"
     23905, // "👨🏼\u200d💼" 1F468-1F3FC-200D-1F4BC
-    14691, // "🏅"
     39262, // "💃🏽" 1F483-1F3FD
     34188, // "👫🏼" 1F46B-1F3FC
     33378, // "👩\u200d👩\u200d👦\u200d👦" 1F469-200D-1F469-200D-1F466-200D-1F466
     47597, // "🕸️" 1F578-FE0F
-    39378, // "💄"
-    9808, // "🌿"
     43048, // "📚️" 1F4DA-FE0F
-    8945, // "🌞"
-    10904, // "🍦"
     16932, // "🏞️" 1F3DE-FE0F
     20754, // "👉️" 1F449-FE0F
-    9786, // "🌾"
     43334, // "📤️" 1F4E4-FE0F
     19970, // "👂🏿" 1F442-1F3FF
     13893, // "🏃🏽" 1F3C3-1F3FD
     30468, // "👩🏽\u200d🔬" 1F469-1F3FD-200D-1F52C
     45862, // "🕉️" 1F549-FE0F
-    44223, // "🔄"
     32657, // "👩🏿\u200d🤝\u200d👩🏾" 1F469-1F3FF-200D-1F91D-200D-1F469-1F3FE
     33539, // "👩\u200d💻" 1F469-200D-1F4BB
-    16869, // "🏜"
-    34130, // "👪"
     23843, // "👨🏼\u200d🏭" 1F468-1F3FC-200D-1F3ED
-    46559, // "🕠"
-    19494, // "🐶"
-    41985, // "💵"
-    11293, // "🍴"
     47708, // "🕺🏾" 1F57A-1F3FE
-    21941, // "👔"
     32405, // "👩🏿\u200d🚀" 1F469-1F3FF-200D-1F680
     25898, // "👨🏾\u200d🦱" 1F468-1F3FE-200D-1F9B1
     27842, // "👨\u200d🚀" 1F468-200D-1F680
     18859, // "🐟️" 1F41F-FE0F
-    10920, // "🍧"
     29079, // "👩🏻\u200d⚕️" 1F469-1F3FB-200D-2695-FE0F
-    44334, // "🔈"
-    19288, // "🐯"
     34686, // "👮🏻\u200d♂️" 1F46E-1F3FB-200D-2642-FE0F
     47045, // "🕴️\u200d♀️" 1F574-FE0F-200D-2640-FE0F
     27463, // "👨\u200d👨\u200d👧\u200d👦" 1F468-200D-1F468-200D-1F467-200D-1F466
     32433, // "👩🏿\u200d🚒" 1F469-1F3FF-200D-1F692
     27719, // "👨\u200d💻" 1F468-200D-1F4BB
-    44657, // "🔔"
     21642, // "👏🏽" 1F44F-1F3FD
-    46079, // "🕑"
-    20754, // "👉"
-    46527, // "🕟"
-    43445, // "📨"
-    18103, // "🐆"
     25127, // "👨🏽\u200d🦼" 1F468-1F3FD-200D-1F9BC
-    17267, // "🏩"
-    28209, // "👨"
-    11314, // "🍵"
-    42419, // "📄"
     16148, // "🏌🏻" 1F3CC-1F3FB
-    8556, // "🌑"
     39399, // "💅🏻" 1F485-1F3FB
     25971, // "👨🏾\u200d🦼" 1F468-1F3FE-200D-1F9BC
     48117, // "🖕🏽" 1F595-1F3FD
@@ -4300,113 +6192,70 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     25616, // "👨🏾\u200d🔧" 1F468-1F3FE-200D-1F527
     19993, // "👂️" 1F442-FE0F
     27431, // "👨\u200d👨\u200d👦" 1F468-200D-1F468-200D-1F466
-    10217, // "🍍"
     47161, // "🕵🏼\u200d♀️" 1F575-1F3FC-200D-2640-FE0F
     26335, // "👨🏿\u200d🏫" 1F468-1F3FF-200D-1F3EB
-    43140, // "📝"
-    46783, // "🕧"
-    21928, // "👓"
     9268, // "🌫️" 1F32B-FE0F
     37343, // "👷🏼\u200d♀️" 1F477-1F3FC-200D-2640-FE0F
     27527, // "👨\u200d👨\u200d👧" 1F468-200D-1F468-200D-1F467
     28488, // "👩🏻\u200d💻" 1F469-1F3FB-200D-1F4BB
     27245, // "👨\u200d👦\u200d👦" 1F468-200D-1F466-200D-1F466
-    44158, // "🔂"
     35892, // "👱🏽\u200d♀️" 1F471-1F3FD-200D-2640-FE0F
-    9213, // "🌩"
     34550, // "👭🏽" 1F46D-1F3FD
     23966, // "👨🏼\u200d🔬" 1F468-1F3FC-200D-1F52C
-    45250, // "🔧"
-    9625, // "🌸"
     25925, // "👨🏾\u200d🦲" 1F468-1F3FE-200D-1F9B2
     23562, // "👨🏻\u200d✈️" 1F468-1F3FB-200D-2708-FE0F
     12418, // "🎚️" 1F39A-FE0F
-    42179, // "💼"
     21768, // "👐🏻" 1F450-1F3FB
-    14721, // "🏆"
-    43202, // "📟"
-    45309, // "🔩"
-    47623, // "🕹"
-    39352, // "💃"
-    18125, // "🐇"
     30203, // "👩🏽\u200d🎄" 1F469-1F3FD-200D-1F384
     31565, // "👩🏾\u200d🤝\u200d👨🏽" 1F469-1F3FE-200D-1F91D-200D-1F468-1F3FD
     36413, // "👳🏼\u200d♀️" 1F473-1F3FC-200D-2640-FE0F
     38941, // "💂🏼" 1F482-1F3FC
-    18216, // "🐉"
     20353, // "👇🏻" 1F447-1F3FB
     36707, // "👳\u200d♂️" 1F473-200D-2642-FE0F
-    9319, // "🌭"
     20256, // "👆🏽" 1F446-1F3FD
     23782, // "👨🏼\u200d🎨" 1F468-1F3FC-200D-1F3A8
     30119, // "👩🏽\u200d🌾" 1F469-1F3FD-200D-1F33E
     9213, // "🌩️" 1F329-FE0F
     14115, // "🏄🏻\u200d♀️" 1F3C4-1F3FB-200D-2640-FE0F
-    40883, // "💓"
     27044, // "👨\u200d🍼" 1F468-200D-1F37C
-    8427, // "🌍"
-    9676, // "🌺"
     25649, // "👨🏾\u200d🔬" 1F468-1F3FE-200D-1F52C
     47108, // "🕵🏻\u200d♂️" 1F575-1F3FB-200D-2642-FE0F
     34577, // "👭🏾" 1F46D-1F3FE
-    48867, // "🗯"
-    41934, // "💳"
     39495, // "💅🏾" 1F485-1F3FE
     22838, // "👨🏻\u200d🍼" 1F468-1F3FB-200D-1F37C
-    12529, // "🎠"
     46917, // "🕴🏼\u200d♂️" 1F574-1F3FC-200D-2642-FE0F
     28643, // "👩🏻\u200d🚒" 1F469-1F3FB-200D-1F692
     28397, // "👩🏻\u200d🎨" 1F469-1F3FB-200D-1F3A8
     34861, // "👮🏽" 1F46E-1F3FD
     21355, // "👍🏿" 1F44D-1F3FF
     29245, // "👩🏼\u200d🍼" 1F469-1F3FC-200D-1F37C
-    47570, // "🕷"
     32945, // "👩🏿" 1F469-1F3FF
-    8168, // "🌅"
     26981, // "👨\u200d🌾" 1F468-200D-1F33E
     33802, // "👩\u200d🦲" 1F469-200D-1F9B2
     21551, // "👎️" 1F44E-FE0F
-    11205, // "🍱"
-    17894, // "🏽"
     14179, // "🏄🏻" 1F3C4-1F3FB
     26306, // "👨🏿\u200d🎨" 1F468-1F3FF-200D-1F3A8
     32973, // "👩\u200d🌾" 1F469-200D-1F33E
     33953, // "👩\u200d✈️" 1F469-200D-2708-FE0F
-    41828, // "💯"
     36503, // "👳🏽\u200d♂️" 1F473-1F3FD-200D-2642-FE0F
-    41057, // "💙"
-    40852, // "💒"
     38101, // "👼🏿" 1F47C-1F3FF
     16257, // "🏌🏽" 1F3CC-1F3FD
-    8621, // "🌓"
     36040, // "👱🏿\u200d♀️" 1F471-1F3FF-200D-2640-FE0F
-    19258, // "🐮"
     15617, // "🏋🏻" 1F3CB-1F3FB
-    45721, // "🔷"
     28995, // "👩🏻\u200d🦳" 1F469-1F3FB-200D-1F9B3
     16349, // "🏌🏿\u200d♂️" 1F3CC-1F3FF-200D-2642-FE0F
     28917, // "👩🏻\u200d🦰" 1F469-1F3FB-200D-1F9B0
-    44374, // "🔊"
     28342, // "👩🏻\u200d🎓" 1F469-1F3FB-200D-1F393
     35112, // "👯\u200d♀️" 1F46F-200D-2640-FE0F
-    42617, // "📋"
     39208, // "💃🏻" 1F483-1F3FB
     15865, // "🏋🏾\u200d♂️" 1F3CB-1F3FE-200D-2642-FE0F
-    42717, // "📏"
     32685, // "👩🏿\u200d🦯" 1F469-1F3FF-200D-1F9AF
     48867, // "🗯️" 1F5EF-FE0F
     39617, // "💆🏻\u200d♂️" 1F486-1F3FB-200D-2642-FE0F
-    40788, // "💐"
     46783, // "🕧️" 1F567-FE0F
-    36729, // "👳"
     31997, // "👩🏾" 1F469-1F3FE
     16388, // "🏌️\u200d♀️" 1F3CC-FE0F-200D-2640-FE0F
-    9898, // "🍂"
-    48838, // "🗨"
-    41115, // "💛"
     36367, // "👳🏻\u200d♂️" 1F473-1F3FB-200D-2642-FE0F
-    16487, // "🏏"
-    16993, // "🏠"
     20330, // "👆️" 1F446-FE0F
     9298, // "🌬️" 1F32C-FE0F
     39063, // "💂🏾" 1F482-1F3FE
@@ -4416,50 +6265,34 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     8427, // "🌍️" 1F30D-FE0F
     25709, // "👨🏾\u200d🚒" 1F468-1F3FE-200D-1F692
     21106, // "👌🏻" 1F44C-1F3FB
-    43657, // "📰"
     28975, // "👩🏻\u200d🦲" 1F469-1F3FB-200D-1F9B2
-    8777, // "🌘"
-    41396, // "💥"
     25236, // "👨🏽\u200d✈️" 1F468-1F3FD-200D-2708-FE0F
     31206, // "👩🏾\u200d🎤" 1F469-1F3FE-200D-1F3A4
-    18081, // "🐅"
     33227, // "👩\u200d👦\u200d👦" 1F469-200D-1F466-200D-1F466
     31592, // "👩🏾\u200d🤝\u200d👨🏿" 1F469-1F3FE-200D-1F91D-200D-1F468-1F3FF
     48140, // "🖕🏾" 1F595-1F3FE
     23429, // "👨🏻\u200d🦳" 1F468-1F3FB-200D-1F9B3
     24443, // "👨🏽\u200d🌾" 1F468-1F3FD-200D-1F33E
-    22281, // "👠"
-    44612, // "🔒"
     46687, // "🕤️" 1F564-FE0F
     34737, // "👮🏼\u200d♀️" 1F46E-1F3FC-200D-2640-FE0F
-    38192, // "👾"
-    9567, // "🌶"
     31361, // "👩🏾\u200d💼" 1F469-1F3FE-200D-1F4BC
     36836, // "👴🏾" 1F474-1F3FE
     47240, // "🕵🏽\u200d♀️" 1F575-1F3FD-200D-2640-FE0F
     26580, // "👨🏿\u200d🤝\u200d👨🏻" 1F468-1F3FF-200D-1F91D-200D-1F468-1F3FB
-    17330, // "🏫"
     15989, // "🏋🏿" 1F3CB-1F3FF
     23693, // "👨🏼\u200d🎄" 1F468-1F3FC-200D-1F384
     46175, // "🕔️" 1F554-FE0F
     40184, // "💇🏼" 1F487-1F3FC
     47213, // "🕵🏼" 1F575-1F3FC
-    16841, // "🏛"
     23362, // "👨🏻\u200d🦰" 1F468-1F3FB-200D-1F9B0
-    46655, // "🕣"
     31298, // "👩🏾\u200d🏭" 1F469-1F3FE-200D-1F3ED
-    19555, // "🐸"
-    44351, // "🔉"
     22807, // "👨🏻\u200d🍳" 1F468-1F3FB-200D-1F373
     23810, // "👨🏼\u200d🏫" 1F468-1F3FC-200D-1F3EB
     27947, // "👨\u200d🦱" 1F468-200D-1F9B1
     48425, // "🖨️" 1F5A8-FE0F
-    41859, // "💰"
-    21551, // "👎"
     34986, // "👮🏿\u200d♂️" 1F46E-1F3FF-200D-2642-FE0F
     34498, // "👭🏻" 1F46D-1F3FB
     32922, // "👩🏿\u200d✈️" 1F469-1F3FF-200D-2708-FE0F
-    39188, // "💂"
     20112, // "👃🏿" 1F443-1F3FF
     39855, // "💆🏾" 1F486-1F3FE
     34761, // "👮🏼\u200d♂️" 1F46E-1F3FC-200D-2642-FE0F
@@ -4467,10 +6300,7 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     47766, // "🖇️" 1F587-FE0F
     38981, // "💂🏽\u200d♂️" 1F482-1F3FD-200D-2642-FE0F
     20730, // "👉🏿" 1F449-1F3FF
-    38254, // "💀"
-    18246, // "🐊"
     35748, // "👱🏻\u200d♀️" 1F471-1F3FB-200D-2640-FE0F
-    46431, // "🕜"
     31538, // "👩🏾\u200d🤝\u200d👨🏼" 1F469-1F3FE-200D-1F91D-200D-1F468-1F3FC
     32282, // "👩🏿\u200d💻" 1F469-1F3FF-200D-1F4BB
     25489, // "👨🏾\u200d🏫" 1F468-1F3FE-200D-1F3EB
@@ -4481,7 +6311,6 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     27013, // "👨\u200d🍳" 1F468-200D-1F373
     37666, // "👷\u200d♂️" 1F477-200D-2642-FE0F
     47824, // "🖋️" 1F58B-FE0F
-    16900, // "🏝"
     46987, // "🕴🏾\u200d♂️" 1F574-1F3FE-200D-2642-FE0F
     38859, // "💂🏻\u200d♂️" 1F482-1F3FB-200D-2642-FE0F
     15401, // "🏊🏿\u200d♂️" 1F3CA-1F3FF-200D-2642-FE0F
@@ -4489,169 +6318,104 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     32740, // "👩🏿\u200d🦱" 1F469-1F3FF-200D-1F9B1
     36292, // "👲🏿" 1F472-1F3FF
     35143, // "👯\u200d♂️" 1F46F-200D-2642-FE0F
-    47559, // "🕶"
-    12558, // "🎡"
-    45562, // "🔲"
-    41231, // "💟"
     16818, // "🏚️" 1F3DA-FE0F
-    19111, // "🐩"
     37642, // "👷\u200d♀️" 1F477-200D-2640-FE0F
-    20612, // "👈"
-    17078, // "🏣"
     21200, // "👌🏿" 1F44C-1F3FF
     8892, // "🌜️" 1F31C-FE0F
-    8809, // "🌙"
-    17485, // "🏰"
     31043, // "👩🏽" 1F469-1F3FD
     31892, // "👩🏾\u200d🦽" 1F469-1F3FE-200D-1F9BD
     27812, // "👨\u200d🔬" 1F468-200D-1F52C
-    45064, // "🔡"
     22859, // "👨🏻\u200d🎄" 1F468-1F3FB-200D-1F384
     36593, // "👳🏾" 1F473-1F3FE
-    17916, // "🏾"
-    41294, // "💡"
-    36895, // "👴"
-    9706, // "🌻"
-    16519, // "🏐"
-    40605, // "💉"
     46815, // "🕯️" 1F56F-FE0F
     26634, // "👨🏿\u200d🤝\u200d👨🏽" 1F468-1F3FF-200D-1F91D-200D-1F468-1F3FD
     30182, // "👩🏽\u200d🍼" 1F469-1F3FD-200D-1F37C
-    43520, // "📫"
     30018, // "👩🏼\u200d⚕️" 1F469-1F3FC-200D-2695-FE0F
-    48809, // "🗣"
     38656, // "💁🏿\u200d♀️" 1F481-1F3FF-200D-2640-FE0F
     23198, // "👨🏻\u200d🚒" 1F468-1F3FB-200D-1F692
-    12217, // "🎏"
     20538, // "👈🏽" 1F448-1F3FD
-    15524, // "🏊"
-    39560, // "💅"
-    19432, // "🐴"
     13565, // "🏂🏼" 1F3C2-1F3FC
     46940, // "🕴🏽\u200d♀️" 1F574-1F3FD-200D-2640-FE0F
-    8491, // "🌏"
     46999, // "🕴🏾" 1F574-1F3FE
     38720, // "💁🏿" 1F481-1F3FF
     26275, // "👨🏿\u200d🎤" 1F468-1F3FF-200D-1F3A4
-    40004, // "💆"
     32486, // "👩🏿\u200d🤝\u200d👨🏼" 1F469-1F3FF-200D-1F91D-200D-1F468-1F3FC
     16369, // "🏌🏿" 1F3CC-1F3FF
     25261, // "👨🏽" 1F468-1F3FD
-    45830, // "🔽"
     29963, // "👩🏼\u200d🦼" 1F469-1F3FC-200D-1F9BC
     12444, // "🎛️" 1F39B-FE0F
     41624, // "💪🏾" 1F4AA-1F3FE
     35011, // "👮🏿" 1F46E-1F3FF
-    22088, // "👙"
-    12129, // "🎌"
     15524, // "🏊️" 1F3CA-FE0F
     39688, // "💆🏼\u200d♂️" 1F486-1F3FC-200D-2642-FE0F
-    9425, // "🌱"
     20636, // "👉🏻" 1F449-1F3FB
     13535, // "🏂🏻" 1F3C2-1F3FB
-    9866, // "🍁"
     27336, // "👨\u200d👧\u200d👧" 1F468-200D-1F467-200D-1F467
-    12298, // "🎒"
     24805, // "👨🏽\u200d🔬" 1F468-1F3FD-200D-1F52C
-    42776, // "📑"
     15586, // "🏋🏻\u200d♂️" 1F3CB-1F3FB-200D-2642-FE0F
-    10108, // "🍉"
     38437, // "💁🏼" 1F481-1F3FC
-    42675, // "📍"
     12738, // "🎧️" 1F3A7-FE0F
     35536, // "👰🏾" 1F470-1F3FE
-    17424, // "🏮"
-    14974, // "🏉"
     33106, // "👩\u200d🎤" 1F469-200D-1F3A4
     44334, // "🔈️" 1F508-FE0F
     24868, // "👨🏽\u200d🚒" 1F468-1F3FD-200D-1F692
     48526, // "🗂️" 1F5C2-FE0F
     14627, // "🏄\u200d♂️" 1F3C4-200D-2642-FE0F
-    22751, // "👧"
     40028, // "💇🏻\u200d♀️" 1F487-1F3FB-200D-2640-FE0F
-    19345, // "🐱"
     47069, // "🕴️" 1F574-FE0F
     38880, // "💂🏻" 1F482-1F3FB
     24773, // "👨🏽\u200d🔧" 1F468-1F3FD-200D-1F527
     23643, // "👨🏼\u200d🍳" 1F468-1F3FC-200D-1F373
-    46143, // "🕓"
     21300, // "👍🏽" 1F44D-1F3FD
     48162, // "🖕🏿" 1F595-1F3FF
-    46687, // "🕤"
     16841, // "🏛️" 1F3DB-FE0F
     16295, // "🏌🏾\u200d♂️" 1F3CC-1F3FE-200D-2642-FE0F
     15493, // "🏊\u200d♂️" 1F3CA-200D-2642-FE0F
     29919, // "👩🏼\u200d🦲" 1F469-1F3FC-200D-1F9B2
-    9298, // "🌬"
     23307, // "👨🏻\u200d🤝\u200d👨🏿" 1F468-1F3FB-200D-1F91D-200D-1F468-1F3FF
-    40060, // "💇🏻\u200d♂️" 1F487-1F3FB-200D-2642-FE0F
-    13689, // "🏂"
-    46335, // "🕙"
+    40060, // "💇🏻\u200d♂️" 1F487-1F3FB-200D-2642-FE0F
     39431, // "💅🏼" 1F485-1F3FC
     31818, // "👩🏾\u200d🦲" 1F469-1F3FE-200D-1F9B2
-    42476, // "📆"
     28723, // "👩🏻\u200d🤝\u200d👨🏾" 1F469-1F3FB-200D-1F91D-200D-1F468-1F3FE
     17653, // "🏴\U000e0067\U000e0062\U000e0077\U000e006c\U000e0073\U000e007f" 1F3F4-E0067-E0062-E0077-E006C-E0073-E007F
     46207, // "🕕️" 1F555-FE0F
     27399, // "👨\u200d👨\u200d👦\u200d👦" 1F468-200D-1F468-200D-1F466-200D-1F466
     39758, // "💆🏽\u200d♂️" 1F486-1F3FD-200D-2642-FE0F
     38281, // "💁🏻\u200d♀️" 1F481-1F3FB-200D-2640-FE0F
-    44966, // "🔞"
     18155, // "🐈\u200d⬛" 1F408-200D-2B1B
     27623, // "👨\u200d👩\u200d👧\u200d👦" 1F468-200D-1F469-200D-1F467-200D-1F466
     32459, // "👩🏿\u200d🤝\u200d👨🏻" 1F469-1F3FF-200D-1F91D-200D-1F468-1F3FB
     17734, // "🏷️" 1F3F7-FE0F
-    10083, // "🍈"
     14307, // "🏄🏽\u200d♀️" 1F3C4-1F3FD-200D-2640-FE0F
     36617, // "👳🏿\u200d♀️" 1F473-1F3FF-200D-2640-FE0F
     25998, // "👨🏾\u200d🦽" 1F468-1F3FE-200D-1F9BD
-    22387, // "👤"
     32712, // "👩🏿\u200d🦰" 1F469-1F3FF-200D-1F9B0
     14147, // "🏄🏻\u200d♂️" 1F3C4-1F3FB-200D-2642-FE0F
-    17703, // "🏵"
     36781, // "👴🏼" 1F474-1F3FC
-    42870, // "📔"
-    42538, // "📈"
     12918, // "🎭️" 1F3AD-FE0F
-    12706, // "🎦"
-    42212, // "💽"
-    37891, // "👹"
-    41686, // "💪"
     15004, // "🏊🏻\u200d♀️" 1F3CA-1F3FB-200D-2640-FE0F
-    42310, // "📀"
     39146, // "💂\u200d♀️" 1F482-200D-2640-FE0F
     46527, // "🕟️" 1F55F-FE0F
-    46463, // "🕝"
     20706, // "👉🏾" 1F449-1F3FE
     14563, // "🏄🏿" 1F3C4-1F3FF
-    43170, // "📞"
-    12974, // "🎯"
     38561, // "💁🏾\u200d♀️" 1F481-1F3FE-200D-2640-FE0F
     35414, // "👰🏽\u200d♂️" 1F470-1F3FD-200D-2642-FE0F
     20660, // "👉🏼" 1F449-1F3FC
     46929, // "🕴🏼" 1F574-1F3FC
     33442, // "👩\u200d👩\u200d👧\u200d👦" 1F469-200D-1F469-200D-1F467-200D-1F466
-    20186, // "👅"
     25007, // "👨🏽\u200d🦯" 1F468-1F3FD-200D-1F9AF
-    10549, // "🍙"
-    41476, // "💨"
     17511, // "🏳️\u200d🌈" 1F3F3-FE0F-200D-1F308
     40436, // "💇🏿\u200d♂️" 1F487-1F3FF-200D-2642-FE0F
     14785, // "🏇🏼" 1F3C7-1F3FC
-    10128, // "🍊"
     33081, // "👩\u200d🎓" 1F469-200D-1F393
     33633, // "👩\u200d🔬" 1F469-200D-1F52C
-    8915, // "🌝"
     13807, // "🏃🏼\u200d♂️" 1F3C3-1F3FC-200D-2642-FE0F
     37271, // "👷🏻\u200d♀️" 1F477-1F3FB-200D-2640-FE0F
-    43422, // "📧"
-    42806, // "📒"
     36922, // "👵🏻" 1F475-1F3FB
     39980, // "💆\u200d♂️" 1F486-200D-2642-FE0F
     28611, // "👩🏻\u200d🚀" 1F469-1F3FB-200D-1F680
     28235, // "👩🏻\u200d🌾" 1F469-1F3FB-200D-1F33E
     20947, // "👋🏼" 1F44B-1F3FC
-    9268, // "🌫"
     36525, // "👳🏽" 1F473-1F3FD
     16312, // "🏌🏾" 1F3CC-1F3FE
     29290, // "👩🏼\u200d🎓" 1F469-1F3FC-200D-1F393
@@ -4661,476 +6425,279 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     22501, // "👦🏽" 1F466-1F3FD
     9123, // "🌦️" 1F326-FE0F
     30314, // "👩🏽\u200d🏫" 1F469-1F3FD-200D-1F3EB
-    10820, // "🍣"
     47371, // "🕵🏾" 1F575-1F3FE
     22619, // "👧🏻" 1F467-1F3FB
     36389, // "👳🏻" 1F473-1F3FB
     40527, // "💇\u200d♂️" 1F487-200D-2642-FE0F
     15064, // "🏊🏻" 1F3CA-1F3FB
     30939, // "👩🏽\u200d🦽" 1F469-1F3FD-200D-1F9BD
-    43462, // "📩"
     29560, // "👩🏼\u200d🚀" 1F469-1F3FC-200D-1F680
     37616, // "👷🏿" 1F477-1F3FF
-    45161, // "🔤"
     25874, // "👨🏾\u200d🦰" 1F468-1F3FE-200D-1F9B0
-    18954, // "🐣"
-    48967, // "🗼"
     15034, // "🏊🏻\u200d♂️" 1F3CA-1F3FB-200D-2642-FE0F
     30966, // "👩🏽\u200d⚕️" 1F469-1F3FD-200D-2695-FE0F
     36115, // "👱\u200d♀️" 1F471-200D-2640-FE0F
     30553, // "👩🏽\u200d🤝\u200d👨🏻" 1F469-1F3FD-200D-1F91D-200D-1F468-1F3FB
-    48475, // "🖲"
-    11150, // "🍯"
-    44804, // "🔙"
     32057, // "👩🏿\u200d🍳" 1F469-1F3FF-200D-1F373
-    22139, // "👛"
-    43258, // "📡"
     19833, // "👁\u200d🗨" 1F441-200D-1F5E8
-    45282, // "🔨"
-    45954, // "🕌"
-    11750, // "🎄"
     14339, // "🏄🏽\u200d♂️" 1F3C4-1F3FD-200D-2642-FE0F
-    16932, // "🏞"
-    8016, // "🌀"
-    12619, // "🎣"
     38010, // "👼🏼" 1F47C-1F3FC
     26400, // "👨🏿\u200d💻" 1F468-1F3FF-200D-1F4BB
-    44741, // "🔗"
     34008, // "👩\u200d❤️\u200d👩" 1F469-200D-2764-FE0F-200D-1F469
-    19616, // "🐺"
     35940, // "👱🏽" 1F471-1F3FD
-    43870, // "📷"
-    48601, // "🗑"
-    17826, // "🏺"
-    22029, // "👗"
-    12072, // "🎊"
     38041, // "👼🏽" 1F47C-1F3FD
     38625, // "💁🏾" 1F481-1F3FE
     12950, // "🎮️" 1F3AE-FE0F
     35868, // "👱🏼" 1F471-1F3FC
     31393, // "👩🏾\u200d🔧" 1F469-1F3FE-200D-1F527
-    22059, // "👘"
     28296, // "👩🏻\u200d🍼" 1F469-1F3FB-200D-1F37C
-    44486, // "🔎"
-    10268, // "🍏"
     31330, // "👩🏾\u200d💻" 1F469-1F3FE-200D-1F4BB
     37318, // "👷🏻" 1F477-1F3FB
-    9596, // "🌷"
     35774, // "👱🏻\u200d♂️" 1F471-1F3FB-200D-2642-FE0F
     25105, // "👨🏽\u200d🦳" 1F468-1F3FD-200D-1F9B3
-    16082, // "🏋"
     32086, // "👩🏿\u200d🍼" 1F469-1F3FF-200D-1F37C
     20496, // "👈🏻" 1F448-1F3FB
-    43949, // "📺"
     46975, // "🕴🏾\u200d♀️" 1F574-1F3FE-200D-2640-FE0F
-    48717, // "🗝"
-    46175, // "🕔"
-    9836, // "🍀"
     24259, // "👨🏼\u200d🦳" 1F468-1F3FC-200D-1F9B3
     33692, // "👩\u200d🚒" 1F469-200D-1F692
     26217, // "👨🏿\u200d🎄" 1F468-1F3FF-200D-1F384
-    19318, // "🐰"
-    19584, // "🐹"
     31760, // "👩🏾\u200d🦰" 1F469-1F3FE-200D-1F9B0
     25287, // "👨🏾\u200d🌾" 1F468-1F3FE-200D-1F33E
     19882, // "👂🏻" 1F442-1F3FB
     37466, // "👷🏽" 1F477-1F3FD
     26052, // "👨🏾\u200d⚖️" 1F468-1F3FE-200D-2696-FE0F
-    12498, // "🎟"
     24392, // "👨🏼\u200d✈️" 1F468-1F3FC-200D-2708-FE0F
-    43312, // "📣"
-    8200, // "🌆"
     48659, // "🗓️" 1F5D3-FE0F
     31103, // "👩🏾\u200d🍳" 1F469-1F3FE-200D-1F373
     16186, // "🏌🏼\u200d♂️" 1F3CC-1F3FC-200D-2642-FE0F
-    13325, // "🎻"
     30721, // "👩🏽\u200d🤝\u200d👩🏾" 1F469-1F3FD-200D-1F91D-200D-1F469-1F3FE
-    38162, // "👽"
-    16786, // "🏙"
-    18005, // "🐂"
     38781, // "💁\u200d♂️" 1F481-200D-2642-FE0F
-    21384, // "👍"
     23875, // "👨🏼\u200d💻" 1F468-1F3FC-200D-1F4BB
-    19671, // "🐻"
     33136, // "👩\u200d🎨" 1F469-200D-1F3A8
-    42012, // "💶"
     26368, // "👨🏿\u200d🏭" 1F468-1F3FF-200D-1F3ED
     22775, // "👨🏻\u200d🌾" 1F468-1F3FB-200D-1F33E
-    14094, // "🏃"
     19033, // "🐦️" 1F426-FE0F
     14211, // "🏄🏼\u200d♀️" 1F3C4-1F3FC-200D-2640-FE0F
     38901, // "💂🏼\u200d♀️" 1F482-1F3FC-200D-2640-FE0F
-    45595, // "🔳"
     43366, // "📥️" 1F4E5-FE0F
-    17049, // "🏢"
     43573, // "📭️" 1F4ED-FE0F
-    41310, // "💢"
-    22310, // "👡"
     35234, // "👰🏻\u200d♂️" 1F470-1F3FB-200D-2642-FE0F
     15803, // "🏋🏽" 1F3CB-1F3FD
-    40588, // "💈"
-    10949, // "🍨"
     23478, // "👨🏻\u200d🦽" 1F468-1F3FB-200D-1F9BD
     21610, // "👏🏼" 1F44F-1F3FC
-    22358, // "👣"
-    19058, // "🐧"
-    12650, // "🎤"
-    44772, // "🔘"
-    10876, // "🍥"
     21043, // "👋🏿" 1F44B-1F3FF
     33902, // "👩\u200d⚕️" 1F469-200D-2695-FE0F
     24281, // "👨🏼\u200d🦼" 1F468-1F3FC-200D-1F9BC
-    11928, // "🎅"
     35384, // "👰🏽\u200d♀️" 1F470-1F3FD-200D-2640-FE0F
     39733, // "💆🏽\u200d♀️" 1F486-1F3FD-200D-2640-FE0F
-    22003, // "👖"
-    22202, // "👝"
     44031, // "📽️" 1F4FD-FE0F
     13828, // "🏃🏼" 1F3C3-1F3FC
     40247, // "💇🏽\u200d♂️" 1F487-1F3FD-200D-2642-FE0F
-    16635, // "🏔"
-    11396, // "🍸"
-    42278, // "💿"
     15124, // "🏊🏼\u200d♂️" 1F3CA-1F3FC-200D-2642-FE0F
     24559, // "👨🏽\u200d🎓" 1F468-1F3FD-200D-1F393
     33845, // "👩\u200d🦼" 1F469-200D-1F9BC
     21705, // "👏🏿" 1F44F-1F3FF
-    44634, // "🔓"
     37150, // "👶🏽" 1F476-1F3FD
     15958, // "🏋🏿\u200d♂️" 1F3CB-1F3FF-200D-2642-FE0F
-    19227, // "🐭"
     13916, // "🏃🏾\u200d♀️" 1F3C3-1F3FE-200D-2640-FE0F
     26663, // "👨🏿\u200d🤝\u200d👨🏾" 1F468-1F3FF-200D-1F91D-200D-1F468-1F3FE
-    18765, // "🐜"
     26432, // "👨🏿\u200d💼" 1F468-1F3FF-200D-1F4BC
     16202, // "🏌🏼" 1F3CC-1F3FC
     44454, // "🔍️" 1F50D-FE0F
-    44710, // "🔖"
     19946, // "👂🏾" 1F442-1F3FE
-    38222, // "👿"
     23725, // "👨🏼\u200d🎓" 1F468-1F3FC-200D-1F393
     20612, // "👈️" 1F448-FE0F
     21247, // "👍🏻" 1F44D-1F3FB
     39907, // "💆🏿\u200d♂️" 1F486-1F3FF-200D-2642-FE0F
     47688, // "🕺🏽" 1F57A-1F3FD
     46623, // "🕢️" 1F562-FE0F
-    13029, // "🎱"
     28779, // "👩🏻\u200d🤝\u200d👩🏼" 1F469-1F3FB-200D-1F91D-200D-1F469-1F3FC
     21578, // "👏🏻" 1F44F-1F3FB
     23410, // "👨🏻\u200d🦲" 1F468-1F3FB-200D-1F9B2
     32629, // "👩🏿\u200d🤝\u200d👩🏽" 1F469-1F3FF-200D-1F91D-200D-1F469-1F3FD
     28069, // "👨\u200d⚕️" 1F468-200D-2695-FE0F
     28124, // "👨\u200d✈️" 1F468-200D-2708-FE0F
-    19525, // "🐷"
     44612, // "🔒️" 1F512-FE0F
-    42932, // "📖"
-    9737, // "🌼"
-    11475, // "🍻"
     27591, // "👨\u200d👩\u200d👦" 1F468-200D-1F469-200D-1F466
     9041, // "🌡️" 1F321-FE0F
-    13005, // "🎰"
     16728, // "🏗️" 1F3D7-FE0F
-    10160, // "🍋"
-    8233, // "🌇"
     34662, // "👮🏻\u200d♀️" 1F46E-1F3FB-200D-2640-FE0F
-    10624, // "🍜"
-    9759, // "🌽"
     27213, // "👨\u200d🏭" 1F468-200D-1F3ED
     40465, // "💇🏿" 1F487-1F3FF
-    9537, // "🌵"
     34273, // "👫🏿" 1F46B-1F3FF
     47022, // "🕴🏿\u200d♂️" 1F574-1F3FF-200D-2642-FE0F
     34525, // "👭🏼" 1F46D-1F3FC
-    11627, // "🎀"
     48452, // "🖱️" 1F5B1-FE0F
     26166, // "👨🏿\u200d🍳" 1F468-1F3FF-200D-1F373
     28267, // "👩🏻\u200d🍳" 1F469-1F3FB-200D-1F373
     8681, // "🌕️" 1F315-FE0F
-    13062, // "🎲"
     21274, // "👍🏼" 1F44D-1F3FC
     40122, // "💇🏼\u200d♀️" 1F487-1F3FC-200D-2640-FE0F
-    10576, // "🍚"
     28458, // "👩🏻\u200d🏭" 1F469-1F3FB-200D-1F3ED
     13961, // "🏃🏾" 1F3C3-1F3FE
     16082, // "🏋️" 1F3CB-FE0F
     48580, // "🗄️" 1F5C4-FE0F
     35293, // "👰🏼\u200d♀️" 1F470-1F3FC-200D-2640-FE0F
-    11260, // "🍳"
-    12418, // "🎚"
     47623, // "🕹️" 1F579-FE0F
     39832, // "💆🏾\u200d♂️" 1F486-1F3FE-200D-2642-FE0F
-    44683, // "🔕"
     43202, // "📟️" 1F4DF-FE0F
-    19402, // "🐳"
     26250, // "👨🏿\u200d🎓" 1F468-1F3FF-200D-1F393
     36753, // "👴🏻" 1F474-1F3FB
-    47532, // "🕵"
     16276, // "🏌🏾\u200d♀️" 1F3CC-1F3FE-200D-2640-FE0F
     23335, // "👨🏻\u200d🦯" 1F468-1F3FB-200D-1F9AF
     44634, // "🔓️" 1F513-FE0F
-    12771, // "🎨"
     36808, // "👴🏽" 1F474-1F3FD
-    19088, // "🐨"
     36481, // "👳🏽\u200d♀️" 1F473-1F3FD-200D-2640-FE0F
-    17762, // "🏸"
-    19733, // "🐽"
-    10603, // "🍛"
     26874, // "👨🏿\u200d⚕️" 1F468-1F3FF-200D-2695-FE0F
     35846, // "👱🏼\u200d♂️" 1F471-1F3FC-200D-2642-FE0F
-    10438, // "🍕"
-    8651, // "🌔"
     46367, // "🕚️" 1F55A-FE0F
-    36319, // "👲"
     20280, // "👆🏾" 1F446-1F3FE
     14435, // "🏄🏾\u200d♂️" 1F3C4-1F3FE-200D-2642-FE0F
     28889, // "👩🏻\u200d🦯" 1F469-1F3FB-200D-1F9AF
     21673, // "👏🏾" 1F44F-1F3FE
     16240, // "🏌🏽\u200d♂️" 1F3CC-1F3FD-200D-2642-FE0F
     31235, // "👩🏾\u200d🎨" 1F469-1F3FE-200D-1F3A8
-    49054, // "🗿"
     40090, // "💇🏻" 1F487-1F3FB
     14659, // "🏄️" 1F3C4-FE0F
-    34635, // "👭"
     11775, // "🎅🏻" 1F385-1F3FB
     46463, // "🕝️" 1F55D-FE0F
     13938, // "🏃🏾\u200d♂️" 1F3C3-1F3FE-200D-2642-FE0F
     32345, // "👩🏿\u200d🔧" 1F469-1F3FF-200D-1F527
     47345, // "🕵🏾\u200d♂️" 1F575-1F3FE-200D-2642-FE0F
     43493, // "📪️" 1F4EA-FE0F
-    18380, // "🐏"
     47856, // "🖌️" 1F58C-FE0F
-    42747, // "📐"
     26082, // "👨🏾\u200d✈️" 1F468-1F3FE-200D-2708-FE0F
     33286, // "👩\u200d👧\u200d👦" 1F469-200D-1F467-200D-1F466
-    21974, // "👕"
-    11375, // "🍷"
     15432, // "🏊🏿" 1F3CA-1F3FF
-    11535, // "🍽"
-    17235, // "🏨"
-    42643, // "📌"
     24893, // "👨🏽\u200d🤝\u200d👨🏻" 1F468-1F3FD-200D-1F91D-200D-1F468-1F3FB
-    17734, // "🏷"
     29184, // "👩🏼\u200d🌾" 1F469-1F3FC-200D-1F33E
-    13449, // "🎿"
-    43366, // "📥"
     36571, // "👳🏾\u200d♂️" 1F473-1F3FE-200D-2642-FE0F
-    41744, // "💬"
     21928, // "👓️" 1F453-FE0F
     28041, // "👨\u200d🦽" 1F468-200D-1F9BD
-    12476, // "🎞"
-    21737, // "👏"
-    47597, // "🕸"
-    48659, // "🗓"
     43398, // "📦️" 1F4E6-FE0F
     26555, // "👨🏿\u200d🚒" 1F468-1F3FF-200D-1F692
-    10656, // "🍝"
     28750, // "👩🏻\u200d🤝\u200d👨🏿" 1F469-1F3FB-200D-1F91D-200D-1F468-1F3FF
-    41425, // "💦"
-    44031, // "📽"
     29102, // "👩🏻\u200d⚖️" 1F469-1F3FB-200D-2696-FE0F
     36980, // "👵🏽" 1F475-1F3FD
     33874, // "👩\u200d🦽" 1F469-200D-1F9BD
     13721, // "🏃🏻\u200d♀️" 1F3C3-1F3FB-200D-2640-FE0F
-    12394, // "🎙"
     13850, // "🏃🏽\u200d♀️" 1F3C3-1F3FD-200D-2640-FE0F
     36186, // "👲🏻" 1F472-1F3FB
     26930, // "👨🏿\u200d✈️" 1F468-1F3FF-200D-2708-FE0F
     29670, // "👩🏼\u200d🤝\u200d👨🏾" 1F469-1F3FC-200D-1F91D-200D-1F468-1F3FE
     14029, // "🏃🏿" 1F3C3-1F3FF
-    47747, // "🕺"
     14243, // "🏄🏼\u200d♂️" 1F3C4-1F3FC-200D-2642-FE0F
-    9101, // "🌥"
-    16665, // "🏕"
     23532, // "👨🏻\u200d⚖️" 1F468-1F3FB-200D-2696-FE0F
-    48892, // "🗳"
     27971, // "👨\u200d🦲" 1F468-200D-1F9B2
     29588, // "👩🏼\u200d🚒" 1F469-1F3FC-200D-1F692
     35991, // "👱🏾\u200d♂️" 1F471-1F3FE-200D-2642-FE0F
-    41457, // "💧"
     15741, // "🏋🏽\u200d♀️" 1F3CB-1F3FD-200D-2640-FE0F
     38466, // "💁🏽\u200d♀️" 1F481-1F3FD-200D-2640-FE0F
-    48393, // "🖥"
-    48748, // "🗞"
     13743, // "🏃🏻\u200d♂️" 1F3C3-1F3FB-200D-2642-FE0F
     48283, // "🖖🏾" 1F596-1F3FE
-    12444, // "🎛"
     15154, // "🏊🏼" 1F3CA-1F3FC
-    21074, // "👋"
-    10976, // "🍩"
     25735, // "👨🏾\u200d🤝\u200d👨🏻" 1F468-1F3FE-200D-1F91D-200D-1F468-1F3FB
     36549, // "👳🏾\u200d♀️" 1F473-1F3FE-200D-2640-FE0F
     29440, // "👩🏼\u200d💻" 1F469-1F3FC-200D-1F4BB
     24217, // "👨🏼\u200d🦱" 1F468-1F3FC-200D-1F9B1
-    45750, // "🔸"
     39042, // "💂🏾\u200d♂️" 1F482-1F3FE-200D-2642-FE0F
-    17986, // "🐁"
     37391, // "👷🏼" 1F477-1F3FC
     45894, // "🕊️" 1F54A-FE0F
     16167, // "🏌🏼\u200d♀️" 1F3CC-1F3FC-200D-2640-FE0F
     47266, // "🕵🏽\u200d♂️" 1F575-1F3FD-200D-2642-FE0F
-    18520, // "🐔"
     48310, // "🖖🏿" 1F596-1F3FF
-    43545, // "📬"
-    41958, // "💴"
-    46047, // "🕐"
     20869, // "👊🏿" 1F44A-1F3FF
     20915, // "👋🏻" 1F44B-1F3FB
     11898, // "🎅🏿" 1F385-1F3FF
     36435, // "👳🏼\u200d♂️" 1F473-1F3FC-200D-2642-FE0F
     13659, // "🏂🏿" 1F3C2-1F3FF
     47451, // "🕵🏿" 1F575-1F3FF
-    45511, // "🔰"
-    45479, // "🔯"
     47034, // "🕴🏿" 1F574-1F3FF
     12360, // "🎖️" 1F396-FE0F
-    9123, // "🌦"
-    34105, // "👩"
-    11567, // "🍾"
     40371, // "💇🏾" 1F487-1F3FE
-    47794, // "🖊"
     26771, // "👨🏿\u200d🦲" 1F468-1F3FF-200D-1F9B2
     27990, // "👨\u200d🦳" 1F468-200D-1F9B3
     16406, // "🏌️\u200d♂️" 1F3CC-FE0F-200D-2642-FE0F
-    18491, // "🐓"
     23279, // "👨🏻\u200d🤝\u200d👨🏾" 1F468-1F3FB-200D-1F91D-200D-1F468-1F3FE
-    48990, // "🗽"
-    12918, // "🎭"
     25206, // "👨🏽\u200d⚖️" 1F468-1F3FD-200D-2696-FE0F
     21465, // "👎🏽" 1F44E-1F3FD
     36661, // "👳🏿" 1F473-1F3FF
     22977, // "👨🏻\u200d🏫" 1F468-1F3FB-200D-1F3EB
-    19033, // "🐦"
-    19140, // "🐪"
     19902, // "👂🏼" 1F442-1F3FC
-    10794, // "🍢"
-    42039, // "💷"
-    11094, // "🍭"
     22670, // "👧🏽" 1F467-1F3FD
     29838, // "👩🏼\u200d🦯" 1F469-1F3FC-200D-1F9AF
     29216, // "👩🏼\u200d🍳" 1F469-1F3FC-200D-1F373
     46964, // "🕴🏽" 1F574-1F3FD
-    10192, // "🍌"
     15308, // "🏊🏾\u200d♂️" 1F3CA-1F3FE-200D-2642-FE0F
     20015, // "👃🏻" 1F443-1F3FB
-    42128, // "💺"
     29754, // "👩🏼\u200d🤝\u200d👩🏽" 1F469-1F3FC-200D-1F91D-200D-1F469-1F3FD
     48050, // "🖐️" 1F590-FE0F
     26744, // "👨🏿\u200d🦱" 1F468-1F3FF-200D-1F9B1
-    9069, // "🌤"
-    16468, // "🏎"
-    41370, // "💤"
-    38132, // "👼"
     24081, // "👨🏼\u200d🤝\u200d👨🏽" 1F468-1F3FC-200D-1F91D-200D-1F468-1F3FD
-    46623, // "🕢"
     47727, // "🕺🏿" 1F57A-1F3FF
     42278, // "💿️" 1F4BF-FE0F
     26106, // "👨🏾" 1F468-1F3FE
     32107, // "👩🏿\u200d🎄" 1F469-1F3FF-200D-1F384
     25791, // "👨🏾\u200d🤝\u200d👨🏽" 1F468-1F3FE-200D-1F91D-200D-1F468-1F3FD
     42154, // "💻️" 1F4BB-FE0F
-    13510, // "🏁"
     29990, // "👩🏼\u200d🦽" 1F469-1F3FC-200D-1F9BD
     12498, // "🎟️" 1F39F-FE0F
     33410, // "👩\u200d👩\u200d👦" 1F469-200D-1F469-200D-1F466
     40403, // "💇🏿\u200d♀️" 1F487-1F3FF-200D-2640-FE0F
-    42956, // "📗"
-    41142, // "💜"
     33036, // "👩\u200d🍼" 1F469-200D-1F37C
-    18619, // "🐗"
     28697, // "👩🏻\u200d🤝\u200d👨🏽" 1F469-1F3FB-200D-1F91D-200D-1F468-1F3FD
-    10354, // "🍒"
-    12831, // "🎪"
     15215, // "🏊🏽\u200d♂️" 1F3CA-1F3FD-200D-2642-FE0F
     21151, // "👌🏽" 1F44C-1F3FD
-    40970, // "💖"
-    45797, // "🔼"
-    43601, // "📮"
     43922, // "📹️" 1F4F9-FE0F
-    46719, // "🕥"
     20423, // "👇🏾" 1F447-1F3FE
     26196, // "👨🏿\u200d🍼" 1F468-1F3FF-200D-1F37C
-    19752, // "🐾"
     47886, // "🖍️" 1F58D-FE0F
     21384, // "👍️" 1F44D-FE0F
     26463, // "👨🏿\u200d🔧" 1F468-1F3FF-200D-1F527
-    11126, // "🍮"
-    41200, // "💞"
     28012, // "👨\u200d🦼" 1F468-200D-1F9BC
     9153, // "🌧️" 1F327-FE0F
-    17394, // "🏭"
-    12589, // "🎢"
-    42590, // "📊"
     36639, // "👳🏿\u200d♂️" 1F473-1F3FF-200D-2642-FE0F
     31423, // "👩🏾\u200d🔬" 1F469-1F3FE-200D-1F52C
     47187, // "🕵🏼\u200d♂️" 1F575-1F3FC-200D-2642-FE0F
-    37952, // "👻"
     47570, // "🕷️" 1F577-FE0F
     14371, // "🏄🏽" 1F3C4-1F3FD
-    18267, // "🐋"
     14880, // "🏇🏿" 1F3C7-1F3FF
-    18401, // "🐐"
-    46303, // "🕘"
     23506, // "👨🏻\u200d⚕️" 1F468-1F3FB-200D-2695-FE0F
     27898, // "👨\u200d🦯" 1F468-200D-1F9AF
-    11718, // "🎃"
-    16426, // "🏌"
     30777, // "👩🏽\u200d🦯" 1F469-1F3FD-200D-1F9AF
     46143, // "🕓️" 1F553-FE0F
     35475, // "👰🏾\u200d♀️" 1F470-1F3FE-200D-2640-FE0F
-    8102, // "🌃"
     16635, // "🏔️" 1F3D4-FE0F
-    18935, // "🐢"
     47292, // "🕵🏽" 1F575-1F3FD
     14403, // "🏄🏾\u200d♀️" 1F3C4-1F3FE-200D-2640-FE0F
-    13175, // "🎶"
     17630, // "🏴\U000e0067\U000e0062\U000e0073\U000e0063\U000e0074\U000e007f" 1F3F4-E0067-E0062-E0073-E0063-E0074-E007F
     34887, // "👮🏾\u200d♀️" 1F46E-1F3FE-200D-2640-FE0F
     15648, // "🏋🏼\u200d♀️" 1F3CB-1F3FC-200D-2640-FE0F
     11805, // "🎅🏼" 1F385-1F3FC
-    44190, // "🔃"
-    18186, // "🐈"
-    44454, // "🔍"
     39664, // "💆🏼\u200d♀️" 1F486-1F3FC-200D-2640-FE0F
     22697, // "👧🏾" 1F467-1F3FE
-    14911, // "🏇"
-    16549, // "🏑"
-    14659, // "🏄"
     30636, // "👩🏽\u200d🤝\u200d👨🏿" 1F469-1F3FD-200D-1F91D-200D-1F468-1F3FF
     21436, // "👎🏼" 1F44E-1F3FC
-    40556, // "💇"
     34442, // "👬🏿" 1F46C-1F3FF
-    43773, // "📴"
-    43978, // "📻"
     38962, // "💂🏽\u200d♀️" 1F482-1F3FD-200D-2640-FE0F
     30832, // "👩🏽\u200d🦱" 1F469-1F3FD-200D-1F9B1
     28832, // "👩🏻\u200d🤝\u200d👩🏾" 1F469-1F3FB-200D-1F91D-200D-1F469-1F3FE
-    9991, // "🍅"
     40154, // "💇🏼\u200d♂️" 1F487-1F3FC-200D-2642-FE0F
     16758, // "🏘️" 1F3D8-FE0F
     31920, // "👩🏾\u200d⚕️" 1F469-1F3FE-200D-2695-FE0F
-    40634, // "💊"
     22561, // "👦🏿" 1F466-1F3FF
-    44426, // "🔌"
-    48526, // "🗂"
     46882, // "🕴🏻\u200d♂️" 1F574-1F3FB-200D-2642-FE0F
-    47856, // "🖌"
-    41171, // "💝"
     48475, // "🖲️" 1F5B2-FE0F
     33056, // "👩\u200d🎄" 1F469-200D-1F384
     33821, // "👩\u200d🦳" 1F469-200D-1F9B3
     29376, // "👩🏼\u200d🏫" 1F469-1F3FC-200D-1F3EB
     39710, // "💆🏼" 1F486-1F3FC
-    45785, // "🔻"
     20400, // "👇🏽" 1F447-1F3FD
     47010, // "🕴🏿\u200d♀️" 1F574-1F3FF-200D-2640-FE0F
     15772, // "🏋🏽\u200d♂️" 1F3CB-1F3FD-200D-2642-FE0F
     13872, // "🏃🏽\u200d♂️" 1F3C3-1F3FD-200D-2642-FE0F
-    9958, // "🍄"
     36239, // "👲🏽" 1F472-1F3FD
     31788, // "👩🏾\u200d🦱" 1F469-1F3FE-200D-1F9B1
-    19993, // "👂"
-    22417, // "👥"
-    10463, // "🍖"
     29641, // "👩🏼\u200d🤝\u200d👨🏽" 1F469-1F3FC-200D-1F91D-200D-1F468-1F3FD
     43870, // "📷️" 1F4F7-FE0F
     30229, // "👩🏽\u200d🎓" 1F469-1F3FD-200D-1F393
@@ -5138,34 +6705,15 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     15555, // "🏋🏻\u200d♀️" 1F3CB-1F3FB-200D-2640-FE0F
     33197, // "👩\u200d🏭" 1F469-200D-1F3ED
     35687, // "👰\u200d♂️" 1F470-200D-2642-FE0F
-    12360, // "🎖"
-    10737, // "🍠"
     13689, // "🏂️" 1F3C2-FE0F
-    9929, // "🍃"
-    21882, // "👑"
-    12330, // "🎓"
-    11346, // "🍶"
     9241, // "🌪️" 1F32A-FE0F
-    45394, // "🔬"
-    16964, // "🏟"
-    37691, // "👷"
     47969, // "🖐🏽" 1F590-1F3FD
-    47766, // "🖇"
-    40999, // "💗"
     48203, // "🖖🏻" 1F596-1F3FB
-    19005, // "🐥"
-    45691, // "🔶"
-    45221, // "🔦"
-    48580, // "🗄"
     35445, // "👰🏽" 1F470-1F3FD
-    48425, // "🖨"
     36015, // "👱🏾" 1F471-1F3FE
-    37241, // "👶"
-    43895, // "📸"
     47996, // "🖐🏾" 1F590-1F3FE
     25058, // "👨🏽\u200d🦱" 1F468-1F3FD-200D-1F9B1
     47398, // "🕵🏿\u200d♀️" 1F575-1F3FF-200D-2640-FE0F
-    11174, // "🍰"
     29408, // "👩🏼\u200d🏭" 1F469-1F3FC-200D-1F3ED
     37092, // "👶🏻" 1F476-1F3FB
     26817, // "👨🏿\u200d🦼" 1F468-1F3FF-200D-1F9BC
@@ -5174,47 +6722,31 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     16445, // "🏍️" 1F3CD-FE0F
     28668, // "👩🏻\u200d🤝\u200d👨🏼" 1F469-1F3FB-200D-1F91D-200D-1F468-1F3FC
     40341, // "💇🏾\u200d♂️" 1F487-1F3FE-200D-2642-FE0F
-    45921, // "🕋"
     21802, // "👐🏽" 1F450-1F3FD
     48688, // "🗜️" 1F5DC-FE0F
     27152, // "👨\u200d🎨" 1F468-200D-1F3A8
     33571, // "👩\u200d💼" 1F469-200D-1F4BC
     13786, // "🏃🏼\u200d♀️" 1F3C3-1F3FC-200D-2640-FE0F
     23104, // "👨🏻\u200d🔧" 1F468-1F3FB-200D-1F527
-    17676, // "🏴"
-    42690, // "📎"
-    45534, // "🔱"
-    11507, // "🍼"
-    17939, // "🏿"
     24584, // "👨🏽\u200d🎤" 1F468-1F3FD-200D-1F3A4
     35263, // "👰🏻" 1F470-1F3FB
     23751, // "👨🏼\u200d🎤" 1F468-1F3FC-200D-1F3A4
     25818, // "👨🏾\u200d🤝\u200d👨🏿" 1F468-1F3FE-200D-1F91D-200D-1F468-1F3FF
     34357, // "👬🏼" 1F46C-1F3FC
     29894, // "👩🏼\u200d🦱" 1F469-1F3FC-200D-1F9B1
-    19197, // "🐬"
     21786, // "👐🏼" 1F450-1F3FC
     24165, // "👨🏼\u200d🦯" 1F468-1F3FC-200D-1F9AF
     46431, // "🕜️" 1F55C-FE0F
     31452, // "👩🏾\u200d🚀" 1F469-1F3FE-200D-1F680
     46952, // "🕴🏽\u200d♂️" 1F574-1F3FD-200D-2642-FE0F
-    8837, // "🌚"
     33507, // "👩\u200d👩\u200d👧" 1F469-200D-1F469-200D-1F467
-    10850, // "🍤"
     31676, // "👩🏾\u200d🤝\u200d👩🏽" 1F469-1F3FE-200D-1F91D-200D-1F469-1F3FD
     27924, // "👨\u200d🦰" 1F468-200D-1F9B0
     34162, // "👫🏻" 1F46B-1F3FB
-    18827, // "🐞"
     20979, // "👋🏽" 1F44B-1F3FD
     48393, // "🖥️" 1F5A5-FE0F
     20061, // "👃🏽" 1F443-1F3FD
-    13118, // "🎴"
-    44869, // "🔛"
-    41264, // "💠"
-    8977, // "🌟"
     23451, // "👨🏻\u200d🦼" 1F468-1F3FB-200D-1F9BC
-    22111, // "👚"
-    46367, // "🕚"
     34384, // "👬🏽" 1F46C-1F3FD
     18186, // "🐈️" 1F408-FE0F
     39956, // "💆\u200d♀️" 1F486-200D-2640-FE0F
@@ -5224,16 +6756,10 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     30665, // "👩🏽\u200d🤝\u200d👩🏻" 1F469-1F3FD-200D-1F91D-200D-1F469-1F3FB
     30750, // "👩🏽\u200d🤝\u200d👩🏿" 1F469-1F3FD-200D-1F91D-200D-1F469-1F3FF
     46559, // "🕠️" 1F560-FE0F
-    17299, // "🏪"
     24193, // "👨🏼\u200d🦰" 1F468-1F3FC-200D-1F9B0
     31509, // "👩🏾\u200d🤝\u200d👨🏻" 1F469-1F3FE-200D-1F91D-200D-1F468-1F3FB
-    44901, // "🔜"
-    13206, // "🎷"
-    18859, // "🐟"
     48601, // "🗑️" 1F5D1-FE0F
     24475, // "👨🏽\u200d🍳" 1F468-1F3FD-200D-1F373
-    18431, // "🐑"
-    18710, // "🐚"
     21175, // "👌🏾" 1F44C-1F3FE
     32870, // "👩🏿\u200d⚕️" 1F469-1F3FF-200D-2695-FE0F
     47918, // "🖐🏻" 1F590-1F3FB
@@ -5248,59 +6774,39 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     46335, // "🕙️" 1F559-FE0F
     48892, // "🗳️" 1F5F3-FE0F
     24741, // "👨🏽\u200d💼" 1F468-1F3FD-200D-1F4BC
-    48364, // "🖤"
-    12377, // "🎗"
     25426, // "👨🏾\u200d🎤" 1F468-1F3FE-200D-1F3A4
-    44061, // "📿"
-    41915, // "💲"
     31943, // "👩🏾\u200d⚖️" 1F469-1F3FE-200D-2696-FE0F
     35965, // "👱🏾\u200d♀️" 1F471-1F3FE-200D-2640-FE0F
     34073, // "👩\u200d❤️\u200d💋\u200d👩" 1F469-200D-2764-FE0F-200D-1F48B-200D-1F469
-    45096, // "🔢"
     35657, // "👰\u200d♀️" 1F470-200D-2640-FE0F
     16132, // "🏌🏻\u200d♂️" 1F3CC-1F3FB-200D-2642-FE0F
     36685, // "👳\u200d♀️" 1F473-200D-2640-FE0F
     29314, // "👩🏼\u200d🎤" 1F469-1F3FC-200D-1F3A4
     34936, // "👮🏾" 1F46E-1F3FE
-    17871, // "🏼"
     30345, // "👩🏽\u200d🏭" 1F469-1F3FD-200D-1F3ED
     29501, // "👩🏼\u200d🔧" 1F469-1F3FC-200D-1F527
     38313, // "💁🏻\u200d♂️" 1F481-1F3FB-200D-2642-FE0F
     48551, // "🗃️" 1F5C3-FE0F
     34215, // "👫🏽" 1F46B-1F3FD
-    13388, // "🎽"
     23611, // "👨🏼\u200d🌾" 1F468-1F3FC-200D-1F33E
-    11030, // "🍫"
     38406, // "💁🏼\u200d♂️" 1F481-1F3FC-200D-2642-FE0F
     13629, // "🏂🏾" 1F3C2-1F3FE
-    46111, // "🕒"
     30804, // "👩🏽\u200d🦰" 1F469-1F3FD-200D-1F9B0
     29939, // "👩🏼\u200d🦳" 1F469-1F3FC-200D-1F9B3
     15927, // "🏋🏿\u200d♀️" 1F3CB-1F3FF-200D-2640-FE0F
-    20139, // "👃"
     32158, // "👩🏿\u200d🎤" 1F469-1F3FF-200D-1F3A4
-    48633, // "🗒"
-    16331, // "🏌🏿\u200d♀️" 1F3CC-1F3FF-200D-2640-FE0F
-    46815, // "🕯"
-    41505, // "💩"
+    16331, // "🏌🏿\u200d♀️" 1F3CC-1F3FF-200D-2640-FE0F
     26846, // "👨🏿\u200d🦽" 1F468-1F3FF-200D-1F9BD
     9069, // "🌤️" 1F324-FE0F
-    46495, // "🕞"
-    16818, // "🏚"
     33256, // "👩\u200d👦" 1F469-200D-1F466
     41537, // "💪🏻" 1F4AA-1F3FB
     48838, // "🗨️" 1F5E8-FE0F
     20682, // "👉🏽" 1F449-1F3FD
     24526, // "👨🏽\u200d🎄" 1F468-1F3FD-200D-1F384
     39781, // "💆🏽" 1F486-1F3FD
-    44126, // "🔁"
-    44405, // "🔋"
-    10685, // "🍞"
     38345, // "💁🏻" 1F481-1F3FB
-    21862, // "👐"
     47082, // "🕵🏻\u200d♀️" 1F575-1F3FB-200D-2640-FE0F
     32791, // "👩🏿\u200d🦳" 1F469-1F3FF-200D-1F9B3
-    37862, // "👸"
     35918, // "👱🏽\u200d♂️" 1F471-1F3FD-200D-2642-FE0F
     15094, // "🏊🏼\u200d♀️" 1F3CA-1F3FC-200D-2640-FE0F
     30988, // "👩🏽\u200d⚖️" 1F469-1F3FD-200D-2696-FE0F
@@ -5308,47 +6814,33 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     37541, // "👷🏾" 1F477-1F3FE
     30498, // "👩🏽\u200d🚀" 1F469-1F3FD-200D-1F680
     28148, // "👨\u200d❤️\u200d👨" 1F468-200D-2764-FE0F-200D-1F468
-    47069, // "🕴"
     15277, // "🏊🏾\u200d♀️" 1F3CA-1F3FE-200D-2640-FE0F
     46399, // "🕛️" 1F55B-FE0F
-    48688, // "🗜"
-    45761, // "🔹"
     14467, // "🏄🏾" 1F3C4-1F3FE
-    42839, // "📓"
     23072, // "👨🏻\u200d💼" 1F468-1F3FB-200D-1F4BC
     32571, // "👩🏿\u200d🤝\u200d👩🏻" 1F469-1F3FF-200D-1F91D-200D-1F469-1F3FB
     34812, // "👮🏽\u200d♀️" 1F46E-1F3FD-200D-2640-FE0F
     31266, // "👩🏾\u200d🏫" 1F469-1F3FE-200D-1F3EB
     33603, // "👩\u200d🔧" 1F469-200D-1F527
     21128, // "👌🏼" 1F44C-1F3FC
-    21225, // "👌"
     34712, // "👮🏻" 1F46E-1F3FB
-    20893, // "👊"
-    8713, // "🌖"
     37007, // "👵🏾" 1F475-1F3FE
-    36163, // "👱"
     38375, // "💁🏼\u200d♀️" 1F481-1F3FC-200D-2640-FE0F
     33316, // "👩\u200d👧\u200d👧" 1F469-200D-1F467-200D-1F467
     28945, // "👩🏻\u200d🦱" 1F469-1F3FB-200D-1F9B1
     22915, // "👨🏻\u200d🎤" 1F468-1F3FB-200D-1F3A4
-    8892, // "🌜"
-    47824, // "🖋"
     26900, // "👨🏿\u200d⚖️" 1F468-1F3FF-200D-2696-FE0F
     32815, // "👩🏿\u200d🦼" 1F469-1F3FF-200D-1F9BC
     39592, // "💆🏻\u200d♀️" 1F486-1F3FB-200D-2640-FE0F
     23042, // "👨🏻\u200d💻" 1F468-1F3FB-200D-1F4BB
     48023, // "🖐🏿" 1F590-1F3FF
-    44934, // "🔝"
     30040, // "👩🏼\u200d⚖️" 1F469-1F3FC-200D-2696-FE0F
     37980, // "👼🏻" 1F47C-1F3FB
     13984, // "🏃🏿\u200d♀️" 1F3C3-1F3FF-200D-2640-FE0F
     34962, // "👮🏿\u200d♀️" 1F46E-1F3FF-200D-2640-FE0F
     24416, // "👨🏼" 1F468-1F3FC
     23673, // "👨🏼\u200d🍼" 1F468-1F3FC-200D-1F37C
-    12158, // "🎍"
     46719, // "🕥️" 1F565-FE0F
-    46399, // "🕛"
-    12857, // "🎫"
     15463, // "🏊\u200d♀️" 1F3CA-200D-2640-FE0F
     34413, // "👬🏾" 1F46C-1F3FE
     32025, // "👩🏿\u200d🌾" 1F469-1F3FF-200D-1F33E
@@ -5357,9 +6849,7 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     39807, // "💆🏾\u200d♀️" 1F486-1F3FE-200D-2640-FE0F
     21522, // "👎🏿" 1F44E-1F3FF
     28861, // "👩🏻\u200d🤝\u200d👩🏿" 1F469-1F3FB-200D-1F91D-200D-1F469-1F3FF
-    44009, // "📼"
     22646, // "👧🏼" 1F467-1F3FC
-    10023, // "🍆"
     26607, // "👨🏿\u200d🤝\u200d👨🏼" 1F468-1F3FF-200D-1F91D-200D-1F468-1F3FC
     18568, // "🐕️" 1F415-FE0F
     47319, // "🕵🏾\u200d♀️" 1F575-1F3FE-200D-2640-FE0F
@@ -5367,117 +6857,171 @@ const OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [usize; 1648] = [
     38688, // "💁🏿\u200d♂️" 1F481-1F3FF-200D-2642-FE0F
     17558, // "🏳️" 1F3F3-FE0F
     15834, // "🏋🏾\u200d♀️" 1F3CB-1F3FE-200D-2640-FE0F
-    9041, // "🌡"
     35820, // "👱🏼\u200d♀️" 1F471-1F3FC-200D-2640-FE0F
-    12246, // "🎐"
-    16728, // "🏗"
-    12025, // "🎈"
     27368, // "👨\u200d👧" 1F468-200D-1F467
     40497, // "💇\u200d♀️" 1F487-200D-2640-FE0F
-    46855, // "🕳"
-    11001, // "🍪"
-    18459, // "🐒"
     30528, // "👩🏽\u200d🚒" 1F469-1F3FD-200D-1F692
     37492, // "👷🏾\u200d♀️" 1F477-1F3FE-200D-2640-FE0F
     39165, // "💂\u200d♂️" 1F482-200D-2642-FE0F
     31705, // "👩🏾\u200d🤝\u200d👩🏿" 1F469-1F3FE-200D-1F91D-200D-1F469-1F3FF
-    48502, // "🖼"
-    45627, // "🔴"
-    41338, // "💣"
     25035, // "👨🏽\u200d🦰" 1F468-1F3FD-200D-1F9B0
-    41716, // "💫"
     32377, // "👩🏿\u200d🔬" 1F469-1F3FF-200D-1F52C
     24240, // "👨🏼\u200d🦲" 1F468-1F3FC-200D-1F9B2
-    35086, // "👮"
     20039, // "👃🏼" 1F443-1F3FC
     20305, // "👆🏿" 1F446-1F3FF
     31154, // "👩🏾\u200d🎄" 1F469-1F3FE-200D-1F384
     13763, // "🏃🏻" 1F3C3-1F3FB
-    46591, // "🕡"
+];
+
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_emoticons_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_EMOTICONS, 0x1F600, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x1F600..=0x1F64F, or NO_GLYPH
+static CODEPOINT_EMOTICONS: [u32; 80] = [
+    49077, // "😀"
+    49107, // "😁"
+    49139, // "😂"
+    49171, // "😃"
+    49201, // "😄"
+    49233, // "😅"
+    49265, // "😆"
+    49295, // "😇"
+    49327, // "😈"
+    49360, // "😉"
+    49390, // "😊"
+    49422, // "😋"
+    49454, // "😌"
+    49484, // "😍"
+    49516, // "😎"
+    49548, // "😏"
+    49578, // "😐"
+    49608, // "😑"
+    49640, // "😒"
+    49672, // "😓"
+    49702, // "😔"
+    49734, // "😕"
+    49766, // "😖"
+    49796, // "😗"
+    49828, // "😘"
+    49860, // "😙"
+    49890, // "😚"
+    49922, // "😛"
+    49954, // "😜"
+    49984, // "😝"
+    50016, // "😞"
+    50048, // "😟"
+    50078, // "😠"
+    50107, // "😡"
+    50139, // "😢"
+    50170, // "😣"
+    50199, // "😤"
+    50230, // "😥"
+    50261, // "😦"
+    50290, // "😧"
+    50321, // "😨"
+    50351, // "😩"
+    50380, // "😪"
+    50411, // "😫"
+    50442, // "😬"
+    50471, // "😭"
+    50502, // "😮"
+    50533, // "😯"
+    50562, // "😰"
+    50592, // "😱"
+    50624, // "😲"
+    50656, // "😳"
+    50686, // "😴"
+    50718, // "😵"
+    50750, // "😶"
+    50780, // "😷"
+    50812, // "😸"
+    50844, // "😹"
+    50876, // "😺"
+    50908, // "😻"
+    50940, // "😼"
+    50970, // "😽"
+    51002, // "😾"
+    51034, // "😿"
+    51064, // "🙀"
+    51094, // "🙁"
+    51126, // "🙂"
+    51158, // "🙃"
+    51188, // "🙄"
+    51743, // "🙅"
+    52309, // "🙆"
+    52887, // "🙇"
+    52919, // "🙈"
+    52950, // "🙉"
+    52981, // "🙊"
+    53459, // "🙋"
+    53642, // "🙌"
+    54073, // "🙍"
+    54499, // "🙎"
+    54679, // "🙏"
 ];
 
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_emoticons(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_EMOTICONS.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_EMOTICONS[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_EMOTICONS.binary_search(&hash) {
+        Ok(index) if KEY_EMOTICONS[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_EMOTICONS[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_EMOTICONS
-const HASH_EMOTICONS: [u32; 193] = [
+static HASH_EMOTICONS: [u32; 113] = [
     0x00170CDD,  // "🙍🏿" 1F64D-1F3FF
     0x0038911A,  // "🙇🏾" 1F647-1F3FE
-    0x009569B3,  // "😄"
     0x0309A0DF,  // "🙎🏾\u200d♀️" 1F64E-1F3FE-200D-2640-FE0F
     0x030FB52B,  // "🙇🏼\u200d♀️" 1F647-1F3FC-200D-2640-FE0F
     0x0431F52E,  // "🙍🏾\u200d♀️" 1F64D-1F3FE-200D-2640-FE0F
-    0x044E67F3,  // "😍"
-    0x051CEFD5,  // "😁"
-    0x062D3FEC,  // "😉"
     0x066BBA40,  // "🙋🏼\u200d♀️" 1F64B-1F3FC-200D-2640-FE0F
-    0x09C42D3E,  // "😡"
-    0x0A166A52,  // "😰"
     0x0BA35EBB,  // "🙆🏽\u200d♀️" 1F646-1F3FD-200D-2640-FE0F
     0x0BB365C4,  // "🙆🏾" 1F646-1F3FE
     0x0CF5740A,  // "🙇🏼\u200d♂️" 1F647-1F3FC-200D-2642-FE0F
     0x0E9C4DDB,  // "🙋🏿\u200d♀️" 1F64B-1F3FF-200D-2640-FE0F
-    0x0F3DB476,  // "🙉"
     0x0F61DD32,  // "🙋🏾" 1F64B-1F3FE
     0x1100BFBE,  // "🙍🏻\u200d♂️" 1F64D-1F3FB-200D-2642-FE0F
     0x1159C420,  // "🙅🏿\u200d♂️" 1F645-1F3FF-200D-2642-FE0F
-    0x11C29DC9,  // "🙌"
     0x124682C0,  // "🙎🏽\u200d♀️" 1F64E-1F3FD-200D-2640-FE0F
-    0x13B1D3E1,  // "😔"
     0x18DDA86E,  // "🙏🏾" 1F64F-1F3FE
-    0x1B43650B,  // "🙅"
     0x1E0D4E55,  // "🙎🏽\u200d♂️" 1F64E-1F3FD-200D-2642-FE0F
     0x1EAAA7EA,  // "🙇🏻\u200d♀️" 1F647-1F3FB-200D-2640-FE0F
     0x2272A8E2,  // "🙅🏾" 1F645-1F3FE
     0x2378563A,  // "🙌🏽" 1F64C-1F3FD
-    0x2452AD6D,  // "😵"
     0x25068D30,  // "🙋🏽" 1F64B-1F3FD
-    0x258EB954,  // "😫"
     0x262BB670,  // "🙏🏼" 1F64F-1F3FC
     0x27E892BE,  // "😐️" 1F610-FE0F
-    0x29B584A9,  // "🙈"
     0x29F5F22A,  // "🙍🏿\u200d♂️" 1F64D-1F3FF-200D-2642-FE0F
-    0x2A690465,  // "😕"
-    0x2BEB40DE,  // "😯"
     0x2D79A2D2,  // "🙇🏾\u200d♂️" 1F647-1F3FE-200D-2642-FE0F
     0x2E0DEF26,  // "🙇🏽\u200d♀️" 1F647-1F3FD-200D-2640-FE0F
     0x2EEF5CE9,  // "🙏🏽" 1F64F-1F3FD
     0x30DE02F5,  // "🙋\u200d♀️" 1F64B-200D-2640-FE0F
-    0x311C3A4E,  // "😽"
-    0x34D3DD2C,  // "😞"
     0x3682FB54,  // "🙋🏽\u200d♀️" 1F64B-1F3FD-200D-2640-FE0F
-    0x38E042AC,  // "😘"
-    0x3A0F7E51,  // "😑"
-    0x3AA94135,  // "😇"
     0x3B99449F,  // "🙇\u200d♂️" 1F647-200D-2642-FE0F
-    0x3C8BFA6D,  // "😷"
     0x3FC34EBD,  // "🙆🏼" 1F646-1F3FC
     0x3FCBC65D,  // "🙍🏾" 1F64D-1F3FE
-    0x40480439,  // "😒"
-    0x4128727C,  // "😨"
     0x4203DF04,  // "🙅🏿" 1F645-1F3FF
     0x430ED0E4,  // "🙆🏼\u200d♂️" 1F646-1F3FC-200D-2642-FE0F
-    0x451A1E38,  // "😟"
     0x45A75F68,  // "🙅🏼\u200d♀️" 1F645-1F3FC-200D-2640-FE0F
     0x47EEF60E,  // "🙅🏻\u200d♂️" 1F645-1F3FB-200D-2642-FE0F
-    0x494448D4,  // "😚"
-    0x4C2982E3,  // "😬"
     0x4E4BFD50,  // "🙋🏽\u200d♂️" 1F64B-1F3FD-200D-2642-FE0F
     0x5011019D,  // "🙎\u200d♀️" 1F64E-200D-2640-FE0F
     0x548B0489,  // "🙎🏽" 1F64E-1F3FD
-    0x55B8028C,  // "🙋"
     0x59ABC330,  // "🙎🏼\u200d♂️" 1F64E-1F3FC-200D-2642-FE0F
-    0x59FCBA98,  // "😳"
     0x5C7CDD20,  // "🙋🏻" 1F64B-1F3FB
-    0x61CE18B1,  // "😪"
-    0x6201F997,  // "🙁"
     0x62BE25C1,  // "🙇\u200d♀️" 1F647-200D-2640-FE0F
     0x639EA53B,  // "🙆🏿\u200d♀️" 1F646-1F3FF-200D-2640-FE0F
     0x65346FD2,  // "🙌🏼" 1F64C-1F3FC
@@ -5490,191 +7034,228 @@ const HASH_EMOTICONS: [u32; 193] = [
     0x6C39CF4F,  // "🙆🏻" 1F646-1F3FB
     0x6DFDA468,  // "🙇🏿" 1F647-1F3FF
     0x6F56A025,  // "🙍\u200d♀️" 1F64D-200D-2640-FE0F
-    0x6FC56B53,  // "😆"
-    0x705002D6,  // "😻"
     0x70CD0C79,  // "🙍🏼\u200d♂️" 1F64D-1F3FC-200D-2642-FE0F
     0x71BB4A8D,  // "🙍🏿\u200d♀️" 1F64D-1F3FF-200D-2640-FE0F
     0x73A3FA53,  // "🙎🏼" 1F64E-1F3FC
-    0x75B61505,  // "😛"
-    0x767F00A2,  // "😅"
     0x79606A37,  // "🙍🏻\u200d♀️" 1F64D-1F3FB-200D-2640-FE0F
-    0x7A1CFF52,  // "🙎"
     0x7B3175C4,  // "🙋🏾\u200d♀️" 1F64B-1F3FE-200D-2640-FE0F
-    0x7D105229,  // "😾"
-    0x7E822F63,  // "😢"
     0x7FEE0929,  // "🙅🏿\u200d♀️" 1F645-1F3FF-200D-2640-FE0F
     0x826B5FA2,  // "🙅🏼" 1F645-1F3FC
-    0x82C43F34,  // "😼"
     0x83540969,  // "🙅🏾\u200d♀️" 1F645-1F3FE-200D-2640-FE0F
     0x86A7C04B,  // "🙇🏿\u200d♂️" 1F647-1F3FF-200D-2642-FE0F
-    0x86E5DD9A,  // "😸"
-    0x8A911B72,  // "😙"
     0x8B7366B1,  // "🙍🏼" 1F64D-1F3FC
-    0x8E95DD30,  // "😦"
     0x8FFA4FF5,  // "🙆🏿\u200d♂️" 1F646-1F3FF-200D-2642-FE0F
-    0x90370920,  // "😏"
-    0x9096EE7E,  // "😶"
     0x911656D4,  // "🙇🏻" 1F647-1F3FB
-    0x9135B6E0,  // "😜"
     0x919EC957,  // "🙆🏿" 1F646-1F3FF
     0x91CCE4E4,  // "🙇🏽" 1F647-1F3FD
     0x9227BC03,  // "🙏🏻" 1F64F-1F3FB
-    0x943E248D,  // "😋"
     0x94DE4F9D,  // "🙆🏽" 1F646-1F3FD
-    0x96846F5E,  // "😌"
-    0x96B5BEE1,  // "😎"
     0x9725371A,  // "🙏🏿" 1F64F-1F3FF
-    0x97B4223A,  // "😊"
-    0x97B69076,  // "😐"
     0x98D6CB30,  // "🙅🏽\u200d♀️" 1F645-1F3FD-200D-2640-FE0F
     0x9909E7AB,  // "🙎🏻" 1F64E-1F3FB
     0x99F8E342,  // "🙇🏽\u200d♂️" 1F647-1F3FD-200D-2642-FE0F
     0x9AB399D2,  // "🙅\u200d♂️" 1F645-200D-2642-FE0F
-    0x9B08B014,  // "🙇"
     0x9CB63BDD,  // "🙆\u200d♂️" 1F646-200D-2642-FE0F
     0x9D35A157,  // "🙋\u200d♂️" 1F64B-200D-2642-FE0F
     0x9E40EF2A,  // "🙌🏿" 1F64C-1F3FF
     0x9EF9F81D,  // "🙅🏽\u200d♂️" 1F645-1F3FD-200D-2642-FE0F
     0x9EFF1B28,  // "🙆🏽\u200d♂️" 1F646-1F3FD-200D-2642-FE0F
     0x9F2A4219,  // "🙍🏽\u200d♀️" 1F64D-1F3FD-200D-2640-FE0F
-    0xA18FF857,  // "😮"
-    0xA1FCD2C1,  // "😂"
     0xA23C7470,  // "🙌🏾" 1F64C-1F3FE
-    0xA293F932,  // "😤"
     0xA313597D,  // "🙎\u200d♂️" 1F64E-200D-2642-FE0F
-    0xA416884D,  // "🙄"
     0xA4F4A9A9,  // "🙋🏼\u200d♂️" 1F64B-1F3FC-200D-2642-FE0F
-    0xA5131A99,  // "😿"
     0xAFA80D71,  // "🙆🏻\u200d♀️" 1F646-1F3FB-200D-2640-FE0F
     0xAFC33908,  // "🙆🏼\u200d♀️" 1F646-1F3FC-200D-2640-FE0F
-    0xB13DB254,  // "😗"
     0xB1E5B84F,  // "🙇🏻\u200d♂️" 1F647-1F3FB-200D-2642-FE0F
     0xB29D56FD,  // "🙍🏼\u200d♀️" 1F64D-1F3FC-200D-2640-FE0F
-    0xB2E5B752,  // "🙂"
     0xB525E855,  // "🙍🏽" 1F64D-1F3FD
-    0xB767870D,  // "🙃"
     0xB8C2EC31,  // "🙆🏻\u200d♂️" 1F646-1F3FB-200D-2642-FE0F
-    0xBAB2B528,  // "😓"
     0xBAF57185,  // "🙌🏻" 1F64C-1F3FB
-    0xBC0F04FF,  // "😖"
-    0xBD4B85B6,  // "😀"
-    0xBE9131B0,  // "😴"
     0xBFA08C9C,  // "🙎🏿\u200d♂️" 1F64E-1F3FF-200D-2642-FE0F
-    0xC02453E0,  // "😭"
     0xC07C3686,  // "🙍🏻" 1F64D-1F3FB
     0xC2ACA275,  // "🙅🏻" 1F645-1F3FB
     0xC2D5023F,  // "🙋🏼" 1F64B-1F3FC
-    0xC49A7EDE,  // "😺"
-    0xC5E7609B,  // "🙍"
     0xC6CA0036,  // "🙎🏾" 1F64E-1F3FE
-    0xC8A44A9E,  // "😃"
-    0xCA8CEA9B,  // "😹"
     0xCB13FE30,  // "🙇🏿\u200d♀️" 1F647-1F3FF-200D-2640-FE0F
     0xCB96F57B,  // "🙎🏼\u200d♀️" 1F64E-1F3FC-200D-2640-FE0F
     0xCBF72E7C,  // "🙅🏼\u200d♂️" 1F645-1F3FC-200D-2642-FE0F
     0xCE34CA16,  // "🙅🏾\u200d♂️" 1F645-1F3FE-200D-2642-FE0F
     0xCFDB1708,  // "🙇🏾\u200d♀️" 1F647-1F3FE-200D-2640-FE0F
-    0xD0A13C46,  // "😈"
     0xD1A15C7A,  // "🙇🏼" 1F647-1F3FC
-    0xD1EFD05D,  // "🙊"
-    0xD31EF94B,  // "😥"
-    0xD5896F2F,  // "😩"
-    0xD5A30866,  // "🙏"
     0xD5AB97E0,  // "🙆🏾\u200d♂️" 1F646-1F3FE-200D-2642-FE0F
     0xD6202E98,  // "🙍🏽\u200d♂️" 1F64D-1F3FD-200D-2642-FE0F
-    0xD6F0EECE,  // "🙆"
     0xD7367EF6,  // "🙅\u200d♀️" 1F645-200D-2640-FE0F
     0xD99216BF,  // "🙎🏻\u200d♂️" 1F64E-1F3FB-200D-2642-FE0F
-    0xDBBB8E2E,  // "🙀"
-    0xDC4A0BF8,  // "😣"
     0xDC533991,  // "🙎🏿\u200d♀️" 1F64E-1F3FF-200D-2640-FE0F
     0xDE33A387,  // "🙎🏾\u200d♂️" 1F64E-1F3FE-200D-2642-FE0F
     0xE129F88F,  // "🙋🏿\u200d♂️" 1F64B-1F3FF-200D-2642-FE0F
-    0xE15D0047,  // "😱"
     0xE2B58A33,  // "🙍🏾\u200d♂️" 1F64D-1F3FE-200D-2642-FE0F
-    0xE5CF4262,  // "😠"
     0xE9F8F387,  // "🙅🏻\u200d♀️" 1F645-1F3FB-200D-2640-FE0F
-    0xECF7AD09,  // "😲"
-    0xEE3482B8,  // "😧"
-    0xEF3CFEF2,  // "😝"
     0xF0F05DFD,  // "🙅🏽" 1F645-1F3FD
     0xFAB87951,  // "🙋🏻\u200d♂️" 1F64B-1F3FB-200D-2642-FE0F
     0xFAE55330,  // "🙋🏾\u200d♂️" 1F64B-1F3FE-200D-2642-FE0F
     0xFF4BF6EA,  // "🙆\u200d♀️" 1F646-200D-2640-FE0F
 ];
 
+/// Verification keys to rule out hash collisions; sort matches HASH_EMOTICONS
+static KEY_EMOTICONS: [u32; 113] = [
+    0xB16F5415,  // "🙍🏿" 1F64D-1F3FF
+    0xD2711719,  // "🙇🏾" 1F647-1F3FE
+    0xE97EBAE5,  // "🙎🏾\u200d♀️" 1F64E-1F3FE-200D-2640-FE0F
+    0xE1BDCC12,  // "🙇🏼\u200d♀️" 1F647-1F3FC-200D-2640-FE0F
+    0x80C23063,  // "🙍🏾\u200d♀️" 1F64D-1F3FE-200D-2640-FE0F
+    0xBFAC5CC6,  // "🙋🏼\u200d♀️" 1F64B-1F3FC-200D-2640-FE0F
+    0xB317F52F,  // "🙆🏽\u200d♀️" 1F646-1F3FD-200D-2640-FE0F
+    0xF33D15AC,  // "🙆🏾" 1F646-1F3FE
+    0xCDE7F3F2,  // "🙇🏼\u200d♂️" 1F647-1F3FC-200D-2642-FE0F
+    0xB9037093,  // "🙋🏿\u200d♀️" 1F64B-1F3FF-200D-2640-FE0F
+    0x9B9C3E17,  // "🙋🏾" 1F64B-1F3FE
+    0xA4A7B0A5,  // "🙍🏻\u200d♂️" 1F64D-1F3FB-200D-2642-FE0F
+    0xA5E46514,  // "🙅🏿\u200d♂️" 1F645-1F3FF-200D-2642-FE0F
+    0xEDD33C38,  // "🙎🏽\u200d♀️" 1F64E-1F3FD-200D-2640-FE0F
+    0xFAF86178,  // "🙏🏾" 1F64F-1F3FE
+    0xD2E405DF,  // "🙎🏽\u200d♂️" 1F64E-1F3FD-200D-2642-FE0F
+    0xCCCF3D3D,  // "🙇🏻\u200d♀️" 1F647-1F3FB-200D-2640-FE0F
+    0xA8E6C628,  // "🙅🏾" 1F645-1F3FE
+    0xCB8EF13A,  // "🙌🏽" 1F64C-1F3FD
+    0x9FC0FCC4,  // "🙋🏽" 1F64B-1F3FD
+    0xB607FF6D,  // "🙏🏼" 1F64F-1F3FC
+    0xA884EC00,  // "😐️" 1F610-FE0F
+    0xB59F5596,  // "🙍🏿\u200d♂️" 1F64D-1F3FF-200D-2642-FE0F
+    0xF662CA2D,  // "🙇🏾\u200d♂️" 1F647-1F3FE-200D-2642-FE0F
+    0xCE52A9B4,  // "🙇🏽\u200d♀️" 1F647-1F3FD-200D-2640-FE0F
+    0x8B2151C0,  // "🙏🏽" 1F64F-1F3FD
+    0xF401F8A6,  // "🙋\u200d♀️" 1F64B-200D-2640-FE0F
+    0x90FBDDE1,  // "🙋🏽\u200d♀️" 1F64B-1F3FD-200D-2640-FE0F
+    0xECF6DFFB,  // "🙇\u200d♂️" 1F647-200D-2642-FE0F
+    0xB36C2B1A,  // "🙆🏼" 1F646-1F3FC
+    0xB20BDDD6,  // "🙍🏾" 1F64D-1F3FE
+    0xBF82EACB,  // "🙅🏿" 1F645-1F3FF
+    0xC7F85659,  // "🙆🏼\u200d♂️" 1F646-1F3FC-200D-2642-FE0F
+    0xE312578B,  // "🙅🏼\u200d♀️" 1F645-1F3FC-200D-2640-FE0F
+    0xCA2BCD51,  // "🙅🏻\u200d♂️" 1F645-1F3FB-200D-2642-FE0F
+    0x91EE2EAE,  // "🙋🏽\u200d♂️" 1F64B-1F3FD-200D-2642-FE0F
+    0xE457A13C,  // "🙎\u200d♀️" 1F64E-200D-2640-FE0F
+    0xF51E3E14,  // "🙎🏽" 1F64E-1F3FD
+    0xEB8C3395,  // "🙎🏼\u200d♂️" 1F64E-1F3FC-200D-2642-FE0F
+    0xAE180011,  // "🙋🏻" 1F64B-1F3FB
+    0xF424879A,  // "🙇\u200d♀️" 1F647-200D-2640-FE0F
+    0xDBEE92B1,  // "🙆🏿\u200d♀️" 1F646-1F3FF-200D-2640-FE0F
+    0xE2CE4926,  // "🙌🏼" 1F64C-1F3FC
+    0x80BD14A3,  // "🙍\u200d♂️" 1F64D-200D-2642-FE0F
+    0xD8CCF255,  // "🙋🏿" 1F64B-1F3FF
+    0xCA466820,  // "🙆🏾\u200d♀️" 1F646-1F3FE-200D-2640-FE0F
+    0xDFC5DF1D,  // "🙋🏻\u200d♀️" 1F64B-1F3FB-200D-2640-FE0F
+    0xB35C926A,  // "🙎🏿" 1F64E-1F3FF
+    0xC245C4AF,  // "🙎🏻\u200d♀️" 1F64E-1F3FB-200D-2640-FE0F
+    0xDBA65CA5,  // "🙆🏻" 1F646-1F3FB
+    0xB65B29A0,  // "🙇🏿" 1F647-1F3FF
+    0x98670454,  // "🙍\u200d♀️" 1F64D-200D-2640-FE0F
+    0xE708D4C9,  // "🙍🏼\u200d♂️" 1F64D-1F3FC-200D-2642-FE0F
+    0xBE490388,  // "🙍🏿\u200d♀️" 1F64D-1F3FF-200D-2640-FE0F
+    0x8B68F36D,  // "🙎🏼" 1F64E-1F3FC
+    0xE2C25E39,  // "🙍🏻\u200d♀️" 1F64D-1F3FB-200D-2640-FE0F
+    0xB215B704,  // "🙋🏾\u200d♀️" 1F64B-1F3FE-200D-2640-FE0F
+    0x8AE7733F,  // "🙅🏿\u200d♀️" 1F645-1F3FF-200D-2640-FE0F
+    0xD0708BAD,  // "🙅🏼" 1F645-1F3FC
+    0xADDDD58F,  // "🙅🏾\u200d♀️" 1F645-1F3FE-200D-2640-FE0F
+    0xB097A8FA,  // "🙇🏿\u200d♂️" 1F647-1F3FF-200D-2642-FE0F
+    0xBD7178B9,  // "🙍🏼" 1F64D-1F3FC
+    0xE5304163,  // "🙆🏿\u200d♂️" 1F646-1F3FF-200D-2642-FE0F
+    0xFF6666B8,  // "🙇🏻" 1F647-1F3FB
+    0xF2C8F0EA,  // "🙆🏿" 1F646-1F3FF
+    0xFEDE8899,  // "🙇🏽" 1F647-1F3FD
+    0x85E86386,  // "🙏🏻" 1F64F-1F3FB
+    0xF1D935F8,  // "🙆🏽" 1F646-1F3FD
+    0x876B13DF,  // "🙏🏿" 1F64F-1F3FF
+    0xDD3D0EB8,  // "🙅🏽\u200d♀️" 1F645-1F3FD-200D-2640-FE0F
+    0xD4CE8527,  // "🙎🏻" 1F64E-1F3FB
+    0xFB51A522,  // "🙇🏽\u200d♂️" 1F647-1F3FD-200D-2642-FE0F
+    0xBDA52A26,  // "🙅\u200d♂️" 1F645-200D-2642-FE0F
+    0x98593661,  // "🙆\u200d♂️" 1F646-200D-2642-FE0F
+    0x9EF88E2A,  // "🙋\u200d♂️" 1F64B-200D-2642-FE0F
+    0xAF6CB9F3,  // "🙌🏿" 1F64C-1F3FF
+    0xEB7CCAEF,  // "🙅🏽\u200d♂️" 1F645-1F3FD-200D-2642-FE0F
+    0xA7873016,  // "🙆🏽\u200d♂️" 1F646-1F3FD-200D-2642-FE0F
+    0x9766753C,  // "🙍🏽\u200d♀️" 1F64D-1F3FD-200D-2640-FE0F
+    0xEABA5ADB,  // "🙌🏾" 1F64C-1F3FE
+    0xCF7E5E14,  // "🙎\u200d♂️" 1F64E-200D-2642-FE0F
+    0x940B70E2,  // "🙋🏼\u200d♂️" 1F64B-1F3FC-200D-2642-FE0F
+    0xF05FC6A1,  // "🙆🏻\u200d♀️" 1F646-1F3FB-200D-2640-FE0F
+    0xD743F7C1,  // "🙆🏼\u200d♀️" 1F646-1F3FC-200D-2640-FE0F
+    0xD77C776A,  // "🙇🏻\u200d♂️" 1F647-1F3FB-200D-2642-FE0F
+    0xA961B66D,  // "🙍🏼\u200d♀️" 1F64D-1F3FC-200D-2640-FE0F
+    0xC4EFF7E9,  // "🙍🏽" 1F64D-1F3FD
+    0x8CCB7FB1,  // "🙆🏻\u200d♂️" 1F646-1F3FB-200D-2642-FE0F
+    0x97D7E9D4,  // "🙌🏻" 1F64C-1F3FB
+    0xA36D4A08,  // "🙎🏿\u200d♂️" 1F64E-1F3FF-200D-2642-FE0F
+    0xBDE4C1B1,  // "🙍🏻" 1F64D-1F3FB
+    0xF6709F3E,  // "🙅🏻" 1F645-1F3FB
+    0xAC1D2D83,  // "🙋🏼" 1F64B-1F3FC
+    0xBB685084,  // "🙎🏾" 1F64E-1F3FE
+    0xFDA70BB5,  // "🙇🏿\u200d♀️" 1F647-1F3FF-200D-2640-FE0F
+    0xE98CE5E9,  // "🙎🏼\u200d♀️" 1F64E-1F3FC-200D-2640-FE0F
+    0x88F6AFCD,  // "🙅🏼\u200d♂️" 1F645-1F3FC-200D-2642-FE0F
+    0xD6B313DC,  // "🙅🏾\u200d♂️" 1F645-1F3FE-200D-2642-FE0F
+    0x8DE43F78,  // "🙇🏾\u200d♀️" 1F647-1F3FE-200D-2640-FE0F
+    0xB4B53856,  // "🙇🏼" 1F647-1F3FC
+    0xCC2FA7C2,  // "🙆🏾\u200d♂️" 1F646-1F3FE-200D-2642-FE0F
+    0xA5A5F889,  // "🙍🏽\u200d♂️" 1F64D-1F3FD-200D-2642-FE0F
+    0x8FA87716,  // "🙅\u200d♀️" 1F645-200D-2640-FE0F
+    0xABAE32A9,  // "🙎🏻\u200d♂️" 1F64E-1F3FB-200D-2642-FE0F
+    0x9A0C7CCF,  // "🙎🏿\u200d♀️" 1F64E-1F3FF-200D-2640-FE0F
+    0xEA575D0F,  // "🙎🏾\u200d♂️" 1F64E-1F3FE-200D-2642-FE0F
+    0xB7E733ED,  // "🙋🏿\u200d♂️" 1F64B-1F3FF-200D-2642-FE0F
+    0xC25E9DF9,  // "🙍🏾\u200d♂️" 1F64D-1F3FE-200D-2642-FE0F
+    0xBC3E38B0,  // "🙅🏻\u200d♀️" 1F645-1F3FB-200D-2640-FE0F
+    0x83353868,  // "🙅🏽" 1F645-1F3FD
+    0xE57D56F6,  // "🙋🏻\u200d♂️" 1F64B-1F3FB-200D-2642-FE0F
+    0xD7D7AB4F,  // "🙋🏾\u200d♂️" 1F64B-1F3FE-200D-2642-FE0F
+    0x81790A9C,  // "🙆\u200d♀️" 1F646-200D-2640-FE0F
+];
+
 /// Lookup table of blit pattern offsets; sort matches HASH_EMOTICONS
-const OFFSET_EMOTICONS: [usize; 193] = [
+static OFFSET_EMOTICONS: [usize; 113] = [
     54002, // "🙍🏿" 1F64D-1F3FF
     52695, // "🙇🏾" 1F647-1F3FE
-    49201, // "😄"
     54309, // "🙎🏾\u200d♀️" 1F64E-1F3FE-200D-2640-FE0F
     52438, // "🙇🏼\u200d♀️" 1F647-1F3FC-200D-2640-FE0F
     53885, // "🙍🏾\u200d♀️" 1F64D-1F3FE-200D-2640-FE0F
-    49484, // "😍"
-    49107, // "😁"
-    49360, // "😉"
     53091, // "🙋🏼\u200d♀️" 1F64B-1F3FC-200D-2640-FE0F
-    50107, // "😡"
-    50562, // "😰"
     51958, // "🙆🏽\u200d♀️" 1F646-1F3FD-200D-2640-FE0F
     52117, // "🙆🏾" 1F646-1F3FE
     52470, // "🙇🏼\u200d♂️" 1F647-1F3FC-200D-2642-FE0F
     53327, // "🙋🏿\u200d♀️" 1F64B-1F3FF-200D-2640-FE0F
-    52950, // "🙉"
     53301, // "🙋🏾" 1F64B-1F3FE
     53697, // "🙍🏻\u200d♂️" 1F64D-1F3FB-200D-2642-FE0F
     51622, // "🙅🏿\u200d♂️" 1F645-1F3FF-200D-2642-FE0F
-    53642, // "🙌"
     54239, // "🙎🏽\u200d♀️" 1F64E-1F3FD-200D-2640-FE0F
-    49702, // "😔"
     54615, // "🙏🏾" 1F64F-1F3FE
-    51743, // "🙅"
     54263, // "🙎🏽\u200d♂️" 1F64E-1F3FD-200D-2642-FE0F
     52341, // "🙇🏻\u200d♀️" 1F647-1F3FB-200D-2640-FE0F
     51559, // "🙅🏾" 1F645-1F3FE
     53546, // "🙌🏽" 1F64C-1F3FD
-    50718, // "😵"
     53222, // "🙋🏽" 1F64B-1F3FD
-    50411, // "😫"
     54552, // "🙏🏼" 1F64F-1F3FC
     49578, // "😐️" 1F610-FE0F
-    52919, // "🙈"
     53979, // "🙍🏿\u200d♂️" 1F64D-1F3FF-200D-2642-FE0F
-    49734, // "😕"
-    50533, // "😯"
     52663, // "🙇🏾\u200d♂️" 1F647-1F3FE-200D-2642-FE0F
     52535, // "🙇🏽\u200d♀️" 1F647-1F3FD-200D-2640-FE0F
     54583, // "🙏🏽" 1F64F-1F3FD
     53406, // "🙋\u200d♀️" 1F64B-200D-2640-FE0F
-    50970, // "😽"
-    50016, // "😞"
     53169, // "🙋🏽\u200d♀️" 1F64B-1F3FD-200D-2640-FE0F
-    49828, // "😘"
-    49608, // "😑"
-    49295, // "😇"
     52855, // "🙇\u200d♂️" 1F647-200D-2642-FE0F
-    50780, // "😷"
     51928, // "🙆🏼" 1F646-1F3FC
     53931, // "🙍🏾" 1F64D-1F3FE
-    49640, // "😒"
-    50321, // "😨"
     51654, // "🙅🏿" 1F645-1F3FF
     51897, // "🙆🏼\u200d♂️" 1F646-1F3FC-200D-2642-FE0F
-    50048, // "😟"
     51311, // "🙅🏼\u200d♀️" 1F645-1F3FC-200D-2640-FE0F
     51251, // "🙅🏻\u200d♂️" 1F645-1F3FB-200D-2642-FE0F
-    49890, // "😚"
-    50442, // "😬"
     53196, // "🙋🏽\u200d♂️" 1F64B-1F3FD-200D-2642-FE0F
     54451, // "🙎\u200d♀️" 1F64E-200D-2640-FE0F
     54286, // "🙎🏽" 1F64E-1F3FD
-    53459, // "🙋"
     54192, // "🙎🏼\u200d♂️" 1F64E-1F3FC-200D-2642-FE0F
-    50656, // "😳"
     53065, // "🙋🏻" 1F64B-1F3FB
-    50380, // "😪"
-    51094, // "🙁"
     52823, // "🙇\u200d♀️" 1F647-200D-2640-FE0F
     52149, // "🙆🏿\u200d♀️" 1F646-1F3FF-200D-2640-FE0F
     53514, // "🙌🏼" 1F64C-1F3FC
@@ -5687,237 +7268,275 @@ const OFFSET_EMOTICONS: [usize; 193] = [
     51835, // "🙆🏻" 1F646-1F3FB
     52791, // "🙇🏿" 1F647-1F3FF
     54026, // "🙍\u200d♀️" 1F64D-200D-2640-FE0F
-    49265, // "😆"
-    50908, // "😻"
     53768, // "🙍🏼\u200d♂️" 1F64D-1F3FC-200D-2642-FE0F
     53955, // "🙍🏿\u200d♀️" 1F64D-1F3FF-200D-2640-FE0F
     54215, // "🙎🏼" 1F64E-1F3FC
-    49922, // "😛"
-    49233, // "😅"
     53674, // "🙍🏻\u200d♀️" 1F64D-1F3FB-200D-2640-FE0F
-    54499, // "🙎"
     53248, // "🙋🏾\u200d♀️" 1F64B-1F3FE-200D-2640-FE0F
-    51002, // "😾"
-    50139, // "😢"
     51590, // "🙅🏿\u200d♀️" 1F645-1F3FF-200D-2640-FE0F
     51372, // "🙅🏼" 1F645-1F3FC
-    50940, // "😼"
     51496, // "🙅🏾\u200d♀️" 1F645-1F3FE-200D-2640-FE0F
     52759, // "🙇🏿\u200d♂️" 1F647-1F3FF-200D-2642-FE0F
-    50812, // "😸"
-    49860, // "😙"
     53792, // "🙍🏼" 1F64D-1F3FC
-    50261, // "😦"
     52181, // "🙆🏿\u200d♂️" 1F646-1F3FF-200D-2642-FE0F
-    49548, // "😏"
-    50750, // "😶"
     52405, // "🙇🏻" 1F647-1F3FB
-    49954, // "😜"
     52213, // "🙆🏿" 1F646-1F3FF
     52599, // "🙇🏽" 1F647-1F3FD
     54521, // "🙏🏻" 1F64F-1F3FB
-    49422, // "😋"
     52022, // "🙆🏽" 1F646-1F3FD
-    49454, // "😌"
-    49516, // "😎"
     54647, // "🙏🏿" 1F64F-1F3FF
-    49390, // "😊"
-    49578, // "😐"
     51402, // "🙅🏽\u200d♀️" 1F645-1F3FD-200D-2640-FE0F
     54144, // "🙎🏻" 1F64E-1F3FB
     52567, // "🙇🏽\u200d♂️" 1F647-1F3FD-200D-2642-FE0F
     51714, // "🙅\u200d♂️" 1F645-200D-2642-FE0F
-    52887, // "🙇"
     52277, // "🙆\u200d♂️" 1F646-200D-2642-FE0F
     53433, // "🙋\u200d♂️" 1F64B-200D-2642-FE0F
     53610, // "🙌🏿" 1F64C-1F3FF
     51434, // "🙅🏽\u200d♂️" 1F645-1F3FD-200D-2642-FE0F
     51990, // "🙆🏽\u200d♂️" 1F646-1F3FD-200D-2642-FE0F
     53814, // "🙍🏽\u200d♀️" 1F64D-1F3FD-200D-2640-FE0F
-    50502, // "😮"
-    49139, // "😂"
     53578, // "🙌🏾" 1F64C-1F3FE
-    50199, // "😤"
     54475, // "🙎\u200d♂️" 1F64E-200D-2642-FE0F
-    51188, // "🙄"
     53117, // "🙋🏼\u200d♂️" 1F64B-1F3FC-200D-2642-FE0F
-    51034, // "😿"
     51771, // "🙆🏻\u200d♀️" 1F646-1F3FB-200D-2640-FE0F
     51866, // "🙆🏼\u200d♀️" 1F646-1F3FC-200D-2640-FE0F
-    49796, // "😗"
     52373, // "🙇🏻\u200d♂️" 1F647-1F3FB-200D-2642-FE0F
     53745, // "🙍🏼\u200d♀️" 1F64D-1F3FC-200D-2640-FE0F
-    51126, // "🙂"
     53861, // "🙍🏽" 1F64D-1F3FD
-    51158, // "🙃"
     51803, // "🙆🏻\u200d♂️" 1F646-1F3FB-200D-2642-FE0F
-    49672, // "😓"
     53485, // "🙌🏻" 1F64C-1F3FB
-    49766, // "😖"
-    49077, // "😀"
-    50686, // "😴"
     54404, // "🙎🏿\u200d♂️" 1F64E-1F3FF-200D-2642-FE0F
-    50471, // "😭"
     53721, // "🙍🏻" 1F64D-1F3FB
     51281, // "🙅🏻" 1F645-1F3FB
     53143, // "🙋🏼" 1F64B-1F3FC
-    50876, // "😺"
-    54073, // "🙍"
     54357, // "🙎🏾" 1F64E-1F3FE
-    49171, // "😃"
-    50844, // "😹"
     52727, // "🙇🏿\u200d♀️" 1F647-1F3FF-200D-2640-FE0F
     54168, // "🙎🏼\u200d♀️" 1F64E-1F3FC-200D-2640-FE0F
     51342, // "🙅🏼\u200d♂️" 1F645-1F3FC-200D-2642-FE0F
     51528, // "🙅🏾\u200d♂️" 1F645-1F3FE-200D-2642-FE0F
     52631, // "🙇🏾\u200d♀️" 1F647-1F3FE-200D-2640-FE0F
-    49327, // "😈"
     52502, // "🙇🏼" 1F647-1F3FC
-    52981, // "🙊"
-    50230, // "😥"
-    50351, // "😩"
-    54679, // "🙏"
     52085, // "🙆🏾\u200d♂️" 1F646-1F3FE-200D-2642-FE0F
     53837, // "🙍🏽\u200d♂️" 1F64D-1F3FD-200D-2642-FE0F
-    52309, // "🙆"
     51685, // "🙅\u200d♀️" 1F645-200D-2640-FE0F
     54121, // "🙎🏻\u200d♂️" 1F64E-1F3FB-200D-2642-FE0F
-    51064, // "🙀"
-    50170, // "😣"
     54380, // "🙎🏿\u200d♀️" 1F64E-1F3FF-200D-2640-FE0F
     54333, // "🙎🏾\u200d♂️" 1F64E-1F3FE-200D-2642-FE0F
     53354, // "🙋🏿\u200d♂️" 1F64B-1F3FF-200D-2642-FE0F
-    50592, // "😱"
     53908, // "🙍🏾\u200d♂️" 1F64D-1F3FE-200D-2642-FE0F
-    50078, // "😠"
     51220, // "🙅🏻\u200d♀️" 1F645-1F3FB-200D-2640-FE0F
-    50624, // "😲"
-    50290, // "😧"
-    49984, // "😝"
     51464, // "🙅🏽" 1F645-1F3FD
     53039, // "🙋🏻\u200d♂️" 1F64B-1F3FB-200D-2642-FE0F
     53275, // "🙋🏾\u200d♂️" 1F64B-1F3FE-200D-2642-FE0F
     52245, // "🙆\u200d♀️" 1F646-200D-2640-FE0F
 ];
 
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_transport_and_map_symbols_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_TRANSPORT_AND_MAP_SYMBOLS, 0x1F680, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x1F680..=0x1F6FC, or NO_GLYPH
+static CODEPOINT_TRANSPORT_AND_MAP_SYMBOLS: [u32; 125] = [
+    54710, // "🚀"
+    54742, // "🚁"
+    54769, // "🚂"
+    54799, // "🚃"
+    54820, // "🚄"
+    54846, // "🚅"
+    54873, // "🚆"
+    54902, // "🚇"
+    54935, // "🚈"
+    54963, // "🚉"
+    54994, // "🚊"
+    55021, // "🚋"
+    55054, // "🚌"
+    55077, // "🚍"
+    55109, // "🚎"
+    55138, // "🚏"
+    55158, // "🚐"
+    55183, // "🚑"
+    55211, // "🚒"
+    55236, // "🚓"
+    55258, // "🚔"
+    55288, // "🚕"
+    55307, // "🚖"
+    55334, // "🚗"
+    55354, // "🚘"
+    55382, // "🚙"
+    55404, // "🚚"
+    55429, // "🚛"
+    55454, // "🚜"
+    55482, // "🚝"
+    55514, // "🚞"
+    55546, // "🚟"
+    55578, // "🚠"
+    55607, // "🚡"
+    55635, // "🚢"
+    56128, // "🚣"
+    56155, // "🚤"
+    56176, // "🚥"
+    56193, // "🚦"
+    56210, // "🚧"
+    56237, // "🚨"
+    56269, // "🚩"
+    56291, // "🚪"
+    56312, // "🚫"
+    56345, // "🚬"
+    56372, // "🚭"
+    56405, // "🚮"
+    56437, // "🚯"
+    56470, // "🚰"
+    56502, // "🚱"
+    56535, // "🚲"
+    56558, // "🚳"
+    57129, // "🚴"
+    57695, // "🚵"
+    57995, // "🚶"
+    58010, // "🚷"
+    58043, // "🚸"
+    58072, // "🚹"
+    58104, // "🚺"
+    58137, // "🚻"
+    58169, // "🚼"
+    58201, // "🚽"
+    58226, // "🚾"
+    58259, // "🚿"
+    58438, // "🛀"
+    58468, // "🛁"
+    58498, // "🛂"
+    58531, // "🛃"
+    58563, // "🛄"
+    58595, // "🛅"
+    super::NO_GLYPH,  // U+1F6C6
+    super::NO_GLYPH,  // U+1F6C7
+    super::NO_GLYPH,  // U+1F6C8
+    super::NO_GLYPH,  // U+1F6C9
+    super::NO_GLYPH,  // U+1F6CA
+    58628, // "🛋"
+    58763, // "🛌"
+    58784, // "🛍"
+    58815, // "🛎"
+    58839, // "🛏"
+    58860, // "🛐"
+    58892, // "🛑"
+    58920, // "🛒"
+    super::NO_GLYPH,  // U+1F6D3
+    super::NO_GLYPH,  // U+1F6D4
+    58946, // "🛕"
+    58975, // "🛖"
+    59003, // "🛗"
+    super::NO_GLYPH,  // U+1F6D8
+    super::NO_GLYPH,  // U+1F6D9
+    super::NO_GLYPH,  // U+1F6DA
+    super::NO_GLYPH,  // U+1F6DB
+    super::NO_GLYPH,  // U+1F6DC
+    super::NO_GLYPH,  // U+1F6DD
+    super::NO_GLYPH,  // U+1F6DE
+    super::NO_GLYPH,  // U+1F6DF
+    59036, // "🛠"
+    59067, // "🛡"
+    59094, // "🛢"
+    59115, // "🛣"
+    59147, // "🛤"
+    59179, // "🛥"
+    super::NO_GLYPH,  // U+1F6E6
+    super::NO_GLYPH,  // U+1F6E7
+    super::NO_GLYPH,  // U+1F6E8
+    59199, // "🛩"
+    super::NO_GLYPH,  // U+1F6EA
+    59230, // "🛫"
+    59259, // "🛬"
+    super::NO_GLYPH,  // U+1F6ED
+    super::NO_GLYPH,  // U+1F6EE
+    super::NO_GLYPH,  // U+1F6EF
+    59291, // "🛰"
+    super::NO_GLYPH,  // U+1F6F1
+    super::NO_GLYPH,  // U+1F6F2
+    59322, // "🛳"
+    59349, // "🛴"
+    59381, // "🛵"
+    59405, // "🛶"
+    59424, // "🛷"
+    59448, // "🛸"
+    59477, // "🛹"
+    59506, // "🛺"
+    59533, // "🛻"
+    59554, // "🛼"
+];
+
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_transport_and_map_symbols(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_TRANSPORT_AND_MAP_SYMBOLS.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_TRANSPORT_AND_MAP_SYMBOLS[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_TRANSPORT_AND_MAP_SYMBOLS.binary_search(&hash) {
+        Ok(index) if KEY_TRANSPORT_AND_MAP_SYMBOLS[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_TRANSPORT_AND_MAP_SYMBOLS[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_TRANSPORT_AND_MAP_SYMBOLS
-const HASH_TRANSPORT_AND_MAP_SYMBOLS: [u32; 202] = [
+static HASH_TRANSPORT_AND_MAP_SYMBOLS: [u32; 101] = [
     0x008770F4,  // "🚑️" 1F691-FE0F
-    0x025FC370,  // "🚼"
     0x03CFF866,  // "🚵🏽\u200d♂️" 1F6B5-1F3FD-200D-2642-FE0F
-    0x046DB145,  // "🚌"
-    0x05DBD6AF,  // "🚣"
-    0x06872089,  // "🚊"
     0x06DF90B6,  // "🚶🏾" 1F6B6-1F3FE
     0x071C011C,  // "🚶🏾\u200d♂️" 1F6B6-1F3FE-200D-2642-FE0F
-    0x074D3916,  // "🚱"
     0x082EC9F1,  // "🚴🏽\u200d♂️" 1F6B4-1F3FD-200D-2642-FE0F
     0x08A9F846,  // "🚣🏽" 1F6A3-1F3FD
-    0x08CC3259,  // "🛣"
     0x0932B278,  // "🛀🏾" 1F6C0-1F3FE
-    0x0B5E52E7,  // "🛺"
-    0x0CA82272,  // "🛢"
-    0x0DC7F440,  // "🚃"
     0x0F3A5893,  // "🚵\u200d♂️" 1F6B5-200D-2642-FE0F
     0x11B3D3A0,  // "🚴🏾" 1F6B4-1F3FE
-    0x11EF40AA,  // "🚍"
     0x12EC7461,  // "🚶🏼\u200d♂️" 1F6B6-1F3FC-200D-2642-FE0F
     0x14C83385,  // "🚘️" 1F698-FE0F
-    0x15A8AB02,  // "🚬"
     0x164FCD58,  // "🚶🏽" 1F6B6-1F3FD
-    0x16A1023F,  // "🚅"
-    0x18418F53,  // "🛸"
-    0x18A03EF5,  // "🛑"
-    0x195C2FE4,  // "🛠"
-    0x19696D55,  // "🚯"
-    0x1B0AF734,  // "🚔"
     0x1C524F6B,  // "🚣🏾\u200d♂️" 1F6A3-1F3FE-200D-2642-FE0F
-    0x1E1A33DB,  // "🛡"
-    0x1EDD946B,  // "🛴"
     0x1EECCB19,  // "🛏️" 1F6CF-FE0F
     0x1F80DB6C,  // "🚵🏾\u200d♀️" 1F6B5-1F3FE-200D-2640-FE0F
     0x1FFFC6CE,  // "🛩️" 1F6E9-FE0F
     0x20A2942E,  // "🚣🏾" 1F6A3-1F3FE
     0x22538BEA,  // "🚶🏿" 1F6B6-1F3FF
     0x23176FE7,  // "🚴🏾\u200d♀️" 1F6B4-1F3FE-200D-2640-FE0F
-    0x24A35A8A,  // "🚩"
-    0x275CFB77,  // "🚤"
     0x28E42041,  // "🚣🏿\u200d♀️" 1F6A3-1F3FF-200D-2640-FE0F
-    0x28F19432,  // "🛷"
     0x28F778A4,  // "🚶🏻\u200d♀️" 1F6B6-1F3FB-200D-2640-FE0F
     0x2970C951,  // "🚴🏼" 1F6B4-1F3FC
-    0x2AC4F147,  // "🚻"
     0x2BDE97BE,  // "🛥️" 1F6E5-FE0F
-    0x2F36F6D1,  // "🚝"
     0x2FE495CA,  // "🚴🏿\u200d♀️" 1F6B4-1F3FF-200D-2640-FE0F
     0x3234312E,  // "🚵🏽" 1F6B5-1F3FD
-    0x3234F795,  // "🚺"
-    0x32F02761,  // "🚽"
-    0x33A0F5EF,  // "🚎"
     0x33EE4EA7,  // "🛰️" 1F6F0-FE0F
     0x34A4E7BF,  // "🚍️" 1F68D-FE0F
-    0x39A87F5B,  // "🚖"
-    0x3ADD5B8D,  // "🚶"
-    0x3BB1123E,  // "🚕"
     0x3C9AEB17,  // "🚴🏿\u200d♂️" 1F6B4-1F3FF-200D-2642-FE0F
     0x3D36482B,  // "🛀🏼" 1F6C0-1F3FC
     0x3DDD08AC,  // "🚶\u200d♂️" 1F6B6-200D-2642-FE0F
-    0x3F257F1B,  // "🚁"
-    0x3F4C92E2,  // "🚄"
     0x416788FE,  // "🛌🏽" 1F6CC-1F3FD
     0x416DA8C4,  // "🚵🏻" 1F6B5-1F3FB
     0x43B77C62,  // "🚣\u200d♂️" 1F6A3-200D-2642-FE0F
     0x443F2FC4,  // "🚵🏿" 1F6B5-1F3FF
-    0x45BDD416,  // "🚐"
-    0x45E18F3A,  // "🚿"
-    0x46458DA4,  // "🛰"
     0x493CCA72,  // "🚴\u200d♂️" 1F6B4-200D-2642-FE0F
-    0x4A08F6C4,  // "🛥"
     0x4B9D464F,  // "🚣🏻\u200d♂️" 1F6A3-1F3FB-200D-2642-FE0F
-    0x4CD8B1E1,  // "🛄"
-    0x4D4E3320,  // "🚭"
     0x4FBD5C51,  // "🚣🏼" 1F6A3-1F3FC
-    0x5031122F,  // "🛃"
-    0x5117080D,  // "🚇"
-    0x51641E58,  // "🚒"
     0x53146F6A,  // "🚹️" 1F6B9-FE0F
-    0x54299EB4,  // "🚮"
     0x5590BBDE,  // "🛍️" 1F6CD-FE0F
     0x572FA28E,  // "🚶🏻" 1F6B6-1F3FB
-    0x57F7D479,  // "🛗"
     0x5874FC29,  // "🚣\u200d♀️" 1F6A3-200D-2640-FE0F
     0x58E883E7,  // "🚴🏻\u200d♀️" 1F6B4-1F3FB-200D-2640-FE0F
-    0x5B1BD1BA,  // "🛩"
     0x5BEB3804,  // "🚣🏿\u200d♂️" 1F6A3-1F3FF-200D-2642-FE0F
     0x5C840416,  // "🚣🏻\u200d♀️" 1F6A3-1F3FB-200D-2640-FE0F
-    0x5DD369F2,  // "🛶"
     0x5E3C7A30,  // "🚴🏽\u200d♀️" 1F6B4-1F3FD-200D-2640-FE0F
     0x5ED006D8,  // "🚵🏿\u200d♀️" 1F6B5-1F3FF-200D-2640-FE0F
     0x5F532674,  // "🛀🏻" 1F6C0-1F3FB
-    0x641020FD,  // "🛤"
     0x643B7844,  // "🛠️" 1F6E0-FE0F
-    0x68BD8EBA,  // "🚳"
     0x68E201C0,  // "🚼️" 1F6BC-FE0F
     0x6A0A9031,  // "🛌🏼" 1F6CC-1F3FC
-    0x6A85411B,  // "🛎"
     0x6F2D85BE,  // "🚣🏿" 1F6A3-1F3FF
-    0x6F68CFCB,  // "🛀"
-    0x710D58EB,  // "🚛"
     0x72C7233B,  // "🛤️" 1F6E4-FE0F
-    0x73F5AD51,  // "🛐"
-    0x73FD80D8,  // "🚫"
-    0x754A1FE1,  // "🚙"
-    0x7742479E,  // "🛬"
     0x7AE22221,  // "🛢️" 1F6E2-FE0F
     0x7BE9269B,  // "🛡️" 1F6E1-FE0F
     0x7FDF1F48,  // "🚴\u200d♀️" 1F6B4-200D-2640-FE0F
@@ -5928,202 +7547,206 @@ const HASH_TRANSPORT_AND_MAP_SYMBOLS: [u32; 202] = [
     0x8923D361,  // "🚵🏼\u200d♂️" 1F6B5-1F3FC-200D-2642-FE0F
     0x8A2B0DBF,  // "🚴🏻" 1F6B4-1F3FB
     0x8ABA293B,  // "🚣🏽\u200d♀️" 1F6A3-1F3FD-200D-2640-FE0F
-    0x8BA0156D,  // "🛵"
     0x8CF9FBF8,  // "🚺️" 1F6BA-FE0F
-    0x8DEF363D,  // "🚦"
     0x8E032EDA,  // "🚶🏿\u200d♂️" 1F6B6-1F3FF-200D-2642-FE0F
-    0x8F9C5DF8,  // "🚏"
-    0x914A3DB4,  // "🚜"
     0x92820B18,  // "🚶🏼\u200d♀️" 1F6B6-1F3FC-200D-2640-FE0F
-    0x9583FC07,  // "🚟"
-    0x97456CDE,  // "🚓"
-    0x9840A988,  // "🛏"
-    0x9B7C206E,  // "🚹"
-    0x9CA78196,  // "🛅"
-    0xA3DF547A,  // "🛻"
     0xA594E73B,  // "🛣️" 1F6E3-FE0F
     0xA5B1A73C,  // "🚭️" 1F6AD-FE0F
-    0xA6423163,  // "🚑"
-    0xA669F108,  // "🛌"
-    0xA712559C,  // "🚡"
-    0xA873C6E2,  // "🚰"
     0xADDE0B60,  // "🚣🏽\u200d♂️" 1F6A3-1F3FD-200D-2642-FE0F
     0xADE85BC6,  // "🚵🏻\u200d♀️" 1F6B5-1F3FB-200D-2640-FE0F
-    0xAE0282FA,  // "🚨"
     0xAF3E4B59,  // "🚔️" 1F694-FE0F
-    0xAF4F45BD,  // "🛫"
-    0xB4F31348,  // "🛍"
     0xB6567B64,  // "🚶\u200d♀️" 1F6B6-200D-2640-FE0F
-    0xB97F1BA5,  // "🚞"
     0xBB05B6FE,  // "🚴🏼\u200d♀️" 1F6B4-1F3FC-200D-2640-FE0F
     0xBB451F03,  // "🚶🏿\u200d♀️" 1F6B6-1F3FF-200D-2640-FE0F
-    0xBB45A574,  // "🚘"
     0xBE8F18BF,  // "🚴🏿" 1F6B4-1F3FF
-    0xBFEFF6BD,  // "🚈"
-    0xC02CF32B,  // "🚸"
-    0xC0FB76A9,  // "🛂"
-    0xC16BB1CE,  // "🛹"
-    0xC1A8E441,  // "🚪"
     0xC274F23B,  // "🚵🏿\u200d♂️" 1F6B5-1F3FF-200D-2642-FE0F
     0xC58E6488,  // "🚣🏻" 1F6A3-1F3FB
     0xC66BF9BB,  // "🚇️" 1F687-FE0F
     0xC7BC55D5,  // "🚶🏾\u200d♀️" 1F6B6-1F3FE-200D-2640-FE0F
     0xC7DB8B90,  // "🚵🏾\u200d♂️" 1F6B5-1F3FE-200D-2642-FE0F
-    0xC96500DF,  // "🚆"
     0xC99F6D05,  // "🚶🏽\u200d♂️" 1F6B6-1F3FD-200D-2642-FE0F
-    0xCAA213BD,  // "🚂"
-    0xCDD327B2,  // "🚢"
     0xCE70DAC3,  // "🚵🏻\u200d♂️" 1F6B5-1F3FB-200D-2642-FE0F
-    0xD2DB81EC,  // "🚲"
-    0xD388D21E,  // "🛳"
     0xD3BBC2A2,  // "🚲️" 1F6B2-FE0F
     0xD4467C1E,  // "🚵🏽\u200d♀️" 1F6B5-1F3FD-200D-2640-FE0F
-    0xD6EABE45,  // "🚵"
-    0xD749BFC9,  // "🚾"
     0xD86AF330,  // "🛀🏿" 1F6C0-1F3FF
-    0xD89AC45B,  // "🚠"
-    0xD9996120,  // "🚴"
     0xD9DAA5A6,  // "🚵🏾" 1F6B5-1F3FE
     0xDCD66728,  // "🚵🏼\u200d♀️" 1F6B5-1F3FC-200D-2640-FE0F
-    0xDD8D43B5,  // "🚧"
-    0xDE8BBF98,  // "🚗"
     0xDF6F604D,  // "🛀🏽" 1F6C0-1F3FD
-    0xE036F457,  // "🚋"
-    0xE0467484,  // "🛋"
     0xE0BABCAD,  // "🚶🏼" 1F6B6-1F3FC
     0xE150BED1,  // "🛋️" 1F6CB-FE0F
     0xE4A19C97,  // "🚶🏽\u200d♀️" 1F6B6-1F3FD-200D-2640-FE0F
-    0xE51D4A54,  // "🚀"
-    0xE5DD8C6B,  // "🛼"
     0xEA779037,  // "🚣🏼\u200d♂️" 1F6A3-1F3FC-200D-2642-FE0F
     0xEAFABF90,  // "🚵🏼" 1F6B5-1F3FC
-    0xEB74528C,  // "🚷"
     0xEBC04CE9,  // "🚵\u200d♀️" 1F6B5-200D-2640-FE0F
-    0xEC1A371F,  // "🚚"
     0xEC8CF419,  // "🛌🏿" 1F6CC-1F3FF
-    0xEF0FB5D5,  // "🚥"
     0xF04C6A10,  // "🚴🏾\u200d♂️" 1F6B4-1F3FE-200D-2642-FE0F
     0xF22B9A22,  // "🚣🏼\u200d♀️" 1F6A3-1F3FC-200D-2640-FE0F
     0xF42AFA2D,  // "🚣🏾\u200d♀️" 1F6A3-1F3FE-200D-2640-FE0F
     0xF4480EB4,  // "🚶🏻\u200d♂️" 1F6B6-1F3FB-200D-2642-FE0F
-    0xF544AB29,  // "🛖"
     0xF802D37D,  // "🚴🏻\u200d♂️" 1F6B4-1F3FB-200D-2642-FE0F
-    0xF80EF49C,  // "🛒"
-    0xF8C121C6,  // "🚉"
     0xF94AB6DA,  // "🚴🏽" 1F6B4-1F3FD
     0xFA667B82,  // "🛌🏾" 1F6CC-1F3FE
-    0xFC6A8268,  // "🛕"
-    0xFF88C47F,  // "🛁"
+];
+
+/// Verification keys to rule out hash collisions; sort matches HASH_TRANSPORT_AND_MAP_SYMBOLS
+static KEY_TRANSPORT_AND_MAP_SYMBOLS: [u32; 101] = [
+    0xD0CAF094,  // "🚑️" 1F691-FE0F
+    0xD5E1EE9E,  // "🚵🏽\u200d♂️" 1F6B5-1F3FD-200D-2642-FE0F
+    0xAF9592B9,  // "🚶🏾" 1F6B6-1F3FE
+    0xEA14ED97,  // "🚶🏾\u200d♂️" 1F6B6-1F3FE-200D-2642-FE0F
+    0xD1D70FE9,  // "🚴🏽\u200d♂️" 1F6B4-1F3FD-200D-2642-FE0F
+    0xFF2F1441,  // "🚣🏽" 1F6A3-1F3FD
+    0xC46310F9,  // "🛀🏾" 1F6C0-1F3FE
+    0x9F62D341,  // "🚵\u200d♂️" 1F6B5-200D-2642-FE0F
+    0xBCE724A6,  // "🚴🏾" 1F6B4-1F3FE
+    0xD0350ACE,  // "🚶🏼\u200d♂️" 1F6B6-1F3FC-200D-2642-FE0F
+    0xED1B9E3D,  // "🚘️" 1F698-FE0F
+    0x85BC73EE,  // "🚶🏽" 1F6B6-1F3FD
+    0xDFE803AA,  // "🚣🏾\u200d♂️" 1F6A3-1F3FE-200D-2642-FE0F
+    0x9CE5CA69,  // "🛏️" 1F6CF-FE0F
+    0xA922D85F,  // "🚵🏾\u200d♀️" 1F6B5-1F3FE-200D-2640-FE0F
+    0xAEED1166,  // "🛩️" 1F6E9-FE0F
+    0xDC30FF74,  // "🚣🏾" 1F6A3-1F3FE
+    0xD54844A9,  // "🚶🏿" 1F6B6-1F3FF
+    0xBE44FD4C,  // "🚴🏾\u200d♀️" 1F6B4-1F3FE-200D-2640-FE0F
+    0xD29F06EF,  // "🚣🏿\u200d♀️" 1F6A3-1F3FF-200D-2640-FE0F
+    0x88D5BC61,  // "🚶🏻\u200d♀️" 1F6B6-1F3FB-200D-2640-FE0F
+    0xEFC451B7,  // "🚴🏼" 1F6B4-1F3FC
+    0xDD48F6B6,  // "🛥️" 1F6E5-FE0F
+    0x80906596,  // "🚴🏿\u200d♀️" 1F6B4-1F3FF-200D-2640-FE0F
+    0xB258F6F5,  // "🚵🏽" 1F6B5-1F3FD
+    0x822705C6,  // "🛰️" 1F6F0-FE0F
+    0xC8C5440A,  // "🚍️" 1F68D-FE0F
+    0xD99798A3,  // "🚴🏿\u200d♂️" 1F6B4-1F3FF-200D-2642-FE0F
+    0x97F04798,  // "🛀🏼" 1F6C0-1F3FC
+    0xD33F0ED4,  // "🚶\u200d♂️" 1F6B6-200D-2642-FE0F
+    0xDE0B5362,  // "🛌🏽" 1F6CC-1F3FD
+    0xC04E06FE,  // "🚵🏻" 1F6B5-1F3FB
+    0xA838F080,  // "🚣\u200d♂️" 1F6A3-200D-2642-FE0F
+    0xC28EE4E8,  // "🚵🏿" 1F6B5-1F3FF
+    0xB35DE59A,  // "🚴\u200d♂️" 1F6B4-200D-2642-FE0F
+    0xAF47F501,  // "🚣🏻\u200d♂️" 1F6A3-1F3FB-200D-2642-FE0F
+    0x94B1D386,  // "🚣🏼" 1F6A3-1F3FC
+    0x9BE1C606,  // "🚹️" 1F6B9-FE0F
+    0xD2C4C57C,  // "🛍️" 1F6CD-FE0F
+    0xA4C87EC5,  // "🚶🏻" 1F6B6-1F3FB
+    0xC9E51F7D,  // "🚣\u200d♀️" 1F6A3-200D-2640-FE0F
+    0xE848BEE1,  // "🚴🏻\u200d♀️" 1F6B4-1F3FB-200D-2640-FE0F
+    0xCEDF644D,  // "🚣🏿\u200d♂️" 1F6A3-1F3FF-200D-2642-FE0F
+    0xB06EEE3F,  // "🚣🏻\u200d♀️" 1F6A3-1F3FB-200D-2640-FE0F
+    0xCD85DFFE,  // "🚴🏽\u200d♀️" 1F6B4-1F3FD-200D-2640-FE0F
+    0x8112B3B3,  // "🚵🏿\u200d♀️" 1F6B5-1F3FF-200D-2640-FE0F
+    0xB98B58C4,  // "🛀🏻" 1F6C0-1F3FB
+    0x89748159,  // "🛠️" 1F6E0-FE0F
+    0xF21CD799,  // "🚼️" 1F6BC-FE0F
+    0xF62014F3,  // "🛌🏼" 1F6CC-1F3FC
+    0xAD67C3D5,  // "🚣🏿" 1F6A3-1F3FF
+    0xBC25BD91,  // "🛤️" 1F6E4-FE0F
+    0x930898AE,  // "🛢️" 1F6E2-FE0F
+    0xEF93221A,  // "🛡️" 1F6E1-FE0F
+    0xD4CF922B,  // "🚴\u200d♀️" 1F6B4-200D-2640-FE0F
+    0xA485C247,  // "🛌🏻" 1F6CC-1F3FB
+    0xF09F93D5,  // "🛳️" 1F6F3-FE0F
+    0x892E4B6B,  // "🚴🏼\u200d♂️" 1F6B4-1F3FC-200D-2642-FE0F
+    0x80089EEC,  // "🛎️" 1F6CE-FE0F
+    0xE10D2D95,  // "🚵🏼\u200d♂️" 1F6B5-1F3FC-200D-2642-FE0F
+    0xAAA3A14E,  // "🚴🏻" 1F6B4-1F3FB
+    0xECC8BCA7,  // "🚣🏽\u200d♀️" 1F6A3-1F3FD-200D-2640-FE0F
+    0xF37B156D,  // "🚺️" 1F6BA-FE0F
+    0xEA19CA5F,  // "🚶🏿\u200d♂️" 1F6B6-1F3FF-200D-2642-FE0F
+    0xD1F3F89D,  // "🚶🏼\u200d♀️" 1F6B6-1F3FC-200D-2640-FE0F
+    0xED152531,  // "🛣️" 1F6E3-FE0F
+    0xE55B2944,  // "🚭️" 1F6AD-FE0F
+    0xD2F3688A,  // "🚣🏽\u200d♂️" 1F6A3-1F3FD-200D-2642-FE0F
+    0xB19C3CBE,  // "🚵🏻\u200d♀️" 1F6B5-1F3FB-200D-2640-FE0F
+    0x917CB612,  // "🚔️" 1F694-FE0F
+    0xAB6DDF63,  // "🚶\u200d♀️" 1F6B6-200D-2640-FE0F
+    0xA40D7E74,  // "🚴🏼\u200d♀️" 1F6B4-1F3FC-200D-2640-FE0F
+    0xFE32BD04,  // "🚶🏿\u200d♀️" 1F6B6-1F3FF-200D-2640-FE0F
+    0xC717C5E7,  // "🚴🏿" 1F6B4-1F3FF
+    0xDF7AB72B,  // "🚵🏿\u200d♂️" 1F6B5-1F3FF-200D-2642-FE0F
+    0xC00C591D,  // "🚣🏻" 1F6A3-1F3FB
+    0xA810B1EC,  // "🚇️" 1F687-FE0F
+    0x9F853CCA,  // "🚶🏾\u200d♀️" 1F6B6-1F3FE-200D-2640-FE0F
+    0xA8379887,  // "🚵🏾\u200d♂️" 1F6B5-1F3FE-200D-2642-FE0F
+    0x9B9D2917,  // "🚶🏽\u200d♂️" 1F6B6-1F3FD-200D-2642-FE0F
+    0xF9DFEFB5,  // "🚵🏻\u200d♂️" 1F6B5-1F3FB-200D-2642-FE0F
+    0xEC2E1E61,  // "🚲️" 1F6B2-FE0F
+    0xA34BAC8A,  // "🚵🏽\u200d♀️" 1F6B5-1F3FD-200D-2640-FE0F
+    0x9F22CA1F,  // "🛀🏿" 1F6C0-1F3FF
+    0xA15618ED,  // "🚵🏾" 1F6B5-1F3FE
+    0xC1ED4307,  // "🚵🏼\u200d♀️" 1F6B5-1F3FC-200D-2640-FE0F
+    0x96844AA4,  // "🛀🏽" 1F6C0-1F3FD
+    0xCB37BA41,  // "🚶🏼" 1F6B6-1F3FC
+    0xD39F7A74,  // "🛋️" 1F6CB-FE0F
+    0xE4BBBE0B,  // "🚶🏽\u200d♀️" 1F6B6-1F3FD-200D-2640-FE0F
+    0xA3640E15,  // "🚣🏼\u200d♂️" 1F6A3-1F3FC-200D-2642-FE0F
+    0xFBEF4E11,  // "🚵🏼" 1F6B5-1F3FC
+    0xFC1177AE,  // "🚵\u200d♀️" 1F6B5-200D-2640-FE0F
+    0x9F98106C,  // "🛌🏿" 1F6CC-1F3FF
+    0xB453D99C,  // "🚴🏾\u200d♂️" 1F6B4-1F3FE-200D-2642-FE0F
+    0xBB03AEEB,  // "🚣🏼\u200d♀️" 1F6A3-1F3FC-200D-2640-FE0F
+    0xC666396C,  // "🚣🏾\u200d♀️" 1F6A3-1F3FE-200D-2640-FE0F
+    0xB0923B51,  // "🚶🏻\u200d♂️" 1F6B6-1F3FB-200D-2642-FE0F
+    0xE8773664,  // "🚴🏻\u200d♂️" 1F6B4-1F3FB-200D-2642-FE0F
+    0x81F5955C,  // "🚴🏽" 1F6B4-1F3FD
+    0xDDA676E0,  // "🛌🏾" 1F6CC-1F3FE
 ];
 
 /// Lookup table of blit pattern offsets; sort matches HASH_TRANSPORT_AND_MAP_SYMBOLS
-const OFFSET_TRANSPORT_AND_MAP_SYMBOLS: [usize; 202] = [
+static OFFSET_TRANSPORT_AND_MAP_SYMBOLS: [usize; 101] = [
     55183, // "🚑️" 1F691-FE0F
-    58169, // "🚼"
     57380, // "🚵🏽\u200d♂️" 1F6B5-1F3FD-200D-2642-FE0F
-    55054, // "🚌"
-    56128, // "🚣"
-    54994, // "🚊"
     57899, // "🚶🏾" 1F6B6-1F3FE
     57883, // "🚶🏾\u200d♂️" 1F6B6-1F3FE-200D-2642-FE0F
-    56502, // "🚱"
     56815, // "🚴🏽\u200d♂️" 1F6B4-1F3FD-200D-2642-FE0F
     55883, // "🚣🏽" 1F6A3-1F3FD
-    59115, // "🛣"
     58379, // "🛀🏾" 1F6C0-1F3FE
-    59506, // "🛺"
-    59094, // "🛢"
-    54799, // "🚃"
     57663, // "🚵\u200d♂️" 1F6B5-200D-2642-FE0F
     56943, // "🚴🏾" 1F6B4-1F3FE
-    55077, // "🚍"
     57789, // "🚶🏼\u200d♂️" 1F6B6-1F3FC-200D-2642-FE0F
     55354, // "🚘️" 1F698-FE0F
-    56345, // "🚬"
     57851, // "🚶🏽" 1F6B6-1F3FD
-    54846, // "🚅"
-    59448, // "🛸"
-    58892, // "🛑"
-    59036, // "🛠"
-    56437, // "🚯"
-    55258, // "🚔"
     55937, // "🚣🏾\u200d♂️" 1F6A3-1F3FE-200D-2642-FE0F
-    59067, // "🛡"
-    59349, // "🛴"
     58839, // "🛏️" 1F6CF-FE0F
     57442, // "🚵🏾\u200d♀️" 1F6B5-1F3FE-200D-2640-FE0F
     59199, // "🛩️" 1F6E9-FE0F
     55964, // "🚣🏾" 1F6A3-1F3FE
     57947, // "🚶🏿" 1F6B6-1F3FF
     56879, // "🚴🏾\u200d♀️" 1F6B4-1F3FE-200D-2640-FE0F
-    56269, // "🚩"
-    56155, // "🚤"
     55991, // "🚣🏿\u200d♀️" 1F6A3-1F3FF-200D-2640-FE0F
-    59424, // "🛷"
     57727, // "🚶🏻\u200d♀️" 1F6B6-1F3FB-200D-2640-FE0F
     56751, // "🚴🏼" 1F6B4-1F3FC
-    58137, // "🚻"
     59179, // "🛥️" 1F6E5-FE0F
-    55482, // "🚝"
     56974, // "🚴🏿\u200d♀️" 1F6B4-1F3FF-200D-2640-FE0F
     57411, // "🚵🏽" 1F6B5-1F3FD
-    58104, // "🚺"
-    58201, // "🚽"
-    55109, // "🚎"
     59291, // "🛰️" 1F6F0-FE0F
     55077, // "🚍️" 1F68D-FE0F
-    55307, // "🚖"
-    57995, // "🚶"
-    55288, // "🚕"
     57005, // "🚴🏿\u200d♂️" 1F6B4-1F3FF-200D-2642-FE0F
     58320, // "🛀🏼" 1F6C0-1F3FC
     57979, // "🚶\u200d♂️" 1F6B6-200D-2642-FE0F
-    54742, // "🚁"
-    54820, // "🚄"
     58700, // "🛌🏽" 1F6CC-1F3FD
     57223, // "🚵🏻" 1F6B5-1F3FB
     56101, // "🚣\u200d♂️" 1F6A3-200D-2642-FE0F
     57600, // "🚵🏿" 1F6B5-1F3FF
-    55158, // "🚐"
-    58259, // "🚿"
-    59291, // "🛰"
     57098, // "🚴\u200d♂️" 1F6B4-200D-2642-FE0F
-    59179, // "🛥"
     55694, // "🚣🏻\u200d♂️" 1F6A3-1F3FB-200D-2642-FE0F
-    58563, // "🛄"
-    56372, // "🚭"
     55802, // "🚣🏼" 1F6A3-1F3FC
-    58531, // "🛃"
-    54902, // "🚇"
-    55211, // "🚒"
     58072, // "🚹️" 1F6B9-FE0F
-    56405, // "🚮"
     58784, // "🛍️" 1F6CD-FE0F
     57757, // "🚶🏻" 1F6B6-1F3FB
-    59003, // "🛗"
     56074, // "🚣\u200d♀️" 1F6A3-200D-2640-FE0F
     56591, // "🚴🏻\u200d♀️" 1F6B4-1F3FB-200D-2640-FE0F
-    59199, // "🛩"
     56018, // "🚣🏿\u200d♂️" 1F6A3-1F3FF-200D-2642-FE0F
     55667, // "🚣🏻\u200d♀️" 1F6A3-1F3FB-200D-2640-FE0F
-    59405, // "🛶"
     56783, // "🚴🏽\u200d♀️" 1F6B4-1F3FD-200D-2640-FE0F
     57537, // "🚵🏿\u200d♀️" 1F6B5-1F3FF-200D-2640-FE0F
     58290, // "🛀🏻" 1F6C0-1F3FB
-    59147, // "🛤"
     59036, // "🛠️" 1F6E0-FE0F
-    56558, // "🚳"
     58169, // "🚼️" 1F6BC-FE0F
     58680, // "🛌🏼" 1F6CC-1F3FC
-    58815, // "🛎"
     56046, // "🚣🏿" 1F6A3-1F3FF
-    58438, // "🛀"
-    55429, // "🚛"
     59147, // "🛤️" 1F6E4-FE0F
-    58860, // "🛐"
-    56312, // "🚫"
-    55382, // "🚙"
-    59259, // "🛬"
     59094, // "🛢️" 1F6E2-FE0F
     59067, // "🛡️" 1F6E1-FE0F
     57067, // "🚴\u200d♀️" 1F6B4-200D-2640-FE0F
@@ -6134,161 +7757,350 @@ const OFFSET_TRANSPORT_AND_MAP_SYMBOLS: [usize; 202] = [
     57286, // "🚵🏼\u200d♂️" 1F6B5-1F3FC-200D-2642-FE0F
     56655, // "🚴🏻" 1F6B4-1F3FB
     55829, // "🚣🏽\u200d♀️" 1F6A3-1F3FD-200D-2640-FE0F
-    59381, // "🛵"
     58104, // "🚺️" 1F6BA-FE0F
-    56193, // "🚦"
     57931, // "🚶🏿\u200d♂️" 1F6B6-1F3FF-200D-2642-FE0F
-    55138, // "🚏"
-    55454, // "🚜"
     57774, // "🚶🏼\u200d♀️" 1F6B6-1F3FC-200D-2640-FE0F
-    55546, // "🚟"
-    55236, // "🚓"
-    58839, // "🛏"
-    58072, // "🚹"
-    58595, // "🛅"
-    59533, // "🛻"
     59115, // "🛣️" 1F6E3-FE0F
     56372, // "🚭️" 1F6AD-FE0F
-    55183, // "🚑"
-    58763, // "🛌"
-    55607, // "🚡"
-    56470, // "🚰"
     55856, // "🚣🏽\u200d♂️" 1F6A3-1F3FD-200D-2642-FE0F
     57160, // "🚵🏻\u200d♀️" 1F6B5-1F3FB-200D-2640-FE0F
-    56237, // "🚨"
     55258, // "🚔️" 1F694-FE0F
-    59230, // "🛫"
-    58784, // "🛍"
     57962, // "🚶\u200d♀️" 1F6B6-200D-2640-FE0F
-    55514, // "🚞"
     56687, // "🚴🏼\u200d♀️" 1F6B4-1F3FC-200D-2640-FE0F
     57914, // "🚶🏿\u200d♀️" 1F6B6-1F3FF-200D-2640-FE0F
-    55354, // "🚘"
     57036, // "🚴🏿" 1F6B4-1F3FF
-    54935, // "🚈"
-    58043, // "🚸"
-    58498, // "🛂"
-    59477, // "🛹"
-    56291, // "🚪"
     57568, // "🚵🏿\u200d♂️" 1F6B5-1F3FF-200D-2642-FE0F
     55721, // "🚣🏻" 1F6A3-1F3FB
     54902, // "🚇️" 1F687-FE0F
     57866, // "🚶🏾\u200d♀️" 1F6B6-1F3FE-200D-2640-FE0F
     57473, // "🚵🏾\u200d♂️" 1F6B5-1F3FE-200D-2642-FE0F
-    54873, // "🚆"
     57835, // "🚶🏽\u200d♂️" 1F6B6-1F3FD-200D-2642-FE0F
-    54769, // "🚂"
-    55635, // "🚢"
     57192, // "🚵🏻\u200d♂️" 1F6B5-1F3FB-200D-2642-FE0F
-    56535, // "🚲"
-    59322, // "🛳"
     56535, // "🚲️" 1F6B2-FE0F
     57348, // "🚵🏽\u200d♀️" 1F6B5-1F3FD-200D-2640-FE0F
-    57695, // "🚵"
-    58226, // "🚾"
     58409, // "🛀🏿" 1F6C0-1F3FF
-    55578, // "🚠"
-    57129, // "🚴"
     57505, // "🚵🏾" 1F6B5-1F3FE
     57254, // "🚵🏼\u200d♀️" 1F6B5-1F3FC-200D-2640-FE0F
-    56210, // "🚧"
-    55334, // "🚗"
     58349, // "🛀🏽" 1F6C0-1F3FD
-    55021, // "🚋"
-    58628, // "🛋"
     57803, // "🚶🏼" 1F6B6-1F3FC
     58628, // "🛋️" 1F6CB-FE0F
     57820, // "🚶🏽\u200d♀️" 1F6B6-1F3FD-200D-2640-FE0F
-    54710, // "🚀"
-    59554, // "🛼"
     55775, // "🚣🏼\u200d♂️" 1F6A3-1F3FC-200D-2642-FE0F
     57317, // "🚵🏼" 1F6B5-1F3FC
-    58010, // "🚷"
     57632, // "🚵\u200d♀️" 1F6B5-200D-2640-FE0F
-    55404, // "🚚"
     58743, // "🛌🏿" 1F6CC-1F3FF
-    56176, // "🚥"
     56911, // "🚴🏾\u200d♂️" 1F6B4-1F3FE-200D-2642-FE0F
     55748, // "🚣🏼\u200d♀️" 1F6A3-1F3FC-200D-2640-FE0F
     55910, // "🚣🏾\u200d♀️" 1F6A3-1F3FE-200D-2640-FE0F
     57742, // "🚶🏻\u200d♂️" 1F6B6-1F3FB-200D-2642-FE0F
-    58975, // "🛖"
     56623, // "🚴🏻\u200d♂️" 1F6B4-1F3FB-200D-2642-FE0F
-    58920, // "🛒"
-    54963, // "🚉"
     56847, // "🚴🏽" 1F6B4-1F3FD
     58721, // "🛌🏾" 1F6CC-1F3FE
-    58946, // "🛕"
-    58468, // "🛁"
 ];
 
-/// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
-/// Only attempt to match grapheme clusters of length limit codepoints.
-fn find_geometric_shapes_extended(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_GEOMETRIC_SHAPES_EXTENDED.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_GEOMETRIC_SHAPES_EXTENDED[index], bytes_hashed)),
-        _ => None,
-    }
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_geometric_shapes_extended_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_GEOMETRIC_SHAPES_EXTENDED, 0x1F7E0, cluster)
 }
 
-/// Index of murmur3(grapheme cluster); sort matches OFFSET_GEOMETRIC_SHAPES_EXTENDED
-const HASH_GEOMETRIC_SHAPES_EXTENDED: [u32; 12] = [
-    0x32CD13F1,  // "🟪"
-    0x4C5F3E6C,  // "🟦"
-    0x63EA2614,  // "🟥"
-    0x64410DB3,  // "🟣"
-    0x7C995BDE,  // "🟫"
-    0x9FF9ADC4,  // "🟨"
-    0xB0E196AE,  // "🟠"
-    0xD849E4AA,  // "🟢"
-    0xD9DD09FD,  // "🟡"
-    0xE2B47887,  // "🟤"
-    0xE7D2447F,  // "🟩"
-    0xFDB5E966,  // "🟧"
-];
-
-/// Lookup table of blit pattern offsets; sort matches HASH_GEOMETRIC_SHAPES_EXTENDED
-const OFFSET_GEOMETRIC_SHAPES_EXTENDED: [usize; 12] = [
-    59904, // "🟪"
-    59777, // "🟦"
-    59744, // "🟥"
-    59680, // "🟣"
-    59936, // "🟫"
-    59841, // "🟨"
+/// Blit pattern offsets for codepoints 0x1F7E0..=0x1F7EB, or NO_GLYPH
+static CODEPOINT_GEOMETRIC_SHAPES_EXTENDED: [u32; 12] = [
     59585, // "🟠"
-    59648, // "🟢"
     59617, // "🟡"
+    59648, // "🟢"
+    59680, // "🟣"
     59712, // "🟤"
-    59872, // "🟩"
+    59744, // "🟥"
+    59777, // "🟦"
     59809, // "🟧"
+    59841, // "🟨"
+    59872, // "🟩"
+    59904, // "🟪"
+    59936, // "🟫"
+];
+
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_supplemental_symbols_and_pictographs_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS, 0x1F90C, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x1F90C..=0x1F9FF, or NO_GLYPH
+static CODEPOINT_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 244] = [
+    60104, // "🤌"
+    60130, // "🤍"
+    60156, // "🤎"
+    60306, // "🤏"
+    60329, // "🤐"
+    60361, // "🤑"
+    60393, // "🤒"
+    60425, // "🤓"
+    60455, // "🤔"
+    60485, // "🤕"
+    60517, // "🤖"
+    60547, // "🤗"
+    60673, // "🤘"
+    60835, // "🤙"
+    60981, // "🤚"
+    61106, // "🤛"
+    61229, // "🤜"
+    61249, // "🤝"
+    61364, // "🤞"
+    61528, // "🤟"
+    61557, // "🤠"
+    61589, // "🤡"
+    61621, // "🤢"
+    61653, // "🤣"
+    61684, // "🤤"
+    61716, // "🤥"
+    62143, // "🤦"
+    62166, // "🤧"
+    62197, // "🤨"
+    62226, // "🤩"
+    62257, // "🤪"
+    62289, // "🤫"
+    62319, // "🤬"
+    62351, // "🤭"
+    62381, // "🤮"
+    62410, // "🤯"
+    62536, // "🤰"
+    62669, // "🤱"
+    62811, // "🤲"
+    62948, // "🤳"
+    63085, // "🤴"
+    63526, // "🤵"
+    63677, // "🤶"
+    64214, // "🤷"
+    64642, // "🤸"
+    65207, // "🤹"
+    65239, // "🤺"
+    super::NO_GLYPH,  // U+1F93B
+    65327, // "🤼"
+    65900, // "🤽"
+    66334, // "🤾"
+    66352, // "🤿"
+    66384, // "🥀"
+    66405, // "🥁"
+    66438, // "🥂"
+    66466, // "🥃"
+    66484, // "🥄"
+    66497, // "🥅"
+    super::NO_GLYPH,  // U+1F946
+    66523, // "🥇"
+    66553, // "🥈"
+    66585, // "🥉"
+    66617, // "🥊"
+    66642, // "🥋"
+    66671, // "🥌"
+    66696, // "🥍"
+    66728, // "🥎"
+    66758, // "🥏"
+    66783, // "🥐"
+    66814, // "🥑"
+    66845, // "🥒"
+    66875, // "🥓"
+    66905, // "🥔"
+    66932, // "🥕"
+    66963, // "🥖"
+    66989, // "🥗"
+    67013, // "🥘"
+    67046, // "🥙"
+    67073, // "🥚"
+    67093, // "🥛"
+    67112, // "🥜"
+    67142, // "🥝"
+    67170, // "🥞"
+    67197, // "🥟"
+    67220, // "🥠"
+    67251, // "🥡"
+    67280, // "🥢"
+    67307, // "🥣"
+    67331, // "🥤"
+    67357, // "🥥"
+    67386, // "🥦"
+    67413, // "🥧"
+    67438, // "🥨"
+    67459, // "🥩"
+    67476, // "🥪"
+    67501, // "🥫"
+    67521, // "🥬"
+    67553, // "🥭"
+    67584, // "🥮"
+    67609, // "🥯"
+    67637, // "🥰"
+    67670, // "🥱"
+    67700, // "🥲"
+    67732, // "🥳"
+    67764, // "🥴"
+    67794, // "🥵"
+    67826, // "🥶"
+    67983, // "🥷"
+    68007, // "🥸"
+    super::NO_GLYPH,  // U+1F979
+    68039, // "🥺"
+    68069, // "🥻"
+    68092, // "🥼"
+    68121, // "🥽"
+    68138, // "🥾"
+    68161, // "🥿"
+    68173, // "🦀"
+    68204, // "🦁"
+    68236, // "🦂"
+    68265, // "🦃"
+    68297, // "🦄"
+    68329, // "🦅"
+    68353, // "🦆"
+    68375, // "🦇"
+    68398, // "🦈"
+    68429, // "🦉"
+    68453, // "🦊"
+    68481, // "🦋"
+    68510, // "🦌"
+    68543, // "🦍"
+    68574, // "🦎"
+    68605, // "🦏"
+    68636, // "🦐"
+    68668, // "🦑"
+    68693, // "🦒"
+    68720, // "🦓"
+    68750, // "🦔"
+    68778, // "🦕"
+    68808, // "🦖"
+    68838, // "🦗"
+    68862, // "🦘"
+    68894, // "🦙"
+    68920, // "🦚"
+    68947, // "🦛"
+    68968, // "🦜"
+    68994, // "🦝"
+    69021, // "🦞"
+    69048, // "🦟"
+    69079, // "🦠"
+    69110, // "🦡"
+    69128, // "🦢"
+    69156, // "🦣"
+    69183, // "🦤"
+    69206, // "🦥"
+    69236, // "🦦"
+    69265, // "🦧"
+    69296, // "🦨"
+    69328, // "🦩"
+    69358, // "🦪"
+    69383, // "🦫"
+    69409, // "🦬"
+    69436, // "🦭"
+    69464, // "🦮"
+    69494, // "🦯"
+    69521, // "🦰"
+    69552, // "🦱"
+    69584, // "🦲"
+    69613, // "🦳"
+    69643, // "🦴"
+    69773, // "🦵"
+    69903, // "🦶"
+    69924, // "🦷"
+    70437, // "🦸"
+    70917, // "🦹"
+    70944, // "🦺"
+    71114, // "🦻"
+    71142, // "🦼"
+    71168, // "🦽"
+    71189, // "🦾"
+    71219, // "🦿"
+    71241, // "🧀"
+    71268, // "🧁"
+    71293, // "🧂"
+    71313, // "🧃"
+    71330, // "🧄"
+    71355, // "🧅"
+    71380, // "🧆"
+    71411, // "🧇"
+    71435, // "🧈"
+    71458, // "🧉"
+    71483, // "🧊"
+    71508, // "🧋"
+    super::NO_GLYPH,  // U+1F9CC
+    71720, // "🧍"
+    72092, // "🧎"
+    72599, // "🧏"
+    72629, // "🧐"
+    77716, // "🧑"
+    77836, // "🧒"
+    77971, // "🧓"
+    78129, // "🧔"
+    78263, // "🧕"
+    78787, // "🧖"
+    79353, // "🧗"
+    79763, // "🧘"
+    80267, // "🧙"
+    80786, // "🧚"
+    81263, // "🧛"
+    81796, // "🧜"
+    82228, // "🧝"
+    82296, // "🧞"
+    82364, // "🧟"
+    82384, // "🧠"
+    82407, // "🧡"
+    82436, // "🧢"
+    82464, // "🧣"
+    82488, // "🧤"
+    82515, // "🧥"
+    82543, // "🧦"
+    82568, // "🧧"
+    82599, // "🧨"
+    82626, // "🧩"
+    82655, // "🧪"
+    82686, // "🧫"
+    82707, // "🧬"
+    82739, // "🧭"
+    82771, // "🧮"
+    82803, // "🧯"
+    82824, // "🧰"
+    82857, // "🧱"
+    82883, // "🧲"
+    82914, // "🧳"
+    82935, // "🧴"
+    82956, // "🧵"
+    82987, // "🧶"
+    83019, // "🧷"
+    83052, // "🧸"
+    83075, // "🧹"
+    83104, // "🧺"
+    83129, // "🧻"
+    83159, // "🧼"
+    83188, // "🧽"
+    83217, // "🧾"
+    83240, // "🧿"
 ];
 
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_supplemental_symbols_and_pictographs(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS.binary_search(&hash) {
+        Ok(index) if KEY_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS
-const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
+static HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 642] = [
     0x0057BCF3,  // "🧜🏽" 1F9DC-1F3FD
-    0x00810C43,  // "🧎"
     0x00967A73,  // "🤦🏻\u200d♂️" 1F926-1F3FB-200D-2642-FE0F
     0x00A97D5B,  // "🧑🏾\u200d🍼" 1F9D1-1F3FE-200D-1F37C
     0x00C640C5,  // "🤾🏽" 1F93E-1F3FD
     0x00D6FC50,  // "🤸🏽\u200d♀️" 1F938-1F3FD-200D-2640-FE0F
     0x01DC34F6,  // "🧗\u200d♀️" 1F9D7-200D-2640-FE0F
     0x020483B2,  // "🧑\u200d🦱" 1F9D1-200D-1F9B1
-    0x02F211D1,  // "🧈"
     0x033519E2,  // "🤞🏾" 1F91E-1F3FE
-    0x034D9C9D,  // "🤾"
     0x03E5EF58,  // "🧗🏿" 1F9D7-1F3FF
-    0x040630E0,  // "🤦"
     0x04891074,  // "🧍🏾\u200d♂️" 1F9CD-1F3FE-200D-2642-FE0F
     0x048E5BA5,  // "🧚🏿" 1F9DA-1F3FF
     0x04A58EEF,  // "🦹🏻\u200d♂️" 1F9B9-1F3FB-200D-2642-FE0F
@@ -6303,55 +8115,37 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0x06EA4D5B,  // "🤹🏽\u200d♂️" 1F939-1F3FD-200D-2642-FE0F
     0x06FF5D47,  // "🤾🏿\u200d♀️" 1F93E-1F3FF-200D-2640-FE0F
     0x07505299,  // "🤵🏽" 1F935-1F3FD
-    0x078F2D59,  // "🤒"
     0x07AA8164,  // "🤘🏽" 1F918-1F3FD
     0x07D46D88,  // "🧝🏻\u200d♀️" 1F9DD-1F3FB-200D-2640-FE0F
     0x07DB1208,  // "🧑🏿\u200d🔬" 1F9D1-1F3FF-200D-1F52C
     0x0875BACF,  // "🤌🏽" 1F90C-1F3FD
     0x08FB2094,  // "🧏🏾\u200d♀️" 1F9CF-1F3FE-200D-2640-FE0F
-    0x094E5EB3,  // "🦹"
     0x09C209F7,  // "🦵🏽" 1F9B5-1F3FD
     0x09E54554,  // "🧗🏼" 1F9D7-1F3FC
     0x0A4791D9,  // "🤱🏿" 1F931-1F3FF
     0x0BE698ED,  // "🤷🏽\u200d♂️" 1F937-1F3FD-200D-2642-FE0F
-    0x0C48F4B9,  // "🥭"
-    0x0C838D6F,  // "🥠"
-    0x0CC7E302,  // "🤝"
     0x0CE2A1A1,  // "🤾🏼" 1F93E-1F3FC
     0x0D396F1F,  // "🧑\u200d🦲" 1F9D1-200D-1F9B2
     0x0E171BC7,  // "🧑🏼\u200d🤝\u200d🧑🏽" 1F9D1-1F3FC-200D-1F91D-200D-1F9D1-1F3FD
     0x0E5B68F2,  // "🧑🏻\u200d🍳" 1F9D1-1F3FB-200D-1F373
-    0x0EBD9E0E,  // "🥚"
-    0x0EBE4A65,  // "🥶"
-    0x0F8FA20F,  // "🤩"
     0x10532DF0,  // "🤸🏾" 1F938-1F3FE
     0x109BC5F9,  // "🧔🏻" 1F9D4-1F3FB
     0x10B4B172,  // "🧍🏿\u200d♂️" 1F9CD-1F3FF-200D-2642-FE0F
     0x11EEC3FA,  // "🧑🏾\u200d⚕️" 1F9D1-1F3FE-200D-2695-FE0F
     0x12128468,  // "🤳🏻" 1F933-1F3FB
-    0x13078DCA,  // "🦽"
     0x13842240,  // "🤷🏾\u200d♀️" 1F937-1F3FE-200D-2640-FE0F
     0x13CA90DF,  // "🧑🏻\u200d🍼" 1F9D1-1F3FB-200D-1F37C
     0x13E24505,  // "🧖🏻\u200d♂️" 1F9D6-1F3FB-200D-2642-FE0F
     0x13EF41C3,  // "🧑🏿\u200d🎓" 1F9D1-1F3FF-200D-1F393
-    0x13EFB594,  // "🤹"
     0x14483979,  // "🧖🏾\u200d♀️" 1F9D6-1F3FE-200D-2640-FE0F
-    0x149213AF,  // "🥼"
     0x14949C8A,  // "🧗🏼\u200d♀️" 1F9D7-1F3FC-200D-2640-FE0F
     0x155B1E86,  // "🧍\u200d♂️" 1F9CD-200D-2642-FE0F
     0x155FA6C3,  // "🤽🏼" 1F93D-1F3FC
-    0x159443F4,  // "🧸"
-    0x1598AF5C,  // "🥳"
     0x163EDB9F,  // "🧑🏼\u200d🎄" 1F9D1-1F3FC-200D-1F384
-    0x166B64E4,  // "🧉"
-    0x16F8E5B9,  // "🦝"
     0x17668F82,  // "🧎🏽\u200d♂️" 1F9CE-1F3FD-200D-2642-FE0F
     0x17782C18,  // "🧚🏼" 1F9DA-1F3FC
-    0x17793641,  // "🤘"
-    0x178C7A7B,  // "🥪"
     0x179C81A5,  // "🧛🏼\u200d♂️" 1F9DB-1F3FC-200D-2642-FE0F
     0x1811A7C5,  // "🧜\u200d♂️" 1F9DC-200D-2642-FE0F
-    0x1950A699,  // "🤛"
     0x19A0F9F0,  // "🦹🏾\u200d♀️" 1F9B9-1F3FE-200D-2640-FE0F
     0x19A8729F,  // "🧑🏽" 1F9D1-1F3FD
     0x19B6DDD3,  // "🤽🏿\u200d♂️" 1F93D-1F3FF-200D-2642-FE0F
@@ -6360,13 +8154,9 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0x19F3656F,  // "🧑🏿\u200d🚒" 1F9D1-1F3FF-200D-1F692
     0x1BB14091,  // "🧎🏾" 1F9CE-1F3FE
     0x1C17C428,  // "🤷🏽" 1F937-1F3FD
-    0x1C4D637F,  // "🦕"
     0x1C54FB25,  // "🧘\u200d♀️" 1F9D8-200D-2640-FE0F
     0x1CE1DCDB,  // "🧕🏼" 1F9D5-1F3FC
-    0x1D18E43E,  // "🤙"
-    0x1DA0636B,  // "🤎"
     0x1DDC5138,  // "🤚🏼" 1F91A-1F3FC
-    0x1E1B9FAA,  // "🦮"
     0x1E21063D,  // "🧘🏼\u200d♂️" 1F9D8-1F3FC-200D-2642-FE0F
     0x1F107568,  // "🧑🏿\u200d🦳" 1F9D1-1F3FF-200D-1F9B3
     0x1F1C3C76,  // "🧖🏿" 1F9D6-1F3FF
@@ -6374,79 +8164,45 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0x1F438F44,  // "🤶🏿" 1F936-1F3FF
     0x1FC4E177,  // "🤴🏿" 1F934-1F3FF
     0x1FFA4852,  // "🤦🏽" 1F926-1F3FD
-    0x2015D53D,  // "🧛"
-    0x20A4BA5C,  // "🦳"
     0x2126482B,  // "🤽🏻\u200d♂️" 1F93D-1F3FB-200D-2642-FE0F
-    0x221C3FBE,  // "🧿"
-    0x22406613,  // "🥮"
     0x2283A906,  // "🧑🏽\u200d🤝\u200d🧑🏻" 1F9D1-1F3FD-200D-1F91D-200D-1F9D1-1F3FB
     0x23433548,  // "🧒🏻" 1F9D2-1F3FB
-    0x234ED357,  // "🦖"
-    0x239C4380,  // "🧒"
     0x243341D3,  // "🧑🏻\u200d🚒" 1F9D1-1F3FB-200D-1F692
-    0x243A160C,  // "🦈"
-    0x2487EB4A,  // "🥞"
     0x2500CD27,  // "🧏🏼\u200d♀️" 1F9CF-1F3FC-200D-2640-FE0F
     0x251F5919,  // "🧚🏻\u200d♀️" 1F9DA-1F3FB-200D-2640-FE0F
-    0x255E9A74,  // "🤐"
     0x257A6592,  // "🧑\u200d🦼" 1F9D1-200D-1F9BC
     0x25AAA64C,  // "🦹\u200d♀️" 1F9B9-200D-2640-FE0F
     0x25DBE623,  // "🧑🏼\u200d💻" 1F9D1-1F3FC-200D-1F4BB
     0x26414A9C,  // "🧑🏿\u200d🦽" 1F9D1-1F3FF-200D-1F9BD
     0x26D6D659,  // "🧑🏼\u200d🎓" 1F9D1-1F3FC-200D-1F393
-    0x26DB42C5,  // "🦔"
-    0x26DE7419,  // "🦬"
-    0x26E2F466,  // "🤏"
     0x2748D4CA,  // "🧑🏿\u200d💼" 1F9D1-1F3FF-200D-1F4BC
-    0x27A5FB53,  // "🤲"
-    0x27D59E0E,  // "🦩"
     0x27FC39A9,  // "🤸🏾\u200d♀️" 1F938-1F3FE-200D-2640-FE0F
-    0x280FC682,  // "🦎"
-    0x283BBD05,  // "🤣"
-    0x2863C51C,  // "🧝"
-    0x2863D35F,  // "🦒"
-    0x28C66123,  // "🤠"
     0x28F25EDE,  // "🧘🏾\u200d♀️" 1F9D8-1F3FE-200D-2640-FE0F
     0x29055359,  // "🤹🏾\u200d♂️" 1F939-1F3FE-200D-2642-FE0F
     0x291A2400,  // "🧑🏽\u200d🍼" 1F9D1-1F3FD-200D-1F37C
     0x298CEC44,  // "🧎🏻\u200d♀️" 1F9CE-1F3FB-200D-2640-FE0F
     0x299AE7CA,  // "🤲🏻" 1F932-1F3FB
-    0x299F536C,  // "🥀"
     0x29C034EA,  // "🦵🏿" 1F9B5-1F3FF
     0x2A95806B,  // "🤽🏿" 1F93D-1F3FF
     0x2AB39FCB,  // "🤼\u200d♀️" 1F93C-200D-2640-FE0F
     0x2AE2D62A,  // "🧑🏽\u200d🦰" 1F9D1-1F3FD-200D-1F9B0
     0x2B204C22,  // "🧝🏾" 1F9DD-1F3FE
-    0x2B31D791,  // "🧰"
     0x2B6C643D,  // "🧙🏻\u200d♀️" 1F9D9-1F3FB-200D-2640-FE0F
-    0x2BAD1B7E,  // "🤓"
     0x2BD8D180,  // "🧑🏽\u200d💼" 1F9D1-1F3FD-200D-1F4BC
-    0x2D0FE865,  // "🤟"
     0x2D1C1D71,  // "🧗🏾\u200d♀️" 1F9D7-1F3FE-200D-2640-FE0F
-    0x2D4806F8,  // "🧼"
     0x2D764ABD,  // "🦵🏼" 1F9B5-1F3FC
-    0x2D8C1465,  // "🤭"
     0x2DA233BD,  // "🧑\u200d🍳" 1F9D1-200D-1F373
     0x2E7A0733,  // "🧛🏻\u200d♀️" 1F9DB-1F3FB-200D-2640-FE0F
-    0x2EB06E2D,  // "🥝"
-    0x2EE65663,  // "🧺"
     0x2EF81800,  // "🧜🏼\u200d♂️" 1F9DC-1F3FC-200D-2642-FE0F
     0x2F0DAF50,  // "🦸🏼\u200d♂️" 1F9B8-1F3FC-200D-2642-FE0F
     0x2F36665C,  // "🧏🏾" 1F9CF-1F3FE
     0x2F6A4E2F,  // "🧛🏿\u200d♂️" 1F9DB-1F3FF-200D-2642-FE0F
-    0x300B2E98,  // "🥲"
-    0x307C8809,  // "🥽"
     0x30DB65A3,  // "🧛🏻" 1F9DB-1F3FB
-    0x314322EB,  // "🧟"
     0x316036F7,  // "🧑🏾\u200d🎓" 1F9D1-1F3FE-200D-1F393
     0x317F1BBB,  // "🧑🏽\u200d🤝\u200d🧑🏼" 1F9D1-1F3FD-200D-1F91D-200D-1F9D1-1F3FC
-    0x3185E72E,  // "🤯"
-    0x31DF78D7,  // "🦥"
-    0x31EC3733,  // "🤰"
     0x321BB5C3,  // "🧑🏼\u200d⚖️" 1F9D1-1F3FC-200D-2696-FE0F
     0x3298C574,  // "🤸🏼\u200d♀️" 1F938-1F3FC-200D-2640-FE0F
     0x3312E8C3,  // "🧕🏻" 1F9D5-1F3FB
-    0x331509D6,  // "🧽"
     0x33306047,  // "🧑\u200d🔬" 1F9D1-200D-1F52C
     0x33487401,  // "🤷🏼" 1F937-1F3FC
     0x337B36C5,  // "🧜🏻\u200d♀️" 1F9DC-1F3FB-200D-2640-FE0F
@@ -6455,14 +8211,9 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0x33ABB4CB,  // "🧑🏼\u200d🦰" 1F9D1-1F3FC-200D-1F9B0
     0x33EC480B,  // "🧑🏾\u200d🦼" 1F9D1-1F3FE-200D-1F9BC
     0x3401FF8D,  // "🧓🏻" 1F9D3-1F3FB
-    0x3449E07E,  // "🧻"
     0x3478818B,  // "🤵🏻" 1F935-1F3FB
     0x34F3290C,  // "🤵🏼" 1F935-1F3FC
-    0x34F89D73,  // "🧫"
-    0x351B01D3,  // "🦦"
-    0x35AA8DC6,  // "🧊"
     0x3610056D,  // "🧑\u200d💻" 1F9D1-200D-1F4BB
-    0x36445ED3,  // "🤽"
     0x3647D05D,  // "🤱🏽" 1F931-1F3FD
     0x3755C520,  // "🤽🏾" 1F93D-1F3FE
     0x37EDB583,  // "🧜🏿" 1F9DC-1F3FF
@@ -6478,14 +8229,10 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0x3AC4E1F6,  // "🧗🏻" 1F9D7-1F3FB
     0x3B223814,  // "🤳🏿" 1F933-1F3FF
     0x3B4046AD,  // "🤳🏼" 1F933-1F3FC
-    0x3B6128F5,  // "🦘"
     0x3B6BF5B0,  // "🧎🏿\u200d♀️" 1F9CE-1F3FF-200D-2640-FE0F
-    0x3B8B9D47,  // "🦗"
     0x3BD04709,  // "🤦🏾\u200d♂️" 1F926-1F3FE-200D-2642-FE0F
     0x3DA34D66,  // "🧚🏽" 1F9DA-1F3FD
-    0x3DEA90A9,  // "🦞"
     0x3DEC26D2,  // "🧜🏽\u200d♂️" 1F9DC-1F3FD-200D-2642-FE0F
-    0x3E669C9D,  // "🧏"
     0x3E9A6333,  // "🧑🏾\u200d🤝\u200d🧑🏿" 1F9D1-1F3FE-200D-1F91D-200D-1F9D1-1F3FF
     0x3EBAA9DE,  // "🤽\u200d♂️" 1F93D-200D-2642-FE0F
     0x3F58ACAF,  // "🧝🏼\u200d♀️" 1F9DD-1F3FC-200D-2640-FE0F
@@ -6493,19 +8240,14 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0x3F7FF0EB,  // "🧚\u200d♂️" 1F9DA-200D-2642-FE0F
     0x3FFCC03A,  // "🤟🏼" 1F91F-1F3FC
     0x408BFA98,  // "🧑🏼\u200d🤝\u200d🧑🏿" 1F9D1-1F3FC-200D-1F91D-200D-1F9D1-1F3FF
-    0x40C1EF7A,  // "🦂"
     0x40E310FB,  // "🧗\u200d♂️" 1F9D7-200D-2642-FE0F
     0x40E58B76,  // "🧗🏽\u200d♂️" 1F9D7-1F3FD-200D-2642-FE0F
     0x41174C4D,  // "🤾🏽\u200d♀️" 1F93E-1F3FD-200D-2640-FE0F
-    0x41F28BF6,  // "🧴"
     0x4205DA98,  // "🧓🏾" 1F9D3-1F3FE
-    0x4352DD20,  // "🧁"
     0x43600754,  // "🤹🏽" 1F939-1F3FD
     0x4395BCEF,  // "🧎🏾\u200d♂️" 1F9CE-1F3FE-200D-2642-FE0F
     0x43C8662D,  // "🤾🏽\u200d♂️" 1F93E-1F3FD-200D-2642-FE0F
     0x43E5709E,  // "🧑🏻\u200d🦼" 1F9D1-1F3FB-200D-1F9BC
-    0x43E8E560,  // "🦶"
-    0x43FC672E,  // "🥌"
     0x44181CCF,  // "🧙🏻\u200d♂️" 1F9D9-1F3FB-200D-2642-FE0F
     0x4431D871,  // "🧑🏽\u200d🏫" 1F9D1-1F3FD-200D-1F3EB
     0x449E5E55,  // "🧒🏽" 1F9D2-1F3FD
@@ -6515,7 +8257,6 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0x45B1D9D1,  // "🧛🏿" 1F9DB-1F3FF
     0x45C5C7BE,  // "🤷🏻\u200d♂️" 1F937-1F3FB-200D-2642-FE0F
     0x460C41DA,  // "🧍🏽" 1F9CD-1F3FD
-    0x460DFADE,  // "🧧"
     0x46A9AFB0,  // "🦻🏼" 1F9BB-1F3FC
     0x46B7CC68,  // "🧛🏼" 1F9DB-1F3FC
     0x470BE297,  // "🦸🏿\u200d♀️" 1F9B8-1F3FF-200D-2640-FE0F
@@ -6524,13 +8265,11 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0x48190810,  // "🧛🏽\u200d♂️" 1F9DB-1F3FD-200D-2642-FE0F
     0x48937542,  // "🧑🏻\u200d🤝\u200d🧑🏻" 1F9D1-1F3FB-200D-1F91D-200D-1F9D1-1F3FB
     0x49954579,  // "🧑\u200d🎓" 1F9D1-200D-1F393
-    0x49BB29DF,  // "🧞"
     0x4A26B090,  // "🦸🏻" 1F9B8-1F3FB
     0x4A87CAFC,  // "🤙🏻" 1F919-1F3FB
     0x4AB6A9DB,  // "🧗🏼\u200d♂️" 1F9D7-1F3FC-200D-2642-FE0F
     0x4B6D4FC2,  // "🧑\u200d💼" 1F9D1-200D-1F4BC
     0x4BA8E81A,  // "🧑🏼\u200d🤝\u200d🧑🏼" 1F9D1-1F3FC-200D-1F91D-200D-1F9D1-1F3FC
-    0x4BDCA1A4,  // "🧓"
     0x4C1FBDE9,  // "🤵🏿\u200d♀️" 1F935-1F3FF-200D-2640-FE0F
     0x4C3F6E0F,  // "🧙🏽" 1F9D9-1F3FD
     0x4CC9C78C,  // "🧑🏼\u200d🍼" 1F9D1-1F3FC-200D-1F37C
@@ -6538,18 +8277,13 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0x4E6E6C96,  // "🧑🏼\u200d⚕️" 1F9D1-1F3FC-200D-2695-FE0F
     0x4E7B1CD9,  // "🧑🏾\u200d🦯" 1F9D1-1F3FE-200D-1F9AF
     0x4E84CA64,  // "🧑🏽\u200d🌾" 1F9D1-1F3FD-200D-1F33E
-    0x4EE36FCD,  // "🥁"
-    0x4EE3A642,  // "🥰"
     0x4EEA8163,  // "🧛🏿\u200d♀️" 1F9DB-1F3FF-200D-2640-FE0F
-    0x4F77D838,  // "🦧"
     0x4FAEF660,  // "🧑🏼\u200d🦱" 1F9D1-1F3FC-200D-1F9B1
     0x4FB3658F,  // "🤹🏽\u200d♀️" 1F939-1F3FD-200D-2640-FE0F
     0x4FF656E9,  // "🧛\u200d♀️" 1F9DB-200D-2640-FE0F
-    0x5015BC48,  // "🦐"
     0x5017C981,  // "🦹🏻" 1F9B9-1F3FB
     0x50801127,  // "🧑🏻\u200d🎄" 1F9D1-1F3FB-200D-1F384
     0x50B3DF5D,  // "🧑🏼" 1F9D1-1F3FC
-    0x50BFD580,  // "🤵"
     0x50FD3369,  // "🤳🏾" 1F933-1F3FE
     0x510EB6CC,  // "🧑🏾\u200d🦰" 1F9D1-1F3FE-200D-1F9B0
     0x5156207F,  // "🤲🏼" 1F932-1F3FC
@@ -6557,25 +8291,17 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0x517D12F3,  // "🤛🏽" 1F91B-1F3FD
     0x51AA81AE,  // "🧍🏼" 1F9CD-1F3FC
     0x51DB3F96,  // "🤌🏿" 1F90C-1F3FF
-    0x52076930,  // "🦀"
     0x5271289F,  // "🧑\u200d🚒" 1F9D1-200D-1F692
     0x52B72AE7,  // "🧑🏻\u200d✈️" 1F9D1-1F3FB-200D-2708-FE0F
-    0x530970AB,  // "🤼"
-    0x53ACD90E,  // "🥢"
     0x53CD5CA2,  // "🧑\u200d🦳" 1F9D1-200D-1F9B3
-    0x5411A638,  // "🧙"
     0x5457E561,  // "🤱🏼" 1F931-1F3FC
     0x5490F275,  // "🤴🏽" 1F934-1F3FD
     0x54FA6201,  // "🧔🏾" 1F9D4-1F3FE
-    0x54FCBAB9,  // "🥗"
-    0x55090877,  // "🥵"
     0x5531599A,  // "🧑\u200d🎨" 1F9D1-200D-1F3A8
     0x55454555,  // "🦹🏼\u200d♂️" 1F9B9-1F3FC-200D-2642-FE0F
-    0x554C599B,  // "🥿"
     0x55546DAC,  // "🤦🏻" 1F926-1F3FB
     0x558C51BD,  // "🧗🏾" 1F9D7-1F3FE
     0x566EB891,  // "🧑🏼\u200d🚒" 1F9D1-1F3FC-200D-1F692
-    0x56BAAA34,  // "🥩"
     0x57249168,  // "🧑🏼\u200d🏫" 1F9D1-1F3FC-200D-1F3EB
     0x57475D3E,  // "🧗🏿\u200d♂️" 1F9D7-1F3FF-200D-2642-FE0F
     0x57579AEF,  // "🧜🏻" 1F9DC-1F3FB
@@ -6583,161 +8309,113 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0x583EFD22,  // "🧙🏾" 1F9D9-1F3FE
     0x587F5260,  // "🧖🏽\u200d♀️" 1F9D6-1F3FD-200D-2640-FE0F
     0x58A4EB69,  // "🤦🏽\u200d♂️" 1F926-1F3FD-200D-2642-FE0F
-    0x58D0FC61,  // "🥂"
     0x58DDA091,  // "🧜🏾" 1F9DC-1F3FE
     0x58FBAB0E,  // "🤰🏻" 1F930-1F3FB
     0x591FAF33,  // "🤘🏼" 1F918-1F3FC
     0x5928170E,  // "🤾🏻\u200d♀️" 1F93E-1F3FB-200D-2640-FE0F
     0x594CE576,  // "🧑🏿\u200d🦲" 1F9D1-1F3FF-200D-1F9B2
-    0x59EF517D,  // "🥸"
     0x5A434EDC,  // "🤾🏿" 1F93E-1F3FF
     0x5A5B5F49,  // "🧑🏻\u200d⚕️" 1F9D1-1F3FB-200D-2695-FE0F
     0x5A8FD6E6,  // "🤦🏼\u200d♂️" 1F926-1F3FC-200D-2642-FE0F
-    0x5AD43D26,  // "🤸"
     0x5B6B74B3,  // "🦻🏿" 1F9BB-1F3FF
     0x5C229382,  // "🤶🏾" 1F936-1F3FE
     0x5CA8A469,  // "🧑🏻\u200d🏫" 1F9D1-1F3FB-200D-1F3EB
     0x5CE3FC62,  // "🤛🏻" 1F91B-1F3FB
-    0x5D6E4CD5,  // "🤮"
     0x5D7C0088,  // "🧑🏿\u200d🏫" 1F9D1-1F3FF-200D-1F3EB
     0x5DAB66BA,  // "🧗🏻\u200d♀️" 1F9D7-1F3FB-200D-2640-FE0F
     0x5E3E88B7,  // "🧑🏿\u200d🍼" 1F9D1-1F3FF-200D-1F37C
-    0x5F11FB89,  // "🦴"
-    0x5F6EED1A,  // "🧄"
     0x5F6F4A94,  // "🤏🏾" 1F90F-1F3FE
     0x5F992F65,  // "🧜🏻\u200d♂️" 1F9DC-1F3FB-200D-2642-FE0F
-    0x604966B2,  // "🧲"
     0x6075C585,  // "🦹🏿\u200d♂️" 1F9B9-1F3FF-200D-2642-FE0F
     0x608C3C90,  // "🧑\u200d🏫" 1F9D1-200D-1F3EB
     0x61361014,  // "🧑🏼\u200d🔧" 1F9D1-1F3FC-200D-1F527
     0x614E35EF,  // "🧙🏽\u200d♂️" 1F9D9-1F3FD-200D-2642-FE0F
     0x62006575,  // "🦹🏾\u200d♂️" 1F9B9-1F3FE-200D-2642-FE0F
-    0x62998429,  // "🧢"
     0x62A4DAC9,  // "🧑🏿\u200d⚖️" 1F9D1-1F3FF-200D-2696-FE0F
     0x635A470C,  // "🧑🏽\u200d⚕️" 1F9D1-1F3FD-200D-2695-FE0F
     0x63691504,  // "🤽🏼\u200d♀️" 1F93D-1F3FC-200D-2640-FE0F
     0x63B1E781,  // "🦶🏽" 1F9B6-1F3FD
     0x649F34D9,  // "🦶🏿" 1F9B6-1F3FF
     0x64B020E2,  // "🧚🏿\u200d♀️" 1F9DA-1F3FF-200D-2640-FE0F
-    0x64E68819,  // "🥟"
-    0x65892314,  // "🥷"
     0x65BD021F,  // "🧖🏻\u200d♀️" 1F9D6-1F3FB-200D-2640-FE0F
     0x65E6A52C,  // "🦹🏼\u200d♀️" 1F9B9-1F3FC-200D-2640-FE0F
     0x65F13977,  // "🧝🏽" 1F9DD-1F3FD
     0x664B60E2,  // "🤏🏼" 1F90F-1F3FC
     0x665E8EEA,  // "🤦🏼\u200d♀️" 1F926-1F3FC-200D-2640-FE0F
-    0x6682E3E3,  // "🥕"
-    0x668C2F90,  // "🧩"
     0x66A54BEC,  // "🧎🏾\u200d♀️" 1F9CE-1F3FE-200D-2640-FE0F
     0x66C8A0C6,  // "🧔🏽" 1F9D4-1F3FD
     0x66F1E76B,  // "🧒🏾" 1F9D2-1F3FE
     0x6720F3EE,  // "🧑🏿\u200d🚀" 1F9D1-1F3FF-200D-1F680
     0x6760D863,  // "🧘🏿\u200d♀️" 1F9D8-1F3FF-200D-2640-FE0F
     0x67B6F390,  // "🤚🏻" 1F91A-1F3FB
-    0x68031D24,  // "🧂"
-    0x6863B0C5,  // "🧑"
     0x68F70405,  // "🧚🏼\u200d♂️" 1F9DA-1F3FC-200D-2642-FE0F
     0x68FCC9BF,  // "🧑🏻\u200d🦳" 1F9D1-1F3FB-200D-1F9B3
     0x692E4C42,  // "🧑🏽\u200d🎄" 1F9D1-1F3FD-200D-1F384
-    0x697F76B2,  // "🧳"
     0x69831AB0,  // "🤦\u200d♂️" 1F926-200D-2642-FE0F
     0x69B4203A,  // "🤷🏼\u200d♂️" 1F937-1F3FC-200D-2642-FE0F
-    0x69FF1902,  // "🧥"
-    0x6A92A4C8,  // "🧀"
     0x6AE30BEB,  // "🤹🏿\u200d♂️" 1F939-1F3FF-200D-2642-FE0F
     0x6B1D63B9,  // "🧝🏽\u200d♀️" 1F9DD-1F3FD-200D-2640-FE0F
     0x6B2B77FD,  // "🧛🏽\u200d♀️" 1F9DB-1F3FD-200D-2640-FE0F
-    0x6B3088CD,  // "🥅"
     0x6C14D82C,  // "🧑\u200d🎤" 1F9D1-200D-1F3A4
     0x6CA63759,  // "🧝\u200d♂️" 1F9DD-200D-2642-FE0F
     0x6CAB1D42,  // "🧛🏻\u200d♂️" 1F9DB-1F3FB-200D-2642-FE0F
     0x6CC29DA0,  // "🤷\u200d♂️" 1F937-200D-2642-FE0F
     0x6D1D3B2B,  // "🧑🏾\u200d🏫" 1F9D1-1F3FE-200D-1F3EB
     0x6D89FC46,  // "🧛🏾\u200d♀️" 1F9DB-1F3FE-200D-2640-FE0F
-    0x6D8ED3BB,  // "🦵"
     0x6DED1578,  // "🧙🏿" 1F9D9-1F3FF
     0x6E96D50D,  // "🧑🏻\u200d🤝\u200d🧑🏾" 1F9D1-1F3FB-200D-1F91D-200D-1F9D1-1F3FE
     0x6ED2FA80,  // "🤵🏽\u200d♂️" 1F935-1F3FD-200D-2642-FE0F
     0x6F87056B,  // "🤵🏾\u200d♀️" 1F935-1F3FE-200D-2640-FE0F
-    0x6FB67311,  // "🧗"
-    0x6FCFD7D4,  // "🥎"
     0x701251E8,  // "🧑🏽\u200d🔬" 1F9D1-1F3FD-200D-1F52C
     0x7101319F,  // "🧙🏿\u200d♂️" 1F9D9-1F3FF-200D-2642-FE0F
     0x7148FE74,  // "🦶🏻" 1F9B6-1F3FB
     0x71C2B31E,  // "🤶🏻" 1F936-1F3FB
     0x71CDF5CD,  // "🧑\u200d⚕️" 1F9D1-200D-2695-FE0F
     0x71EBD5B2,  // "🧑🏾\u200d🔬" 1F9D1-1F3FE-200D-1F52C
-    0x723C5249,  // "🥧"
     0x72752E77,  // "🧑🏻\u200d💼" 1F9D1-1F3FB-200D-1F4BC
-    0x72823F6C,  // "🦪"
     0x72D07B29,  // "🧑🏽\u200d⚖️" 1F9D1-1F3FD-200D-2696-FE0F
     0x730C5672,  // "🧝🏾\u200d♀️" 1F9DD-1F3FE-200D-2640-FE0F
     0x7344F757,  // "🧑🏼\u200d🌾" 1F9D1-1F3FC-200D-1F33E
     0x73B2F6F9,  // "🥷🏼" 1F977-1F3FC
     0x73CAC324,  // "🧚🏿\u200d♂️" 1F9DA-1F3FF-200D-2642-FE0F
     0x74680DDC,  // "🤾\u200d♀️" 1F93E-200D-2640-FE0F
-    0x747FDC37,  // "🦇"
     0x74CD9D13,  // "🧑\u200d🦽" 1F9D1-200D-1F9BD
-    0x74CE543F,  // "🧕"
-    0x75D00005,  // "🦚"
     0x761CABBC,  // "🧕🏿" 1F9D5-1F3FF
-    0x762AE47C,  // "🦨"
     0x7637FED8,  // "🤾🏼\u200d♂️" 1F93E-1F3FC-200D-2642-FE0F
     0x763E838B,  // "🧑🏻\u200d🎨" 1F9D1-1F3FB-200D-1F3A8
     0x764F019B,  // "🦸🏾\u200d♂️" 1F9B8-1F3FE-200D-2642-FE0F
     0x76E1BE52,  // "🧔🏿" 1F9D4-1F3FF
-    0x76EAF20F,  // "🧬"
     0x774E36EC,  // "🧗🏽" 1F9D7-1F3FD
     0x77755D30,  // "🧘🏽" 1F9D8-1F3FD
-    0x77944CF5,  // "🥴"
     0x7839F81F,  // "🧑🏽\u200d🚀" 1F9D1-1F3FD-200D-1F680
     0x784D9757,  // "🧖🏻" 1F9D6-1F3FB
-    0x7958F123,  // "🥃"
     0x79604C12,  // "🧑🏼\u200d💼" 1F9D1-1F3FC-200D-1F4BC
-    0x79752D1C,  // "🦃"
-    0x797EEE4D,  // "🦡"
     0x79DF3E8B,  // "🦸🏽\u200d♂️" 1F9B8-1F3FD-200D-2642-FE0F
-    0x7B12F33C,  // "🥍"
     0x7B5EC79B,  // "🤘🏿" 1F918-1F3FF
     0x7B65A43E,  // "🤞🏻" 1F91E-1F3FB
     0x7B83D043,  // "🧝\u200d♀️" 1F9DD-200D-2640-FE0F
     0x7C72CD0A,  // "🤜🏿" 1F91C-1F3FF
-    0x7CAD2634,  // "🤍"
     0x7CB773A1,  // "🧍🏻\u200d♀️" 1F9CD-1F3FB-200D-2640-FE0F
-    0x7CD2CD40,  // "🦆"
     0x7CEC2061,  // "🤸🏻\u200d♂️" 1F938-1F3FB-200D-2642-FE0F
-    0x7D08F492,  // "🦺"
     0x7D98E52A,  // "🤵🏿" 1F935-1F3FF
-    0x7DF23142,  // "🤕"
-    0x7E232D19,  // "🥏"
-    0x7E44169D,  // "🥜"
-    0x7E4D6C47,  // "🥾"
     0x7E5AD18B,  // "🧍🏾\u200d♀️" 1F9CD-1F3FE-200D-2640-FE0F
     0x7E65C816,  // "🧑🏽\u200d🦯" 1F9D1-1F3FD-200D-1F9AF
-    0x7E6B28A9,  // "🧷"
     0x7E91FDEF,  // "🤘🏻" 1F918-1F3FB
     0x7F37C38F,  // "🧘🏽\u200d♀️" 1F9D8-1F3FD-200D-2640-FE0F
     0x807546C1,  // "🤹🏼" 1F939-1F3FC
-    0x80ADA5F8,  // "🦠"
     0x80D0A0CB,  // "🧑🏻\u200d🤝\u200d🧑🏿" 1F9D1-1F3FB-200D-1F91D-200D-1F9D1-1F3FF
-    0x812D0700,  // "🧅"
     0x819BDD8E,  // "🧍🏽\u200d♀️" 1F9CD-1F3FD-200D-2640-FE0F
-    0x819C5DCB,  // "🧹"
     0x81D806FC,  // "🤷🏾" 1F937-1F3FE
     0x821B1EF2,  // "🧍🏿\u200d♀️" 1F9CD-1F3FF-200D-2640-FE0F
     0x82A2076C,  // "🤾🏼\u200d♀️" 1F93E-1F3FC-200D-2640-FE0F
-    0x82DC4427,  // "🧐"
     0x8304BA7E,  // "🧑🏾\u200d🤝\u200d🧑🏽" 1F9D1-1F3FE-200D-1F91D-200D-1F9D1-1F3FD
     0x8344C512,  // "🧙\u200d♀️" 1F9D9-200D-2640-FE0F
     0x838129EE,  // "🤴🏾" 1F934-1F3FE
     0x839A7D1E,  // "🤾🏻" 1F93E-1F3FB
     0x839EC3C9,  // "🧚🏽\u200d♂️" 1F9DA-1F3FD-200D-2642-FE0F
-    0x83BAFBE6,  // "🤶"
     0x83DCB402,  // "🧛🏼\u200d♀️" 1F9DB-1F3FC-200D-2640-FE0F
-    0x84401FA4,  // "🦾"
     0x846D5356,  // "🧑🏿\u200d🤝\u200d🧑🏽" 1F9D1-1F3FF-200D-1F91D-200D-1F9D1-1F3FD
     0x849B2772,  // "🧑🏾\u200d🍳" 1F9D1-1F3FE-200D-1F373
-    0x849F3AF9,  // "🧵"
     0x84CF947D,  // "🧏🏻\u200d♀️" 1F9CF-1F3FB-200D-2640-FE0F
     0x84E3CA8C,  // "🤸🏿\u200d♂️" 1F938-1F3FF-200D-2642-FE0F
     0x84F6F7FB,  // "🧝🏾\u200d♂️" 1F9DD-1F3FE-200D-2642-FE0F
@@ -6753,31 +8431,20 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0x87E91B51,  // "🧞\u200d♀️" 1F9DE-200D-2640-FE0F
     0x883905ED,  // "🧎🏽\u200d♀️" 1F9CE-1F3FD-200D-2640-FE0F
     0x889E6A48,  // "🤹\u200d♀️" 1F939-200D-2640-FE0F
-    0x88A3B3BD,  // "🧜"
     0x88D943CF,  // "🧑🏾\u200d🦲" 1F9D1-1F3FE-200D-1F9B2
-    0x89D0C9F7,  // "🦸"
     0x89F9C8F7,  // "🧑🏻\u200d🌾" 1F9D1-1F3FB-200D-1F33E
     0x8A0E8BB9,  // "🧑🏼\u200d🦽" 1F9D1-1F3FC-200D-1F9BD
     0x8A24B1C6,  // "🧑\u200d🚀" 1F9D1-200D-1F680
     0x8A4A8CB5,  // "🤵🏿\u200d♂️" 1F935-1F3FF-200D-2642-FE0F
     0x8ACBE3D4,  // "🧝🏽\u200d♂️" 1F9DD-1F3FD-200D-2642-FE0F
     0x8B220010,  // "🧖🏾" 1F9D6-1F3FE
-    0x8B3F1B49,  // "🤧"
-    0x8C30D4BD,  // "🧦"
     0x8D195902,  // "🤸🏻\u200d♀️" 1F938-1F3FB-200D-2640-FE0F
-    0x8DFE5EA1,  // "🥥"
-    0x8EAE792F,  // "🦟"
-    0x8EEF48C1,  // "🥺"
-    0x8F0ADCBF,  // "🦌"
     0x8F38048E,  // "🧎🏼\u200d♀️" 1F9CE-1F3FC-200D-2640-FE0F
     0x8F8FFD0F,  // "🧙🏼" 1F9D9-1F3FC
     0x90249214,  // "🧑🏼\u200d🔬" 1F9D1-1F3FC-200D-1F52C
     0x90F18280,  // "🧑🏾\u200d🔧" 1F9D1-1F3FE-200D-1F527
     0x913B81EB,  // "🧟\u200d♂️" 1F9DF-200D-2642-FE0F
-    0x91A518F8,  // "🥐"
-    0x91E99AE4,  // "🤳"
     0x92081956,  // "🧑🏽\u200d🦳" 1F9D1-1F3FD-200D-1F9B3
-    0x9248F6E8,  // "🤱"
     0x9270C024,  // "🤽🏼\u200d♂️" 1F93D-1F3FC-200D-2642-FE0F
     0x927637A4,  // "🧚🏾" 1F9DA-1F3FE
     0x9281E5C2,  // "🧝🏻\u200d♂️" 1F9DD-1F3FB-200D-2642-FE0F
@@ -6785,29 +8452,19 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0x93288CC1,  // "🧜🏽\u200d♀️" 1F9DC-1F3FD-200D-2640-FE0F
     0x93B12354,  // "🦹\u200d♂️" 1F9B9-200D-2642-FE0F
     0x93F1CCAB,  // "🤴🏼" 1F934-1F3FC
-    0x9408AE59,  // "🦿"
     0x947D1ACA,  // "🧑🏾\u200d🚒" 1F9D1-1F3FE-200D-1F692
     0x947EA4EA,  // "🦹🏻\u200d♀️" 1F9B9-1F3FB-200D-2640-FE0F
     0x94A97B7C,  // "🤵🏻\u200d♂️" 1F935-1F3FB-200D-2642-FE0F
-    0x94C4A465,  // "🥖"
-    0x94F9D926,  // "🤖"
-    0x9537D6A1,  // "🤺"
     0x957ECED8,  // "🧍🏻\u200d♂️" 1F9CD-1F3FB-200D-2642-FE0F
     0x96C3476A,  // "🧑🏾\u200d🦽" 1F9D1-1F3FE-200D-1F9BD
-    0x97086F30,  // "🦉"
-    0x977D1458,  // "🦭"
-    0x978E8659,  // "🧋"
     0x97DF4A6A,  // "🦸🏾\u200d♀️" 1F9B8-1F3FE-200D-2640-FE0F
     0x984D644F,  // "🦸🏿" 1F9B8-1F3FF
     0x98545BA2,  // "🧑🏻\u200d⚖️" 1F9D1-1F3FB-200D-2696-FE0F
-    0x9869CEA8,  // "🤨"
     0x98716A77,  // "🧙\u200d♂️" 1F9D9-200D-2642-FE0F
     0x987E04A0,  // "🤛🏼" 1F91B-1F3FC
     0x9930B16F,  // "🧑🏻\u200d🏭" 1F9D1-1F3FB-200D-1F3ED
     0x99315C0A,  // "🤽🏿\u200d♀️" 1F93D-1F3FF-200D-2640-FE0F
     0x993A8876,  // "🤞🏼" 1F91E-1F3FC
-    0x994B5ECA,  // "🦤"
-    0x9981BCF5,  // "🧪"
     0x998B4A6D,  // "🤵🏼\u200d♂️" 1F935-1F3FC-200D-2642-FE0F
     0x9A25553F,  // "🤵🏼\u200d♀️" 1F935-1F3FC-200D-2640-FE0F
     0x9A5E989F,  // "🦸🏼" 1F9B8-1F3FC
@@ -6815,17 +8472,13 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0x9AC8E3BB,  // "🧏🏿\u200d♀️" 1F9CF-1F3FF-200D-2640-FE0F
     0x9ADED966,  // "🤙🏾" 1F919-1F3FE
     0x9B0ED4F9,  // "🦹🏿\u200d♀️" 1F9B9-1F3FF-200D-2640-FE0F
-    0x9B545046,  // "🦣"
     0x9B82726E,  // "🧑🏿\u200d🤝\u200d🧑🏻" 1F9D1-1F3FF-200D-1F91D-200D-1F9D1-1F3FB
     0x9C13656B,  // "🧏🏿" 1F9CF-1F3FF
-    0x9C2E07C7,  // "🥙"
     0x9CA3423E,  // "🤟🏻" 1F91F-1F3FB
-    0x9CB799B8,  // "🤑"
     0x9D0AB10D,  // "🧚\u200d♀️" 1F9DA-200D-2640-FE0F
     0x9D0D0060,  // "🧑🏿\u200d🏭" 1F9D1-1F3FF-200D-1F3ED
     0x9D2D55E8,  // "🧘🏽\u200d♂️" 1F9D8-1F3FD-200D-2642-FE0F
     0x9D399917,  // "🤏🏿" 1F90F-1F3FF
-    0x9D457955,  // "🦫"
     0x9EEAB105,  // "🤰🏿" 1F930-1F3FF
     0x9F30279C,  // "🤙🏼" 1F919-1F3FC
     0x9F52590F,  // "🧑🏾\u200d🌾" 1F9D1-1F3FE-200D-1F33E
@@ -6839,19 +8492,12 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0xA091C251,  // "🧑🏿\u200d🦯" 1F9D1-1F3FF-200D-1F9AF
     0xA0CB29AA,  // "🧗🏻\u200d♂️" 1F9D7-1F3FB-200D-2642-FE0F
     0xA118016C,  // "🧑🏽\u200d💻" 1F9D1-1F3FD-200D-1F4BB
-    0xA13418CD,  // "🥒"
-    0xA1433CBF,  // "🤤"
     0xA1F74984,  // "🧑\u200d🦰" 1F9D1-200D-1F9B0
     0xA200A940,  // "🧑🏽\u200d🍳" 1F9D1-1F3FD-200D-1F373
-    0xA228F983,  // "🧶"
-    0xA2338657,  // "🥻"
-    0xA2409EBF,  // "🤫"
     0xA25E7E7F,  // "🤟🏾" 1F91F-1F3FE
-    0xA2F29419,  // "🤔"
     0xA2F692A4,  // "🧑🏾\u200d🎄" 1F9D1-1F3FE-200D-1F384
     0xA38884E3,  // "🧑🏾\u200d🤝\u200d🧑🏼" 1F9D1-1F3FE-200D-1F91D-200D-1F9D1-1F3FC
     0xA393896E,  // "🧑\u200d⚖️" 1F9D1-200D-2696-FE0F
-    0xA405D6DC,  // "🤡"
     0xA4A1ABD5,  // "🧜🏼" 1F9DC-1F3FC
     0xA4D4ABF3,  // "🦸🏿\u200d♂️" 1F9B8-1F3FF-200D-2642-FE0F
     0xA545FDB2,  // "🧖🏿\u200d♂️" 1F9D6-1F3FF-200D-2642-FE0F
@@ -6864,7 +8510,6 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0xA71996A4,  // "🤦🏿" 1F926-1F3FF
     0xA789269D,  // "🤹🏿\u200d♀️" 1F939-1F3FF-200D-2640-FE0F
     0xA8A3481D,  // "🤼\u200d♂️" 1F93C-200D-2642-FE0F
-    0xA8CEED3A,  // "🥈"
     0xA9227C54,  // "🧑🏿\u200d🤝\u200d🧑🏾" 1F9D1-1F3FF-200D-1F91D-200D-1F9D1-1F3FE
     0xA92CA089,  // "🧚🏽\u200d♀️" 1F9DA-1F3FD-200D-2640-FE0F
     0xA9504F13,  // "🧘🏿\u200d♂️" 1F9D8-1F3FF-200D-2642-FE0F
@@ -6879,13 +8524,8 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0xAB1C45C7,  // "🧙🏿\u200d♀️" 1F9D9-1F3FF-200D-2640-FE0F
     0xAB30CAB3,  // "🧏🏽\u200d♂️" 1F9CF-1F3FD-200D-2642-FE0F
     0xAB4EED99,  // "🧏🏿\u200d♂️" 1F9CF-1F3FF-200D-2642-FE0F
-    0xAC0DFA42,  // "🦜"
     0xAC4F92A6,  // "🧙🏾\u200d♂️" 1F9D9-1F3FE-200D-2642-FE0F
     0xAC72CA71,  // "🤏🏻" 1F90F-1F3FB
-    0xAC7DD616,  // "🦷"
-    0xAC8F9771,  // "🧡"
-    0xACA9C86F,  // "🦰"
-    0xACB16D39,  // "🤜"
     0xACECDCC3,  // "🦸\u200d♂️" 1F9B8-200D-2642-FE0F
     0xAD6D89E6,  // "🦶🏾" 1F9B6-1F3FE
     0xAD74644C,  // "🤽🏽\u200d♂️" 1F93D-1F3FD-200D-2642-FE0F
@@ -6895,7 +8535,6 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0xAE3E8633,  // "🤹🏻\u200d♂️" 1F939-1F3FB-200D-2642-FE0F
     0xAE4EA229,  // "🤾🏾" 1F93E-1F3FE
     0xAE8CD5C6,  // "🤹🏿" 1F939-1F3FF
-    0xAF0C4136,  // "🦏"
     0xAF509201,  // "🧎🏻" 1F9CE-1F3FB
     0xAF6B7ADB,  // "🧜🏾\u200d♂️" 1F9DC-1F3FE-200D-2642-FE0F
     0xAF9B3A92,  // "🧑🏼\u200d🤝\u200d🧑🏻" 1F9D1-1F3FC-200D-1F91D-200D-1F9D1-1F3FB
@@ -6912,22 +8551,15 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0xB358149F,  // "🧙🏾\u200d♀️" 1F9D9-1F3FE-200D-2640-FE0F
     0xB381CF5B,  // "🤸\u200d♀️" 1F938-200D-2640-FE0F
     0xB3DD4009,  // "🧛🏽" 1F9DB-1F3FD
-    0xB44833CC,  // "🦛"
     0xB477F610,  // "🧑🏽\u200d🏭" 1F9D1-1F3FD-200D-1F3ED
     0xB4BF5FE9,  // "🧑🏿\u200d🦼" 1F9D1-1F3FF-200D-1F9BC
-    0xB4CEED98,  // "🥇"
     0xB58333DF,  // "🤵\u200d♀️" 1F935-200D-2640-FE0F
-    0xB5955C05,  // "🦱"
     0xB5AB999B,  // "🦸🏾" 1F9B8-1F3FE
-    0xB6C30F70,  // "🦄"
     0xB6D5F094,  // "🦻🏽" 1F9BB-1F3FD
-    0xB6E3FD30,  // "🧚"
     0xB6FBC8F9,  // "🦹🏼" 1F9B9-1F3FC
     0xB736E4E6,  // "🧓🏿" 1F9D3-1F3FF
-    0xB75A38D6,  // "🦼"
     0xB77602C5,  // "🧏🏻\u200d♂️" 1F9CF-1F3FB-200D-2642-FE0F
     0xB7889780,  // "🧖🏾\u200d♂️" 1F9D6-1F3FE-200D-2642-FE0F
-    0xB7BE7A06,  // "🥔"
     0xB83B91F3,  // "🧏🏻" 1F9CF-1F3FB
     0xB8B39BAC,  // "🧒🏿" 1F9D2-1F3FF
     0xB8FE7808,  // "🧗🏿\u200d♀️" 1F9D7-1F3FF-200D-2640-FE0F
@@ -6953,63 +8585,44 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0xBDD10844,  // "🤸\u200d♂️" 1F938-200D-2642-FE0F
     0xBDDE3A32,  // "🧑🏽\u200d🤝\u200d🧑🏿" 1F9D1-1F3FD-200D-1F91D-200D-1F9D1-1F3FF
     0xBE23ECCB,  // "🧘🏻\u200d♂️" 1F9D8-1F3FB-200D-2642-FE0F
-    0xBEB718F4,  // "🤌"
     0xBF360185,  // "🧑🏿\u200d🔧" 1F9D1-1F3FF-200D-1F527
     0xBF6F5189,  // "🧑🏽\u200d🤝\u200d🧑🏾" 1F9D1-1F3FD-200D-1F91D-200D-1F9D1-1F3FE
     0xBFE923E2,  // "🤱🏾" 1F931-1F3FE
     0xC019AB67,  // "🦵🏾" 1F9B5-1F3FE
     0xC0240461,  // "🧑🏼\u200d🤝\u200d🧑🏾" 1F9D1-1F3FC-200D-1F91D-200D-1F9D1-1F3FE
-    0xC0C3C658,  // "🧖"
     0xC0EB4875,  // "🤷🏿\u200d♂️" 1F937-1F3FF-200D-2642-FE0F
     0xC1024470,  // "🧑🏿\u200d💻" 1F9D1-1F3FF-200D-1F4BB
-    0xC142A5E3,  // "🧱"
     0xC19E92D5,  // "🧑🏾\u200d🎤" 1F9D1-1F3FE-200D-1F3A4
     0xC1D6E01B,  // "🧍\u200d♀️" 1F9CD-200D-2640-FE0F
     0xC1DA022D,  // "🧑🏼\u200d🚀" 1F9D1-1F3FC-200D-1F680
-    0xC200A4E4,  // "🥛"
-    0xC208F08A,  // "🥤"
     0xC29B65FE,  // "🧑🏽\u200d🤝\u200d🧑🏽" 1F9D1-1F3FD-200D-1F91D-200D-1F9D1-1F3FD
     0xC35DFF0C,  // "🧓🏽" 1F9D3-1F3FD
     0xC362C014,  // "🧑🏽\u200d🦲" 1F9D1-1F3FD-200D-1F9B2
-    0xC36E6A6F,  // "🧃"
     0xC3959CD8,  // "🧚🏾\u200d♀️" 1F9DA-1F3FE-200D-2640-FE0F
-    0xC3CA6823,  // "🦑"
     0xC4EE5FB5,  // "🦸🏻\u200d♀️" 1F9B8-1F3FB-200D-2640-FE0F
     0xC567E25D,  // "🤦🏿\u200d♀️" 1F926-1F3FF-200D-2640-FE0F
     0xC5BC7CAE,  // "🤜🏻" 1F91C-1F3FB
     0xC5C5F509,  // "🤸🏼\u200d♂️" 1F938-1F3FC-200D-2642-FE0F
     0xC625F4A0,  // "🧑🏿\u200d🤝\u200d🧑🏿" 1F9D1-1F3FF-200D-1F91D-200D-1F9D1-1F3FF
     0xC634B690,  // "🧏🏼\u200d♂️" 1F9CF-1F3FC-200D-2642-FE0F
-    0xC66F8540,  // "🥱"
     0xC6B98A04,  // "🤚🏿" 1F91A-1F3FF
     0xC6D1AF45,  // "🧑🏾\u200d✈️" 1F9D1-1F3FE-200D-2708-FE0F
-    0xC7600427,  // "🦋"
     0xC7C93627,  // "🧑🏼\u200d✈️" 1F9D1-1F3FC-200D-2708-FE0F
     0xC7CA335A,  // "🤾\u200d♂️" 1F93E-200D-2642-FE0F
-    0xC7E224F4,  // "🤷"
     0xCA35B93C,  // "🧑🏽\u200d🔧" 1F9D1-1F3FD-200D-1F527
     0xCA57D10B,  // "🧎🏽" 1F9CE-1F3FD
     0xCA58CCC8,  // "🧍🏽\u200d♂️" 1F9CD-1F3FD-200D-2642-FE0F
-    0xCAA8EBDB,  // "🥬"
-    0xCB09E943,  // "🦲"
-    0xCBC1DB53,  // "🦯"
     0xCBC5B570,  // "🤙🏿" 1F919-1F3FF
     0xCBEE7C19,  // "🧑🏻\u200d🦲" 1F9D1-1F3FB-200D-1F9B2
-    0xCC3B8EA7,  // "🥘"
-    0xCC511483,  // "🥯"
     0xCC5682DC,  // "🧑🏽\u200d🦼" 1F9D1-1F3FD-200D-1F9BC
     0xCC73B9F6,  // "🤸🏿" 1F938-1F3FF
-    0xCC792ED2,  // "🧨"
     0xCCA5B1A3,  // "🧘🏾\u200d♂️" 1F9D8-1F3FE-200D-2642-FE0F
     0xCCC49066,  // "🧑🏻\u200d💻" 1F9D1-1F3FB-200D-1F4BB
-    0xCD8A6BE3,  // "🧘"
     0xCDB3D26B,  // "🤦🏽\u200d♀️" 1F926-1F3FD-200D-2640-FE0F
     0xCDF7E99B,  // "🧖🏽" 1F9D6-1F3FD
     0xCF2CD0C6,  // "🧑🏼\u200d🏭" 1F9D1-1F3FC-200D-1F3ED
-    0xCFBDD9FE,  // "🧾"
     0xCFFC5F68,  // "🧘🏻\u200d♀️" 1F9D8-1F3FB-200D-2640-FE0F
     0xD0A146E9,  // "🧑🏿\u200d🎄" 1F9D1-1F3FF-200D-1F384
-    0xD0B5CBAA,  // "🦍"
     0xD0CCC0C9,  // "🤷\u200d♀️" 1F937-200D-2640-FE0F
     0xD0DBBC2E,  // "🧑🏿\u200d🤝\u200d🧑🏼" 1F9D1-1F3FF-200D-1F91D-200D-1F9D1-1F3FC
     0xD14CB0F5,  // "🦸🏻\u200d♂️" 1F9B8-1F3FB-200D-2642-FE0F
@@ -7017,105 +8630,80 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0xD229EE27,  // "🤦🏿\u200d♂️" 1F926-1F3FF-200D-2642-FE0F
     0xD23C981E,  // "🤰🏼" 1F930-1F3FC
     0xD263532E,  // "🧎🏼\u200d♂️" 1F9CE-1F3FC-200D-2642-FE0F
-    0xD2E1483F,  // "🤴"
     0xD2F9FFFD,  // "🧑🏻\u200d🤝\u200d🧑🏽" 1F9D1-1F3FB-200D-1F91D-200D-1F9D1-1F3FD
     0xD4933D23,  // "🧑🏻" 1F9D1-1F3FB
     0xD5B57544,  // "🤽🏽" 1F93D-1F3FD
     0xD630CBAA,  // "🧑🏼\u200d🦲" 1F9D1-1F3FC-200D-1F9B2
-    0xD6760CA2,  // "🥊"
     0xD70E8344,  // "🤹🏾" 1F939-1F3FE
     0xD71C164F,  // "🤸🏽\u200d♂️" 1F938-1F3FD-200D-2642-FE0F
     0xD7597BB8,  // "🧎🏿\u200d♂️" 1F9CE-1F3FF-200D-2642-FE0F
     0xD7D4686F,  // "🦻🏾" 1F9BB-1F3FE
-    0xD7DD2DD3,  // "🧣"
     0xD87ACDE0,  // "🤏🏽" 1F90F-1F3FD
-    0xD8CF7E70,  // "🧆"
     0xD9A11C42,  // "🦸🏽\u200d♀️" 1F9B8-1F3FD-200D-2640-FE0F
     0xD9A9AB29,  // "🤞🏽" 1F91E-1F3FD
     0xD9F0008C,  // "🧜\u200d♀️" 1F9DC-200D-2640-FE0F
     0xD9F08FEF,  // "🥷🏾" 1F977-1F3FE
     0xD9F74EBE,  // "🧑🏻\u200d🎤" 1F9D1-1F3FB-200D-1F3A4
-    0xDA36A68E,  // "🥓"
     0xDA4BC6AD,  // "🧍🏿" 1F9CD-1F3FF
     0xDA57395D,  // "🧑🏽\u200d🦱" 1F9D1-1F3FD-200D-1F9B1
     0xDB90E687,  // "🤹🏼\u200d♂️" 1F939-1F3FC-200D-2642-FE0F
     0xDBAC18BC,  // "🧑🏿\u200d🎨" 1F9D1-1F3FF-200D-1F3A8
     0xDBAD0667,  // "🧑\u200d🦯" 1F9D1-200D-1F9AF
     0xDC070DEF,  // "🧎🏼" 1F9CE-1F3FC
-    0xDC370115,  // "🥨"
     0xDC3C0EE6,  // "🤰🏾" 1F930-1F3FE
     0xDC49C08F,  // "🧜🏼\u200d♀️" 1F9DC-1F3FC-200D-2640-FE0F
     0xDC705755,  // "🤲🏿" 1F932-1F3FF
     0xDC79E8BB,  // "🧝🏿" 1F9DD-1F3FF
     0xDCCF926F,  // "🤟🏽" 1F91F-1F3FD
-    0xDD06A35D,  // "🧍"
     0xDD90F070,  // "🧑🏻\u200d🎓" 1F9D1-1F3FB-200D-1F393
     0xDD914C68,  // "🧑\u200d🔧" 1F9D1-200D-1F527
     0xDD966D97,  // "🦸🏽" 1F9B8-1F3FD
     0xDDACDF45,  // "🧙🏼\u200d♂️" 1F9D9-1F3FC-200D-2642-FE0F
     0xDDBEF8C6,  // "🧘🏻" 1F9D8-1F3FB
-    0xDE0E6CE1,  // "🦅"
-    0xDE210892,  // "🤬"
     0xDEC2E4E4,  // "🧗🏽\u200d♀️" 1F9D7-1F3FD-200D-2640-FE0F
-    0xDED16434,  // "🦓"
     0xDF212789,  // "🧑🏽\u200d🎨" 1F9D1-1F3FD-200D-1F3A8
     0xDF311DB9,  // "🤾🏾\u200d♂️" 1F93E-1F3FE-200D-2642-FE0F
     0xDF3BCC09,  // "🥷🏻" 1F977-1F3FB
     0xDF81BEC8,  // "🧏\u200d♀️" 1F9CF-200D-2640-FE0F
-    0xDFA52C6E,  // "🦢"
     0xDFB769DE,  // "🧛🏾" 1F9DB-1F3FE
-    0xDFC08D15,  // "🥣"
-    0xE0A11816,  // "🧭"
-    0xE0AF2CD3,  // "🥑"
     0xE0BE636B,  // "🧍🏻" 1F9CD-1F3FB
     0xE0D3EBF7,  // "🦵🏻" 1F9B5-1F3FB
-    0xE14EBA86,  // "🧯"
     0xE1CCFAE1,  // "🦹🏾" 1F9B9-1F3FE
     0xE1FF6F12,  // "🧝🏿\u200d♀️" 1F9DD-1F3FF-200D-2640-FE0F
     0xE2ED644E,  // "🤛🏾" 1F91B-1F3FE
-    0xE2F862B9,  // "🤪"
     0xE4B0577A,  // "🧞\u200d♂️" 1F9DE-200D-2642-FE0F
     0xE4D214A7,  // "🤞🏿" 1F91E-1F3FF
     0xE52A4A18,  // "🧝🏼" 1F9DD-1F3FC
     0xE5F1DFD9,  // "🤜🏼" 1F91C-1F3FC
     0xE6541920,  // "🧑🏿\u200d🦰" 1F9D1-1F3FF-200D-1F9B0
     0xE663CB31,  // "🧖🏼" 1F9D6-1F3FC
-    0xE6B8AAD3,  // "🤚"
     0xE7AA5806,  // "🧚🏻" 1F9DA-1F3FB
-    0xE7E8BC6A,  // "🦁"
     0xE81968F6,  // "🤌🏾" 1F90C-1F3FE
     0xE8674739,  // "🧏🏼" 1F9CF-1F3FC
     0xE8931AC3,  // "🤽🏻" 1F93D-1F3FB
-    0xE8C6EDED,  // "🤗"
     0xE954E5BD,  // "🤷🏽\u200d♀️" 1F937-1F3FD-200D-2640-FE0F
     0xE969D762,  // "🧛🏾\u200d♂️" 1F9DB-1F3FE-200D-2642-FE0F
     0xE989A3D2,  // "🧕🏽" 1F9D5-1F3FD
     0xEA9B4BF9,  // "🧍🏼\u200d♀️" 1F9CD-1F3FC-200D-2640-FE0F
     0xEB256D56,  // "🧑🏻\u200d🔬" 1F9D1-1F3FB-200D-1F52C
     0xEB570002,  // "🤛🏿" 1F91B-1F3FF
-    0xEB9E30CC,  // "🦊"
     0xECAAFAC8,  // "🧚🏻\u200d♂️" 1F9DA-1F3FB-200D-2642-FE0F
-    0xECB5FCCA,  // "🥦"
     0xED25E8E1,  // "🧑🏾" 1F9D1-1F3FE
     0xED7F1F29,  // "🧑🏾\u200d🤝\u200d🧑🏾" 1F9D1-1F3FE-200D-1F91D-200D-1F9D1-1F3FE
-    0xED91ACA9,  // "🧇"
     0xEDCDBBEA,  // "🧚🏾\u200d♂️" 1F9DA-1F3FE-200D-2642-FE0F
     0xEDF58F82,  // "🤦🏾" 1F926-1F3FE
     0xEDF9CE81,  // "🤷🏻\u200d♀️" 1F937-1F3FB-200D-2640-FE0F
     0xEE627433,  // "🤹\u200d♂️" 1F939-200D-2642-FE0F
-    0xEF1AA5A1,  // "🦻"
     0xEF226E3C,  // "🧑🏿\u200d🎤" 1F9D1-1F3FF-200D-1F3A4
     0xEF2D6409,  // "🧑🏻\u200d🔧" 1F9D1-1F3FB-200D-1F527
     0xEF6DA9F0,  // "🧑🏿" 1F9D1-1F3FF
     0xEF72E91B,  // "🤵\u200d♂️" 1F935-200D-2642-FE0F
-    0xEFCB8561,  // "🥄"
     0xF06129D6,  // "🤽🏽\u200d♀️" 1F93D-1F3FD-200D-2640-FE0F
     0xF089E092,  // "🤵🏾\u200d♂️" 1F935-1F3FE-200D-2642-FE0F
     0xF0920D15,  // "🤵🏽\u200d♀️" 1F935-1F3FD-200D-2640-FE0F
     0xF122FB66,  // "🤦🏻\u200d♀️" 1F926-1F3FB-200D-2640-FE0F
     0xF1BEBA5F,  // "🧑🏿\u200d⚕️" 1F9D1-1F3FF-200D-2695-FE0F
     0xF1EC3896,  // "🧎🏿" 1F9CE-1F3FF
-    0xF24128DF,  // "🥫"
     0xF250E316,  // "🤸🏻" 1F938-1F3FB
     0xF265A246,  // "🧘🏼" 1F9D8-1F3FC
     0xF3AB6F81,  // "🤸🏾\u200d♂️" 1F938-1F3FE-200D-2642-FE0F
@@ -7124,57 +8712,687 @@ const HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 882] = [
     0xF4E31F01,  // "🧝🏿\u200d♂️" 1F9DD-1F3FF-200D-2642-FE0F
     0xF5499E4B,  // "🧎🏻\u200d♂️" 1F9CE-1F3FB-200D-2642-FE0F
     0xF553678C,  // "🧑🏾\u200d🎨" 1F9D1-1F3FE-200D-1F3A8
-    0xF6411E67,  // "🧤"
     0xF6F1112F,  // "🤷🏼\u200d♀️" 1F937-1F3FC-200D-2640-FE0F
     0xF75232E4,  // "🧑🏾\u200d💼" 1F9D1-1F3FE-200D-1F4BC
     0xF79C7AC3,  // "🧖🏼\u200d♀️" 1F9D6-1F3FC-200D-2640-FE0F
     0xF7EDE269,  // "🤽🏾\u200d♀️" 1F93D-1F3FE-200D-2640-FE0F
     0xF8212956,  // "🤷🏿\u200d♀️" 1F937-1F3FF-200D-2640-FE0F
     0xF8747FBD,  // "🧏\u200d♂️" 1F9CF-200D-2642-FE0F
-    0xF8AF1BA0,  // "🤞"
     0xF8C763C3,  // "🧑🏽\u200d🎤" 1F9D1-1F3FD-200D-1F3A4
     0xF8CD80FA,  // "🦹🏽\u200d♀️" 1F9B9-1F3FD-200D-2640-FE0F
     0xF9303BA8,  // "🤌🏼" 1F90C-1F3FC
-    0xF93C660B,  // "🥋"
     0xF93D9129,  // "🧑\u200d🍼" 1F9D1-200D-1F37C
-    0xF978896E,  // "🥡"
     0xF981F9B5,  // "🤸🏼" 1F938-1F3FC
     0xF9E681FA,  // "🤷🏾\u200d♂️" 1F937-1F3FE-200D-2642-FE0F
     0xFA1E2747,  // "🧓🏼" 1F9D3-1F3FC
-    0xFA237666,  // "🦙"
     0xFA2D783A,  // "🧝🏼\u200d♂️" 1F9DD-1F3FC-200D-2642-FE0F
-    0xFA5C3615,  // "🧠"
     0xFAA9ED18,  // "🧜🏿\u200d♀️" 1F9DC-1F3FF-200D-2640-FE0F
     0xFB57039F,  // "🧑🏾\u200d🏭" 1F9D1-1F3FE-200D-1F3ED
-    0xFBF86378,  // "🧮"
-    0xFC450E43,  // "🤢"
     0xFC703B72,  // "🧜🏿\u200d♂️" 1F9DC-1F3FF-200D-2642-FE0F
-    0xFD236471,  // "🤿"
-    0xFDCA4540,  // "🧔"
-    0xFE3BF70E,  // "🥉"
     0xFEA14EA0,  // "🧑🏿\u200d✈️" 1F9D1-1F3FF-200D-2708-FE0F
     0xFEDBCAF3,  // "🧑🏾\u200d🤝\u200d🧑🏻" 1F9D1-1F3FE-200D-1F91D-200D-1F9D1-1F3FB
     0xFF167425,  // "🧑🏾\u200d🦱" 1F9D1-1F3FE-200D-1F9B1
     0xFF6596EE,  // "🧑🏼\u200d🎨" 1F9D1-1F3FC-200D-1F3A8
-    0xFFA96F5B,  // "🤥"
     0xFFCE5538,  // "🧑🏿\u200d🦱" 1F9D1-1F3FF-200D-1F9B1
 ];
 
+/// Verification keys to rule out hash collisions; sort matches HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS
+static KEY_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [u32; 642] = [
+    0xEF3FE6D4,  // "🧜🏽" 1F9DC-1F3FD
+    0xFE990F0A,  // "🤦🏻\u200d♂️" 1F926-1F3FB-200D-2642-FE0F
+    0xAF0A4D9C,  // "🧑🏾\u200d🍼" 1F9D1-1F3FE-200D-1F37C
+    0xD2636CDA,  // "🤾🏽" 1F93E-1F3FD
+    0x8B809C87,  // "🤸🏽\u200d♀️" 1F938-1F3FD-200D-2640-FE0F
+    0x956337E1,  // "🧗\u200d♀️" 1F9D7-200D-2640-FE0F
+    0xC509B848,  // "🧑\u200d🦱" 1F9D1-200D-1F9B1
+    0xE5912CF9,  // "🤞🏾" 1F91E-1F3FE
+    0x82438DDC,  // "🧗🏿" 1F9D7-1F3FF
+    0xDEB53D47,  // "🧍🏾\u200d♂️" 1F9CD-1F3FE-200D-2642-FE0F
+    0xAF08D844,  // "🧚🏿" 1F9DA-1F3FF
+    0xE64D684C,  // "🦹🏻\u200d♂️" 1F9B9-1F3FB-200D-2642-FE0F
+    0xB10FDF9E,  // "🤶🏼" 1F936-1F3FC
+    0xDDA8DD79,  // "🤷🏿" 1F937-1F3FF
+    0x857D98D6,  // "🤌🏻" 1F90C-1F3FB
+    0xB2F7166F,  // "🧏🏽\u200d♀️" 1F9CF-1F3FD-200D-2640-FE0F
+    0x85917732,  // "🤵🏾" 1F935-1F3FE
+    0xBFD4A68E,  // "🧑🏻\u200d🤝\u200d🧑🏼" 1F9D1-1F3FB-200D-1F91D-200D-1F9D1-1F3FC
+    0x916B6D0A,  // "🧖🏽\u200d♂️" 1F9D6-1F3FD-200D-2642-FE0F
+    0xC688D404,  // "🧎\u200d♂️" 1F9CE-200D-2642-FE0F
+    0xFD4D919C,  // "🤹🏽\u200d♂️" 1F939-1F3FD-200D-2642-FE0F
+    0xF094C7F8,  // "🤾🏿\u200d♀️" 1F93E-1F3FF-200D-2640-FE0F
+    0x8CEF7DD8,  // "🤵🏽" 1F935-1F3FD
+    0xFC4FC4ED,  // "🤘🏽" 1F918-1F3FD
+    0xF540A4EA,  // "🧝🏻\u200d♀️" 1F9DD-1F3FB-200D-2640-FE0F
+    0xFB646100,  // "🧑🏿\u200d🔬" 1F9D1-1F3FF-200D-1F52C
+    0xD997754D,  // "🤌🏽" 1F90C-1F3FD
+    0xC74FEB58,  // "🧏🏾\u200d♀️" 1F9CF-1F3FE-200D-2640-FE0F
+    0xCD59ABC7,  // "🦵🏽" 1F9B5-1F3FD
+    0xAA715CA0,  // "🧗🏼" 1F9D7-1F3FC
+    0xAC0E967D,  // "🤱🏿" 1F931-1F3FF
+    0xC4DA7D5E,  // "🤷🏽\u200d♂️" 1F937-1F3FD-200D-2642-FE0F
+    0xF08092B0,  // "🤾🏼" 1F93E-1F3FC
+    0xCEB1D7C5,  // "🧑\u200d🦲" 1F9D1-200D-1F9B2
+    0xE971A7DB,  // "🧑🏼\u200d🤝\u200d🧑🏽" 1F9D1-1F3FC-200D-1F91D-200D-1F9D1-1F3FD
+    0x9E5A9390,  // "🧑🏻\u200d🍳" 1F9D1-1F3FB-200D-1F373
+    0xCC2F1238,  // "🤸🏾" 1F938-1F3FE
+    0x991CFF39,  // "🧔🏻" 1F9D4-1F3FB
+    0xA375F8B2,  // "🧍🏿\u200d♂️" 1F9CD-1F3FF-200D-2642-FE0F
+    0x9A13E9A4,  // "🧑🏾\u200d⚕️" 1F9D1-1F3FE-200D-2695-FE0F
+    0xFD6B58EB,  // "🤳🏻" 1F933-1F3FB
+    0xC3535849,  // "🤷🏾\u200d♀️" 1F937-1F3FE-200D-2640-FE0F
+    0xED7F84CC,  // "🧑🏻\u200d🍼" 1F9D1-1F3FB-200D-1F37C
+    0x9A96048B,  // "🧖🏻\u200d♂️" 1F9D6-1F3FB-200D-2642-FE0F
+    0x804EBB70,  // "🧑🏿\u200d🎓" 1F9D1-1F3FF-200D-1F393
+    0xFDA33E10,  // "🧖🏾\u200d♀️" 1F9D6-1F3FE-200D-2640-FE0F
+    0xBCF52B52,  // "🧗🏼\u200d♀️" 1F9D7-1F3FC-200D-2640-FE0F
+    0xFA37053D,  // "🧍\u200d♂️" 1F9CD-200D-2642-FE0F
+    0xFB85848B,  // "🤽🏼" 1F93D-1F3FC
+    0x9A08BB53,  // "🧑🏼\u200d🎄" 1F9D1-1F3FC-200D-1F384
+    0x8AB851BF,  // "🧎🏽\u200d♂️" 1F9CE-1F3FD-200D-2642-FE0F
+    0xADB62EB5,  // "🧚🏼" 1F9DA-1F3FC
+    0xB2B2EDE7,  // "🧛🏼\u200d♂️" 1F9DB-1F3FC-200D-2642-FE0F
+    0xBC79DD15,  // "🧜\u200d♂️" 1F9DC-200D-2642-FE0F
+    0x93E3E22C,  // "🦹🏾\u200d♀️" 1F9B9-1F3FE-200D-2640-FE0F
+    0xEABBE29F,  // "🧑🏽" 1F9D1-1F3FD
+    0xCFB6F74A,  // "🤽🏿\u200d♂️" 1F93D-1F3FF-200D-2642-FE0F
+    0xF0CD07F1,  // "🧗🏾\u200d♂️" 1F9D7-1F3FE-200D-2642-FE0F
+    0xE849C09B,  // "🧑🏼\u200d🦯" 1F9D1-1F3FC-200D-1F9AF
+    0xA82B89F7,  // "🧑🏿\u200d🚒" 1F9D1-1F3FF-200D-1F692
+    0x9C5CD1FF,  // "🧎🏾" 1F9CE-1F3FE
+    0x9B6E7CF6,  // "🤷🏽" 1F937-1F3FD
+    0x9DA80650,  // "🧘\u200d♀️" 1F9D8-200D-2640-FE0F
+    0xE6CB7D28,  // "🧕🏼" 1F9D5-1F3FC
+    0xC8828CC0,  // "🤚🏼" 1F91A-1F3FC
+    0x8711AB97,  // "🧘🏼\u200d♂️" 1F9D8-1F3FC-200D-2642-FE0F
+    0xC95AAEEA,  // "🧑🏿\u200d🦳" 1F9D1-1F3FF-200D-1F9B3
+    0xC63A1F3B,  // "🧖🏿" 1F9D6-1F3FF
+    0xD4490189,  // "🧔🏼" 1F9D4-1F3FC
+    0xB376A75E,  // "🤶🏿" 1F936-1F3FF
+    0xDA87916E,  // "🤴🏿" 1F934-1F3FF
+    0xAF86175D,  // "🤦🏽" 1F926-1F3FD
+    0xEAE4B4BA,  // "🤽🏻\u200d♂️" 1F93D-1F3FB-200D-2642-FE0F
+    0xF1BAB92D,  // "🧑🏽\u200d🤝\u200d🧑🏻" 1F9D1-1F3FD-200D-1F91D-200D-1F9D1-1F3FB
+    0x914E7900,  // "🧒🏻" 1F9D2-1F3FB
+    0x91A108D2,  // "🧑🏻\u200d🚒" 1F9D1-1F3FB-200D-1F692
+    0x84DEAABA,  // "🧏🏼\u200d♀️" 1F9CF-1F3FC-200D-2640-FE0F
+    0xF528B23E,  // "🧚🏻\u200d♀️" 1F9DA-1F3FB-200D-2640-FE0F
+    0xA513113F,  // "🧑\u200d🦼" 1F9D1-200D-1F9BC
+    0x873C66C2,  // "🦹\u200d♀️" 1F9B9-200D-2640-FE0F
+    0x9D3946EF,  // "🧑🏼\u200d💻" 1F9D1-1F3FC-200D-1F4BB
+    0x9ABA69C8,  // "🧑🏿\u200d🦽" 1F9D1-1F3FF-200D-1F9BD
+    0xB32ECFFB,  // "🧑🏼\u200d🎓" 1F9D1-1F3FC-200D-1F393
+    0xB215AC05,  // "🧑🏿\u200d💼" 1F9D1-1F3FF-200D-1F4BC
+    0xD0F67AE6,  // "🤸🏾\u200d♀️" 1F938-1F3FE-200D-2640-FE0F
+    0xEFAEBD58,  // "🧘🏾\u200d♀️" 1F9D8-1F3FE-200D-2640-FE0F
+    0xC75251E0,  // "🤹🏾\u200d♂️" 1F939-1F3FE-200D-2642-FE0F
+    0x96575009,  // "🧑🏽\u200d🍼" 1F9D1-1F3FD-200D-1F37C
+    0xE1513B67,  // "🧎🏻\u200d♀️" 1F9CE-1F3FB-200D-2640-FE0F
+    0x83D64FE0,  // "🤲🏻" 1F932-1F3FB
+    0xEDBD0C12,  // "🦵🏿" 1F9B5-1F3FF
+    0xF368B30E,  // "🤽🏿" 1F93D-1F3FF
+    0xC1A129F6,  // "🤼\u200d♀️" 1F93C-200D-2640-FE0F
+    0xEC1E709A,  // "🧑🏽\u200d🦰" 1F9D1-1F3FD-200D-1F9B0
+    0xD03495F7,  // "🧝🏾" 1F9DD-1F3FE
+    0x84C18A2B,  // "🧙🏻\u200d♀️" 1F9D9-1F3FB-200D-2640-FE0F
+    0xAF4B7A65,  // "🧑🏽\u200d💼" 1F9D1-1F3FD-200D-1F4BC
+    0xC0BF9BB5,  // "🧗🏾\u200d♀️" 1F9D7-1F3FE-200D-2640-FE0F
+    0x85922637,  // "🦵🏼" 1F9B5-1F3FC
+    0xCED35DC9,  // "🧑\u200d🍳" 1F9D1-200D-1F373
+    0xE9CDCCC0,  // "🧛🏻\u200d♀️" 1F9DB-1F3FB-200D-2640-FE0F
+    0xDDA10379,  // "🧜🏼\u200d♂️" 1F9DC-1F3FC-200D-2642-FE0F
+    0xCFA39BFA,  // "🦸🏼\u200d♂️" 1F9B8-1F3FC-200D-2642-FE0F
+    0xB06ABA1A,  // "🧏🏾" 1F9CF-1F3FE
+    0x8707507C,  // "🧛🏿\u200d♂️" 1F9DB-1F3FF-200D-2642-FE0F
+    0xB9E8DB56,  // "🧛🏻" 1F9DB-1F3FB
+    0xED54F7D9,  // "🧑🏾\u200d🎓" 1F9D1-1F3FE-200D-1F393
+    0xD6B806C1,  // "🧑🏽\u200d🤝\u200d🧑🏼" 1F9D1-1F3FD-200D-1F91D-200D-1F9D1-1F3FC
+    0xC118712B,  // "🧑🏼\u200d⚖️" 1F9D1-1F3FC-200D-2696-FE0F
+    0xC90BED6E,  // "🤸🏼\u200d♀️" 1F938-1F3FC-200D-2640-FE0F
+    0xC86B4378,  // "🧕🏻" 1F9D5-1F3FB
+    0xCAC363A4,  // "🧑\u200d🔬" 1F9D1-200D-1F52C
+    0xBFABEC27,  // "🤷🏼" 1F937-1F3FC
+    0xA8813913,  // "🧜🏻\u200d♀️" 1F9DC-1F3FB-200D-2640-FE0F
+    0xA47D63E6,  // "🤲🏾" 1F932-1F3FE
+    0xE204E3C4,  // "🧑\u200d✈️" 1F9D1-200D-2708-FE0F
+    0x8C626AD5,  // "🧑🏼\u200d🦰" 1F9D1-1F3FC-200D-1F9B0
+    0x80AE6EFE,  // "🧑🏾\u200d🦼" 1F9D1-1F3FE-200D-1F9BC
+    0xE4090EAC,  // "🧓🏻" 1F9D3-1F3FB
+    0x88B0543F,  // "🤵🏻" 1F935-1F3FB
+    0xA58CD30B,  // "🤵🏼" 1F935-1F3FC
+    0xFA9B044E,  // "🧑\u200d💻" 1F9D1-200D-1F4BB
+    0x8F8F2E86,  // "🤱🏽" 1F931-1F3FD
+    0x92030A7A,  // "🤽🏾" 1F93D-1F3FE
+    0x91B3BCE4,  // "🧜🏿" 1F9DC-1F3FF
+    0xCD7A3E99,  // "🧑🏼\u200d🍳" 1F9D1-1F3FC-200D-1F373
+    0xD0943FA9,  // "🧖\u200d♀️" 1F9D6-200D-2640-FE0F
+    0xD6927328,  // "🤷🏻" 1F937-1F3FB
+    0x99803DC0,  // "🧍🏼\u200d♂️" 1F9CD-1F3FC-200D-2642-FE0F
+    0x92D88D2C,  // "🧜🏾\u200d♀️" 1F9DC-1F3FE-200D-2640-FE0F
+    0x9F1A2AA7,  // "🧚🏼\u200d♀️" 1F9DA-1F3FC-200D-2640-FE0F
+    0x91AD577D,  // "🧙🏻" 1F9D9-1F3FB
+    0xE843544A,  // "🧟\u200d♀️" 1F9DF-200D-2640-FE0F
+    0xA48D9139,  // "🤾🏻\u200d♂️" 1F93E-1F3FB-200D-2642-FE0F
+    0xEF4F749A,  // "🧗🏻" 1F9D7-1F3FB
+    0xABA1AB17,  // "🤳🏿" 1F933-1F3FF
+    0xD12B80FA,  // "🤳🏼" 1F933-1F3FC
+    0xC621BFF2,  // "🧎🏿\u200d♀️" 1F9CE-1F3FF-200D-2640-FE0F
+    0xFEDE91E6,  // "🤦🏾\u200d♂️" 1F926-1F3FE-200D-2642-FE0F
+    0x89E2028C,  // "🧚🏽" 1F9DA-1F3FD
+    0x9B52963E,  // "🧜🏽\u200d♂️" 1F9DC-1F3FD-200D-2642-FE0F
+    0xD99F98AE,  // "🧑🏾\u200d🤝\u200d🧑🏿" 1F9D1-1F3FE-200D-1F91D-200D-1F9D1-1F3FF
+    0xE94326B6,  // "🤽\u200d♂️" 1F93D-200D-2642-FE0F
+    0xCF6C9B0B,  // "🧝🏼\u200d♀️" 1F9DD-1F3FC-200D-2640-FE0F
+    0xC3ACF2E0,  // "🧖🏼\u200d♂️" 1F9D6-1F3FC-200D-2642-FE0F
+    0xDAADF368,  // "🧚\u200d♂️" 1F9DA-200D-2642-FE0F
+    0x8F6BAEC6,  // "🤟🏼" 1F91F-1F3FC
+    0xB7061F8F,  // "🧑🏼\u200d🤝\u200d🧑🏿" 1F9D1-1F3FC-200D-1F91D-200D-1F9D1-1F3FF
+    0xAFE11FDE,  // "🧗\u200d♂️" 1F9D7-200D-2642-FE0F
+    0xDE1B3EF2,  // "🧗🏽\u200d♂️" 1F9D7-1F3FD-200D-2642-FE0F
+    0xA7BB663C,  // "🤾🏽\u200d♀️" 1F93E-1F3FD-200D-2640-FE0F
+    0xC8AFCA3C,  // "🧓🏾" 1F9D3-1F3FE
+    0x93AC1E41,  // "🤹🏽" 1F939-1F3FD
+    0xEBC19FCB,  // "🧎🏾\u200d♂️" 1F9CE-1F3FE-200D-2642-FE0F
+    0xC8B134DD,  // "🤾🏽\u200d♂️" 1F93E-1F3FD-200D-2642-FE0F
+    0xD795A9F0,  // "🧑🏻\u200d🦼" 1F9D1-1F3FB-200D-1F9BC
+    0xACD19CF1,  // "🧙🏻\u200d♂️" 1F9D9-1F3FB-200D-2642-FE0F
+    0xF4CB3F48,  // "🧑🏽\u200d🏫" 1F9D1-1F3FD-200D-1F3EB
+    0x86C1277D,  // "🧒🏽" 1F9D2-1F3FD
+    0x9203AC4F,  // "🧒🏼" 1F9D2-1F3FC
+    0xF16F1357,  // "🧖🏿\u200d♀️" 1F9D6-1F3FF-200D-2640-FE0F
+    0xE786E301,  // "🤹🏾\u200d♀️" 1F939-1F3FE-200D-2640-FE0F
+    0x8F57DEF8,  // "🧛🏿" 1F9DB-1F3FF
+    0xC7FB3498,  // "🤷🏻\u200d♂️" 1F937-1F3FB-200D-2642-FE0F
+    0xEFF8DD9D,  // "🧍🏽" 1F9CD-1F3FD
+    0xB397A271,  // "🦻🏼" 1F9BB-1F3FC
+    0xD04EC62D,  // "🧛🏼" 1F9DB-1F3FC
+    0xB631AA04,  // "🦸🏿\u200d♀️" 1F9B8-1F3FF-200D-2640-FE0F
+    0xE2E33C64,  // "🧑🏻\u200d🦯" 1F9D1-1F3FB-200D-1F9AF
+    0xF999B161,  // "🧑\u200d🏭" 1F9D1-200D-1F3ED
+    0xA993E47D,  // "🧛🏽\u200d♂️" 1F9DB-1F3FD-200D-2642-FE0F
+    0xD8B36F45,  // "🧑🏻\u200d🤝\u200d🧑🏻" 1F9D1-1F3FB-200D-1F91D-200D-1F9D1-1F3FB
+    0xEC979225,  // "🧑\u200d🎓" 1F9D1-200D-1F393
+    0xBAA88687,  // "🦸🏻" 1F9B8-1F3FB
+    0xED8865A9,  // "🤙🏻" 1F919-1F3FB
+    0xE230AD8F,  // "🧗🏼\u200d♂️" 1F9D7-1F3FC-200D-2642-FE0F
+    0xCF48F8D3,  // "🧑\u200d💼" 1F9D1-200D-1F4BC
+    0x9DFC8D15,  // "🧑🏼\u200d🤝\u200d🧑🏼" 1F9D1-1F3FC-200D-1F91D-200D-1F9D1-1F3FC
+    0xB2D6628F,  // "🤵🏿\u200d♀️" 1F935-1F3FF-200D-2640-FE0F
+    0xA5874AF9,  // "🧙🏽" 1F9D9-1F3FD
+    0xF5829017,  // "🧑🏼\u200d🍼" 1F9D1-1F3FC-200D-1F37C
+    0xBC016F83,  // "🤹🏼\u200d♀️" 1F939-1F3FC-200D-2640-FE0F
+    0x8C1DC3E7,  // "🧑🏼\u200d⚕️" 1F9D1-1F3FC-200D-2695-FE0F
+    0xB73FFF00,  // "🧑🏾\u200d🦯" 1F9D1-1F3FE-200D-1F9AF
+    0xC0DA0DB4,  // "🧑🏽\u200d🌾" 1F9D1-1F3FD-200D-1F33E
+    0xEF4AFE79,  // "🧛🏿\u200d♀️" 1F9DB-1F3FF-200D-2640-FE0F
+    0xED3DE9A4,  // "🧑🏼\u200d🦱" 1F9D1-1F3FC-200D-1F9B1
+    0x8C76CE6F,  // "🤹🏽\u200d♀️" 1F939-1F3FD-200D-2640-FE0F
+    0xDB237BDA,  // "🧛\u200d♀️" 1F9DB-200D-2640-FE0F
+    0xEC7D4A16,  // "🦹🏻" 1F9B9-1F3FB
+    0xAE4EF33A,  // "🧑🏻\u200d🎄" 1F9D1-1F3FB-200D-1F384
+    0xCD567E0D,  // "🧑🏼" 1F9D1-1F3FC
+    0xE4C299D1,  // "🤳🏾" 1F933-1F3FE
+    0xFB853E8F,  // "🧑🏾\u200d🦰" 1F9D1-1F3FE-200D-1F9B0
+    0x8102067B,  // "🤲🏼" 1F932-1F3FC
+    0x90A7C1A8,  // "🤦\u200d♀️" 1F926-200D-2640-FE0F
+    0x956FCF1E,  // "🤛🏽" 1F91B-1F3FD
+    0x956F87B4,  // "🧍🏼" 1F9CD-1F3FC
+    0xCF7588EA,  // "🤌🏿" 1F90C-1F3FF
+    0x8985F192,  // "🧑\u200d🚒" 1F9D1-200D-1F692
+    0xF8EC8022,  // "🧑🏻\u200d✈️" 1F9D1-1F3FB-200D-2708-FE0F
+    0xDB3179E9,  // "🧑\u200d🦳" 1F9D1-200D-1F9B3
+    0xD5082436,  // "🤱🏼" 1F931-1F3FC
+    0xBDB2EC22,  // "🤴🏽" 1F934-1F3FD
+    0xC46C4D44,  // "🧔🏾" 1F9D4-1F3FE
+    0xB551211F,  // "🧑\u200d🎨" 1F9D1-200D-1F3A8
+    0xF12A3D2A,  // "🦹🏼\u200d♂️" 1F9B9-1F3FC-200D-2642-FE0F
+    0xEAB97F2F,  // "🤦🏻" 1F926-1F3FB
+    0xDAAB9520,  // "🧗🏾" 1F9D7-1F3FE
+    0xC1563854,  // "🧑🏼\u200d🚒" 1F9D1-1F3FC-200D-1F692
+    0x8F937EBA,  // "🧑🏼\u200d🏫" 1F9D1-1F3FC-200D-1F3EB
+    0xC996AF3C,  // "🧗🏿\u200d♂️" 1F9D7-1F3FF-200D-2642-FE0F
+    0xF4EC7433,  // "🧜🏻" 1F9DC-1F3FB
+    0xCBD6D99C,  // "🧑🏻\u200d🦱" 1F9D1-1F3FB-200D-1F9B1
+    0xE909FE82,  // "🧙🏾" 1F9D9-1F3FE
+    0xB30845AD,  // "🧖🏽\u200d♀️" 1F9D6-1F3FD-200D-2640-FE0F
+    0xBFB66F1F,  // "🤦🏽\u200d♂️" 1F926-1F3FD-200D-2642-FE0F
+    0xE37ED006,  // "🧜🏾" 1F9DC-1F3FE
+    0x82CE3F3A,  // "🤰🏻" 1F930-1F3FB
+    0xDE92E79E,  // "🤘🏼" 1F918-1F3FC
+    0xB1C0C70F,  // "🤾🏻\u200d♀️" 1F93E-1F3FB-200D-2640-FE0F
+    0x9B01B75F,  // "🧑🏿\u200d🦲" 1F9D1-1F3FF-200D-1F9B2
+    0xD18C520D,  // "🤾🏿" 1F93E-1F3FF
+    0xBF51631C,  // "🧑🏻\u200d⚕️" 1F9D1-1F3FB-200D-2695-FE0F
+    0xBB202E58,  // "🤦🏼\u200d♂️" 1F926-1F3FC-200D-2642-FE0F
+    0x96E18CE3,  // "🦻🏿" 1F9BB-1F3FF
+    0xD2B23050,  // "🤶🏾" 1F936-1F3FE
+    0x974F4833,  // "🧑🏻\u200d🏫" 1F9D1-1F3FB-200D-1F3EB
+    0xFB9BC940,  // "🤛🏻" 1F91B-1F3FB
+    0xE24672F2,  // "🧑🏿\u200d🏫" 1F9D1-1F3FF-200D-1F3EB
+    0xDBBD7E04,  // "🧗🏻\u200d♀️" 1F9D7-1F3FB-200D-2640-FE0F
+    0xD964417A,  // "🧑🏿\u200d🍼" 1F9D1-1F3FF-200D-1F37C
+    0xE5E066E3,  // "🤏🏾" 1F90F-1F3FE
+    0xDC66E391,  // "🧜🏻\u200d♂️" 1F9DC-1F3FB-200D-2642-FE0F
+    0xF7B0D9D0,  // "🦹🏿\u200d♂️" 1F9B9-1F3FF-200D-2642-FE0F
+    0xD1875D40,  // "🧑\u200d🏫" 1F9D1-200D-1F3EB
+    0xC3A11B0A,  // "🧑🏼\u200d🔧" 1F9D1-1F3FC-200D-1F527
+    0x9F945750,  // "🧙🏽\u200d♂️" 1F9D9-1F3FD-200D-2642-FE0F
+    0xC0B49186,  // "🦹🏾\u200d♂️" 1F9B9-1F3FE-200D-2642-FE0F
+    0x83A95D88,  // "🧑🏿\u200d⚖️" 1F9D1-1F3FF-200D-2696-FE0F
+    0xAFB2BDDC,  // "🧑🏽\u200d⚕️" 1F9D1-1F3FD-200D-2695-FE0F
+    0xD316CBD2,  // "🤽🏼\u200d♀️" 1F93D-1F3FC-200D-2640-FE0F
+    0xBD974102,  // "🦶🏽" 1F9B6-1F3FD
+    0xEC350372,  // "🦶🏿" 1F9B6-1F3FF
+    0x9E572A68,  // "🧚🏿\u200d♀️" 1F9DA-1F3FF-200D-2640-FE0F
+    0xA7CE5426,  // "🧖🏻\u200d♀️" 1F9D6-1F3FB-200D-2640-FE0F
+    0xAF25A3E5,  // "🦹🏼\u200d♀️" 1F9B9-1F3FC-200D-2640-FE0F
+    0xF7E2CD0D,  // "🧝🏽" 1F9DD-1F3FD
+    0xB9E408AE,  // "🤏🏼" 1F90F-1F3FC
+    0xDB28ABE5,  // "🤦🏼\u200d♀️" 1F926-1F3FC-200D-2640-FE0F
+    0xC5C6D104,  // "🧎🏾\u200d♀️" 1F9CE-1F3FE-200D-2640-FE0F
+    0xC900845E,  // "🧔🏽" 1F9D4-1F3FD
+    0xD0563761,  // "🧒🏾" 1F9D2-1F3FE
+    0xC363CF12,  // "🧑🏿\u200d🚀" 1F9D1-1F3FF-200D-1F680
+    0xBA65F0BE,  // "🧘🏿\u200d♀️" 1F9D8-1F3FF-200D-2640-FE0F
+    0x9BAC0795,  // "🤚🏻" 1F91A-1F3FB
+    0xFEAB84A9,  // "🧚🏼\u200d♂️" 1F9DA-1F3FC-200D-2642-FE0F
+    0xCEE52796,  // "🧑🏻\u200d🦳" 1F9D1-1F3FB-200D-1F9B3
+    0xB4E90476,  // "🧑🏽\u200d🎄" 1F9D1-1F3FD-200D-1F384
+    0xD904D6AD,  // "🤦\u200d♂️" 1F926-200D-2642-FE0F
+    0xC6F619D3,  // "🤷🏼\u200d♂️" 1F937-1F3FC-200D-2642-FE0F
+    0xDC884417,  // "🤹🏿\u200d♂️" 1F939-1F3FF-200D-2642-FE0F
+    0xF8BC59FA,  // "🧝🏽\u200d♀️" 1F9DD-1F3FD-200D-2640-FE0F
+    0xC1C49B09,  // "🧛🏽\u200d♀️" 1F9DB-1F3FD-200D-2640-FE0F
+    0xC0256125,  // "🧑\u200d🎤" 1F9D1-200D-1F3A4
+    0x9E29ED4C,  // "🧝\u200d♂️" 1F9DD-200D-2642-FE0F
+    0xF1B8DD2C,  // "🧛🏻\u200d♂️" 1F9DB-1F3FB-200D-2642-FE0F
+    0xC5204280,  // "🤷\u200d♂️" 1F937-200D-2642-FE0F
+    0x89A793CE,  // "🧑🏾\u200d🏫" 1F9D1-1F3FE-200D-1F3EB
+    0xCD27A234,  // "🧛🏾\u200d♀️" 1F9DB-1F3FE-200D-2640-FE0F
+    0xD3A5DA50,  // "🧙🏿" 1F9D9-1F3FF
+    0xBF064D6A,  // "🧑🏻\u200d🤝\u200d🧑🏾" 1F9D1-1F3FB-200D-1F91D-200D-1F9D1-1F3FE
+    0xD8D1F582,  // "🤵🏽\u200d♂️" 1F935-1F3FD-200D-2642-FE0F
+    0xD5C9B1F5,  // "🤵🏾\u200d♀️" 1F935-1F3FE-200D-2640-FE0F
+    0x9F28B8FB,  // "🧑🏽\u200d🔬" 1F9D1-1F3FD-200D-1F52C
+    0xFB9CB0DD,  // "🧙🏿\u200d♂️" 1F9D9-1F3FF-200D-2642-FE0F
+    0xF776833C,  // "🦶🏻" 1F9B6-1F3FB
+    0xF6FC1260,  // "🤶🏻" 1F936-1F3FB
+    0x8DA1ED05,  // "🧑\u200d⚕️" 1F9D1-200D-2695-FE0F
+    0x9115A054,  // "🧑🏾\u200d🔬" 1F9D1-1F3FE-200D-1F52C
+    0xDF19921B,  // "🧑🏻\u200d💼" 1F9D1-1F3FB-200D-1F4BC
+    0xA5C14243,  // "🧑🏽\u200d⚖️" 1F9D1-1F3FD-200D-2696-FE0F
+    0x9CC4751F,  // "🧝🏾\u200d♀️" 1F9DD-1F3FE-200D-2640-FE0F
+    0xFDCF263B,  // "🧑🏼\u200d🌾" 1F9D1-1F3FC-200D-1F33E
+    0xC2BDF04D,  // "🥷🏼" 1F977-1F3FC
+    0xAF6F8F65,  // "🧚🏿\u200d♂️" 1F9DA-1F3FF-200D-2642-FE0F
+    0xCF564FB0,  // "🤾\u200d♀️" 1F93E-200D-2640-FE0F
+    0xCD9350AE,  // "🧑\u200d🦽" 1F9D1-200D-1F9BD
+    0xB8B5FDE8,  // "🧕🏿" 1F9D5-1F3FF
+    0xA0D0DB17,  // "🤾🏼\u200d♂️" 1F93E-1F3FC-200D-2642-FE0F
+    0xB839FA4F,  // "🧑🏻\u200d🎨" 1F9D1-1F3FB-200D-1F3A8
+    0x91071D06,  // "🦸🏾\u200d♂️" 1F9B8-1F3FE-200D-2642-FE0F
+    0x880A479A,  // "🧔🏿" 1F9D4-1F3FF
+    0x9C33E3A1,  // "🧗🏽" 1F9D7-1F3FD
+    0xE364A46F,  // "🧘🏽" 1F9D8-1F3FD
+    0x9EC19ADF,  // "🧑🏽\u200d🚀" 1F9D1-1F3FD-200D-1F680
+    0x897C7208,  // "🧖🏻" 1F9D6-1F3FB
+    0xBE155536,  // "🧑🏼\u200d💼" 1F9D1-1F3FC-200D-1F4BC
+    0xA53B5A05,  // "🦸🏽\u200d♂️" 1F9B8-1F3FD-200D-2642-FE0F
+    0xB7FCBAC5,  // "🤘🏿" 1F918-1F3FF
+    0xE1019C3D,  // "🤞🏻" 1F91E-1F3FB
+    0xAC05079A,  // "🧝\u200d♀️" 1F9DD-200D-2640-FE0F
+    0x9B2F41E6,  // "🤜🏿" 1F91C-1F3FF
+    0xEC1E47B1,  // "🧍🏻\u200d♀️" 1F9CD-1F3FB-200D-2640-FE0F
+    0xC0D01D23,  // "🤸🏻\u200d♂️" 1F938-1F3FB-200D-2642-FE0F
+    0xECAA2880,  // "🤵🏿" 1F935-1F3FF
+    0xFD72E556,  // "🧍🏾\u200d♀️" 1F9CD-1F3FE-200D-2640-FE0F
+    0xD7138D73,  // "🧑🏽\u200d🦯" 1F9D1-1F3FD-200D-1F9AF
+    0xA44158F3,  // "🤘🏻" 1F918-1F3FB
+    0xA8534255,  // "🧘🏽\u200d♀️" 1F9D8-1F3FD-200D-2640-FE0F
+    0xDD06C172,  // "🤹🏼" 1F939-1F3FC
+    0x99816686,  // "🧑🏻\u200d🤝\u200d🧑🏿" 1F9D1-1F3FB-200D-1F91D-200D-1F9D1-1F3FF
+    0xE2C851EE,  // "🧍🏽\u200d♀️" 1F9CD-1F3FD-200D-2640-FE0F
+    0xFCA8423D,  // "🤷🏾" 1F937-1F3FE
+    0xCAC64DA9,  // "🧍🏿\u200d♀️" 1F9CD-1F3FF-200D-2640-FE0F
+    0xBF2CF2FB,  // "🤾🏼\u200d♀️" 1F93E-1F3FC-200D-2640-FE0F
+    0x8ADD5735,  // "🧑🏾\u200d🤝\u200d🧑🏽" 1F9D1-1F3FE-200D-1F91D-200D-1F9D1-1F3FD
+    0xB01A2F05,  // "🧙\u200d♀️" 1F9D9-200D-2640-FE0F
+    0xF3837C59,  // "🤴🏾" 1F934-1F3FE
+    0xDC8817A0,  // "🤾🏻" 1F93E-1F3FB
+    0xFAEEBB70,  // "🧚🏽\u200d♂️" 1F9DA-1F3FD-200D-2642-FE0F
+    0xE5B65966,  // "🧛🏼\u200d♀️" 1F9DB-1F3FC-200D-2640-FE0F
+    0xF180DD0E,  // "🧑🏿\u200d🤝\u200d🧑🏽" 1F9D1-1F3FF-200D-1F91D-200D-1F9D1-1F3FD
+    0xA756BB53,  // "🧑🏾\u200d🍳" 1F9D1-1F3FE-200D-1F373
+    0xD1A54AAD,  // "🧏🏻\u200d♀️" 1F9CF-1F3FB-200D-2640-FE0F
+    0xFBB3854B,  // "🤸🏿\u200d♂️" 1F938-1F3FF-200D-2642-FE0F
+    0xDCDA0457,  // "🧝🏾\u200d♂️" 1F9DD-1F3FE-200D-2642-FE0F
+    0xC4AC9E8B,  // "🤱🏻" 1F931-1F3FB
+    0xED5E89D7,  // "🦸🏼\u200d♀️" 1F9B8-1F3FC-200D-2640-FE0F
+    0xA2FCDCA0,  // "🧑🏼\u200d🎤" 1F9D1-1F3FC-200D-1F3A4
+    0xE01E40C8,  // "🤶🏽" 1F936-1F3FD
+    0x86F84D7E,  // "🥷🏽" 1F977-1F3FD
+    0xB7326AB7,  // "🧘🏿" 1F9D8-1F3FF
+    0x9F99CC1C,  // "🤟🏿" 1F91F-1F3FF
+    0xFEE76249,  // "🧑🏽\u200d🚒" 1F9D1-1F3FD-200D-1F692
+    0xAD8A670D,  // "🧑🏽\u200d🎓" 1F9D1-1F3FD-200D-1F393
+    0xF28B4A8E,  // "🧞\u200d♀️" 1F9DE-200D-2640-FE0F
+    0x846578B6,  // "🧎🏽\u200d♀️" 1F9CE-1F3FD-200D-2640-FE0F
+    0xC7B66EA2,  // "🤹\u200d♀️" 1F939-200D-2640-FE0F
+    0xD101F22E,  // "🧑🏾\u200d🦲" 1F9D1-1F3FE-200D-1F9B2
+    0xAF1214B3,  // "🧑🏻\u200d🌾" 1F9D1-1F3FB-200D-1F33E
+    0x95637BD2,  // "🧑🏼\u200d🦽" 1F9D1-1F3FC-200D-1F9BD
+    0xF744CA4E,  // "🧑\u200d🚀" 1F9D1-200D-1F680
+    0xC3D5BE4E,  // "🤵🏿\u200d♂️" 1F935-1F3FF-200D-2642-FE0F
+    0xF1F72D24,  // "🧝🏽\u200d♂️" 1F9DD-1F3FD-200D-2642-FE0F
+    0xC39E37D4,  // "🧖🏾" 1F9D6-1F3FE
+    0x83EAD128,  // "🤸🏻\u200d♀️" 1F938-1F3FB-200D-2640-FE0F
+    0xA9B7E0D8,  // "🧎🏼\u200d♀️" 1F9CE-1F3FC-200D-2640-FE0F
+    0xC68D742D,  // "🧙🏼" 1F9D9-1F3FC
+    0xF9BDFAC4,  // "🧑🏼\u200d🔬" 1F9D1-1F3FC-200D-1F52C
+    0xB3CDEBF4,  // "🧑🏾\u200d🔧" 1F9D1-1F3FE-200D-1F527
+    0xFA211662,  // "🧟\u200d♂️" 1F9DF-200D-2642-FE0F
+    0xC2F5F25D,  // "🧑🏽\u200d🦳" 1F9D1-1F3FD-200D-1F9B3
+    0x98D89DCF,  // "🤽🏼\u200d♂️" 1F93D-1F3FC-200D-2642-FE0F
+    0x82F52F51,  // "🧚🏾" 1F9DA-1F3FE
+    0xCB31F824,  // "🧝🏻\u200d♂️" 1F9DD-1F3FB-200D-2642-FE0F
+    0x98684488,  // "🤦🏾\u200d♀️" 1F926-1F3FE-200D-2640-FE0F
+    0xE1848CA5,  // "🧜🏽\u200d♀️" 1F9DC-1F3FD-200D-2640-FE0F
+    0xBAECEC5B,  // "🦹\u200d♂️" 1F9B9-200D-2642-FE0F
+    0x8F08485B,  // "🤴🏼" 1F934-1F3FC
+    0x9A993832,  // "🧑🏾\u200d🚒" 1F9D1-1F3FE-200D-1F692
+    0xF203E1EB,  // "🦹🏻\u200d♀️" 1F9B9-1F3FB-200D-2640-FE0F
+    0x816071D0,  // "🤵🏻\u200d♂️" 1F935-1F3FB-200D-2642-FE0F
+    0xAC07088E,  // "🧍🏻\u200d♂️" 1F9CD-1F3FB-200D-2642-FE0F
+    0xC0E24A3A,  // "🧑🏾\u200d🦽" 1F9D1-1F3FE-200D-1F9BD
+    0xB08D2CEA,  // "🦸🏾\u200d♀️" 1F9B8-1F3FE-200D-2640-FE0F
+    0x84F0B377,  // "🦸🏿" 1F9B8-1F3FF
+    0xE12ECD4B,  // "🧑🏻\u200d⚖️" 1F9D1-1F3FB-200D-2696-FE0F
+    0xB2B96952,  // "🧙\u200d♂️" 1F9D9-200D-2642-FE0F
+    0xC4959415,  // "🤛🏼" 1F91B-1F3FC
+    0x92A3403A,  // "🧑🏻\u200d🏭" 1F9D1-1F3FB-200D-1F3ED
+    0x8F832CD2,  // "🤽🏿\u200d♀️" 1F93D-1F3FF-200D-2640-FE0F
+    0xC29BA02E,  // "🤞🏼" 1F91E-1F3FC
+    0xF5D07550,  // "🤵🏼\u200d♂️" 1F935-1F3FC-200D-2642-FE0F
+    0xACC80348,  // "🤵🏼\u200d♀️" 1F935-1F3FC-200D-2640-FE0F
+    0x995C4F9B,  // "🦸🏼" 1F9B8-1F3FC
+    0xDB6F19C7,  // "🤾🏿\u200d♂️" 1F93E-1F3FF-200D-2642-FE0F
+    0xE8037490,  // "🧏🏿\u200d♀️" 1F9CF-1F3FF-200D-2640-FE0F
+    0xDAD545DE,  // "🤙🏾" 1F919-1F3FE
+    0xA22BF689,  // "🦹🏿\u200d♀️" 1F9B9-1F3FF-200D-2640-FE0F
+    0xDF626734,  // "🧑🏿\u200d🤝\u200d🧑🏻" 1F9D1-1F3FF-200D-1F91D-200D-1F9D1-1F3FB
+    0xF1010062,  // "🧏🏿" 1F9CF-1F3FF
+    0x92680A9C,  // "🤟🏻" 1F91F-1F3FB
+    0xA252F946,  // "🧚\u200d♀️" 1F9DA-200D-2640-FE0F
+    0xA3532FF6,  // "🧑🏿\u200d🏭" 1F9D1-1F3FF-200D-1F3ED
+    0xB9017F91,  // "🧘🏽\u200d♂️" 1F9D8-1F3FD-200D-2642-FE0F
+    0x88630AA5,  // "🤏🏿" 1F90F-1F3FF
+    0xE24DC87B,  // "🤰🏿" 1F930-1F3FF
+    0xC0176BCB,  // "🤙🏼" 1F919-1F3FC
+    0xF23529B4,  // "🧑🏾\u200d🌾" 1F9D1-1F3FE-200D-1F33E
+    0xC89F42A5,  // "🤜🏾" 1F91C-1F3FE
+    0xB6E9C7AE,  // "🧙🏼\u200d♀️" 1F9D9-1F3FC-200D-2640-FE0F
+    0xF99F6321,  // "🧛\u200d♂️" 1F9DB-200D-2642-FE0F
+    0xE2857C3B,  // "🦹🏿" 1F9B9-1F3FF
+    0xE7209875,  // "🤰🏽" 1F930-1F3FD
+    0x92CA63A0,  // "🧘🏾" 1F9D8-1F3FE
+    0xECF8F139,  // "🧍🏾" 1F9CD-1F3FE
+    0xFBEDA780,  // "🧑🏿\u200d🦯" 1F9D1-1F3FF-200D-1F9AF
+    0x9BAA877E,  // "🧗🏻\u200d♂️" 1F9D7-1F3FB-200D-2642-FE0F
+    0xFBB06071,  // "🧑🏽\u200d💻" 1F9D1-1F3FD-200D-1F4BB
+    0xE48C9310,  // "🧑\u200d🦰" 1F9D1-200D-1F9B0
+    0xE95B9A60,  // "🧑🏽\u200d🍳" 1F9D1-1F3FD-200D-1F373
+    0xF1919F59,  // "🤟🏾" 1F91F-1F3FE
+    0xE5AEE0B3,  // "🧑🏾\u200d🎄" 1F9D1-1F3FE-200D-1F384
+    0xEEC51002,  // "🧑🏾\u200d🤝\u200d🧑🏼" 1F9D1-1F3FE-200D-1F91D-200D-1F9D1-1F3FC
+    0xB3F93E1F,  // "🧑\u200d⚖️" 1F9D1-200D-2696-FE0F
+    0xFA813B79,  // "🧜🏼" 1F9DC-1F3FC
+    0xB71DE79E,  // "🦸🏿\u200d♂️" 1F9B8-1F3FF-200D-2642-FE0F
+    0xBE2086CE,  // "🧖🏿\u200d♂️" 1F9D6-1F3FF-200D-2642-FE0F
+    0x96F2AD91,  // "🧑🏾\u200d⚖️" 1F9D1-1F3FE-200D-2696-FE0F
+    0x9E2595A8,  // "🧑🏾\u200d🦳" 1F9D1-1F3FE-200D-1F9B3
+    0xFE1A762F,  // "🤽🏻\u200d♀️" 1F93D-1F3FB-200D-2640-FE0F
+    0xFFC227A9,  // "🥷🏿" 1F977-1F3FF
+    0xEF9E254A,  // "🧏🏾\u200d♂️" 1F9CF-1F3FE-200D-2642-FE0F
+    0xFF72FF98,  // "🧑🏿\u200d🍳" 1F9D1-1F3FF-200D-1F373
+    0xDD19C360,  // "🤦🏿" 1F926-1F3FF
+    0x8812463E,  // "🤹🏿\u200d♀️" 1F939-1F3FF-200D-2640-FE0F
+    0xC079AAC1,  // "🤼\u200d♂️" 1F93C-200D-2642-FE0F
+    0x8F81DAE0,  // "🧑🏿\u200d🤝\u200d🧑🏾" 1F9D1-1F3FF-200D-1F91D-200D-1F9D1-1F3FE
+    0xABECD2CA,  // "🧚🏽\u200d♀️" 1F9DA-1F3FD-200D-2640-FE0F
+    0xC35C981A,  // "🧘🏿\u200d♂️" 1F9D8-1F3FF-200D-2642-FE0F
+    0xB9402909,  // "🧑🏽\u200d🦽" 1F9D1-1F3FD-200D-1F9BD
+    0xD70AC3C9,  // "🤾🏾\u200d♀️" 1F93E-1F3FE-200D-2640-FE0F
+    0x90D242D6,  // "🤸🏽" 1F938-1F3FD
+    0xEA766EB8,  // "🧙🏽\u200d♀️" 1F9D9-1F3FD-200D-2640-FE0F
+    0xBB2795EA,  // "🧏🏽" 1F9CF-1F3FD
+    0xA0AA3E6B,  // "🦸\u200d♀️" 1F9B8-200D-2640-FE0F
+    0xFF702C68,  // "🧑\u200d🌾" 1F9D1-200D-1F33E
+    0xE198714B,  // "🧘\u200d♂️" 1F9D8-200D-2642-FE0F
+    0xB57C95C2,  // "🧙🏿\u200d♀️" 1F9D9-1F3FF-200D-2640-FE0F
+    0xA89F2C35,  // "🧏🏽\u200d♂️" 1F9CF-1F3FD-200D-2642-FE0F
+    0xEF5D940A,  // "🧏🏿\u200d♂️" 1F9CF-1F3FF-200D-2642-FE0F
+    0xC3ACB1B1,  // "🧙🏾\u200d♂️" 1F9D9-1F3FE-200D-2642-FE0F
+    0xE80AC1A2,  // "🤏🏻" 1F90F-1F3FB
+    0xC8B1436B,  // "🦸\u200d♂️" 1F9B8-200D-2642-FE0F
+    0xF11CCA97,  // "🦶🏾" 1F9B6-1F3FE
+    0xEF7982E9,  // "🤽🏽\u200d♂️" 1F93D-1F3FD-200D-2642-FE0F
+    0xFB844E55,  // "🤙🏽" 1F919-1F3FD
+    0xD666D72A,  // "🦹🏽\u200d♂️" 1F9B9-1F3FD-200D-2642-FE0F
+    0x988A4B3F,  // "🧑🏻\u200d🦽" 1F9D1-1F3FB-200D-1F9BD
+    0xC9DCDE47,  // "🤹🏻\u200d♂️" 1F939-1F3FB-200D-2642-FE0F
+    0xC0A14093,  // "🤾🏾" 1F93E-1F3FE
+    0xE8465403,  // "🤹🏿" 1F939-1F3FF
+    0x9A841D24,  // "🧎🏻" 1F9CE-1F3FB
+    0xB1BDE7F3,  // "🧜🏾\u200d♂️" 1F9DC-1F3FE-200D-2642-FE0F
+    0xC51698B9,  // "🧑🏼\u200d🤝\u200d🧑🏻" 1F9D1-1F3FC-200D-1F91D-200D-1F9D1-1F3FB
+    0xD75B3CFF,  // "🤘🏾" 1F918-1F3FE
+    0xAD8A7A77,  // "🤸🏿\u200d♀️" 1F938-1F3FF-200D-2640-FE0F
+    0xBDA076FE,  // "🤽🏾\u200d♂️" 1F93D-1F3FE-200D-2642-FE0F
+    0xA861B307,  // "🤴🏻" 1F934-1F3FB
+    0xA2DE9C88,  // "🤳🏽" 1F933-1F3FD
+    0xB2841925,  // "🦹🏽" 1F9B9-1F3FD
+    0xEA55DA2C,  // "🤵🏻\u200d♀️" 1F935-1F3FB-200D-2640-FE0F
+    0x95DA4F85,  // "🧘🏼\u200d♀️" 1F9D8-1F3FC-200D-2640-FE0F
+    0xD269122D,  // "🧑🏾\u200d🚀" 1F9D1-1F3FE-200D-1F680
+    0xFE58569B,  // "🧑\u200d🎄" 1F9D1-200D-1F384
+    0x815FD5D7,  // "🧙🏾\u200d♀️" 1F9D9-1F3FE-200D-2640-FE0F
+    0xB038A215,  // "🤸\u200d♀️" 1F938-200D-2640-FE0F
+    0xAA2217B4,  // "🧛🏽" 1F9DB-1F3FD
+    0xC8E2BDB0,  // "🧑🏽\u200d🏭" 1F9D1-1F3FD-200D-1F3ED
+    0xA4730DAB,  // "🧑🏿\u200d🦼" 1F9D1-1F3FF-200D-1F9BC
+    0xDDBA4DFD,  // "🤵\u200d♀️" 1F935-200D-2640-FE0F
+    0xC796320F,  // "🦸🏾" 1F9B8-1F3FE
+    0xC82FBAF0,  // "🦻🏽" 1F9BB-1F3FD
+    0xCC57441C,  // "🦹🏼" 1F9B9-1F3FC
+    0xE2331316,  // "🧓🏿" 1F9D3-1F3FF
+    0xDBDB6EEE,  // "🧏🏻\u200d♂️" 1F9CF-1F3FB-200D-2642-FE0F
+    0xFDAC2353,  // "🧖🏾\u200d♂️" 1F9D6-1F3FE-200D-2642-FE0F
+    0xDDD5ED50,  // "🧏🏻" 1F9CF-1F3FB
+    0xD26B28A5,  // "🧒🏿" 1F9D2-1F3FF
+    0xFE20EFFF,  // "🧗🏿\u200d♀️" 1F9D7-1F3FF-200D-2640-FE0F
+    0xC2CC23A8,  // "🧖\u200d♂️" 1F9D6-200D-2642-FE0F
+    0xFE6C6F83,  // "🧑🏻\u200d🚀" 1F9D1-1F3FB-200D-1F680
+    0xAC388667,  // "🤹🏻\u200d♀️" 1F939-1F3FB-200D-2640-FE0F
+    0xA17F8EDA,  // "🦶🏼" 1F9B6-1F3FC
+    0xA6158284,  // "🧑🏽\u200d✈️" 1F9D1-1F3FD-200D-2708-FE0F
+    0x8E15799E,  // "🧑🏼\u200d🦼" 1F9D1-1F3FC-200D-1F9BC
+    0xC2DEB9B2,  // "🤚🏽" 1F91A-1F3FD
+    0xB298F631,  // "🧝🏻" 1F9DD-1F3FB
+    0x924D43C0,  // "🧑🏼\u200d🦳" 1F9D1-1F3FC-200D-1F9B3
+    0xB97BB5F6,  // "🤦🏼" 1F926-1F3FC
+    0x892BCDE0,  // "🧑\u200d🤝\u200d🧑" 1F9D1-200D-1F91D-200D-1F9D1
+    0x8F127233,  // "🧎\u200d♀️" 1F9CE-200D-2640-FE0F
+    0xE8000056,  // "🤜🏽" 1F91C-1F3FD
+    0xFB12F356,  // "🧑🏿\u200d🌾" 1F9D1-1F3FF-200D-1F33E
+    0xD73ADC1C,  // "🧑🏻\u200d🦰" 1F9D1-1F3FB-200D-1F9B0
+    0xFCAE917F,  // "🤹🏻" 1F939-1F3FB
+    0xB53090C9,  // "🧑🏾\u200d💻" 1F9D1-1F3FE-200D-1F4BB
+    0xADFEB2DC,  // "🤚🏾" 1F91A-1F3FE
+    0x8868AABD,  // "🤽\u200d♀️" 1F93D-200D-2640-FE0F
+    0x800F9915,  // "🤸\u200d♂️" 1F938-200D-2642-FE0F
+    0x8E594A61,  // "🧑🏽\u200d🤝\u200d🧑🏿" 1F9D1-1F3FD-200D-1F91D-200D-1F9D1-1F3FF
+    0x9647821C,  // "🧘🏻\u200d♂️" 1F9D8-1F3FB-200D-2642-FE0F
+    0xF1F67CE1,  // "🧑🏿\u200d🔧" 1F9D1-1F3FF-200D-1F527
+    0xFC699906,  // "🧑🏽\u200d🤝\u200d🧑🏾" 1F9D1-1F3FD-200D-1F91D-200D-1F9D1-1F3FE
+    0xC0B0383B,  // "🤱🏾" 1F931-1F3FE
+    0xDB0EC06E,  // "🦵🏾" 1F9B5-1F3FE
+    0xD9C4FE3F,  // "🧑🏼\u200d🤝\u200d🧑🏾" 1F9D1-1F3FC-200D-1F91D-200D-1F9D1-1F3FE
+    0xFA146B45,  // "🤷🏿\u200d♂️" 1F937-1F3FF-200D-2642-FE0F
+    0xA55DE72A,  // "🧑🏿\u200d💻" 1F9D1-1F3FF-200D-1F4BB
+    0xE8F258B4,  // "🧑🏾\u200d🎤" 1F9D1-1F3FE-200D-1F3A4
+    0xF8DD0E33,  // "🧍\u200d♀️" 1F9CD-200D-2640-FE0F
+    0xCA9C8946,  // "🧑🏼\u200d🚀" 1F9D1-1F3FC-200D-1F680
+    0xB3426EAE,  // "🧑🏽\u200d🤝\u200d🧑🏽" 1F9D1-1F3FD-200D-1F91D-200D-1F9D1-1F3FD
+    0x8F14BC45,  // "🧓🏽" 1F9D3-1F3FD
+    0xCE4A9EA2,  // "🧑🏽\u200d🦲" 1F9D1-1F3FD-200D-1F9B2
+    0xBED78D9B,  // "🧚🏾\u200d♀️" 1F9DA-1F3FE-200D-2640-FE0F
+    0xD6565F53,  // "🦸🏻\u200d♀️" 1F9B8-1F3FB-200D-2640-FE0F
+    0xF30E88EC,  // "🤦🏿\u200d♀️" 1F926-1F3FF-200D-2640-FE0F
+    0xEA70E4D8,  // "🤜🏻" 1F91C-1F3FB
+    0xBA46DDE4,  // "🤸🏼\u200d♂️" 1F938-1F3FC-200D-2642-FE0F
+    0x8678915A,  // "🧑🏿\u200d🤝\u200d🧑🏿" 1F9D1-1F3FF-200D-1F91D-200D-1F9D1-1F3FF
+    0xB4087CA6,  // "🧏🏼\u200d♂️" 1F9CF-1F3FC-200D-2642-FE0F
+    0xB5E42E25,  // "🤚🏿" 1F91A-1F3FF
+    0xCD116645,  // "🧑🏾\u200d✈️" 1F9D1-1F3FE-200D-2708-FE0F
+    0x9D4F9562,  // "🧑🏼\u200d✈️" 1F9D1-1F3FC-200D-2708-FE0F
+    0xC318D5DD,  // "🤾\u200d♂️" 1F93E-200D-2642-FE0F
+    0xE8322042,  // "🧑🏽\u200d🔧" 1F9D1-1F3FD-200D-1F527
+    0xFA277B6E,  // "🧎🏽" 1F9CE-1F3FD
+    0x97AB8EB0,  // "🧍🏽\u200d♂️" 1F9CD-1F3FD-200D-2642-FE0F
+    0xCE4DEEE0,  // "🤙🏿" 1F919-1F3FF
+    0xADFC5C09,  // "🧑🏻\u200d🦲" 1F9D1-1F3FB-200D-1F9B2
+    0x941FD0DC,  // "🧑🏽\u200d🦼" 1F9D1-1F3FD-200D-1F9BC
+    0x98015A32,  // "🤸🏿" 1F938-1F3FF
+    0x9C46D531,  // "🧘🏾\u200d♂️" 1F9D8-1F3FE-200D-2642-FE0F
+    0xED5BFDD7,  // "🧑🏻\u200d💻" 1F9D1-1F3FB-200D-1F4BB
+    0xA3AC615E,  // "🤦🏽\u200d♀️" 1F926-1F3FD-200D-2640-FE0F
+    0xB59832E1,  // "🧖🏽" 1F9D6-1F3FD
+    0xAA7D013F,  // "🧑🏼\u200d🏭" 1F9D1-1F3FC-200D-1F3ED
+    0xC113AFBF,  // "🧘🏻\u200d♀️" 1F9D8-1F3FB-200D-2640-FE0F
+    0xBA6156E9,  // "🧑🏿\u200d🎄" 1F9D1-1F3FF-200D-1F384
+    0xDEA2205A,  // "🤷\u200d♀️" 1F937-200D-2640-FE0F
+    0xEDC2E6F4,  // "🧑🏿\u200d🤝\u200d🧑🏼" 1F9D1-1F3FF-200D-1F91D-200D-1F9D1-1F3FC
+    0xD0381184,  // "🦸🏻\u200d♂️" 1F9B8-1F3FB-200D-2642-FE0F
+    0xB65D9F0C,  // "🤲🏽" 1F932-1F3FD
+    0xCA054158,  // "🤦🏿\u200d♂️" 1F926-1F3FF-200D-2642-FE0F
+    0xCCB8A838,  // "🤰🏼" 1F930-1F3FC
+    0xACD3F2BA,  // "🧎🏼\u200d♂️" 1F9CE-1F3FC-200D-2642-FE0F
+    0x866535F8,  // "🧑🏻\u200d🤝\u200d🧑🏽" 1F9D1-1F3FB-200D-1F91D-200D-1F9D1-1F3FD
+    0xFD208122,  // "🧑🏻" 1F9D1-1F3FB
+    0xBC8EDD2B,  // "🤽🏽" 1F93D-1F3FD
+    0x9ED53F1F,  // "🧑🏼\u200d🦲" 1F9D1-1F3FC-200D-1F9B2
+    0xB07F229C,  // "🤹🏾" 1F939-1F3FE
+    0xDE93E1A0,  // "🤸🏽\u200d♂️" 1F938-1F3FD-200D-2642-FE0F
+    0xC17CC226,  // "🧎🏿\u200d♂️" 1F9CE-1F3FF-200D-2642-FE0F
+    0xF805CB66,  // "🦻🏾" 1F9BB-1F3FE
+    0xE9337535,  // "🤏🏽" 1F90F-1F3FD
+    0x87AA261C,  // "🦸🏽\u200d♀️" 1F9B8-1F3FD-200D-2640-FE0F
+    0xC89B84F5,  // "🤞🏽" 1F91E-1F3FD
+    0x8D81BE33,  // "🧜\u200d♀️" 1F9DC-200D-2640-FE0F
+    0xAB2DCE47,  // "🥷🏾" 1F977-1F3FE
+    0xDA550C8A,  // "🧑🏻\u200d🎤" 1F9D1-1F3FB-200D-1F3A4
+    0xF2D1E9F8,  // "🧍🏿" 1F9CD-1F3FF
+    0xA4BCFE5F,  // "🧑🏽\u200d🦱" 1F9D1-1F3FD-200D-1F9B1
+    0x9B1206AA,  // "🤹🏼\u200d♂️" 1F939-1F3FC-200D-2642-FE0F
+    0xA4273B52,  // "🧑🏿\u200d🎨" 1F9D1-1F3FF-200D-1F3A8
+    0xB6AB750C,  // "🧑\u200d🦯" 1F9D1-200D-1F9AF
+    0xEB30EE58,  // "🧎🏼" 1F9CE-1F3FC
+    0xAA5505AA,  // "🤰🏾" 1F930-1F3FE
+    0x8BBC25F4,  // "🧜🏼\u200d♀️" 1F9DC-1F3FC-200D-2640-FE0F
+    0x8A0860B5,  // "🤲🏿" 1F932-1F3FF
+    0xA1898FD4,  // "🧝🏿" 1F9DD-1F3FF
+    0x97BF145C,  // "🤟🏽" 1F91F-1F3FD
+    0x99DEF382,  // "🧑🏻\u200d🎓" 1F9D1-1F3FB-200D-1F393
+    0xE45461EF,  // "🧑\u200d🔧" 1F9D1-200D-1F527
+    0xF00151F5,  // "🦸🏽" 1F9B8-1F3FD
+    0xFDCDEA60,  // "🧙🏼\u200d♂️" 1F9D9-1F3FC-200D-2642-FE0F
+    0xB0CC5795,  // "🧘🏻" 1F9D8-1F3FB
+    0x8FF83BBB,  // "🧗🏽\u200d♀️" 1F9D7-1F3FD-200D-2640-FE0F
+    0xEA9B7769,  // "🧑🏽\u200d🎨" 1F9D1-1F3FD-200D-1F3A8
+    0xABA8C1F3,  // "🤾🏾\u200d♂️" 1F93E-1F3FE-200D-2642-FE0F
+    0xD9C4B298,  // "🥷🏻" 1F977-1F3FB
+    0x8D51E2B3,  // "🧏\u200d♀️" 1F9CF-200D-2640-FE0F
+    0xC9EF47B2,  // "🧛🏾" 1F9DB-1F3FE
+    0x8269F5CC,  // "🧍🏻" 1F9CD-1F3FB
+    0x9B2C4249,  // "🦵🏻" 1F9B5-1F3FB
+    0xEC9B920D,  // "🦹🏾" 1F9B9-1F3FE
+    0xAAA3BD8C,  // "🧝🏿\u200d♀️" 1F9DD-1F3FF-200D-2640-FE0F
+    0x854ACC30,  // "🤛🏾" 1F91B-1F3FE
+    0xA9222CBD,  // "🧞\u200d♂️" 1F9DE-200D-2642-FE0F
+    0xE2FB4E60,  // "🤞🏿" 1F91E-1F3FF
+    0xE70179DA,  // "🧝🏼" 1F9DD-1F3FC
+    0x90C93D52,  // "🤜🏼" 1F91C-1F3FC
+    0xE8BDAF77,  // "🧑🏿\u200d🦰" 1F9D1-1F3FF-200D-1F9B0
+    0xFC435BAD,  // "🧖🏼" 1F9D6-1F3FC
+    0x985D3604,  // "🧚🏻" 1F9DA-1F3FB
+    0x83128419,  // "🤌🏾" 1F90C-1F3FE
+    0xB94DF207,  // "🧏🏼" 1F9CF-1F3FC
+    0x9828A78D,  // "🤽🏻" 1F93D-1F3FB
+    0x9108CD4E,  // "🤷🏽\u200d♀️" 1F937-1F3FD-200D-2640-FE0F
+    0x9A1BADB6,  // "🧛🏾\u200d♂️" 1F9DB-1F3FE-200D-2642-FE0F
+    0xE71957C5,  // "🧕🏽" 1F9D5-1F3FD
+    0x887A6F65,  // "🧍🏼\u200d♀️" 1F9CD-1F3FC-200D-2640-FE0F
+    0xF93E5BC6,  // "🧑🏻\u200d🔬" 1F9D1-1F3FB-200D-1F52C
+    0xB5DE928D,  // "🤛🏿" 1F91B-1F3FF
+    0x8BC3A13F,  // "🧚🏻\u200d♂️" 1F9DA-1F3FB-200D-2642-FE0F
+    0xAB2BBDF9,  // "🧑🏾" 1F9D1-1F3FE
+    0x8FEF1361,  // "🧑🏾\u200d🤝\u200d🧑🏾" 1F9D1-1F3FE-200D-1F91D-200D-1F9D1-1F3FE
+    0xA4AE3114,  // "🧚🏾\u200d♂️" 1F9DA-1F3FE-200D-2642-FE0F
+    0xE28BF844,  // "🤦🏾" 1F926-1F3FE
+    0xFD2286F7,  // "🤷🏻\u200d♀️" 1F937-1F3FB-200D-2640-FE0F
+    0x8C65A4B2,  // "🤹\u200d♂️" 1F939-200D-2642-FE0F
+    0xDFD58733,  // "🧑🏿\u200d🎤" 1F9D1-1F3FF-200D-1F3A4
+    0xE78D2044,  // "🧑🏻\u200d🔧" 1F9D1-1F3FB-200D-1F527
+    0xDC498ACA,  // "🧑🏿" 1F9D1-1F3FF
+    0xD00427BA,  // "🤵\u200d♂️" 1F935-200D-2642-FE0F
+    0x95835043,  // "🤽🏽\u200d♀️" 1F93D-1F3FD-200D-2640-FE0F
+    0xCD113645,  // "🤵🏾\u200d♂️" 1F935-1F3FE-200D-2642-FE0F
+    0x99FE5C9E,  // "🤵🏽\u200d♀️" 1F935-1F3FD-200D-2640-FE0F
+    0xF2FAAA9D,  // "🤦🏻\u200d♀️" 1F926-1F3FB-200D-2640-FE0F
+    0xFDB9F8D2,  // "🧑🏿\u200d⚕️" 1F9D1-1F3FF-200D-2695-FE0F
+    0xED000EEB,  // "🧎🏿" 1F9CE-1F3FF
+    0x84DFEB84,  // "🤸🏻" 1F938-1F3FB
+    0xD7D13AC0,  // "🧘🏼" 1F9D8-1F3FC
+    0xE97E6F4D,  // "🤸🏾\u200d♂️" 1F938-1F3FE-200D-2642-FE0F
+    0xD5FCBBB8,  // "🧕🏾" 1F9D5-1F3FE
+    0xE4563BF0,  // "🦻🏻" 1F9BB-1F3FB
+    0xA90BE097,  // "🧝🏿\u200d♂️" 1F9DD-1F3FF-200D-2642-FE0F
+    0xE57C5DCE,  // "🧎🏻\u200d♂️" 1F9CE-1F3FB-200D-2642-FE0F
+    0x8155AB26,  // "🧑🏾\u200d🎨" 1F9D1-1F3FE-200D-1F3A8
+    0xA3A69324,  // "🤷🏼\u200d♀️" 1F937-1F3FC-200D-2640-FE0F
+    0xF69469A4,  // "🧑🏾\u200d💼" 1F9D1-1F3FE-200D-1F4BC
+    0xCD15509B,  // "🧖🏼\u200d♀️" 1F9D6-1F3FC-200D-2640-FE0F
+    0xCB519A59,  // "🤽🏾\u200d♀️" 1F93D-1F3FE-200D-2640-FE0F
+    0xB9E5FB21,  // "🤷🏿\u200d♀️" 1F937-1F3FF-200D-2640-FE0F
+    0xC66C6E28,  // "🧏\u200d♂️" 1F9CF-200D-2642-FE0F
+    0xEE3561BF,  // "🧑🏽\u200d🎤" 1F9D1-1F3FD-200D-1F3A4
+    0xA3D98AE6,  // "🦹🏽\u200d♀️" 1F9B9-1F3FD-200D-2640-FE0F
+    0xF75D33AA,  // "🤌🏼" 1F90C-1F3FC
+    0x809E1E4B,  // "🧑\u200d🍼" 1F9D1-200D-1F37C
+    0xA9B7B802,  // "🤸🏼" 1F938-1F3FC
+    0xB02EF90F,  // "🤷🏾\u200d♂️" 1F937-1F3FE-200D-2642-FE0F
+    0xB5D35EF5,  // "🧓🏼" 1F9D3-1F3FC
+    0xCC93FC11,  // "🧝🏼\u200d♂️" 1F9DD-1F3FC-200D-2642-FE0F
+    0xA775E99D,  // "🧜🏿\u200d♀️" 1F9DC-1F3FF-200D-2640-FE0F
+    0xBE06D64E,  // "🧑🏾\u200d🏭" 1F9D1-1F3FE-200D-1F3ED
+    0xF6D110D1,  // "🧜🏿\u200d♂️" 1F9DC-1F3FF-200D-2642-FE0F
+    0xDB233CC8,  // "🧑🏿\u200d✈️" 1F9D1-1F3FF-200D-2708-FE0F
+    0xAAB92CC8,  // "🧑🏾\u200d🤝\u200d🧑🏻" 1F9D1-1F3FE-200D-1F91D-200D-1F9D1-1F3FB
+    0x99F3D216,  // "🧑🏾\u200d🦱" 1F9D1-1F3FE-200D-1F9B1
+    0x891EC0C7,  // "🧑🏼\u200d🎨" 1F9D1-1F3FC-200D-1F3A8
+    0xF26C218F,  // "🧑🏿\u200d🦱" 1F9D1-1F3FF-200D-1F9B1
+];
+
 /// Lookup table of blit pattern offsets; sort matches HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS
-const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
+static OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 642] = [
     81529, // "🧜🏽" 1F9DC-1F3FD
-    72092, // "🧎"
     61767, // "🤦🏻\u200d♂️" 1F926-1F3FB-200D-2642-FE0F
     75315, // "🧑🏾\u200d🍼" 1F9D1-1F3FE-200D-1F37C
     66123, // "🤾🏽" 1F93E-1F3FD
     64379, // "🤸🏽\u200d♀️" 1F938-1F3FD-200D-2640-FE0F
     79290, // "🧗\u200d♀️" 1F9D7-200D-2640-FE0F
     77516, // "🧑\u200d🦱" 1F9D1-200D-1F9B1
-    71435, // "🧈"
     61325, // "🤞🏾" 1F91E-1F3FE
-    66334, // "🤾"
     79258, // "🧗🏿" 1F9D7-1F3FF
-    62143, // "🤦"
     71641, // "🧍🏾\u200d♂️" 1F9CD-1F3FE-200D-2642-FE0F
     80699, // "🧚🏿" 1F9DA-1F3FF
     70490, // "🦹🏻\u200d♂️" 1F9B9-1F3FB-200D-2642-FE0F
@@ -7189,55 +9407,37 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     64887, // "🤹🏽\u200d♂️" 1F939-1F3FD-200D-2642-FE0F
     66223, // "🤾🏿\u200d♀️" 1F93E-1F3FF-200D-2640-FE0F
     63303, // "🤵🏽" 1F935-1F3FD
-    60393, // "🤒"
     60615, // "🤘🏽" 1F918-1F3FD
     81827, // "🧝🏻\u200d♀️" 1F9DD-1F3FB-200D-2640-FE0F
     76481, // "🧑🏿\u200d🔬" 1F9D1-1F3FF-200D-1F52C
     60022, // "🤌🏽" 1F90C-1F3FD
     72366, // "🧏🏾\u200d♀️" 1F9CF-1F3FE-200D-2640-FE0F
-    70917, // "🦹"
     69712, // "🦵🏽" 1F9B5-1F3FD
     78972, // "🧗🏼" 1F9D7-1F3FC
     62646, // "🤱🏿" 1F931-1F3FF
     63902, // "🤷🏽\u200d♂️" 1F937-1F3FD-200D-2642-FE0F
-    67553, // "🥭"
-    67220, // "🥠"
-    61249, // "🤝"
     66051, // "🤾🏼" 1F93E-1F3FC
     77540, // "🧑\u200d🦲" 1F9D1-200D-1F9B2
     74023, // "🧑🏼\u200d🤝\u200d🧑🏽" 1F9D1-1F3FC-200D-1F91D-200D-1F9D1-1F3FD
     72692, // "🧑🏻\u200d🍳" 1F9D1-1F3FB-200D-1F373
-    67073, // "🥚"
-    67826, // "🥶"
-    62226, // "🤩"
     64499, // "🤸🏾" 1F938-1F3FE
     77995, // "🧔🏻" 1F9D4-1F3FB
     71676, // "🧍🏿\u200d♂️" 1F9CD-1F3FF-200D-2642-FE0F
     76023, // "🧑🏾\u200d⚕️" 1F9D1-1F3FE-200D-2695-FE0F
     62836, // "🤳🏻" 1F933-1F3FB
-    71168, // "🦽"
     63963, // "🤷🏾\u200d♀️" 1F937-1F3FE-200D-2640-FE0F
     72724, // "🧑🏻\u200d🍼" 1F9D1-1F3FB-200D-1F37C
     78316, // "🧖🏻\u200d♂️" 1F9D6-1F3FB-200D-2642-FE0F
     76236, // "🧑🏿\u200d🎓" 1F9D1-1F3FF-200D-1F393
-    65207, // "🤹"
     78547, // "🧖🏾\u200d♀️" 1F9D6-1F3FE-200D-2640-FE0F
-    68092, // "🥼"
     78909, // "🧗🏼\u200d♀️" 1F9D7-1F3FC-200D-2640-FE0F
     71709, // "🧍\u200d♂️" 1F9CD-200D-2642-FE0F
     65518, // "🤽🏼" 1F93D-1F3FC
-    83052, // "🧸"
-    67732, // "🥳"
     73607, // "🧑🏼\u200d🎄" 1F9D1-1F3FC-200D-1F384
-    71458, // "🧉"
-    68994, // "🦝"
     71879, // "🧎🏽\u200d♂️" 1F9CE-1F3FD-200D-2642-FE0F
     80439, // "🧚🏼" 1F9DA-1F3FC
-    60673, // "🤘"
-    67476, // "🥪"
     80922, // "🧛🏼\u200d♂️" 1F9DB-1F3FC-200D-2642-FE0F
     81767, // "🧜\u200d♂️" 1F9DC-200D-2642-FE0F
-    61106, // "🤛"
     70707, // "🦹🏾\u200d♀️" 1F9B9-1F3FE-200D-2640-FE0F
     75226, // "🧑🏽" 1F9D1-1F3FD
     65774, // "🤽🏿\u200d♂️" 1F93D-1F3FF-200D-2642-FE0F
@@ -7246,13 +9446,9 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     76541, // "🧑🏿\u200d🚒" 1F9D1-1F3FF-200D-1F692
     71964, // "🧎🏾" 1F9CE-1F3FE
     63931, // "🤷🏽" 1F937-1F3FD
-    68778, // "🦕"
     79719, // "🧘\u200d♀️" 1F9D8-200D-2640-FE0F
     78177, // "🧕🏼" 1F9D5-1F3FC
-    60835, // "🤙"
-    60156, // "🤎"
     60884, // "🤚🏼" 1F91A-1F3FC
-    69464, // "🦮"
     79472, // "🧘🏼\u200d♂️" 1F9D8-1F3FC-200D-2642-FE0F
     76809, // "🧑🏿\u200d🦳" 1F9D1-1F3FF-200D-1F9B3
     78699, // "🧖🏿" 1F9D6-1F3FF
@@ -7260,79 +9456,45 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     63651, // "🤶🏿" 1F936-1F3FF
     63061, // "🤴🏿" 1F934-1F3FF
     61933, // "🤦🏽" 1F926-1F3FD
-    81263, // "🧛"
-    69613, // "🦳"
     65390, // "🤽🏻\u200d♂️" 1F93D-1F3FB-200D-2642-FE0F
-    83240, // "🧿"
-    67584, // "🥮"
     74828, // "🧑🏽\u200d🤝\u200d🧑🏻" 1F9D1-1F3FD-200D-1F91D-200D-1F9D1-1F3FB
     77739, // "🧒🏻" 1F9D2-1F3FB
-    68808, // "🦖"
-    77836, // "🧒"
     73084, // "🧑🏻\u200d🚒" 1F9D1-1F3FB-200D-1F692
-    68398, // "🦈"
-    67170, // "🥞"
     72198, // "🧏🏼\u200d♀️" 1F9CF-1F3FC-200D-2640-FE0F
     80296, // "🧚🏻\u200d♀️" 1F9DA-1F3FB-200D-2640-FE0F
-    60329, // "🤐"
     77583, // "🧑\u200d🦼" 1F9D1-200D-1F9BC
     70865, // "🦹\u200d♀️" 1F9B9-200D-2640-FE0F
     73786, // "🧑🏼\u200d💻" 1F9D1-1F3FC-200D-1F4BB
     76859, // "🧑🏿\u200d🦽" 1F9D1-1F3FF-200D-1F9BD
     73635, // "🧑🏼\u200d🎓" 1F9D1-1F3FC-200D-1F393
-    68750, // "🦔"
-    69409, // "🦬"
-    60306, // "🤏"
     76417, // "🧑🏿\u200d💼" 1F9D1-1F3FF-200D-1F4BC
-    62811, // "🤲"
-    69328, // "🦩"
     64449, // "🤸🏾\u200d♀️" 1F938-1F3FE-200D-2640-FE0F
-    68574, // "🦎"
-    61653, // "🤣"
-    82228, // "🧝"
-    68693, // "🦒"
-    61557, // "🤠"
     79585, // "🧘🏾\u200d♀️" 1F9D8-1F3FE-200D-2640-FE0F
     64983, // "🤹🏾\u200d♂️" 1F939-1F3FE-200D-2642-FE0F
     74448, // "🧑🏽\u200d🍼" 1F9D1-1F3FD-200D-1F37C
     71731, // "🧎🏻\u200d♀️" 1F9CE-1F3FB-200D-2640-FE0F
     62692, // "🤲🏻" 1F932-1F3FB
-    66384, // "🥀"
     69751, // "🦵🏿" 1F9B5-1F3FF
     65806, // "🤽🏿" 1F93D-1F3FF
     65266, // "🤼\u200d♀️" 1F93C-200D-2640-FE0F
     74994, // "🧑🏽\u200d🦰" 1F9D1-1F3FD-200D-1F9B0
     82088, // "🧝🏾" 1F9DD-1F3FE
-    82824, // "🧰"
     79786, // "🧙🏻\u200d♀️" 1F9D9-1F3FB-200D-2640-FE0F
-    60425, // "🤓"
     74680, // "🧑🏽\u200d💼" 1F9D1-1F3FD-200D-1F4BC
-    61528, // "🤟"
     79099, // "🧗🏾\u200d♀️" 1F9D7-1F3FE-200D-2640-FE0F
-    83159, // "🧼"
     69692, // "🦵🏼" 1F9B5-1F3FC
-    62351, // "🤭"
     77026, // "🧑\u200d🍳" 1F9D1-200D-1F373
     80815, // "🧛🏻\u200d♀️" 1F9DB-1F3FB-200D-2640-FE0F
-    67142, // "🥝"
-    83104, // "🧺"
     81409, // "🧜🏼\u200d♂️" 1F9DC-1F3FC-200D-2642-FE0F
     70056, // "🦸🏼\u200d♂️" 1F9B8-1F3FC-200D-2642-FE0F
     72424, // "🧏🏾" 1F9CF-1F3FE
     81159, // "🧛🏿\u200d♂️" 1F9DB-1F3FF-200D-2642-FE0F
-    67700, // "🥲"
-    68121, // "🥽"
     80868, // "🧛🏻" 1F9DB-1F3FB
-    82364, // "🧟"
     75367, // "🧑🏾\u200d🎓" 1F9D1-1F3FE-200D-1F393
     74855, // "🧑🏽\u200d🤝\u200d🧑🏼" 1F9D1-1F3FD-200D-1F91D-200D-1F9D1-1F3FC
-    62410, // "🤯"
-    69206, // "🦥"
-    62536, // "🤰"
     74308, // "🧑🏼\u200d⚖️" 1F9D1-1F3FC-200D-2696-FE0F
     64312, // "🤸🏼\u200d♀️" 1F938-1F3FC-200D-2640-FE0F
     78155, // "🧕🏻" 1F9D5-1F3FB
-    83188, // "🧽"
     77350, // "🧑\u200d🔬" 1F9D1-200D-1F52C
     63846, // "🤷🏼" 1F937-1F3FC
     81289, // "🧜🏻\u200d♀️" 1F9DC-1F3FB-200D-2640-FE0F
@@ -7341,14 +9503,9 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     74134, // "🧑🏼\u200d🦰" 1F9D1-1F3FC-200D-1F9B0
     75966, // "🧑🏾\u200d🦼" 1F9D1-1F3FE-200D-1F9BC
     77855, // "🧓🏻" 1F9D3-1F3FB
-    83129, // "🧻"
     63156, // "🤵🏻" 1F935-1F3FB
     63230, // "🤵🏼" 1F935-1F3FC
-    82686, // "🧫"
-    69236, // "🦦"
-    71483, // "🧊"
     77258, // "🧑\u200d💻" 1F9D1-200D-1F4BB
-    65900, // "🤽"
     62600, // "🤱🏽" 1F931-1F3FD
     65710, // "🤽🏾" 1F93D-1F3FE
     81707, // "🧜🏿" 1F9DC-1F3FF
@@ -7364,14 +9521,10 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     78878, // "🧗🏻" 1F9D7-1F3FB
     62925, // "🤳🏿" 1F933-1F3FF
     62858, // "🤳🏼" 1F933-1F3FC
-    68862, // "🦘"
     71986, // "🧎🏿\u200d♀️" 1F9CE-1F3FF-200D-2640-FE0F
-    68838, // "🦗"
     61980, // "🤦🏾\u200d♂️" 1F926-1F3FE-200D-2642-FE0F
     80525, // "🧚🏽" 1F9DA-1F3FD
-    69021, // "🦞"
     81499, // "🧜🏽\u200d♂️" 1F9DC-1F3FD-200D-2642-FE0F
-    72599, // "🧏"
     75814, // "🧑🏾\u200d🤝\u200d🧑🏿" 1F9D1-1F3FE-200D-1F91D-200D-1F9D1-1F3FF
     65869, // "🤽\u200d♂️" 1F93D-200D-2642-FE0F
     81899, // "🧝🏼\u200d♀️" 1F9DD-1F3FC-200D-2640-FE0F
@@ -7379,19 +9532,14 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     80757, // "🧚\u200d♂️" 1F9DA-200D-2642-FE0F
     61411, // "🤟🏼" 1F91F-1F3FC
     74078, // "🧑🏼\u200d🤝\u200d🧑🏿" 1F9D1-1F3FC-200D-1F91D-200D-1F9D1-1F3FF
-    68236, // "🦂"
     79321, // "🧗\u200d♂️" 1F9D7-200D-2642-FE0F
     79035, // "🧗🏽\u200d♂️" 1F9D7-1F3FD-200D-2642-FE0F
     66073, // "🤾🏽\u200d♀️" 1F93E-1F3FD-200D-2640-FE0F
-    82935, // "🧴"
     77925, // "🧓🏾" 1F9D3-1F3FE
-    71268, // "🧁"
     64919, // "🤹🏽" 1F939-1F3FD
     71943, // "🧎🏾\u200d♂️" 1F9CE-1F3FE-200D-2642-FE0F
     66097, // "🤾🏽\u200d♂️" 1F93E-1F3FD-200D-2642-FE0F
     73365, // "🧑🏻\u200d🦼" 1F9D1-1F3FB-200D-1F9BC
-    69903, // "🦶"
-    66671, // "🥌"
     79814, // "🧙🏻\u200d♂️" 1F9D9-1F3FB-200D-2642-FE0F
     74584, // "🧑🏽\u200d🏫" 1F9D1-1F3FD-200D-1F3EB
     77776, // "🧒🏽" 1F9D2-1F3FD
@@ -7401,7 +9549,6 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     81184, // "🧛🏿" 1F9DB-1F3FF
     63730, // "🤷🏻\u200d♂️" 1F937-1F3FB-200D-2642-FE0F
     71619, // "🧍🏽" 1F9CD-1F3FD
-    82568, // "🧧"
     71000, // "🦻🏼" 1F9BB-1F3FC
     80947, // "🧛🏼" 1F9DB-1F3FC
     70291, // "🦸🏿\u200d♀️" 1F9B8-1F3FF-200D-2640-FE0F
@@ -7410,13 +9557,11 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     81001, // "🧛🏽\u200d♂️" 1F9DB-1F3FD-200D-2642-FE0F
     73109, // "🧑🏻\u200d🤝\u200d🧑🏻" 1F9D1-1F3FB-200D-1F91D-200D-1F9D1-1F3FB
     77107, // "🧑\u200d🎓" 1F9D1-200D-1F393
-    82296, // "🧞"
     70000, // "🦸🏻" 1F9B8-1F3FB
     60691, // "🤙🏻" 1F919-1F3FB
     78940, // "🧗🏼\u200d♂️" 1F9D7-1F3FC-200D-2642-FE0F
     77289, // "🧑\u200d💼" 1F9D1-200D-1F4BC
     73994, // "🧑🏼\u200d🤝\u200d🧑🏼" 1F9D1-1F3FC-200D-1F91D-200D-1F9D1-1F3FC
-    77971, // "🧓"
     63403, // "🤵🏿\u200d♀️" 1F935-1F3FF-200D-2640-FE0F
     80013, // "🧙🏽" 1F9D9-1F3FD
     73587, // "🧑🏼\u200d🍼" 1F9D1-1F3FC-200D-1F37C
@@ -7424,18 +9569,13 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     74282, // "🧑🏼\u200d⚕️" 1F9D1-1F3FC-200D-2695-FE0F
     75842, // "🧑🏾\u200d🦯" 1F9D1-1F3FE-200D-1F9AF
     74385, // "🧑🏽\u200d🌾" 1F9D1-1F3FD-200D-1F33E
-    66405, // "🥁"
-    67637, // "🥰"
     81131, // "🧛🏿\u200d♀️" 1F9DB-1F3FF-200D-2640-FE0F
-    69265, // "🦧"
     74159, // "🧑🏼\u200d🦱" 1F9D1-1F3FC-200D-1F9B1
     64855, // "🤹🏽\u200d♀️" 1F939-1F3FD-200D-2640-FE0F
     81210, // "🧛\u200d♀️" 1F9DB-200D-2640-FE0F
-    68636, // "🦐"
     70521, // "🦹🏻" 1F9B9-1F3FB
     72746, // "🧑🏻\u200d🎄" 1F9D1-1F3FB-200D-1F384
     74362, // "🧑🏼" 1F9D1-1F3FC
-    63526, // "🤵"
     62903, // "🤳🏾" 1F933-1F3FE
     75870, // "🧑🏾\u200d🦰" 1F9D1-1F3FE-200D-1F9B0
     62713, // "🤲🏼" 1F932-1F3FC
@@ -7443,25 +9583,17 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     61043, // "🤛🏽" 1F91B-1F3FD
     71585, // "🧍🏼" 1F9CD-1F3FC
     60075, // "🤌🏿" 1F90C-1F3FF
-    68173, // "🦀"
     77412, // "🧑\u200d🚒" 1F9D1-200D-1F692
-    73478, // "🧑🏻\u200d✈️" 1F9D1-1F3FB-200D-2708-FE0F
-    65327, // "🤼"
-    67280, // "🥢"
+    73478, // "🧑🏻\u200d✈️" 1F9D1-1F3FB-200D-2708-FE0F
     77560, // "🧑\u200d🦳" 1F9D1-200D-1F9B3
-    80267, // "🧙"
     62579, // "🤱🏼" 1F931-1F3FC
     63014, // "🤴🏽" 1F934-1F3FD
     78075, // "🧔🏾" 1F9D4-1F3FE
-    66989, // "🥗"
-    67794, // "🥵"
     77164, // "🧑\u200d🎨" 1F9D1-200D-1F3A8
     70572, // "🦹🏼\u200d♂️" 1F9B9-1F3FC-200D-2642-FE0F
-    68161, // "🥿"
     61791, // "🤦🏻" 1F926-1F3FB
     79162, // "🧗🏾" 1F9D7-1F3FE
     73941, // "🧑🏼\u200d🚒" 1F9D1-1F3FC-200D-1F692
-    67459, // "🥩"
     73724, // "🧑🏼\u200d🏫" 1F9D1-1F3FC-200D-1F3EB
     79226, // "🧗🏿\u200d♂️" 1F9D7-1F3FF-200D-2642-FE0F
     81349, // "🧜🏻" 1F9DC-1F3FB
@@ -7469,161 +9601,113 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     80098, // "🧙🏾" 1F9D9-1F3FE
     78459, // "🧖🏽\u200d♀️" 1F9D6-1F3FD-200D-2640-FE0F
     61909, // "🤦🏽\u200d♂️" 1F926-1F3FD-200D-2642-FE0F
-    66438, // "🥂"
     81617, // "🧜🏾" 1F9DC-1F3FE
     62440, // "🤰🏻" 1F930-1F3FB
     60597, // "🤘🏼" 1F918-1F3FC
     65931, // "🤾🏻\u200d♀️" 1F93E-1F3FB-200D-2640-FE0F
     76788, // "🧑🏿\u200d🦲" 1F9D1-1F3FF-200D-1F9B2
-    68007, // "🥸"
     66271, // "🤾🏿" 1F93E-1F3FF
     73422, // "🧑🏻\u200d⚕️" 1F9D1-1F3FB-200D-2695-FE0F
     61838, // "🤦🏼\u200d♂️" 1F926-1F3FC-200D-2642-FE0F
-    64642, // "🤸"
     71085, // "🦻🏿" 1F9BB-1F3FF
     63625, // "🤶🏾" 1F936-1F3FE
     72863, // "🧑🏻\u200d🏫" 1F9D1-1F3FB-200D-1F3EB
     61003, // "🤛🏻" 1F91B-1F3FB
-    62381, // "🤮"
     76324, // "🧑🏿\u200d🏫" 1F9D1-1F3FF-200D-1F3EB
     78813, // "🧗🏻\u200d♀️" 1F9D7-1F3FB-200D-2640-FE0F
     76188, // "🧑🏿\u200d🍼" 1F9D1-1F3FF-200D-1F37C
-    69643, // "🦴"
-    71330, // "🧄"
     60255, // "🤏🏾" 1F90F-1F3FE
     81319, // "🧜🏻\u200d♂️" 1F9DC-1F3FB-200D-2642-FE0F
-    82883, // "🧲"
     70808, // "🦹🏿\u200d♂️" 1F9B9-1F3FF-200D-2642-FE0F
     77196, // "🧑\u200d🏫" 1F9D1-200D-1F3EB
     73849, // "🧑🏼\u200d🔧" 1F9D1-1F3FC-200D-1F527
     79984, // "🧙🏽\u200d♂️" 1F9D9-1F3FD-200D-2642-FE0F
     70729, // "🦹🏾\u200d♂️" 1F9B9-1F3FE-200D-2642-FE0F
-    82436, // "🧢"
     76913, // "🧑🏿\u200d⚖️" 1F9D1-1F3FF-200D-2696-FE0F
     75146, // "🧑🏽\u200d⚕️" 1F9D1-1F3FD-200D-2695-FE0F
     65454, // "🤽🏼\u200d♀️" 1F93D-1F3FC-200D-2640-FE0F
     69835, // "🦶🏽" 1F9B6-1F3FD
     69879, // "🦶🏿" 1F9B6-1F3FF
     80642, // "🧚🏿\u200d♀️" 1F9DA-1F3FF-200D-2640-FE0F
-    67197, // "🥟"
-    67983, // "🥷"
     78285, // "🧖🏻\u200d♀️" 1F9D6-1F3FB-200D-2640-FE0F
     70550, // "🦹🏼\u200d♀️" 1F9B9-1F3FC-200D-2640-FE0F
     82018, // "🧝🏽" 1F9DD-1F3FD
     60208, // "🤏🏼" 1F90F-1F3FC
     61815, // "🤦🏼\u200d♀️" 1F926-1F3FC-200D-2640-FE0F
-    66932, // "🥕"
-    82626, // "🧩"
     71922, // "🧎🏾\u200d♀️" 1F9CE-1F3FE-200D-2640-FE0F
     78048, // "🧔🏽" 1F9D4-1F3FD
     77796, // "🧒🏾" 1F9D2-1F3FE
     76513, // "🧑🏿\u200d🚀" 1F9D1-1F3FF-200D-1F680
     79652, // "🧘🏿\u200d♀️" 1F9D8-1F3FF-200D-2640-FE0F
     60862, // "🤚🏻" 1F91A-1F3FB
-    71293, // "🧂"
-    77716, // "🧑"
     80411, // "🧚🏼\u200d♂️" 1F9DA-1F3FC-200D-2642-FE0F
     73342, // "🧑🏻\u200d🦳" 1F9D1-1F3FB-200D-1F9B3
     74468, // "🧑🏽\u200d🎄" 1F9D1-1F3FD-200D-1F384
-    82914, // "🧳"
     62120, // "🤦\u200d♂️" 1F926-200D-2642-FE0F
     63817, // "🤷🏼\u200d♂️" 1F937-1F3FC-200D-2642-FE0F
-    82515, // "🧥"
-    71241, // "🧀"
     65079, // "🤹🏿\u200d♂️" 1F939-1F3FF-200D-2642-FE0F
     81971, // "🧝🏽\u200d♀️" 1F9DD-1F3FD-200D-2640-FE0F
     80973, // "🧛🏽\u200d♀️" 1F9DB-1F3FD-200D-2640-FE0F
-    66497, // "🥅"
     77132, // "🧑\u200d🎤" 1F9D1-200D-1F3A4
     82206, // "🧝\u200d♂️" 1F9DD-200D-2642-FE0F
     80843, // "🧛🏻\u200d♂️" 1F9DB-1F3FB-200D-2642-FE0F
     64182, // "🤷\u200d♂️" 1F937-200D-2642-FE0F
     75457, // "🧑🏾\u200d🏫" 1F9D1-1F3FE-200D-1F3EB
     81052, // "🧛🏾\u200d♀️" 1F9DB-1F3FE-200D-2640-FE0F
-    69773, // "🦵"
     80183, // "🧙🏿" 1F9D9-1F3FF
     73191, // "🧑🏻\u200d🤝\u200d🧑🏾" 1F9D1-1F3FB-200D-1F91D-200D-1F9D1-1F3FE
     63279, // "🤵🏽\u200d♂️" 1F935-1F3FD-200D-2642-FE0F
     63329, // "🤵🏾\u200d♀️" 1F935-1F3FE-200D-2640-FE0F
-    79353, // "🧗"
-    66728, // "🥎"
     74741, // "🧑🏽\u200d🔬" 1F9D1-1F3FD-200D-1F52C
     80154, // "🧙🏿\u200d♂️" 1F9D9-1F3FF-200D-2642-FE0F
     69792, // "🦶🏻" 1F9B6-1F3FB
     63551, // "🤶🏻" 1F936-1F3FB
     77638, // "🧑\u200d⚕️" 1F9D1-200D-2695-FE0F
     75616, // "🧑🏾\u200d🔬" 1F9D1-1F3FE-200D-1F52C
-    67413, // "🥧"
     72958, // "🧑🏻\u200d💼" 1F9D1-1F3FB-200D-1F4BC
-    69358, // "🦪"
     75171, // "🧑🏽\u200d⚖️" 1F9D1-1F3FD-200D-2696-FE0F
     82041, // "🧝🏾\u200d♀️" 1F9DD-1F3FE-200D-2640-FE0F
     73526, // "🧑🏼\u200d🌾" 1F9D1-1F3FC-200D-1F33E
     67884, // "🥷🏼" 1F977-1F3FC
     80670, // "🧚🏿\u200d♂️" 1F9DA-1F3FF-200D-2642-FE0F
     66298, // "🤾\u200d♀️" 1F93E-200D-2640-FE0F
-    68375, // "🦇"
     77611, // "🧑\u200d🦽" 1F9D1-200D-1F9BD
-    78263, // "🧕"
-    68920, // "🦚"
     78242, // "🧕🏿" 1F9D5-1F3FF
-    69296, // "🦨"
     66027, // "🤾🏼\u200d♂️" 1F93E-1F3FC-200D-2642-FE0F
     72832, // "🧑🏻\u200d🎨" 1F9D1-1F3FB-200D-1F3A8
     70231, // "🦸🏾\u200d♂️" 1F9B8-1F3FE-200D-2642-FE0F
     78102, // "🧔🏿" 1F9D4-1F3FF
-    82707, // "🧬"
     79067, // "🧗🏽" 1F9D7-1F3FD
     79562, // "🧘🏽" 1F9D8-1F3FD
-    67764, // "🥴"
     74772, // "🧑🏽\u200d🚀" 1F9D1-1F3FD-200D-1F680
     78346, // "🧖🏻" 1F9D6-1F3FB
-    66466, // "🥃"
     73817, // "🧑🏼\u200d💼" 1F9D1-1F3FC-200D-1F4BC
-    68265, // "🦃"
-    69110, // "🦡"
     70143, // "🦸🏽\u200d♂️" 1F9B8-1F3FD-200D-2642-FE0F
-    66696, // "🥍"
     60654, // "🤘🏿" 1F918-1F3FF
     61270, // "🤞🏻" 1F91E-1F3FB
     82181, // "🧝\u200d♀️" 1F9DD-200D-2640-FE0F
     61208, // "🤜🏿" 1F91C-1F3FF
-    60130, // "🤍"
     71529, // "🧍🏻\u200d♀️" 1F9CD-1F3FB-200D-2640-FE0F
-    68353, // "🦆"
     64268, // "🤸🏻\u200d♂️" 1F938-1F3FB-200D-2642-FE0F
-    70944, // "🦺"
     63452, // "🤵🏿" 1F935-1F3FF
-    60485, // "🤕"
-    66758, // "🥏"
-    67112, // "🥜"
-    68138, // "🥾"
     71630, // "🧍🏾\u200d♀️" 1F9CD-1F3FE-200D-2640-FE0F
     74967, // "🧑🏽\u200d🦯" 1F9D1-1F3FD-200D-1F9AF
-    83019, // "🧷"
     60579, // "🤘🏻" 1F918-1F3FB
     79518, // "🧘🏽\u200d♀️" 1F9D8-1F3FD-200D-2640-FE0F
     64823, // "🤹🏼" 1F939-1F3FC
-    69079, // "🦠"
     73220, // "🧑🏻\u200d🤝\u200d🧑🏿" 1F9D1-1F3FB-200D-1F91D-200D-1F9D1-1F3FF
-    71355, // "🧅"
     71596, // "🧍🏽\u200d♀️" 1F9CD-1F3FD-200D-2640-FE0F
-    83075, // "🧹"
     64026, // "🤷🏾" 1F937-1F3FE
     71665, // "🧍🏿\u200d♀️" 1F9CD-1F3FF-200D-2640-FE0F
     66003, // "🤾🏼\u200d♀️" 1F93E-1F3FC-200D-2640-FE0F
-    72629, // "🧐"
     75758, // "🧑🏾\u200d🤝\u200d🧑🏽" 1F9D1-1F3FE-200D-1F91D-200D-1F9D1-1F3FD
     80211, // "🧙\u200d♀️" 1F9D9-200D-2640-FE0F
     63037, // "🤴🏾" 1F934-1F3FE
     65981, // "🤾🏻" 1F93E-1F3FB
     80496, // "🧚🏽\u200d♂️" 1F9DA-1F3FD-200D-2642-FE0F
-    63677, // "🤶"
     80894, // "🧛🏼\u200d♀️" 1F9DB-1F3FC-200D-2640-FE0F
-    71189, // "🦾"
     76623, // "🧑🏿\u200d🤝\u200d🧑🏽" 1F9D1-1F3FF-200D-1F91D-200D-1F9D1-1F3FD
     75283, // "🧑🏾\u200d🍳" 1F9D1-1F3FE-200D-1F373
-    82956, // "🧵"
     72114, // "🧏🏻\u200d♀️" 1F9CF-1F3FB-200D-2640-FE0F
     64547, // "🤸🏿\u200d♂️" 1F938-1F3FF-200D-2642-FE0F
     82066, // "🧝🏾\u200d♂️" 1F9DD-1F3FE-200D-2642-FE0F
@@ -7639,31 +9723,20 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     82251, // "🧞\u200d♀️" 1F9DE-200D-2640-FE0F
     71857, // "🧎🏽\u200d♀️" 1F9CE-1F3FD-200D-2640-FE0F
     65143, // "🤹\u200d♀️" 1F939-200D-2640-FE0F
-    81796, // "🧜"
     75922, // "🧑🏾\u200d🦲" 1F9D1-1F3FE-200D-1F9B2
-    70437, // "🦸"
     72660, // "🧑🏻\u200d🌾" 1F9D1-1F3FB-200D-1F33E
     74254, // "🧑🏼\u200d🦽" 1F9D1-1F3FC-200D-1F9BD
     77381, // "🧑\u200d🚀" 1F9D1-200D-1F680
     63427, // "🤵🏿\u200d♂️" 1F935-1F3FF-200D-2642-FE0F
     81996, // "🧝🏽\u200d♂️" 1F9DD-1F3FD-200D-2642-FE0F
     78609, // "🧖🏾" 1F9D6-1F3FE
-    62166, // "🤧"
-    82543, // "🧦"
     64246, // "🤸🏻\u200d♀️" 1F938-1F3FB-200D-2640-FE0F
-    67357, // "🥥"
-    69048, // "🦟"
-    68039, // "🥺"
-    68510, // "🦌"
     71794, // "🧎🏼\u200d♀️" 1F9CE-1F3FC-200D-2640-FE0F
     79928, // "🧙🏼" 1F9D9-1F3FC
     73881, // "🧑🏼\u200d🔬" 1F9D1-1F3FC-200D-1F52C
     75584, // "🧑🏾\u200d🔧" 1F9D1-1F3FE-200D-1F527
     82341, // "🧟\u200d♂️" 1F9DF-200D-2642-FE0F
-    66783, // "🥐"
-    62948, // "🤳"
     75067, // "🧑🏽\u200d🦳" 1F9D1-1F3FD-200D-1F9B3
-    62669, // "🤱"
     65486, // "🤽🏼\u200d♂️" 1F93D-1F3FC-200D-2642-FE0F
     80612, // "🧚🏾" 1F9DA-1F3FE
     81852, // "🧝🏻\u200d♂️" 1F9DD-1F3FB-200D-2642-FE0F
@@ -7671,29 +9744,19 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     81469, // "🧜🏽\u200d♀️" 1F9DC-1F3FD-200D-2640-FE0F
     70887, // "🦹\u200d♂️" 1F9B9-200D-2642-FE0F
     62992, // "🤴🏼" 1F934-1F3FC
-    71219, // "🦿"
     75678, // "🧑🏾\u200d🚒" 1F9D1-1F3FE-200D-1F692
     70466, // "🦹🏻\u200d♀️" 1F9B9-1F3FB-200D-2640-FE0F
     63131, // "🤵🏻\u200d♂️" 1F935-1F3FB-200D-2642-FE0F
-    66963, // "🥖"
-    60517, // "🤖"
-    65239, // "🤺"
     71540, // "🧍🏻\u200d♂️" 1F9CD-1F3FB-200D-2642-FE0F
     75995, // "🧑🏾\u200d🦽" 1F9D1-1F3FE-200D-1F9BD
-    68429, // "🦉"
-    69436, // "🦭"
-    71508, // "🧋"
     70203, // "🦸🏾\u200d♀️" 1F9B8-1F3FE-200D-2640-FE0F
     70349, // "🦸🏿" 1F9B8-1F3FF
     73448, // "🧑🏻\u200d⚖️" 1F9D1-1F3FB-200D-2696-FE0F
-    62197, // "🤨"
     80240, // "🧙\u200d♂️" 1F9D9-200D-2642-FE0F
     61023, // "🤛🏼" 1F91B-1F3FC
     72895, // "🧑🏻\u200d🏭" 1F9D1-1F3FB-200D-1F3ED
     65742, // "🤽🏿\u200d♀️" 1F93D-1F3FF-200D-2640-FE0F
     61288, // "🤞🏼" 1F91E-1F3FC
-    69183, // "🦤"
-    82655, // "🧪"
     63206, // "🤵🏼\u200d♂️" 1F935-1F3FC-200D-2642-FE0F
     63182, // "🤵🏼\u200d♀️" 1F935-1F3FC-200D-2640-FE0F
     70086, // "🦸🏼" 1F9B8-1F3FC
@@ -7701,17 +9764,13 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     72454, // "🧏🏿\u200d♀️" 1F9CF-1F3FF-200D-2640-FE0F
     60776, // "🤙🏾" 1F919-1F3FE
     70786, // "🦹🏿\u200d♀️" 1F9B9-1F3FF-200D-2640-FE0F
-    69156, // "🦣"
     76567, // "🧑🏿\u200d🤝\u200d🧑🏻" 1F9D1-1F3FF-200D-1F91D-200D-1F9D1-1F3FB
     72512, // "🧏🏿" 1F9CF-1F3FF
-    67046, // "🥙"
     61382, // "🤟🏻" 1F91F-1F3FB
-    60361, // "🤑"
     80728, // "🧚\u200d♀️" 1F9DA-200D-2640-FE0F
     76354, // "🧑🏿\u200d🏭" 1F9D1-1F3FF-200D-1F3ED
     79539, // "🧘🏽\u200d♂️" 1F9D8-1F3FD-200D-2642-FE0F
     60281, // "🤏🏿" 1F90F-1F3FF
-    69383, // "🦫"
     62518, // "🤰🏿" 1F930-1F3FF
     60719, // "🤙🏼" 1F919-1F3FC
     75251, // "🧑🏾\u200d🌾" 1F9D1-1F3FE-200D-1F33E
@@ -7725,19 +9784,12 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     76708, // "🧑🏿\u200d🦯" 1F9D1-1F3FF-200D-1F9AF
     78845, // "🧗🏻\u200d♂️" 1F9D7-1F3FB-200D-2642-FE0F
     74648, // "🧑🏽\u200d💻" 1F9D1-1F3FD-200D-1F4BB
-    66845, // "🥒"
-    61684, // "🤤"
     77491, // "🧑\u200d🦰" 1F9D1-200D-1F9B0
     74418, // "🧑🏽\u200d🍳" 1F9D1-1F3FD-200D-1F373
-    82987, // "🧶"
-    68069, // "🥻"
-    62289, // "🤫"
     61468, // "🤟🏾" 1F91F-1F3FE
-    60455, // "🤔"
     75337, // "🧑🏾\u200d🎄" 1F9D1-1F3FE-200D-1F384
     75732, // "🧑🏾\u200d🤝\u200d🧑🏼" 1F9D1-1F3FE-200D-1F91D-200D-1F9D1-1F3FC
     77662, // "🧑\u200d⚖️" 1F9D1-200D-2696-FE0F
-    61589, // "🤡"
     81439, // "🧜🏼" 1F9DC-1F3FC
     70319, // "🦸🏿\u200d♂️" 1F9B8-1F3FF-200D-2642-FE0F
     78667, // "🧖🏿\u200d♂️" 1F9D6-1F3FF-200D-2642-FE0F
@@ -7750,7 +9802,6 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     62073, // "🤦🏿" 1F926-1F3FF
     65047, // "🤹🏿\u200d♀️" 1F939-1F3FF-200D-2640-FE0F
     65296, // "🤼\u200d♂️" 1F93C-200D-2642-FE0F
-    66553, // "🥈"
     76652, // "🧑🏿\u200d🤝\u200d🧑🏾" 1F9D1-1F3FF-200D-1F91D-200D-1F9D1-1F3FE
     80468, // "🧚🏽\u200d♀️" 1F9DA-1F3FD-200D-2640-FE0F
     79673, // "🧘🏿\u200d♂️" 1F9D8-1F3FF-200D-2642-FE0F
@@ -7765,13 +9816,8 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     80126, // "🧙🏿\u200d♀️" 1F9D9-1F3FF-200D-2640-FE0F
     72306, // "🧏🏽\u200d♂️" 1F9CF-1F3FD-200D-2642-FE0F
     72482, // "🧏🏿\u200d♂️" 1F9CF-1F3FF-200D-2642-FE0F
-    68968, // "🦜"
     80069, // "🧙🏾\u200d♂️" 1F9D9-1F3FE-200D-2642-FE0F
     60185, // "🤏🏻" 1F90F-1F3FB
-    69924, // "🦷"
-    82407, // "🧡"
-    69521, // "🦰"
-    61229, // "🤜"
     70407, // "🦸\u200d♂️" 1F9B8-200D-2642-FE0F
     69856, // "🦶🏾" 1F9B6-1F3FE
     65582, // "🤽🏽\u200d♂️" 1F93D-1F3FD-200D-2642-FE0F
@@ -7781,7 +9827,6 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     64697, // "🤹🏻\u200d♂️" 1F939-1F3FB-200D-2642-FE0F
     66198, // "🤾🏾" 1F93E-1F3FE
     65111, // "🤹🏿" 1F939-1F3FF
-    68605, // "🦏"
     71774, // "🧎🏻" 1F9CE-1F3FB
     81588, // "🧜🏾\u200d♂️" 1F9DC-1F3FE-200D-2642-FE0F
     73967, // "🧑🏼\u200d🤝\u200d🧑🏻" 1F9D1-1F3FC-200D-1F91D-200D-1F9D1-1F3FB
@@ -7798,22 +9843,15 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     80041, // "🧙🏾\u200d♀️" 1F9D9-1F3FE-200D-2640-FE0F
     64595, // "🤸\u200d♀️" 1F938-200D-2640-FE0F
     81026, // "🧛🏽" 1F9DB-1F3FD
-    68947, // "🦛"
     74616, // "🧑🏽\u200d🏭" 1F9D1-1F3FD-200D-1F3ED
     76832, // "🧑🏿\u200d🦼" 1F9D1-1F3FF-200D-1F9BC
-    66523, // "🥇"
     63478, // "🤵\u200d♀️" 1F935-200D-2640-FE0F
-    69552, // "🦱"
     70261, // "🦸🏾" 1F9B8-1F3FE
-    68297, // "🦄"
     71029, // "🦻🏽" 1F9BB-1F3FD
-    80786, // "🧚"
     70601, // "🦹🏼" 1F9B9-1F3FC
     77948, // "🧓🏿" 1F9D3-1F3FF
-    71142, // "🦼"
     72140, // "🧏🏻\u200d♂️" 1F9CF-1F3FB-200D-2642-FE0F
     78578, // "🧖🏾\u200d♂️" 1F9D6-1F3FE-200D-2642-FE0F
-    66905, // "🥔"
     72169, // "🧏🏻" 1F9CF-1F3FB
     77816, // "🧒🏿" 1F9D2-1F3FF
     79194, // "🧗🏿\u200d♀️" 1F9D7-1F3FF-200D-2640-FE0F
@@ -7839,63 +9877,44 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     64619, // "🤸\u200d♂️" 1F938-200D-2642-FE0F
     74940, // "🧑🏽\u200d🤝\u200d🧑🏿" 1F9D1-1F3FD-200D-1F91D-200D-1F9D1-1F3FF
     79408, // "🧘🏻\u200d♂️" 1F9D8-1F3FB-200D-2642-FE0F
-    60104, // "🤌"
     76449, // "🧑🏿\u200d🔧" 1F9D1-1F3FF-200D-1F527
     74911, // "🧑🏽\u200d🤝\u200d🧑🏾" 1F9D1-1F3FD-200D-1F91D-200D-1F9D1-1F3FE
     62623, // "🤱🏾" 1F931-1F3FE
     69731, // "🦵🏾" 1F9B5-1F3FE
     74051, // "🧑🏼\u200d🤝\u200d🧑🏾" 1F9D1-1F3FC-200D-1F91D-200D-1F9D1-1F3FE
-    78787, // "🧖"
     64089, // "🤷🏿\u200d♂️" 1F937-1F3FF-200D-2642-FE0F
     76386, // "🧑🏿\u200d💻" 1F9D1-1F3FF-200D-1F4BB
-    82857, // "🧱"
     75393, // "🧑🏾\u200d🎤" 1F9D1-1F3FE-200D-1F3A4
     71700, // "🧍\u200d♀️" 1F9CD-200D-2640-FE0F
     73913, // "🧑🏼\u200d🚀" 1F9D1-1F3FC-200D-1F680
-    67093, // "🥛"
-    67331, // "🥤"
     74882, // "🧑🏽\u200d🤝\u200d🧑🏽" 1F9D1-1F3FD-200D-1F91D-200D-1F9D1-1F3FD
     77901, // "🧓🏽" 1F9D3-1F3FD
     75046, // "🧑🏽\u200d🦲" 1F9D1-1F3FD-200D-1F9B2
-    71313, // "🧃"
     80555, // "🧚🏾\u200d♀️" 1F9DA-1F3FE-200D-2640-FE0F
-    68668, // "🦑"
     69942, // "🦸🏻\u200d♀️" 1F9B8-1F3FB-200D-2640-FE0F
     62027, // "🤦🏿\u200d♀️" 1F926-1F3FF-200D-2640-FE0F
     61125, // "🤜🏻" 1F91C-1F3FB
     64334, // "🤸🏼\u200d♂️" 1F938-1F3FC-200D-2642-FE0F
     76679, // "🧑🏿\u200d🤝\u200d🧑🏿" 1F9D1-1F3FF-200D-1F91D-200D-1F9D1-1F3FF
     72223, // "🧏🏼\u200d♂️" 1F9CF-1F3FC-200D-2642-FE0F
-    67670, // "🥱"
     60954, // "🤚🏿" 1F91A-1F3FF
     76079, // "🧑🏾\u200d✈️" 1F9D1-1F3FE-200D-2708-FE0F
-    68481, // "🦋"
     74338, // "🧑🏼\u200d✈️" 1F9D1-1F3FC-200D-2708-FE0F
     66314, // "🤾\u200d♂️" 1F93E-200D-2642-FE0F
-    64214, // "🤷"
     74711, // "🧑🏽\u200d🔧" 1F9D1-1F3FD-200D-1F527
     71900, // "🧎🏽" 1F9CE-1F3FD
     71607, // "🧍🏽\u200d♂️" 1F9CD-1F3FD-200D-2642-FE0F
-    67521, // "🥬"
-    69584, // "🦲"
-    69494, // "🦯"
     60806, // "🤙🏿" 1F919-1F3FF
     73323, // "🧑🏻\u200d🦲" 1F9D1-1F3FB-200D-1F9B2
-    67013, // "🥘"
-    67609, // "🥯"
     75090, // "🧑🏽\u200d🦼" 1F9D1-1F3FD-200D-1F9BC
     64572, // "🤸🏿" 1F938-1F3FF
-    82599, // "🧨"
     79606, // "🧘🏾\u200d♂️" 1F9D8-1F3FE-200D-2642-FE0F
     72928, // "🧑🏻\u200d💻" 1F9D1-1F3FB-200D-1F4BB
-    79763, // "🧘"
     61886, // "🤦🏽\u200d♀️" 1F926-1F3FD-200D-2640-FE0F
     78520, // "🧖🏽" 1F9D6-1F3FD
     73754, // "🧑🏼\u200d🏭" 1F9D1-1F3FC-200D-1F3ED
-    83217, // "🧾"
     79385, // "🧘🏻\u200d♀️" 1F9D8-1F3FB-200D-2640-FE0F
     76208, // "🧑🏿\u200d🎄" 1F9D1-1F3FF-200D-1F384
-    68543, // "🦍"
     64153, // "🤷\u200d♀️" 1F937-200D-2640-FE0F
     76594, // "🧑🏿\u200d🤝\u200d🧑🏼" 1F9D1-1F3FF-200D-1F91D-200D-1F9D1-1F3FC
     69970, // "🦸🏻\u200d♂️" 1F9B8-1F3FB-200D-2642-FE0F
@@ -7903,105 +9922,80 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     62050, // "🤦🏿\u200d♂️" 1F926-1F3FF-200D-2642-FE0F
     62460, // "🤰🏼" 1F930-1F3FC
     71816, // "🧎🏼\u200d♂️" 1F9CE-1F3FC-200D-2642-FE0F
-    63085, // "🤴"
     73164, // "🧑🏻\u200d🤝\u200d🧑🏽" 1F9D1-1F3FB-200D-1F91D-200D-1F9D1-1F3FD
     73502, // "🧑🏻" 1F9D1-1F3FB
     65614, // "🤽🏽" 1F93D-1F3FD
     74184, // "🧑🏼\u200d🦲" 1F9D1-1F3FC-200D-1F9B2
-    66617, // "🥊"
     65015, // "🤹🏾" 1F939-1F3FE
     64401, // "🤸🏽\u200d♂️" 1F938-1F3FD-200D-2642-FE0F
     72007, // "🧎🏿\u200d♂️" 1F9CE-1F3FF-200D-2642-FE0F
     71058, // "🦻🏾" 1F9BB-1F3FE
-    82464, // "🧣"
     60231, // "🤏🏽" 1F90F-1F3FD
-    71380, // "🧆"
     70115, // "🦸🏽\u200d♀️" 1F9B8-1F3FD-200D-2640-FE0F
     61306, // "🤞🏽" 1F91E-1F3FD
     81738, // "🧜\u200d♀️" 1F9DC-200D-2640-FE0F
     67934, // "🥷🏾" 1F977-1F3FE
     72800, // "🧑🏻\u200d🎤" 1F9D1-1F3FB-200D-1F3A4
-    66875, // "🥓"
     71688, // "🧍🏿" 1F9CD-1F3FF
     75019, // "🧑🏽\u200d🦱" 1F9D1-1F3FD-200D-1F9B1
     64791, // "🤹🏼\u200d♂️" 1F939-1F3FC-200D-2642-FE0F
     76294, // "🧑🏿\u200d🎨" 1F9D1-1F3FF-200D-1F3A8
     77463, // "🧑\u200d🦯" 1F9D1-200D-1F9AF
     71837, // "🧎🏼" 1F9CE-1F3FC
-    67438, // "🥨"
     62498, // "🤰🏾" 1F930-1F3FE
     81379, // "🧜🏼\u200d♀️" 1F9DC-1F3FC-200D-2640-FE0F
     62785, // "🤲🏿" 1F932-1F3FF
     82158, // "🧝🏿" 1F9DD-1F3FF
     61439, // "🤟🏽" 1F91F-1F3FD
-    71720, // "🧍"
     72774, // "🧑🏻\u200d🎓" 1F9D1-1F3FB-200D-1F393
     77320, // "🧑\u200d🔧" 1F9D1-200D-1F527
     70173, // "🦸🏽" 1F9B8-1F3FD
     79899, // "🧙🏼\u200d♂️" 1F9D9-1F3FC-200D-2642-FE0F
     79431, // "🧘🏻" 1F9D8-1F3FB
-    68329, // "🦅"
-    62319, // "🤬"
     79004, // "🧗🏽\u200d♀️" 1F9D7-1F3FD-200D-2640-FE0F
-    68720, // "🦓"
     74556, // "🧑🏽\u200d🎨" 1F9D1-1F3FD-200D-1F3A8
     66172, // "🤾🏾\u200d♂️" 1F93E-1F3FE-200D-2642-FE0F
     67859, // "🥷🏻" 1F977-1F3FB
     72542, // "🧏\u200d♀️" 1F9CF-200D-2640-FE0F
-    69128, // "🦢"
     81105, // "🧛🏾" 1F9DB-1F3FE
-    67307, // "🥣"
-    82739, // "🧭"
-    66814, // "🥑"
     71552, // "🧍🏻" 1F9CD-1F3FB
     69675, // "🦵🏻" 1F9B5-1F3FB
-    82803, // "🧯"
     70759, // "🦹🏾" 1F9B9-1F3FE
     82111, // "🧝🏿\u200d♀️" 1F9DD-1F3FF-200D-2640-FE0F
     61063, // "🤛🏾" 1F91B-1F3FE
-    62257, // "🤪"
     82274, // "🧞\u200d♂️" 1F9DE-200D-2642-FE0F
     61344, // "🤞🏿" 1F91E-1F3FF
     81948, // "🧝🏼" 1F9DD-1F3FC
     61145, // "🤜🏼" 1F91C-1F3FC
     76736, // "🧑🏿\u200d🦰" 1F9D1-1F3FF-200D-1F9B0
     78434, // "🧖🏼" 1F9D6-1F3FC
-    60981, // "🤚"
     80353, // "🧚🏻" 1F9DA-1F3FB
-    68204, // "🦁"
     60048, // "🤌🏾" 1F90C-1F3FE
     72250, // "🧏🏼" 1F9CF-1F3FC
     65422, // "🤽🏻" 1F93D-1F3FB
-    60547, // "🤗"
     63873, // "🤷🏽\u200d♀️" 1F937-1F3FD-200D-2640-FE0F
     81080, // "🧛🏾\u200d♂️" 1F9DB-1F3FE-200D-2642-FE0F
     78198, // "🧕🏽" 1F9D5-1F3FD
     71563, // "🧍🏼\u200d♀️" 1F9CD-1F3FC-200D-2640-FE0F
     73022, // "🧑🏻\u200d🔬" 1F9D1-1F3FB-200D-1F52C
     61084, // "🤛🏿" 1F91B-1F3FF
-    68453, // "🦊"
     80324, // "🧚🏻\u200d♂️" 1F9DA-1F3FB-200D-2642-FE0F
-    67386, // "🥦"
     76103, // "🧑🏾" 1F9D1-1F3FE
     75785, // "🧑🏾\u200d🤝\u200d🧑🏾" 1F9D1-1F3FE-200D-1F91D-200D-1F9D1-1F3FE
-    71411, // "🧇"
     80583, // "🧚🏾\u200d♂️" 1F9DA-1F3FE-200D-2642-FE0F
     62003, // "🤦🏾" 1F926-1F3FE
     63701, // "🤷🏻\u200d♀️" 1F937-1F3FB-200D-2640-FE0F
     65175, // "🤹\u200d♂️" 1F939-200D-2642-FE0F
-    71114, // "🦻"
     76262, // "🧑🏿\u200d🎤" 1F9D1-1F3FF-200D-1F3A4
     72990, // "🧑🏻\u200d🔧" 1F9D1-1F3FB-200D-1F527
     76967, // "🧑🏿" 1F9D1-1F3FF
     63502, // "🤵\u200d♂️" 1F935-200D-2642-FE0F
-    66484, // "🥄"
     65550, // "🤽🏽\u200d♀️" 1F93D-1F3FD-200D-2640-FE0F
     63353, // "🤵🏾\u200d♂️" 1F935-1F3FE-200D-2642-FE0F
     63255, // "🤵🏽\u200d♀️" 1F935-1F3FD-200D-2640-FE0F
     61744, // "🤦🏻\u200d♀️" 1F926-1F3FB-200D-2640-FE0F
     76887, // "🧑🏿\u200d⚕️" 1F9D1-1F3FF-200D-2695-FE0F
     72028, // "🧎🏿" 1F9CE-1F3FF
-    67501, // "🥫"
     64290, // "🤸🏻" 1F938-1F3FB
     79495, // "🧘🏼" 1F9D8-1F3FC
     64474, // "🤸🏾\u200d♂️" 1F938-1F3FE-200D-2642-FE0F
@@ -8010,172 +10004,394 @@ const OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [usize; 882] = [
     82136, // "🧝🏿\u200d♂️" 1F9DD-1F3FF-200D-2642-FE0F
     71753, // "🧎🏻\u200d♂️" 1F9CE-1F3FB-200D-2642-FE0F
     75425, // "🧑🏾\u200d🎨" 1F9D1-1F3FE-200D-1F3A8
-    82488, // "🧤"
     63788, // "🤷🏼\u200d♀️" 1F937-1F3FC-200D-2640-FE0F
     75552, // "🧑🏾\u200d💼" 1F9D1-1F3FE-200D-1F4BC
     78376, // "🧖🏼\u200d♀️" 1F9D6-1F3FC-200D-2640-FE0F
     65646, // "🤽🏾\u200d♀️" 1F93D-1F3FE-200D-2640-FE0F
     64058, // "🤷🏿\u200d♀️" 1F937-1F3FF-200D-2640-FE0F
     72569, // "🧏\u200d♂️" 1F9CF-200D-2642-FE0F
-    61364, // "🤞"
     74524, // "🧑🏽\u200d🎤" 1F9D1-1F3FD-200D-1F3A4
     70628, // "🦹🏽\u200d♀️" 1F9B9-1F3FD-200D-2640-FE0F
     59994, // "🤌🏼" 1F90C-1F3FC
-    66642, // "🥋"
     77056, // "🧑\u200d🍼" 1F9D1-200D-1F37C
-    67251, // "🥡"
     64356, // "🤸🏼" 1F938-1F3FC
     63994, // "🤷🏾\u200d♂️" 1F937-1F3FE-200D-2642-FE0F
     77878, // "🧓🏼" 1F9D3-1F3FC
-    68894, // "🦙"
     81924, // "🧝🏼\u200d♂️" 1F9DD-1F3FC-200D-2642-FE0F
-    82384, // "🧠"
     81648, // "🧜🏿\u200d♀️" 1F9DC-1F3FF-200D-2640-FE0F
     75489, // "🧑🏾\u200d🏭" 1F9D1-1F3FE-200D-1F3ED
-    82771, // "🧮"
-    61621, // "🤢"
     81678, // "🧜🏿\u200d♂️" 1F9DC-1F3FF-200D-2642-FE0F
-    66352, // "🤿"
-    78129, // "🧔"
-    66585, // "🥉"
     76943, // "🧑🏿\u200d✈️" 1F9D1-1F3FF-200D-2708-FE0F
     75703, // "🧑🏾\u200d🤝\u200d🧑🏻" 1F9D1-1F3FE-200D-1F91D-200D-1F9D1-1F3FB
     75896, // "🧑🏾\u200d🦱" 1F9D1-1F3FE-200D-1F9B1
     73692, // "🧑🏼\u200d🎨" 1F9D1-1F3FC-200D-1F3A8
-    61716, // "🤥"
     76761, // "🧑🏿\u200d🦱" 1F9D1-1F3FF-200D-1F9B1
 ];
 
-/// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
-/// Only attempt to match grapheme clusters of length limit codepoints.
-fn find_symbols_and_pictographs_extended_a(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_SYMBOLS_AND_PICTOGRAPHS_EXTENDED_A.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_SYMBOLS_AND_PICTOGRAPHS_EXTENDED_A[index], bytes_hashed)),
-        _ => None,
-    }
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_symbols_and_pictographs_extended_a_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_SYMBOLS_AND_PICTOGRAPHS_EXTENDED_A, 0x1FA70, cluster)
 }
 
-/// Index of murmur3(grapheme cluster); sort matches OFFSET_SYMBOLS_AND_PICTOGRAPHS_EXTENDED_A
-const HASH_SYMBOLS_AND_PICTOGRAPHS_EXTENDED_A: [u32; 57] = [
-    0x0296B2E3,  // "🪵"
-    0x049BBC67,  // "🪤"
-    0x0524762A,  // "🩳"
-    0x0A1A6928,  // "🪞"
-    0x0B66A480,  // "🪚"
-    0x18EBAA23,  // "🪅"
-    0x1C11D7B6,  // "🪖"
-    0x2069D861,  // "🩸"
-    0x2B248FAF,  // "🪡"
-    0x2FC6B5E2,  // "🪆"
-    0x304CA9C1,  // "🪧"
-    0x3B72AAF6,  // "🫔"
-    0x3C2FF8D0,  // "🫐"
-    0x3CC6DB29,  // "🪨"
-    0x3DA24B3A,  // "🫁"
-    0x464D8BC4,  // "🪔"
-    0x4A0760A4,  // "🪲"
-    0x4AC863CC,  // "🪒"
-    0x4B45D010,  // "🩺"
-    0x53FA6A26,  // "🪱"
-    0x57E83149,  // "🪟"
-    0x581551B0,  // "🪶"
-    0x6293815B,  // "🪃"
-    0x6555C91A,  // "🪐"
-    0x69E8CFB5,  // "🪳"
-    0x7A346478,  // "🪀"
-    0x7FEADE75,  // "🩴"
-    0x8427B8BD,  // "🪝"
-    0x85C72968,  // "🪠"
-    0x8BD06F70,  // "🪰"
-    0x8CA9276C,  // "🩱"
-    0x8EA0E1A6,  // "🪴"
-    0x96E68A5C,  // "🫂"
-    0xA1F6A0BF,  // "🪕"
-    0xA88BB5D0,  // "🪦"
-    0xABEAB4BE,  // "🪘"
-    0xAC516601,  // "🩹"
-    0xAF7B7F7F,  // "🪢"
-    0xB6E2D80A,  // "🪁"
-    0xBE512ACC,  // "🪙"
-    0xC4788884,  // "🪄"
-    0xCA5516D3,  // "🪣"
-    0xCA7AEC06,  // "🫕"
-    0xCAA2ECAF,  // "🫓"
-    0xCB181854,  // "🪗"
-    0xCBA7A499,  // "🪛"
-    0xCE1AF862,  // "🫑"
-    0xD29ABB28,  // "🫒"
-    0xDA7B5E06,  // "🪑"
-    0xDBAD5566,  // "🩲"
-    0xE0DC67EF,  // "🪥"
-    0xE60B928B,  // "🪂"
-    0xEC48623D,  // "🪓"
-    0xEE081BCD,  // "🫖"
-    0xF595BA4D,  // "🫀"
-    0xF91E2A8A,  // "🪜"
-    0xFE3C1358,  // "🩰"
-];
-
-/// Lookup table of blit pattern offsets; sort matches HASH_SYMBOLS_AND_PICTOGRAPHS_EXTENDED_A
-const OFFSET_SYMBOLS_AND_PICTOGRAPHS_EXTENDED_A: [usize; 57] = [
-    84459, // "🪵"
-    84185, // "🪤"
+/// Blit pattern offsets for codepoints 0x1FA70..=0x1FAD6, or NO_GLYPH
+static CODEPOINT_SYMBOLS_AND_PICTOGRAPHS_EXTENDED_A: [u32; 103] = [
+    83273, // "🩰"
+    83299, // "🩱"
+    83316, // "🩲"
     83340, // "🩳"
-    84020, // "🪞"
-    83932, // "🪚"
-    83609, // "🪅"
-    83817, // "🪖"
+    83370, // "🩴"
+    super::NO_GLYPH,  // U+1FA75
+    super::NO_GLYPH,  // U+1FA76
+    super::NO_GLYPH,  // U+1FA77
     83383, // "🩸"
-    84102, // "🪡"
-    83638, // "🪆"
-    84269, // "🪧"
-    84693, // "🫔"
-    84587, // "🫐"
-    84289, // "🪨"
-    84531, // "🫁"
-    83756, // "🪔"
-    84372, // "🪲"
-    83701, // "🪒"
+    83403, // "🩹"
     83432, // "🩺"
-    84345, // "🪱"
-    84040, // "🪟"
-    84480, // "🪶"
+    super::NO_GLYPH,  // U+1FA7B
+    super::NO_GLYPH,  // U+1FA7C
+    super::NO_GLYPH,  // U+1FA7D
+    super::NO_GLYPH,  // U+1FA7E
+    super::NO_GLYPH,  // U+1FA7F
+    83464, // "🪀"
+    83495, // "🪁"
+    83524, // "🪂"
     83557, // "🪃"
+    83583, // "🪄"
+    83609, // "🪅"
+    83638, // "🪆"
+    super::NO_GLYPH,  // U+1FA87
+    super::NO_GLYPH,  // U+1FA88
+    super::NO_GLYPH,  // U+1FA89
+    super::NO_GLYPH,  // U+1FA8A
+    super::NO_GLYPH,  // U+1FA8B
+    super::NO_GLYPH,  // U+1FA8C
+    super::NO_GLYPH,  // U+1FA8D
+    super::NO_GLYPH,  // U+1FA8E
+    super::NO_GLYPH,  // U+1FA8F
     83657, // "🪐"
-    84402, // "🪳"
-    83464, // "🪀"
-    83370, // "🩴"
+    83675, // "🪑"
+    83701, // "🪒"
+    83731, // "🪓"
+    83756, // "🪔"
+    83786, // "🪕"
+    83817, // "🪖"
+    83847, // "🪗"
+    83873, // "🪘"
+    83903, // "🪙"
+    83932, // "🪚"
+    83955, // "🪛"
+    83984, // "🪜"
     84003, // "🪝"
+    84020, // "🪞"
+    84040, // "🪟"
     84072, // "🪠"
+    84102, // "🪡"
+    84130, // "🪢"
+    84156, // "🪣"
+    84185, // "🪤"
+    84216, // "🪥"
+    84240, // "🪦"
+    84269, // "🪧"
+    84289, // "🪨"
+    super::NO_GLYPH,  // U+1FAA9
+    super::NO_GLYPH,  // U+1FAAA
+    super::NO_GLYPH,  // U+1FAAB
+    super::NO_GLYPH,  // U+1FAAC
+    super::NO_GLYPH,  // U+1FAAD
+    super::NO_GLYPH,  // U+1FAAE
+    super::NO_GLYPH,  // U+1FAAF
     84317, // "🪰"
-    83299, // "🩱"
+    84345, // "🪱"
+    84372, // "🪲"
+    84402, // "🪳"
     84432, // "🪴"
+    84459, // "🪵"
+    84480, // "🪶"
+    super::NO_GLYPH,  // U+1FAB7
+    super::NO_GLYPH,  // U+1FAB8
+    super::NO_GLYPH,  // U+1FAB9
+    super::NO_GLYPH,  // U+1FABA
+    super::NO_GLYPH,  // U+1FABB
+    super::NO_GLYPH,  // U+1FABC
+    super::NO_GLYPH,  // U+1FABD
+    super::NO_GLYPH,  // U+1FABE
+    super::NO_GLYPH,  // U+1FABF
+    84509, // "🫀"
+    84531, // "🫁"
     84560, // "🫂"
-    83786, // "🪕"
-    84240, // "🪦"
-    83873, // "🪘"
-    83403, // "🩹"
-    84130, // "🪢"
-    83495, // "🪁"
-    83903, // "🪙"
-    83583, // "🪄"
-    84156, // "🪣"
-    84719, // "🫕"
-    84665, // "🫓"
-    83847, // "🪗"
-    83955, // "🪛"
+    super::NO_GLYPH,  // U+1FAC3
+    super::NO_GLYPH,  // U+1FAC4
+    super::NO_GLYPH,  // U+1FAC5
+    super::NO_GLYPH,  // U+1FAC6
+    super::NO_GLYPH,  // U+1FAC7
+    super::NO_GLYPH,  // U+1FAC8
+    super::NO_GLYPH,  // U+1FAC9
+    super::NO_GLYPH,  // U+1FACA
+    super::NO_GLYPH,  // U+1FACB
+    super::NO_GLYPH,  // U+1FACC
+    super::NO_GLYPH,  // U+1FACD
+    super::NO_GLYPH,  // U+1FACE
+    super::NO_GLYPH,  // U+1FACF
+    84587, // "🫐"
     84614, // "🫑"
     84639, // "🫒"
-    83675, // "🪑"
-    83316, // "🩲"
-    84216, // "🪥"
-    83524, // "🪂"
-    83731, // "🪓"
+    84665, // "🫓"
+    84693, // "🫔"
+    84719, // "🫕"
     84750, // "🫖"
-    84509, // "🫀"
-    83984, // "🪜"
-    83273, // "🩰"
+];
+
+/// Index blocks in the order that get_blit_pattern_offset() checks them
+pub static BLOCKS: [super::Block; 25] = [
+    super::Block {
+        low: 0x0,
+        high: 0x7F,
+        cluster_lengths: &[3, 2],
+        codepoints_low: 0x0,
+        codepoints: &[],
+        hashes: &HASH_BASIC_LATIN,
+        keys: &KEY_BASIC_LATIN,
+        offsets: &OFFSET_BASIC_LATIN,
+    },
+    super::Block {
+        low: 0x80,
+        high: 0xFF,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0xA9,
+        codepoints: &CODEPOINT_LATIN_1_SUPPLEMENT,
+        hashes: &HASH_LATIN_1_SUPPLEMENT,
+        keys: &KEY_LATIN_1_SUPPLEMENT,
+        offsets: &OFFSET_LATIN_1_SUPPLEMENT,
+    },
+    super::Block {
+        low: 0x2000,
+        high: 0x206F,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x2000,
+        codepoints: &[],
+        hashes: &HASH_GENERAL_PUNCTUATION,
+        keys: &KEY_GENERAL_PUNCTUATION,
+        offsets: &OFFSET_GENERAL_PUNCTUATION,
+    },
+    super::Block {
+        low: 0x2100,
+        high: 0x214F,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x2100,
+        codepoints: &[],
+        hashes: &HASH_LETTERLIKE_SYMBOLS,
+        keys: &KEY_LETTERLIKE_SYMBOLS,
+        offsets: &OFFSET_LETTERLIKE_SYMBOLS,
+    },
+    super::Block {
+        low: 0x2190,
+        high: 0x21FF,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x2194,
+        codepoints: &CODEPOINT_ARROWS,
+        hashes: &HASH_ARROWS,
+        keys: &KEY_ARROWS,
+        offsets: &OFFSET_ARROWS,
+    },
+    super::Block {
+        low: 0x2300,
+        high: 0x23FF,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x2300,
+        codepoints: &[],
+        hashes: &HASH_MISCELLANEOUS_TECHNICAL,
+        keys: &KEY_MISCELLANEOUS_TECHNICAL,
+        offsets: &OFFSET_MISCELLANEOUS_TECHNICAL,
+    },
+    super::Block {
+        low: 0x2460,
+        high: 0x24FF,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x24C2,
+        codepoints: &CODEPOINT_ENCLOSED_ALPHANUMERICS,
+        hashes: &HASH_ENCLOSED_ALPHANUMERICS,
+        keys: &KEY_ENCLOSED_ALPHANUMERICS,
+        offsets: &OFFSET_ENCLOSED_ALPHANUMERICS,
+    },
+    super::Block {
+        low: 0x25A0,
+        high: 0x25FF,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x25A0,
+        codepoints: &[],
+        hashes: &HASH_GEOMETRIC_SHAPES,
+        keys: &KEY_GEOMETRIC_SHAPES,
+        offsets: &OFFSET_GEOMETRIC_SHAPES,
+    },
+    super::Block {
+        low: 0x2600,
+        high: 0x26FF,
+        cluster_lengths: &[5, 2, 1],
+        codepoints_low: 0x2600,
+        codepoints: &[],
+        hashes: &HASH_MISCELLANEOUS_SYMBOLS,
+        keys: &KEY_MISCELLANEOUS_SYMBOLS,
+        offsets: &OFFSET_MISCELLANEOUS_SYMBOLS,
+    },
+    super::Block {
+        low: 0x2700,
+        high: 0x27BF,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x2700,
+        codepoints: &[],
+        hashes: &HASH_DINGBATS,
+        keys: &KEY_DINGBATS,
+        offsets: &OFFSET_DINGBATS,
+    },
+    super::Block {
+        low: 0x2900,
+        high: 0x297F,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x2934,
+        codepoints: &CODEPOINT_SUPPLEMENTAL_ARROWS_B,
+        hashes: &HASH_SUPPLEMENTAL_ARROWS_B,
+        keys: &KEY_SUPPLEMENTAL_ARROWS_B,
+        offsets: &OFFSET_SUPPLEMENTAL_ARROWS_B,
+    },
+    super::Block {
+        low: 0x2B00,
+        high: 0x2BFF,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x2B00,
+        codepoints: &[],
+        hashes: &HASH_MISCELLANEOUS_SYMBOLS_AND_ARROWS,
+        keys: &KEY_MISCELLANEOUS_SYMBOLS_AND_ARROWS,
+        offsets: &OFFSET_MISCELLANEOUS_SYMBOLS_AND_ARROWS,
+    },
+    super::Block {
+        low: 0x3000,
+        high: 0x303F,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x3000,
+        codepoints: &[],
+        hashes: &HASH_CJK_SYMBOLS_AND_PUNCTUATION,
+        keys: &KEY_CJK_SYMBOLS_AND_PUNCTUATION,
+        offsets: &OFFSET_CJK_SYMBOLS_AND_PUNCTUATION,
+    },
+    super::Block {
+        low: 0x3200,
+        high: 0x32FF,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x3297,
+        codepoints: &CODEPOINT_ENCLOSED_CJK_LETTERS_AND_MONTHS,
+        hashes: &HASH_ENCLOSED_CJK_LETTERS_AND_MONTHS,
+        keys: &KEY_ENCLOSED_CJK_LETTERS_AND_MONTHS,
+        offsets: &OFFSET_ENCLOSED_CJK_LETTERS_AND_MONTHS,
+    },
+    super::Block {
+        low: 0xE000,
+        high: 0xF8FF,
+        cluster_lengths: &[1],
+        codepoints_low: 0xE50A,
+        codepoints: &CODEPOINT_PRIVATE_USE_AREA,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
+    super::Block {
+        low: 0x1F000,
+        high: 0x1F02F,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x1F004,
+        codepoints: &CODEPOINT_MAHJONG_TILES,
+        hashes: &HASH_MAHJONG_TILES,
+        keys: &KEY_MAHJONG_TILES,
+        offsets: &OFFSET_MAHJONG_TILES,
+    },
+    super::Block {
+        low: 0x1F0A0,
+        high: 0x1F0FF,
+        cluster_lengths: &[1],
+        codepoints_low: 0x1F0CF,
+        codepoints: &CODEPOINT_PLAYING_CARDS,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
+    super::Block {
+        low: 0x1F100,
+        high: 0x1F1FF,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x1F100,
+        codepoints: &[],
+        hashes: &HASH_ENCLOSED_ALPHANUMERIC_SUPPLEMENT,
+        keys: &KEY_ENCLOSED_ALPHANUMERIC_SUPPLEMENT,
+        offsets: &OFFSET_ENCLOSED_ALPHANUMERIC_SUPPLEMENT,
+    },
+    super::Block {
+        low: 0x1F200,
+        high: 0x1F2FF,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x1F200,
+        codepoints: &[],
+        hashes: &HASH_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT,
+        keys: &KEY_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT,
+        offsets: &OFFSET_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT,
+    },
+    super::Block {
+        low: 0x1F300,
+        high: 0x1F5FF,
+        cluster_lengths: &[8, 7, 6, 5, 4, 3, 2, 1],
+        codepoints_low: 0x1F300,
+        codepoints: &CODEPOINT_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS,
+        hashes: &HASH_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS,
+        keys: &KEY_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS,
+        offsets: &OFFSET_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS,
+    },
+    super::Block {
+        low: 0x1F600,
+        high: 0x1F64F,
+        cluster_lengths: &[5, 4, 2, 1],
+        codepoints_low: 0x1F600,
+        codepoints: &CODEPOINT_EMOTICONS,
+        hashes: &HASH_EMOTICONS,
+        keys: &KEY_EMOTICONS,
+        offsets: &OFFSET_EMOTICONS,
+    },
+    super::Block {
+        low: 0x1F680,
+        high: 0x1F6FF,
+        cluster_lengths: &[5, 4, 2, 1],
+        codepoints_low: 0x1F680,
+        codepoints: &CODEPOINT_TRANSPORT_AND_MAP_SYMBOLS,
+        hashes: &HASH_TRANSPORT_AND_MAP_SYMBOLS,
+        keys: &KEY_TRANSPORT_AND_MAP_SYMBOLS,
+        offsets: &OFFSET_TRANSPORT_AND_MAP_SYMBOLS,
+    },
+    super::Block {
+        low: 0x1F780,
+        high: 0x1F7FF,
+        cluster_lengths: &[1],
+        codepoints_low: 0x1F7E0,
+        codepoints: &CODEPOINT_GEOMETRIC_SHAPES_EXTENDED,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
+    super::Block {
+        low: 0x1F900,
+        high: 0x1F9FF,
+        cluster_lengths: &[7, 5, 4, 3, 2, 1],
+        codepoints_low: 0x1F90C,
+        codepoints: &CODEPOINT_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS,
+        hashes: &HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS,
+        keys: &KEY_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS,
+        offsets: &OFFSET_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS,
+    },
+    super::Block {
+        low: 0x1FA70,
+        high: 0x1FAFF,
+        cluster_lengths: &[1],
+        codepoints_low: 0x1FA70,
+        codepoints: &CODEPOINT_SYMBOLS_AND_PICTOGRAPHS_EXTENDED_A,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
 ];
 
 /// Packed glyph pattern data.
@@ -8188,7 +10404,7 @@ const OFFSET_SYMBOLS_AND_PICTOGRAPHS_EXTENDED_A: [usize; 57] = [
 ///  h: Height of pattern in pixels
 ///  yOffset: Vertical offset (pixels downward from top of line) to position
 ///     glyph pattern properly relative to text baseline
-pub const DATA: [u32; 91228] = [
+pub static DATA: [u32; 91228] = [
     // [0]: 1f004 "🀄"
     0x00171f01, 0x92492492, 0x49200000, 0x0492c924, 0x92492006, 0x00249649, 0x24924909, 0x30016db6,
     0x49e4b6ca, 0xc1870b25, 0x964b2492, 0x420c304b, 0x6cb24b6d, 0x92006902, 0x49649249, 0x24900300,
@@ -24085,15 +26301,9 @@ mod tests {
         for i in 0..HASH_ENCLOSED_CJK_LETTERS_AND_MONTHS.len()-1 {
             assert!(HASH_ENCLOSED_CJK_LETTERS_AND_MONTHS[i] < HASH_ENCLOSED_CJK_LETTERS_AND_MONTHS[i+1]);
         }
-        for i in 0..HASH_PRIVATE_USE_AREA.len()-1 {
-            assert!(HASH_PRIVATE_USE_AREA[i] < HASH_PRIVATE_USE_AREA[i+1]);
-        }
         for i in 0..HASH_MAHJONG_TILES.len()-1 {
             assert!(HASH_MAHJONG_TILES[i] < HASH_MAHJONG_TILES[i+1]);
         }
-        for i in 0..HASH_PLAYING_CARDS.len()-1 {
-            assert!(HASH_PLAYING_CARDS[i] < HASH_PLAYING_CARDS[i+1]);
-        }
         for i in 0..HASH_ENCLOSED_ALPHANUMERIC_SUPPLEMENT.len()-1 {
             assert!(HASH_ENCLOSED_ALPHANUMERIC_SUPPLEMENT[i] < HASH_ENCLOSED_ALPHANUMERIC_SUPPLEMENT[i+1]);
         }
@@ -24109,14 +26319,8 @@ mod tests {
         for i in 0..HASH_TRANSPORT_AND_MAP_SYMBOLS.len()-1 {
             assert!(HASH_TRANSPORT_AND_MAP_SYMBOLS[i] < HASH_TRANSPORT_AND_MAP_SYMBOLS[i+1]);
         }
-        for i in 0..HASH_GEOMETRIC_SHAPES_EXTENDED.len()-1 {
-            assert!(HASH_GEOMETRIC_SHAPES_EXTENDED[i] < HASH_GEOMETRIC_SHAPES_EXTENDED[i+1]);
-        }
         for i in 0..HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS.len()-1 {
             assert!(HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS[i] < HASH_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS[i+1]);
         }
-        for i in 0..HASH_SYMBOLS_AND_PICTOGRAPHS_EXTENDED_A.len()-1 {
-            assert!(HASH_SYMBOLS_AND_PICTOGRAPHS_EXTENDED_A[i] < HASH_SYMBOLS_AND_PICTOGRAPHS_EXTENDED_A[i+1]);
-        }
     }
 }
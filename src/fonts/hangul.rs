@@ -0,0 +1,250 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Hangul Syllables
+//!
+//! Composes hangul syllables (U+AC00..U+D7A3) at blit time from the positional
+//! jamo glyphs in jamo::DATA, like classic 8x4x4 johab bitmap fonts do. Each
+//! syllable decomposes into an initial consonant, a vowel, and an optional
+//! final consonant:
+//! - The vowel's shape and whether there is a final consonant pick 1 of 8
+//!   initial consonant variants
+//! - Whether the initial is ㄱ or ㅋ and whether there is a final consonant
+//!   pick 1 of 4 vowel variants
+//! - The vowel picks 1 of 4 final consonant variants
+//!
+//! Jamo variants are 32px wide, so overlaying them lines up their strokes. In
+//! jamo.rs, variant n of a jamo is indexed as the jamo followed by U+FE00+n.
+#![forbid(unsafe_code)]
+
+use super::{jamo, GlyphData, NoGlyphErr};
+
+/// Maximum height of composed syllables
+pub const MAX_HEIGHT: u8 = jamo::MAX_HEIGHT;
+
+/// Syllables U+AC00..U+D7A3 are numbered (initial * 21 + vowel) * 28 + final
+const SYLLABLE_BASE: u32 = 0xAC00;
+const SYLLABLE_COUNT: u32 = 11172;
+const VOWEL_COUNT: u32 = 21;
+const FINAL_COUNT: u32 = 28;
+
+/// Conjoining jamo (U+1100..U+11FF) for initials, vowels, and finals
+const INITIAL_BASE: u32 = 0x1100;
+const VOWEL_BASE: u32 = 0x1161;
+const FINAL_BASE: u32 = 0x11A7;
+const INITIAL_COUNT: u32 = 19;
+
+/// Tag for jamo variants in the jamo.rs index: jamo + U+FE00 + variant number
+const VARIANT_TAG: u32 = 0xFE00;
+
+/// Initial consonants ㄱ and ㅋ have a leg down their right side
+const INITIAL_G: u32 = 0;
+const INITIAL_K: u32 = 15;
+
+/// Offsets into jamo::DATA for the jamo variants that make up a syllable
+#[derive(Copy, Clone, Debug)]
+pub struct Syllable {
+    initial: usize,
+    vowel: usize,
+    final_consonant: Option<usize>,
+}
+
+impl Syllable {
+    /// Packed header word for the composed glyph, in the same format as the
+    /// DATA arrays of the generated fonts
+    pub fn header(self) -> u32 {
+        (32 << 16) | ((MAX_HEIGHT as u32) << 8)
+    }
+
+    /// Composed pixel word n of the glyph. Syllables are 32px wide, so word n
+    /// holds row n-1, which is the OR of that row of each jamo.
+    pub fn nth_word(self, n: usize) -> u32 {
+        let row = n - 1;
+        let mut word = jamo_row(self.initial, row) | jamo_row(self.vowel, row);
+        if let Some(offset) = self.final_consonant {
+            word |= jamo_row(offset, row);
+        }
+        word
+    }
+}
+
+/// Return row y of the 32px wide jamo pattern at offset, or 0 if y is outside
+/// of its trimmed rows
+fn jamo_row(offset: usize, y: usize) -> u32 {
+    let header = jamo::DATA[offset];
+    let h = ((header >> 8) & 0xff) as usize;
+    let y_offset = (header & 0xff) as usize;
+    if y < y_offset || y >= y_offset + h {
+        return 0;
+    }
+    jamo::DATA[offset + 1 + y - y_offset]
+}
+
+/// Return Okay(composed syllable) for a precomposed hangul syllable at the
+/// start of cluster, or for a sequence of conjoining jamo (initial, vowel, and
+/// optional final).
+///
+/// Returns: Result<(composed syllable, bytes of cluster used by match)>
+pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGlyphErr> {
+    let (initial, vowel, final_consonant, bytes_used) = match decompose(cluster) {
+        Some(jamo) => jamo,
+        None => return Err(NoGlyphErr),
+    };
+    let (initial_set, vowel_set, final_set) = variant_sets(initial, vowel, final_consonant);
+    let syllable = Syllable {
+        initial: variant(INITIAL_BASE + initial, initial_set)?,
+        vowel: variant(VOWEL_BASE + vowel, vowel_set)?,
+        final_consonant: match final_consonant {
+            0 => None,
+            n => Some(variant(FINAL_BASE + n, final_set)?),
+        },
+    };
+    Ok((GlyphData::Hangul(syllable), bytes_used))
+}
+
+/// Split the syllable at the start of cluster into jamo numbers.
+/// Returns: (initial 0..19, vowel 0..21, final 0..28 (0 is none), bytes used)
+fn decompose(cluster: &str) -> Option<(u32, u32, u32, usize)> {
+    let mut chars = cluster.chars().map(|c| c as u32);
+    let first = chars.next()?;
+    if (SYLLABLE_BASE..SYLLABLE_BASE + SYLLABLE_COUNT).contains(&first) {
+        let s = first - SYLLABLE_BASE;
+        let initial = s / (VOWEL_COUNT * FINAL_COUNT);
+        let vowel = (s / FINAL_COUNT) % VOWEL_COUNT;
+        // Precomposed syllables are 3 bytes in UTF-8
+        return Some((initial, vowel, s % FINAL_COUNT, 3));
+    }
+    // Conjoining jamo are 3 bytes each in UTF-8
+    let initial = first.wrapping_sub(INITIAL_BASE);
+    let vowel = chars.next()?.wrapping_sub(VOWEL_BASE);
+    if initial >= INITIAL_COUNT || vowel >= VOWEL_COUNT {
+        return None;
+    }
+    match chars.next().map(|c| c.wrapping_sub(FINAL_BASE)) {
+        Some(n) if n > 0 && n < FINAL_COUNT => Some((initial, vowel, n, 9)),
+        _ => Some((initial, vowel, 0, 6)),
+    }
+}
+
+/// Vowel shapes, which decide how a syllable is laid out
+#[derive(Copy, Clone, PartialEq)]
+enum Shape {
+    /// Vertical vowel to the right of the initial: ㅏㅐㅑㅒㅓㅔㅕㅖㅣ
+    Right,
+    /// Horizontal vowel below the initial: ㅗㅛㅡ
+    Below,
+    /// Horizontal vowel below the initial with a stem going down: ㅜㅠ
+    BelowStem,
+    /// Compound of ㅗ or ㅡ with a vertical vowel: ㅘㅙㅚㅢ
+    Wrap,
+    /// Compound of ㅜ with a vertical vowel: ㅝㅞㅟ
+    WrapStem,
+}
+
+/// Return the shape of vowel number v
+fn shape(v: u32) -> Shape {
+    match v {
+        8 | 12 | 18 => Shape::Below,
+        13 | 17 => Shape::BelowStem,
+        9 | 10 | 11 | 19 => Shape::Wrap,
+        14..=16 => Shape::WrapStem,
+        _ => Shape::Right,
+    }
+}
+
+/// Pick the 8x4x4 variant sets for the jamo of a syllable.
+/// Returns: (initial set 0..8, vowel set 0..4, final set 0..4)
+fn variant_sets(initial: u32, vowel: u32, final_consonant: u32) -> (u32, u32, u32) {
+    let has_final = final_consonant != 0;
+    let initial_set = match (shape(vowel), has_final) {
+        (Shape::Right, false) => 0,
+        (Shape::Below, false) => 1,
+        (Shape::BelowStem, false) => 2,
+        (Shape::Wrap, false) => 3,
+        (Shape::WrapStem, false) => 4,
+        (Shape::Right, true) => 5,
+        (Shape::Below, true) | (Shape::BelowStem, true) => 6,
+        (Shape::Wrap, true) | (Shape::WrapStem, true) => 7,
+    };
+    let leg = initial == INITIAL_G || initial == INITIAL_K;
+    let vowel_set = match (has_final, leg) {
+        (false, true) => 0,
+        (false, false) => 1,
+        (true, true) => 2,
+        (true, false) => 3,
+    };
+    let final_set = match vowel {
+        // ㅏ ㅑ ㅘ
+        0 | 2 | 9 => 0,
+        // ㅐ ㅒ ㅔ ㅖ ㅙ ㅞ
+        1 | 3 | 5 | 7 | 10 | 15 => 2,
+        // ㅗ ㅛ ㅜ ㅠ ㅡ
+        8 | 12 | 13 | 17 | 18 => 3,
+        // ㅓ ㅕ ㅚ ㅝ ㅟ ㅢ ㅣ
+        _ => 1,
+    };
+    (initial_set, vowel_set, final_set)
+}
+
+/// Look up the offset into jamo::DATA for variant set of a conjoining jamo
+fn variant(jamo: u32, set: u32) -> Result<usize, NoGlyphErr> {
+    let jamo = core::char::from_u32(jamo).ok_or(NoGlyphErr)?;
+    let tag = core::char::from_u32(VARIANT_TAG + set).ok_or(NoGlyphErr)?;
+    let mut buf = [0; 8];
+    let len = jamo.encode_utf8(&mut buf).len();
+    let len = len + tag.encode_utf8(&mut buf[len..]).len();
+    let key = core::str::from_utf8(&buf[..len]).map_err(|_| NoGlyphErr)?;
+    match jamo::get_blit_pattern_offset(key)? {
+        (GlyphData::Jamo(offset), _) => Ok(offset),
+        _ => Err(NoGlyphErr),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decompose() {
+        assert_eq!(decompose("가"), Some((0, 0, 0, 3)));
+        assert_eq!(decompose("한국"), Some((18, 0, 4, 3)));
+        assert_eq!(decompose("힣"), Some((18, 20, 27, 3)));
+        assert_eq!(decompose("\u{1112}\u{1161}\u{11AB}"), Some((18, 0, 4, 9)));
+        assert_eq!(decompose("\u{1112}\u{1161}a"), Some((18, 0, 0, 6)));
+        assert_eq!(decompose("\u{1112}"), None);
+        assert_eq!(decompose("\u{1161}"), None);
+        assert_eq!(decompose("a"), None);
+        assert_eq!(decompose(""), None);
+    }
+
+    #[test]
+    fn test_jamo_variants_are_32px_wide() {
+        let jamo = [
+            (INITIAL_BASE, INITIAL_COUNT, 8),
+            (VOWEL_BASE, VOWEL_COUNT, 4),
+            (FINAL_BASE + 1, FINAL_COUNT - 1, 4),
+        ];
+        for (base, count, sets) in jamo.iter() {
+            for n in 0..*count {
+                for set in 0..*sets {
+                    let offset = variant(base + n, set).unwrap();
+                    let header = GlyphData::Jamo(offset).header();
+                    assert_eq!(header.w, 32);
+                    assert!(header.h + header.y_offset <= MAX_HEIGHT as usize);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_syllable_composes() {
+        for cp in SYLLABLE_BASE..SYLLABLE_BASE + SYLLABLE_COUNT {
+            let c = core::char::from_u32(cp).unwrap();
+            let mut buf = [0; 4];
+            let cluster = c.encode_utf8(&mut buf);
+            let (glyph_data, bytes_used) = get_blit_pattern_offset(cluster).unwrap();
+            assert_eq!(bytes_used, 3);
+            assert_eq!(glyph_data.header().w, 32);
+        }
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Hex Digits
+//!
+//! Tiny 3x5 pixel digits for painting the codepoints of missing chars inside a
+//! box, like the last resort fonts of desktop systems.
+#![forbid(unsafe_code)]
+
+/// Width of a digit in pixels
+pub const WIDTH: usize = 3;
+
+/// Height of a digit in pixels
+pub const HEIGHT: usize = 5;
+
+/// Return true if the pixel at (x, y) of hex digit d is set
+pub fn pixel(d: u32, x: usize, y: usize) -> bool {
+    let pattern = DIGITS[(d & 0xf) as usize];
+    let bit = (HEIGHT - 1 - y) * WIDTH + (WIDTH - 1 - x);
+    (pattern >> bit) & 1 == 1
+}
+
+/// Digit patterns for 0..F, one 3-bit row after another from top to bottom,
+/// with the leftmost pixel of each row in its most significant bit
+#[rustfmt::skip]
+static DIGITS: [u16; 16] = [
+    0b111_101_101_101_111, // 0
+    0b010_110_010_010_111, // 1
+    0b111_001_111_100_111, // 2
+    0b111_001_111_001_111, // 3
+    0b101_101_111_001_001, // 4
+    0b111_100_111_001_111, // 5
+    0b111_100_111_101_111, // 6
+    0b111_001_001_001_001, // 7
+    0b111_101_111_101_111, // 8
+    0b111_101_111_001_111, // 9
+    0b111_101_111_101_101, // A
+    0b110_101_110_101_110, // B
+    0b111_100_100_100_111, // C
+    0b110_101_101_101_110, // D
+    0b111_100_111_100_111, // E
+    0b111_100_111_100_100, // F
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_digit_pixels() {
+        // 1: .#. ##. .#. .#. ###
+        assert!(!pixel(1, 0, 0) && pixel(1, 1, 0) && !pixel(1, 2, 0));
+        assert!(pixel(1, 0, 1) && pixel(1, 1, 1) && !pixel(1, 2, 1));
+        assert!(pixel(1, 0, 4) && pixel(1, 1, 4) && pixel(1, 2, 4));
+        // Every digit has a distinct pattern
+        for (i, a) in DIGITS.iter().enumerate() {
+            assert!(DIGITS[i + 1..].iter().all(|b| b != a));
+        }
+    }
+}
@@ -0,0 +1,135 @@
+// DO NOT MAKE EDITS HERE because this file is automatically generated.
+// To make changes, see blitstr/codegen/main.go
+//
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// NOTE: The copyright notice above applies to the rust source code in this
+// file, but not to the bitmap graphics encoded in the DATA array (see credits).
+//
+// CREDITS:
+// This code includes encoded bitmaps of user interface icons for Precursor
+// (battery level, radio signal strength, and keyboard symbols) that were drawn
+// for blitstr in column 0 of the regular.png sprite sheet. The icon bitmaps are
+// covered by the same license as the rust source code in this file.
+//
+//! Icons Font
+#![forbid(unsafe_code)]
+#![allow(dead_code)]
+
+use super::{GlyphData, NoGlyphErr};
+
+/// Maximum height of glyph patterns in this bitmap typeface.
+/// This will be true: h + y_offset <= MAX_HEIGHT
+pub const MAX_HEIGHT: u8 = 30;
+
+/// Seed for Murmur3 hashes in the HASH_* index arrays
+pub const M3_SEED: u32 = 0;
+
+/// Return Okay(offset into DATA[]) for start of blit pattern for grapheme cluster.
+///
+/// Before doing an expensive lookup for the whole cluster, this does a pre-filter
+/// check to see whether the first character falls into one of the codepoint ranges
+/// for Unicode blocks included in this font.
+///
+/// Returns: Result<(blit pattern offset into DATA, bytes of cluster used by match)>
+pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGlyphErr> {
+    let first_char: u32 = match cluster.chars().next() {
+        Some(c) => c as u32,
+        None => return Err(NoGlyphErr),
+    };
+    match first_char {
+        0xE000..=0xF8FF => {
+            if let Some((offset, bytes_used)) = find_private_use_area_codepoint(cluster) {
+                Ok((GlyphData::Icons(offset), bytes_used))
+            } else {
+                Err(NoGlyphErr)
+            }
+        }
+        _ => Err(super::NoGlyphErr),
+    }
+}
+
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_private_use_area_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_PRIVATE_USE_AREA, 0xE700, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0xE700..=0xE70C, or NO_GLYPH
+static CODEPOINT_PRIVATE_USE_AREA: [u32; 13] = [
+    0,     // "\ue700"
+    10,    // "\ue701"
+    20,    // "\ue702"
+    30,    // "\ue703"
+    40,    // "\ue704"
+    50,    // "\ue705"
+    63,    // "\ue706"
+    70,    // "\ue707"
+    74,    // "\ue708"
+    76,    // "\ue709"
+    89,    // "\ue70a"
+    97,    // "\ue70b"
+    113,   // "\ue70c"
+];
+
+/// Index blocks in the order that get_blit_pattern_offset() checks them
+pub static BLOCKS: [super::Block; 1] = [
+    super::Block {
+        low: 0xE000,
+        high: 0xF8FF,
+        cluster_lengths: &[1],
+        codepoints_low: 0xE700,
+        codepoints: &CODEPOINT_PRIVATE_USE_AREA,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
+];
+
+/// Packed glyph pattern data.
+/// Record format:
+///  [offset+0]: ((w as u8) << 16) | ((h as u8) << 8) | (yOffset as u8)
+///  [offset+1..=ceil(w*h/32)]: packed 1-bit pixels; 0=clear, 1=set
+/// Pixels are packed in top to bottom, left to right order with MSB of first
+/// pixel word containing the top left pixel.
+///  w: Width of pattern in pixels
+///  h: Height of pattern in pixels
+///  yOffset: Vertical offset (pixels downward from top of line) to position
+///     glyph pattern properly relative to text baseline
+pub static DATA: [u32; 125] = [
+    // [0]: E700 "\ue700"
+    0x00180c0c, 0x3ffffe40, 0x00014000, 0x0dc0000d, 0xc0000dc0, 0x000dc000, 0x0dc0000d, 0xc0000d40,
+    0x000d4000, 0x013ffffe,
+    // [10]: E701 "\ue701"
+    0x00180c0c, 0x3ffffe40, 0x00014000, 0x7dc0007d, 0xc0007dc0, 0x007dc000, 0x7dc0007d, 0xc0007d40,
+    0x007d4000, 0x013ffffe,
+    // [20]: E702 "\ue702"
+    0x00180c0c, 0x3ffffe40, 0x0001400f, 0xfdc00ffd, 0xc00ffdc0, 0x0ffdc00f, 0xfdc00ffd, 0xc00ffd40,
+    0x0ffd4000, 0x013ffffe,
+    // [30]: E703 "\ue703"
+    0x00180c0c, 0x3ffffe40, 0x000141ff, 0xfdc1fffd, 0xc1fffdc1, 0xfffdc1ff, 0xfdc1fffd, 0xc1fffd41,
+    0xfffd4000, 0x013ffffe,
+    // [40]: E704 "\ue704"
+    0x00180c0c, 0x3ffffe40, 0x00015fff, 0xfddffffd, 0xdffffddf, 0xfffddfff, 0xfddffffd, 0xdffffd5f,
+    0xfffd4000, 0x013ffffe,
+    // [50]: E705 "\ue705"
+    0x00151107, 0x00f8001f, 0xf003e3e0, 0x3c078380, 0x0e387c3b, 0x8ff8e8f1, 0xe20e0380, 0xe10e023e,
+    0x2003f800, 0x38e00082, 0x00008000, 0x0e000020, 0x00000000,
+    // [63]: E706 "\ue706"
+    0x000f0c0c, 0x07c03fe0, 0xf1e380ee, 0x10e8f883, 0xf80e3808, 0x2002000e, 0x00080000,
+    // [70]: E707 "\ue707"
+    0x00090810, 0x081f1fdc, 0x74104070, 0x10000000,
+    // [74]: E708 "\ue708"
+    0x00030315, 0x5d000000,
+    // [76]: E709 "\ue709"
+    0x00151107, 0x00f80018, 0x30030060, 0x20008200, 0x0220000a, 0x00002800, 0x02200020, 0x80020200,
+    0x20080200, 0x20200082, 0x00022000, 0x0a000020, 0x00000000,
+    // [89]: E70A "\ue70a"
+    0x000a1406, 0x0c0783f1, 0xfefffff0, 0xc0300c03, 0x00c0300c, 0x0300c030, 0x0c0300c0, 0x30000000,
+    // [97]: E70B "\ue70b"
+    0x001a1206, 0xffffc03f, 0xfff80c00, 0x07030000, 0xe0c6061c, 0x31c3838c, 0x39c07307, 0xe00ec0f0,
+    0x01f03c00, 0x7c1f803b, 0x0e701cc7, 0x0e0e3181, 0x870c0003, 0x830001c0, 0xffffe03f, 0xfff00000,
+    // [113]: E70C "\ue70c"
+    0x00180e08, 0xc00000c0, 0x0000c000, 0x00c00000, 0xc00030c0, 0x0038c000, 0x3cc0003e, 0xffffffff,
+    0xffff0000, 0x3e00003c, 0x00003800, 0x00300000,
+];
@@ -0,0 +1,2286 @@
+// DO NOT MAKE EDITS HERE because this file is automatically generated.
+// To make changes, see blitstr/codegen/main.go
+//
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// NOTE: The copyright notice above applies to the rust source code in this
+// file, but not to the bitmap graphics encoded in the DATA array (see credits).
+//
+// CREDITS:
+// This code includes encoded bitmaps of positional hangul jamo glyphs that
+// were drawn for blitstr. They follow the layout of classic 8x4x4 johab
+// bitmap fonts, with 8 variants of each initial consonant, 4 of each vowel,
+// and 4 of each final consonant, sized to compose into 32px syllables. The
+// jamo bitmaps are covered by the same license as the rust source code in
+// this file.
+//
+//! Jamo Font
+#![forbid(unsafe_code)]
+#![allow(dead_code)]
+
+use super::{GlyphData, NoGlyphErr};
+
+/// Maximum height of glyph patterns in this bitmap typeface.
+/// This will be true: h + y_offset <= MAX_HEIGHT
+pub const MAX_HEIGHT: u8 = 32;
+
+/// Seed for Murmur3 hashes in the HASH_* index arrays
+pub const M3_SEED: u32 = 0;
+
+/// Return Okay(offset into DATA[]) for start of blit pattern for grapheme cluster.
+///
+/// Before doing an expensive lookup for the whole cluster, this does a pre-filter
+/// check to see whether the first character falls into one of the codepoint ranges
+/// for Unicode blocks included in this font.
+///
+/// Returns: Result<(blit pattern offset into DATA, bytes of cluster used by match)>
+pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGlyphErr> {
+    let first_char: u32 = match cluster.chars().next() {
+        Some(c) => c as u32,
+        None => return Err(NoGlyphErr),
+    };
+    match first_char {
+        0x1100..=0x11FF => {
+            if let Some((offset, bytes_used)) = find_hangul_jamo(cluster, 2) {
+                Ok((GlyphData::Jamo(offset), bytes_used))
+            } else {
+                Err(NoGlyphErr)
+            }
+        }
+        _ => Err(super::NoGlyphErr),
+    }
+}
+
+/// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
+/// Only attempt to match grapheme clusters of length limit codepoints.
+fn find_hangul_jamo(cluster: &str, limit: u32) -> Option<(usize, usize)> {
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_HANGUL_JAMO.binary_search(&hash) {
+        Ok(index) if KEY_HANGUL_JAMO[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_HANGUL_JAMO[index], bytes_hashed))
+        }
+        _ => None,
+    }
+}
+
+/// Index of murmur3(grapheme cluster); sort matches OFFSET_HANGUL_JAMO
+static HASH_HANGUL_JAMO: [u32; 344] = [
+    0x004546FA,  // "ᇀ︃" 11C0-FE03
+    0x00A9D6D5,  // "ᄍ︆" 110D-FE06
+    0x00BA34A6,  // "ᄑ︆" 1111-FE06
+    0x00C808C3,  // "ᄃ︇" 1103-FE07
+    0x028C2121,  // "ᅵ︁" 1175-FE01
+    0x02CE2A1A,  // "ᄅ︅" 1105-FE05
+    0x033AF2AA,  // "ᅳ︂" 1173-FE02
+    0x03A53A65,  // "ᄃ︁" 1103-FE01
+    0x03C6B1BD,  // "ᄏ︄" 110F-FE04
+    0x04644792,  // "ᄊ︃" 110A-FE03
+    0x0468C9A1,  // "ᄂ︆" 1102-FE06
+    0x06069BEE,  // "ᅣ︃" 1163-FE03
+    0x082A11AE,  // "ᆷ︂" 11B7-FE02
+    0x08C0AB42,  // "ᅧ︀" 1167-FE00
+    0x09178524,  // "ᄒ︄" 1112-FE04
+    0x09ADD9B5,  // "ᅴ︃" 1174-FE03
+    0x0A497F20,  // "ᆿ︃" 11BF-FE03
+    0x0BE8E7C7,  // "ᄐ︂" 1110-FE02
+    0x0BF6A514,  // "ᄋ︀" 110B-FE00
+    0x0C3C1395,  // "ᆵ︃" 11B5-FE03
+    0x0C8AB335,  // "ᅳ︁" 1173-FE01
+    0x0C9C9ECF,  // "ᄅ︂" 1105-FE02
+    0x0D88A627,  // "ᄄ︁" 1104-FE01
+    0x0DA79C2E,  // "ᅭ︀" 116D-FE00
+    0x0E97FD7B,  // "ᄁ︂" 1101-FE02
+    0x0EDF5B10,  // "ᄉ︂" 1109-FE02
+    0x0EFE07A7,  // "ᆱ︃" 11B1-FE03
+    0x0F26E78C,  // "ᄉ︇" 1109-FE07
+    0x0FAE19F4,  // "ᄄ︄" 1104-FE04
+    0x11A04555,  // "ᆽ︃" 11BD-FE03
+    0x1220864C,  // "ᄑ︁" 1111-FE01
+    0x127655BB,  // "ᇂ︂" 11C2-FE02
+    0x12ABFB3F,  // "ᄍ︇" 110D-FE07
+    0x12C9AA5F,  // "ᄎ︀" 110E-FE00
+    0x1302BEF4,  // "ᅳ︀" 1173-FE00
+    0x13565B63,  // "ᄋ︃" 110B-FE03
+    0x13C728E9,  // "ᄁ︄" 1101-FE04
+    0x144A5C9C,  // "ᄑ︀" 1111-FE00
+    0x14EC48CC,  // "ᅤ︃" 1164-FE03
+    0x14EDEB23,  // "ᄃ︂" 1103-FE02
+    0x15173470,  // "ᆭ︁" 11AD-FE01
+    0x1677884C,  // "ᄀ︃" 1100-FE03
+    0x16D71152,  // "ᅬ︀" 116C-FE00
+    0x17AA108B,  // "ᄌ︁" 110C-FE01
+    0x17FDF6FC,  // "ᅯ︁" 116F-FE01
+    0x1AE2A47E,  // "ᅢ︁" 1162-FE01
+    0x1C1480D6,  // "ᄊ︀" 110A-FE00
+    0x1CFAC3BA,  // "ᆾ︂" 11BE-FE02
+    0x1DF05544,  // "ᆹ︃" 11B9-FE03
+    0x1FE001A1,  // "ᆰ︀" 11B0-FE00
+    0x200018D7,  // "ᆿ︁" 11BF-FE01
+    0x20C94999,  // "ᄍ︂" 110D-FE02
+    0x20FF5CD9,  // "ᄅ︀" 1105-FE00
+    0x220044E2,  // "ᄁ︀" 1101-FE00
+    0x23058537,  // "ᅯ︂" 116F-FE02
+    0x23BAD6EE,  // "ᅳ︃" 1173-FE03
+    0x25C5AFA7,  // "ᆰ︂" 11B0-FE02
+    0x265B62D5,  // "ᄄ︆" 1104-FE06
+    0x2761167E,  // "ᅡ︃" 1161-FE03
+    0x2776F74E,  // "ᄎ︅" 110E-FE05
+    0x27922D91,  // "ᄌ︅" 110C-FE05
+    0x287AC62C,  // "ᄇ︀" 1107-FE00
+    0x29060119,  // "ᄆ︂" 1106-FE02
+    0x29AFED5C,  // "ᄈ︃" 1108-FE03
+    0x2ABC4CCB,  // "ᅬ︁" 116C-FE01
+    0x2ADB74DB,  // "ᄉ︄" 1109-FE04
+    0x2B12F8C4,  // "ᆲ︃" 11B2-FE03
+    0x2B5A3DFF,  // "ᅢ︂" 1162-FE02
+    0x2BAFEF2F,  // "ᅴ︁" 1174-FE01
+    0x2C2D04F9,  // "ᆲ︀" 11B2-FE00
+    0x2C86AB68,  // "ᆩ︃" 11A9-FE03
+    0x2CB139F8,  // "ᆽ︀" 11BD-FE00
+    0x2CB26147,  // "ᄇ︅" 1107-FE05
+    0x2D49E38E,  // "ᆮ︁" 11AE-FE01
+    0x2D6B14B5,  // "ᄊ︄" 110A-FE04
+    0x2D9BE441,  // "ᆬ︃" 11AC-FE03
+    0x2DF873CE,  // "ᄄ︅" 1104-FE05
+    0x2F0C6816,  // "ᄈ︂" 1108-FE02
+    0x320DAC58,  // "ᄆ︀" 1106-FE00
+    0x328C7F7F,  // "ᄀ︇" 1100-FE07
+    0x32C4C6F3,  // "ᆬ︁" 11AC-FE01
+    0x3353233C,  // "ᄍ︁" 110D-FE01
+    0x33D2B52F,  // "ᄆ︁" 1106-FE01
+    0x3583071A,  // "ᇁ︀" 11C1-FE00
+    0x374D5E28,  // "ᄃ︀" 1103-FE00
+    0x3880FD68,  // "ᄐ︅" 1110-FE05
+    0x3ABF8351,  // "ᅯ︀" 116F-FE00
+    0x3AC46568,  // "ᄊ︁" 110A-FE01
+    0x3C05E3BE,  // "ᆹ︀" 11B9-FE00
+    0x3C55FBE3,  // "ᄉ︅" 1109-FE05
+    0x3D3096FC,  // "ᆺ︁" 11BA-FE01
+    0x3D65E45B,  // "ᅦ︃" 1166-FE03
+    0x3D7F929F,  // "ᅱ︀" 1171-FE00
+    0x3FAD0E70,  // "ᅢ︀" 1162-FE00
+    0x3FE304FF,  // "ᄐ︆" 1110-FE06
+    0x4009808A,  // "ᆷ︃" 11B7-FE03
+    0x4033D834,  // "ᆵ︂" 11B5-FE02
+    0x409C1DB1,  // "ᄅ︇" 1105-FE07
+    0x41564192,  // "ᇂ︁" 11C2-FE01
+    0x42923CD3,  // "ᄄ︀" 1104-FE00
+    0x43A3416E,  // "ᆹ︁" 11B9-FE01
+    0x44FB000B,  // "ᄍ︅" 110D-FE05
+    0x45827365,  // "ᄉ︀" 1109-FE00
+    0x45DD3135,  // "ᇀ︂" 11C0-FE02
+    0x4785F261,  // "ᄀ︄" 1100-FE04
+    0x48707CD5,  // "ᄆ︃" 1106-FE03
+    0x4A2BA745,  // "ᄁ︁" 1101-FE01
+    0x4A72E45A,  // "ᆯ︃" 11AF-FE03
+    0x4B054985,  // "ᆽ︂" 11BD-FE02
+    0x4B4B5760,  // "ᆸ︁" 11B8-FE01
+    0x4B561AAD,  // "ᄊ︅" 110A-FE05
+    0x4CE260C1,  // "ᄏ︀" 110F-FE00
+    0x50AFA3BE,  // "ᄇ︄" 1107-FE04
+    0x5149042D,  // "ᅵ︂" 1175-FE02
+    0x51D19B7A,  // "ᄆ︅" 1106-FE05
+    0x523ED679,  // "ᅫ︁" 116B-FE01
+    0x52C200E5,  // "ᅲ︁" 1172-FE01
+    0x535ADCDB,  // "ᄏ︅" 110F-FE05
+    0x53BF7BF9,  // "ᅥ︀" 1165-FE00
+    0x5500E4AC,  // "ᄀ︁" 1100-FE01
+    0x552924EB,  // "ᅧ︃" 1167-FE03
+    0x554AD76A,  // "ᆵ︀" 11B5-FE00
+    0x57815FCC,  // "ᆵ︁" 11B5-FE01
+    0x58AA7194,  // "ᆼ︀" 11BC-FE00
+    0x58D46148,  // "ᄍ︀" 110D-FE00
+    0x5B4A7258,  // "ᆷ︁" 11B7-FE01
+    0x5BC7E289,  // "ᄉ︁" 1109-FE01
+    0x5BD71C5C,  // "ᆴ︁" 11B4-FE01
+    0x5C7D737C,  // "ᅪ︂" 116A-FE02
+    0x5C9D7873,  // "ᆲ︂" 11B2-FE02
+    0x5CC2FBA9,  // "ᄈ︅" 1108-FE05
+    0x5CE5C3B4,  // "ᄀ︅" 1100-FE05
+    0x5E09253F,  // "ᄁ︇" 1101-FE07
+    0x5EC78B9B,  // "ᄁ︆" 1101-FE06
+    0x600E4419,  // "ᄌ︇" 110C-FE07
+    0x60572E51,  // "ᆮ︃" 11AE-FE03
+    0x616C58F5,  // "ᄈ︁" 1108-FE01
+    0x62C3332D,  // "ᄂ︁" 1102-FE01
+    0x63AAB25E,  // "ᅥ︁" 1165-FE01
+    0x63F0E4D7,  // "ᄑ︂" 1111-FE02
+    0x64DC565C,  // "ᆫ︂" 11AB-FE02
+    0x66834D1C,  // "ᄎ︃" 110E-FE03
+    0x66E4E152,  // "ᅧ︁" 1167-FE01
+    0x68B1FEDB,  // "ᄒ︇" 1112-FE07
+    0x69DC112C,  // "ᅨ︀" 1168-FE00
+    0x6A6C4EF6,  // "ᄐ︇" 1110-FE07
+    0x6B518B1B,  // "ᇁ︁" 11C1-FE01
+    0x6C68EC6B,  // "ᆫ︁" 11AB-FE01
+    0x6DB358FD,  // "ᅦ︂" 1166-FE02
+    0x6DCE949A,  // "ᄑ︄" 1111-FE04
+    0x6DF7FD8F,  // "ᇂ︀" 11C2-FE00
+    0x6DFE66FC,  // "ᄈ︇" 1108-FE07
+    0x6E651C74,  // "ᅱ︁" 1171-FE01
+    0x703D7147,  // "ᆶ︃" 11B6-FE03
+    0x7098F1EC,  // "ᄂ︇" 1102-FE07
+    0x70A6B09E,  // "ᇀ︀" 11C0-FE00
+    0x70EABFFD,  // "ᄊ︇" 110A-FE07
+    0x70ED4F11,  // "ᅫ︀" 116B-FE00
+    0x73EFA7B7,  // "ᇀ︁" 11C0-FE01
+    0x74179CF5,  // "ᄎ︆" 110E-FE06
+    0x7441B167,  // "ᄏ︆" 110F-FE06
+    0x7528060D,  // "ᄏ︂" 110F-FE02
+    0x7530083E,  // "ᄅ︄" 1105-FE04
+    0x756A606B,  // "ᄊ︆" 110A-FE06
+    0x75FC2F2C,  // "ᆹ︂" 11B9-FE02
+    0x76108211,  // "ᄎ︂" 110E-FE02
+    0x767B07D3,  // "ᄆ︄" 1106-FE04
+    0x78AE5A73,  // "ᆳ︂" 11B3-FE02
+    0x7A6BBE5D,  // "ᆫ︃" 11AB-FE03
+    0x7B803A52,  // "ᄒ︆" 1112-FE06
+    0x7C7FF6D6,  // "ᄆ︇" 1106-FE07
+    0x7CECA63C,  // "ᅥ︂" 1165-FE02
+    0x7EA94E08,  // "ᄂ︅" 1102-FE05
+    0x7EED90FB,  // "ᄌ︂" 110C-FE02
+    0x7F73D2F7,  // "ᄒ︁" 1112-FE01
+    0x804547A6,  // "ᅩ︃" 1169-FE03
+    0x823BC859,  // "ᄇ︁" 1107-FE01
+    0x82A0CE62,  // "ᄒ︃" 1112-FE03
+    0x83216D15,  // "ᅦ︀" 1166-FE00
+    0x847450CF,  // "ᅲ︂" 1172-FE02
+    0x84BCB016,  // "ᄉ︃" 1109-FE03
+    0x854526E5,  // "ᆪ︁" 11AA-FE01
+    0x85FE5F1E,  // "ᅰ︁" 1170-FE01
+    0x860AD995,  // "ᄅ︁" 1105-FE01
+    0x860C4ECC,  // "ᄋ︆" 110B-FE06
+    0x87E56D06,  // "ᅦ︁" 1166-FE01
+    0x87EE63A8,  // "ᅥ︃" 1165-FE03
+    0x88E01971,  // "ᄏ︇" 110F-FE07
+    0x892E9CE2,  // "ᄒ︅" 1112-FE05
+    0x8993FCC1,  // "ᆬ︂" 11AC-FE02
+    0x8BB1850E,  // "ᆱ︁" 11B1-FE01
+    0x8C08E8D5,  // "ᄋ︅" 110B-FE05
+    0x8C22054D,  // "ᄅ︃" 1105-FE03
+    0x8C5A39DA,  // "ᅭ︃" 116D-FE03
+    0x8CFEE7FB,  // "ᆺ︂" 11BA-FE02
+    0x8D1B63E6,  // "ᆷ︀" 11B7-FE00
+    0x8DB30866,  // "ᆿ︀" 11BF-FE00
+    0x8DB653EC,  // "ᆸ︂" 11B8-FE02
+    0x8E4EEA9C,  // "ᆽ︁" 11BD-FE01
+    0x8EB270A3,  // "ᇁ︂" 11C1-FE02
+    0x8F6ED463,  // "ᆶ︀" 11B6-FE00
+    0x8F9F9331,  // "ᆳ︁" 11B3-FE01
+    0x90B64B6B,  // "ᄏ︁" 110F-FE01
+    0x91148DFB,  // "ᄄ︃" 1104-FE03
+    0x9122539A,  // "ᆬ︀" 11AC-FE00
+    0x9146ABBB,  // "ᄃ︅" 1103-FE05
+    0x919197C6,  // "ᆫ︀" 11AB-FE00
+    0x9416166A,  // "ᅤ︁" 1164-FE01
+    0x945E6256,  // "ᆱ︂" 11B1-FE02
+    0x9465FA48,  // "ᆾ︃" 11BE-FE03
+    0x9489F7E4,  // "ᅲ︃" 1172-FE03
+    0x959F5086,  // "ᄐ︃" 1110-FE03
+    0x96406CDF,  // "ᅮ︁" 116E-FE01
+    0x96C5A640,  // "ᅣ︀" 1163-FE00
+    0x96E7E40C,  // "ᅭ︁" 116D-FE01
+    0x97942D6D,  // "ᄌ︄" 110C-FE04
+    0x9825F39E,  // "ᄍ︄" 110D-FE04
+    0x99A04329,  // "ᆶ︂" 11B6-FE02
+    0x9BF396AC,  // "ᅪ︃" 116A-FE03
+    0x9DB48DEC,  // "ᅫ︃" 116B-FE03
+    0x9E3DD409,  // "ᆭ︂" 11AD-FE02
+    0xA16F1E33,  // "ᄐ︁" 1110-FE01
+    0xA1D2683D,  // "ᅴ︀" 1174-FE00
+    0xA288EBAE,  // "ᆴ︂" 11B4-FE02
+    0xA2CB16FC,  // "ᆩ︁" 11A9-FE01
+    0xA2DE641A,  // "ᆨ︃" 11A8-FE03
+    0xA2EC7979,  // "ᆪ︂" 11AA-FE02
+    0xA335540B,  // "ᆻ︃" 11BB-FE03
+    0xA3AC72E7,  // "ᅩ︀" 1169-FE00
+    0xA427109A,  // "ᆲ︁" 11B2-FE01
+    0xA46BF280,  // "ᄐ︀" 1110-FE00
+    0xA4FE6352,  // "ᄎ︄" 110E-FE04
+    0xA533E6AF,  // "ᆼ︂" 11BC-FE02
+    0xA5DE18E9,  // "ᆼ︃" 11BC-FE03
+    0xA681B2E3,  // "ᄉ︆" 1109-FE06
+    0xA68C6DD3,  // "ᅡ︀" 1161-FE00
+    0xA6FBA0DD,  // "ᅮ︂" 116E-FE02
+    0xA93F470C,  // "ᅪ︁" 116A-FE01
+    0xA9628766,  // "ᆪ︀" 11AA-FE00
+    0xA9B004FC,  // "ᆨ︀" 11A8-FE00
+    0xAAAD3494,  // "ᆻ︂" 11BB-FE02
+    0xABC3C805,  // "ᄇ︂" 1107-FE02
+    0xABE69ED6,  // "ᆸ︀" 11B8-FE00
+    0xAC670A31,  // "ᇂ︃" 11C2-FE03
+    0xAD72627D,  // "ᅵ︀" 1175-FE00
+    0xAE7A0D38,  // "ᄒ︂" 1112-FE02
+    0xAE97DA93,  // "ᆩ︂" 11A9-FE02
+    0xAF40632F,  // "ᆯ︂" 11AF-FE02
+    0xAFE1D2ED,  // "ᄈ︄" 1108-FE04
+    0xB1DC8B2F,  // "ᄌ︃" 110C-FE03
+    0xB29F3B40,  // "ᆮ︂" 11AE-FE02
+    0xB2C9D7A8,  // "ᄒ︀" 1112-FE00
+    0xB3D675E5,  // "ᄃ︃" 1103-FE03
+    0xB436804B,  // "ᅭ︂" 116D-FE02
+    0xB50FE846,  // "ᅣ︁" 1163-FE01
+    0xB6FB6325,  // "ᅬ︂" 116C-FE02
+    0xB79B42C6,  // "ᅱ︂" 1171-FE02
+    0xB7D6029E,  // "ᅰ︀" 1170-FE00
+    0xBB75FF95,  // "ᄌ︀" 110C-FE00
+    0xBC3555DD,  // "ᄋ︁" 110B-FE01
+    0xBD745921,  // "ᆳ︃" 11B3-FE03
+    0xBDC0A838,  // "ᄋ︇" 110B-FE07
+    0xBDD18FA9,  // "ᅱ︃" 1171-FE03
+    0xBE8E25A7,  // "ᄍ︃" 110D-FE03
+    0xBF5CB2CC,  // "ᅴ︂" 1174-FE02
+    0xC18DAA01,  // "ᄅ︆" 1105-FE06
+    0xC2205557,  // "ᆾ︁" 11BE-FE01
+    0xC268738A,  // "ᆱ︀" 11B1-FE00
+    0xC2830E39,  // "ᅡ︂" 1161-FE02
+    0xC2FAB95E,  // "ᆴ︀" 11B4-FE00
+    0xC4266F75,  // "ᄑ︇" 1111-FE07
+    0xC47919B8,  // "ᄋ︄" 110B-FE04
+    0xC4FF1B88,  // "ᅨ︃" 1168-FE03
+    0xC517FA92,  // "ᅮ︃" 116E-FE03
+    0xC5516FAF,  // "ᄇ︇" 1107-FE07
+    0xC5B2015F,  // "ᄀ︂" 1100-FE02
+    0xC61B2AB4,  // "ᅲ︀" 1172-FE00
+    0xC71382F2,  // "ᅡ︁" 1161-FE01
+    0xC76D3AF1,  // "ᅤ︀" 1164-FE00
+    0xC80E648B,  // "ᅫ︂" 116B-FE02
+    0xC87D7DD6,  // "ᆸ︃" 11B8-FE03
+    0xC9721D37,  // "ᄃ︆" 1103-FE06
+    0xCB76CBE3,  // "ᅰ︂" 1170-FE02
+    0xCC024271,  // "ᄂ︂" 1102-FE02
+    0xCC02E53E,  // "ᆰ︁" 11B0-FE01
+    0xCC58D2A0,  // "ᅬ︃" 116C-FE03
+    0xCE642CAC,  // "ᆪ︃" 11AA-FE03
+    0xCF3B01A6,  // "ᅤ︂" 1164-FE02
+    0xD1F11B53,  // "ᆴ︃" 11B4-FE03
+    0xD293BF83,  // "ᅮ︀" 116E-FE00
+    0xD43ADD14,  // "ᆮ︀" 11AE-FE00
+    0xD6A2787F,  // "ᄃ︄" 1103-FE04
+    0xD6EE6A58,  // "ᄏ︃" 110F-FE03
+    0xD7AF4DF7,  // "ᆯ︀" 11AF-FE00
+    0xD7C65CD4,  // "ᅰ︃" 1170-FE03
+    0xD873FD95,  // "ᄑ︅" 1111-FE05
+    0xD9232DE2,  // "ᄈ︀" 1108-FE00
+    0xDAEC50ED,  // "ᆻ︀" 11BB-FE00
+    0xDB477174,  // "ᆨ︁" 11A8-FE01
+    0xDBCE32C2,  // "ᅧ︂" 1167-FE02
+    0xDC104612,  // "ᅪ︀" 116A-FE00
+    0xDC12A0CB,  // "ᅨ︂" 1168-FE02
+    0xDC7D8413,  // "ᄁ︅" 1101-FE05
+    0xDD01409A,  // "ᅢ︃" 1162-FE03
+    0xDD184687,  // "ᄆ︆" 1106-FE06
+    0xDEE68065,  // "ᆭ︃" 11AD-FE03
+    0xDFAEB235,  // "ᄇ︆" 1107-FE06
+    0xE011411C,  // "ᄎ︁" 110E-FE01
+    0xE03FAFF0,  // "ᄐ︄" 1110-FE04
+    0xE042AFF5,  // "ᄁ︃" 1101-FE03
+    0xE0E3D568,  // "ᄂ︀" 1102-FE00
+    0xE15E67FE,  // "ᄂ︃" 1102-FE03
+    0xE418E69F,  // "ᆻ︁" 11BB-FE01
+    0xE461E66D,  // "ᅩ︂" 1169-FE02
+    0xE53C1F6C,  // "ᆿ︂" 11BF-FE02
+    0xE5ABD98F,  // "ᄇ︃" 1107-FE03
+    0xE6FB409B,  // "ᆯ︁" 11AF-FE01
+    0xE95229AA,  // "ᆳ︀" 11B3-FE00
+    0xEA825FB5,  // "ᄂ︄" 1102-FE04
+    0xEB026E00,  // "ᄄ︂" 1104-FE02
+    0xEB0543D8,  // "ᆨ︂" 11A8-FE02
+    0xEB36320C,  // "ᄑ︃" 1111-FE03
+    0xEBBE7A52,  // "ᆾ︀" 11BE-FE00
+    0xEC51F7A1,  // "ᆶ︁" 11B6-FE01
+    0xED177142,  // "ᅨ︁" 1168-FE01
+    0xED17CAA5,  // "ᆺ︀" 11BA-FE00
+    0xEE08E139,  // "ᄊ︂" 110A-FE02
+    0xEEE511D3,  // "ᅣ︂" 1163-FE02
+    0xEFE196C7,  // "ᆩ︀" 11A9-FE00
+    0xF100448B,  // "ᄀ︆" 1100-FE06
+    0xF22A4D28,  // "ᆭ︀" 11AD-FE00
+    0xF25A4D69,  // "ᄈ︆" 1108-FE06
+    0xF3C17FE6,  // "ᄄ︇" 1104-FE07
+    0xF3E29F0C,  // "ᇁ︃" 11C1-FE03
+    0xF4683DF5,  // "ᅯ︃" 116F-FE03
+    0xF4714308,  // "ᅩ︁" 1169-FE01
+    0xF78660E1,  // "ᄌ︆" 110C-FE06
+    0xF7C04079,  // "ᄀ︀" 1100-FE00
+    0xF8F6DC96,  // "ᄎ︇" 110E-FE07
+    0xF9A345C2,  // "ᆼ︁" 11BC-FE01
+    0xFCAE0FC3,  // "ᆰ︃" 11B0-FE03
+    0xFCF63D7E,  // "ᆺ︃" 11BA-FE03
+    0xFE5F8764,  // "ᅵ︃" 1175-FE03
+    0xFF376C0B,  // "ᄋ︂" 110B-FE02
+];
+
+/// Verification keys to rule out hash collisions; sort matches HASH_HANGUL_JAMO
+static KEY_HANGUL_JAMO: [u32; 344] = [
+    0xDE272BB1,  // "ᇀ︃" 11C0-FE03
+    0xDF40C763,  // "ᄍ︆" 110D-FE06
+    0xACF63728,  // "ᄑ︆" 1111-FE06
+    0xCD4226F4,  // "ᄃ︇" 1103-FE07
+    0xE9866F46,  // "ᅵ︁" 1175-FE01
+    0xDB3BA5DC,  // "ᄅ︅" 1105-FE05
+    0xA0987AC1,  // "ᅳ︂" 1173-FE02
+    0x895D6E1E,  // "ᄃ︁" 1103-FE01
+    0x9933B676,  // "ᄏ︄" 110F-FE04
+    0xCCCA8C45,  // "ᄊ︃" 110A-FE03
+    0xDE43672A,  // "ᄂ︆" 1102-FE06
+    0x935FDDCC,  // "ᅣ︃" 1163-FE03
+    0xC03C7930,  // "ᆷ︂" 11B7-FE02
+    0xB194A50E,  // "ᅧ︀" 1167-FE00
+    0xF5F256C2,  // "ᄒ︄" 1112-FE04
+    0xC6E7F232,  // "ᅴ︃" 1174-FE03
+    0xEEC1BE98,  // "ᆿ︃" 11BF-FE03
+    0x9B8E14AA,  // "ᄐ︂" 1110-FE02
+    0xAD918D21,  // "ᄋ︀" 110B-FE00
+    0xCF184B13,  // "ᆵ︃" 11B5-FE03
+    0xFD1A702F,  // "ᅳ︁" 1173-FE01
+    0xA9FAC0C4,  // "ᄅ︂" 1105-FE02
+    0xA0F67234,  // "ᄄ︁" 1104-FE01
+    0xAED6A2F4,  // "ᅭ︀" 116D-FE00
+    0xF4F5F4AC,  // "ᄁ︂" 1101-FE02
+    0xA13F10FE,  // "ᄉ︂" 1109-FE02
+    0xEEC60BF8,  // "ᆱ︃" 11B1-FE03
+    0x8747B15B,  // "ᄉ︇" 1109-FE07
+    0xCDCF4F2A,  // "ᄄ︄" 1104-FE04
+    0xC3299922,  // "ᆽ︃" 11BD-FE03
+    0x97FA80E5,  // "ᄑ︁" 1111-FE01
+    0x81ACE5E1,  // "ᇂ︂" 11C2-FE02
+    0xEE8C4AB8,  // "ᄍ︇" 110D-FE07
+    0x9F3DC53D,  // "ᄎ︀" 110E-FE00
+    0x9F1CD504,  // "ᅳ︀" 1173-FE00
+    0xEED42860,  // "ᄋ︃" 110B-FE03
+    0x83C70E42,  // "ᄁ︄" 1101-FE04
+    0xFB878539,  // "ᄑ︀" 1111-FE00
+    0x8EA05DAC,  // "ᅤ︃" 1164-FE03
+    0xB47DAAFB,  // "ᄃ︂" 1103-FE02
+    0xFCB14E76,  // "ᆭ︁" 11AD-FE01
+    0x9F84CFBD,  // "ᄀ︃" 1100-FE03
+    0xE80C2BF3,  // "ᅬ︀" 116C-FE00
+    0xC348EFEC,  // "ᄌ︁" 110C-FE01
+    0xC8686968,  // "ᅯ︁" 116F-FE01
+    0x81DD1864,  // "ᅢ︁" 1162-FE01
+    0xCDB64DC2,  // "ᄊ︀" 110A-FE00
+    0xA0CDAA8C,  // "ᆾ︂" 11BE-FE02
+    0x9F251C50,  // "ᆹ︃" 11B9-FE03
+    0xEEAAB4B6,  // "ᆰ︀" 11B0-FE00
+    0xB461F8AB,  // "ᆿ︁" 11BF-FE01
+    0xA8A3DD95,  // "ᄍ︂" 110D-FE02
+    0xF6B2D5CD,  // "ᄅ︀" 1105-FE00
+    0xB8CB6631,  // "ᄁ︀" 1101-FE00
+    0xCAF51268,  // "ᅯ︂" 116F-FE02
+    0xD5F269E2,  // "ᅳ︃" 1173-FE03
+    0xD2EB4249,  // "ᆰ︂" 11B0-FE02
+    0xF43A45D9,  // "ᄄ︆" 1104-FE06
+    0xFD510132,  // "ᅡ︃" 1161-FE03
+    0x86A86FCD,  // "ᄎ︅" 110E-FE05
+    0xB94CC1F2,  // "ᄌ︅" 110C-FE05
+    0x90A65D78,  // "ᄇ︀" 1107-FE00
+    0xBD4E53D9,  // "ᄆ︂" 1106-FE02
+    0xC9FBB9DC,  // "ᄈ︃" 1108-FE03
+    0xCC3BDB30,  // "ᅬ︁" 116C-FE01
+    0x945613CE,  // "ᄉ︄" 1109-FE04
+    0xD76B88E1,  // "ᆲ︃" 11B2-FE03
+    0x965F72E0,  // "ᅢ︂" 1162-FE02
+    0x880CDA1E,  // "ᅴ︁" 1174-FE01
+    0x9F5A69E2,  // "ᆲ︀" 11B2-FE00
+    0xAEA1C123,  // "ᆩ︃" 11A9-FE03
+    0x88B3EB87,  // "ᆽ︀" 11BD-FE00
+    0x8927892B,  // "ᄇ︅" 1107-FE05
+    0xC22E9909,  // "ᆮ︁" 11AE-FE01
+    0xDD41DC78,  // "ᄊ︄" 110A-FE04
+    0xD62854EA,  // "ᆬ︃" 11AC-FE03
+    0xAEDF9B86,  // "ᄄ︅" 1104-FE05
+    0xEC29109E,  // "ᄈ︂" 1108-FE02
+    0xB6725211,  // "ᄆ︀" 1106-FE00
+    0xA8C76B68,  // "ᄀ︇" 1100-FE07
+    0x9A5F591F,  // "ᆬ︁" 11AC-FE01
+    0xF969B777,  // "ᄍ︁" 110D-FE01
+    0xE8679514,  // "ᄆ︁" 1106-FE01
+    0x9557D647,  // "ᇁ︀" 11C1-FE00
+    0x9650204D,  // "ᄃ︀" 1103-FE00
+    0xB37538EF,  // "ᄐ︅" 1110-FE05
+    0x9DA189FB,  // "ᅯ︀" 116F-FE00
+    0xB34976B5,  // "ᄊ︁" 110A-FE01
+    0xFCB8E5B9,  // "ᆹ︀" 11B9-FE00
+    0xACF5F622,  // "ᄉ︅" 1109-FE05
+    0x8E2CC25E,  // "ᆺ︁" 11BA-FE01
+    0xB3EE0844,  // "ᅦ︃" 1166-FE03
+    0x84052B41,  // "ᅱ︀" 1171-FE00
+    0xC5FDF4CB,  // "ᅢ︀" 1162-FE00
+    0x91D847BD,  // "ᄐ︆" 1110-FE06
+    0xC1A38B37,  // "ᆷ︃" 11B7-FE03
+    0xE163C205,  // "ᆵ︂" 11B5-FE02
+    0x99D1385A,  // "ᄅ︇" 1105-FE07
+    0xC55009A6,  // "ᇂ︁" 11C2-FE01
+    0x8EA865E6,  // "ᄄ︀" 1104-FE00
+    0xB8DB7DA5,  // "ᆹ︁" 11B9-FE01
+    0xA94BBCFB,  // "ᄍ︅" 110D-FE05
+    0xAA32A716,  // "ᄉ︀" 1109-FE00
+    0xD721FC85,  // "ᇀ︂" 11C0-FE02
+    0xF1EF8F70,  // "ᄀ︄" 1100-FE04
+    0x8B1876E6,  // "ᄆ︃" 1106-FE03
+    0xBCDB8713,  // "ᄁ︁" 1101-FE01
+    0xC43853D1,  // "ᆯ︃" 11AF-FE03
+    0xCEF97845,  // "ᆽ︂" 11BD-FE02
+    0x853080BB,  // "ᆸ︁" 11B8-FE01
+    0x9C11203A,  // "ᄊ︅" 110A-FE05
+    0xB1049F27,  // "ᄏ︀" 110F-FE00
+    0xA9FD2149,  // "ᄇ︄" 1107-FE04
+    0xCA5DF83C,  // "ᅵ︂" 1175-FE02
+    0xBAED1C6A,  // "ᄆ︅" 1106-FE05
+    0xCEAC5A6D,  // "ᅫ︁" 116B-FE01
+    0xC61A4EC3,  // "ᅲ︁" 1172-FE01
+    0x9ADE24C0,  // "ᄏ︅" 110F-FE05
+    0xA5B483A5,  // "ᅥ︀" 1165-FE00
+    0x82F3C2F4,  // "ᄀ︁" 1100-FE01
+    0xC3753507,  // "ᅧ︃" 1167-FE03
+    0x8420DDDB,  // "ᆵ︀" 11B5-FE00
+    0xD072A482,  // "ᆵ︁" 11B5-FE01
+    0xFA734389,  // "ᆼ︀" 11BC-FE00
+    0xE134C184,  // "ᄍ︀" 110D-FE00
+    0xC3418B87,  // "ᆷ︁" 11B7-FE01
+    0xB8D1A9BA,  // "ᄉ︁" 1109-FE01
+    0xD01B6E4E,  // "ᆴ︁" 11B4-FE01
+    0xCF79EAE1,  // "ᅪ︂" 116A-FE02
+    0xD897CEF5,  // "ᆲ︂" 11B2-FE02
+    0x974F5195,  // "ᄈ︅" 1108-FE05
+    0xEBE0DB68,  // "ᄀ︅" 1100-FE05
+    0xEA9B2DFE,  // "ᄁ︇" 1101-FE07
+    0x85660ECC,  // "ᄁ︆" 1101-FE06
+    0xB5B6EBB3,  // "ᄌ︇" 110C-FE07
+    0x83C54C92,  // "ᆮ︃" 11AE-FE03
+    0xA65947B6,  // "ᄈ︁" 1108-FE01
+    0xC83E0FDB,  // "ᄂ︁" 1102-FE01
+    0xF5E5A371,  // "ᅥ︁" 1165-FE01
+    0xD59AF650,  // "ᄑ︂" 1111-FE02
+    0xAEB2CB6E,  // "ᆫ︂" 11AB-FE02
+    0x93A21D7D,  // "ᄎ︃" 110E-FE03
+    0xDB117C32,  // "ᅧ︁" 1167-FE01
+    0xFC872D45,  // "ᄒ︇" 1112-FE07
+    0xE1BFFCEE,  // "ᅨ︀" 1168-FE00
+    0xF7293DD5,  // "ᄐ︇" 1110-FE07
+    0x9A377166,  // "ᇁ︁" 11C1-FE01
+    0xC7A85090,  // "ᆫ︁" 11AB-FE01
+    0xBE734E36,  // "ᅦ︂" 1166-FE02
+    0xFF3CD197,  // "ᄑ︄" 1111-FE04
+    0xF6BB4D11,  // "ᇂ︀" 11C2-FE00
+    0xAA27DB40,  // "ᄈ︇" 1108-FE07
+    0xF5C01F77,  // "ᅱ︁" 1171-FE01
+    0xBCC865B3,  // "ᆶ︃" 11B6-FE03
+    0xC142CC7B,  // "ᄂ︇" 1102-FE07
+    0xCD2E9463,  // "ᇀ︀" 11C0-FE00
+    0xD55E8304,  // "ᄊ︇" 110A-FE07
+    0xB8391F72,  // "ᅫ︀" 116B-FE00
+    0xE36EADF3,  // "ᇀ︁" 11C0-FE01
+    0x87325473,  // "ᄎ︆" 110E-FE06
+    0x8B5ADD22,  // "ᄏ︆" 110F-FE06
+    0x80145E7C,  // "ᄏ︂" 110F-FE02
+    0x8C5CECAB,  // "ᄅ︄" 1105-FE04
+    0xE37F24EC,  // "ᄊ︆" 110A-FE06
+    0xDC049A8F,  // "ᆹ︂" 11B9-FE02
+    0x8DBD0C5B,  // "ᄎ︂" 110E-FE02
+    0xC4A7BB6F,  // "ᄆ︄" 1106-FE04
+    0xB4A05710,  // "ᆳ︂" 11B3-FE02
+    0xA52C9873,  // "ᆫ︃" 11AB-FE03
+    0xAD3FFA5A,  // "ᄒ︆" 1112-FE06
+    0xAD85A31C,  // "ᄆ︇" 1106-FE07
+    0xBF9C0890,  // "ᅥ︂" 1165-FE02
+    0xD77E060F,  // "ᄂ︅" 1102-FE05
+    0xB2081A57,  // "ᄌ︂" 110C-FE02
+    0xE6505923,  // "ᄒ︁" 1112-FE01
+    0xA55488B8,  // "ᅩ︃" 1169-FE03
+    0xE76967F9,  // "ᄇ︁" 1107-FE01
+    0xF51D3CC5,  // "ᄒ︃" 1112-FE03
+    0x8EDA5D22,  // "ᅦ︀" 1166-FE00
+    0xE5FB54BC,  // "ᅲ︂" 1172-FE02
+    0xFA3B8C76,  // "ᄉ︃" 1109-FE03
+    0xD7711293,  // "ᆪ︁" 11AA-FE01
+    0xC0B0A0E8,  // "ᅰ︁" 1170-FE01
+    0xB0D9995F,  // "ᄅ︁" 1105-FE01
+    0xCC44495A,  // "ᄋ︆" 110B-FE06
+    0xA64DDCC7,  // "ᅦ︁" 1166-FE01
+    0xD83527E4,  // "ᅥ︃" 1165-FE03
+    0xC10EC280,  // "ᄏ︇" 110F-FE07
+    0xF06CFF11,  // "ᄒ︅" 1112-FE05
+    0x89463624,  // "ᆬ︂" 11AC-FE02
+    0xFA48376E,  // "ᆱ︁" 11B1-FE01
+    0xC06AEF99,  // "ᄋ︅" 110B-FE05
+    0xF445AF50,  // "ᄅ︃" 1105-FE03
+    0xA96B541E,  // "ᅭ︃" 116D-FE03
+    0xFA7C6167,  // "ᆺ︂" 11BA-FE02
+    0x877729ED,  // "ᆷ︀" 11B7-FE00
+    0xD4484CEE,  // "ᆿ︀" 11BF-FE00
+    0xB8804F9B,  // "ᆸ︂" 11B8-FE02
+    0xAD6A2A32,  // "ᆽ︁" 11BD-FE01
+    0x971D6FF0,  // "ᇁ︂" 11C1-FE02
+    0x83D7B2B6,  // "ᆶ︀" 11B6-FE00
+    0xA69970A2,  // "ᆳ︁" 11B3-FE01
+    0xB69CC525,  // "ᄏ︁" 110F-FE01
+    0xFD371082,  // "ᄄ︃" 1104-FE03
+    0xCC223426,  // "ᆬ︀" 11AC-FE00
+    0x9D03DF51,  // "ᄃ︅" 1103-FE05
+    0x9550FDCA,  // "ᆫ︀" 11AB-FE00
+    0xC251A58C,  // "ᅤ︁" 1164-FE01
+    0xCC057AE7,  // "ᆱ︂" 11B1-FE02
+    0xBDC01087,  // "ᆾ︃" 11BE-FE03
+    0x83979A28,  // "ᅲ︃" 1172-FE03
+    0x8DEB96D2,  // "ᄐ︃" 1110-FE03
+    0xF672BC83,  // "ᅮ︁" 116E-FE01
+    0xC87D2354,  // "ᅣ︀" 1163-FE00
+    0x83AD2640,  // "ᅭ︁" 116D-FE01
+    0xED3861EA,  // "ᄌ︄" 110C-FE04
+    0x802C5D89,  // "ᄍ︄" 110D-FE04
+    0xD9CFA72C,  // "ᆶ︂" 11B6-FE02
+    0xFA759E63,  // "ᅪ︃" 116A-FE03
+    0x8B0DF0FC,  // "ᅫ︃" 116B-FE03
+    0xA0E603BA,  // "ᆭ︂" 11AD-FE02
+    0xF187B3E1,  // "ᄐ︁" 1110-FE01
+    0x962BED6F,  // "ᅴ︀" 1174-FE00
+    0xD3B0599B,  // "ᆴ︂" 11B4-FE02
+    0xF3148477,  // "ᆩ︁" 11A9-FE01
+    0xE1C6E5F4,  // "ᆨ︃" 11A8-FE03
+    0x81611D95,  // "ᆪ︂" 11AA-FE02
+    0xF535AC4E,  // "ᆻ︃" 11BB-FE03
+    0xA5FF5F51,  // "ᅩ︀" 1169-FE00
+    0xB9B6A507,  // "ᆲ︁" 11B2-FE01
+    0xAFA597C1,  // "ᄐ︀" 1110-FE00
+    0xCF0850D5,  // "ᄎ︄" 110E-FE04
+    0x897E979B,  // "ᆼ︂" 11BC-FE02
+    0xAE90529C,  // "ᆼ︃" 11BC-FE03
+    0xAF7FE7EA,  // "ᄉ︆" 1109-FE06
+    0xCF836A06,  // "ᅡ︀" 1161-FE00
+    0xF62C283A,  // "ᅮ︂" 116E-FE02
+    0x995BC6F0,  // "ᅪ︁" 116A-FE01
+    0xEF136DB0,  // "ᆪ︀" 11AA-FE00
+    0xB3B49843,  // "ᆨ︀" 11A8-FE00
+    0xECAD9ACC,  // "ᆻ︂" 11BB-FE02
+    0xE6EE0BF7,  // "ᄇ︂" 1107-FE02
+    0xE8F15487,  // "ᆸ︀" 11B8-FE00
+    0x9ED677FB,  // "ᇂ︃" 11C2-FE03
+    0x8BB16A00,  // "ᅵ︀" 1175-FE00
+    0xDD8A855F,  // "ᄒ︂" 1112-FE02
+    0x9F17B1AB,  // "ᆩ︂" 11A9-FE02
+    0xFFB85699,  // "ᆯ︂" 11AF-FE02
+    0xC7A06010,  // "ᄈ︄" 1108-FE04
+    0xAFBD894E,  // "ᄌ︃" 110C-FE03
+    0xA5C55BCB,  // "ᆮ︂" 11AE-FE02
+    0x8FD3E927,  // "ᄒ︀" 1112-FE00
+    0xD3C2C661,  // "ᄃ︃" 1103-FE03
+    0x9DB75A04,  // "ᅭ︂" 116D-FE02
+    0xFD0F6AC2,  // "ᅣ︁" 1163-FE01
+    0x933AF6C2,  // "ᅬ︂" 116C-FE02
+    0xBF187DDC,  // "ᅱ︂" 1171-FE02
+    0xA6BCC0B2,  // "ᅰ︀" 1170-FE00
+    0xDAB770E5,  // "ᄌ︀" 110C-FE00
+    0xC5309045,  // "ᄋ︁" 110B-FE01
+    0xA4278F62,  // "ᆳ︃" 11B3-FE03
+    0xA15B51D9,  // "ᄋ︇" 110B-FE07
+    0xFA6BF6C8,  // "ᅱ︃" 1171-FE03
+    0x8A0F2ED7,  // "ᄍ︃" 110D-FE03
+    0x9AFBF557,  // "ᅴ︂" 1174-FE02
+    0x8A72700B,  // "ᄅ︆" 1105-FE06
+    0xDFF1FBFC,  // "ᆾ︁" 11BE-FE01
+    0xA9696A2D,  // "ᆱ︀" 11B1-FE00
+    0x9C9B1BE5,  // "ᅡ︂" 1161-FE02
+    0x80E965C0,  // "ᆴ︀" 11B4-FE00
+    0xEA69AA23,  // "ᄑ︇" 1111-FE07
+    0xB54ADADD,  // "ᄋ︄" 110B-FE04
+    0xB064F255,  // "ᅨ︃" 1168-FE03
+    0xE7874495,  // "ᅮ︃" 116E-FE03
+    0xED46D15D,  // "ᄇ︇" 1107-FE07
+    0xEF2257F8,  // "ᄀ︂" 1100-FE02
+    0x94A6841A,  // "ᅲ︀" 1172-FE00
+    0xEC6EB597,  // "ᅡ︁" 1161-FE01
+    0xEA06C91E,  // "ᅤ︀" 1164-FE00
+    0x8C5FF81B,  // "ᅫ︂" 116B-FE02
+    0x8E1BE571,  // "ᆸ︃" 11B8-FE03
+    0xB5CC5B2D,  // "ᄃ︆" 1103-FE06
+    0xD8FB44AF,  // "ᅰ︂" 1170-FE02
+    0xE7BDE429,  // "ᄂ︂" 1102-FE02
+    0xCA102BD3,  // "ᆰ︁" 11B0-FE01
+    0x81587FAE,  // "ᅬ︃" 116C-FE03
+    0xE1A13ECF,  // "ᆪ︃" 11AA-FE03
+    0xB939DFA0,  // "ᅤ︂" 1164-FE02
+    0xC3B7C7CF,  // "ᆴ︃" 11B4-FE03
+    0x81462386,  // "ᅮ︀" 116E-FE00
+    0xA83A5FF4,  // "ᆮ︀" 11AE-FE00
+    0xA5BF7D19,  // "ᄃ︄" 1103-FE04
+    0x93623B78,  // "ᄏ︃" 110F-FE03
+    0xCEE4004C,  // "ᆯ︀" 11AF-FE00
+    0xD6F82435,  // "ᅰ︃" 1170-FE03
+    0xED5FDBCB,  // "ᄑ︅" 1111-FE05
+    0xEC538E16,  // "ᄈ︀" 1108-FE00
+    0x89C157F0,  // "ᆻ︀" 11BB-FE00
+    0xBDDC24A3,  // "ᆨ︁" 11A8-FE01
+    0x882FE4D9,  // "ᅧ︂" 1167-FE02
+    0xB56BB358,  // "ᅪ︀" 116A-FE00
+    0xE61B37AB,  // "ᅨ︂" 1168-FE02
+    0x84F84B97,  // "ᄁ︅" 1101-FE05
+    0xB14F16BC,  // "ᅢ︃" 1162-FE03
+    0xAD1A3562,  // "ᄆ︆" 1106-FE06
+    0xCB5E42B7,  // "ᆭ︃" 11AD-FE03
+    0xC0FAD011,  // "ᄇ︆" 1107-FE06
+    0xD88C2433,  // "ᄎ︁" 110E-FE01
+    0x8E4B5FF3,  // "ᄐ︄" 1110-FE04
+    0xCEF75235,  // "ᄁ︃" 1101-FE03
+    0xCA5FED99,  // "ᄂ︀" 1102-FE00
+    0xB817EAA4,  // "ᄂ︃" 1102-FE03
+    0xC7FCB39B,  // "ᆻ︁" 11BB-FE01
+    0x94F1C4C9,  // "ᅩ︂" 1169-FE02
+    0xEAB87470,  // "ᆿ︂" 11BF-FE02
+    0x9CE61491,  // "ᄇ︃" 1107-FE03
+    0x96724061,  // "ᆯ︁" 11AF-FE01
+    0xD6BA3AFA,  // "ᆳ︀" 11B3-FE00
+    0x80AFE987,  // "ᄂ︄" 1102-FE04
+    0xE5304FC9,  // "ᄄ︂" 1104-FE02
+    0xAFFBD377,  // "ᆨ︂" 11A8-FE02
+    0x83306843,  // "ᄑ︃" 1111-FE03
+    0xD8A05561,  // "ᆾ︀" 11BE-FE00
+    0xAD060060,  // "ᆶ︁" 11B6-FE01
+    0xA3E373AD,  // "ᅨ︁" 1168-FE01
+    0xC1E6CE7D,  // "ᆺ︀" 11BA-FE00
+    0xBFFB425E,  // "ᄊ︂" 110A-FE02
+    0x87864E93,  // "ᅣ︂" 1163-FE02
+    0xDFC1CBE9,  // "ᆩ︀" 11A9-FE00
+    0xAA359768,  // "ᄀ︆" 1100-FE06
+    0xCF992D2B,  // "ᆭ︀" 11AD-FE00
+    0x9901E4B7,  // "ᄈ︆" 1108-FE06
+    0xE185D95A,  // "ᄄ︇" 1104-FE07
+    0xEBF1330E,  // "ᇁ︃" 11C1-FE03
+    0xAB2D6C38,  // "ᅯ︃" 116F-FE03
+    0x80AE9F44,  // "ᅩ︁" 1169-FE01
+    0xCE6691C3,  // "ᄌ︆" 110C-FE06
+    0xD233EE4F,  // "ᄀ︀" 1100-FE00
+    0xAA1A8B76,  // "ᄎ︇" 110E-FE07
+    0xDA9AA07F,  // "ᆼ︁" 11BC-FE01
+    0xC9DA139A,  // "ᆰ︃" 11B0-FE03
+    0x9440930E,  // "ᆺ︃" 11BA-FE03
+    0xA06930DC,  // "ᅵ︃" 1175-FE03
+    0xEAD3135A,  // "ᄋ︂" 110B-FE02
+];
+
+/// Lookup table of blit pattern offsets; sort matches HASH_HANGUL_JAMO
+static OFFSET_HANGUL_JAMO: [usize; 344] = [
+    4543, // "ᇀ︃" 11C0-FE03
+    1808, // "ᄍ︆" 110D-FE06
+    1844, // "ᄑ︆" 1111-FE06
+    1883, // "ᄃ︇" 1103-FE07
+    2914, // "ᅵ︁" 1175-FE01
+    1453, // "ᄅ︅" 1105-FE05
+    3219, // "ᅳ︂" 1173-FE02
+    441,  // "ᄃ︁" 1103-FE01
+    1324, // "ᄏ︄" 110F-FE04
+    1042, // "ᄊ︃" 110A-FE03
+    1709, // "ᄂ︆" 1102-FE06
+    3294, // "ᅣ︃" 1163-FE03
+    4196, // "ᆷ︂" 11B7-FE02
+    2157, // "ᅧ︀" 1167-FE00
+    1357, // "ᄒ︄" 1112-FE04
+    3539, // "ᅴ︃" 1174-FE03
+    4533, // "ᆿ︃" 11BF-FE03
+    873,  // "ᄐ︂" 1110-FE02
+    231,  // "ᄋ︀" 110B-FE00
+    4434, // "ᆵ︃" 11B5-FE03
+    2884, // "ᅳ︁" 1173-FE01
+    730,  // "ᄅ︂" 1105-FE02
+    455,  // "ᄄ︁" 1104-FE01
+    2299, // "ᅭ︀" 116D-FE00
+    678,  // "ᄁ︂" 1101-FE02
+    782,  // "ᄉ︂" 1109-FE02
+    4394, // "ᆱ︃" 11B1-FE03
+    1925, // "ᄉ︇" 1109-FE07
+    1203, // "ᄄ︄" 1104-FE04
+    4514, // "ᆽ︃" 11BD-FE03
+    637,  // "ᄑ︁" 1111-FE01
+    4295, // "ᇂ︂" 11C2-FE02
+    1953, // "ᄍ︇" 110D-FE07
+    294,  // "ᄎ︀" 110E-FE00
+    2411, // "ᅳ︀" 1173-FE00
+    1055, // "ᄋ︃" 110B-FE03
+    1170, // "ᄁ︄" 1101-FE04
+    357,  // "ᄑ︀" 1111-FE00
+    3312, // "ᅤ︃" 1164-FE03
+    704,  // "ᄃ︂" 1103-FE02
+    3863, // "ᆭ︁" 11AD-FE01
+    912,  // "ᄀ︃" 1100-FE03
+    2272, // "ᅬ︀" 116C-FE00
+    567,  // "ᄌ︁" 110C-FE01
+    2791, // "ᅯ︁" 116F-FE01
+    2495, // "ᅢ︁" 1162-FE01
+    210,  // "ᄊ︀" 110A-FE00
+    4259, // "ᆾ︂" 11BE-FE02
+    4474, // "ᆹ︃" 11B9-FE03
+    3647, // "ᆰ︀" 11B0-FE00
+    4025, // "ᆿ︁" 11BF-FE01
+    834,  // "ᄍ︂" 110D-FE02
+    105,  // "ᄅ︀" 1105-FE00
+    21,   // "ᄁ︀" 1101-FE00
+    3157, // "ᅯ︂" 116F-FE02
+    3536, // "ᅳ︃" 1173-FE03
+    4133, // "ᆰ︂" 11B0-FE02
+    1727, // "ᄄ︆" 1104-FE06
+    3258, // "ᅡ︃" 1161-FE03
+    1606, // "ᄎ︅" 110E-FE05
+    1572, // "ᄌ︅" 110C-FE05
+    147,  // "ᄇ︀" 1107-FE00
+    743,  // "ᄆ︂" 1106-FE02
+    1016, // "ᄈ︃" 1108-FE03
+    2745, // "ᅬ︁" 116C-FE01
+    1258, // "ᄉ︄" 1109-FE04
+    4404, // "ᆲ︃" 11B2-FE03
+    2959, // "ᅢ︂" 1162-FE02
+    2887, // "ᅴ︁" 1174-FE01
+    3665, // "ᆲ︀" 11B2-FE00
+    4314, // "ᆩ︃" 11A9-FE03
+    3764, // "ᆽ︀" 11BD-FE00
+    1487, // "ᄇ︅" 1107-FE05
+    3872, // "ᆮ︁" 11AE-FE01
+    1269, // "ᄊ︄" 110A-FE04
+    4344, // "ᆬ︃" 11AC-FE03
+    1436, // "ᄄ︅" 1104-FE05
+    769,  // "ᄈ︂" 1108-FE02
+    126,  // "ᄆ︀" 1106-FE00
+    1862, // "ᄀ︇" 1100-FE07
+    3854, // "ᆬ︁" 11AC-FE01
+    581,  // "ᄍ︁" 110D-FE01
+    483,  // "ᄆ︁" 1106-FE01
+    3800, // "ᇁ︀" 11C1-FE00
+    63,   // "ᄃ︀" 1103-FE00
+    1640, // "ᄐ︅" 1110-FE05
+    2318, // "ᅯ︀" 116F-FE00
+    539,  // "ᄊ︁" 110A-FE01
+    3728, // "ᆹ︀" 11B9-FE00
+    1521, // "ᄉ︅" 1109-FE05
+    3980, // "ᆺ︁" 11BA-FE01
+    3348, // "ᅦ︃" 1166-FE03
+    2372, // "ᅱ︀" 1171-FE00
+    2022, // "ᅢ︀" 1162-FE00
+    1835, // "ᄐ︆" 1110-FE06
+    4454, // "ᆷ︃" 11B7-FE03
+    4178, // "ᆵ︂" 11B5-FE02
+    1897, // "ᄅ︇" 1105-FE07
+    4052, // "ᇂ︁" 11C2-FE01
+    84,   // "ᄄ︀" 1104-FE00
+    3971, // "ᆹ︁" 11B9-FE01
+    1589, // "ᄍ︅" 110D-FE05
+    189,  // "ᄉ︀" 1109-FE00
+    4277, // "ᇀ︂" 11C0-FE02
+    1159, // "ᄀ︄" 1100-FE04
+    990,  // "ᄆ︃" 1106-FE03
+    413,  // "ᄁ︁" 1101-FE01
+    4374, // "ᆯ︃" 11AF-FE03
+    4250, // "ᆽ︂" 11BD-FE02
+    3962, // "ᆸ︁" 11B8-FE01
+    1538, // "ᄊ︅" 110A-FE05
+    315,  // "ᄏ︀" 110F-FE00
+    1236, // "ᄇ︄" 1107-FE04
+    3240, // "ᅵ︂" 1175-FE02
+    1470, // "ᄆ︅" 1106-FE05
+    2718, // "ᅫ︁" 116B-FE01
+    2872, // "ᅲ︁" 1172-FE01
+    1623, // "ᄏ︅" 110F-FE05
+    2103, // "ᅥ︀" 1165-FE00
+    399,  // "ᄀ︁" 1100-FE01
+    3366, // "ᅧ︃" 1167-FE03
+    3692, // "ᆵ︀" 11B5-FE00
+    3935, // "ᆵ︁" 11B5-FE01
+    3755, // "ᆼ︀" 11BC-FE00
+    273,  // "ᄍ︀" 110D-FE00
+    3953, // "ᆷ︁" 11B7-FE01
+    525,  // "ᄉ︁" 1109-FE01
+    3926, // "ᆴ︁" 11B4-FE01
+    3090, // "ᅪ︂" 116A-FE02
+    4151, // "ᆲ︂" 11B2-FE02
+    1504, // "ᄈ︅" 1108-FE05
+    1368, // "ᄀ︅" 1100-FE05
+    1869, // "ᄁ︇" 1101-FE07
+    1700, // "ᄁ︆" 1101-FE06
+    1946, // "ᄌ︇" 110C-FE07
+    4364, // "ᆮ︃" 11AE-FE03
+    511,  // "ᄈ︁" 1108-FE01
+    427,  // "ᄂ︁" 1102-FE01
+    2576, // "ᅥ︁" 1165-FE01
+    886,  // "ᄑ︂" 1111-FE02
+    4088, // "ᆫ︂" 11AB-FE02
+    1094, // "ᄎ︃" 110E-FE03
+    2630, // "ᅧ︁" 1167-FE01
+    1988, // "ᄒ︇" 1112-FE07
+    2184, // "ᅨ︀" 1168-FE00
+    1974, // "ᄐ︇" 1110-FE07
+    4043, // "ᇁ︁" 11C1-FE01
+    3845, // "ᆫ︁" 11AB-FE01
+    3031, // "ᅦ︂" 1166-FE02
+    1346, // "ᄑ︄" 1111-FE04
+    3809, // "ᇂ︀" 11C2-FE00
+    1918, // "ᄈ︇" 1108-FE07
+    2845, // "ᅱ︁" 1171-FE01
+    4444, // "ᆶ︃" 11B6-FE03
+    1876, // "ᄂ︇" 1102-FE07
+    3791, // "ᇀ︀" 11C0-FE00
+    1932, // "ᄊ︇" 110A-FE07
+    2245, // "ᅫ︀" 116B-FE00
+    4034, // "ᇀ︁" 11C0-FE01
+    1817, // "ᄎ︆" 110E-FE06
+    1826, // "ᄏ︆" 110F-FE06
+    860,  // "ᄏ︂" 110F-FE02
+    1214, // "ᄅ︄" 1105-FE04
+    1781, // "ᄊ︆" 110A-FE06
+    4214, // "ᆹ︂" 11B9-FE02
+    847,  // "ᄎ︂" 110E-FE02
+    1225, // "ᄆ︄" 1106-FE04
+    4160, // "ᆳ︂" 11B3-FE02
+    4334, // "ᆫ︃" 11AB-FE03
+    1853, // "ᄒ︆" 1112-FE06
+    1904, // "ᄆ︇" 1106-FE07
+    3013, // "ᅥ︂" 1165-FE02
+    1402, // "ᄂ︅" 1102-FE05
+    821,  // "ᄌ︂" 110C-FE02
+    651,  // "ᄒ︁" 1112-FE01
+    3402, // "ᅩ︃" 1169-FE03
+    497,  // "ᄇ︁" 1107-FE01
+    1146, // "ᄒ︃" 1112-FE03
+    2130, // "ᅦ︀" 1166-FE00
+    3211, // "ᅲ︂" 1172-FE02
+    1029, // "ᄉ︃" 1109-FE03
+    3836, // "ᆪ︁" 11AA-FE01
+    2818, // "ᅰ︁" 1170-FE01
+    469,  // "ᄅ︁" 1105-FE01
+    1790, // "ᄋ︆" 110B-FE06
+    2603, // "ᅦ︁" 1166-FE01
+    3330, // "ᅥ︃" 1165-FE03
+    1967, // "ᄏ︇" 110F-FE07
+    1674, // "ᄒ︅" 1112-FE05
+    4097, // "ᆬ︂" 11AC-FE02
+    3899, // "ᆱ︁" 11B1-FE01
+    1555, // "ᄋ︅" 110B-FE05
+    977,  // "ᄅ︃" 1105-FE03
+    3461, // "ᅭ︃" 116D-FE03
+    4223, // "ᆺ︂" 11BA-FE02
+    3710, // "ᆷ︀" 11B7-FE00
+    3782, // "ᆿ︀" 11BF-FE00
+    4205, // "ᆸ︂" 11B8-FE02
+    4007, // "ᆽ︁" 11BD-FE01
+    4286, // "ᇁ︂" 11C1-FE02
+    3701, // "ᆶ︀" 11B6-FE00
+    3917, // "ᆳ︁" 11B3-FE01
+    609,  // "ᄏ︁" 110F-FE01
+    964,  // "ᄄ︃" 1104-FE03
+    3611, // "ᆬ︀" 11AC-FE00
+    1419, // "ᄃ︅" 1103-FE05
+    3602, // "ᆫ︀" 11AB-FE00
+    2549, // "ᅤ︁" 1164-FE01
+    4142, // "ᆱ︂" 11B1-FE02
+    4524, // "ᆾ︃" 11BE-FE03
+    3528, // "ᅲ︃" 1172-FE03
+    1120, // "ᄐ︃" 1110-FE03
+    2779, // "ᅮ︁" 116E-FE01
+    2049, // "ᅣ︀" 1163-FE00
+    2772, // "ᅭ︁" 116D-FE01
+    1291, // "ᄌ︄" 110C-FE04
+    1302, // "ᄍ︄" 110D-FE04
+    4187, // "ᆶ︂" 11B6-FE02
+    3407, // "ᅪ︃" 116A-FE03
+    3425, // "ᅫ︃" 116B-FE03
+    4106, // "ᆭ︂" 11AD-FE02
+    623,  // "ᄐ︁" 1110-FE01
+    2414, // "ᅴ︀" 1174-FE00
+    4169, // "ᆴ︂" 11B4-FE02
+    3827, // "ᆩ︁" 11A9-FE01
+    4304, // "ᆨ︃" 11A8-FE03
+    4079, // "ᆪ︂" 11AA-FE02
+    4494, // "ᆻ︃" 11BB-FE03
+    2211, // "ᅩ︀" 1169-FE00
+    3908, // "ᆲ︁" 11B2-FE01
+    336,  // "ᄐ︀" 1110-FE00
+    1313, // "ᄎ︄" 110E-FE04
+    4241, // "ᆼ︂" 11BC-FE02
+    4504, // "ᆼ︃" 11BC-FE03
+    1772, // "ᄉ︆" 1109-FE06
+    1995, // "ᅡ︀" 1161-FE00
+    3149, // "ᅮ︂" 116E-FE02
+    2691, // "ᅪ︁" 116A-FE01
+    3593, // "ᆪ︀" 11AA-FE00
+    3575, // "ᆨ︀" 11A8-FE00
+    4232, // "ᆻ︂" 11BB-FE02
+    756,  // "ᄇ︂" 1107-FE02
+    3719, // "ᆸ︀" 11B8-FE00
+    4563, // "ᇂ︃" 11C2-FE03
+    2441, // "ᅵ︀" 1175-FE00
+    899,  // "ᄒ︂" 1112-FE02
+    4070, // "ᆩ︂" 11A9-FE02
+    4124, // "ᆯ︂" 11AF-FE02
+    1247, // "ᄈ︄" 1108-FE04
+    1068, // "ᄌ︃" 110C-FE03
+    4115, // "ᆮ︂" 11AE-FE02
+    378,  // "ᄒ︀" 1112-FE00
+    951,  // "ᄃ︃" 1103-FE03
+    3144, // "ᅭ︂" 116D-FE02
+    2522, // "ᅣ︁" 1163-FE01
+    3126, // "ᅬ︂" 116C-FE02
+    3193, // "ᅱ︂" 1171-FE02
+    2345, // "ᅰ︀" 1170-FE00
+    252,  // "ᄌ︀" 110C-FE00
+    553,  // "ᄋ︁" 110B-FE01
+    4414, // "ᆳ︃" 11B3-FE03
+    1939, // "ᄋ︇" 110B-FE07
+    3510, // "ᅱ︃" 1171-FE03
+    1081, // "ᄍ︃" 110D-FE03
+    3222, // "ᅴ︂" 1174-FE02
+    1736, // "ᄅ︆" 1105-FE06
+    4016, // "ᆾ︁" 11BE-FE01
+    3656, // "ᆱ︀" 11B1-FE00
+    2941, // "ᅡ︂" 1161-FE02
+    3683, // "ᆴ︀" 11B4-FE00
+    1981, // "ᄑ︇" 1111-FE07
+    1280, // "ᄋ︄" 110B-FE04
+    3384, // "ᅨ︃" 1168-FE03
+    3466, // "ᅮ︃" 116E-FE03
+    1911, // "ᄇ︇" 1107-FE07
+    665,  // "ᄀ︂" 1100-FE02
+    2399, // "ᅲ︀" 1172-FE00
+    2468, // "ᅡ︁" 1161-FE01
+    2076, // "ᅤ︀" 1164-FE00
+    3108, // "ᅫ︂" 116B-FE02
+    4464, // "ᆸ︃" 11B8-FE03
+    1718, // "ᄃ︆" 1103-FE06
+    3175, // "ᅰ︂" 1170-FE02
+    691,  // "ᄂ︂" 1102-FE02
+    3890, // "ᆰ︁" 11B0-FE01
+    3443, // "ᅬ︃" 116C-FE03
+    4324, // "ᆪ︃" 11AA-FE03
+    2995, // "ᅤ︂" 1164-FE02
+    4424, // "ᆴ︃" 11B4-FE03
+    2306, // "ᅮ︀" 116E-FE00
+    3629, // "ᆮ︀" 11AE-FE00
+    1192, // "ᄃ︄" 1103-FE04
+    1107, // "ᄏ︃" 110F-FE03
+    3638, // "ᆯ︀" 11AF-FE00
+    3492, // "ᅰ︃" 1170-FE03
+    1657, // "ᄑ︅" 1111-FE05
+    168,  // "ᄈ︀" 1108-FE00
+    3746, // "ᆻ︀" 11BB-FE00
+    3818, // "ᆨ︁" 11A8-FE01
+    3049, // "ᅧ︂" 1167-FE02
+    2218, // "ᅪ︀" 116A-FE00
+    3067, // "ᅨ︂" 1168-FE02
+    1385, // "ᄁ︅" 1101-FE05
+    3276, // "ᅢ︃" 1162-FE03
+    1745, // "ᄆ︆" 1106-FE06
+    4354, // "ᆭ︃" 11AD-FE03
+    1754, // "ᄇ︆" 1107-FE06
+    595,  // "ᄎ︁" 110E-FE01
+    1335, // "ᄐ︄" 1110-FE04
+    925,  // "ᄁ︃" 1101-FE03
+    42,   // "ᄂ︀" 1102-FE00
+    938,  // "ᄂ︃" 1102-FE03
+    3989, // "ᆻ︁" 11BB-FE01
+    3085, // "ᅩ︂" 1169-FE02
+    4268, // "ᆿ︂" 11BF-FE02
+    1003, // "ᄇ︃" 1107-FE03
+    3881, // "ᆯ︁" 11AF-FE01
+    3674, // "ᆳ︀" 11B3-FE00
+    1181, // "ᄂ︄" 1102-FE04
+    717,  // "ᄄ︂" 1104-FE02
+    4061, // "ᆨ︂" 11A8-FE02
+    1133, // "ᄑ︃" 1111-FE03
+    3773, // "ᆾ︀" 11BE-FE00
+    3944, // "ᆶ︁" 11B6-FE01
+    2657, // "ᅨ︁" 1168-FE01
+    3737, // "ᆺ︀" 11BA-FE00
+    795,  // "ᄊ︂" 110A-FE02
+    2977, // "ᅣ︂" 1163-FE02
+    3584, // "ᆩ︀" 11A9-FE00
+    1691, // "ᄀ︆" 1100-FE06
+    3620, // "ᆭ︀" 11AD-FE00
+    1763, // "ᄈ︆" 1108-FE06
+    1890, // "ᄄ︇" 1104-FE07
+    4553, // "ᇁ︃" 11C1-FE03
+    3474, // "ᅯ︃" 116F-FE03
+    2684, // "ᅩ︁" 1169-FE01
+    1799, // "ᄌ︆" 110C-FE06
+    0,    // "ᄀ︀" 1100-FE00
+    1960, // "ᄎ︇" 110E-FE07
+    3998, // "ᆼ︁" 11BC-FE01
+    4384, // "ᆰ︃" 11B0-FE03
+    4484, // "ᆺ︃" 11BA-FE03
+    3557, // "ᅵ︃" 1175-FE03
+    808,  // "ᄋ︂" 110B-FE02
+];
+
+/// Index blocks in the order that get_blit_pattern_offset() checks them
+pub static BLOCKS: [super::Block; 1] = [
+    super::Block {
+        low: 0x1100,
+        high: 0x11FF,
+        cluster_lengths: &[2],
+        codepoints_low: 0x1100,
+        codepoints: &[],
+        hashes: &HASH_HANGUL_JAMO,
+        keys: &KEY_HANGUL_JAMO,
+        offsets: &OFFSET_HANGUL_JAMO,
+    },
+];
+
+/// Packed glyph pattern data.
+/// Record format:
+///  [offset+0]: ((w as u8) << 16) | ((h as u8) << 8) | (yOffset as u8)
+///  [offset+1..=ceil(w*h/32)]: packed 1-bit pixels; 0=clear, 1=set
+/// Pixels are packed in top to bottom, left to right order with MSB of first
+/// pixel word containing the top left pixel.
+///  w: Width of pattern in pixels
+///  h: Height of pattern in pixels
+///  yOffset: Vertical offset (pixels downward from top of line) to position
+///     glyph pattern properly relative to text baseline
+pub static DATA: [u32; 4573] = [
+    // [0]: 1100-FE00 "ᄀ︀" 1100-FE00
+    0x00201406, 0x00007ff8, 0x00007ff8, 0x00006000, 0x00006000, 0x00006000, 0x00006000, 0x00006000,
+    0x00006000, 0x00006000, 0x00006000, 0x00006000, 0x00006000, 0x00006000, 0x00006000, 0x00006000,
+    0x00006000, 0x00006000, 0x00006000, 0x00006000, 0x00006000,
+    // [21]: 1101-FE00 "ᄁ︀" 1101-FE00
+    0x00201406, 0x00007ef8, 0x00007ef8, 0x00004080, 0x00004080, 0x00004080, 0x00004080, 0x00004080,
+    0x00004080, 0x00004080, 0x00004080, 0x00004080, 0x00004080, 0x00004080, 0x00004080, 0x00004080,
+    0x00004080, 0x00004080, 0x00004080, 0x00004080, 0x00004080,
+    // [42]: 1102-FE00 "ᄂ︀" 1102-FE00
+    0x00201406, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018,
+    0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018,
+    0x00000018, 0x00000018, 0x00000018, 0x00007ff8, 0x00007ff8,
+    // [63]: 1103-FE00 "ᄃ︀" 1103-FE00
+    0x00201406, 0x00007ff8, 0x00007ff8, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018,
+    0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018,
+    0x00000018, 0x00000018, 0x00000018, 0x00007ff8, 0x00007ff8,
+    // [84]: 1104-FE00 "ᄄ︀" 1104-FE00
+    0x00201406, 0x00007ef8, 0x00007ef8, 0x00000208, 0x00000208, 0x00000208, 0x00000208, 0x00000208,
+    0x00000208, 0x00000208, 0x00000208, 0x00000208, 0x00000208, 0x00000208, 0x00000208, 0x00000208,
+    0x00000208, 0x00000208, 0x00000208, 0x00007ef8, 0x00007ef8,
+    // [105]: 1105-FE00 "ᄅ︀" 1105-FE00
+    0x00201406, 0x00007ff8, 0x00007ff8, 0x00006000, 0x00006000, 0x00006000, 0x00006000, 0x00006000,
+    0x00006000, 0x00006000, 0x00007ff8, 0x00007ff8, 0x00000018, 0x00000018, 0x00000018, 0x00000018,
+    0x00000018, 0x00000018, 0x00000018, 0x00007ff8, 0x00007ff8,
+    // [126]: 1106-FE00 "ᄆ︀" 1106-FE00
+    0x00201406, 0x00007ff8, 0x00007ff8, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018,
+    0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018,
+    0x00006018, 0x00006018, 0x00006018, 0x00007ff8, 0x00007ff8,
+    // [147]: 1107-FE00 "ᄇ︀" 1107-FE00
+    0x00201406, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018,
+    0x00006018, 0x00006018, 0x00007ff8, 0x00007ff8, 0x00006018, 0x00006018, 0x00006018, 0x00006018,
+    0x00006018, 0x00006018, 0x00006018, 0x00007ff8, 0x00007ff8,
+    // [168]: 1108-FE00 "ᄈ︀" 1108-FE00
+    0x00201406, 0x00004288, 0x00004288, 0x00004288, 0x00004288, 0x00004288, 0x00004288, 0x00004288,
+    0x00004288, 0x00004288, 0x00007ef8, 0x00007ef8, 0x00004288, 0x00004288, 0x00004288, 0x00004288,
+    0x00004288, 0x00004288, 0x00004288, 0x00007ef8, 0x00007ef8,
+    // [189]: 1109-FE00 "ᄉ︀" 1109-FE00
+    0x00201406, 0x00000600, 0x00000600, 0x00000700, 0x00000300, 0x00000300, 0x00000380, 0x00000180,
+    0x00000380, 0x000003c0, 0x000006c0, 0x000006c0, 0x00000ee0, 0x00000c60, 0x00001c60, 0x00001870,
+    0x00003830, 0x00003030, 0x00003038, 0x00006018, 0x00006018,
+    // [210]: 110A-FE00 "ᄊ︀" 110A-FE00
+    0x00201406, 0x00001020, 0x00001020, 0x00001020, 0x00001820, 0x00001820, 0x00000830, 0x00000810,
+    0x00000830, 0x00000830, 0x00001c30, 0x00001c70, 0x00001450, 0x00001450, 0x00003450, 0x00002458,
+    0x00002648, 0x000026c8, 0x00006288, 0x00004288, 0x00004288,
+    // [231]: 110B-FE00 "ᄋ︀" 110B-FE00
+    0x00201406, 0x00000780, 0x00000fc0, 0x00001860, 0x00003870, 0x00003030, 0x00003030, 0x00006018,
+    0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00003030,
+    0x00003030, 0x00003870, 0x00001860, 0x00000fc0, 0x00000780,
+    // [252]: 110C-FE00 "ᄌ︀" 110C-FE00
+    0x00201406, 0x00007ff8, 0x00007ff8, 0x00000700, 0x00000300, 0x00000300, 0x00000380, 0x00000180,
+    0x00000380, 0x000003c0, 0x000006c0, 0x000006c0, 0x00000ee0, 0x00000c60, 0x00001c60, 0x00001870,
+    0x00003830, 0x00003030, 0x00003038, 0x00006018, 0x00006018,
+    // [273]: 110D-FE00 "ᄍ︀" 110D-FE00
+    0x00201406, 0x00007ef8, 0x00007ef8, 0x00001020, 0x00001820, 0x00001820, 0x00000830, 0x00000810,
+    0x00000830, 0x00000830, 0x00001c30, 0x00001c70, 0x00001450, 0x00001450, 0x00003450, 0x00002458,
+    0x00002648, 0x000026c8, 0x00006288, 0x00004288, 0x00004288,
+    // [294]: 110E-FE00 "ᄎ︀" 110E-FE00
+    0x00201406, 0x00000300, 0x00000300, 0x00000000, 0x00000000, 0x00007ff8, 0x00007ff8, 0x00000300,
+    0x00000300, 0x00000380, 0x00000180, 0x00000380, 0x000003c0, 0x000006c0, 0x00000e60, 0x00000c60,
+    0x00001870, 0x00003830, 0x00003030, 0x00006018, 0x00006018,
+    // [315]: 110F-FE00 "ᄏ︀" 110F-FE00
+    0x00201406, 0x00007ff8, 0x00007ff8, 0x00006000, 0x00006000, 0x00006000, 0x00006000, 0x00006000,
+    0x00006000, 0x00006000, 0x00007ff8, 0x00007ff8, 0x00006000, 0x00006000, 0x00006000, 0x00006000,
+    0x00006000, 0x00006000, 0x00006000, 0x00006000, 0x00006000,
+    // [336]: 1110-FE00 "ᄐ︀" 1110-FE00
+    0x00201406, 0x00007ff8, 0x00007ff8, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018,
+    0x00000018, 0x00000018, 0x00007ff8, 0x00007ff8, 0x00000018, 0x00000018, 0x00000018, 0x00000018,
+    0x00000018, 0x00000018, 0x00000018, 0x00007ff8, 0x00007ff8,
+    // [357]: 1111-FE00 "ᄑ︀" 1111-FE00
+    0x00201406, 0x00007ff8, 0x00007ff8, 0x00001860, 0x00001860, 0x00001860, 0x00001860, 0x00001860,
+    0x00001860, 0x00001860, 0x00001860, 0x00001860, 0x00001860, 0x00001860, 0x00001860, 0x00001860,
+    0x00001860, 0x00001860, 0x00001860, 0x00007ff8, 0x00007ff8,
+    // [378]: 1112-FE00 "ᄒ︀" 1112-FE00
+    0x00201406, 0x00000300, 0x00000300, 0x00000000, 0x00000000, 0x00007ff8, 0x00007ff8, 0x00000000,
+    0x00000000, 0x00000000, 0x00000780, 0x00000fc0, 0x00001860, 0x00003870, 0x00003030, 0x00003030,
+    0x00003030, 0x00001860, 0x00001860, 0x00000fc0, 0x00000780,
+    // [399]: 1100-FE01 "ᄀ︁" 1100-FE01
+    0x00200d03, 0x03ffffe0, 0x03ffffe0, 0x03000000, 0x03000000, 0x03000000, 0x03000000, 0x03000000,
+    0x03000000, 0x03000000, 0x03000000, 0x03000000, 0x03000000, 0x03000000,
+    // [413]: 1101-FE01 "ᄁ︁" 1101-FE01
+    0x00200d03, 0x03ff3fe0, 0x03ff3fe0, 0x03003000, 0x03003000, 0x03003000, 0x03003000, 0x03003000,
+    0x03003000, 0x03003000, 0x03003000, 0x03003000, 0x03003000, 0x03003000,
+    // [427]: 1102-FE01 "ᄂ︁" 1102-FE01
+    0x00200d03, 0x00000060, 0x00000060, 0x00000060, 0x00000060, 0x00000060, 0x00000060, 0x00000060,
+    0x00000060, 0x00000060, 0x00000060, 0x00000060, 0x03ffffe0, 0x03ffffe0,
+    // [441]: 1103-FE01 "ᄃ︁" 1103-FE01
+    0x00200d03, 0x03ffffe0, 0x03ffffe0, 0x00000060, 0x00000060, 0x00000060, 0x00000060, 0x00000060,
+    0x00000060, 0x00000060, 0x00000060, 0x00000060, 0x03ffffe0, 0x03ffffe0,
+    // [455]: 1104-FE01 "ᄄ︁" 1104-FE01
+    0x00200d03, 0x03ff3fe0, 0x03ff3fe0, 0x00030060, 0x00030060, 0x00030060, 0x00030060, 0x00030060,
+    0x00030060, 0x00030060, 0x00030060, 0x00030060, 0x03ff3fe0, 0x03ff3fe0,
+    // [469]: 1105-FE01 "ᄅ︁" 1105-FE01
+    0x00200d03, 0x03ffffe0, 0x03ffffe0, 0x03000000, 0x03000000, 0x03000000, 0x03000000, 0x03ffffe0,
+    0x03ffffe0, 0x00000060, 0x00000060, 0x00000060, 0x03ffffe0, 0x03ffffe0,
+    // [483]: 1106-FE01 "ᄆ︁" 1106-FE01
+    0x00200d03, 0x03ffffe0, 0x03ffffe0, 0x03000060, 0x03000060, 0x03000060, 0x03000060, 0x03000060,
+    0x03000060, 0x03000060, 0x03000060, 0x03000060, 0x03ffffe0, 0x03ffffe0,
+    // [497]: 1107-FE01 "ᄇ︁" 1107-FE01
+    0x00200d03, 0x03000060, 0x03000060, 0x03000060, 0x03000060, 0x03000060, 0x03000060, 0x03ffffe0,
+    0x03ffffe0, 0x03000060, 0x03000060, 0x03000060, 0x03ffffe0, 0x03ffffe0,
+    // [511]: 1108-FE01 "ᄈ︁" 1108-FE01
+    0x00200d03, 0x03033060, 0x03033060, 0x03033060, 0x03033060, 0x03033060, 0x03033060, 0x03ff3fe0,
+    0x03ff3fe0, 0x03033060, 0x03033060, 0x03033060, 0x03ff3fe0, 0x03ff3fe0,
+    // [525]: 1109-FE01 "ᄉ︁" 1109-FE01
+    0x00200d03, 0x00018000, 0x0001c000, 0x0000e000, 0x00007000, 0x0001b800, 0x00039800, 0x000f0c00,
+    0x001c0600, 0x00380700, 0x00700380, 0x01e001c0, 0x038000e0, 0x03000060,
+    // [539]: 110A-FE01 "ᄊ︁" 110A-FE01
+    0x00200d03, 0x00300600, 0x00300600, 0x00380700, 0x00180300, 0x00380700, 0x003c0780, 0x006c0d80,
+    0x00ec0d80, 0x00c61cc0, 0x01c618c0, 0x018718e0, 0x03033060, 0x03033060,
+    // [553]: 110B-FE01 "ᄋ︁" 110B-FE01
+    0x00200d03, 0x000ff800, 0x003ffe00, 0x00f00780, 0x01c001c0, 0x018000c0, 0x03000060, 0x03000060,
+    0x03000060, 0x018000c0, 0x01c001c0, 0x00f00780, 0x003ffe00, 0x000ff800,
+    // [567]: 110C-FE01 "ᄌ︁" 110C-FE01
+    0x00200d03, 0x03ffffe0, 0x03ffffe0, 0x0000e000, 0x00007000, 0x0001b800, 0x00039800, 0x000f0c00,
+    0x001c0600, 0x00380700, 0x00700380, 0x01e001c0, 0x038000e0, 0x03000060,
+    // [581]: 110D-FE01 "ᄍ︁" 110D-FE01
+    0x00200d03, 0x03ff3fe0, 0x03ff3fe0, 0x00380700, 0x00180300, 0x00380700, 0x003c0780, 0x006c0d80,
+    0x00ec0d80, 0x00c61cc0, 0x01c618c0, 0x018718e0, 0x03033060, 0x03033060,
+    // [595]: 110E-FE01 "ᄎ︁" 110E-FE01
+    0x00200d03, 0x00018000, 0x00018000, 0x00000000, 0x00000000, 0x03ffffe0, 0x03ffffe0, 0x0000f000,
+    0x0001b800, 0x000f9e00, 0x003e0700, 0x00f803c0, 0x03e000e0, 0x03000060,
+    // [609]: 110F-FE01 "ᄏ︁" 110F-FE01
+    0x00200d03, 0x03ffffe0, 0x03ffffe0, 0x03000000, 0x03000000, 0x03000000, 0x03000000, 0x03ffffe0,
+    0x03ffffe0, 0x03000000, 0x03000000, 0x03000000, 0x03000000, 0x03000000,
+    // [623]: 1110-FE01 "ᄐ︁" 1110-FE01
+    0x00200d03, 0x03ffffe0, 0x03ffffe0, 0x00000060, 0x00000060, 0x00000060, 0x00000060, 0x03ffffe0,
+    0x03ffffe0, 0x00000060, 0x00000060, 0x00000060, 0x03ffffe0, 0x03ffffe0,
+    // [637]: 1111-FE01 "ᄑ︁" 1111-FE01
+    0x00200d03, 0x03ffffe0, 0x03ffffe0, 0x00180c00, 0x00180c00, 0x00180c00, 0x00180c00, 0x00180c00,
+    0x00180c00, 0x00180c00, 0x00180c00, 0x00180c00, 0x03ffffe0, 0x03ffffe0,
+    // [651]: 1112-FE01 "ᄒ︁" 1112-FE01
+    0x00200d03, 0x00018000, 0x00018000, 0x00000000, 0x00000000, 0x03ffffe0, 0x03ffffe0, 0x00000000,
+    0x001ffc00, 0x007fff00, 0x00c00180, 0x00e00380, 0x007fff00, 0x000ff800,
+    // [665]: 1100-FE02 "ᄀ︂" 1100-FE02
+    0x00200c03, 0x03ffffe0, 0x03ffffe0, 0x03000000, 0x03000000, 0x03000000, 0x03000000, 0x03000000,
+    0x03000000, 0x03000000, 0x03000000, 0x03000000, 0x03000000,
+    // [678]: 1101-FE02 "ᄁ︂" 1101-FE02
+    0x00200c03, 0x03ff3fe0, 0x03ff3fe0, 0x03003000, 0x03003000, 0x03003000, 0x03003000, 0x03003000,
+    0x03003000, 0x03003000, 0x03003000, 0x03003000, 0x03003000,
+    // [691]: 1102-FE02 "ᄂ︂" 1102-FE02
+    0x00200c03, 0x00000060, 0x00000060, 0x00000060, 0x00000060, 0x00000060, 0x00000060, 0x00000060,
+    0x00000060, 0x00000060, 0x00000060, 0x03ffffe0, 0x03ffffe0,
+    // [704]: 1103-FE02 "ᄃ︂" 1103-FE02
+    0x00200c03, 0x03ffffe0, 0x03ffffe0, 0x00000060, 0x00000060, 0x00000060, 0x00000060, 0x00000060,
+    0x00000060, 0x00000060, 0x00000060, 0x03ffffe0, 0x03ffffe0,
+    // [717]: 1104-FE02 "ᄄ︂" 1104-FE02
+    0x00200c03, 0x03ff3fe0, 0x03ff3fe0, 0x00030060, 0x00030060, 0x00030060, 0x00030060, 0x00030060,
+    0x00030060, 0x00030060, 0x00030060, 0x03ff3fe0, 0x03ff3fe0,
+    // [730]: 1105-FE02 "ᄅ︂" 1105-FE02
+    0x00200c03, 0x03ffffe0, 0x03ffffe0, 0x03000000, 0x03000000, 0x03000000, 0x03ffffe0, 0x03ffffe0,
+    0x00000060, 0x00000060, 0x00000060, 0x03ffffe0, 0x03ffffe0,
+    // [743]: 1106-FE02 "ᄆ︂" 1106-FE02
+    0x00200c03, 0x03ffffe0, 0x03ffffe0, 0x03000060, 0x03000060, 0x03000060, 0x03000060, 0x03000060,
+    0x03000060, 0x03000060, 0x03000060, 0x03ffffe0, 0x03ffffe0,
+    // [756]: 1107-FE02 "ᄇ︂" 1107-FE02
+    0x00200c03, 0x03000060, 0x03000060, 0x03000060, 0x03000060, 0x03000060, 0x03ffffe0, 0x03ffffe0,
+    0x03000060, 0x03000060, 0x03000060, 0x03ffffe0, 0x03ffffe0,
+    // [769]: 1108-FE02 "ᄈ︂" 1108-FE02
+    0x00200c03, 0x03033060, 0x03033060, 0x03033060, 0x03033060, 0x03033060, 0x03ff3fe0, 0x03ff3fe0,
+    0x03033060, 0x03033060, 0x03033060, 0x03ff3fe0, 0x03ff3fe0,
+    // [782]: 1109-FE02 "ᄉ︂" 1109-FE02
+    0x00200c03, 0x00018000, 0x0001c000, 0x0000e000, 0x00007000, 0x0001b800, 0x00079c00, 0x000f0e00,
+    0x003c0700, 0x00780380, 0x01e001c0, 0x03c000e0, 0x03000060,
+    // [795]: 110A-FE02 "ᄊ︂" 110A-FE02
+    0x00200c03, 0x00300600, 0x00300600, 0x00180300, 0x00180300, 0x00380700, 0x007c0780, 0x006c0d80,
+    0x00e60cc0, 0x01c618c0, 0x018618c0, 0x03833060, 0x03033060,
+    // [808]: 110B-FE02 "ᄋ︂" 110B-FE02
+    0x00200c03, 0x000ff800, 0x003ffe00, 0x00f00780, 0x01c001c0, 0x03000060, 0x03000060, 0x03000060,
+    0x03000060, 0x01c001c0, 0x00f00780, 0x003ffe00, 0x000ff800,
+    // [821]: 110C-FE02 "ᄌ︂" 110C-FE02
+    0x00200c03, 0x03ffffe0, 0x03ffffe0, 0x0000e000, 0x00007000, 0x0001b800, 0x00079c00, 0x000f0e00,
+    0x003c0700, 0x00780380, 0x01e001c0, 0x03c000e0, 0x03000060,
+    // [834]: 110D-FE02 "ᄍ︂" 110D-FE02
+    0x00200c03, 0x03ff3fe0, 0x03ff3fe0, 0x00180300, 0x00180300, 0x00380700, 0x007c0780, 0x006c0d80,
+    0x00e60cc0, 0x01c618c0, 0x018618c0, 0x03833060, 0x03033060,
+    // [847]: 110E-FE02 "ᄎ︂" 110E-FE02
+    0x00200c03, 0x00018000, 0x00018000, 0x00000000, 0x00000000, 0x03ffffe0, 0x03ffffe0, 0x0001f000,
+    0x000fbc00, 0x003e0f00, 0x00f80380, 0x03e001e0, 0x03000060,
+    // [860]: 110F-FE02 "ᄏ︂" 110F-FE02
+    0x00200c03, 0x03ffffe0, 0x03ffffe0, 0x03000000, 0x03000000, 0x03000000, 0x03ffffe0, 0x03ffffe0,
+    0x03000000, 0x03000000, 0x03000000, 0x03000000, 0x03000000,
+    // [873]: 1110-FE02 "ᄐ︂" 1110-FE02
+    0x00200c03, 0x03ffffe0, 0x03ffffe0, 0x00000060, 0x00000060, 0x00000060, 0x03ffffe0, 0x03ffffe0,
+    0x00000060, 0x00000060, 0x00000060, 0x03ffffe0, 0x03ffffe0,
+    // [886]: 1111-FE02 "ᄑ︂" 1111-FE02
+    0x00200c03, 0x03ffffe0, 0x03ffffe0, 0x00180c00, 0x00180c00, 0x00180c00, 0x00180c00, 0x00180c00,
+    0x00180c00, 0x00180c00, 0x00180c00, 0x03ffffe0, 0x03ffffe0,
+    // [899]: 1112-FE02 "ᄒ︂" 1112-FE02
+    0x00200c03, 0x00018000, 0x00018000, 0x00000000, 0x00000000, 0x03ffffe0, 0x03ffffe0, 0x0003e000,
+    0x003ffe00, 0x00f00780, 0x00c00180, 0x007fff00, 0x000ff800,
+    // [912]: 1100-FE03 "ᄀ︃" 1100-FE03
+    0x00200c03, 0x0001fff8, 0x0001fff8, 0x00018000, 0x00018000, 0x00018000, 0x00018000, 0x00018000,
+    0x00018000, 0x00018000, 0x00018000, 0x00018000, 0x00018000,
+    // [925]: 1101-FE03 "ᄁ︃" 1101-FE03
+    0x00200c03, 0x0001f9f8, 0x0001f9f8, 0x00010100, 0x00010100, 0x00010100, 0x00010100, 0x00010100,
+    0x00010100, 0x00010100, 0x00010100, 0x00010100, 0x00010100,
+    // [938]: 1102-FE03 "ᄂ︃" 1102-FE03
+    0x00200c03, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018,
+    0x00000018, 0x00000018, 0x00000018, 0x0001fff8, 0x0001fff8,
+    // [951]: 1103-FE03 "ᄃ︃" 1103-FE03
+    0x00200c03, 0x0001fff8, 0x0001fff8, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018,
+    0x00000018, 0x00000018, 0x00000018, 0x0001fff8, 0x0001fff8,
+    // [964]: 1104-FE03 "ᄄ︃" 1104-FE03
+    0x00200c03, 0x0001f9f8, 0x0001f9f8, 0x00000808, 0x00000808, 0x00000808, 0x00000808, 0x00000808,
+    0x00000808, 0x00000808, 0x00000808, 0x0001f9f8, 0x0001f9f8,
+    // [977]: 1105-FE03 "ᄅ︃" 1105-FE03
+    0x00200c03, 0x0001fff8, 0x0001fff8, 0x00018000, 0x00018000, 0x00018000, 0x0001fff8, 0x0001fff8,
+    0x00000018, 0x00000018, 0x00000018, 0x0001fff8, 0x0001fff8,
+    // [990]: 1106-FE03 "ᄆ︃" 1106-FE03
+    0x00200c03, 0x0001fff8, 0x0001fff8, 0x00018018, 0x00018018, 0x00018018, 0x00018018, 0x00018018,
+    0x00018018, 0x00018018, 0x00018018, 0x0001fff8, 0x0001fff8,
+    // [1003]: 1107-FE03 "ᄇ︃" 1107-FE03
+    0x00200c03, 0x00018018, 0x00018018, 0x00018018, 0x00018018, 0x00018018, 0x0001fff8, 0x0001fff8,
+    0x00018018, 0x00018018, 0x00018018, 0x0001fff8, 0x0001fff8,
+    // [1016]: 1108-FE03 "ᄈ︃" 1108-FE03
+    0x00200c03, 0x00010908, 0x00010908, 0x00010908, 0x00010908, 0x00010908, 0x0001f9f8, 0x0001f9f8,
+    0x00010908, 0x00010908, 0x00010908, 0x0001f9f8, 0x0001f9f8,
+    // [1029]: 1109-FE03 "ᄉ︃" 1109-FE03
+    0x00200c03, 0x00000c00, 0x00000e00, 0x00000600, 0x00000300, 0x00000780, 0x00000f80, 0x00001cc0,
+    0x000038e0, 0x00007060, 0x0000e030, 0x0001c038, 0x00018018,
+    // [1042]: 110A-FE03 "ᄊ︃" 110A-FE03
+    0x00200c03, 0x00004040, 0x00004040, 0x00006060, 0x00002020, 0x00002020, 0x00002020, 0x00005050,
+    0x00005050, 0x00009090, 0x00009898, 0x00010908, 0x00010908,
+    // [1055]: 110B-FE03 "ᄋ︃" 110B-FE03
+    0x00200c03, 0x00001f80, 0x00007fe0, 0x0000e070, 0x0000c030, 0x00018018, 0x00018018, 0x00018018,
+    0x00018018, 0x0000c030, 0x0000e070, 0x00007fe0, 0x00001f80,
+    // [1068]: 110C-FE03 "ᄌ︃" 110C-FE03
+    0x00200c03, 0x0001fff8, 0x0001fff8, 0x00000600, 0x00000300, 0x00000780, 0x00000f80, 0x00001cc0,
+    0x000038e0, 0x00007060, 0x0000e030, 0x0001c038, 0x00018018,
+    // [1081]: 110D-FE03 "ᄍ︃" 110D-FE03
+    0x00200c03, 0x0001f9f8, 0x0001f9f8, 0x00006060, 0x00002020, 0x00002020, 0x00002020, 0x00005050,
+    0x00005050, 0x00009090, 0x00009898, 0x00010908, 0x00010908,
+    // [1094]: 110E-FE03 "ᄎ︃" 110E-FE03
+    0x00200c03, 0x00000600, 0x00000600, 0x00000000, 0x00000000, 0x0001fff8, 0x0001fff8, 0x00000700,
+    0x00001f80, 0x00003ce0, 0x0000f070, 0x0001e038, 0x00018018,
+    // [1107]: 110F-FE03 "ᄏ︃" 110F-FE03
+    0x00200c03, 0x0001fff8, 0x0001fff8, 0x00018000, 0x00018000, 0x00018000, 0x0001fff8, 0x0001fff8,
+    0x00018000, 0x00018000, 0x00018000, 0x00018000, 0x00018000,
+    // [1120]: 1110-FE03 "ᄐ︃" 1110-FE03
+    0x00200c03, 0x0001fff8, 0x0001fff8, 0x00000018, 0x00000018, 0x00000018, 0x0001fff8, 0x0001fff8,
+    0x00000018, 0x00000018, 0x00000018, 0x0001fff8, 0x0001fff8,
+    // [1133]: 1111-FE03 "ᄑ︃" 1111-FE03
+    0x00200c03, 0x0001fff8, 0x0001fff8, 0x000030c0, 0x000030c0, 0x000030c0, 0x000030c0, 0x000030c0,
+    0x000030c0, 0x000030c0, 0x000030c0, 0x0001fff8, 0x0001fff8,
+    // [1146]: 1112-FE03 "ᄒ︃" 1112-FE03
+    0x00200c03, 0x00000600, 0x00000600, 0x00000000, 0x00000000, 0x0001fff8, 0x0001fff8, 0x00000600,
+    0x00003fc0, 0x000070e0, 0x0000e070, 0x00007fe0, 0x00001f80,
+    // [1159]: 1100-FE04 "ᄀ︄" 1100-FE04
+    0x00200a03, 0x0001fff8, 0x0001fff8, 0x00018000, 0x00018000, 0x00018000, 0x00018000, 0x00018000,
+    0x00018000, 0x00018000, 0x00018000,
+    // [1170]: 1101-FE04 "ᄁ︄" 1101-FE04
+    0x00200a03, 0x0001f9f8, 0x0001f9f8, 0x00010100, 0x00010100, 0x00010100, 0x00010100, 0x00010100,
+    0x00010100, 0x00010100, 0x00010100,
+    // [1181]: 1102-FE04 "ᄂ︄" 1102-FE04
+    0x00200a03, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018,
+    0x00000018, 0x0001fff8, 0x0001fff8,
+    // [1192]: 1103-FE04 "ᄃ︄" 1103-FE04
+    0x00200a03, 0x0001fff8, 0x0001fff8, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018,
+    0x00000018, 0x0001fff8, 0x0001fff8,
+    // [1203]: 1104-FE04 "ᄄ︄" 1104-FE04
+    0x00200a03, 0x0001f9f8, 0x0001f9f8, 0x00000808, 0x00000808, 0x00000808, 0x00000808, 0x00000808,
+    0x00000808, 0x0001f9f8, 0x0001f9f8,
+    // [1214]: 1105-FE04 "ᄅ︄" 1105-FE04
+    0x00200a03, 0x0001fff8, 0x0001fff8, 0x00018000, 0x00018000, 0x0001fff8, 0x0001fff8, 0x00000018,
+    0x00000018, 0x0001fff8, 0x0001fff8,
+    // [1225]: 1106-FE04 "ᄆ︄" 1106-FE04
+    0x00200a03, 0x0001fff8, 0x0001fff8, 0x00018018, 0x00018018, 0x00018018, 0x00018018, 0x00018018,
+    0x00018018, 0x0001fff8, 0x0001fff8,
+    // [1236]: 1107-FE04 "ᄇ︄" 1107-FE04
+    0x00200a03, 0x00018018, 0x00018018, 0x00018018, 0x00018018, 0x0001fff8, 0x0001fff8, 0x00018018,
+    0x00018018, 0x0001fff8, 0x0001fff8,
+    // [1247]: 1108-FE04 "ᄈ︄" 1108-FE04
+    0x00200a03, 0x00010908, 0x00010908, 0x00010908, 0x00010908, 0x0001f9f8, 0x0001f9f8, 0x00010908,
+    0x00010908, 0x0001f9f8, 0x0001f9f8,
+    // [1258]: 1109-FE04 "ᄉ︄" 1109-FE04
+    0x00200a03, 0x00000c00, 0x00000e00, 0x00000700, 0x00000780, 0x00000f80, 0x00001cc0, 0x000078e0,
+    0x0000e070, 0x0001c038, 0x00018018,
+    // [1269]: 110A-FE04 "ᄊ︄" 110A-FE04
+    0x00200a03, 0x00004040, 0x00004040, 0x00006060, 0x00002020, 0x00002020, 0x00005050, 0x0000d0d0,
+    0x00009898, 0x00010908, 0x00010908,
+    // [1280]: 110B-FE04 "ᄋ︄" 110B-FE04
+    0x00200a03, 0x00001f80, 0x00007fe0, 0x0000e070, 0x0001c038, 0x00018018, 0x00018018, 0x0001c038,
+    0x0000e070, 0x00007fe0, 0x00001f80,
+    // [1291]: 110C-FE04 "ᄌ︄" 110C-FE04
+    0x00200a03, 0x0001fff8, 0x0001fff8, 0x00000700, 0x00000780, 0x00000f80, 0x00001cc0, 0x000078e0,
+    0x0000e070, 0x0001c038, 0x00018018,
+    // [1302]: 110D-FE04 "ᄍ︄" 110D-FE04
+    0x00200a03, 0x0001f9f8, 0x0001f9f8, 0x00006060, 0x00002020, 0x00002020, 0x00005050, 0x0000d0d0,
+    0x00009898, 0x00010908, 0x00010908,
+    // [1313]: 110E-FE04 "ᄎ︄" 110E-FE04
+    0x00200a03, 0x00000600, 0x00000000, 0x0001fff8, 0x0001fff8, 0x00000700, 0x00001f80, 0x00003ce0,
+    0x0000f070, 0x0001e038, 0x00018018,
+    // [1324]: 110F-FE04 "ᄏ︄" 110F-FE04
+    0x00200a03, 0x0001fff8, 0x0001fff8, 0x00018000, 0x00018000, 0x0001fff8, 0x0001fff8, 0x00018000,
+    0x00018000, 0x00018000, 0x00018000,
+    // [1335]: 1110-FE04 "ᄐ︄" 1110-FE04
+    0x00200a03, 0x0001fff8, 0x0001fff8, 0x00000018, 0x00000018, 0x0001fff8, 0x0001fff8, 0x00000018,
+    0x00000018, 0x0001fff8, 0x0001fff8,
+    // [1346]: 1111-FE04 "ᄑ︄" 1111-FE04
+    0x00200a03, 0x0001fff8, 0x0001fff8, 0x000030c0, 0x000030c0, 0x000030c0, 0x000030c0, 0x000030c0,
+    0x000030c0, 0x0001fff8, 0x0001fff8,
+    // [1357]: 1112-FE04 "ᄒ︄" 1112-FE04
+    0x00200a03, 0x00000600, 0x00000000, 0x0001fff8, 0x0001fff8, 0x00000600, 0x00003fc0, 0x000070e0,
+    0x0000e070, 0x00007fe0, 0x00001f80,
+    // [1368]: 1100-FE05 "ᄀ︅" 1100-FE05
+    0x00201003, 0x00007ff8, 0x00007ff8, 0x00006000, 0x00006000, 0x00006000, 0x00006000, 0x00006000,
+    0x00006000, 0x00006000, 0x00006000, 0x00006000, 0x00006000, 0x00006000, 0x00006000, 0x00006000,
+    0x00006000,
+    // [1385]: 1101-FE05 "ᄁ︅" 1101-FE05
+    0x00201003, 0x00007ef8, 0x00007ef8, 0x00004080, 0x00004080, 0x00004080, 0x00004080, 0x00004080,
+    0x00004080, 0x00004080, 0x00004080, 0x00004080, 0x00004080, 0x00004080, 0x00004080, 0x00004080,
+    0x00004080,
+    // [1402]: 1102-FE05 "ᄂ︅" 1102-FE05
+    0x00201003, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018,
+    0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00007ff8,
+    0x00007ff8,
+    // [1419]: 1103-FE05 "ᄃ︅" 1103-FE05
+    0x00201003, 0x00007ff8, 0x00007ff8, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018,
+    0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00007ff8,
+    0x00007ff8,
+    // [1436]: 1104-FE05 "ᄄ︅" 1104-FE05
+    0x00201003, 0x00007ef8, 0x00007ef8, 0x00000208, 0x00000208, 0x00000208, 0x00000208, 0x00000208,
+    0x00000208, 0x00000208, 0x00000208, 0x00000208, 0x00000208, 0x00000208, 0x00000208, 0x00007ef8,
+    0x00007ef8,
+    // [1453]: 1105-FE05 "ᄅ︅" 1105-FE05
+    0x00201003, 0x00007ff8, 0x00007ff8, 0x00006000, 0x00006000, 0x00006000, 0x00006000, 0x00006000,
+    0x00007ff8, 0x00007ff8, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00007ff8,
+    0x00007ff8,
+    // [1470]: 1106-FE05 "ᄆ︅" 1106-FE05
+    0x00201003, 0x00007ff8, 0x00007ff8, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018,
+    0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00007ff8,
+    0x00007ff8,
+    // [1487]: 1107-FE05 "ᄇ︅" 1107-FE05
+    0x00201003, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018,
+    0x00007ff8, 0x00007ff8, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00007ff8,
+    0x00007ff8,
+    // [1504]: 1108-FE05 "ᄈ︅" 1108-FE05
+    0x00201003, 0x00004288, 0x00004288, 0x00004288, 0x00004288, 0x00004288, 0x00004288, 0x00004288,
+    0x00007ef8, 0x00007ef8, 0x00004288, 0x00004288, 0x00004288, 0x00004288, 0x00004288, 0x00007ef8,
+    0x00007ef8,
+    // [1521]: 1109-FE05 "ᄉ︅" 1109-FE05
+    0x00201003, 0x00000600, 0x00000600, 0x00000300, 0x00000300, 0x00000380, 0x00000180, 0x00000380,
+    0x000003c0, 0x000006c0, 0x00000e60, 0x00000c60, 0x00001870, 0x00003830, 0x00003030, 0x00006018,
+    0x00006018,
+    // [1538]: 110A-FE05 "ᄊ︅" 110A-FE05
+    0x00201003, 0x00001020, 0x00001020, 0x00001020, 0x00001820, 0x00000830, 0x00000810, 0x00000830,
+    0x00000c30, 0x00001c30, 0x00001450, 0x00001450, 0x00002458, 0x00002648, 0x00006288, 0x00004288,
+    0x00004288,
+    // [1555]: 110B-FE05 "ᄋ︅" 110B-FE05
+    0x00201003, 0x00000780, 0x00000fc0, 0x00001860, 0x00003030, 0x00003030, 0x00006018, 0x00006018,
+    0x00006018, 0x00006018, 0x00006018, 0x00006018, 0x00003030, 0x00003030, 0x00001860, 0x00000fc0,
+    0x00000780,
+    // [1572]: 110C-FE05 "ᄌ︅" 110C-FE05
+    0x00201003, 0x00007ff8, 0x00007ff8, 0x00000300, 0x00000300, 0x00000380, 0x00000180, 0x00000380,
+    0x000003c0, 0x000006c0, 0x00000e60, 0x00000c60, 0x00001870, 0x00003830, 0x00003030, 0x00006018,
+    0x00006018,
+    // [1589]: 110D-FE05 "ᄍ︅" 110D-FE05
+    0x00201003, 0x00007ef8, 0x00007ef8, 0x00001020, 0x00001820, 0x00000830, 0x00000810, 0x00000830,
+    0x00000c30, 0x00001c30, 0x00001450, 0x00001450, 0x00002458, 0x00002648, 0x00006288, 0x00004288,
+    0x00004288,
+    // [1606]: 110E-FE05 "ᄎ︅" 110E-FE05
+    0x00201003, 0x00000300, 0x00000300, 0x00000000, 0x00000000, 0x00007ff8, 0x00007ff8, 0x00000300,
+    0x00000380, 0x00000380, 0x000007c0, 0x00000ec0, 0x00000c60, 0x00001870, 0x00003830, 0x00007018,
+    0x00006018,
+    // [1623]: 110F-FE05 "ᄏ︅" 110F-FE05
+    0x00201003, 0x00007ff8, 0x00007ff8, 0x00006000, 0x00006000, 0x00006000, 0x00006000, 0x00006000,
+    0x00007ff8, 0x00007ff8, 0x00006000, 0x00006000, 0x00006000, 0x00006000, 0x00006000, 0x00006000,
+    0x00006000,
+    // [1640]: 1110-FE05 "ᄐ︅" 1110-FE05
+    0x00201003, 0x00007ff8, 0x00007ff8, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018,
+    0x00007ff8, 0x00007ff8, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00007ff8,
+    0x00007ff8,
+    // [1657]: 1111-FE05 "ᄑ︅" 1111-FE05
+    0x00201003, 0x00007ff8, 0x00007ff8, 0x00001860, 0x00001860, 0x00001860, 0x00001860, 0x00001860,
+    0x00001860, 0x00001860, 0x00001860, 0x00001860, 0x00001860, 0x00001860, 0x00001860, 0x00007ff8,
+    0x00007ff8,
+    // [1674]: 1112-FE05 "ᄒ︅" 1112-FE05
+    0x00201003, 0x00000300, 0x00000300, 0x00000000, 0x00000000, 0x00007ff8, 0x00007ff8, 0x00000000,
+    0x00000000, 0x00000fc0, 0x00001fe0, 0x00003870, 0x00003030, 0x00003030, 0x00001860, 0x00001fe0,
+    0x00000780,
+    // [1691]: 1100-FE06 "ᄀ︆" 1100-FE06
+    0x00200803, 0x01ffffc0, 0x01ffffc0, 0x01800000, 0x01800000, 0x01800000, 0x01800000, 0x01800000,
+    0x01800000,
+    // [1700]: 1101-FE06 "ᄁ︆" 1101-FE06
+    0x00200803, 0x01ff3fc0, 0x01ff3fc0, 0x01803000, 0x01803000, 0x01803000, 0x01803000, 0x01803000,
+    0x01803000,
+    // [1709]: 1102-FE06 "ᄂ︆" 1102-FE06
+    0x00200803, 0x000000c0, 0x000000c0, 0x000000c0, 0x000000c0, 0x000000c0, 0x000000c0, 0x01ffffc0,
+    0x01ffffc0,
+    // [1718]: 1103-FE06 "ᄃ︆" 1103-FE06
+    0x00200803, 0x01ffffc0, 0x01ffffc0, 0x000000c0, 0x000000c0, 0x000000c0, 0x000000c0, 0x01ffffc0,
+    0x01ffffc0,
+    // [1727]: 1104-FE06 "ᄄ︆" 1104-FE06
+    0x00200803, 0x01ff3fc0, 0x01ff3fc0, 0x000300c0, 0x000300c0, 0x000300c0, 0x000300c0, 0x01ff3fc0,
+    0x01ff3fc0,
+    // [1736]: 1105-FE06 "ᄅ︆" 1105-FE06
+    0x00200803, 0x01ffffc0, 0x01ffffc0, 0x01800000, 0x01ffffc0, 0x01ffffc0, 0x000000c0, 0x01ffffc0,
+    0x01ffffc0,
+    // [1745]: 1106-FE06 "ᄆ︆" 1106-FE06
+    0x00200803, 0x01ffffc0, 0x01ffffc0, 0x018000c0, 0x018000c0, 0x018000c0, 0x018000c0, 0x01ffffc0,
+    0x01ffffc0,
+    // [1754]: 1107-FE06 "ᄇ︆" 1107-FE06
+    0x00200803, 0x018000c0, 0x018000c0, 0x018000c0, 0x01ffffc0, 0x01ffffc0, 0x018000c0, 0x01ffffc0,
+    0x01ffffc0,
+    // [1763]: 1108-FE06 "ᄈ︆" 1108-FE06
+    0x00200803, 0x018330c0, 0x018330c0, 0x018330c0, 0x01ff3fc0, 0x01ff3fc0, 0x018330c0, 0x01ff3fc0,
+    0x01ff3fc0,
+    // [1772]: 1109-FE06 "ᄉ︆" 1109-FE06
+    0x00200803, 0x00018000, 0x0001e000, 0x0000f000, 0x0007fc00, 0x001f1e00, 0x007c0780, 0x01f003c0,
+    0x018000c0,
+    // [1781]: 110A-FE06 "ᄊ︆" 110A-FE06
+    0x00200803, 0x00300600, 0x00380600, 0x00380700, 0x007c0f00, 0x006e0d80, 0x00c61980, 0x01c738c0,
+    0x018330c0,
+    // [1790]: 110B-FE06 "ᄋ︆" 110B-FE06
+    0x00200803, 0x000ff800, 0x007fff00, 0x00e00380, 0x018000c0, 0x018000c0, 0x00e00380, 0x007fff00,
+    0x000ff800,
+    // [1799]: 110C-FE06 "ᄌ︆" 110C-FE06
+    0x00200803, 0x01ffffc0, 0x01ffffc0, 0x0000f000, 0x0007fc00, 0x001f1e00, 0x007c0780, 0x01f003c0,
+    0x018000c0,
+    // [1808]: 110D-FE06 "ᄍ︆" 110D-FE06
+    0x00200803, 0x01ff3fc0, 0x01ff3fc0, 0x00380700, 0x007c0f00, 0x006e0d80, 0x00c61980, 0x01c738c0,
+    0x018330c0,
+    // [1817]: 110E-FE06 "ᄎ︆" 110E-FE06
+    0x00200803, 0x0000c000, 0x00000000, 0x01ffffc0, 0x01ffffc0, 0x0000b800, 0x000f8e00, 0x00f80380,
+    0x00800000,
+    // [1826]: 110F-FE06 "ᄏ︆" 110F-FE06
+    0x00200803, 0x01ffffc0, 0x01ffffc0, 0x01800000, 0x01ffffc0, 0x01ffffc0, 0x01800000, 0x01800000,
+    0x01800000,
+    // [1835]: 1110-FE06 "ᄐ︆" 1110-FE06
+    0x00200803, 0x01ffffc0, 0x01ffffc0, 0x000000c0, 0x01ffffc0, 0x01ffffc0, 0x000000c0, 0x01ffffc0,
+    0x01ffffc0,
+    // [1844]: 1111-FE06 "ᄑ︆" 1111-FE06
+    0x00200803, 0x01ffffc0, 0x01ffffc0, 0x00180c00, 0x00180c00, 0x00180c00, 0x00180c00, 0x01ffffc0,
+    0x01ffffc0,
+    // [1853]: 1112-FE06 "ᄒ︆" 1112-FE06
+    0x00200803, 0x0000c000, 0x00000000, 0x01ffffc0, 0x01ffffc0, 0x001ffc00, 0x00600300, 0x00600300,
+    0x000ff800,
+    // [1862]: 1100-FE07 "ᄀ︇" 1100-FE07
+    0x00200603, 0x0001fff8, 0x00018000, 0x00018000, 0x00018000, 0x00018000, 0x00018000,
+    // [1869]: 1101-FE07 "ᄁ︇" 1101-FE07
+    0x00200603, 0x0001f9f8, 0x00010100, 0x00010100, 0x00010100, 0x00010100, 0x00010100,
+    // [1876]: 1102-FE07 "ᄂ︇" 1102-FE07
+    0x00200603, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x0001fff8,
+    // [1883]: 1103-FE07 "ᄃ︇" 1103-FE07
+    0x00200603, 0x0001fff8, 0x00000018, 0x00000018, 0x00000018, 0x00000018, 0x0001fff8,
+    // [1890]: 1104-FE07 "ᄄ︇" 1104-FE07
+    0x00200603, 0x0001f9f8, 0x00000808, 0x00000808, 0x00000808, 0x00000808, 0x0001f9f8,
+    // [1897]: 1105-FE07 "ᄅ︇" 1105-FE07
+    0x00200603, 0x0001fff8, 0x00018000, 0x0001fff8, 0x00000018, 0x00000018, 0x0001fff8,
+    // [1904]: 1106-FE07 "ᄆ︇" 1106-FE07
+    0x00200603, 0x0001fff8, 0x00018018, 0x00018018, 0x00018018, 0x00018018, 0x0001fff8,
+    // [1911]: 1107-FE07 "ᄇ︇" 1107-FE07
+    0x00200603, 0x00018018, 0x00018018, 0x0001fff8, 0x00018018, 0x00018018, 0x0001fff8,
+    // [1918]: 1108-FE07 "ᄈ︇" 1108-FE07
+    0x00200603, 0x00010908, 0x00010908, 0x0001f9f8, 0x00010908, 0x00010908, 0x0001f9f8,
+    // [1925]: 1109-FE07 "ᄉ︇" 1109-FE07
+    0x00200603, 0x00000c00, 0x00000600, 0x00000780, 0x000018c0, 0x00006030, 0x00018018,
+    // [1932]: 110A-FE07 "ᄊ︇" 110A-FE07
+    0x00200603, 0x00004040, 0x00006060, 0x00002020, 0x00005050, 0x00009898, 0x00010908,
+    // [1939]: 110B-FE07 "ᄋ︇" 110B-FE07
+    0x00200603, 0x00003fc0, 0x0000c030, 0x00010008, 0x00010008, 0x0000c030, 0x00003fc0,
+    // [1946]: 110C-FE07 "ᄌ︇" 110C-FE07
+    0x00200603, 0x0001fff8, 0x00000600, 0x00000780, 0x000018c0, 0x00006030, 0x00018018,
+    // [1953]: 110D-FE07 "ᄍ︇" 110D-FE07
+    0x00200603, 0x0001f9f8, 0x00006060, 0x00002020, 0x00005050, 0x00009898, 0x00010908,
+    // [1960]: 110E-FE07 "ᄎ︇" 110E-FE07
+    0x00200603, 0x00000600, 0x00000000, 0x0001fff8, 0x00000f80, 0x000078e0, 0x0001c038,
+    // [1967]: 110F-FE07 "ᄏ︇" 110F-FE07
+    0x00200603, 0x0001fff8, 0x00018000, 0x0001fff8, 0x00018000, 0x00018000, 0x00018000,
+    // [1974]: 1110-FE07 "ᄐ︇" 1110-FE07
+    0x00200603, 0x0001fff8, 0x00000018, 0x0001fff8, 0x00000018, 0x00000018, 0x0001fff8,
+    // [1981]: 1111-FE07 "ᄑ︇" 1111-FE07
+    0x00200603, 0x0001fff8, 0x000030c0, 0x000030c0, 0x000030c0, 0x000030c0, 0x0001fff8,
+    // [1988]: 1112-FE07 "ᄒ︇" 1112-FE07
+    0x00200603, 0x00000600, 0x00000000, 0x0001fff8, 0x00001f80, 0x0000c030, 0x00003fc0,
+    // [1995]: 1161-FE00 "ᅡ︀" 1161-FE00
+    0x00201a03, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x03f00000, 0x03f00000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000,
+    // [2022]: 1162-FE00 "ᅢ︀" 1162-FE00
+    0x00201a03, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x03fc0000, 0x03fc0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030c0000,
+    // [2049]: 1163-FE00 "ᅣ︀" 1163-FE00
+    0x00201a03, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x03f00000, 0x03f00000, 0x00300000, 0x00300000, 0x00300000, 0x03f00000,
+    0x03f00000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000,
+    // [2076]: 1164-FE00 "ᅤ︀" 1164-FE00
+    0x00201a03, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x03fc0000, 0x03fc0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x03fc0000,
+    0x03fc0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030c0000,
+    // [2103]: 1165-FE00 "ᅥ︀" 1165-FE00
+    0x00201a03, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x003f0000, 0x003f0000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000,
+    // [2130]: 1166-FE00 "ᅦ︀" 1166-FE00
+    0x00201a03, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030f8000, 0x030f8000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030c0000,
+    // [2157]: 1167-FE00 "ᅧ︀" 1167-FE00
+    0x00201a03, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x003f0000, 0x003f0000, 0x00300000, 0x00300000, 0x00300000, 0x003f0000,
+    0x003f0000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000,
+    // [2184]: 1168-FE00 "ᅨ︀" 1168-FE00
+    0x00201a03, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030f8000, 0x030f8000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030f8000,
+    0x030f8000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030c0000,
+    // [2211]: 1169-FE00 "ᅩ︀" 1169-FE00
+    0x00200611, 0x0000c000, 0x0000c000, 0x0000c000, 0x0000c000, 0x0ffffff0, 0x0ffffff0,
+    // [2218]: 116A-FE00 "ᅪ︀" 116A-FE00
+    0x00201a03, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x0fc00000, 0x0fc00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00300, 0x00c00300,
+    0x00c00300, 0x00c00300, 0x00dffff8, 0x00dffff8, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00c00000,
+    // [2245]: 116B-FE00 "ᅫ︀" 116B-FE00
+    0x00201a03, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000,
+    0x0ff00000, 0x0ff00000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300300, 0x0c300300,
+    0x0c300300, 0x0c300300, 0x0c37fff8, 0x0c37fff8, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000,
+    0x0c300000, 0x0c300000, 0x0c300000,
+    // [2272]: 116C-FE00 "ᅬ︀" 116C-FE00
+    0x00201a03, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00300, 0x00c00300,
+    0x00c00300, 0x00c00300, 0x00dffff8, 0x00dffff8, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00c00000,
+    // [2299]: 116D-FE00 "ᅭ︀" 116D-FE00
+    0x00200611, 0x000c0c00, 0x000c0c00, 0x000c0c00, 0x000c0c00, 0x0ffffff0, 0x0ffffff0,
+    // [2306]: 116E-FE00 "ᅮ︀" 116E-FE00
+    0x00200b11, 0x0ffffff0, 0x0ffffff0, 0x0000c000, 0x0000c000, 0x0000c000, 0x0000c000, 0x0000c000,
+    0x0000c000, 0x0000c000, 0x0000c000, 0x0000c000,
+    // [2318]: 116F-FE00 "ᅯ︀" 116F-FE00
+    0x00201a03, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00dffff8, 0x00dffff8, 0x00c00300, 0x00c00300,
+    0x00c00300, 0x00fc0300, 0x00fc0300, 0x00c00300, 0x00c00300, 0x00c00300, 0x00c00300, 0x00c00300,
+    0x00c00300, 0x00c00300, 0x00c00000,
+    // [2345]: 1170-FE00 "ᅰ︀" 1170-FE00
+    0x00201a03, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000,
+    0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c37fff8, 0x0c37fff8, 0x0c300300, 0x0c300300,
+    0x0c300300, 0x0c3e0300, 0x0c3e0300, 0x0c300300, 0x0c300300, 0x0c300300, 0x0c300300, 0x0c300300,
+    0x0c300300, 0x0c300300, 0x0c300000,
+    // [2372]: 1171-FE00 "ᅱ︀" 1171-FE00
+    0x00201a03, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00dffff8, 0x00dffff8, 0x00c00300, 0x00c00300,
+    0x00c00300, 0x00c00300, 0x00c00300, 0x00c00300, 0x00c00300, 0x00c00300, 0x00c00300, 0x00c00300,
+    0x00c00300, 0x00c00300, 0x00c00000,
+    // [2399]: 1172-FE00 "ᅲ︀" 1172-FE00
+    0x00200b11, 0x0ffffff0, 0x0ffffff0, 0x000c0c00, 0x000c0c00, 0x000c0c00, 0x000c0c00, 0x000c0c00,
+    0x000c0c00, 0x000c0c00, 0x000c0c00, 0x000c0c00,
+    // [2411]: 1173-FE00 "ᅳ︀" 1173-FE00
+    0x00200214, 0x0ffffff0, 0x0ffffff0,
+    // [2414]: 1174-FE00 "ᅴ︀" 1174-FE00
+    0x00201a03, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00dffff8, 0x00dffff8, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00c00000,
+    // [2441]: 1175-FE00 "ᅵ︀" 1175-FE00
+    0x00201a03, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000,
+    // [2468]: 1161-FE01 "ᅡ︁" 1161-FE01
+    0x00201a03, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x03f00000, 0x03f00000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000,
+    // [2495]: 1162-FE01 "ᅢ︁" 1162-FE01
+    0x00201a03, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x03fc0000, 0x03fc0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030c0000,
+    // [2522]: 1163-FE01 "ᅣ︁" 1163-FE01
+    0x00201a03, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x03f00000, 0x03f00000, 0x00300000, 0x00300000, 0x00300000, 0x03f00000,
+    0x03f00000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000,
+    // [2549]: 1164-FE01 "ᅤ︁" 1164-FE01
+    0x00201a03, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x03fc0000, 0x03fc0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x03fc0000,
+    0x03fc0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030c0000,
+    // [2576]: 1165-FE01 "ᅥ︁" 1165-FE01
+    0x00201a03, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x003f0000, 0x003f0000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000,
+    // [2603]: 1166-FE01 "ᅦ︁" 1166-FE01
+    0x00201a03, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030f8000, 0x030f8000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030c0000,
+    // [2630]: 1167-FE01 "ᅧ︁" 1167-FE01
+    0x00201a03, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x003f0000, 0x003f0000, 0x00300000, 0x00300000, 0x00300000, 0x003f0000,
+    0x003f0000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000,
+    // [2657]: 1168-FE01 "ᅨ︁" 1168-FE01
+    0x00201a03, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030f8000, 0x030f8000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030f8000,
+    0x030f8000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030c0000,
+    // [2684]: 1169-FE01 "ᅩ︁" 1169-FE01
+    0x00200611, 0x00018000, 0x00018000, 0x00018000, 0x00018000, 0x0ffffff0, 0x0ffffff0,
+    // [2691]: 116A-FE01 "ᅪ︁" 116A-FE01
+    0x00201a03, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x0fc00000, 0x0fc00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00600, 0x00c00600,
+    0x00c00600, 0x00c00600, 0x00dffff8, 0x00dffff8, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00c00000,
+    // [2718]: 116B-FE01 "ᅫ︁" 116B-FE01
+    0x00201a03, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000,
+    0x0ff00000, 0x0ff00000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300600, 0x0c300600,
+    0x0c300600, 0x0c300600, 0x0c37fff8, 0x0c37fff8, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000,
+    0x0c300000, 0x0c300000, 0x0c300000,
+    // [2745]: 116C-FE01 "ᅬ︁" 116C-FE01
+    0x00201a03, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00600, 0x00c00600,
+    0x00c00600, 0x00c00600, 0x00dffff8, 0x00dffff8, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00c00000,
+    // [2772]: 116D-FE01 "ᅭ︁" 116D-FE01
+    0x00200611, 0x00181800, 0x00181800, 0x00181800, 0x00181800, 0x0ffffff0, 0x0ffffff0,
+    // [2779]: 116E-FE01 "ᅮ︁" 116E-FE01
+    0x00200b11, 0x0ffffff0, 0x0ffffff0, 0x00018000, 0x00018000, 0x00018000, 0x00018000, 0x00018000,
+    0x00018000, 0x00018000, 0x00018000, 0x00018000,
+    // [2791]: 116F-FE01 "ᅯ︁" 116F-FE01
+    0x00201a03, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00dffff8, 0x00dffff8, 0x00c00600, 0x00c00600,
+    0x00c00600, 0x00fc0600, 0x00fc0600, 0x00c00600, 0x00c00600, 0x00c00600, 0x00c00600, 0x00c00600,
+    0x00c00600, 0x00c00600, 0x00c00000,
+    // [2818]: 1170-FE01 "ᅰ︁" 1170-FE01
+    0x00201a03, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000,
+    0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c37fff8, 0x0c37fff8, 0x0c300600, 0x0c300600,
+    0x0c300600, 0x0c3e0600, 0x0c3e0600, 0x0c300600, 0x0c300600, 0x0c300600, 0x0c300600, 0x0c300600,
+    0x0c300600, 0x0c300600, 0x0c300000,
+    // [2845]: 1171-FE01 "ᅱ︁" 1171-FE01
+    0x00201a03, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00dffff8, 0x00dffff8, 0x00c00600, 0x00c00600,
+    0x00c00600, 0x00c00600, 0x00c00600, 0x00c00600, 0x00c00600, 0x00c00600, 0x00c00600, 0x00c00600,
+    0x00c00600, 0x00c00600, 0x00c00000,
+    // [2872]: 1172-FE01 "ᅲ︁" 1172-FE01
+    0x00200b11, 0x0ffffff0, 0x0ffffff0, 0x00181800, 0x00181800, 0x00181800, 0x00181800, 0x00181800,
+    0x00181800, 0x00181800, 0x00181800, 0x00181800,
+    // [2884]: 1173-FE01 "ᅳ︁" 1173-FE01
+    0x00200214, 0x0ffffff0, 0x0ffffff0,
+    // [2887]: 1174-FE01 "ᅴ︁" 1174-FE01
+    0x00201a03, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00dffff8, 0x00dffff8, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00c00000,
+    // [2914]: 1175-FE01 "ᅵ︁" 1175-FE01
+    0x00201a03, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000,
+    // [2941]: 1161-FE02 "ᅡ︂" 1161-FE02
+    0x00201103, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x03f00000, 0x03f00000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000,
+    // [2959]: 1162-FE02 "ᅢ︂" 1162-FE02
+    0x00201103, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x03fc0000, 0x03fc0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000,
+    // [2977]: 1163-FE02 "ᅣ︂" 1163-FE02
+    0x00201103, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x03f00000, 0x03f00000, 0x00300000,
+    0x00300000, 0x00300000, 0x03f00000, 0x03f00000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000,
+    // [2995]: 1164-FE02 "ᅤ︂" 1164-FE02
+    0x00201103, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x03fc0000, 0x03fc0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x03fc0000, 0x03fc0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000,
+    // [3013]: 1165-FE02 "ᅥ︂" 1165-FE02
+    0x00201103, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x003f0000, 0x003f0000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000,
+    // [3031]: 1166-FE02 "ᅦ︂" 1166-FE02
+    0x00201103, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030f8000, 0x030f8000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000,
+    // [3049]: 1167-FE02 "ᅧ︂" 1167-FE02
+    0x00201103, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x003f0000, 0x003f0000, 0x00300000,
+    0x00300000, 0x00300000, 0x003f0000, 0x003f0000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000,
+    // [3067]: 1168-FE02 "ᅨ︂" 1168-FE02
+    0x00201103, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030f8000, 0x030f8000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030f8000, 0x030f8000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000,
+    // [3085]: 1169-FE02 "ᅩ︂" 1169-FE02
+    0x0020040c, 0x0000c000, 0x0000c000, 0x0ffffff0, 0x0ffffff0,
+    // [3090]: 116A-FE02 "ᅪ︂" 116A-FE02
+    0x00201103, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x0fc00000, 0x0fc00000,
+    0x00c00300, 0x00c00300, 0x00dffff8, 0x00dffff8, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000,
+    // [3108]: 116B-FE02 "ᅫ︂" 116B-FE02
+    0x00201103, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0ff00000, 0x0ff00000,
+    0x0c300300, 0x0c300300, 0x0c37fff8, 0x0c37fff8, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000,
+    0x0c300000, 0x0c300000,
+    // [3126]: 116C-FE02 "ᅬ︂" 116C-FE02
+    0x00201103, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00300, 0x00c00300, 0x00dffff8, 0x00dffff8, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000,
+    // [3144]: 116D-FE02 "ᅭ︂" 116D-FE02
+    0x0020040c, 0x000c0c00, 0x000c0c00, 0x0ffffff0, 0x0ffffff0,
+    // [3149]: 116E-FE02 "ᅮ︂" 116E-FE02
+    0x0020070c, 0x0ffffff0, 0x0ffffff0, 0x0000c000, 0x0000c000, 0x0000c000, 0x0000c000, 0x0000c000,
+    // [3157]: 116F-FE02 "ᅯ︂" 116F-FE02
+    0x00201103, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00dffff8, 0x00dffff8, 0x00c00300, 0x00fc0300, 0x00fc0300, 0x00c00300,
+    0x00c00300, 0x00c00000,
+    // [3175]: 1170-FE02 "ᅰ︂" 1170-FE02
+    0x00201103, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000,
+    0x0c300000, 0x0c300000, 0x0c37fff8, 0x0c37fff8, 0x0c300300, 0x0c3e0300, 0x0c3e0300, 0x0c300300,
+    0x0c300300, 0x0c300000,
+    // [3193]: 1171-FE02 "ᅱ︂" 1171-FE02
+    0x00201103, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00dffff8, 0x00dffff8, 0x00c00300, 0x00c00300, 0x00c00300, 0x00c00300,
+    0x00c00300, 0x00c00000,
+    // [3211]: 1172-FE02 "ᅲ︂" 1172-FE02
+    0x0020070c, 0x0ffffff0, 0x0ffffff0, 0x000c0c00, 0x000c0c00, 0x000c0c00, 0x000c0c00, 0x000c0c00,
+    // [3219]: 1173-FE02 "ᅳ︂" 1173-FE02
+    0x0020020d, 0x0ffffff0, 0x0ffffff0,
+    // [3222]: 1174-FE02 "ᅴ︂" 1174-FE02
+    0x00201103, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00c00000, 0x00dffff8, 0x00dffff8, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000,
+    // [3240]: 1175-FE02 "ᅵ︂" 1175-FE02
+    0x00201103, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000,
+    // [3258]: 1161-FE03 "ᅡ︃" 1161-FE03
+    0x00201103, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x03f00000, 0x03f00000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000,
+    // [3276]: 1162-FE03 "ᅢ︃" 1162-FE03
+    0x00201103, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x03fc0000, 0x03fc0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000,
+    // [3294]: 1163-FE03 "ᅣ︃" 1163-FE03
+    0x00201103, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x03f00000, 0x03f00000, 0x00300000,
+    0x00300000, 0x00300000, 0x03f00000, 0x03f00000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000,
+    // [3312]: 1164-FE03 "ᅤ︃" 1164-FE03
+    0x00201103, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x03fc0000, 0x03fc0000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x03fc0000, 0x03fc0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000,
+    // [3330]: 1165-FE03 "ᅥ︃" 1165-FE03
+    0x00201103, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x003f0000, 0x003f0000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000,
+    // [3348]: 1166-FE03 "ᅦ︃" 1166-FE03
+    0x00201103, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030f8000, 0x030f8000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000,
+    // [3366]: 1167-FE03 "ᅧ︃" 1167-FE03
+    0x00201103, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x003f0000, 0x003f0000, 0x00300000,
+    0x00300000, 0x00300000, 0x003f0000, 0x003f0000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000,
+    // [3384]: 1168-FE03 "ᅨ︃" 1168-FE03
+    0x00201103, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030f8000, 0x030f8000, 0x030c0000,
+    0x030c0000, 0x030c0000, 0x030f8000, 0x030f8000, 0x030c0000, 0x030c0000, 0x030c0000, 0x030c0000,
+    0x030c0000, 0x030c0000,
+    // [3402]: 1169-FE03 "ᅩ︃" 1169-FE03
+    0x0020040c, 0x00018000, 0x00018000, 0x0ffffff0, 0x0ffffff0,
+    // [3407]: 116A-FE03 "ᅪ︃" 116A-FE03
+    0x00201103, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x0fc00000, 0x0fc00000,
+    0x00c00600, 0x00c00600, 0x00dffff8, 0x00dffff8, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000,
+    // [3425]: 116B-FE03 "ᅫ︃" 116B-FE03
+    0x00201103, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0ff00000, 0x0ff00000,
+    0x0c300600, 0x0c300600, 0x0c37fff8, 0x0c37fff8, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000,
+    0x0c300000, 0x0c300000,
+    // [3443]: 116C-FE03 "ᅬ︃" 116C-FE03
+    0x00201103, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00600, 0x00c00600, 0x00dffff8, 0x00dffff8, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000,
+    // [3461]: 116D-FE03 "ᅭ︃" 116D-FE03
+    0x0020040c, 0x00181800, 0x00181800, 0x0ffffff0, 0x0ffffff0,
+    // [3466]: 116E-FE03 "ᅮ︃" 116E-FE03
+    0x0020070c, 0x0ffffff0, 0x0ffffff0, 0x00018000, 0x00018000, 0x00018000, 0x00018000, 0x00018000,
+    // [3474]: 116F-FE03 "ᅯ︃" 116F-FE03
+    0x00201103, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00dffff8, 0x00dffff8, 0x00c00600, 0x00fc0600, 0x00fc0600, 0x00c00600,
+    0x00c00600, 0x00c00000,
+    // [3492]: 1170-FE03 "ᅰ︃" 1170-FE03
+    0x00201103, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000, 0x0c300000,
+    0x0c300000, 0x0c300000, 0x0c37fff8, 0x0c37fff8, 0x0c300600, 0x0c3e0600, 0x0c3e0600, 0x0c300600,
+    0x0c300600, 0x0c300000,
+    // [3510]: 1171-FE03 "ᅱ︃" 1171-FE03
+    0x00201103, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00dffff8, 0x00dffff8, 0x00c00600, 0x00c00600, 0x00c00600, 0x00c00600,
+    0x00c00600, 0x00c00000,
+    // [3528]: 1172-FE03 "ᅲ︃" 1172-FE03
+    0x0020070c, 0x0ffffff0, 0x0ffffff0, 0x00181800, 0x00181800, 0x00181800, 0x00181800, 0x00181800,
+    // [3536]: 1173-FE03 "ᅳ︃" 1173-FE03
+    0x0020020d, 0x0ffffff0, 0x0ffffff0,
+    // [3539]: 1174-FE03 "ᅴ︃" 1174-FE03
+    0x00201103, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000, 0x00c00000, 0x00dffff8, 0x00dffff8, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000, 0x00c00000,
+    // [3557]: 1175-FE03 "ᅵ︃" 1175-FE03
+    0x00201103, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000, 0x00300000,
+    0x00300000, 0x00300000,
+    // [3575]: 11A8-FE00 "ᆨ︀" 11A8-FE00
+    0x00200815, 0x01fffff0, 0x01fffff0, 0x01800000, 0x01800000, 0x01800000, 0x01800000, 0x01800000,
+    0x01800000,
+    // [3584]: 11A9-FE00 "ᆩ︀" 11A9-FE00
+    0x00200815, 0x01ff9ff0, 0x01ff9ff0, 0x01801800, 0x01801800, 0x01801800, 0x01801800, 0x01801800,
+    0x01801800,
+    // [3593]: 11AA-FE00 "ᆪ︀" 11AA-FE00
+    0x00200815, 0x00181ff0, 0x001c1ff0, 0x001c1800, 0x003e1800, 0x00771800, 0x00e31800, 0x01c39800,
+    0x01819800,
+    // [3602]: 11AB-FE00 "ᆫ︀" 11AB-FE00
+    0x00200815, 0x00000030, 0x00000030, 0x00000030, 0x00000030, 0x00000030, 0x00000030, 0x01fffff0,
+    0x01fffff0,
+    // [3611]: 11AC-FE00 "ᆬ︀" 11AC-FE00
+    0x00200815, 0x01ff8030, 0x01ff8030, 0x001c0030, 0x003e0030, 0x00770030, 0x00e30030, 0x01c39ff0,
+    0x01819ff0,
+    // [3620]: 11AD-FE00 "ᆭ︀" 11AD-FE00
+    0x00200815, 0x00180030, 0x00000030, 0x01ff8030, 0x01ff8030, 0x007e0030, 0x00810030, 0x00c31ff0,
+    0x007e1ff0,
+    // [3629]: 11AE-FE00 "ᆮ︀" 11AE-FE00
+    0x00200815, 0x01fffff0, 0x01fffff0, 0x00000030, 0x00000030, 0x00000030, 0x00000030, 0x01fffff0,
+    0x01fffff0,
+    // [3638]: 11AF-FE00 "ᆯ︀" 11AF-FE00
+    0x00200815, 0x01fffff0, 0x01fffff0, 0x01800000, 0x01fffff0, 0x01fffff0, 0x00000030, 0x01fffff0,
+    0x01fffff0,
+    // [3647]: 11B0-FE00 "ᆰ︀" 11B0-FE00
+    0x00200815, 0x01ff9ff0, 0x01ff9ff0, 0x01801800, 0x01801ff0, 0x01801ff0, 0x01800030, 0x01801ff0,
+    0x01801ff0,
+    // [3656]: 11B1-FE00 "ᆱ︀" 11B1-FE00
+    0x00200815, 0x01ff9ff0, 0x01ff9ff0, 0x01819800, 0x01819ff0, 0x01819ff0, 0x01818030, 0x01ff9ff0,
+    0x01ff9ff0,
+    // [3665]: 11B2-FE00 "ᆲ︀" 11B2-FE00
+    0x00200815, 0x01819ff0, 0x01819ff0, 0x01819800, 0x01ff9ff0, 0x01ff9ff0, 0x01818030, 0x01ff9ff0,
+    0x01ff9ff0,
+    // [3674]: 11B3-FE00 "ᆳ︀" 11B3-FE00
+    0x00200815, 0x00181ff0, 0x001c1ff0, 0x001c1800, 0x003e1ff0, 0x00771ff0, 0x00e30030, 0x01c39ff0,
+    0x01819ff0,
+    // [3683]: 11B4-FE00 "ᆴ︀" 11B4-FE00
+    0x00200815, 0x01ff9ff0, 0x01ff9ff0, 0x00019800, 0x01ff9ff0, 0x01ff9ff0, 0x00018030, 0x01ff9ff0,
+    0x01ff9ff0,
+    // [3692]: 11B5-FE00 "ᆵ︀" 11B5-FE00
+    0x00200815, 0x01ff9ff0, 0x01ff9ff0, 0x00661800, 0x00661ff0, 0x00661ff0, 0x00660030, 0x01ff9ff0,
+    0x01ff9ff0,
+    // [3701]: 11B6-FE00 "ᆶ︀" 11B6-FE00
+    0x00200815, 0x00181ff0, 0x00001ff0, 0x01ff9800, 0x01ff9ff0, 0x007e1ff0, 0x00810030, 0x00c31ff0,
+    0x007e1ff0,
+    // [3710]: 11B7-FE00 "ᆷ︀" 11B7-FE00
+    0x00200815, 0x01fffff0, 0x01fffff0, 0x01800030, 0x01800030, 0x01800030, 0x01800030, 0x01fffff0,
+    0x01fffff0,
+    // [3719]: 11B8-FE00 "ᆸ︀" 11B8-FE00
+    0x00200815, 0x01800030, 0x01800030, 0x01800030, 0x01fffff0, 0x01fffff0, 0x01800030, 0x01fffff0,
+    0x01fffff0,
+    // [3728]: 11B9-FE00 "ᆹ︀" 11B9-FE00
+    0x00200815, 0x00181830, 0x001c1830, 0x001c1830, 0x003e1ff0, 0x00771ff0, 0x00e31830, 0x01c39ff0,
+    0x01819ff0,
+    // [3737]: 11BA-FE00 "ᆺ︀" 11BA-FE00
+    0x00200815, 0x0000c000, 0x0000f000, 0x0000f800, 0x0007de00, 0x001f0780, 0x007c01c0, 0x01f000f0,
+    0x01800030,
+    // [3746]: 11BB-FE00 "ᆻ︀" 11BB-FE00
+    0x00200815, 0x00180300, 0x001c0380, 0x001c0380, 0x003e07c0, 0x007706e0, 0x00e30c60, 0x01c39c70,
+    0x01819830,
+    // [3755]: 11BC-FE00 "ᆼ︀" 11BC-FE00
+    0x00200815, 0x000ffe00, 0x007fffc0, 0x00e000e0, 0x01800030, 0x01800030, 0x00e000e0, 0x007fffc0,
+    0x000ffe00,
+    // [3764]: 11BD-FE00 "ᆽ︀" 11BD-FE00
+    0x00200815, 0x01fffff0, 0x01fffff0, 0x0000f800, 0x0007de00, 0x001f0780, 0x007c01c0, 0x01f000f0,
+    0x01800030,
+    // [3773]: 11BE-FE00 "ᆾ︀" 11BE-FE00
+    0x00200815, 0x0000c000, 0x00000000, 0x01fffff0, 0x01fffff0, 0x00009c00, 0x000f8380, 0x00f800e0,
+    0x00800000,
+    // [3782]: 11BF-FE00 "ᆿ︀" 11BF-FE00
+    0x00200815, 0x01fffff0, 0x01fffff0, 0x01800000, 0x01fffff0, 0x01fffff0, 0x01800000, 0x01800000,
+    0x01800000,
+    // [3791]: 11C0-FE00 "ᇀ︀" 11C0-FE00
+    0x00200815, 0x01fffff0, 0x01fffff0, 0x00000030, 0x01fffff0, 0x01fffff0, 0x00000030, 0x01fffff0,
+    0x01fffff0,
+    // [3800]: 11C1-FE00 "ᇁ︀" 11C1-FE00
+    0x00200815, 0x01fffff0, 0x01fffff0, 0x000c0600, 0x000c0600, 0x000c0600, 0x000c0600, 0x01fffff0,
+    0x01fffff0,
+    // [3809]: 11C2-FE00 "ᇂ︀" 11C2-FE00
+    0x00200815, 0x0000c000, 0x00000000, 0x01fffff0, 0x01fffff0, 0x001fff00, 0x006000c0, 0x007001c0,
+    0x000ffe00,
+    // [3818]: 11A8-FE01 "ᆨ︁" 11A8-FE01
+    0x00200815, 0x00fffff0, 0x00fffff0, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000, 0x00c00000,
+    0x00c00000,
+    // [3827]: 11A9-FE01 "ᆩ︁" 11A9-FE01
+    0x00200815, 0x00ff9ff0, 0x00ff9ff0, 0x00c01800, 0x00c01800, 0x00c01800, 0x00c01800, 0x00c01800,
+    0x00c01800,
+    // [3836]: 11AA-FE01 "ᆪ︁" 11AA-FE01
+    0x00200815, 0x00181ff0, 0x001c1ff0, 0x001c1800, 0x003e1800, 0x00371800, 0x00631800, 0x00e39800,
+    0x00c19800,
+    // [3845]: 11AB-FE01 "ᆫ︁" 11AB-FE01
+    0x00200815, 0x00000030, 0x00000030, 0x00000030, 0x00000030, 0x00000030, 0x00000030, 0x00fffff0,
+    0x00fffff0,
+    // [3854]: 11AC-FE01 "ᆬ︁" 11AC-FE01
+    0x00200815, 0x00ff8030, 0x00ff8030, 0x001c0030, 0x003e0030, 0x00370030, 0x00630030, 0x00e39ff0,
+    0x00c19ff0,
+    // [3863]: 11AD-FE01 "ᆭ︁" 11AD-FE01
+    0x00200815, 0x00180030, 0x00000030, 0x00ff8030, 0x00ff8030, 0x003e0030, 0x00410030, 0x00631ff0,
+    0x003e1ff0,
+    // [3872]: 11AE-FE01 "ᆮ︁" 11AE-FE01
+    0x00200815, 0x00fffff0, 0x00fffff0, 0x00000030, 0x00000030, 0x00000030, 0x00000030, 0x00fffff0,
+    0x00fffff0,
+    // [3881]: 11AF-FE01 "ᆯ︁" 11AF-FE01
+    0x00200815, 0x00fffff0, 0x00fffff0, 0x00c00000, 0x00fffff0, 0x00fffff0, 0x00000030, 0x00fffff0,
+    0x00fffff0,
+    // [3890]: 11B0-FE01 "ᆰ︁" 11B0-FE01
+    0x00200815, 0x00ff9ff0, 0x00ff9ff0, 0x00c01800, 0x00c01ff0, 0x00c01ff0, 0x00c00030, 0x00c01ff0,
+    0x00c01ff0,
+    // [3899]: 11B1-FE01 "ᆱ︁" 11B1-FE01
+    0x00200815, 0x00ff9ff0, 0x00ff9ff0, 0x00c19800, 0x00c19ff0, 0x00c19ff0, 0x00c18030, 0x00ff9ff0,
+    0x00ff9ff0,
+    // [3908]: 11B2-FE01 "ᆲ︁" 11B2-FE01
+    0x00200815, 0x00c19ff0, 0x00c19ff0, 0x00c19800, 0x00ff9ff0, 0x00ff9ff0, 0x00c18030, 0x00ff9ff0,
+    0x00ff9ff0,
+    // [3917]: 11B3-FE01 "ᆳ︁" 11B3-FE01
+    0x00200815, 0x00181ff0, 0x001c1ff0, 0x001c1800, 0x003e1ff0, 0x00371ff0, 0x00630030, 0x00e39ff0,
+    0x00c19ff0,
+    // [3926]: 11B4-FE01 "ᆴ︁" 11B4-FE01
+    0x00200815, 0x00ff9ff0, 0x00ff9ff0, 0x00019800, 0x00ff9ff0, 0x00ff9ff0, 0x00018030, 0x00ff9ff0,
+    0x00ff9ff0,
+    // [3935]: 11B5-FE01 "ᆵ︁" 11B5-FE01
+    0x00200815, 0x00ff9ff0, 0x00ff9ff0, 0x00361800, 0x00361ff0, 0x00361ff0, 0x00360030, 0x00ff9ff0,
+    0x00ff9ff0,
+    // [3944]: 11B6-FE01 "ᆶ︁" 11B6-FE01
+    0x00200815, 0x00181ff0, 0x00001ff0, 0x00ff9800, 0x00ff9ff0, 0x003e1ff0, 0x00410030, 0x00631ff0,
+    0x003e1ff0,
+    // [3953]: 11B7-FE01 "ᆷ︁" 11B7-FE01
+    0x00200815, 0x00fffff0, 0x00fffff0, 0x00c00030, 0x00c00030, 0x00c00030, 0x00c00030, 0x00fffff0,
+    0x00fffff0,
+    // [3962]: 11B8-FE01 "ᆸ︁" 11B8-FE01
+    0x00200815, 0x00c00030, 0x00c00030, 0x00c00030, 0x00fffff0, 0x00fffff0, 0x00c00030, 0x00fffff0,
+    0x00fffff0,
+    // [3971]: 11B9-FE01 "ᆹ︁" 11B9-FE01
+    0x00200815, 0x00181830, 0x001c1830, 0x001c1830, 0x003e1ff0, 0x00371ff0, 0x00631830, 0x00e39ff0,
+    0x00c19ff0,
+    // [3980]: 11BA-FE01 "ᆺ︁" 11BA-FE01
+    0x00200815, 0x0000c000, 0x0000f000, 0x00007800, 0x0003fe00, 0x000f8780, 0x003e01c0, 0x00f800f0,
+    0x00c00030,
+    // [3989]: 11BB-FE01 "ᆻ︁" 11BB-FE01
+    0x00200815, 0x00180300, 0x001c0380, 0x001c0380, 0x003e07c0, 0x003706e0, 0x00630c60, 0x00e39c70,
+    0x00c19830,
+    // [3998]: 11BC-FE01 "ᆼ︁" 11BC-FE01
+    0x00200815, 0x0007fe00, 0x003fffc0, 0x007000e0, 0x00c00030, 0x00c00030, 0x007000e0, 0x003fffc0,
+    0x0007fe00,
+    // [4007]: 11BD-FE01 "ᆽ︁" 11BD-FE01
+    0x00200815, 0x00fffff0, 0x00fffff0, 0x00007800, 0x0003fe00, 0x000f8780, 0x003e01c0, 0x00f800f0,
+    0x00c00030,
+    // [4016]: 11BE-FE01 "ᆾ︁" 11BE-FE01
+    0x00200815, 0x00006000, 0x00000000, 0x00fffff0, 0x00fffff0, 0x00005c00, 0x0007c380, 0x007c00e0,
+    0x00400000,
+    // [4025]: 11BF-FE01 "ᆿ︁" 11BF-FE01
+    0x00200815, 0x00fffff0, 0x00fffff0, 0x00c00000, 0x00fffff0, 0x00fffff0, 0x00c00000, 0x00c00000,
+    0x00c00000,
+    // [4034]: 11C0-FE01 "ᇀ︁" 11C0-FE01
+    0x00200815, 0x00fffff0, 0x00fffff0, 0x00000030, 0x00fffff0, 0x00fffff0, 0x00000030, 0x00fffff0,
+    0x00fffff0,
+    // [4043]: 11C1-FE01 "ᇁ︁" 11C1-FE01
+    0x00200815, 0x00fffff0, 0x00fffff0, 0x000c0300, 0x000c0300, 0x000c0300, 0x000c0300, 0x00fffff0,
+    0x00fffff0,
+    // [4052]: 11C2-FE01 "ᇂ︁" 11C2-FE01
+    0x00200815, 0x00006000, 0x00000000, 0x00fffff0, 0x00fffff0, 0x000fff00, 0x003000c0, 0x003000c0,
+    0x0007fe00,
+    // [4061]: 11A8-FE02 "ᆨ︂" 11A8-FE02
+    0x00200815, 0x03fffff0, 0x03fffff0, 0x03000000, 0x03000000, 0x03000000, 0x03000000, 0x03000000,
+    0x03000000,
+    // [4070]: 11A9-FE02 "ᆩ︂" 11A9-FE02
+    0x00200815, 0x03ff3ff0, 0x03ff3ff0, 0x03003000, 0x03003000, 0x03003000, 0x03003000, 0x03003000,
+    0x03003000,
+    // [4079]: 11AA-FE02 "ᆪ︂" 11AA-FE02
+    0x00200815, 0x00303ff0, 0x00383ff0, 0x00383000, 0x007c3000, 0x00ee3000, 0x01c63000, 0x03873000,
+    0x03033000,
+    // [4088]: 11AB-FE02 "ᆫ︂" 11AB-FE02
+    0x00200815, 0x00000030, 0x00000030, 0x00000030, 0x00000030, 0x00000030, 0x00000030, 0x03fffff0,
+    0x03fffff0,
+    // [4097]: 11AC-FE02 "ᆬ︂" 11AC-FE02
+    0x00200815, 0x03ff0030, 0x03ff0030, 0x00380030, 0x007c0030, 0x00ee0030, 0x01c60030, 0x03873ff0,
+    0x03033ff0,
+    // [4106]: 11AD-FE02 "ᆭ︂" 11AD-FE02
+    0x00200815, 0x00300030, 0x00000030, 0x03ff0030, 0x03ff0030, 0x00fc0030, 0x01020030, 0x01863ff0,
+    0x00fc3ff0,
+    // [4115]: 11AE-FE02 "ᆮ︂" 11AE-FE02
+    0x00200815, 0x03fffff0, 0x03fffff0, 0x00000030, 0x00000030, 0x00000030, 0x00000030, 0x03fffff0,
+    0x03fffff0,
+    // [4124]: 11AF-FE02 "ᆯ︂" 11AF-FE02
+    0x00200815, 0x03fffff0, 0x03fffff0, 0x03000000, 0x03fffff0, 0x03fffff0, 0x00000030, 0x03fffff0,
+    0x03fffff0,
+    // [4133]: 11B0-FE02 "ᆰ︂" 11B0-FE02
+    0x00200815, 0x03ff3ff0, 0x03ff3ff0, 0x03003000, 0x03003ff0, 0x03003ff0, 0x03000030, 0x03003ff0,
+    0x03003ff0,
+    // [4142]: 11B1-FE02 "ᆱ︂" 11B1-FE02
+    0x00200815, 0x03ff3ff0, 0x03ff3ff0, 0x03033000, 0x03033ff0, 0x03033ff0, 0x03030030, 0x03ff3ff0,
+    0x03ff3ff0,
+    // [4151]: 11B2-FE02 "ᆲ︂" 11B2-FE02
+    0x00200815, 0x03033ff0, 0x03033ff0, 0x03033000, 0x03ff3ff0, 0x03ff3ff0, 0x03030030, 0x03ff3ff0,
+    0x03ff3ff0,
+    // [4160]: 11B3-FE02 "ᆳ︂" 11B3-FE02
+    0x00200815, 0x00303ff0, 0x00383ff0, 0x00383000, 0x007c3ff0, 0x00ee3ff0, 0x01c60030, 0x03873ff0,
+    0x03033ff0,
+    // [4169]: 11B4-FE02 "ᆴ︂" 11B4-FE02
+    0x00200815, 0x03ff3ff0, 0x03ff3ff0, 0x00033000, 0x03ff3ff0, 0x03ff3ff0, 0x00030030, 0x03ff3ff0,
+    0x03ff3ff0,
+    // [4178]: 11B5-FE02 "ᆵ︂" 11B5-FE02
+    0x00200815, 0x03ff3ff0, 0x03ff3ff0, 0x00cc3000, 0x00cc3ff0, 0x00cc3ff0, 0x00cc0030, 0x03ff3ff0,
+    0x03ff3ff0,
+    // [4187]: 11B6-FE02 "ᆶ︂" 11B6-FE02
+    0x00200815, 0x00303ff0, 0x00003ff0, 0x03ff3000, 0x03ff3ff0, 0x00fc3ff0, 0x01020030, 0x01863ff0,
+    0x00fc3ff0,
+    // [4196]: 11B7-FE02 "ᆷ︂" 11B7-FE02
+    0x00200815, 0x03fffff0, 0x03fffff0, 0x03000030, 0x03000030, 0x03000030, 0x03000030, 0x03fffff0,
+    0x03fffff0,
+    // [4205]: 11B8-FE02 "ᆸ︂" 11B8-FE02
+    0x00200815, 0x03000030, 0x03000030, 0x03000030, 0x03fffff0, 0x03fffff0, 0x03000030, 0x03fffff0,
+    0x03fffff0,
+    // [4214]: 11B9-FE02 "ᆹ︂" 11B9-FE02
+    0x00200815, 0x00303030, 0x00383030, 0x00383030, 0x007c3ff0, 0x00ee3ff0, 0x01c63030, 0x03873ff0,
+    0x03033ff0,
+    // [4223]: 11BA-FE02 "ᆺ︂" 11BA-FE02
+    0x00200815, 0x00018000, 0x0001e000, 0x0000f800, 0x0007fe00, 0x001f0f80, 0x00f803c0, 0x03e000f0,
+    0x03000030,
+    // [4232]: 11BB-FE02 "ᆻ︂" 11BB-FE02
+    0x00200815, 0x00300300, 0x00380380, 0x00380380, 0x007c07c0, 0x00ee0ee0, 0x01c61c60, 0x03873870,
+    0x03033030,
+    // [4241]: 11BC-FE02 "ᆼ︂" 11BC-FE02
+    0x00200815, 0x000ffc00, 0x00ffffc0, 0x01e001e0, 0x03000030, 0x03000030, 0x01e001e0, 0x00ffffc0,
+    0x000ffc00,
+    // [4250]: 11BD-FE02 "ᆽ︂" 11BD-FE02
+    0x00200815, 0x03fffff0, 0x03fffff0, 0x0000f800, 0x0007fe00, 0x001f0f80, 0x00f803c0, 0x03e000f0,
+    0x03000030,
+    // [4259]: 11BE-FE02 "ᆾ︂" 11BE-FE02
+    0x00200815, 0x0000c000, 0x00000000, 0x03fffff0, 0x03fffff0, 0x0000bc00, 0x001f8780, 0x01f800e0,
+    0x01000000,
+    // [4268]: 11BF-FE02 "ᆿ︂" 11BF-FE02
+    0x00200815, 0x03fffff0, 0x03fffff0, 0x03000000, 0x03fffff0, 0x03fffff0, 0x03000000, 0x03000000,
+    0x03000000,
+    // [4277]: 11C0-FE02 "ᇀ︂" 11C0-FE02
+    0x00200815, 0x03fffff0, 0x03fffff0, 0x00000030, 0x03fffff0, 0x03fffff0, 0x00000030, 0x03fffff0,
+    0x03fffff0,
+    // [4286]: 11C1-FE02 "ᇁ︂" 11C1-FE02
+    0x00200815, 0x03fffff0, 0x03fffff0, 0x00180600, 0x00180600, 0x00180600, 0x00180600, 0x03fffff0,
+    0x03fffff0,
+    // [4295]: 11C2-FE02 "ᇂ︂" 11C2-FE02
+    0x00200815, 0x0000c000, 0x00000000, 0x03fffff0, 0x03fffff0, 0x003fff00, 0x00c000c0, 0x00600180,
+    0x001ffe00,
+    // [4304]: 11A8-FE03 "ᆨ︃" 11A8-FE03
+    0x00200914, 0x01ffffc0, 0x01ffffc0, 0x01800000, 0x01800000, 0x01800000, 0x01800000, 0x01800000,
+    0x01800000, 0x01800000,
+    // [4314]: 11A9-FE03 "ᆩ︃" 11A9-FE03
+    0x00200914, 0x01ff3fc0, 0x01ff3fc0, 0x01803000, 0x01803000, 0x01803000, 0x01803000, 0x01803000,
+    0x01803000, 0x01803000,
+    // [4324]: 11AA-FE03 "ᆪ︃" 11AA-FE03
+    0x00200914, 0x00303fc0, 0x00303fc0, 0x00183000, 0x003c3000, 0x007c3000, 0x006e3000, 0x00c63000,
+    0x01c33000, 0x01833000,
+    // [4334]: 11AB-FE03 "ᆫ︃" 11AB-FE03
+    0x00200914, 0x000000c0, 0x000000c0, 0x000000c0, 0x000000c0, 0x000000c0, 0x000000c0, 0x000000c0,
+    0x01ffffc0, 0x01ffffc0,
+    // [4344]: 11AC-FE03 "ᆬ︃" 11AC-FE03
+    0x00200914, 0x01ff00c0, 0x01ff00c0, 0x001800c0, 0x003c00c0, 0x007c00c0, 0x006e00c0, 0x00c600c0,
+    0x01c33fc0, 0x01833fc0,
+    // [4354]: 11AD-FE03 "ᆭ︃" 11AD-FE03
+    0x00200914, 0x003000c0, 0x000000c0, 0x01ff00c0, 0x01ff00c0, 0x003800c0, 0x00c600c0, 0x008200c0,
+    0x00c63fc0, 0x007c3fc0,
+    // [4364]: 11AE-FE03 "ᆮ︃" 11AE-FE03
+    0x00200914, 0x01ffffc0, 0x01ffffc0, 0x000000c0, 0x000000c0, 0x000000c0, 0x000000c0, 0x000000c0,
+    0x01ffffc0, 0x01ffffc0,
+    // [4374]: 11AF-FE03 "ᆯ︃" 11AF-FE03
+    0x00200914, 0x01ffffc0, 0x01ffffc0, 0x01800000, 0x01800000, 0x01ffffc0, 0x01ffffc0, 0x000000c0,
+    0x01ffffc0, 0x01ffffc0,
+    // [4384]: 11B0-FE03 "ᆰ︃" 11B0-FE03
+    0x00200914, 0x01ff3fc0, 0x01ff3fc0, 0x01803000, 0x01803000, 0x01803fc0, 0x01803fc0, 0x018000c0,
+    0x01803fc0, 0x01803fc0,
+    // [4394]: 11B1-FE03 "ᆱ︃" 11B1-FE03
+    0x00200914, 0x01ff3fc0, 0x01ff3fc0, 0x01833000, 0x01833000, 0x01833fc0, 0x01833fc0, 0x018300c0,
+    0x01ff3fc0, 0x01ff3fc0,
+    // [4404]: 11B2-FE03 "ᆲ︃" 11B2-FE03
+    0x00200914, 0x01833fc0, 0x01833fc0, 0x01833000, 0x01833000, 0x01ff3fc0, 0x01ff3fc0, 0x018300c0,
+    0x01ff3fc0, 0x01ff3fc0,
+    // [4414]: 11B3-FE03 "ᆳ︃" 11B3-FE03
+    0x00200914, 0x00303fc0, 0x00303fc0, 0x00183000, 0x003c3000, 0x007c3fc0, 0x006e3fc0, 0x00c600c0,
+    0x01c33fc0, 0x01833fc0,
+    // [4424]: 11B4-FE03 "ᆴ︃" 11B4-FE03
+    0x00200914, 0x01ff3fc0, 0x01ff3fc0, 0x00033000, 0x00033000, 0x01ff3fc0, 0x01ff3fc0, 0x000300c0,
+    0x01ff3fc0, 0x01ff3fc0,
+    // [4434]: 11B5-FE03 "ᆵ︃" 11B5-FE03
+    0x00200914, 0x01ff3fc0, 0x01ff3fc0, 0x006c3000, 0x006c3000, 0x006c3fc0, 0x006c3fc0, 0x006c00c0,
+    0x01ff3fc0, 0x01ff3fc0,
+    // [4444]: 11B6-FE03 "ᆶ︃" 11B6-FE03
+    0x00200914, 0x00303fc0, 0x00003fc0, 0x01ff3000, 0x01ff3000, 0x00383fc0, 0x00c63fc0, 0x008200c0,
+    0x00c63fc0, 0x007c3fc0,
+    // [4454]: 11B7-FE03 "ᆷ︃" 11B7-FE03
+    0x00200914, 0x01ffffc0, 0x01ffffc0, 0x018000c0, 0x018000c0, 0x018000c0, 0x018000c0, 0x018000c0,
+    0x01ffffc0, 0x01ffffc0,
+    // [4464]: 11B8-FE03 "ᆸ︃" 11B8-FE03
+    0x00200914, 0x018000c0, 0x018000c0, 0x018000c0, 0x018000c0, 0x01ffffc0, 0x01ffffc0, 0x018000c0,
+    0x01ffffc0, 0x01ffffc0,
+    // [4474]: 11B9-FE03 "ᆹ︃" 11B9-FE03
+    0x00200914, 0x003030c0, 0x003030c0, 0x001830c0, 0x003c30c0, 0x007c3fc0, 0x006e3fc0, 0x00c630c0,
+    0x01c33fc0, 0x01833fc0,
+    // [4484]: 11BA-FE03 "ᆺ︃" 11BA-FE03
+    0x00200914, 0x00018000, 0x0001c000, 0x0000f000, 0x0000f800, 0x0007dc00, 0x001f0e00, 0x007c0780,
+    0x01f001c0, 0x018000c0,
+    // [4494]: 11BB-FE03 "ᆻ︃" 11BB-FE03
+    0x00200914, 0x00300600, 0x00300600, 0x00180300, 0x003c0700, 0x007c0f80, 0x006e0d80, 0x00c61980,
+    0x01c338c0, 0x018330c0,
+    // [4504]: 11BC-FE03 "ᆼ︃" 11BC-FE03
+    0x00200914, 0x000ff800, 0x007fff00, 0x00f00780, 0x018000c0, 0x018000c0, 0x018000c0, 0x00f00780,
+    0x007fff00, 0x000ff800,
+    // [4514]: 11BD-FE03 "ᆽ︃" 11BD-FE03
+    0x00200914, 0x01ffffc0, 0x01ffffc0, 0x0000f000, 0x0000f800, 0x0007dc00, 0x001f0e00, 0x007c0780,
+    0x01f001c0, 0x018000c0,
+    // [4524]: 11BE-FE03 "ᆾ︃" 11BE-FE03
+    0x00200814, 0x0000c000, 0x00000000, 0x01ffffc0, 0x01ffffc0, 0x00007000, 0x00039c00, 0x001c0700,
+    0x00e00180,
+    // [4533]: 11BF-FE03 "ᆿ︃" 11BF-FE03
+    0x00200914, 0x01ffffc0, 0x01ffffc0, 0x01800000, 0x01800000, 0x01ffffc0, 0x01ffffc0, 0x01800000,
+    0x01800000, 0x01800000,
+    // [4543]: 11C0-FE03 "ᇀ︃" 11C0-FE03
+    0x00200914, 0x01ffffc0, 0x01ffffc0, 0x000000c0, 0x000000c0, 0x01ffffc0, 0x01ffffc0, 0x000000c0,
+    0x01ffffc0, 0x01ffffc0,
+    // [4553]: 11C1-FE03 "ᇁ︃" 11C1-FE03
+    0x00200914, 0x01ffffc0, 0x01ffffc0, 0x00180c00, 0x00180c00, 0x00180c00, 0x00180c00, 0x00180c00,
+    0x01ffffc0, 0x01ffffc0,
+    // [4563]: 11C2-FE03 "ᇂ︃" 11C2-FE03
+    0x00200914, 0x0000c000, 0x00000000, 0x01ffffc0, 0x01ffffc0, 0x000ff800, 0x00300600, 0x00400100,
+    0x00700700, 0x000ff800,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // If this fails, there's probably a hash collision, so change the seed.
+    fn test_hashes_unique_and_sorted() {
+        for i in 0..HASH_HANGUL_JAMO.len()-1 {
+            assert!(HASH_HANGUL_JAMO[i] < HASH_HANGUL_JAMO[i+1]);
+        }
+    }
+}
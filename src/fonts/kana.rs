@@ -0,0 +1,1092 @@
+// DO NOT MAKE EDITS HERE because this file is automatically generated.
+// To make changes, see blitstr/codegen/main.go
+//
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// NOTE: The copyright notice above applies to the rust source code in this
+// file, but not to the bitmap graphics encoded in the DATA array (see credits).
+//
+// CREDITS:
+// This code includes encoded bitmaps of kana glyphs that were drawn for
+// blitstr. Their stroke weight and 32px cell size match the Hanzi glyphs so
+// that mixed kana and kanji text has an even texture. The kana bitmaps are
+// covered by the same license as the rust source code in this file.
+//
+//! Kana Font
+#![forbid(unsafe_code)]
+#![allow(dead_code)]
+
+use super::{GlyphData, NoGlyphErr};
+
+/// Maximum height of glyph patterns in this bitmap typeface.
+/// This will be true: h + y_offset <= MAX_HEIGHT
+pub const MAX_HEIGHT: u8 = 32;
+
+/// Seed for Murmur3 hashes in the HASH_* index arrays
+pub const M3_SEED: u32 = 0;
+
+/// Return Okay(offset into DATA[]) for start of blit pattern for grapheme cluster.
+///
+/// Before doing an expensive lookup for the whole cluster, this does a pre-filter
+/// check to see whether the first character falls into one of the codepoint ranges
+/// for Unicode blocks included in this font.
+///
+/// Returns: Result<(blit pattern offset into DATA, bytes of cluster used by match)>
+pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGlyphErr> {
+    let first_char: u32 = match cluster.chars().next() {
+        Some(c) => c as u32,
+        None => return Err(NoGlyphErr),
+    };
+    match first_char {
+        0x3040..=0x309F => {
+            if let Some((offset, bytes_used)) = find_hiragana(cluster, 2) {
+                Ok((GlyphData::Kana(offset), bytes_used))
+            } else if let Some((offset, bytes_used)) = find_hiragana_codepoint(cluster) {
+                Ok((GlyphData::Kana(offset), bytes_used))
+            } else {
+                Err(NoGlyphErr)
+            }
+        }
+        0x30A0..=0x30FF => {
+            if let Some((offset, bytes_used)) = find_katakana(cluster, 2) {
+                Ok((GlyphData::Kana(offset), bytes_used))
+            } else if let Some((offset, bytes_used)) = find_katakana_codepoint(cluster) {
+                Ok((GlyphData::Kana(offset), bytes_used))
+            } else {
+                Err(NoGlyphErr)
+            }
+        }
+        _ => Err(super::NoGlyphErr),
+    }
+}
+
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_hiragana_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_HIRAGANA, 0x3041, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x3041..=0x309E, or NO_GLYPH
+static CODEPOINT_HIRAGANA: [u32; 94] = [
+    0,     // "ぁ"
+    12,    // "あ"
+    27,    // "ぃ"
+    35,    // "い"
+    46,    // "ぅ"
+    55,    // "う"
+    65,    // "ぇ"
+    75,    // "え"
+    87,    // "ぉ"
+    98,    // "お"
+    112,   // "か"
+    128,   // "が"
+    147,   // "き"
+    158,   // "ぎ"
+    172,   // "く"
+    184,   // "ぐ"
+    199,   // "け"
+    210,   // "げ"
+    224,   // "こ"
+    232,   // "ご"
+    243,   // "さ"
+    252,   // "ざ"
+    264,   // "し"
+    272,   // "じ"
+    284,   // "す"
+    293,   // "ず"
+    306,   // "せ"
+    316,   // "ぜ"
+    329,   // "そ"
+    340,   // "ぞ"
+    354,   // "た"
+    365,   // "だ"
+    380,   // "ち"
+    391,   // "ぢ"
+    406,   // "っ"
+    414,   // "つ"
+    425,   // "づ"
+    439,   // "て"
+    449,   // "で"
+    462,   // "と"
+    473,   // "ど"
+    488,   // "な"
+    501,   // "に"
+    511,   // "ぬ"
+    529,   // "ね"
+    544,   // "の"
+    559,   // "は"
+    571,   // "ば"
+    586,   // "ぱ"
+    599,   // "ひ"
+    614,   // "び"
+    632,   // "ぴ"
+    648,   // "ふ"
+    662,   // "ぶ"
+    679,   // "ぷ"
+    694,   // "へ"
+    704,   // "べ"
+    717,   // "ぺ"
+    729,   // "ほ"
+    741,   // "ぼ"
+    756,   // "ぽ"
+    769,   // "ま"
+    779,   // "み"
+    794,   // "む"
+    807,   // "め"
+    824,   // "も"
+    836,   // "ゃ"
+    846,   // "や"
+    859,   // "ゅ"
+    873,   // "ゆ"
+    891,   // "ょ"
+    900,   // "よ"
+    909,   // "ら"
+    920,   // "り"
+    932,   // "る"
+    946,   // "れ"
+    959,   // "ろ"
+    972,   // "ゎ"
+    982,   // "わ"
+    996,   // "ゐ"
+    1013,  // "ゑ"
+    1028,  // "を"
+    1040,  // "ん"
+    1053,  // "ゔ"
+    1066,  // "ゕ"
+    1078,  // "ゖ"
+    super::NO_GLYPH,  // U+3097
+    super::NO_GLYPH,  // U+3098
+    super::NO_GLYPH,  // U+3099
+    super::NO_GLYPH,  // U+309A
+    1087,  // "゛"
+    1094,  // "゜"
+    1101,  // "ゝ"
+    1109,  // "ゞ"
+];
+
+/// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
+/// Only attempt to match grapheme clusters of length limit codepoints.
+fn find_hiragana(cluster: &str, limit: u32) -> Option<(usize, usize)> {
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_HIRAGANA.binary_search(&hash) {
+        Ok(index) if KEY_HIRAGANA[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_HIRAGANA[index], bytes_hashed))
+        }
+        _ => None,
+    }
+}
+
+/// Index of murmur3(grapheme cluster); sort matches OFFSET_HIRAGANA
+static HASH_HIRAGANA: [u32; 27] = [
+    0x020512B1,  // "ゞ" 309D-3099
+    0x03E4DA44,  // "ば" 306F-3099
+    0x0B349A2C,  // "ぴ" 3072-309A
+    0x2258F26A,  // "ぞ" 305D-3099
+    0x2FA4D710,  // "べ" 3078-3099
+    0x32A6AA86,  // "で" 3066-3099
+    0x39F42A02,  // "ぜ" 305B-3099
+    0x51EA4FC7,  // "が" 304B-3099
+    0x62B12AE1,  // "だ" 305F-3099
+    0x8218FA6D,  // "げ" 3051-3099
+    0x86945D01,  // "ぎ" 304D-3099
+    0x8B00C095,  // "づ" 3064-3099
+    0x904D4DA3,  // "ぱ" 306F-309A
+    0xA0544F5D,  // "ご" 3053-3099
+    0xA4714E2C,  // "ざ" 3055-3099
+    0xA9872036,  // "ど" 3068-3099
+    0xAE74BCF9,  // "ぼ" 307B-3099
+    0xB077349F,  // "ぐ" 304F-3099
+    0xB7548911,  // "ぶ" 3075-3099
+    0xB895A893,  // "ぺ" 3078-309A
+    0xC46FD025,  // "ぢ" 3061-3099
+    0xDA029F73,  // "ず" 3059-3099
+    0xDE2076B8,  // "ぷ" 3075-309A
+    0xE7C0F4F0,  // "び" 3072-3099
+    0xE7F7AA44,  // "ぽ" 307B-309A
+    0xE8D98FA8,  // "じ" 3057-3099
+    0xFFB35A73,  // "ゔ" 3046-3099
+];
+
+/// Verification keys to rule out hash collisions; sort matches HASH_HIRAGANA
+static KEY_HIRAGANA: [u32; 27] = [
+    0xA4D4A5E3,  // "ゞ" 309D-3099
+    0x88616E67,  // "ば" 306F-3099
+    0xC34C9E37,  // "ぴ" 3072-309A
+    0xD49D72A9,  // "ぞ" 305D-3099
+    0xF0714CDC,  // "べ" 3078-3099
+    0xBD847ED9,  // "で" 3066-3099
+    0x95F2D132,  // "ぜ" 305B-3099
+    0x85673114,  // "が" 304B-3099
+    0xAD58650E,  // "だ" 305F-3099
+    0xAFE53390,  // "げ" 3051-3099
+    0xE92D5E18,  // "ぎ" 304D-3099
+    0xA6B9D7BC,  // "づ" 3064-3099
+    0xE57EB786,  // "ぱ" 306F-309A
+    0xBACECC97,  // "ご" 3053-3099
+    0xE227B2C0,  // "ざ" 3055-3099
+    0xA29C1498,  // "ど" 3068-3099
+    0x8B03B27E,  // "ぼ" 307B-3099
+    0xB8493F1A,  // "ぐ" 304F-3099
+    0xC7DCAB9D,  // "ぶ" 3075-3099
+    0xE4D9EDBF,  // "ぺ" 3078-309A
+    0xAB631906,  // "ぢ" 3061-3099
+    0xB12508FA,  // "ず" 3059-3099
+    0xA2D8A091,  // "ぷ" 3075-309A
+    0x80AC4484,  // "び" 3072-3099
+    0xDF8B4A5C,  // "ぽ" 307B-309A
+    0x923951C3,  // "じ" 3057-3099
+    0xB5487287,  // "ゔ" 3046-3099
+];
+
+/// Lookup table of blit pattern offsets; sort matches HASH_HIRAGANA
+static OFFSET_HIRAGANA: [usize; 27] = [
+    1109, // "ゞ" 309D-3099
+    571,  // "ば" 306F-3099
+    632,  // "ぴ" 3072-309A
+    340,  // "ぞ" 305D-3099
+    704,  // "べ" 3078-3099
+    449,  // "で" 3066-3099
+    316,  // "ぜ" 305B-3099
+    128,  // "が" 304B-3099
+    365,  // "だ" 305F-3099
+    210,  // "げ" 3051-3099
+    158,  // "ぎ" 304D-3099
+    425,  // "づ" 3064-3099
+    586,  // "ぱ" 306F-309A
+    232,  // "ご" 3053-3099
+    252,  // "ざ" 3055-3099
+    473,  // "ど" 3068-3099
+    741,  // "ぼ" 307B-3099
+    184,  // "ぐ" 304F-3099
+    662,  // "ぶ" 3075-3099
+    717,  // "ぺ" 3078-309A
+    391,  // "ぢ" 3061-3099
+    293,  // "ず" 3059-3099
+    679,  // "ぷ" 3075-309A
+    614,  // "び" 3072-3099
+    756,  // "ぽ" 307B-309A
+    272,  // "じ" 3057-3099
+    1053, // "ゔ" 3046-3099
+];
+
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_katakana_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_KATAKANA, 0x30A1, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x30A1..=0x30FE, or NO_GLYPH
+static CODEPOINT_KATAKANA: [u32; 94] = [
+    1120,  // "ァ"
+    1129,  // "ア"
+    1141,  // "ィ"
+    1150,  // "イ"
+    1161,  // "ゥ"
+    1170,  // "ウ"
+    1179,  // "ェ"
+    1184,  // "エ"
+    1190,  // "ォ"
+    1199,  // "オ"
+    1212,  // "カ"
+    1224,  // "ガ"
+    1240,  // "キ"
+    1249,  // "ギ"
+    1262,  // "ク"
+    1276,  // "グ"
+    1293,  // "ケ"
+    1304,  // "ゲ"
+    1318,  // "コ"
+    1324,  // "ゴ"
+    1333,  // "サ"
+    1342,  // "ザ"
+    1355,  // "シ"
+    1368,  // "ジ"
+    1384,  // "ス"
+    1397,  // "ズ"
+    1413,  // "セ"
+    1422,  // "ゼ"
+    1435,  // "ソ"
+    1448,  // "ゾ"
+    1464,  // "タ"
+    1478,  // "ダ"
+    1495,  // "チ"
+    1505,  // "ヂ"
+    1518,  // "ッ"
+    1530,  // "ツ"
+    1545,  // "ヅ"
+    1563,  // "テ"
+    1570,  // "デ"
+    1580,  // "ト"
+    1587,  // "ド"
+    1598,  // "ナ"
+    1606,  // "ニ"
+    1611,  // "ヌ"
+    1623,  // "ネ"
+    1636,  // "ノ"
+    1647,  // "ハ"
+    1659,  // "バ"
+    1674,  // "パ"
+    1688,  // "ヒ"
+    1696,  // "ビ"
+    1708,  // "ピ"
+    1718,  // "フ"
+    1729,  // "ブ"
+    1743,  // "プ"
+    1755,  // "ヘ"
+    1766,  // "ベ"
+    1780,  // "ペ"
+    1792,  // "ホ"
+    1805,  // "ボ"
+    1822,  // "ポ"
+    1837,  // "マ"
+    1848,  // "ミ"
+    1858,  // "ム"
+    1869,  // "メ"
+    1883,  // "モ"
+    1889,  // "ャ"
+    1898,  // "ヤ"
+    1909,  // "ュ"
+    1914,  // "ユ"
+    1919,  // "ョ"
+    1925,  // "ヨ"
+    1932,  // "ラ"
+    1942,  // "リ"
+    1950,  // "ル"
+    1963,  // "レ"
+    1974,  // "ロ"
+    1980,  // "ヮ"
+    1988,  // "ワ"
+    1998,  // "ヰ"
+    2006,  // "ヱ"
+    2015,  // "ヲ"
+    2026,  // "ン"
+    2039,  // "ヴ"
+    2052,  // "ヵ"
+    2061,  // "ヶ"
+    2069,  // "ヷ"
+    2082,  // "ヸ"
+    2093,  // "ヹ"
+    2104,  // "ヺ"
+    2117,  // "・"
+    2122,  // "ー"
+    2126,  // "ヽ"
+    2132,  // "ヾ"
+];
+
+/// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
+/// Only attempt to match grapheme clusters of length limit codepoints.
+fn find_katakana(cluster: &str, limit: u32) -> Option<(usize, usize)> {
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_KATAKANA.binary_search(&hash) {
+        Ok(index) if KEY_KATAKANA[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_KATAKANA[index], bytes_hashed))
+        }
+        _ => None,
+    }
+}
+
+/// Index of murmur3(grapheme cluster); sort matches OFFSET_KATAKANA
+static HASH_KATAKANA: [u32; 31] = [
+    0x022395A3,  // "ヹ" 30F1-3099
+    0x088630E2,  // "ヷ" 30EF-3099
+    0x0B35DB71,  // "ガ" 30AB-3099
+    0x1F051767,  // "ポ" 30DB-309A
+    0x26EB8E50,  // "ゾ" 30BD-3099
+    0x2A3A9439,  // "ヅ" 30C4-3099
+    0x2BBB83FE,  // "ズ" 30B9-3099
+    0x2F586198,  // "ゲ" 30B1-3099
+    0x32501A9C,  // "ド" 30C8-3099
+    0x48B8C740,  // "ジ" 30B7-3099
+    0x4D8D8532,  // "デ" 30C6-3099
+    0x516D559B,  // "ヺ" 30F2-3099
+    0x599A9CB1,  // "ブ" 30D5-3099
+    0x65C0E4AA,  // "グ" 30AF-3099
+    0x776F433D,  // "ヾ" 30FD-3099
+    0x780855D8,  // "ヴ" 30A6-3099
+    0x7BA546E8,  // "ペ" 30D8-309A
+    0x81196A1F,  // "ボ" 30DB-3099
+    0x8599838F,  // "ビ" 30D2-3099
+    0x85B39A1E,  // "ピ" 30D2-309A
+    0x9492C6BB,  // "ヂ" 30C1-3099
+    0xA02310E4,  // "ダ" 30BF-3099
+    0xA425FA6A,  // "ギ" 30AD-3099
+    0xA9650CDD,  // "ザ" 30B5-3099
+    0xAC7E8B07,  // "バ" 30CF-3099
+    0xAD57B624,  // "プ" 30D5-309A
+    0xAE8619EC,  // "ゼ" 30BB-3099
+    0xCA7924C2,  // "ゴ" 30B3-3099
+    0xD5EF9513,  // "ベ" 30D8-3099
+    0xD6710E6E,  // "ヸ" 30F0-3099
+    0xF93137A2,  // "パ" 30CF-309A
+];
+
+/// Verification keys to rule out hash collisions; sort matches HASH_KATAKANA
+static KEY_KATAKANA: [u32; 31] = [
+    0xA1363117,  // "ヹ" 30F1-3099
+    0xE01E5404,  // "ヷ" 30EF-3099
+    0xCDAEE071,  // "ガ" 30AB-3099
+    0xA3D6E1D7,  // "ポ" 30DB-309A
+    0xEDDF9FC2,  // "ゾ" 30BD-3099
+    0xD63A12E2,  // "ヅ" 30C4-3099
+    0xB8BABD84,  // "ズ" 30B9-3099
+    0xAC328C62,  // "ゲ" 30B1-3099
+    0xB99B6561,  // "ド" 30C8-3099
+    0xD24FF1AE,  // "ジ" 30B7-3099
+    0x97881C82,  // "デ" 30C6-3099
+    0xFA582984,  // "ヺ" 30F2-3099
+    0xBB7A65A7,  // "ブ" 30D5-3099
+    0xF5ED506C,  // "グ" 30AF-3099
+    0x8DA412E6,  // "ヾ" 30FD-3099
+    0xFBC5CC68,  // "ヴ" 30A6-3099
+    0xAFE215DB,  // "ペ" 30D8-309A
+    0xBA9CBEBC,  // "ボ" 30DB-3099
+    0xAFE49528,  // "ビ" 30D2-3099
+    0xC8B90248,  // "ピ" 30D2-309A
+    0xEE27C22C,  // "ヂ" 30C1-3099
+    0xC369C9FE,  // "ダ" 30BF-3099
+    0xE421AA49,  // "ギ" 30AD-3099
+    0xE727D076,  // "ザ" 30B5-3099
+    0xEEEE1A33,  // "バ" 30CF-3099
+    0xC2E2D38B,  // "プ" 30D5-309A
+    0x9BCF81A1,  // "ゼ" 30BB-3099
+    0xECDD1980,  // "ゴ" 30B3-3099
+    0xA1B67403,  // "ベ" 30D8-3099
+    0xC9933C57,  // "ヸ" 30F0-3099
+    0x85A22B8E,  // "パ" 30CF-309A
+];
+
+/// Lookup table of blit pattern offsets; sort matches HASH_KATAKANA
+static OFFSET_KATAKANA: [usize; 31] = [
+    2093, // "ヹ" 30F1-3099
+    2069, // "ヷ" 30EF-3099
+    1224, // "ガ" 30AB-3099
+    1822, // "ポ" 30DB-309A
+    1448, // "ゾ" 30BD-3099
+    1545, // "ヅ" 30C4-3099
+    1397, // "ズ" 30B9-3099
+    1304, // "ゲ" 30B1-3099
+    1587, // "ド" 30C8-3099
+    1368, // "ジ" 30B7-3099
+    1570, // "デ" 30C6-3099
+    2104, // "ヺ" 30F2-3099
+    1729, // "ブ" 30D5-3099
+    1276, // "グ" 30AF-3099
+    2132, // "ヾ" 30FD-3099
+    2039, // "ヴ" 30A6-3099
+    1780, // "ペ" 30D8-309A
+    1805, // "ボ" 30DB-3099
+    1696, // "ビ" 30D2-3099
+    1708, // "ピ" 30D2-309A
+    1505, // "ヂ" 30C1-3099
+    1478, // "ダ" 30BF-3099
+    1249, // "ギ" 30AD-3099
+    1342, // "ザ" 30B5-3099
+    1659, // "バ" 30CF-3099
+    1743, // "プ" 30D5-309A
+    1422, // "ゼ" 30BB-3099
+    1324, // "ゴ" 30B3-3099
+    1766, // "ベ" 30D8-3099
+    2082, // "ヸ" 30F0-3099
+    1674, // "パ" 30CF-309A
+];
+
+/// Index blocks in the order that get_blit_pattern_offset() checks them
+pub static BLOCKS: [super::Block; 2] = [
+    super::Block {
+        low: 0x3040,
+        high: 0x309F,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x3041,
+        codepoints: &CODEPOINT_HIRAGANA,
+        hashes: &HASH_HIRAGANA,
+        keys: &KEY_HIRAGANA,
+        offsets: &OFFSET_HIRAGANA,
+    },
+    super::Block {
+        low: 0x30A0,
+        high: 0x30FF,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x30A1,
+        codepoints: &CODEPOINT_KATAKANA,
+        hashes: &HASH_KATAKANA,
+        keys: &KEY_KATAKANA,
+        offsets: &OFFSET_KATAKANA,
+    },
+];
+
+/// Packed glyph pattern data.
+/// Record format:
+///  [offset+0]: ((w as u8) << 16) | ((h as u8) << 8) | (yOffset as u8)
+///  [offset+1..=ceil(w*h/32)]: packed 1-bit pixels; 0=clear, 1=set
+/// Pixels are packed in top to bottom, left to right order with MSB of first
+/// pixel word containing the top left pixel.
+///  w: Width of pattern in pixels
+///  h: Height of pattern in pixels
+///  yOffset: Vertical offset (pixels downward from top of line) to position
+///     glyph pattern properly relative to text baseline
+/// Glyphs that take fewer words compressed have ((n as u8) << 24) in the
+/// header, followed by n words of compressed pattern data (see compress.rs).
+pub static DATA: [u32; 2141] = [
+    // [0]: 3041 "ぁ"
+    0x0b202000, 0xf80007ff, 0x00001000, 0xf42f99f8, 0xbfc211f9, 0x222fb6fa, 0x8f7bf622, 0x422f5224,
+    0x32f42333, 0x2f4238f4, 0xcf7521fc, 0x1e000000,
+    // [12]: 3042 "あ"
+    0x0e202000, 0xfc00003f, 0x00002080, 0xf42f8342, 0xf7ef7bfa, 0x2f9242fa, 0x232f99f6, 0xdf341212,
+    0x23f13321, 0x233f0254, 0x43e25452, 0xe26352f0, 0x25433f03, 0x49f2725f, 0x46e00000,
+    // [27]: 3043 "ぃ"
+    0x07202000, 0xfe001fff, 0x001d0000, 0xf72f8162, 0xf7262f62, 0x72f52512, 0x2fc212fc, 0x4fe39000,
+    // [35]: 3044 "い"
+    0x0a202000, 0xfe0000ff, 0x00070800, 0xf91ff12f, 0x4292f339, 0x2f32a2f2, 0x3a2f22b2, 0xf12b2f31,
+    0x7222fb22, 0x2fc212fc, 0x4fe29000,
+    // [46]: 3045 "ぅ"
+    0x08202000, 0xf80047ff, 0x00380000, 0xf33fc5fc, 0x3fd7f99f, 0x7361f72f, 0xf12ff04f, 0xf04fe4ff,
+    0x11b00000,
+    // [55]: 3046 "う"
+    0x09202000, 0xfc00063f, 0x000f0000, 0xf34fb5fb, 0x3fd8f8bf, 0x5364f33f, 0xe2ff03ff, 0x03ff03ff,
+    0x04ff05fe, 0x4a000000,
+    // [65]: 3047 "ぇ"
+    0x09202000, 0xf800c7ff, 0x00020000, 0xf33fc5fc, 0x3fd8fa3f, 0xf03ff03f, 0xe4fd5fb2, 0x13f73122,
+    0x3f6632f9, 0x1f000000,
+    // [75]: 3048 "え"
+    0x0b202000, 0xf8000e3f, 0x00000000, 0xf34fb5fb, 0x3fcbf6cf, 0x63ff03ff, 0x03ff03ff, 0x03fe4fd5,
+    0xfb7fa223, 0xfa233f25, 0x1343f276, 0x1f71f100,
+    // [87]: 3049 "ぉ"
+    0x0a202000, 0xf80007ff, 0x00041000, 0xf42fa232, 0xf9bf5319, 0xf4252ff0, 0x2fa7f99f, 0x7344f625,
+    0x5f525213, 0xf4526f55, 0x24f9141a,
+    // [98]: 304A "お"
+    0x0d202000, 0xf800003f, 0x0001c080, 0xf52f8162, 0xf7262f62, 0x2bf123ce, 0x292ff02f, 0x89f6bf53,
+    0x74f2385f, 0x129213f0, 0x29222f03, 0x8232f064, 0x6f2644fe, 0x19000000,
+    // [112]: 304B "か"
+    0x0f202000, 0xfc00003f, 0x00020300, 0xf41ff12f, 0x8162f721, 0x8f531cf1, 0x21257f02, 0x1252f422,
+    0x252f4222, 0x62f23325, 0x2f314262, 0xf7262f72, 0x63f7262f, 0x721232f8, 0x352f8161, 0x70000000,
+    // [128]: 304C "が"
+    0x12202000, 0xfc000001, 0x00020208, 0x41ff0222, 0xfa313fa2, 0x22fb222b, 0x1f31c2ff, 0x02f8162f,
+    0x7218f531, 0xcf121257, 0xf021252f, 0x422252f4, 0x22262f23, 0x3252f314, 0x262f7262, 0xf7263f72,
+    0x62f72123, 0x2f8352f8, 0x16170000,
+    // [147]: 304D "き"
+    0x0a202000, 0xf800003f, 0x00600000, 0xf52fe2fa, 0x232f9cf8, 0xaf82fe3f, 0x97faf0f6, 0x219f43fe,
+    0x2fe3fe2f, 0xf92fe3fc, 0x4f6af86d,
+    // [158]: 304E "ぎ"
+    0x0d202000, 0xf8000001, 0x00600008, 0x41ff0222, 0xfa313fa2, 0x22fb222c, 0x2f11c2fa, 0x232f9cf8,
+    0xaf82fe3f, 0x97faf0f6, 0x219f43fe, 0x2fe3fe2f, 0xf92fe3fc, 0x4f6af86d,
+    // [172]: 304F "く"
+    0x0b202000, 0xfc00003f, 0x00010000, 0xe3ff02ff, 0x12ff12ff, 0x03ff03ff, 0x03ff03ff, 0x03ff03fd,
+    0x3fd3fd3f, 0xd3fd3fe2, 0xfe2fe2fe, 0x3f000000,
+    // [184]: 3050 "ぐ"
+    0x0e202000, 0xfc000001, 0x00010008, 0x41ff0222, 0xfa313fa2, 0x22fb2226, 0x3f6182ff, 0x12ff12ff,
+    0x03ff03ff, 0x03ff03ff, 0x03ff03fd, 0x3fd3fd3f, 0xd3fd3fe2, 0xfe2fe2fe, 0x3f000000,
+    // [199]: 3051 "け"
+    0x0a202000, 0xf800003f, 0x004f8600, 0xf01ff028, 0x1f6282f1, 0x592f0d22, 0xf4922f42, 0x92f4282f,
+    0x6272f636, 0x2f7261f9, 0x3ff03c00,
+    // [210]: 3052 "げ"
+    0x0d202000, 0xf8000001, 0x004f8608, 0x41ff0222, 0xfa313fa2, 0x22fb2227, 0x1f717281, 0xf6282f15,
+    0x92f0d22f, 0x4922f429, 0x2f4282f6, 0x272f6362, 0xf7261f93, 0xff03c000,
+    // [224]: 3053 "こ"
+    0x07202000, 0xfc07e0ff, 0x00300000, 0xe4fcbf63, 0x27f63ff0, 0x2ff92fe2, 0xf3734f4c, 0xfb3b0000,
+    // [232]: 3054 "ご"
+    0x0a202000, 0xfc07e001, 0x00300048, 0x41ff0222, 0xfa313fa2, 0x22ff01ff, 0x94fcbf63, 0x27f63ff0,
+    0x2ff92fe2, 0xf3734f4c, 0xfb3b0000,
+    // [243]: 3055 "さ"
+    0x08202000, 0xf800003f, 0x00f54100, 0xf52fe2f8, 0x332f8ef7, 0xbf82fe2f, 0xe2fe2ff8, 0x2fc4f6af,
+    0x86d00000,
+    // [252]: 3056 "ざ"
+    0x0b202000, 0xf8000001, 0x00f54008, 0x41ff0222, 0xfa313fa2, 0x22fb222c, 0x2f11c2ff, 0x02f8332f,
+    0x8ef7bf82, 0xfe2fe2fe, 0x2ff82fc4, 0xf6af86d0,
+    // [264]: 3057 "し"
+    0x07202000, 0xf800003f, 0x000fff00, 0xf71ff12f, 0x32a2f429, 0x2f5272f6, 0x452f88fa, 0x6fd2d000,
+    // [272]: 3058 "じ"
+    0x0b202000, 0xf8000001, 0x000ffe08, 0x41ff0222, 0xfa313fa2, 0x22fb222e, 0x1f01f02f, 0xf02f32a2,
+    0xf4292f52, 0x72f6452f, 0x88fa6fd2, 0xd0000000,
+    // [284]: 3059 "す"
+    0x08202000, 0xfc00003f, 0x0018a380, 0xf12f8f3d, 0xf5f55fc2, 0x22fb6fb5, 0xfc2ff03f, 0xf02ff03f,
+    0xf03ff03a,
+    // [293]: 305A "ず"
+    0x0c202000, 0xfc000001, 0x0018a208, 0x41ff0222, 0xfa313fa2, 0x22fb2228, 0x2f5192ff, 0x02f8f3df,
+    0x5f55fc22, 0x2fb6fb5f, 0xc2ff03ff, 0x02ff03ff, 0x03ff03a0,
+    // [306]: 305B "せ"
+    0x09202000, 0xfc00007f, 0x00470e00, 0xe161f826, 0x2f3752f2, 0xf4f321cf, 0x2262f744, 0x2f8342ff,
+    0x02fe3f4c, 0xf69c0000,
+    // [316]: 305C "ぜ"
+    0x0c202000, 0xfc000001, 0x00470e48, 0x41ff0222, 0xfa313fa2, 0x22ff0171, 0x61f8262f, 0x3752f2f4,
+    0xf321cf22, 0x62f7442f, 0x8342ff02, 0xfe3f4cf6, 0x9c000000,
+    // [329]: 305D "そ"
+    0x0a202000, 0xfc00003f, 0x00780000, 0xf08f8af8, 0x3ff03ff0, 0x3ff03ff0, 0x3f5373f3, 0xc13f5df8,
+    0x315f92ff, 0x12fd3fb5, 0xfb5e0000,
+    // [340]: 305E "ぞ"
+    0x0d202000, 0xfc000001, 0x00780008, 0x41ff0222, 0xfa313fa2, 0x22fb2227, 0x8f017af8, 0x3ff03ff0,
+    0x3ff03ff0, 0x3f5373f3, 0xc13f5df8, 0x315f92ff, 0x12fd3fb5, 0xfb5e0000,
+    // [354]: 305F "た"
+    0x0a202000, 0xfc00003f, 0x00260380, 0xf52fbaf7, 0xbfb2f537, 0x2f5732f8, 0x432ff12f, 0xf12f9242,
+    0xf9252f04, 0x1462f187, 0x2f22b160,
+    // [365]: 3060 "だ"
+    0x0e202000, 0xfc000001, 0x00260208, 0x41ff0222, 0xfa313fa2, 0x22fb222c, 0x2f11d2ff, 0x02fbaf7b,
+    0xfb2f5372, 0xf5732f84, 0x32ff12ff, 0x12f9242f, 0x9252f041, 0x462f1872, 0xf22b1600,
+    // [380]: 3061 "ち"
+    0x0a202000, 0xf800003f, 0x00582080, 0xf42f8343, 0xf6f0f6cf, 0xa2ff12f7, 0x712f6bf5, 0x364f3392,
+    0xf32ff12f, 0xf13ff04f, 0xe8fc4b00,
+    // [391]: 3062 "ぢ"
+    0x0e202000, 0xf8000001, 0x00582008, 0x41ff0222, 0xfa313fa2, 0x22fb222b, 0x2f21c2f8, 0x343f6f0f,
+    0x6cfa2ff1, 0x2f7712f6, 0xbf5364f3, 0x392f32ff, 0x12ff13ff, 0x04fe8fc4, 0xb0000000,
+    // [406]: 3063 "っ"
+    0x07202000, 0xfc003fff, 0x00180000, 0xf14fbaf6, 0x345f4291, 0xf52ff12f, 0xf03ff05f, 0xe5fe3b00,
+    // [414]: 3064 "つ"
+    0x0a202000, 0xfc0003ff, 0x00000000, 0xf06f8cf4, 0x456f13b3, 0xf02fe3fe, 0x2ff03ff0, 0x2ff03ff0,
+    0x2ff13ff0, 0x4fe6fe5f, 0xf01b0000,
+    // [425]: 3065 "づ"
+    0x0d202000, 0xfc000301, 0x00000048, 0x41ff0222, 0xfa313fa2, 0x22ff01ff, 0xa6f8cf44, 0x56f13b3f,
+    0x02fe3fe2, 0xff03ff02, 0xff03ff02, 0xff13ff04, 0xfe6fe5ff, 0x01b00000,
+    // [439]: 3066 "て"
+    0x09202000, 0xfc00007f, 0x003e0000, 0xb1ff0af8, 0xf0f4437f, 0x53ff03ff, 0x02ff12ff, 0x03ff02fd,
+    0x3fa7f96f, 0xc1f30000,
+    // [449]: 3067 "で"
+    0x0c202000, 0xfc000001, 0x003e0048, 0x41ff0222, 0xfa313fa2, 0x22ff0141, 0xff0af8f0, 0xf4437f53,
+    0xff03ff02, 0xff12ff03, 0xff02fd3f, 0xa7f96fc1, 0xf3000000,
+    // [462]: 3068 "と"
+    0x0a202000, 0xf800003f, 0x00b00280, 0xf62fe2f8, 0x162f7352, 0xf8422fb3, 0x12fc5fd4, 0xff03ff03,
+    0xff02ff12, 0xfe2f4525, 0xf6afb2f0,
+    // [473]: 3069 "ど"
+    0x0e202000, 0xf8000001, 0x00b00208, 0x41ff0222, 0xfa313fa2, 0x22fb222d, 0x2f01e2fe, 0x2f8162f7,
+    0x352f8422, 0xfb312fc5, 0xfd4ff03f, 0xf03ff02f, 0xf12fe2f4, 0x525f6afb, 0x2f000000,
+    // [488]: 306A "な"
+    0x0c202000, 0xf800003f, 0x00030380, 0xf62f8219, 0xf431af23, 0x72f4382f, 0x322262f7, 0x272f6282,
+    0xf5283f46, 0x51f39f75, 0x32f63123, 0x2f6236f5, 0x344f71f6,
+    // [501]: 306B "に"
+    0x09202000, 0xfc00007f, 0x0027f000, 0xf91f4392, 0xf2a32f13, 0x2632ff02, 0xfa232fa2, 0x22fa322f,
+    0x243332f3, 0x841f73f0,
+    // [511]: 306C "ぬ"
+    0x11202000, 0xf800003f, 0x00000800, 0xf11ff02f, 0xf0261f83, 0x52f8242f, 0x5732f4a1, 0x2f3347f3,
+    0x25223f23, 0x6214f127, 0x214f1287, 0xf028322f, 0x02153223, 0xe92322f0, 0x33218e41, 0x41214f08,
+    0x2132f032, 0x2fb1f700,
+    // [529]: 306D "ね"
+    0x0e202000, 0xfc00003f, 0x00000380, 0xf62fd4fd, 0x7f5418f2, 0x713f4436, 0xf4274f42, 0x84f22a3f,
+    0x22a4f121, 0x455f0845, 0xf1332321, 0x3e432322, 0x1f051332, 0xf231542f, 0x31335290,
+    // [544]: 306E "の"
+    0x0e202000, 0xfc0001ff, 0x00040000, 0xf04fb9f6, 0x427f4243, 0x22f32623, 0x2f136233, 0xf027242f,
+    0x027243e2, 0x8242e283, 0x32f02823, 0x2f038222, 0xf1375f34, 0x63f64fe4, 0xd0000000,
+    // [559]: 306F "は"
+    0x0b202000, 0xfc00003f, 0x000fc600, 0xf01ff028, 0x1f6282f1, 0xb32f0c32, 0xf5282f46, 0x42f4832f,
+    0x361322f2, 0x3231222f, 0x134521f3, 0x171d0000,
+    // [571]: 3070 "ば"
+    0x0e202000, 0xfc000001, 0x000fc608, 0x41ff0222, 0xfa313fa2, 0x22fb2227, 0x1f717281, 0xf6282f1b,
+    0x32f0c32f, 0x5282f464, 0x2f4832f3, 0x61322f23, 0x231222f1, 0x34521f31, 0x71d00000,
+    // [586]: 3071 "ぱ"
+    0x0c202000, 0xfc000003, 0x000fc630, 0x35fc131f, 0xc571ff02, 0x81f6282f, 0x1b32f0c3, 0x2f5282f4,
+    0x642f4832, 0xf361322f, 0x23231222, 0xf134521f, 0x3171d000,
+    // [599]: 3072 "ひ"
+    0x0e202000, 0xfc0000ff, 0x00000000, 0xe1ff0256, 0xf4257f32, 0x52f7353f, 0x6362f636, 0x3f4472f3,
+    0x572f1412, 0x73f12228, 0x2f5282f6, 0x272f6262, 0xf7352f83, 0x23fa6fd2, 0xc0000000,
+    // [614]: 3073 "び"
+    0x11202000, 0xfc000001, 0x00000048, 0x41ff0222, 0xfa313fa2, 0x22ff01ff, 0x91ff0256, 0xf4257f32,
+    0x52f7353f, 0x6362f636, 0x3f4472f3, 0x572f1412, 0x73f12228, 0x2f5282f6, 0x272f6262, 0xf7352f83,
+    0x23fa6fd2, 0xc0000000,
+    // [632]: 3074 "ぴ"
+    0x0f202000, 0xfc000083, 0x00000030, 0x35fc131f, 0xc5ff81ff, 0x0256f425, 0x7f3252f7, 0x353f6362,
+    0xf6363f44, 0x72f3572f, 0x141273f1, 0x22282f52, 0x82f6272f, 0x6262f735, 0x2f8323fa, 0x6fd2c000,
+    // [648]: 3075 "ふ"
+    0x0d202000, 0xf800063f, 0x00020000, 0xf33fd3fd, 0x3fe2ff03, 0xff03ff03, 0xff02f826, 0x2f626232,
+    0xf126332f, 0x035342e3, 0x5362e153, 0x72f5283f, 0x4651f64f, 0xf01d0000,
+    // [662]: 3076 "ぶ"
+    0x10202000, 0xf8000601, 0x00020008, 0x41ff0222, 0xfa313fa2, 0x22fb222a, 0x3f21a3fd, 0x3fe2ff03,
+    0xff03ff03, 0xff02f826, 0x2f626232, 0xf126332f, 0x035342e3, 0x5362e153, 0x72f5283f, 0x4651f64f,
+    0xf01d0000,
+    // [679]: 3077 "ぷ"
+    0x0e202000, 0xf8000603, 0x00020030, 0x35fc131f, 0xc5a3fd3f, 0xd3fe2ff0, 0x3ff03ff0, 0x3ff02f82,
+    0x62f62623, 0x2f126332, 0xf035342e, 0x35362e15, 0x372f5283, 0xf4651f64, 0xff01d000,
+    // [694]: 3078 "へ"
+    0x09202000, 0xff0007ff, 0x00000000, 0xf53fd5fb, 0x312fa332, 0xf8343f63, 0x63f4383f, 0x23a1f14f,
+    0xc3fd3fd3, 0xff01f700,
+    // [704]: 3079 "べ"
+    0x0c202000, 0xff000701, 0x00000048, 0x41ff0222, 0xfa313fa2, 0x22ff01ff, 0xf03fd5fb, 0x312fa332,
+    0xf8343f63, 0x63f4383f, 0x23a1f14f, 0xc3fd3fd3, 0xff01f700,
+    // [717]: 307A "ぺ"
+    0x0b202000, 0xff000783, 0x00000030, 0x35fc131f, 0xc5ffe3fd, 0x5fb312fa, 0x332f8343, 0xf6363f43,
+    0x83f23a1f, 0x14fc3fd3, 0xfd3ff01f, 0x70000000,
+    // [729]: 307B "ほ"
+    0x0b202000, 0xfc00007f, 0x000eb800, 0xf91f3942, 0xf1b32f52, 0x82f1b32f, 0x5282f464, 0x2f4832f3,
+    0x61322f23, 0x231222f1, 0x34521f31, 0x71d00000,
+    // [741]: 307C "ぼ"
+    0x0e202000, 0xfc000001, 0x000eb848, 0x41ff0222, 0xfa313fa2, 0x22ff01f2, 0x1f3942f1, 0xb32f5282,
+    0xf1b32f52, 0x82f4642f, 0x4832f361, 0x322f2323, 0x1222f134, 0x521f3171, 0xd0000000,
+    // [756]: 307D "ぽ"
+    0x0c202000, 0xfc000003, 0x000eb830, 0x35fc131f, 0xc5fff31f, 0x3942f1b3, 0x2f5282f1, 0xb32f5282,
+    0xf4642f48, 0x32f36132, 0x2f232312, 0x22f13452, 0x1f3171d0,
+    // [769]: 307E "ま"
+    0x09202000, 0xfc00003f, 0x000eb180, 0xf22fadf3, 0xf0f82fad, 0xf92fe6f9, 0x9f641223, 0xf434312f,
+    0x3355f518, 0x2a000000,
+    // [779]: 307F "み"
+    0x0e202000, 0xfc0000ff, 0x00004000, 0xf28f99f8, 0x3ff02fa2, 0x32fa242f, 0x9243f825, 0x2f8262f5,
+    0x643f2a32, 0xf3212233, 0x2f622323, 0xf523322f, 0x623313f5, 0x336f6244, 0x60000000,
+    // [794]: 3080 "む"
+    0x0c202000, 0xf800003f, 0x00440180, 0xf52f7172, 0xf622bf12, 0x3ce292f3, 0x2a2ff02f, 0xf04fd5fc,
+    0x213fb5f3, 0x274f4272, 0xf7253f74, 0x23f97fc3, 0xe0000000,
+    // [807]: 3081 "め"
+    0x10202000, 0xfc00003f, 0x00000100, 0xf11ff02f, 0xf0351f92, 0x52f8242f, 0x5732f4a1, 0x2f3347f3,
+    0x25223f23, 0x6214f127, 0x214f1287, 0xf028322f, 0x029223e2, 0x8422f027, 0x8f135124, 0xf3382f53,
+    0xf1000000,
+    // [824]: 3082 "も"
+    0x0b202000, 0xf800003f, 0x00088180, 0xf52ff12f, 0x9bf6cfb2, 0xff03f429, 0x2f423bf1, 0x23af2292,
+    0xf4382f52, 0x82f5363f, 0x6262f833, 0x3f97fc3c,
+    // [836]: 3083 "ゃ"
+    0x09202000, 0xf8000fff, 0x03808000, 0xf3211fc2, 0x13f79f72, 0x55f52521, 0x3f4333f9, 0x412fc212,
+    0xfe3fe2ff, 0x01e00000,
+    // [846]: 3084 "や"
+    0x0c202000, 0xfc00007f, 0x02600000, 0xf3221fb2, 0x32f6622f, 0x5912f523, 0x7f4285f2, 0x286f1272,
+    0x33f0272f, 0x6443f842, 0x2fb222fe, 0x3fe2fe3f, 0xe2e00000,
+    // [859]: 3085 "ゅ"
+    0x0d202000, 0xf80007ff, 0x00002000, 0xf21ff12f, 0xf0232f75, 0x32f6722f, 0x532422f4, 0x23512f42,
+    0x321212f4, 0x23224f42, 0x3314f432, 0x232f6632, 0xf76ff03f, 0xf02b0000,
+    // [873]: 3086 "ゆ"
+    0x11202000, 0xf800007f, 0x00000100, 0xf12ff026, 0x1f8262f4, 0x562f2852, 0xf223442f, 0x124532f1,
+    0x2421322f, 0x03422322, 0xe2523222, 0xe2524212, 0xe34425f0, 0x24433f12, 0x4343f284, 0x3f3751f9,
+    0x3ff03ff0, 0x3a000000,
+    // [891]: 3087 "ょ"
+    0x08202000, 0xf8000fff, 0x0018e000, 0xf32fa7fb, 0x6ff02fd6, 0xf99f7322, 0x12f6335f, 0x6253f71f,
+    0x40000000,
+    // [900]: 3088 "よ"
+    0x08202000, 0xf800007f, 0x000f8f00, 0xf32f98f8, 0x9ff02fb9, 0xf6cf4432, 0x32f23522, 0x3f2266f2,
+    0x382f51f6,
+    // [909]: 3089 "ら"
+    0x0a202000, 0xf800023f, 0x00503000, 0xf53fc4fd, 0x3ff41ff1, 0x2f6812f5, 0xcf4283f3, 0x393f22b1,
+    0xf32ff12f, 0xf14fe8fc, 0x6ff01a00,
+    // [920]: 308A "り"
+    0x0b202000, 0xf800003f, 0x0020d600, 0xf01ff027, 0x1f7272f5, 0x282f5292, 0xf4283f42, 0x73f5272f,
+    0x62ff12ff, 0x03ff02ff, 0x13ff03ff, 0x03b00000,
+    // [932]: 308B "る"
+    0x0d202000, 0xf80000ff, 0x00000000, 0xebf5df52, 0xff12ff12, 0xff03ff03, 0xfb7f8af6, 0x353f5373,
+    0xf4293f32, 0x4423f223, 0x623f2222, 0x2231f335, 0x2f89f97f, 0xd2e00000,
+    // [946]: 308C "れ"
+    0x0c202000, 0xfc00003f, 0x00020380, 0xf62fd4fd, 0x7f6cf49f, 0x8234f824, 0x3f8253f8, 0x244f7245,
+    0xf624212f, 0x624213f5, 0x24221e51, 0x342f3752, 0xf6362900,
+    // [959]: 308D "ろ"
+    0x0c202000, 0xf80000ff, 0x00000000, 0xebf5df52, 0xff12ff12, 0xff03ff03, 0xfb7f8af6, 0x353f5373,
+    0xf4293f32, 0xa3f22b3f, 0x22b1f33f, 0xf05fd9fc, 0x4b000000,
+    // [972]: 308E "ゎ"
+    0x09202000, 0xf80007ff, 0x00203000, 0xf52fe3fe, 0x6f6bf59f, 0x7254f626, 0x3f6264f5, 0x265f5342,
+    0x11f7422f, 0xa412fd1d,
+    // [982]: 308F "わ"
+    0x0d202000, 0xfc00003f, 0x00000380, 0xf62fd4fd, 0x7f5418f2, 0x713f4436, 0xf4274f42, 0x84f22a3f,
+    0x22a4f12a, 0x5f1295f1, 0x29213f12, 0x8221f336, 0x2f7532f9, 0x42290000,
+    // [996]: 3090 "ゐ"
+    0x10202000, 0xf80000ff, 0x00000000, 0xf28f99f9, 0x2ff03ff0, 0x3ff03f73, 0x52f5742f, 0x333333f1,
+    0x35242f12, 0x7232f123, 0x642f0227, 0x42f02222, 0x432f0265, 0x13f12521, 0x5f241424, 0xf4641f81,
+    0xf2000000,
+    // [1013]: 3091 "ゑ"
+    0x0e202000, 0xf800003f, 0x00000000, 0xf09f7bf7, 0x3ff03ff0, 0x3ff03fc6, 0xf9af6335, 0xf6242fa2,
+    0x32fa7fb6, 0xf922222f, 0x6cf5df32, 0x1812f232, 0x22232f12, 0x412143e3, 0xd1f11f60,
+    // [1028]: 3092 "を"
+    0x0b202000, 0xf800003f, 0x00c01080, 0xf52ff12f, 0x9bf5dfc2, 0xfa412f32, 0x49f15223, 0x4f3744f4,
+    0x562f63ff, 0x03ff03ff, 0x02fa7f87, 0xfb1f4000,
+    // [1040]: 3093 "ん"
+    0x0c202000, 0xf800003f, 0x00025a80, 0xf22ff12f, 0xf12ff12f, 0xf12fc5fb, 0x223f2262, 0x32f32523,
+    0x3f234242, 0xf333242f, 0x422352f4, 0x562f5472, 0xff016000,
+    // [1053]: 3094 "ゔ"
+    0x0c202000, 0xfc000601, 0x000f0008, 0x41ff0222, 0xfa313fa2, 0x22fb222a, 0x4f1195fb, 0x3fd8f8bf,
+    0x5364f33f, 0xe2ff03ff, 0x03ff03ff, 0x04ff05fe, 0x4a000000,
+    // [1066]: 3095 "ゕ"
+    0x0b202000, 0xfc0007ff, 0x00102000, 0xf41ff12f, 0x9242f9af, 0x6df42123, 0x211f5212, 0x32f62223,
+    0x2f622242, 0xf9242fa4, 0x13f9422f, 0xa2328000,
+    // [1078]: 3096 "ゖ"
+    0x08202000, 0xf8000fff, 0x00d86000, 0xf0252f57, 0x32f5912f, 0x723212f7, 0x262f8252, 0xf9232fa4,
+    0xfe3c0000,
+    // [1087]: 309B "゛"
+    0x06202000, 0xffffe03f, 0x00000a00, 0xf5231fa3, 0x32f9232f, 0x9232ff02, 0x70000000,
+    // [1094]: 309C "゜"
+    0x06202000, 0xffffc03f, 0x00000400, 0xf72fd6fa, 0x232fa152, 0xf9242fa2, 0x22fb5700,
+    // [1101]: 309D "ゝ"
+    0x07202000, 0xff8007ff, 0x00020000, 0xf62fe3fc, 0x4fc3fd3f, 0xd2ff12ff, 0x04ff03ff, 0x03ff01b0,
+    // [1109]: 309E "ゞ"
+    0x0a202000, 0xff800701, 0x00020048, 0x41ff0222, 0xfa313fa2, 0x22ff01ff, 0xf12fe3fc, 0x4fc3fd3f,
+    0xd2ff12ff, 0x04ff03ff, 0x03ff01b0,
+    // [1120]: 30A1 "ァ"
+    0x08202000, 0xfc000fff, 0x00382000, 0xcdf52ff0, 0x322fb312, 0xfc212ff0, 0x2ff12ff0, 0x3ff03ff0,
+    0x38000000,
+    // [1129]: 30A2 "ア"
+    0x0b202000, 0xf800007f, 0x000f0000, 0xaf1f0f3f, 0x03ff02ff, 0x1242fa23, 0x2fa322fb, 0x222ff02f,
+    0xf03ff02f, 0xf12ff03f, 0xf04ff03f, 0xf0170000,
+    // [1141]: 30A3 "ィ"
+    0x08202000, 0xf80007ff, 0x03c00000, 0xe2ff03ff, 0x02ff12ff, 0x12ff03fe, 0x5fc213fb, 0x224f9242,
+    0xf92ff11d,
+    // [1150]: 30A4 "イ"
+    0x0a202000, 0xfc00001f, 0x03f00000, 0xd1ff03ff, 0x02ff12ff, 0x03ff03ff, 0x03ff03fe, 0x4fd5fc21,
+    0x4fa233f9, 0x244f7261, 0xf82d0000,
+    // [1161]: 30A5 "ゥ"
+    0x08202000, 0xf80007ff, 0x0002b000, 0xf22facf5, 0x282f6272, 0xf62ff03f, 0xf02ff13f, 0xf03ff04f,
+    0xe3ff11b0,
+    // [1170]: 30A6 "ウ"
+    0x08202000, 0xfc00003f, 0x000af180, 0xf22f9ef2, 0xf1f12c2f, 0x22ff12ff, 0x12ff04ff, 0x03ff04fe,
+    0x4ff03a00,
+    // [1179]: 30A7 "ェ"
+    0x04202000, 0xfe001fff, 0x017f4000, 0xdafb2fad, 0x70000000,
+    // [1184]: 30A8 "エ"
+    0x05202000, 0xfe0000ff, 0x007ff800, 0xccf4ef92, 0xf7f3f0f1, 0x60000000,
+    // [1190]: 30A9 "ォ"
+    0x08202000, 0xf80007ff, 0x02017000, 0xf02fcdf7, 0x4fd5fc21, 0x3fb223fa, 0x233f9243, 0xf8253f74,
+    0xfe2e0000,
+    // [1199]: 30AA "オ"
+    0x0c202000, 0xf800003f, 0x00000780, 0xe2fbf1f0, 0xf3f44fd5, 0xfc213fb2, 0x23fa233f, 0x9243f825,
+    0x3f7263f6, 0x273f5283, 0xf4291f52, 0x12fc4fe2, 0xf0000000,
+    // [1212]: 30AB "カ"
+    0x0b202000, 0xfc00003f, 0x0001a380, 0xf32f9df3, 0xf0f2252f, 0x8262f726, 0x3f6272f6, 0x273f5282,
+    0xf5292f42, 0x93f4292f, 0x4653f447, 0x17000000,
+    // [1224]: 30AC "ガ"
+    0x0f202000, 0xfc000001, 0x0001a208, 0x41ff0222, 0xfa313fa2, 0x22fb222a, 0x2f31b2ff, 0x02f9df3f,
+    0x0f2252f8, 0x262f7263, 0xf6272f62, 0x73f5282f, 0x5292f429, 0x3f4292f4, 0x653f4471, 0x70000000,
+    // [1240]: 30AD "キ"
+    0x08202000, 0xfc00003f, 0x03304180, 0xf42f8242, 0xf89fbbfa, 0x8f92f654, 0x2f6df9df, 0x7234f82f,
+    0xe3fe2e00,
+    // [1249]: 30AE "ギ"
+    0x0c202000, 0xfc000001, 0x03304008, 0x41ff0222, 0xfa313fa2, 0x22fb222b, 0x2f21c2ff, 0x02f8242f,
+    0x89fbbfa8, 0xf92f6542, 0xf6df9df7, 0x234f82fe, 0x3fe2e000,
+    // [1262]: 30AF "ク"
+    0x0d202000, 0xfc00003f, 0x00000000, 0xf42ff03f, 0xf02f7bf5, 0xdf5282f5, 0x292f4293, 0xf4292f42,
+    0xa2f32ff1, 0x2ff03ff0, 0x3ff02ff1, 0x2ff12ff1, 0x2ff03ff0, 0x39000000,
+    // [1276]: 30B0 "グ"
+    0x10202000, 0xfc000001, 0x00000008, 0x41ff0222, 0xfa313fa2, 0x22fb222b, 0x2f21c3ff, 0x02f7bf5d,
+    0xf5282f52, 0x92f4293f, 0x4292f42a, 0x2f32ff12, 0xff03ff03, 0xff02ff12, 0xff12ff12, 0xff03ff03,
+    0x90000000,
+    // [1293]: 30B1 "ケ"
+    0x0a202000, 0xfc00003f, 0x000a4280, 0xf62ff12f, 0xf12f2f0f, 0x1f2f6272, 0xf6282f52, 0xff12ff03,
+    0xff02ff12, 0xff03ff03, 0xff039000,
+    // [1304]: 30B2 "ゲ"
+    0x0d202000, 0xfc000001, 0x000a4208, 0x41ff0222, 0xfa313fa2, 0x22fb222d, 0x2f01e2ff, 0x12ff12f2,
+    0xf0f1f2f6, 0x272f6282, 0xf52ff12f, 0xf03ff02f, 0xf12ff03f, 0xf03ff039,
+    // [1318]: 30B3 "コ"
+    0x05202000, 0xfe0000ff, 0x007ff800, 0xcdf3f0f2, 0x2ff0f0f3, 0xd7000000,
+    // [1324]: 30B4 "ゴ"
+    0x08202000, 0xfe000001, 0x007ff848, 0x41ff0222, 0xfa313fa2, 0x22ff01ff, 0x7df3f0f2, 0x2ff0f0f3,
+    0xd7000000,
+    // [1333]: 30B5 "サ"
+    0x08202000, 0xfc00003f, 0x0017c700, 0xe161f826, 0x2f3f3df5, 0xf2262f82, 0xff03ff02, 0xff12ff05,
+    0xfe4a0000,
+    // [1342]: 30B6 "ザ"
+    0x0c202000, 0xfc000001, 0x0017c608, 0x41ff0222, 0xfa313fa2, 0x22fb2226, 0x161f1162, 0x62f7262f,
+    0x3f3df5f2, 0x262f82ff, 0x03ff02ff, 0x12ff05fe, 0x4a000000,
+    // [1355]: 30B7 "シ"
+    0x0c202000, 0xfc00007f, 0x00000000, 0xf91ff03f, 0xc4f3192f, 0x43ff02ff, 0x12b1f329, 0x4f3273f5,
+    0x362f72ff, 0x12ff03ff, 0x03ff04ff, 0x04ff04fe, 0x4ff01700,
+    // [1368]: 30B8 "ジ"
+    0x0f202000, 0xfc000001, 0x00000048, 0x41ff0222, 0xfa313fa2, 0x22ff01f2, 0x1ff03fc4, 0xf3192f43,
+    0xff02ff12, 0xb1f3294f, 0x3273f536, 0x2f72ff12, 0xff03ff03, 0xff04ff04, 0xff04fe4f, 0xf0170000,
+    // [1384]: 30B9 "ス"
+    0x0c202000, 0xfc0000ff, 0x00000000, 0xdcf4ef42, 0xff03ff02, 0xff12ff03, 0xff02ff12, 0xfe4fc6fa,
+    0x323f7353, 0xf5373f33, 0x93f13b3f, 0x11d3ff01, 0x60000000,
+    // [1397]: 30BA "ズ"
+    0x0f202000, 0xfc000001, 0x00000048, 0x41ff0222, 0xfa313fa2, 0x22ff01ff, 0x8cf4ef42, 0xff03ff02,
+    0xff12ff03, 0xff02ff12, 0xfe4fc6fa, 0x323f7353, 0xf5373f33, 0x93f13b3f, 0x11d3ff01, 0x60000000,
+    // [1413]: 30BB "セ"
+    0x08202000, 0xfe00003f, 0x007c0700, 0xf61ff12f, 0x4652f3f3, 0xf033cf03, 0x72f6362f, 0x7262ff02,
+    0xf5cf6aa0,
+    // [1422]: 30BC "ゼ"
+    0x0c202000, 0xfe000001, 0x007c0608, 0x41ff0222, 0xfa313fa2, 0x22fb222d, 0x1f11e2ff, 0x02f4652f,
+    0x3f3f033c, 0xf0372f63, 0x62f7262f, 0xf02f5cf6, 0xaa000000,
+    // [1435]: 30BD "ソ"
+    0x0c202000, 0xfc00007f, 0x00004000, 0xc1ff02b1, 0xf4293f32, 0x92f4283f, 0x4282f626, 0x2f73ff02,
+    0xff03ff02, 0xff12ff12, 0xff03ff03, 0xff03ff03, 0xff01a000,
+    // [1448]: 30BE "ゾ"
+    0x0f202000, 0xfc000001, 0x00004048, 0x41ff0222, 0xfa313fa2, 0x22ff0151, 0xff02b1f4, 0x293f3292,
+    0xf4283f42, 0x82f6262f, 0x73ff02ff, 0x03ff02ff, 0x12ff12ff, 0x03ff03ff, 0x03ff03ff, 0x01a00000,
+    // [1464]: 30BF "タ"
+    0x0d202000, 0xfc00003f, 0x00000000, 0xf42ff03f, 0xf02f7bf5, 0xdf5282f5, 0x292f4293, 0xf426212f,
+    0x424422f3, 0x224fa5fb, 0x4fd5ff02, 0xff12ff12, 0xff12ff03, 0xff039000,
+    // [1478]: 30C0 "ダ"
+    0x10202000, 0xfc000001, 0x00000008, 0x41ff0222, 0xfa313fa2, 0x22fb222b, 0x2f21c3ff, 0x02f7bf5d,
+    0xf5282f52, 0x92f4293f, 0x426212f4, 0x24422f32, 0x24fa5fb4, 0xfd5ff02f, 0xf12ff12f, 0xf12ff03f,
+    0xf0390000,
+    // [1495]: 30C1 "チ"
+    0x09202000, 0xfc00001f, 0x001f1800, 0xd1ff05fd, 0x7fe6fb21, 0x4fa2f8f1, 0xf0f3f72f, 0xf03ff02f,
+    0xf03ff02f, 0xf12a0000,
+    // [1505]: 30C2 "ヂ"
+    0x0c202000, 0xfc000001, 0x001f1808, 0x41ff0222, 0xfa313fa2, 0x2251f522, 0x245f6167, 0xfe6fb214,
+    0xfa2f8f1f, 0x0f3f72ff, 0x03ff02ff, 0x03ff02ff, 0x12a00000,
+    // [1518]: 30C3 "ッ"
+    0x0b202000, 0xfc001fff, 0x00000000, 0xd152f923, 0x232f5232, 0x22f62321, 0x3f721222, 0xf8252f83,
+    0xff02ff12, 0xff03ff03, 0xff03ff03, 0xa0000000,
+    // [1530]: 30C4 "ツ"
+    0x0e202000, 0xfc0000ff, 0x00000000, 0xb171f727, 0x241f2253, 0x33f12524, 0x2f225232, 0xf423242f,
+    0x423232f5, 0x372f62ff, 0x03ff02ff, 0x03ff03ff, 0x03ff03ff, 0x03ff03ff, 0x03900000,
+    // [1545]: 30C5 "ヅ"
+    0x11202000, 0xfc000001, 0x00000048, 0x41ff0222, 0xfa313fa2, 0x22ff01ff, 0x6171f727, 0x241f2253,
+    0x33f12524, 0x2f225232, 0xf423242f, 0x423232f5, 0x372f62ff, 0x03ff02ff, 0x03ff03ff, 0x03ff03ff,
+    0x03ff03ff, 0x03900000,
+    // [1563]: 30C6 "テ"
+    0x06202000, 0xfc001e7f, 0x015f0000, 0xccf4ef2f, 0x1f0f3f72, 0xff12ff12, 0xff12a000,
+    // [1570]: 30C7 "デ"
+    0x09202000, 0xfc001e01, 0x015f0048, 0x41ff0222, 0xfa313fa2, 0x22ff015c, 0xf4ef2f1f, 0x0f3f72ff,
+    0x12ff12ff, 0x12a00000,
+    // [1580]: 30C8 "ト"
+    0x06202000, 0xfc00003f, 0x03f81f80, 0xf62fd4fa, 0x7f8522f6, 0x542f6272, 0xff029000,
+    // [1587]: 30C9 "ド"
+    0x0a202000, 0xfc000001, 0x03f81e08, 0x41ff0222, 0xfa313fa2, 0x22fb222d, 0x2f01e2ff, 0x02fd4fa7,
+    0xf8522f65, 0x42f6272f, 0xf0290000,
+    // [1598]: 30CA "ナ"
+    0x07202000, 0xf800003f, 0x001f8f80, 0xf12f8f3d, 0xf5f52ff1, 0x2ff03ff0, 0x4fe4ff03, 0xff019000,
+    // [1606]: 30CB "ニ"
+    0x04202000, 0xff3ff9ff, 0x00000000, 0xccf4ef1f, 0x3f0f1600,
+    // [1611]: 30CC "ヌ"
+    0x0b202000, 0xf80000ff, 0x00002800, 0xcdf3f0f3, 0x2ff12ff0, 0x352f8243, 0xf9214fa5, 0xfd3fd5fa,
+    0x413f8343, 0xf6363f61, 0x83ff03ff, 0x03ff0170,
+    // [1623]: 30CD "ネ"
+    0x0c202000, 0xfc00003f, 0x03000180, 0xf22fadf3, 0xf0f33ff0, 0x4ff03ff0, 0x3ff04fa2, 0x15f83121,
+    0x3f632223, 0xf433234f, 0x134253e3, 0x5261f116, 0x2ff02d00,
+    // [1636]: 30CE "ノ"
+    0x0a202000, 0xf800003f, 0x0000b600, 0xc1ff02ff, 0x12ff12ff, 0x12ff12ff, 0x03ff03ff, 0x03ff03ff,
+    0x03ff03ff, 0x03ff03ff, 0x03ff0170,
+    // [1647]: 30CF "ハ"
+    0x0b202000, 0xff8001ff, 0x00000000, 0xf0231fb2, 0x32f9332f, 0x9252f735, 0x2f7272f5, 0x372f5283,
+    0xf3392f32, 0xa2f23b2f, 0x12c2f03c, 0x3f01e160,
+    // [1659]: 30D0 "バ"
+    0x0e202000, 0xff800101, 0x00000048, 0x41ff0222, 0xfa313fa2, 0x22ff01ff, 0xa231fb23, 0x2f9332f9,
+    0x252f7352, 0xf7272f53, 0x72f5283f, 0x3392f32a, 0x2f23b2f1, 0x2c2f03c3, 0xf01e1600,
+    // [1674]: 30D1 "パ"
+    0x0d202000, 0xff800183, 0x00000030, 0x35fc131f, 0xc5ff9231, 0xfb232f93, 0x32f9252f, 0x7352f727,
+    0x2f5372f5, 0x283f3392, 0xf32a2f23, 0xb2f12c2f, 0x03c3f01e, 0x16000000,
+    // [1688]: 30D2 "ヒ"
+    0x07202000, 0xfe00003f, 0x007e0700, 0xf81ff12f, 0x4382f465, 0x2f6812fa, 0x7fe3ff02, 0xf2f0f3d8,
+    // [1696]: 30D3 "ビ"
+    0x0b202000, 0xfe000001, 0x007e0608, 0x41ff0222, 0xfa313fa2, 0x22fb222f, 0x01e1f12f, 0xf02f4382,
+    0xf4652f68, 0x12fa7fe3, 0xff02f2f0, 0xf3d80000,
+    // [1708]: 30D4 "ピ"
+    0x09202000, 0xfe000003, 0x007e0730, 0x35fc131f, 0xc5f01ff1, 0x2f4382f4, 0x652f6812, 0xfa7fe3ff,
+    0x02f2f0f3, 0xd8000000,
+    // [1718]: 30D5 "フ"
+    0x0a202000, 0xfc0000ff, 0x00005800, 0xbf0f1f2f, 0x12ff12ff, 0x03ff02ff, 0x03ff03ff, 0x02ff12ff,
+    0x12ff12ff, 0x03ff03ff, 0x03900000,
+    // [1729]: 30D6 "ブ"
+    0x0d202000, 0xfc000001, 0x00005848, 0x41ff0222, 0xfa313fa2, 0x22ff01ff, 0x6f0f1f2f, 0x12ff12ff,
+    0x03ff02ff, 0x03ff03ff, 0x02ff12ff, 0x12ff12ff, 0x03ff03ff, 0x03900000,
+    // [1743]: 30D7 "プ"
+    0x0b202000, 0xfc000083, 0x00005830, 0x35fc131f, 0xc5ff5f0f, 0x1f2f12ff, 0x12ff03ff, 0x02ff03ff,
+    0x03ff02ff, 0x12ff12ff, 0x12ff03ff, 0x03ff0390,
+    // [1755]: 30D8 "ヘ"
+    0x0a202000, 0xff0003ff, 0x00000000, 0xf62fe3fd, 0x5fb313f9, 0x332f8352, 0xf6363f42, 0x93f13b1f,
+    0x13fd3fd3, 0xfd3ff01f, 0x70000000,
+    // [1766]: 30D9 "ベ"
+    0x0d202000, 0xff000301, 0x00000048, 0x41ff0222, 0xfa313fa2, 0x22ff01ff, 0xf12fe3fd, 0x5fb313f9,
+    0x332f8352, 0xf6363f42, 0x93f13b1f, 0x13fd3fd3, 0xfd3ff01f, 0x70000000,
+    // [1780]: 30DA "ペ"
+    0x0b202000, 0xff000383, 0x00000030, 0x35fc131f, 0xc5fff02f, 0xe3fd5fb3, 0x13f9332f, 0x8352f636,
+    0x3f4293f1, 0x3b1f13fd, 0x3fd3fd3f, 0xf01f7000,
+    // [1792]: 30DB "ホ"
+    0x0c202000, 0xf800003f, 0x00014780, 0xf22f7f3d, 0xf5f62fa2, 0x3232f433, 0x233f3242, 0x42f23424,
+    0x3f125252, 0xf035253f, 0x016261f8, 0x2ff04fd3, 0xff02c000,
+    // [1805]: 30DC "ボ"
+    0x10202000, 0xf8000001, 0x00014608, 0x41ff0222, 0xfa313fa2, 0x22fb2229, 0x2f41a2ff, 0x02f7f3df,
+    0x5f62fa23, 0x232f4332, 0x33f32424, 0x2f234243, 0xf125252f, 0x035253f0, 0x16261f82, 0xff04fd3f,
+    0xf02c0000,
+    // [1822]: 30DD "ポ"
+    0x0e202000, 0xf8000003, 0x00014730, 0x35fc131f, 0xc592ff02, 0xf7f3df5f, 0x62fa2323, 0x2f433233,
+    0xf324242f, 0x234243f1, 0x25252f03, 0x5253f016, 0x261f82ff, 0x04fd3ff0, 0x2c000000,
+    // [1837]: 30DE "マ"
+    0x0a202000, 0xff0000ff, 0x00000000, 0xaf1f0f3f, 0x03ff03ff, 0x03ff02ff, 0x1242fa22, 0x3fa312fc,
+    0x5fd3fe2f, 0xe3fe2fe2, 0xff11f000,
+    // [1848]: 30DF "ミ"
+    0x09202000, 0xfc06107f, 0x00000000, 0xf71fd5f9, 0x7f77fa4f, 0xf63fa7f7, 0x7fa3ff83, 0xfb5fa5fa,
+    0x5f95fb4f, 0xe1f30000,
+    // [1858]: 30E0 "ム"
+    0x0a202000, 0xfe00007f, 0x00012500, 0xf32ff12f, 0xf03ff02f, 0xf12f8252, 0xf7362f62, 0x72f5373f,
+    0x2672f3ef, 0x323af13a, 0x2f31f500,
+    // [1869]: 30E1 "メ"
+    0x0d202000, 0xfc00003f, 0x00000200, 0xd1ff02ff, 0x12ff12ff, 0x0262f734, 0x3f8233f9, 0x313fb5fd,
+    0x3fc5fb31, 0x2fa332f8, 0x352f6363, 0xf6183ff0, 0x3ff03ff0, 0x17000000,
+    // [1883]: 30E2 "モ"
+    0x05202000, 0xfc0000ff, 0x00feb800, 0xbef2f1f9, 0x2f6f3f82, 0xf6bf78e0,
+    // [1889]: 30E3 "ャ"
+    0x08202000, 0xf80007ff, 0x02e03000, 0xf52f7612, 0xf8cf6327, 0xf632231f, 0x7222fc11, 0x2ff02fe2,
+    0xff11d000,
+    // [1898]: 30E4 "ヤ"
+    0x0a202000, 0xfc00003f, 0x03780080, 0xf62fe2f5, 0x282f4832, 0xf5df533a, 0xf225225f, 0x2242f933,
+    0x2fa232ff, 0x02fe2fe2, 0xd0000000,
+    // [1909]: 30E5 "ュ"
+    0x04202000, 0xff003fff, 0x003e8000, 0xf08f92fc, 0xdf3e7000,
+    // [1914]: 30E6 "ユ"
+    0x04202000, 0xff0003ff, 0x003fe000, 0xdbf6cf52, 0xfaf5df35,
+    // [1919]: 30E7 "ョ"
+    0x05202000, 0xfc000fff, 0x02eba000, 0xdbf62ff0, 0xaf72ff0b, 0x80000000,
+    // [1925]: 30E8 "ヨ"
+    0x06202000, 0xfc00007f, 0x00fd7c00, 0xcdf3f0f2, 0x2ff0ef32, 0xff0f0f3d, 0x70000000,
+    // [1932]: 30E9 "ラ"
+    0x09202000, 0xfc00073f, 0x00024000, 0xdbf5df4d, 0xf3f0f32f, 0xf03ff02f, 0xf12ff03f, 0xf03ff03f,
+    0xf03ff03f, 0xf03ff039,
+    // [1942]: 30EA "リ"
+    0x07202000, 0xfc00003f, 0x0017fe00, 0xd1ff0291, 0xf5292f52, 0xff12ff12, 0xff13ff04, 0xff03a000,
+    // [1950]: 30EB "ル"
+    0x0c202000, 0xfc00007f, 0x00007e00, 0xf1221fc2, 0x22fb223f, 0xa232f326, 0x222f4252, 0x22f43423,
+    0x2f433232, 0xf531252f, 0x5553f546, 0x2f6363ff, 0x01600000,
+    // [1963]: 30EC "レ"
+    0x0a202000, 0xfc00003f, 0x00007f00, 0xf71ff12f, 0x22b2f24a, 0x2f3392f4, 0x382f5372, 0xf6362f73,
+    0x52f8432f, 0xa312fc5f, 0xe2900000,
+    // [1974]: 30ED "ロ"
+    0x05202000, 0xfe0000ff, 0x007ff800, 0xbef2f1f1, 0x2c2f1f1f, 0x2e700000,
+    // [1980]: 30EE "ヮ"
+    0x07202000, 0xfc001fff, 0x00074000, 0xdbf6272f, 0x63ff02ff, 0x03ff03ff, 0x04ff03ff, 0x03a00000,
+    // [1988]: 30EF "ワ"
+    0x09202000, 0xfc0000ff, 0x0000f000, 0xbef2f1f1, 0x2c2f22b2, 0xf23ff02f, 0xf03ff02f, 0xf12ff04f,
+    0xf03ff04f, 0xe4ff0390,
+    // [1998]: 30F0 "ヰ"
+    0x07202000, 0xfc00003f, 0x03f1e300, 0xe241fa24, 0x2f6ef2f1, 0xf5242f3f, 0x5df3f42f, 0x10000000,
+    // [2006]: 30F1 "ヱ"
+    0x08202000, 0xfe00007f, 0x007f0000, 0xcdf3f0f3, 0x3ff02ff1, 0x2ff12ff0, 0x4fe3ff02, 0xf7f3f0f1,
+    0x60000000,
+    // [2015]: 30F2 "ヲ"
+    0x0a202000, 0xfc00007f, 0x0000b800, 0xcdf3f0f2, 0x2ff12fef, 0x0f43ff02, 0xff12ff03, 0xff03ff03,
+    0xff03ff02, 0xff12ff03, 0x90000000,
+    // [2026]: 30F3 "ン"
+    0x0c202000, 0xfc0000ff, 0x00000000, 0xf91ff03f, 0x21a3f238, 0x3f4273f6, 0x262f72ff, 0x12ff03ff,
+    0x02ff12ff, 0x03ff03ff, 0x04ff04ff, 0x04fe4ff0, 0x17000000,
+    // [2039]: 30F4 "ヴ"
+    0x0c202000, 0xfc000001, 0x000af008, 0x41ff0222, 0xfa313fa2, 0x22fb2229, 0x2f41a2ff, 0x02f9ef2f,
+    0x1f12c2f2, 0x2ff12ff1, 0x2ff04ff0, 0x3ff04fe4, 0xff03a000,
+    // [2052]: 30F5 "ヵ"
+    0x08202000, 0xfc0007ff, 0x000ab000, 0xf32fabf6, 0x232fa242, 0xf9243f82, 0x52f8253f, 0x7262f763,
+    0x2f744280,
+    // [2061]: 30F6 "ヶ"
+    0x07202000, 0xfc0007ff, 0x00515000, 0xf52ff12f, 0x6cf9243f, 0x8252f82f, 0xf12ff12f, 0xf12ff12a,
+    // [2069]: 30F7 "ヷ"
+    0x0c202000, 0xfc000001, 0x0000f048, 0x41ff0222, 0xfa313fa2, 0x22ff01ff, 0x6ef2f1f1, 0x2c2f22b2,
+    0xf23ff02f, 0xf03ff02f, 0xf12ff04f, 0xf03ff04f, 0xe4ff0390,
+    // [2082]: 30F8 "ヸ"
+    0x0a202000, 0xfc000001, 0x03f1e208, 0x41ff0222, 0xfa313fa2, 0x22fb2226, 0x241f2172, 0x42f9242f,
+    0x6ef2f1f5, 0x242f3f5d, 0xf3f42f10,
+    // [2093]: 30F9 "ヹ"
+    0x0a202000, 0xfe000001, 0x007f0048, 0x41ff0222, 0xfa313fa2, 0x22ff015d, 0xf3f0f33f, 0xf02ff12f,
+    0xf12ff04f, 0xe3ff02f7, 0xf3f0f160,
+    // [2104]: 30FA "ヺ"
+    0x0c202000, 0xfc000001, 0x0000b848, 0x41ff0222, 0xfa313fa2, 0x22ff015d, 0xf3f0f22f, 0xf12fef0f,
+    0x43ff02ff, 0x12ff03ff, 0x03ff03ff, 0x03ff02ff, 0x12ff0390,
+    // [2117]: 30FB "・"
+    0x04202000, 0xfffc3fff, 0x00010000, 0xf22fe4fe, 0x2d000000,
+    // [2122]: 30FC "ー"
+    0x03202000, 0xfffe7fff, 0x00010000, 0x9f350000,
+    // [2126]: 30FD "ヽ"
+    0x05202000, 0xfff80fff, 0x00000000, 0xf52fe3fd, 0x3fd3fd3f, 0xd3fe2f00,
+    // [2132]: 30FE "ヾ"
+    0x08202000, 0xfff80f01, 0x00000048, 0x41ff0222, 0xfa313fa2, 0x22ff01ff, 0xf02fe3fd, 0x3fd3fd3f,
+    0xd3fe2f00,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // If this fails, there's probably a hash collision, so change the seed.
+    fn test_hashes_unique_and_sorted() {
+        for i in 0..HASH_HIRAGANA.len()-1 {
+            assert!(HASH_HIRAGANA[i] < HASH_HIRAGANA[i+1]);
+        }
+        for i in 0..HASH_KATAKANA.len()-1 {
+            assert!(HASH_KATAKANA[i] < HASH_KATAKANA[i+1]);
+        }
+    }
+}
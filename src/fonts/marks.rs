@@ -0,0 +1,104 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Combining Marks
+//!
+//! Lookup table for drawing combining diacritical marks (U+0300..U+036F) as an
+//! overlay on top of, or below, a base glyph when a latin font has no
+//! precomposed glyph for the base + mark cluster. Most marks borrow a spacing
+//! accent glyph from the latin font in use (e.g. U+0301 uses "´") so they match
+//! its style. Marks with no spacing equivalent in the latin fonts have their
+//! own blit patterns in DATA.
+#![forbid(unsafe_code)]
+
+/// Where a mark goes relative to its base glyph
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Placement {
+    /// Centered above the top of the base glyph
+    Above,
+    /// Centered below the bottom of the base glyph
+    Below,
+    /// Attached to the top right corner of the base glyph
+    Right,
+}
+
+/// Source of the blit pattern for a mark
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum MarkGlyph {
+    /// Spacing accent character to look up in the active latin font
+    Spacing(char),
+    /// Offset into DATA
+    Pattern(usize),
+}
+
+/// Return glyph source and placement for a combining mark, if it is supported
+pub fn find_mark(c: char) -> Option<(MarkGlyph, Placement)> {
+    use MarkGlyph::{Pattern, Spacing};
+    use Placement::{Above, Below, Right};
+    Some(match c {
+        '\u{0300}' | '\u{0340}' => (Spacing('`'), Above),
+        '\u{0301}' | '\u{0341}' => (Spacing('´'), Above),
+        '\u{0302}' => (Spacing('^'), Above),
+        '\u{0303}' => (Spacing('~'), Above),
+        '\u{0304}' | '\u{0305}' => (Spacing('¯'), Above),
+        '\u{0306}' => (Pattern(BREVE), Above),
+        '\u{0307}' => (Spacing('.'), Above),
+        '\u{0308}' => (Spacing('¨'), Above),
+        '\u{0309}' => (Pattern(HOOK_ABOVE), Above),
+        '\u{030A}' => (Spacing('°'), Above),
+        '\u{030B}' => (Pattern(DOUBLE_ACUTE), Above),
+        '\u{030C}' => (Pattern(CARON), Above),
+        '\u{031B}' => (Pattern(HORN), Right),
+        '\u{0323}' => (Spacing('.'), Below),
+        '\u{0324}' => (Spacing('¨'), Below),
+        '\u{0325}' => (Spacing('°'), Below),
+        '\u{0326}' => (Spacing(','), Below),
+        '\u{0327}' => (Spacing('¸'), Below),
+        '\u{0328}' => (Pattern(OGONEK), Below),
+        '\u{0330}' => (Spacing('~'), Below),
+        '\u{0331}' => (Spacing('¯'), Below),
+        _ => return None,
+    })
+}
+
+/// Offsets into DATA for marks with their own blit patterns
+const BREVE: usize = 0;
+const CARON: usize = 2;
+const DOUBLE_ACUTE: usize = 4;
+const HOOK_ABOVE: usize = 6;
+const HORN: usize = 9;
+const OGONEK: usize = 11;
+
+/// Packed mark pattern data, using the same record format as the DATA arrays
+/// of the generated fonts. Patterns are drawn at 2x scale to match the latin
+/// fonts. Each comment shows the 1x pattern, one row at a time.
+#[rustfmt::skip]
+pub static DATA: [u32; 13] = [
+    // [0]: 306 breve: #..# .##.
+    0x00080400, 0xc3c33c3c,
+    // [2]: 30C caron: #.# .#.
+    0x00060400, 0xcf330c00,
+    // [4]: 30B double acute: .#.# #.#.
+    0x00080400, 0xcccc3333,
+    // [6]: 309 hook above: ##. ..# .#.
+    0x00060600, 0x3cfc3030, 0xc0000000,
+    // [9]: 31B horn: .# #.
+    0x00040400, 0xcc330000,
+    // [11]: 328 ogonek: #. .#
+    0x00040400, 0x33cc0000,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mark_patterns_fit_in_data() {
+        for &offset in [BREVE, CARON, DOUBLE_ACUTE, HOOK_ABOVE, HORN, OGONEK].iter() {
+            let w = ((DATA[offset] >> 16) & 0xff) as usize;
+            let h = ((DATA[offset] >> 8) & 0xff) as usize;
+            let words = (w * h).div_ceil(32);
+            assert!(offset + words < DATA.len());
+        }
+    }
+}
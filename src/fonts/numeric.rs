@@ -0,0 +1,252 @@
+// DO NOT MAKE EDITS HERE because this file is automatically generated.
+// To make changes, see blitstr/codegen/main.go
+//
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// NOTE: The copyright notice above applies to the rust source code in this
+// file, but not to the bitmap graphics encoded in the DATA array (see credits).
+//
+// CREDITS:
+// This code includes encoded bitmaps of large digits and numeric symbols that
+// were drawn for blitstr, for clocks and other numeric readouts. Digits all
+// have the same width so that changing numbers don't shift around. The bitmaps
+// are covered by the same license as the rust source code in this file.
+//
+//! Numeric Font
+#![forbid(unsafe_code)]
+#![allow(dead_code)]
+
+use super::{GlyphData, NoGlyphErr};
+
+/// Maximum height of glyph patterns in this bitmap typeface.
+/// This will be true: h + y_offset <= MAX_HEIGHT
+pub const MAX_HEIGHT: u8 = 64;
+
+/// Seed for Murmur3 hashes in the HASH_* index arrays
+pub const M3_SEED: u32 = 0;
+
+/// Return Okay(offset into DATA[]) for start of blit pattern for grapheme cluster.
+///
+/// Before doing an expensive lookup for the whole cluster, this does a pre-filter
+/// check to see whether the first character falls into one of the codepoint ranges
+/// for Unicode blocks included in this font.
+///
+/// Returns: Result<(blit pattern offset into DATA, bytes of cluster used by match)>
+pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGlyphErr> {
+    let first_char: u32 = match cluster.chars().next() {
+        Some(c) => c as u32,
+        None => return Err(NoGlyphErr),
+    };
+    match first_char {
+        0x0..=0x7F => {
+            if let Some((offset, bytes_used)) = find_basic_latin_codepoint(cluster) {
+                Ok((GlyphData::Numeric(offset), bytes_used))
+            } else {
+                Err(NoGlyphErr)
+            }
+        }
+        0x2200..=0x22FF => {
+            if let Some((offset, bytes_used)) = find_mathematical_operators_codepoint(cluster) {
+                Ok((GlyphData::Numeric(offset), bytes_used))
+            } else {
+                Err(NoGlyphErr)
+            }
+        }
+        _ => Err(super::NoGlyphErr),
+    }
+}
+
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_basic_latin_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_BASIC_LATIN, 0x25, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x25..=0x3A, or NO_GLYPH
+static CODEPOINT_BASIC_LATIN: [u32; 22] = [
+    656,   // "%"
+    super::NO_GLYPH,  // U+0026
+    super::NO_GLYPH,  // U+0027
+    super::NO_GLYPH,  // U+0028
+    super::NO_GLYPH,  // U+0029
+    super::NO_GLYPH,  // U+002A
+    740,   // "+"
+    super::NO_GLYPH,  // U+002C
+    731,   // "-"
+    651,   // "."
+    super::NO_GLYPH,  // U+002F
+    0,     // "0"
+    64,    // "1"
+    128,   // "2"
+    192,   // "3"
+    256,   // "4"
+    320,   // "5"
+    384,   // "6"
+    448,   // "7"
+    512,   // "8"
+    576,   // "9"
+    640,   // ":"
+];
+
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_mathematical_operators_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_MATHEMATICAL_OPERATORS, 0x2212, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x2212..=0x2212, or NO_GLYPH
+static CODEPOINT_MATHEMATICAL_OPERATORS: [u32; 1] = [
+    731,   // "−"
+];
+
+/// Index blocks in the order that get_blit_pattern_offset() checks them
+pub static BLOCKS: [super::Block; 2] = [
+    super::Block {
+        low: 0x0,
+        high: 0x7F,
+        cluster_lengths: &[1],
+        codepoints_low: 0x25,
+        codepoints: &CODEPOINT_BASIC_LATIN,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
+    super::Block {
+        low: 0x2200,
+        high: 0x22FF,
+        cluster_lengths: &[1],
+        codepoints_low: 0x2212,
+        codepoints: &CODEPOINT_MATHEMATICAL_OPERATORS,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
+];
+
+/// Packed glyph pattern data.
+/// Record format:
+///  [offset+0]: ((w as u8) << 16) | ((h as u8) << 8) | (yOffset as u8)
+///  [offset+1..=ceil(w*h/32)]: packed 1-bit pixels; 0=clear, 1=set
+/// Pixels are packed in top to bottom, left to right order with MSB of first
+/// pixel word containing the top left pixel.
+///  w: Width of pattern in pixels
+///  h: Height of pattern in pixels
+///  yOffset: Vertical offset (pixels downward from top of line) to position
+///     glyph pattern properly relative to text baseline
+pub static DATA: [u32; 782] = [
+    // [0]: 0030 "0"
+    0x00243804, 0x0003fc00, 0x0001fff8, 0x00007fff, 0xe0001fff, 0xff8003ff, 0xfffc007f, 0xffffe00f,
+    0xffffff01, 0xfffffff8, 0x1ffe07ff, 0x83ff801f, 0xfc3fe000, 0x7fc7fc00, 0x03fe7fc0, 0x003fe7f8,
+    0x0001feff, 0x80001fff, 0xf00000ff, 0xff00000f, 0xfff00000, 0xffff0000, 0x0ffff000, 0x00ffff00,
+    0x000ffff0, 0x0000ffff, 0x00000fff, 0xf00000ff, 0xff00000f, 0xfff00000, 0xffff0000, 0x0ffff000,
+    0x00ffff00, 0x000ffff0, 0x0000ffff, 0x00000fff, 0xf00000ff, 0xff00000f, 0xfff00000, 0xffff0000,
+    0x0ffff000, 0x00ffff00, 0x000ffff0, 0x0000ffff, 0x00000fff, 0xf00000ff, 0xff00000f, 0xfff80001,
+    0xff7f8000, 0x1fe7fc00, 0x03fe7fc0, 0x003fe3fe, 0x0007fc3f, 0xf801ffc1, 0xffe07ff8, 0x1fffffff,
+    0x80ffffff, 0xf007ffff, 0xfe003fff, 0xffc001ff, 0xfff80007, 0xfffe0000, 0x1fff8000, 0x003fc000,
+    // [64]: 0031 "1"
+    0x00243804, 0x0000f000, 0x00001f80, 0x000003fc, 0x0000003f, 0xe0000003, 0xff800000, 0x3ffc0000,
+    0x03ffe000, 0x003fff00, 0x0003fff8, 0x00003fff, 0xc00003ff, 0xfe00003f, 0xffe00003, 0xfffe0000,
+    0x3fdfe000, 0x03fcfc00, 0x003fc780, 0x0003fc00, 0x00003fc0, 0x000003fc, 0x0000003f, 0xc0000003,
+    0xfc000000, 0x3fc00000, 0x03fc0000, 0x003fc000, 0x0003fc00, 0x00003fc0, 0x000003fc, 0x0000003f,
+    0xc0000003, 0xfc000000, 0x3fc00000, 0x03fc0000, 0x003fc000, 0x0003fc00, 0x00003fc0, 0x000003fc,
+    0x0000003f, 0xc0000003, 0xfc000000, 0x3fc00000, 0x03fc0000, 0x003fc000, 0x0003fc00, 0x00003fc0,
+    0x000003fc, 0x0000003f, 0xc0000003, 0xfc000000, 0x3fc00000, 0x03fc0000, 0x003fc000, 0x3fffffff,
+    0xc7ffffff, 0xfeffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffff7f, 0xffffffe3, 0xfffffffc,
+    // [128]: 0032 "2"
+    0x00243804, 0x0003fc00, 0x0001fff8, 0x00007fff, 0xe0001fff, 0xff8003ff, 0xfffc007f, 0xffffe00f,
+    0xffffff01, 0xfffffff8, 0x1ffe07ff, 0x83ff801f, 0xfc3fe000, 0x7fc7fc00, 0x03fe7fc0, 0x003fe7f8,
+    0x0001feff, 0x80001fff, 0xf00000ff, 0xff00000f, 0xfff00000, 0xffff0000, 0x0ffff000, 0x00ffff00,
+    0x0007eff0, 0x00003cff, 0x0000000f, 0xf0000000, 0xff800000, 0x07f80000, 0x007fc000, 0x0007fe00,
+    0x00003ff0, 0x000003ff, 0x8000001f, 0xfc000000, 0xffe00000, 0x07ff0000, 0x003ff800, 0x0001ffc0,
+    0x00000ffe, 0x0000007f, 0xf0000003, 0xffc00000, 0x1ffe0000, 0x00fff000, 0x0007ff80, 0x00003ffc,
+    0x000001ff, 0xe000000f, 0xff000000, 0x7ffc0000, 0x01ffe000, 0x000fff00, 0x00007ff8, 0x3fffffff,
+    0xc7ffffff, 0xfeffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffff7f, 0xffffffe3, 0xfffffffc,
+    // [192]: 0033 "3"
+    0x00243804, 0x0003fc00, 0x0001fff8, 0x00007fff, 0xe0001fff, 0xff8003ff, 0xfffc007f, 0xffffe00f,
+    0xffffff00, 0xfffffff0, 0x1ffe07ff, 0x83ff801f, 0xfc3fe000, 0x7fc3fe00, 0x07fc7fc0, 0x003fe7f8,
+    0x0001fe7f, 0x80001fe7, 0xf80001fe, 0x7f80001f, 0xe7f80001, 0xfe7f8000, 0x0fc7fc00, 0x00783fe0,
+    0x000003fe, 0x0000003f, 0xf8000001, 0xffe00000, 0x0ffff000, 0x00ffff80, 0x0007fffc, 0x00003fff,
+    0xc00007ff, 0xfc0000ff, 0xffc0000f, 0xfff80001, 0xffff0000, 0x3ffe0000, 0x03ff0000, 0x007fe000,
+    0x0007fc00, 0x0000ff80, 0x0003cff8, 0x00007eff, 0x00000fff, 0xf00000ff, 0xff00000f, 0xfff00000,
+    0xffff8000, 0x1ffff800, 0x01ff7fc0, 0x003fe7fe, 0x0007fe3f, 0xf000ffc3, 0xffe07ffc, 0x1fffffff,
+    0x80ffffff, 0xf00fffff, 0xff007fff, 0xffe001ff, 0xfff8000f, 0xffff0000, 0x3fffc000, 0x003fc000,
+    // [256]: 0034 "4"
+    0x00243804, 0x003c0000, 0x0007e000, 0x0000ff00, 0x00000ff0, 0x000000ff, 0x8000000f, 0xfc000000,
+    0xffc00000, 0x0ffe0000, 0x00ffe000, 0x000fff00, 0x0000fff0, 0x00000fff, 0x800000ff, 0xfc00000f,
+    0xffc00000, 0xfffe0000, 0x0fffe000, 0x00ffff00, 0x000ffff8, 0x0000ffff, 0x80000fff, 0xfc0000ff,
+    0x7fc0000f, 0xf3fe0000, 0xff3ff000, 0x0ff1ff00, 0x00ff1ff8, 0x000ff0ff, 0x8000ff07, 0xfc000ff0,
+    0x7fc000ff, 0x03fe000f, 0xf03ff000, 0xff01ff00, 0x0ff00ff8, 0x00ff00ff, 0x800ff007, 0xfc3fffff,
+    0xffe7ffff, 0xfffeffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0x7fffffff, 0xe3ffffff,
+    0xfc00ff00, 0x00000ff0, 0x000000ff, 0x0000000f, 0xf0000000, 0xff000000, 0x0ff00000, 0x00ff0000,
+    0x000ff000, 0x0000ff00, 0x00000ff0, 0x000000ff, 0x0000000f, 0xf0000000, 0x7e000000, 0x03c00000,
+    // [320]: 0035 "5"
+    0x00243804, 0x0fffffff, 0xc1ffffff, 0xfe3fffff, 0xfff3ffff, 0xffff3fff, 0xfffff3ff, 0xffffff1f,
+    0xfffffff0, 0xffffffff, 0x0000000f, 0xf0000000, 0xff000000, 0x0ff00000, 0x00ff0000, 0x000ff000,
+    0x0000ff00, 0x00000ff0, 0x000000ff, 0x0000000f, 0xf0000000, 0xff000000, 0x0ff00000, 0x00ff0003,
+    0xfc0ff001, 0xfff8ff00, 0x7ffffff0, 0x1fffffff, 0x03ffffff, 0xf07fffff, 0xff0fffff, 0xfff1ffff,
+    0xffff1ffe, 0x07ffe3ff, 0x801ffc3f, 0xe0000007, 0xfc000000, 0x7fc00000, 0x07f80000, 0x00ff8000,
+    0x000ff000, 0x0000ff00, 0x00000ff0, 0x000000ff, 0x0000000f, 0xf0000000, 0xff000007, 0x8ff80000,
+    0xfc7f8000, 0x1fc7fc00, 0x03fe7fc0, 0x003fe3fe, 0x0007fc3f, 0xf801ffc1, 0xffe07ff8, 0x1fffffff,
+    0x80ffffff, 0xf007ffff, 0xfe003fff, 0xffc001ff, 0xfff80007, 0xfffe0000, 0x1fff8000, 0x003fc000,
+    // [384]: 0036 "6"
+    0x00243804, 0x001ff000, 0x0007ffe0, 0x0001ffff, 0x80003fff, 0xfc000fff, 0xffe001ff, 0xffff003f,
+    0xfffff803, 0xffffffc0, 0x3ff81ffc, 0x03fe007f, 0xe01fc003, 0xfe00f000, 0x1ff00000, 0x01ff8000,
+    0x000ff800, 0x00007f80, 0x000007fc, 0x0000003f, 0xc0000003, 0xfc000000, 0x3fe00000, 0x01fe0003,
+    0xfc1fe001, 0xfff9fe00, 0x7ffffff0, 0x1fffffff, 0x03ffffff, 0xf07fffff, 0xff0fffff, 0xfff1ffff,
+    0xffff1ffe, 0x07fff3ff, 0x801fff3f, 0xe0007ff7, 0xfc0003ff, 0x7fc0003f, 0xf7f80001, 0xffff8000,
+    0x1ffff000, 0x00ffff00, 0x000ffff0, 0x0000ffff, 0x00000fff, 0xf00000ff, 0xff00000f, 0xfff80001,
+    0xff7f8000, 0x1fe7fc00, 0x03fe7fc0, 0x003fe3fe, 0x0007fc3f, 0xf801ffc1, 0xffe07ff8, 0x1fffffff,
+    0x80ffffff, 0xf007ffff, 0xfe003fff, 0xffc001ff, 0xfff80007, 0xfffe0000, 0x1fff8000, 0x003fc000,
+    // [448]: 0037 "7"
+    0x00243804, 0x3fffffff, 0xc7ffffff, 0xfeffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffff7f,
+    0xffffffe7, 0xfffffffc, 0x7fc00000, 0x03fc0000, 0x003fe000, 0x0001fe00, 0x00001fe0, 0x000001ff,
+    0x0000000f, 0xf0000000, 0xff800000, 0x0ff80000, 0x007f8000, 0x0007fc00, 0x00003fc0, 0x000003fc,
+    0x0000003f, 0xe0000001, 0xfe000000, 0x1ff00000, 0x01ff0000, 0x000ff000, 0x0000ff80, 0x000007f8,
+    0x0000007f, 0x80000007, 0xfc000000, 0x3fc00000, 0x03fe0000, 0x003fe000, 0x0001fe00, 0x00001ff0,
+    0x000000ff, 0x0000000f, 0xf0000000, 0xff800000, 0x07f80000, 0x007fc000, 0x0007fc00, 0x00003fc0,
+    0x000003fe, 0x0000001f, 0xe0000001, 0xfe000000, 0x1ff00000, 0x00ff0000, 0x000ff800, 0x0000ff80,
+    0x000007f8, 0x0000007f, 0xc0000003, 0xfc000000, 0x3fc00000, 0x03fc0000, 0x001f8000, 0x0000f000,
+    // [512]: 0038 "8"
+    0x00243804, 0x0003fc00, 0x0001fff8, 0x00007fff, 0xe0000fff, 0xff0001ff, 0xfff8003f, 0xffffc007,
+    0xfffffe00, 0xfffffff0, 0x0ffe07ff, 0x01ff801f, 0xf81ff000, 0xff81fe00, 0x07f83fe0, 0x007fc3fc,
+    0x0003fc3f, 0xc0003fc3, 0xfc0003fc, 0x3fc0003f, 0xc3fc0003, 0xfc3fc000, 0x3fc3fe00, 0x07fc1fe0,
+    0x007f81ff, 0x000ff81f, 0xf801ff80, 0xffe07ff0, 0x0fffffff, 0x007fffff, 0xe003ffff, 0xfc001fff,
+    0xff8007ff, 0xfffe00ff, 0xfffff00f, 0xffffff01, 0xfffffff8, 0x3ffe07ff, 0xc3ff000f, 0xfc7fe000,
+    0x7fe7fc00, 0x03feff80, 0x001ffff8, 0x0001ffff, 0x00000fff, 0xf00000ff, 0xff00000f, 0xfff00000,
+    0xffff8000, 0x1ffff800, 0x01ff7fc0, 0x003fe7fe, 0x0007fe3f, 0xf000ffc3, 0xffe07ffc, 0x1fffffff,
+    0x80ffffff, 0xf00fffff, 0xff007fff, 0xffe001ff, 0xfff8000f, 0xffff0000, 0x3fffc000, 0x003fc000,
+    // [576]: 0039 "9"
+    0x00243804, 0x0003fc00, 0x0001fff8, 0x00007fff, 0xe0001fff, 0xff8003ff, 0xfffc007f, 0xffffe00f,
+    0xffffff01, 0xfffffff8, 0x1ffe07ff, 0x83ff801f, 0xfc3fe000, 0x7fc7fc00, 0x03fe7fc0, 0x003fe7f8,
+    0x0001feff, 0x80001fff, 0xf00000ff, 0xff00000f, 0xfff00000, 0xffff0000, 0x0ffff000, 0x00ffff00,
+    0x000ffff8, 0x0001ffff, 0x80001fef, 0xfc0003fe, 0xffc0003f, 0xeffe0007, 0xfcfff801, 0xffcfffe0,
+    0x7ff8ffff, 0xffff8fff, 0xfffff0ff, 0xfffffe0f, 0xffffffc0, 0xfffffff8, 0x0ffffffe, 0x007f9fff,
+    0x8007f83f, 0xc0007f80, 0x000007fc, 0x0000003f, 0xc0000003, 0xfc000000, 0x3fe00000, 0x01fe0000,
+    0x001ff000, 0x0001ff80, 0x00000ff8, 0x000f007f, 0xc003f807, 0xfe007fc0, 0x3ff81ffc, 0x03ffffff,
+    0xc01fffff, 0xfc00ffff, 0xff8007ff, 0xfff0003f, 0xfffc0001, 0xffff8000, 0x07ffe000, 0x000ff800,
+    // [640]: 003A ":"
+    0x000a2013, 0x3f1fefff, 0xffffffff, 0xffff7f8f, 0xc0000000, 0x00000000, 0x00000000, 0x00000003,
+    0xf1feffff, 0xffffffff, 0xfff7f8fc,
+    // [651]: 002E "."
+    0x000a0a2f, 0x3f1fefff, 0xffffffff, 0xffff7f8f, 0xc0000000,
+    // [656]: 0025 "%"
+    0x002a3804, 0x01e0001f, 0x8000fc00, 0x3ffc007f, 0x801fff80, 0x1fe00fff, 0xf007fc07, 0xfffe01ff,
+    0x03ffffc0, 0x3fe0ffff, 0xf00ff87f, 0xfffe01ff, 0x1ff0ff80, 0x7fcff81f, 0xf00ffbfe, 0x07fc03fe,
+    0xff00ff00, 0x7fffc03f, 0xc01ffff0, 0x0ff003ff, 0xfc03fc00, 0xffff81ff, 0x001fffe0, 0x7fc007ff,
+    0xfc3fe000, 0xfffffff8, 0x003fffff, 0xfc0007ff, 0xffff0001, 0xffffff80, 0x003fffff, 0xc0000fff,
+    0xffe00001, 0xfffff000, 0x007fc7e0, 0x00000ff8, 0x00000003, 0xfe000000, 0x007fc000, 0x00001ff0,
+    0x000007e3, 0xfe00000f, 0xffff8000, 0x07fffff0, 0x0003ffff, 0xfc0001ff, 0xffff8000, 0xffffffe0,
+    0x003fffff, 0xfc001fff, 0xffff0007, 0xfc3fffe0, 0x03fe07ff, 0xf800ff81, 0xffff003f, 0xc03fffc0,
+    0x0ff00fff, 0xf803fc03, 0xfffe00ff, 0x00ff7fc0, 0x3fe07fdf, 0xf00ff81f, 0xf3fe01ff, 0x0ff8ff80,
+    0x7ffffe1f, 0xf00fffff, 0x07fc03ff, 0xffc0ff80, 0x7fffe03f, 0xe00ffff0, 0x07f801ff, 0xf801fe00,
+    0x3ffc003f, 0x0001f800, 0x07800000,
+    // [731]: 002D "-"
+    0x001e081c, 0x3ffffff1, 0xffffffef, 0xffffffff, 0xffffffff, 0xffffffff, 0xfffff7ff, 0xffff8fff,
+    0xfffc0000,
+    // [740]: 002B "+"
+    0x0024240e, 0x0000f000, 0x00001f80, 0x000003fc, 0x0000003f, 0xc0000003, 0xfc000000, 0x3fc00000,
+    0x03fc0000, 0x003fc000, 0x0003fc00, 0x00003fc0, 0x000003fc, 0x0000003f, 0xc0000003, 0xfc000000,
+    0x3fc0003f, 0xffffffc7, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffff7fff,
+    0xffffe3ff, 0xfffffc00, 0x03fc0000, 0x003fc000, 0x0003fc00, 0x00003fc0, 0x000003fc, 0x0000003f,
+    0xc0000003, 0xfc000000, 0x3fc00000, 0x03fc0000, 0x003fc000, 0x0003fc00, 0x00003fc0, 0x000001f8,
+    0x0000000f, 0x00000000,
+];
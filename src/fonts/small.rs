@@ -33,684 +33,882 @@ pub const M3_SEED: u32 = 0;
 ///
 /// Returns: Result<(blit pattern offset into DATA, bytes of cluster used by match)>
 pub fn get_blit_pattern_offset(cluster: &str) -> Result<(GlyphData, usize), NoGlyphErr> {
-    let first_char: u32;
-    match cluster.chars().next() {
-        Some(c) => first_char = c as u32,
+    let first_char: u32 = match cluster.chars().next() {
+        Some(c) => c as u32,
         None => return Err(NoGlyphErr),
-    }
-    return match first_char {
+    };
+    match first_char {
         0x0..=0x7F => {
             if let Some((offset, bytes_used)) = find_basic_latin(cluster, 2) {
                 Ok((GlyphData::Small(offset), bytes_used))
-            } else if let Some((offset, bytes_used)) = find_basic_latin(cluster, 1) {
+            } else if let Some((offset, bytes_used)) = find_basic_latin_codepoint(cluster) {
                 Ok((GlyphData::Small(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
             }
         }
         0x80..=0xFF => {
-            if let Some((offset, bytes_used)) = find_latin_1_supplement(cluster, 1) {
+            if let Some((offset, bytes_used)) = find_latin_1_supplement_codepoint(cluster) {
                 Ok((GlyphData::Small(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
             }
         }
         0x100..=0x17F => {
-            if let Some((offset, bytes_used)) = find_latin_extended_a(cluster, 1) {
+            if let Some((offset, bytes_used)) = find_latin_extended_a_codepoint(cluster) {
+                Ok((GlyphData::Small(offset), bytes_used))
+            } else {
+                Err(NoGlyphErr)
+            }
+        }
+        0x370..=0x3FF => {
+            if let Some((offset, bytes_used)) = find_greek_and_coptic_codepoint(cluster) {
+                Ok((GlyphData::Small(offset), bytes_used))
+            } else {
+                Err(NoGlyphErr)
+            }
+        }
+        0x400..=0x4FF => {
+            if let Some((offset, bytes_used)) = find_cyrillic(cluster, 2) {
+                Ok((GlyphData::Small(offset), bytes_used))
+            } else if let Some((offset, bytes_used)) = find_cyrillic_codepoint(cluster) {
                 Ok((GlyphData::Small(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
             }
         }
         0x2000..=0x206F => {
-            if let Some((offset, bytes_used)) = find_general_punctuation(cluster, 1) {
+            if let Some((offset, bytes_used)) = find_general_punctuation_codepoint(cluster) {
                 Ok((GlyphData::Small(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
             }
         }
         0x20A0..=0x20CF => {
-            if let Some((offset, bytes_used)) = find_currency_symbols(cluster, 1) {
+            if let Some((offset, bytes_used)) = find_currency_symbols_codepoint(cluster) {
                 Ok((GlyphData::Small(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
             }
         }
         0xFFF0..=0xFFFF => {
-            if let Some((offset, bytes_used)) = find_specials(cluster, 1) {
+            if let Some((offset, bytes_used)) = find_specials_codepoint(cluster) {
                 Ok((GlyphData::Small(offset), bytes_used))
             } else {
                 Err(NoGlyphErr)
             }
         }
         _ => Err(super::NoGlyphErr),
-    };
+    }
+}
+
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_basic_latin_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_BASIC_LATIN, 0x20, cluster)
 }
 
+/// Blit pattern offsets for codepoints 0x20..=0x7E, or NO_GLYPH
+static CODEPOINT_BASIC_LATIN: [u32; 95] = [
+    0,     // " "
+    2,     // "!"
+    4,     // "\""
+    6,     // "#"
+    11,    // "$"
+    18,    // "%"
+    26,    // "&"
+    34,    // "'"
+    36,    // "("
+    41,    // ")"
+    46,    // "*"
+    51,    // "+"
+    56,    // ","
+    58,    // "-"
+    60,    // "."
+    62,    // "/"
+    67,    // "0"
+    73,    // "1"
+    76,    // "2"
+    82,    // "3"
+    88,    // "4"
+    94,    // "5"
+    100,   // "6"
+    106,   // "7"
+    112,   // "8"
+    118,   // "9"
+    124,   // ":"
+    126,   // ";"
+    129,   // "<"
+    132,   // "="
+    135,   // ">"
+    138,   // "?"
+    143,   // "@"
+    151,   // "A"
+    157,   // "B"
+    163,   // "C"
+    169,   // "D"
+    175,   // "E"
+    180,   // "F"
+    185,   // "G"
+    191,   // "H"
+    197,   // "I"
+    199,   // "J"
+    205,   // "K"
+    211,   // "L"
+    216,   // "M"
+    224,   // "N"
+    230,   // "O"
+    236,   // "P"
+    242,   // "Q"
+    248,   // "R"
+    254,   // "S"
+    260,   // "T"
+    266,   // "U"
+    272,   // "V"
+    278,   // "W"
+    286,   // "X"
+    292,   // "Y"
+    298,   // "Z"
+    303,   // "["
+    307,   // "\\"
+    312,   // "]"
+    316,   // "^"
+    318,   // "_"
+    320,   // "`"
+    322,   // "a"
+    326,   // "b"
+    331,   // "c"
+    335,   // "d"
+    340,   // "e"
+    344,   // "f"
+    349,   // "g"
+    354,   // "h"
+    359,   // "i"
+    361,   // "j"
+    366,   // "k"
+    371,   // "l"
+    373,   // "m"
+    379,   // "n"
+    383,   // "o"
+    387,   // "p"
+    392,   // "q"
+    397,   // "r"
+    401,   // "s"
+    405,   // "t"
+    409,   // "u"
+    413,   // "v"
+    418,   // "w"
+    424,   // "x"
+    429,   // "y"
+    435,   // "z"
+    439,   // "{"
+    444,   // "|"
+    447,   // "}"
+    452,   // "~"
+];
+
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
 fn find_basic_latin(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_BASIC_LATIN.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_BASIC_LATIN[index], bytes_hashed)),
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_BASIC_LATIN.binary_search(&hash) {
+        Ok(index) if KEY_BASIC_LATIN[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_BASIC_LATIN[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
 /// Index of murmur3(grapheme cluster); sort matches OFFSET_BASIC_LATIN
-const HASH_BASIC_LATIN: [u32; 148] = [
+static HASH_BASIC_LATIN: [u32; 53] = [
     0x0323CD4F,  // "ë" 65-308
-    0x049BF55E,  // "}"
     0x0537C05E,  // "È" 45-300
-    0x0B9EA876,  // "8"
     0x0D4E2B64,  // "è" 65-300
     0x11DDB5BC,  // "Ù" 55-300
     0x129F2A7F,  // "ã" 61-303
-    0x13156136,  // "F"
-    0x131AB870,  // "O"
     0x1403A883,  // "Ê" 45-302
-    0x1586C0AA,  // "j"
-    0x1672836B,  // "`"
-    0x16F9A05F,  // "#"
-    0x18D53BB6,  // "("
     0x198B90FC,  // "ù" 75-300
-    0x1BF82D35,  // "y"
     0x1C40E42F,  // "Ö" 4F-308
-    0x1CFCD293,  // ")"
     0x1F2B17F3,  // "Î" 49-302
-    0x1F79A4D0,  // "$"
-    0x21840E11,  // "q"
-    0x22ABE92F,  // "K"
     0x2379B646,  // "Õ" 4F-303
     0x24189EC6,  // "ä" 61-308
-    0x259E68D3,  // "6"
-    0x26FF6E36,  // "3"
-    0x2A3184F1,  // "U"
-    0x2B038801,  // "a"
-    0x2C17F13B,  // "r"
-    0x2D2269F8,  // "m"
     0x30F856B5,  // "Â" 41-302
-    0x31099644,  // "t"
-    0x315F5687,  // "I"
-    0x3673CB35,  // "{"
-    0x39BC06BB,  // "L"
     0x435F26F6,  // "Ñ" 4E-303
-    0x43603571,  // "C"
-    0x44C216BA,  // "R"
     0x455E0578,  // "Ü" 55-308
-    0x45EF573F,  // ";"
     0x465D3B9D,  // "í" 69-301
     0x47EF8034,  // "ÿ" 79-308
-    0x4938EA00,  // "7"
-    0x4AB0FC13,  // "4"
-    0x4BCD3197,  // "A"
-    0x4CC5A899,  // "|"
-    0x524C4F7E,  // "B"
-    0x52D3CB36,  // "%"
     0x55C7F2AE,  // "ý" 79-301
-    0x56DEE618,  // "v"
-    0x5A352E5E,  // "c"
-    0x5B06F60A,  // "1"
-    0x5C904E6C,  // "_"
-    0x5CA0F32F,  // "&"
-    0x5FF80125,  // "S"
-    0x61901224,  // "5"
     0x67BD451E,  // "ñ" 6E-303
     0x681839B8,  // "Ò" 4F-300
-    0x68E75BE7,  // "@"
     0x6C058218,  // "Û" 55-302
-    0x6E85A379,  // "x"
-    0x72DE1EFB,  // "*"
-    0x73688484,  // "p"
-    0x7627CA6C,  // "D"
-    0x779CDA2E,  // "Y"
     0x7834A19C,  // "ó" 6F-301
-    0x78FD8C32,  // "u"
     0x7A435EEE,  // "Ï" 49-308
-    0x7B6B1369,  // "9"
-    0x7BA23029,  // "J"
     0x7F4EBF76,  // "Ç" 43-327
-    0x803AF153,  // "["
-    0x804744C4,  // "e"
-    0x84510F7D,  // "o"
     0x8499B628,  // "å" 61-30A
-    0x86A8B043,  // ","
-    0x8B86BA97,  // "="
-    0x8C1A54EE,  // "g"
-    0x8C69C315,  // " "
     0x8C94654B,  // "ê" 65-302
-    0x8DB20A6B,  // "!"
     0x933CEC52,  // "Ä" 41-308
-    0x9500C437,  // "<"
-    0x9582C02E,  // "G"
-    0x98453FD6,  // "?"
     0x98C6B546,  // "ü" 75-308
-    0x99933C47,  // "w"
     0x9B44CABE,  // "á" 61-301
     0x9DD6D1DF,  // "â" 61-302
     0x9F99932F,  // "À" 41-300
     0xA03D6D22,  // "Å" 41-30A
-    0xA13B361A,  // "/"
-    0xA1A410C7,  // "2"
     0xA26A0E29,  // "Ã" 41-303
     0xA30CAD6B,  // "û" 75-302
-    0xA5872342,  // "k"
-    0xA891E88A,  // "0"
-    0xABE208D0,  // "P"
-    0xADABEA12,  // "d"
     0xADC91A24,  // "ç" 63-327
     0xAE495307,  // "õ" 6F-303
-    0xAF41DB71,  // "]"
-    0xB12BF2EE,  // "V"
-    0xB35F1351,  // "E"
     0xB454A383,  // "ú" 75-301
     0xB5097BAB,  // "ö" 6F-308
-    0xB8ED6BE2,  // "X"
     0xBE856396,  // "ì" 69-300
-    0xBFE47D0D,  // "Q"
-    0xC06E932F,  // "T"
     0xC0AFD8ED,  // "Ô" 4F-302
-    0xC1209A73,  // "f"
-    0xC2DDC575,  // "\""
     0xC3AB3492,  // "ò" 6F-300
     0xC57A2C66,  // "Ó" 4F-301
     0xC5E4D96B,  // "î" 69-302
-    0xC890E3DD,  // ">"
-    0xC99309A2,  // "-"
-    0xC9BEA311,  // "Z"
-    0xCAECCF17,  // "s"
-    0xCAF83468,  // "H"
-    0xCB07FD3E,  // "'"
-    0xCB7242E0,  // "+"
-    0xCD3FDBE3,  // "M"
-    0xCE94AE25,  // "b"
     0xD363E17B,  // "à" 61-300
-    0xD4FFA898,  // ":"
-    0xD7B03F23,  // "z"
     0xD822B857,  // "Ì" 49-300
-    0xDA2B151D,  // "\\"
-    0xDB01AF22,  // "n"
-    0xDC135ABD,  // "N"
-    0xE2AA1EBB,  // "."
-    0xE5CA55BF,  // "i"
-    0xE6556D01,  // "h"
     0xE7C19BA9,  // "é" 65-301
     0xED67FA54,  // "Ý" 59-301
-    0xEF026B52,  // "l"
     0xEF302693,  // "Í" 49-301
     0xF04B9422,  // "Ú" 55-301
-    0xF0F19A38,  // "~"
-    0xF29AB82D,  // "W"
     0xF31D8BFB,  // "ï" 69-308
     0xF3A5A351,  // "Á" 41-301
     0xF3BF2609,  // "É" 45-301
     0xF85223EB,  // "Ë" 45-308
-    0xFC2C2430,  // "^"
     0xFFF1D70C,  // "ô" 6F-302
 ];
 
+/// Verification keys to rule out hash collisions; sort matches HASH_BASIC_LATIN
+static KEY_BASIC_LATIN: [u32; 53] = [
+    0x80D44E97,  // "ë" 65-308
+    0xDB1ED85C,  // "È" 45-300
+    0xE04C4E1C,  // "è" 65-300
+    0xA28AC6EA,  // "Ù" 55-300
+    0xE2E050C3,  // "ã" 61-303
+    0xD9FAA663,  // "Ê" 45-302
+    0xABC0AB4B,  // "ù" 75-300
+    0xE42C33D7,  // "Ö" 4F-308
+    0xB175A1B9,  // "Î" 49-302
+    0xD74A4220,  // "Õ" 4F-303
+    0x80943004,  // "ä" 61-308
+    0xE2AB7B52,  // "Â" 41-302
+    0xACDB8AB2,  // "Ñ" 4E-303
+    0xA77793A4,  // "Ü" 55-308
+    0xA360CABE,  // "í" 69-301
+    0xE210549A,  // "ÿ" 79-308
+    0xBE05027D,  // "ý" 79-301
+    0xE75C9E2F,  // "ñ" 6E-303
+    0xA89AE289,  // "Ò" 4F-300
+    0x8D2F33EB,  // "Û" 55-302
+    0xF203F879,  // "ó" 6F-301
+    0xB3605562,  // "Ï" 49-308
+    0xB0D4E0C2,  // "Ç" 43-327
+    0xA0CF5C31,  // "å" 61-30A
+    0xD1BFE014,  // "ê" 65-302
+    0xCFEE06C2,  // "Ä" 41-308
+    0xD1F64E74,  // "ü" 75-308
+    0xBCA67AFB,  // "á" 61-301
+    0xCB130ED9,  // "â" 61-302
+    0xD9283FBB,  // "À" 41-300
+    0xB0326F4E,  // "Å" 41-30A
+    0x97B109FE,  // "Ã" 41-303
+    0xFBEBF095,  // "û" 75-302
+    0xCA6BBFA7,  // "ç" 63-327
+    0x8C3671BD,  // "õ" 6F-303
+    0xF5F21CDB,  // "ú" 75-301
+    0xC894E903,  // "ö" 6F-308
+    0xB66FD0FD,  // "ì" 69-300
+    0xF8F41517,  // "Ô" 4F-302
+    0xB440ABE0,  // "ò" 6F-300
+    0xC2A3381F,  // "Ó" 4F-301
+    0xE25FA6D2,  // "î" 69-302
+    0x9523889F,  // "à" 61-300
+    0xCF2F30E0,  // "Ì" 49-300
+    0xAAEB4531,  // "é" 65-301
+    0xBE8AF7BB,  // "Ý" 59-301
+    0xAF5882A5,  // "Í" 49-301
+    0x91A3D630,  // "Ú" 55-301
+    0xB1E3AEA7,  // "ï" 69-308
+    0x9CC2135C,  // "Á" 41-301
+    0xBD3D9CD7,  // "É" 45-301
+    0xB64E013C,  // "Ë" 45-308
+    0xEBB3788F,  // "ô" 6F-302
+];
+
 /// Lookup table of blit pattern offsets; sort matches HASH_BASIC_LATIN
-const OFFSET_BASIC_LATIN: [usize; 148] = [
+static OFFSET_BASIC_LATIN: [usize; 53] = [
     898,  // "ë" 65-308
-    447,  // "}"
     682,  // "È" 45-300
-    112,  // "8"
     883,  // "è" 65-300
     791,  // "Ù" 55-300
     856,  // "ã" 61-303
-    180,  // "F"
-    230,  // "O"
     694,  // "Ê" 45-302
-    361,  // "j"
-    320,  // "`"
-    6,    // "#"
-    36,   // "("
     963,  // "ù" 75-300
-    429,  // "y"
     771,  // "Ö" 4F-308
-    41,   // ")"
     714,  // "Î" 49-302
-    11,   // "$"
-    392,  // "q"
-    205,  // "K"
     763,  // "Õ" 4F-303
     861,  // "ä" 61-308
-    100,  // "6"
-    82,   // "3"
-    266,  // "U"
-    322,  // "a"
-    397,  // "r"
-    373,  // "m"
     636,  // "Â" 41-302
-    405,  // "t"
-    197,  // "I"
-    439,  // "{"
-    211,  // "L"
     731,  // "Ñ" 4E-303
-    163,  // "C"
-    248,  // "R"
     815,  // "Ü" 55-308
-    126,  // ";"
     906,  // "í" 69-301
     997,  // "ÿ" 79-308
-    106,  // "7"
-    88,   // "4"
-    151,  // "A"
-    444,  // "|"
-    157,  // "B"
-    18,   // "%"
     983,  // "ý" 79-301
-    413,  // "v"
-    331,  // "c"
-    73,   // "1"
-    318,  // "_"
-    26,   // "&"
-    254,  // "S"
-    94,   // "5"
     922,  // "ñ" 6E-303
     739,  // "Ò" 4F-300
-    143,  // "@"
     807,  // "Û" 55-302
-    424,  // "x"
-    46,   // "*"
-    387,  // "p"
-    169,  // "D"
-    292,  // "Y"
     932,  // "ó" 6F-301
-    409,  // "u"
     719,  // "Ï" 49-308
-    118,  // "9"
-    199,  // "J"
     675,  // "Ç" 43-327
-    303,  // "["
-    340,  // "e"
-    383,  // "o"
     866,  // "å" 61-30A
-    56,   // ","
-    132,  // "="
-    349,  // "g"
-    0,    // " "
     893,  // "ê" 65-302
-    2,    // "!"
     652,  // "Ä" 41-308
-    129,  // "<"
-    185,  // "G"
-    138,  // "?"
     978,  // "ü" 75-308
-    418,  // "w"
     846,  // "á" 61-301
     851,  // "â" 61-302
     620,  // "À" 41-300
     659,  // "Å" 41-30A
-    62,   // "/"
-    76,   // "2"
     644,  // "Ã" 41-303
     973,  // "û" 75-302
-    366,  // "k"
-    67,   // "0"
-    236,  // "P"
-    335,  // "d"
     878,  // "ç" 63-327
     942,  // "õ" 6F-303
-    312,  // "]"
-    272,  // "V"
-    175,  // "E"
     968,  // "ú" 75-301
     947,  // "ö" 6F-308
-    286,  // "X"
     903,  // "ì" 69-300
-    242,  // "Q"
-    260,  // "T"
     755,  // "Ô" 4F-302
-    344,  // "f"
-    4,    // "\""
     927,  // "ò" 6F-300
     747,  // "Ó" 4F-301
     909,  // "î" 69-302
-    135,  // ">"
-    58,   // "-"
-    298,  // "Z"
-    401,  // "s"
-    191,  // "H"
-    34,   // "'"
-    51,   // "+"
-    216,  // "M"
-    326,  // "b"
     841,  // "à" 61-300
-    124,  // ":"
-    435,  // "z"
     706,  // "Ì" 49-300
-    307,  // "\\"
-    379,  // "n"
-    224,  // "N"
-    60,   // "."
-    359,  // "i"
-    354,  // "h"
     888,  // "é" 65-301
     822,  // "Ý" 59-301
-    371,  // "l"
     710,  // "Í" 49-301
     799,  // "Ú" 55-301
-    452,  // "~"
-    278,  // "W"
     913,  // "ï" 69-308
     628,  // "Á" 41-301
     688,  // "É" 45-301
     700,  // "Ë" 45-308
-    316,  // "^"
     937,  // "ô" 6F-302
 ];
 
-/// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
-/// Only attempt to match grapheme clusters of length limit codepoints.
-fn find_latin_1_supplement(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_LATIN_1_SUPPLEMENT.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_LATIN_1_SUPPLEMENT[index], bytes_hashed)),
-        _ => None,
-    }
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_latin_1_supplement_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_LATIN_1_SUPPLEMENT, 0xA0, cluster)
 }
 
-/// Index of murmur3(grapheme cluster); sort matches OFFSET_LATIN_1_SUPPLEMENT
-const HASH_LATIN_1_SUPPLEMENT: [u32; 96] = [
-    0x00EAC56E,  // "°"
-    0x0254FD66,  // "®"
-    0x056DE9E6,  // "Î"
-    0x06FE368D,  // "Ö"
-    0x137E3259,  // "Õ"
-    0x18E6F281,  // "à"
-    0x1A5A66CD,  // "ø"
-    0x1B0DE252,  // "¢"
-    0x1B43C661,  // "¤"
-    0x1C505947,  // "í"
-    0x252158D3,  // "¥"
-    0x25872B22,  // "ë"
-    0x259437AC,  // "§"
-    0x268B196D,  // "ä"
-    0x275E5021,  // "¨"
-    0x2766361E,  // "Ú"
-    0x278A377A,  // "Ì"
-    0x28CA6AD7,  // "Ø"
-    0x291E971E,  // "Ï"
-    0x3280724D,  // "»"
-    0x36953E7B,  // "õ"
-    0x388FB155,  // "ï"
-    0x39056A43,  // "É"
-    0x3A073FBE,  // "È"
-    0x3AE00F65,  // "ü"
-    0x3E168B4D,  // "ó"
-    0x3E82329E,  // "û"
-    0x402DCFF6,  // "×"
-    0x4236CDD1,  // "¾"
-    0x44D0C2C4,  // "ê"
-    0x450DB83D,  // "Û"
-    0x4F92356C,  // "µ"
-    0x511BCA1D,  // "á"
-    0x554A5349,  // "Æ"
-    0x5E39BCEE,  // "´"
-    0x5EB9D2A0,  // "ç"
-    0x5EE10367,  // "ò"
-    0x62D494F4,  // "Ý"
-    0x6984AA2D,  // "Í"
-    0x6BA96CC3,  // "Ü"
-    0x6E3C05CF,  // "ý"
-    0x6EF8ED06,  // "\u00AD" Soft Hyphen
-    0x6FA3C127,  // "ã"
-    0x71EE15F2,  // "Ò"
-    0x7619D892,  // "ª"
-    0x76554D10,  // "Ç"
-    0x79026F8E,  // "Å"
-    0x7E2A203C,  // "÷"
-    0x839D40CB,  // "£"
-    0x84FF8F94,  // "Ô"
-    0x8AE0A2B9,  // "å"
-    0x8D741045,  // "Þ"
-    0x8EA3F31F,  // "³"
-    0x9194CCB5,  // "¬"
-    0x92979158,  // "ô"
-    0x95C4DC63,  // "Ã"
-    0x97A9C1DA,  // "¶"
-    0x97C63B05,  // "\u00A0" No-Break Space
-    0xA10C8120,  // "·"
-    0xA5F3368B,  // "¹"
-    0xA7883CA5,  // "¸"
-    0xA7B0FE42,  // "©"
-    0xABF561B2,  // "«"
-    0xAD97621D,  // "Ð"
-    0xB1A64F1E,  // "Â"
-    0xB1BF38B8,  // "º"
-    0xB55DA0FA,  // "î"
-    0xB5CA05F5,  // "¦"
-    0xB7001103,  // "¡"
-    0xB9229669,  // "¿"
-    0xBA8E9829,  // "Ä"
-    0xBB608964,  // "ú"
-    0xC0837C42,  // "¼"
-    0xC0E7AB63,  // "¯"
-    0xC20FE7B7,  // "þ"
-    0xC6EF143E,  // "ß"
-    0xC98BE718,  // "Á"
-    0xCAD0511F,  // "é"
-    0xCAF24984,  // "Ù"
-    0xD040D3E3,  // "è"
-    0xD4D6097A,  // "Ë"
-    0xD83534E2,  // "Ñ"
-    0xDD061C7A,  // "²"
-    0xDE3FB757,  // "ì"
-    0xE14F2E81,  // "â"
-    0xE2532EDC,  // "ð"
-    0xE51FF77C,  // "½"
-    0xE5DA3FED,  // "±"
-    0xE9131AA1,  // "ù"
-    0xEBC8143D,  // "ö"
-    0xEC99A516,  // "À"
-    0xF446CD1A,  // "Ó"
-    0xF520EF41,  // "æ"
-    0xF64ED921,  // "ñ"
-    0xFB89D3F4,  // "ÿ"
-    0xFBFECC1C,  // "Ê"
+/// Blit pattern offsets for codepoints 0xA0..=0xFF, or NO_GLYPH
+static CODEPOINT_LATIN_1_SUPPLEMENT: [u32; 96] = [
+    455,   // "\u00A0" No-Break Space
+    457,   // "¡"
+    459,   // "¢"
+    465,   // "£"
+    471,   // "¤"
+    477,   // "¥"
+    483,   // "¦"
+    486,   // "§"
+    493,   // "¨"
+    495,   // "©"
+    504,   // "ª"
+    508,   // "«"
+    513,   // "¬"
+    516,   // "\u00AD" Soft Hyphen
+    518,   // "®"
+    527,   // "¯"
+    529,   // "°"
+    532,   // "±"
+    537,   // "²"
+    540,   // "³"
+    543,   // "´"
+    545,   // "µ"
+    552,   // "¶"
+    560,   // "·"
+    562,   // "¸"
+    564,   // "¹"
+    567,   // "º"
+    571,   // "»"
+    576,   // "¼"
+    589,   // "½"
+    602,   // "¾"
+    615,   // "¿"
+    620,   // "À"
+    628,   // "Á"
+    636,   // "Â"
+    644,   // "Ã"
+    652,   // "Ä"
+    659,   // "Å"
+    667,   // "Æ"
+    675,   // "Ç"
+    682,   // "È"
+    688,   // "É"
+    694,   // "Ê"
+    700,   // "Ë"
+    706,   // "Ì"
+    710,   // "Í"
+    714,   // "Î"
+    719,   // "Ï"
+    724,   // "Ð"
+    731,   // "Ñ"
+    739,   // "Ò"
+    747,   // "Ó"
+    755,   // "Ô"
+    763,   // "Õ"
+    771,   // "Ö"
+    778,   // "×"
+    783,   // "Ø"
+    791,   // "Ù"
+    799,   // "Ú"
+    807,   // "Û"
+    815,   // "Ü"
+    822,   // "Ý"
+    830,   // "Þ"
+    835,   // "ß"
+    841,   // "à"
+    846,   // "á"
+    851,   // "â"
+    856,   // "ã"
+    861,   // "ä"
+    866,   // "å"
+    872,   // "æ"
+    878,   // "ç"
+    883,   // "è"
+    888,   // "é"
+    893,   // "ê"
+    898,   // "ë"
+    903,   // "ì"
+    906,   // "í"
+    909,   // "î"
+    913,   // "ï"
+    917,   // "ð"
+    922,   // "ñ"
+    927,   // "ò"
+    932,   // "ó"
+    937,   // "ô"
+    942,   // "õ"
+    947,   // "ö"
+    952,   // "÷"
+    957,   // "ø"
+    963,   // "ù"
+    968,   // "ú"
+    973,   // "û"
+    978,   // "ü"
+    983,   // "ý"
+    991,   // "þ"
+    997,   // "ÿ"
 ];
 
-/// Lookup table of blit pattern offsets; sort matches HASH_LATIN_1_SUPPLEMENT
-const OFFSET_LATIN_1_SUPPLEMENT: [usize; 96] = [
-    529,  // "°"
-    518,  // "®"
-    714,  // "Î"
-    771,  // "Ö"
-    763,  // "Õ"
-    841,  // "à"
-    957,  // "ø"
-    459,  // "¢"
-    471,  // "¤"
-    906,  // "í"
-    477,  // "¥"
-    898,  // "ë"
-    486,  // "§"
-    861,  // "ä"
-    493,  // "¨"
-    799,  // "Ú"
-    706,  // "Ì"
-    783,  // "Ø"
-    719,  // "Ï"
-    571,  // "»"
-    942,  // "õ"
-    913,  // "ï"
-    688,  // "É"
-    682,  // "È"
-    978,  // "ü"
-    932,  // "ó"
-    973,  // "û"
-    778,  // "×"
-    602,  // "¾"
-    893,  // "ê"
-    807,  // "Û"
-    545,  // "µ"
-    846,  // "á"
-    667,  // "Æ"
-    543,  // "´"
-    878,  // "ç"
-    927,  // "ò"
-    822,  // "Ý"
-    710,  // "Í"
-    815,  // "Ü"
-    983,  // "ý"
-    516,  // "\u00AD" Soft Hyphen
-    856,  // "ã"
-    739,  // "Ò"
-    504,  // "ª"
-    675,  // "Ç"
-    659,  // "Å"
-    952,  // "÷"
-    465,  // "£"
-    755,  // "Ô"
-    866,  // "å"
-    830,  // "Þ"
-    540,  // "³"
-    513,  // "¬"
-    937,  // "ô"
-    644,  // "Ã"
-    552,  // "¶"
-    455,  // "\u00A0" No-Break Space
-    560,  // "·"
-    564,  // "¹"
-    562,  // "¸"
-    495,  // "©"
-    508,  // "«"
-    724,  // "Ð"
-    636,  // "Â"
-    567,  // "º"
-    909,  // "î"
-    483,  // "¦"
-    457,  // "¡"
-    615,  // "¿"
-    652,  // "Ä"
-    968,  // "ú"
-    576,  // "¼"
-    527,  // "¯"
-    991,  // "þ"
-    835,  // "ß"
-    628,  // "Á"
-    888,  // "é"
-    791,  // "Ù"
-    883,  // "è"
-    700,  // "Ë"
-    731,  // "Ñ"
-    537,  // "²"
-    903,  // "ì"
-    851,  // "â"
-    917,  // "ð"
-    589,  // "½"
-    532,  // "±"
-    963,  // "ù"
-    947,  // "ö"
-    620,  // "À"
-    747,  // "Ó"
-    872,  // "æ"
-    922,  // "ñ"
-    997,  // "ÿ"
-    694,  // "Ê"
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_latin_extended_a_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_LATIN_EXTENDED_A, 0x152, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x152..=0x153, or NO_GLYPH
+static CODEPOINT_LATIN_EXTENDED_A: [u32; 2] = [
+    1004,  // "Œ"
+    1012,  // "œ"
 ];
 
-/// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
-/// Only attempt to match grapheme clusters of length limit codepoints.
-fn find_latin_extended_a(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_LATIN_EXTENDED_A.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_LATIN_EXTENDED_A[index], bytes_hashed)),
-        _ => None,
-    }
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_greek_and_coptic_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_GREEK_AND_COPTIC, 0x37E, cluster)
 }
 
-/// Index of murmur3(grapheme cluster); sort matches OFFSET_LATIN_EXTENDED_A
-const HASH_LATIN_EXTENDED_A: [u32; 2] = [
-    0x1A01594C,  // "Œ"
-    0x8C60DA30,  // "œ"
+/// Blit pattern offsets for codepoints 0x37E..=0x3C9, or NO_GLYPH
+static CODEPOINT_GREEK_AND_COPTIC: [u32; 76] = [
+    126,   // ";"
+    super::NO_GLYPH,  // U+037F
+    super::NO_GLYPH,  // U+0380
+    super::NO_GLYPH,  // U+0381
+    super::NO_GLYPH,  // U+0382
+    super::NO_GLYPH,  // U+0383
+    543,   // "΄"
+    super::NO_GLYPH,  // U+0385
+    super::NO_GLYPH,  // U+0386
+    560,   // "·"
+    super::NO_GLYPH,  // U+0388
+    super::NO_GLYPH,  // U+0389
+    super::NO_GLYPH,  // U+038A
+    super::NO_GLYPH,  // U+038B
+    super::NO_GLYPH,  // U+038C
+    super::NO_GLYPH,  // U+038D
+    super::NO_GLYPH,  // U+038E
+    super::NO_GLYPH,  // U+038F
+    super::NO_GLYPH,  // U+0390
+    151,   // "Α"
+    157,   // "Β"
+    1018,  // "Γ"
+    1023,  // "Δ"
+    175,   // "Ε"
+    298,   // "Ζ"
+    191,   // "Η"
+    1029,  // "Θ"
+    197,   // "Ι"
+    205,   // "Κ"
+    1035,  // "Λ"
+    216,   // "Μ"
+    224,   // "Ν"
+    1041,  // "Ξ"
+    230,   // "Ο"
+    1047,  // "Π"
+    236,   // "Ρ"
+    super::NO_GLYPH,  // U+03A2
+    1053,  // "Σ"
+    260,   // "Τ"
+    292,   // "Υ"
+    1059,  // "Φ"
+    286,   // "Χ"
+    1065,  // "Ψ"
+    1071,  // "Ω"
+    super::NO_GLYPH,  // U+03AA
+    super::NO_GLYPH,  // U+03AB
+    super::NO_GLYPH,  // U+03AC
+    super::NO_GLYPH,  // U+03AD
+    super::NO_GLYPH,  // U+03AE
+    super::NO_GLYPH,  // U+03AF
+    super::NO_GLYPH,  // U+03B0
+    1077,  // "α"
+    1082,  // "β"
+    1088,  // "γ"
+    1094,  // "δ"
+    1099,  // "ε"
+    1103,  // "ζ"
+    1109,  // "η"
+    1114,  // "θ"
+    1119,  // "ι"
+    1122,  // "κ"
+    1126,  // "λ"
+    545,   // "μ"
+    413,   // "ν"
+    1131,  // "ξ"
+    383,   // "ο"
+    1137,  // "π"
+    1142,  // "ρ"
+    1147,  // "ς"
+    1151,  // "σ"
+    1156,  // "τ"
+    1160,  // "υ"
+    1164,  // "φ"
+    1170,  // "χ"
+    1176,  // "ψ"
+    1183,  // "ω"
 ];
 
-/// Lookup table of blit pattern offsets; sort matches HASH_LATIN_EXTENDED_A
-const OFFSET_LATIN_EXTENDED_A: [usize; 2] = [
-    1004, // "Œ"
-    1012, // "œ"
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_cyrillic_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_CYRILLIC, 0x400, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x400..=0x491, or NO_GLYPH
+static CODEPOINT_CYRILLIC: [u32; 146] = [
+    682,   // "Ѐ"
+    700,   // "Ё"
+    1189,  // "Ђ"
+    super::NO_GLYPH,  // U+0403
+    1196,  // "Є"
+    254,   // "Ѕ"
+    197,   // "І"
+    719,   // "Ї"
+    199,   // "Ј"
+    1202,  // "Љ"
+    1210,  // "Њ"
+    1218,  // "Ћ"
+    super::NO_GLYPH,  // U+040C
+    super::NO_GLYPH,  // U+040D
+    super::NO_GLYPH,  // U+040E
+    1224,  // "Џ"
+    151,   // "А"
+    1230,  // "Б"
+    157,   // "В"
+    1018,  // "Г"
+    1235,  // "Д"
+    175,   // "Е"
+    1241,  // "Ж"
+    1249,  // "З"
+    1255,  // "И"
+    1261,  // "Й"
+    205,   // "К"
+    1269,  // "Л"
+    216,   // "М"
+    191,   // "Н"
+    230,   // "О"
+    1047,  // "П"
+    236,   // "Р"
+    163,   // "С"
+    260,   // "Т"
+    1275,  // "У"
+    1059,  // "Ф"
+    286,   // "Х"
+    1281,  // "Ц"
+    1287,  // "Ч"
+    1293,  // "Ш"
+    1299,  // "Щ"
+    1306,  // "Ъ"
+    1312,  // "Ы"
+    1319,  // "Ь"
+    1324,  // "Э"
+    1330,  // "Ю"
+    1338,  // "Я"
+    322,   // "а"
+    1344,  // "б"
+    1349,  // "в"
+    1353,  // "г"
+    1356,  // "д"
+    340,   // "е"
+    1361,  // "ж"
+    1366,  // "з"
+    1370,  // "и"
+    1374,  // "й"
+    1122,  // "к"
+    1379,  // "л"
+    1383,  // "м"
+    1388,  // "н"
+    383,   // "о"
+    1392,  // "п"
+    387,   // "р"
+    331,   // "с"
+    1396,  // "т"
+    429,   // "у"
+    1399,  // "ф"
+    424,   // "х"
+    1406,  // "ц"
+    1411,  // "ч"
+    1415,  // "ш"
+    1420,  // "щ"
+    1426,  // "ъ"
+    1430,  // "ы"
+    1435,  // "ь"
+    1438,  // "э"
+    1442,  // "ю"
+    1447,  // "я"
+    883,   // "ѐ"
+    898,   // "ё"
+    1451,  // "ђ"
+    super::NO_GLYPH,  // U+0453
+    1458,  // "є"
+    401,   // "ѕ"
+    359,   // "і"
+    913,   // "ї"
+    361,   // "ј"
+    1462,  // "љ"
+    1467,  // "њ"
+    1472,  // "ћ"
+    super::NO_GLYPH,  // U+045C
+    super::NO_GLYPH,  // U+045D
+    super::NO_GLYPH,  // U+045E
+    1478,  // "џ"
+    super::NO_GLYPH,  // U+0460
+    super::NO_GLYPH,  // U+0461
+    super::NO_GLYPH,  // U+0462
+    super::NO_GLYPH,  // U+0463
+    super::NO_GLYPH,  // U+0464
+    super::NO_GLYPH,  // U+0465
+    super::NO_GLYPH,  // U+0466
+    super::NO_GLYPH,  // U+0467
+    super::NO_GLYPH,  // U+0468
+    super::NO_GLYPH,  // U+0469
+    super::NO_GLYPH,  // U+046A
+    super::NO_GLYPH,  // U+046B
+    super::NO_GLYPH,  // U+046C
+    super::NO_GLYPH,  // U+046D
+    super::NO_GLYPH,  // U+046E
+    super::NO_GLYPH,  // U+046F
+    super::NO_GLYPH,  // U+0470
+    super::NO_GLYPH,  // U+0471
+    super::NO_GLYPH,  // U+0472
+    super::NO_GLYPH,  // U+0473
+    super::NO_GLYPH,  // U+0474
+    super::NO_GLYPH,  // U+0475
+    super::NO_GLYPH,  // U+0476
+    super::NO_GLYPH,  // U+0477
+    super::NO_GLYPH,  // U+0478
+    super::NO_GLYPH,  // U+0479
+    super::NO_GLYPH,  // U+047A
+    super::NO_GLYPH,  // U+047B
+    super::NO_GLYPH,  // U+047C
+    super::NO_GLYPH,  // U+047D
+    super::NO_GLYPH,  // U+047E
+    super::NO_GLYPH,  // U+047F
+    super::NO_GLYPH,  // U+0480
+    super::NO_GLYPH,  // U+0481
+    super::NO_GLYPH,  // U+0482
+    super::NO_GLYPH,  // U+0483
+    super::NO_GLYPH,  // U+0484
+    super::NO_GLYPH,  // U+0485
+    super::NO_GLYPH,  // U+0486
+    super::NO_GLYPH,  // U+0487
+    super::NO_GLYPH,  // U+0488
+    super::NO_GLYPH,  // U+0489
+    super::NO_GLYPH,  // U+048A
+    super::NO_GLYPH,  // U+048B
+    super::NO_GLYPH,  // U+048C
+    super::NO_GLYPH,  // U+048D
+    super::NO_GLYPH,  // U+048E
+    super::NO_GLYPH,  // U+048F
+    1482,  // "Ґ"
+    1487,  // "ґ"
 ];
 
 /// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
 /// Only attempt to match grapheme clusters of length limit codepoints.
-fn find_general_punctuation(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_GENERAL_PUNCTUATION.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_GENERAL_PUNCTUATION[index], bytes_hashed)),
+fn find_cyrillic(cluster: &str, limit: u32) -> Option<(usize, usize)> {
+    // Single codepoints don't need hashing, so skip clusters where the next
+    // char can't continue the cluster, or that are too short to match
+    if limit > 1 && super::skip_hash(cluster) {
+        return None;
+    }
+    cluster.chars().nth(limit as usize - 1)?;
+    let (hash, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
+    match HASH_CYRILLIC.binary_search(&hash) {
+        Ok(index) if KEY_CYRILLIC[index] == super::cluster_key(cluster, M3_SEED, limit) => {
+            Some((OFFSET_CYRILLIC[index], bytes_hashed))
+        }
         _ => None,
     }
 }
 
-/// Index of murmur3(grapheme cluster); sort matches OFFSET_GENERAL_PUNCTUATION
-const HASH_GENERAL_PUNCTUATION: [u32; 11] = [
-    0x0D0042B1,  // "•"
-    0x18E68A7D,  // "‚"
-    0x372ED469,  // "“"
-    0x3A3C25C2,  // "”"
-    0x80E4E277,  // "‡"
-    0x885E576B,  // "‟"
-    0x8B80C01D,  // "‛"
-    0x8F0BE1DB,  // "„"
-    0x8F56D335,  // "†"
-    0xA6C64F29,  // "‘"
-    0xE29813B0,  // "’"
+/// Index of murmur3(grapheme cluster); sort matches OFFSET_CYRILLIC
+static HASH_CYRILLIC: [u32; 2] = [
+    0x96A19F32,  // "й" 438-306
+    0xFDAF4D81,  // "Й" 418-306
 ];
 
-/// Lookup table of blit pattern offsets; sort matches HASH_GENERAL_PUNCTUATION
-const OFFSET_GENERAL_PUNCTUATION: [usize; 11] = [
-    1046, // "•"
-    1022, // "‚"
-    1026, // "“"
-    1029, // "”"
-    1041, // "‡"
-    1035, // "‟"
-    1024, // "‛"
-    1032, // "„"
-    1038, // "†"
-    1018, // "‘"
-    1020, // "’"
+/// Verification keys to rule out hash collisions; sort matches HASH_CYRILLIC
+static KEY_CYRILLIC: [u32; 2] = [
+    0xE507E6DB,  // "й" 438-306
+    0xE62754ED,  // "Й" 418-306
 ];
 
-/// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
-/// Only attempt to match grapheme clusters of length limit codepoints.
-fn find_currency_symbols(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_CURRENCY_SYMBOLS.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_CURRENCY_SYMBOLS[index], bytes_hashed)),
-        _ => None,
-    }
+/// Lookup table of blit pattern offsets; sort matches HASH_CYRILLIC
+static OFFSET_CYRILLIC: [usize; 2] = [
+    1374, // "й" 438-306
+    1261, // "Й" 418-306
+];
+
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_general_punctuation_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_GENERAL_PUNCTUATION, 0x2018, cluster)
 }
 
-/// Index of murmur3(grapheme cluster); sort matches OFFSET_CURRENCY_SYMBOLS
-const HASH_CURRENCY_SYMBOLS: [u32; 1] = [
-    0x1ACA36BB,  // "€"
+/// Blit pattern offsets for codepoints 0x2018..=0x2022, or NO_GLYPH
+static CODEPOINT_GENERAL_PUNCTUATION: [u32; 11] = [
+    1491,  // "‘"
+    1493,  // "’"
+    1495,  // "‚"
+    1497,  // "‛"
+    1499,  // "“"
+    1502,  // "”"
+    1505,  // "„"
+    1508,  // "‟"
+    1511,  // "†"
+    1514,  // "‡"
+    1519,  // "•"
 ];
 
-/// Lookup table of blit pattern offsets; sort matches HASH_CURRENCY_SYMBOLS
-const OFFSET_CURRENCY_SYMBOLS: [usize; 1] = [
-    1051, // "€"
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_currency_symbols_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_CURRENCY_SYMBOLS, 0x20AC, cluster)
+}
+
+/// Blit pattern offsets for codepoints 0x20AC..=0x20AC, or NO_GLYPH
+static CODEPOINT_CURRENCY_SYMBOLS: [u32; 1] = [
+    1524,  // "€"
 ];
 
-/// Use binary search on table of grapheme cluster hashes to find blit pattern for grapheme cluster.
-/// Only attempt to match grapheme clusters of length limit codepoints.
-fn find_specials(cluster: &str, limit: u32) -> Option<(usize, usize)> {
-    let (key, bytes_hashed) = super::murmur3(cluster, M3_SEED, limit);
-    match HASH_SPECIALS.binary_search(&key) {
-        Ok(index) => return Some((OFFSET_SPECIALS[index], bytes_hashed)),
-        _ => None,
-    }
+/// Look up single codepoint grapheme clusters in a table indexed by codepoint.
+fn find_specials_codepoint(cluster: &str) -> Option<(usize, usize)> {
+    super::find_codepoint(&CODEPOINT_SPECIALS, 0xFFFD, cluster)
 }
 
-/// Index of murmur3(grapheme cluster); sort matches OFFSET_SPECIALS
-const HASH_SPECIALS: [u32; 1] = [
-    0x58A5DA35,  // "�"
+/// Blit pattern offsets for codepoints 0xFFFD..=0xFFFD, or NO_GLYPH
+static CODEPOINT_SPECIALS: [u32; 1] = [
+    1531,  // "�"
 ];
 
-/// Lookup table of blit pattern offsets; sort matches HASH_SPECIALS
-const OFFSET_SPECIALS: [usize; 1] = [
-    1058, // "�"
+/// Index blocks in the order that get_blit_pattern_offset() checks them
+pub static BLOCKS: [super::Block; 8] = [
+    super::Block {
+        low: 0x0,
+        high: 0x7F,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x20,
+        codepoints: &CODEPOINT_BASIC_LATIN,
+        hashes: &HASH_BASIC_LATIN,
+        keys: &KEY_BASIC_LATIN,
+        offsets: &OFFSET_BASIC_LATIN,
+    },
+    super::Block {
+        low: 0x80,
+        high: 0xFF,
+        cluster_lengths: &[1],
+        codepoints_low: 0xA0,
+        codepoints: &CODEPOINT_LATIN_1_SUPPLEMENT,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
+    super::Block {
+        low: 0x100,
+        high: 0x17F,
+        cluster_lengths: &[1],
+        codepoints_low: 0x152,
+        codepoints: &CODEPOINT_LATIN_EXTENDED_A,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
+    super::Block {
+        low: 0x370,
+        high: 0x3FF,
+        cluster_lengths: &[1],
+        codepoints_low: 0x37E,
+        codepoints: &CODEPOINT_GREEK_AND_COPTIC,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
+    super::Block {
+        low: 0x400,
+        high: 0x4FF,
+        cluster_lengths: &[2, 1],
+        codepoints_low: 0x400,
+        codepoints: &CODEPOINT_CYRILLIC,
+        hashes: &HASH_CYRILLIC,
+        keys: &KEY_CYRILLIC,
+        offsets: &OFFSET_CYRILLIC,
+    },
+    super::Block {
+        low: 0x2000,
+        high: 0x206F,
+        cluster_lengths: &[1],
+        codepoints_low: 0x2018,
+        codepoints: &CODEPOINT_GENERAL_PUNCTUATION,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
+    super::Block {
+        low: 0x20A0,
+        high: 0x20CF,
+        cluster_lengths: &[1],
+        codepoints_low: 0x20AC,
+        codepoints: &CODEPOINT_CURRENCY_SYMBOLS,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
+    super::Block {
+        low: 0xFFF0,
+        high: 0xFFFF,
+        cluster_lengths: &[1],
+        codepoints_low: 0xFFFD,
+        codepoints: &CODEPOINT_SPECIALS,
+        hashes: &[],
+        keys: &[],
+        offsets: &[],
+    },
 ];
 
 /// Packed glyph pattern data.
@@ -723,7 +921,7 @@ const OFFSET_SPECIALS: [usize; 1] = [
 ///  h: Height of pattern in pixels
 ///  yOffset: Vertical offset (pixels downward from top of line) to position
 ///     glyph pattern properly relative to text baseline
-pub const DATA: [u32; 1071] = [
+pub static DATA: [u32; 1544] = [
     // [0]: 20 " "
     0x0004020b, 0x00000000,
     // [2]: 21 "!"
@@ -1115,31 +1313,207 @@ pub const DATA: [u32; 1071] = [
     0x00100e06, 0xfffcfffc, 0x03030303, 0x03030303, 0x3f033f03, 0x03030303, 0x03030303, 0xfffcfffc,
     // [1012]: 153 "œ"
     0x000e0a0a, 0x3cf0f3cc, 0x30f0c3ff, 0x0ffc3030, 0xc0c33ff0, 0xffc00000,
-    // [1018]: 2018 "‘"
+    // [1018]: 393 "Γ"
+    0x00080e06, 0xffff0303, 0x03030303, 0x03030303, 0x03030000,
+    // [1023]: 394 "Δ"
+    0x000a0e06, 0x0c0300c0, 0x30330cc3, 0x30ccc0f0, 0x3c0f03ff, 0xfff00000,
+    // [1029]: 398 "Θ"
+    0x000a0e06, 0x3f0fcc0f, 0x03c0f03f, 0xffffc0f0, 0x3c0f033f, 0x0fc00000,
+    // [1035]: 39B "Λ"
+    0x000a0e06, 0x0c0300c0, 0x30330cc3, 0x30ccc0f0, 0x3c0f03c0, 0xf0300000,
+    // [1041]: 39E "Ξ"
+    0x000a0e06, 0xfffff000, 0x00000003, 0xf0fc0000, 0x000000ff, 0xfff00000,
+    // [1047]: 3A0 "Π"
+    0x000a0e06, 0xfffffc0f, 0x03c0f03c, 0x0f03c0f0, 0x3c0f03c0, 0xf0300000,
+    // [1053]: 3A3 "Σ"
+    0x000a0e06, 0xfffff00c, 0x030300c0, 0xc0300300, 0xc00c03ff, 0xfff00000,
+    // [1059]: 3A6 "Φ"
+    0x000a0e06, 0x0c0303f0, 0xfcccf33c, 0xcf33ccf3, 0x33f0fc0c, 0x03000000,
+    // [1065]: 3A8 "Ψ"
+    0x000a0e06, 0xccf33ccf, 0x33ccf333, 0xf0fc0c03, 0x00c0300c, 0x03000000,
+    // [1071]: 3A9 "Ω"
+    0x000a0e06, 0x3f0fcc0f, 0x03c0f03c, 0x0f03330c, 0xc330ccf3, 0xfcf00000,
+    // [1077]: 3B1 "α"
+    0x000a0a0a, 0xcf33c30c, 0xc330cc33, 0x0cc3cf33, 0xc0000000,
+    // [1082]: 3B2 "β"
+    0x00081206, 0x3c3cc3c3, 0x3f3fc3c3, 0xc3c3c3c3, 0x3f3f0303, 0x03030000,
+    // [1088]: 3B3 "γ"
+    0x000a0e0a, 0xc0f03c0f, 0x03330cc3, 0x30cc0c03, 0x00c0300c, 0x03000000,
+    // [1094]: 3B4 "δ"
+    0x00080e06, 0xfcfc0303, 0x3c3cc3c3, 0xc3c3c3c3, 0x3c3c0000,
+    // [1099]: 3B5 "ε"
+    0x00080a0a, 0xfcfc0303, 0x3c3c0303, 0xfcfc0000,
+    // [1103]: 3B6 "ζ"
+    0x00081206, 0xffff3030, 0x0c0c0303, 0x03030303, 0x3c3cc0c0, 0x30300000,
+    // [1109]: 3B7 "η"
+    0x00080e0a, 0x3333cfcf, 0xc3c3c3c3, 0xc3c3c0c0, 0xc0c00000,
+    // [1114]: 3B8 "θ"
+    0x00080e06, 0x3c3cc3c3, 0xc3c3ffff, 0xc3c3c3c3, 0x3c3c0000,
+    // [1119]: 3B9 "ι"
+    0x00040a0a, 0x33333333, 0xcc000000,
+    // [1122]: 3BA "κ"
+    0x00080a0a, 0xc3c33333, 0x0f0f3333, 0xc3c30000,
+    // [1126]: 3BB "λ"
+    0x00080e06, 0x03030303, 0x0c0c0c0c, 0x3c3cc3c3, 0xc3c30000,
+    // [1131]: 3BE "ξ"
+    0x00081206, 0xfcfc0303, 0x3c3c0303, 0x03030303, 0x3c3cc0c0, 0x30300000,
+    // [1137]: 3C0 "π"
+    0x000a0a0a, 0xfffff330, 0xcc330cc3, 0x30cc330c, 0xc0000000,
+    // [1142]: 3C1 "ρ"
+    0x00080e0a, 0x3c3cc3c3, 0xc3c3c3c3, 0x3f3f0303, 0x03030000,
+    // [1147]: 3C2 "ς"
+    0x00080c0a, 0xfcfc0303, 0x03033c3c, 0xc0c03030,
+    // [1151]: 3C3 "σ"
+    0x000a0a0a, 0xff3fc30c, 0xc330cc33, 0x0cc30f03, 0xc0000000,
+    // [1156]: 3C4 "τ"
+    0x00080a0a, 0xffff0c0c, 0x0c0c0c0c, 0xf0f00000,
+    // [1160]: 3C5 "υ"
+    0x00080a0a, 0xc3c3c3c3, 0xc3c3c3c3, 0x3c3c0000,
+    // [1164]: 3C6 "φ"
+    0x000a0e0a, 0x0c0303f0, 0xfcccf33c, 0xcf333f0f, 0xc0c0300c, 0x03000000,
+    // [1170]: 3C7 "χ"
+    0x000a0e0a, 0xc0f03330, 0xcc330cc0, 0xc030330c, 0xc330ccc0, 0xf0300000,
+    // [1176]: 3C8 "ψ"
+    0x000a1206, 0x0c0300c0, 0x30ccf33c, 0xcf33ccf3, 0x3ccf333f, 0x0fc0c030, 0x0c030000,
+    // [1183]: 3C9 "ω"
+    0x000e0a0a, 0x3030c0cc, 0x00f003c3, 0x0f0c3c30, 0xf0c33cf0, 0xf3c00000,
+    // [1189]: 402 "Ђ"
+    0x000a1206, 0x3fcff030, 0x0c3f0fcc, 0x330cc330, 0xcc330cc3, 0x30cc0300, 0x300c0000,
+    // [1196]: 404 "Є"
+    0x000a0e06, 0x3f0fcc0f, 0x0300c030, 0xfc3f00c0, 0x3c0f033f, 0x0fc00000,
+    // [1202]: 409 "Љ"
+    0x000e0e06, 0x00f003c0, 0x3300cc03, 0x300cc3f3, 0x0fccc333, 0x0ccc3330, 0xcc3f0cfc, 0x30000000,
+    // [1210]: 40A "Њ"
+    0x000e0e06, 0x030c0c30, 0x30c0c303, 0x0c0c33ff, 0xcfffc30f, 0x0c3c30f0, 0xc33f0cfc, 0x30000000,
+    // [1218]: 40B "Ћ"
+    0x000a0e06, 0x3fcff030, 0x0c3f0fcc, 0x330cc330, 0xcc330cc3, 0x30c00000,
+    // [1224]: 40F "Џ"
+    0x000a1006, 0xc0f03c0f, 0x03c0f03c, 0x0f03c0f0, 0x3c0f03ff, 0xfff0c030,
+    // [1230]: 411 "Б"
+    0x00080e06, 0xffff0303, 0x03033f3f, 0xc3c3c3c3, 0x3f3f0000,
+    // [1235]: 414 "Д"
+    0x000a1006, 0x3f0fc330, 0xcc330cc3, 0x30cc330c, 0xc330ccff, 0xfffc0f03,
+    // [1241]: 416 "Ж"
+    0x000e0e06, 0xc30f0c3c, 0x30f0c333, 0x30ccc0fc, 0x03f03330, 0xcccc30f0, 0xc3c30f0c, 0x30000000,
+    // [1249]: 417 "З"
+    0x000a0e06, 0x3f0fcc0f, 0x03c03003, 0xc0f0c030, 0x0c0f033f, 0x0fc00000,
+    // [1255]: 418 "И"
+    0x000a0e06, 0xc0f03c0f, 0x03f0fc3c, 0xcf33c3f0, 0xfc0f03c0, 0xf0300000,
+    // [1261]: 419 "Й"
+    0x000a1400, 0x330cc0c0, 0x3000000c, 0x0f03c0f0, 0x3f0fc3cc, 0xf33c3f0f, 0xc0f03c0f, 0x03000000,
+    // [1269]: 41B "Л"
+    0x000a0e06, 0xfc3f0cc3, 0x30cc330c, 0xc330cc33, 0x0c330cc0, 0xf0300000,
+    // [1275]: 423 "У"
+    0x000a0e06, 0xc0f03c0f, 0x03c0f03f, 0xf3fcc030, 0x0c03003f, 0x0fc00000,
+    // [1281]: 426 "Ц"
+    0x000a1006, 0x30cc330c, 0xc330cc33, 0x0cc330cc, 0x330cc3ff, 0xfffc0300,
+    // [1287]: 427 "Ч"
+    0x000a0e06, 0xc0f03c0f, 0x03c0f03f, 0xf3fcc030, 0x0c0300c0, 0x30000000,
+    // [1293]: 428 "Ш"
+    0x000a0e06, 0xccf33ccf, 0x33ccf33c, 0xcf33ccf3, 0x3ccf33ff, 0xfff00000,
+    // [1299]: 429 "Щ"
+    0x000c1006, 0x33333333, 0x33333333, 0x33333333, 0x33333333, 0x3333ffff, 0xffc00c00,
+    // [1306]: 42A "Ъ"
+    0x000a0e06, 0x03c0f030, 0x0c0300c3, 0xf0fcc330, 0xcc330c3f, 0x0fc00000,
+    // [1312]: 42B "Ы"
+    0x000c0e06, 0xc03c03c0, 0x3c03c03c, 0x03c3fc3f, 0xcc3cc3cc, 0x3cc3c3fc, 0x3f000000,
+    // [1319]: 42C "Ь"
+    0x00080e06, 0x03030303, 0x03033f3f, 0xc3c3c3c3, 0x3f3f0000,
+    // [1324]: 42D "Э"
+    0x000a0e06, 0x3f0fcc0f, 0x03c0300f, 0xc3f0c030, 0x0c0f033f, 0x0fc00000,
+    // [1330]: 42E "Ю"
+    0x000e0e06, 0x3f0cfc3c, 0x0cf033c0, 0xcf033c0f, 0xf03fc0cf, 0x033c0cf0, 0x333f0cfc, 0x30000000,
+    // [1338]: 42F "Я"
+    0x000a0e06, 0xff3fcc0f, 0x03c0f03f, 0xf3fccc33, 0x0c330cc0, 0xf0300000,
+    // [1344]: 431 "б"
+    0x00080e06, 0xfcfc0303, 0x3f3fc3c3, 0xc3c3c3c3, 0x3c3c0000,
+    // [1349]: 432 "в"
+    0x00080a0a, 0x3f3fc3c3, 0x3f3fc3c3, 0x3f3f0000,
+    // [1353]: 433 "г"
+    0x00060a0a, 0xfff0c30c, 0x30c30c30,
+    // [1356]: 434 "д"
+    0x000a0c0a, 0x3f0fc330, 0xcc330cc3, 0x30ccffff, 0xfc0f0300,
+    // [1361]: 436 "ж"
+    0x000a0a0a, 0xccf33ccf, 0x333f0fcc, 0xcf33ccf3, 0x30000000,
+    // [1366]: 437 "з"
+    0x00080a0a, 0x3c3cc3c3, 0x3030c3c3, 0x3c3c0000,
+    // [1370]: 438 "и"
+    0x00080a0a, 0xc3c3c3c3, 0xf3f3cfcf, 0xc3c30000,
+    // [1374]: 439 "й"
+    0x00081004, 0xc3c33c3c, 0x0000c3c3, 0xc3c3f3f3, 0xcfcfc3c3,
+    // [1379]: 43B "л"
+    0x00080a0a, 0xf0f0cccc, 0xcccccccc, 0xc3c30000,
+    // [1383]: 43C "м"
+    0x000a0a0a, 0xc0f03f3f, 0xcfccf33c, 0x0f03c0f0, 0x30000000,
+    // [1388]: 43D "н"
+    0x00080a0a, 0xc3c3c3c3, 0xffffc3c3, 0xc3c30000,
+    // [1392]: 43F "п"
+    0x00080a0a, 0xffffc3c3, 0xc3c3c3c3, 0xc3c30000,
+    // [1396]: 442 "т"
+    0x00060a0a, 0xfff30c30, 0xc30c30c0,
+    // [1399]: 444 "ф"
+    0x000a1206, 0x0c0300c0, 0x303f0fcc, 0xcf33ccf3, 0x3ccf333f, 0x0fc0c030, 0x0c030000,
+    // [1406]: 446 "ц"
+    0x000a0c0a, 0x30cc330c, 0xc330cc33, 0x0cc3ffff, 0xfc030000,
+    // [1411]: 447 "ч"
+    0x00080a0a, 0xc3c3c3c3, 0xfcfcc0c0, 0xc0c00000,
+    // [1415]: 448 "ш"
+    0x000a0a0a, 0xccf33ccf, 0x33ccf33c, 0xcf33ffff, 0xf0000000,
+    // [1420]: 449 "щ"
+    0x000c0c0a, 0x33333333, 0x33333333, 0x33333333, 0xffffffc0, 0x0c000000,
+    // [1426]: 44A "ъ"
+    0x00080a0a, 0x0f0f0c0c, 0x3c3ccccc, 0x3c3c0000,
+    // [1430]: 44B "ы"
+    0x000a0a0a, 0xc0f03c0f, 0x03c3f0fc, 0xcf33c3f0, 0xf0000000,
+    // [1435]: 44C "ь"
+    0x00060a0a, 0x0c30c33c, 0xfcf33cf0,
+    // [1438]: 44D "э"
+    0x00080a0a, 0x3c3cc3c3, 0xf0f0c3c3, 0x3c3c0000,
+    // [1442]: 44E "ю"
+    0x000c0a0a, 0x3c33c3c3, 0x3c33c3fc, 0x3fc33c33, 0x3c33c300,
+    // [1447]: 44F "я"
+    0x00080a0a, 0xfcfcc3c3, 0xfcfccccc, 0xc3c30000,
+    // [1451]: 452 "ђ"
+    0x000a1206, 0x0300c0fc, 0x3f0300c3, 0xf0fcc330, 0xcc330cc3, 0x30cc0300, 0x300c0000,
+    // [1458]: 454 "є"
+    0x00080a0a, 0xfcfc0303, 0x3f3f0303, 0xfcfc0000,
+    // [1462]: 459 "љ"
+    0x000c0a0a, 0x03c03c3c, 0xc3cccccc, 0xcccccccc, 0x3c33c300,
+    // [1467]: 45A "њ"
+    0x000c0a0a, 0x03303303, 0x30333ff3, 0xffc33c33, 0x3f33f300,
+    // [1472]: 45B "ћ"
+    0x000a0e06, 0x0300c0fc, 0x3f0300c3, 0xf0fcc330, 0xcc330cc3, 0x30c00000,
+    // [1478]: 45F "џ"
+    0x00080c0a, 0xc3c3c3c3, 0xc3c3c3c3, 0xffff0c0c,
+    // [1482]: 490 "Ґ"
+    0x00081004, 0xc0c0ffff, 0x03030303, 0x03030303, 0x03030303,
+    // [1487]: 491 "ґ"
+    0x00060c08, 0xc30fff0c, 0x30c30c30, 0xc3000000,
+    // [1491]: 2018 "‘"
     0x00040604, 0xcc333300,
-    // [1020]: 2019 "’"
+    // [1493]: 2019 "’"
     0x00040604, 0xcccc3300,
-    // [1022]: 201A "‚"
+    // [1495]: 201A "‚"
     0x00040612, 0xcccc3300,
-    // [1024]: 201B "‛"
+    // [1497]: 201B "‛"
     0x00040604, 0x3333cc00,
-    // [1026]: 201C "“"
+    // [1499]: 201C "“"
     0x00080604, 0xcccc3333, 0x33330000,
-    // [1029]: 201D "”"
+    // [1502]: 201D "”"
     0x00080604, 0xcccccccc, 0x33330000,
-    // [1032]: 201E "„"
+    // [1505]: 201E "„"
     0x00080612, 0xcccccccc, 0x33330000,
-    // [1035]: 201F "‟"
+    // [1508]: 201F "‟"
     0x00080604, 0x33333333, 0xcccc0000,
-    // [1038]: 2020 "†"
+    // [1511]: 2020 "†"
     0x00060a04, 0x30cfff30, 0xc30c30c0,
-    // [1041]: 2021 "‡"
+    // [1514]: 2021 "‡"
     0x00061206, 0x30c30cff, 0xf30c30c3, 0x0cfff30c, 0x30c00000,
-    // [1046]: 2022 "•"
+    // [1519]: 2022 "•"
     0x000a0a08, 0x3f0fcfff, 0xffffffff, 0xffff3f0f, 0xc0000000,
-    // [1051]: 20AC "€"
+    // [1524]: 20AC "€"
     0x000c0e04, 0x3f03f0c0, 0xcc0c0ff0, 0xff00c00c, 0x0ff0ffc0, 0xcc0c3f03, 0xf0000000,
-    // [1058]: FFFD "�"
+    // [1531]: FFFD "�"
     0x00121402, 0x00c00030, 0x003f000f, 0xc00f3c03, 0xcf03ccf0, 0xf33cfcff, 0xff3ffff3, 0xfffcff3f,
     0xff0fffc0, 0xf3c03cf0, 0x03f000fc, 0x000c0003, 0x00000000,
 ];
@@ -1154,20 +1528,8 @@ mod tests {
         for i in 0..HASH_BASIC_LATIN.len()-1 {
             assert!(HASH_BASIC_LATIN[i] < HASH_BASIC_LATIN[i+1]);
         }
-        for i in 0..HASH_LATIN_1_SUPPLEMENT.len()-1 {
-            assert!(HASH_LATIN_1_SUPPLEMENT[i] < HASH_LATIN_1_SUPPLEMENT[i+1]);
-        }
-        for i in 0..HASH_LATIN_EXTENDED_A.len()-1 {
-            assert!(HASH_LATIN_EXTENDED_A[i] < HASH_LATIN_EXTENDED_A[i+1]);
-        }
-        for i in 0..HASH_GENERAL_PUNCTUATION.len()-1 {
-            assert!(HASH_GENERAL_PUNCTUATION[i] < HASH_GENERAL_PUNCTUATION[i+1]);
-        }
-        for i in 0..HASH_CURRENCY_SYMBOLS.len()-1 {
-            assert!(HASH_CURRENCY_SYMBOLS[i] < HASH_CURRENCY_SYMBOLS[i+1]);
-        }
-        for i in 0..HASH_SPECIALS.len()-1 {
-            assert!(HASH_SPECIALS[i] < HASH_SPECIALS[i+1]);
+        for i in 0..HASH_CYRILLIC.len()-1 {
+            assert!(HASH_CYRILLIC[i] < HASH_CYRILLIC[i+1]);
         }
     }
 }
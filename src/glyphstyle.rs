@@ -6,7 +6,7 @@
 //
 #![forbid(unsafe_code)]
 
-use crate::fonts;
+use crate::fonts::GlyphSet;
 
 /// Style options for Latin script fonts
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -14,6 +14,10 @@ pub enum GlyphStyle {
     Small = 0,
     Regular = 1,
     Bold = 2,
+    /// Large digits for clocks and numeric readouts. Digits, ":", ".", "%",
+    /// "-", and "+" come from a 64px font with digits that all have the same
+    /// width. Everything else is painted the same as Bold.
+    Numeric = 3,
 }
 
 /// Convert number to style for use with register-based message passing sytems
@@ -24,6 +28,7 @@ impl From<usize> for GlyphStyle {
             0 => GlyphStyle::Small,
             1 => GlyphStyle::Regular,
             2 => GlyphStyle::Bold,
+            3 => GlyphStyle::Numeric,
             _ => GlyphStyle::Regular,
         }
     }
@@ -31,12 +36,13 @@ impl From<usize> for GlyphStyle {
 
 /// Convert style to number for use with register-based message passing sytems
 // [by bunnie for Xous]
-impl Into<usize> for GlyphStyle {
-    fn into(self) -> usize {
-        match self {
+impl From<GlyphStyle> for usize {
+    fn from(gs: GlyphStyle) -> Self {
+        match gs {
             GlyphStyle::Small => 0,
             GlyphStyle::Regular => 1,
             GlyphStyle::Bold => 2,
+            GlyphStyle::Numeric => 3,
         }
     }
 }
@@ -45,9 +51,9 @@ impl Into<usize> for GlyphStyle {
 // [by bunnie for Xous]
 pub fn glyph_to_height_hint(g: GlyphStyle) -> usize {
     match g {
-        GlyphStyle::Small => fonts::small::MAX_HEIGHT as usize,
-        GlyphStyle::Regular => fonts::regular::MAX_HEIGHT as usize,
-        GlyphStyle::Bold => fonts::regular::MAX_HEIGHT as usize,
+        #[cfg(feature = "numeric")]
+        GlyphStyle::Numeric => GlyphSet::Numeric.max_height(),
+        _ => GlyphSet::latin(g).max_height(),
     }
 }
 
@@ -60,9 +66,11 @@ mod tests {
         let s: usize = GlyphStyle::Small.into();
         let r: usize = GlyphStyle::Regular.into();
         let b: usize = GlyphStyle::Bold.into();
+        let n: usize = GlyphStyle::Numeric.into();
         assert_eq!(GlyphStyle::Small, GlyphStyle::from(s));
         assert_eq!(GlyphStyle::Regular, GlyphStyle::from(r));
         assert_eq!(GlyphStyle::Bold, GlyphStyle::from(b));
+        assert_eq!(GlyphStyle::Numeric, GlyphStyle::from(n));
         let bad_arg = 255;
         assert_eq!(GlyphStyle::Regular, GlyphStyle::from(bad_arg));
     }
@@ -72,8 +80,16 @@ mod tests {
         let h1 = glyph_to_height_hint(GlyphStyle::Small);
         let h2 = glyph_to_height_hint(GlyphStyle::Regular);
         let h3 = glyph_to_height_hint(GlyphStyle::Bold);
+        let h4 = glyph_to_height_hint(GlyphStyle::Numeric);
+        #[cfg(feature = "latin-small")]
         assert_eq!(h1, 24);
+        #[cfg(feature = "latin-regular")]
         assert_eq!(h2, 30);
+        #[cfg(feature = "latin-bold")]
         assert_eq!(h3, 30);
+        #[cfg(feature = "numeric")]
+        assert_eq!(h4, 64);
+        // Styles with no font use the height of the closest included font
+        assert!(h1 <= h2 && h2 == h3 && h3 <= h4);
     }
 }
@@ -0,0 +1,74 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+/// Precursor UI icons, which paint_str() draws from Private Use Area
+/// codepoints U+E700..U+E70C (see codegen/src_data/icon_index.json)
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Icon {
+    Battery05 = 0xE700,
+    Battery25 = 0xE701,
+    Battery50 = 0xE702,
+    Battery75 = 0xE703,
+    Battery99 = 0xE704,
+    Radio3 = 0xE705,
+    Radio2 = 0xE706,
+    Radio1 = 0xE707,
+    Radio0 = 0xE708,
+    RadioOff = 0xE709,
+    ShiftArrow = 0xE70A,
+    BackspaceSymbol = 0xE70B,
+    EnterSymbol = 0xE70C,
+}
+
+impl Icon {
+    /// Pick the battery icon for a charge level in percent
+    pub fn battery(percent: u8) -> Icon {
+        match percent {
+            0..=14 => Icon::Battery05,
+            15..=37 => Icon::Battery25,
+            38..=62 => Icon::Battery50,
+            63..=87 => Icon::Battery75,
+            _ => Icon::Battery99,
+        }
+    }
+
+    /// Pick the radio icon for a signal strength of 0 to 3 bars, or None when
+    /// the radio is off
+    pub fn radio(bars: Option<u8>) -> Icon {
+        match bars {
+            None => Icon::RadioOff,
+            Some(0) => Icon::Radio0,
+            Some(1) => Icon::Radio1,
+            Some(2) => Icon::Radio2,
+            Some(_) => Icon::Radio3,
+        }
+    }
+}
+
+/// Convert icon to its Private Use Area char, for use in strings
+impl From<Icon> for char {
+    fn from(icon: Icon) -> Self {
+        // All the icon codepoints are valid chars
+        core::char::from_u32(icon as u32).unwrap_or('\u{FFFD}')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_icon_picks() {
+        assert_eq!(Icon::battery(0), Icon::Battery05);
+        assert_eq!(Icon::battery(25), Icon::Battery25);
+        assert_eq!(Icon::battery(50), Icon::Battery50);
+        assert_eq!(Icon::battery(80), Icon::Battery75);
+        assert_eq!(Icon::battery(100), Icon::Battery99);
+        assert_eq!(Icon::radio(None), Icon::RadioOff);
+        assert_eq!(Icon::radio(Some(0)), Icon::Radio0);
+        assert_eq!(Icon::radio(Some(9)), Icon::Radio3);
+        assert_eq!(char::from(Icon::EnterSymbol), '\u{E70C}');
+    }
+}
@@ -4,16 +4,36 @@
 #![no_std]
 #![forbid(unsafe_code)]
 
+#[cfg(not(any(
+    feature = "latin-small",
+    feature = "latin-regular",
+    feature = "latin-bold"
+)))]
+compile_error!(
+    "blitstr needs at least one of the latin-small, latin-regular, or latin-bold features"
+);
+
 mod api;
 mod blit;
+mod cache;
 mod cliprect;
+mod coverage;
 mod cursor;
+mod custom;
 pub mod demo;
+mod fallback;
+mod font;
+mod fontfile;
 mod fonts;
 mod framebuffer;
 mod glyphstyle;
+mod icon;
 mod m3hash;
+mod normalize;
+mod options;
 mod pt;
+mod scale;
+mod synthetic;
 
 // Export v1 api names. The point of using re-exports is to allow for splitting
 // the crate implementation into relatively small modules that are easy to
@@ -37,6 +57,13 @@ mod tests {
     }
 
     #[test]
+    #[cfg(all(
+        feature = "emoji",
+        feature = "hanzi",
+        feature = "latin-small",
+        feature = "latin-regular",
+        feature = "latin-bold"
+    ))]
     /// Test for hashed frame buffer match using the font sampler demo screen.
     /// This covers many string blitting features and edge cases all at once.
     /// If this test fails, try loading the wasm demo to look for what changed.
@@ -48,6 +75,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "latin-small")]
     /// Test paint_str() with GlyphStyle::Small and short ascii string
     fn test_paint_str_glyphstyle_small_abc() {
         let fb = &mut new_fr_buf();
@@ -60,6 +88,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "latin-regular")]
     /// Test paint_str() with GlyphStyle::Regluar and short ascii string
     fn test_paint_str_glyphstyle_regular_abc() {
         let fb = &mut new_fr_buf();
@@ -72,6 +101,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "latin-bold")]
     /// Test paint_str() with GlyphStyle::Bold and short ascii string
     fn test_paint_str_glyphstyle_bold_abc() {
         let fb = &mut new_fr_buf();
@@ -84,6 +114,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "emoji")]
     /// Test paint_str() with an emoji cat in multiple styles.
     /// The point is that emoji glyphs are the same regardless of GlyphStyle.
     fn test_paint_str_emoji_cat_multi_style() {
@@ -106,6 +137,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(all(feature = "hanzi", feature = "latin-regular"))]
     /// Test paint_str() with a 4 line poem using hanzi.
     fn test_paint_str_hanzi_goose_poem() {
         let fb = &mut new_fr_buf();
@@ -114,6 +146,679 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "emoji")]
+    /// Test paint_str() with a regional indicator pair that has a flag glyph
+    fn test_paint_str_regional_indicator_flag() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str(fb, clip, cursor, GlyphStyle::Regular, "🇺🇸");
+        assert_eq!(m3hash::frame_buffer(fb, 0), 0x80C1A383);
+    }
+
+    #[test]
+    #[cfg(all(feature = "emoji", feature = "latin-small"))]
+    /// Test paint_str() with a regional indicator pair that has no flag glyph.
+    /// The pair should become one boxed region code rather than two letters.
+    fn test_paint_str_regional_indicator_unknown_pair() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str(fb, clip, cursor, GlyphStyle::Regular, "🇦🇦");
+        assert_eq!(m3hash::frame_buffer(fb, 0), 0xBE4A703E);
+
+        // Painting the same symbols one at a time gives two letter glyphs
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str(fb, clip, cursor, GlyphStyle::Regular, "🇦");
+        paint_str(fb, clip, cursor, GlyphStyle::Regular, "🇦");
+        assert_eq!(m3hash::frame_buffer(fb, 0), 0x9321295E);
+    }
+
+    #[test]
+    #[cfg(all(feature = "latin-small", feature = "latin-regular"))]
+    /// Test paint_str() with NFD base + combining mark clusters that have no
+    /// precomposed glyph, so the marks get overlaid on their base glyphs.
+    fn test_paint_str_combining_mark_overlay() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        // Vietnamese "Tiếng Việt" and Pinyin "Hànyǔ Pīnyīn", all NFD
+        let vi = "Tie\u{302}\u{301}ng Vie\u{323}\u{302}t\n";
+        let zh = "Ha\u{300}nyu\u{30C} Pi\u{304}nyi\u{304}n";
+        paint_str(fb, clip, cursor, GlyphStyle::Regular, vi);
+        paint_str(fb, clip, cursor, GlyphStyle::Small, zh);
+        assert_eq!(m3hash::frame_buffer(fb, 0), 0x818E1683);
+    }
+
+    #[test]
+    /// Test paint_str() with a combining mark that has no mark glyph. It should
+    /// still be painted as U+FFFD after its base glyph.
+    fn test_paint_str_combining_mark_unsupported() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str(fb, clip, cursor, GlyphStyle::Regular, "x\u{353}");
+        let hash = m3hash::frame_buffer(fb, 0);
+
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str(fb, clip, cursor, GlyphStyle::Regular, "x\u{FFFD}");
+        assert_eq!(m3hash::frame_buffer(fb, 0), hash);
+    }
+
+    #[test]
+    /// Test paint_str() with clusters that have no glyph, but that do have a
+    /// Unicode equivalent with glyphs. Each pair should paint identically.
+    fn test_paint_str_unicode_equivalents() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let pairs = [
+            ("\u{1CE}", "a\u{30C}"), // NFC with no glyph => NFD mark overlay
+            ("\u{212B}", "\u{C5}"),  // Angstrom sign => NFD => NFC alias glyph
+            ("\u{FB01}", "fi"),      // Ligature => compatibility fold
+            ("\u{2026}", "..."),     // Ellipsis => compatibility fold
+        ];
+        for (s, equivalent) in pairs.iter() {
+            clear_region(fb, clip);
+            let cursor = &mut Cursor::from_top_left_of(clip);
+            paint_str(fb, clip, cursor, GlyphStyle::Regular, s);
+            let hash = m3hash::frame_buffer(fb, 0);
+
+            clear_region(fb, clip);
+            let cursor = &mut Cursor::from_top_left_of(clip);
+            paint_str(fb, clip, cursor, GlyphStyle::Regular, equivalent);
+            assert_eq!(m3hash::frame_buffer(fb, 0), hash);
+        }
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "latin-small",
+        feature = "latin-regular",
+        feature = "latin-bold"
+    ))]
+    /// Test paint_str() with Greek and Cyrillic text in each GlyphStyle
+    fn test_paint_str_greek_and_cyrillic() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let s = "Γειά σου κόσμε!\nПривет, мир! Їжак, ґанок, Ђорђе";
+        let styles = [
+            (GlyphStyle::Small, 0x3C802A16),
+            (GlyphStyle::Regular, 0x14154BE4),
+            (GlyphStyle::Bold, 0x8F2A743E),
+        ];
+        for (st, expected) in styles.iter() {
+            assert_eq!(coverage(s, *st).next(), None);
+            clear_region(fb, clip);
+            let cursor = &mut Cursor::from_top_left_of(clip);
+            paint_str(fb, clip, cursor, *st, s);
+            assert_eq!(m3hash::frame_buffer(fb, 0), *expected);
+        }
+    }
+
+    #[test]
+    /// Test paint_str() with Greek and Cyrillic letters that share glyphs
+    /// with latin letters. Each pair should paint identically.
+    fn test_paint_str_greek_and_cyrillic_homoglyphs() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let pairs = [
+            ("ΑΒΕΗΙΚΜΝΟΡΤΧ", "ABEHIKMNOPTX"), // Greek => latin
+            ("АВЕКМНОРСТХ", "ABEKMHOPCTX"),   // Cyrillic => latin
+            ("аеорсух ёї", "aeopcyx ëï"),     // Cyrillic => latin
+            ("ГПФк", "ΓΠΦκ"),                 // Cyrillic => Greek
+        ];
+        for st in [GlyphStyle::Small, GlyphStyle::Regular, GlyphStyle::Bold].iter() {
+            for (s, homoglyphs) in pairs.iter() {
+                clear_region(fb, clip);
+                let cursor = &mut Cursor::from_top_left_of(clip);
+                paint_str(fb, clip, cursor, *st, s);
+                let hash = m3hash::frame_buffer(fb, 0);
+
+                clear_region(fb, clip);
+                let cursor = &mut Cursor::from_top_left_of(clip);
+                paint_str(fb, clip, cursor, *st, homoglyphs);
+                assert_eq!(m3hash::frame_buffer(fb, 0), hash);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "hanzi", feature = "latin-regular"))]
+    /// Test paint_str() with Japanese hiragana, katakana, and kanji (hanzi)
+    fn test_paint_str_kana() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let s = "こんにちは、世界！\nカタカナ ガギグ\nぱぴぷ がっこう";
+        assert_eq!(coverage(s, GlyphStyle::Regular).next(), None);
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str(fb, clip, cursor, GlyphStyle::Regular, s);
+        assert_eq!(m3hash::frame_buffer(fb, 0), 0x40C9E890);
+    }
+
+    #[test]
+    /// Test paint_str() with kana plus combining (han)dakuten (NFD). Each
+    /// pair should paint identically.
+    fn test_paint_str_kana_nfd() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let pairs = [
+            ("が", "か\u{3099}"),
+            ("パ", "ハ\u{309A}"),
+            ("ヴ", "ウ\u{3099}"),
+        ];
+        for (nfc, nfd) in pairs.iter() {
+            clear_region(fb, clip);
+            let cursor = &mut Cursor::from_top_left_of(clip);
+            paint_str(fb, clip, cursor, GlyphStyle::Regular, nfc);
+            let hash = m3hash::frame_buffer(fb, 0);
+
+            clear_region(fb, clip);
+            let cursor = &mut Cursor::from_top_left_of(clip);
+            paint_str(fb, clip, cursor, GlyphStyle::Regular, nfd);
+            assert_eq!(m3hash::frame_buffer(fb, 0), hash);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "latin-regular")]
+    /// Test paint_str() with hangul syllables composed from jamo glyphs
+    fn test_paint_str_hangul() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let s = "안녕하세요, 세계!\n한국어 읽기 뷁 값";
+        assert_eq!(coverage(s, GlyphStyle::Regular).next(), None);
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str(fb, clip, cursor, GlyphStyle::Regular, s);
+        assert_eq!(m3hash::frame_buffer(fb, 0), 0x5433D9AB);
+    }
+
+    #[test]
+    /// Test paint_str() with hangul syllables as conjoining jamo (NFD). Each
+    /// pair should paint identically.
+    fn test_paint_str_hangul_nfd() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let pairs = [
+            ("한", "\u{1112}\u{1161}\u{11AB}"),
+            ("가", "\u{1100}\u{1161}"),
+            ("뷁", "\u{1107}\u{1170}\u{11B0}"),
+        ];
+        for (nfc, nfd) in pairs.iter() {
+            clear_region(fb, clip);
+            let cursor = &mut Cursor::from_top_left_of(clip);
+            paint_str(fb, clip, cursor, GlyphStyle::Regular, nfc);
+            let hash = m3hash::frame_buffer(fb, 0);
+
+            clear_region(fb, clip);
+            let cursor = &mut Cursor::from_top_left_of(clip);
+            paint_str(fb, clip, cursor, GlyphStyle::Regular, nfd);
+            assert_eq!(m3hash::frame_buffer(fb, 0), hash);
+        }
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "latin-small",
+        feature = "latin-regular",
+        feature = "latin-bold"
+    ))]
+    /// Test paint_str() with box drawing and block elements in each style
+    fn test_paint_str_box_drawing() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let s = "┌─┬─┐ ╔═╦═╗ ╭─╮\n│a│b│ ║ ║ ║ │╳│\n└─┴─┘ ╚═╩═╝ ╰─╯\n░▒▓█▁▂▃▄▅▆▇ ┄┅┈┉ ▖▗▘▙▚▛▜▝▞▟";
+        let cases = [
+            (GlyphStyle::Small, 0x8FAFA5DF),
+            (GlyphStyle::Regular, 0x125DE3CE),
+            (GlyphStyle::Bold, 0x13AC8213),
+        ];
+        for (st, expected) in cases.iter() {
+            assert_eq!(coverage(s, *st).next(), None);
+            clear_region(fb, clip);
+            let cursor = &mut Cursor::from_top_left_of(clip);
+            paint_str(fb, clip, cursor, *st, s);
+            assert_eq!(m3hash::frame_buffer(fb, 0), *expected);
+        }
+    }
+
+    #[test]
+    /// Test that box drawing cells on very tall lines get stretched as far as
+    /// the cell height allows, without overflowing it
+    fn test_paint_str_box_drawing_tall_line() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let painted = |fb: &FrBuf| fb.iter().map(|word| word.count_zeros()).sum::<u32>();
+        let mut counts = [0; 2];
+        for (line_height, count) in [255, 1000].iter().zip(counts.iter_mut()) {
+            clear_region(fb, clip);
+            let cursor = &mut Cursor::new(0, 0, *line_height);
+            paint_str(fb, clip, cursor, GlyphStyle::Regular, "\u{2500}");
+            assert_eq!(cursor.line_height, *line_height);
+            *count = painted(fb);
+        }
+        assert!(counts[0] > 0);
+        assert_eq!(counts[0], counts[1]);
+    }
+
+    #[test]
+    /// Test that rows of full blocks tile into a solid rectangle with no gaps
+    /// between cells or lines, for each style
+    fn test_paint_str_box_drawing_tiles_seamlessly() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let is_black = |fb: &FrBuf, x: usize, y: usize| {
+            (fb[y * WORDS_PER_LINE + (x >> 5)] >> (x & 0x1f)) & 1 == 0
+        };
+        for st in [GlyphStyle::Small, GlyphStyle::Regular, GlyphStyle::Bold].iter() {
+            clear_region(fb, clip);
+            let cursor = &mut Cursor::from_top_left_of(clip);
+            paint_str(fb, clip, cursor, *st, "████\n████\n████");
+            // Cells are half as wide as the line height, and lines are 1px
+            // further apart than that
+            let line_height = glyph_to_height_hint(*st);
+            let (w, h) = (4 * (line_height / 2), 3 * (line_height + 1));
+            for y in 0..h {
+                for x in 0..w {
+                    assert!(is_black(fb, x, y), "gap at ({}, {}) for {:?}", x, y, st);
+                }
+                assert!(!is_black(fb, w, y));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "latin-small",
+        feature = "latin-regular",
+        feature = "latin-bold"
+    ))]
+    /// Test paint_str() with a braille sparkline in each style
+    fn test_paint_str_braille() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let s = "Battery ⣀⣠⣤⣴⣶⣾⣿⣷⣶⣦⣤⣄⣀\nSignal ⡇⣿⢸⠀⠉⠛⠿⣿ ⠁⠂⠄⡀⢀⠠⠐⠈";
+        let cases = [
+            (GlyphStyle::Small, 0xC4A8B83E),
+            (GlyphStyle::Regular, 0x9B5B3401),
+            (GlyphStyle::Bold, 0xDFA243DF),
+        ];
+        for (st, expected) in cases.iter() {
+            assert_eq!(coverage(s, *st).next(), None);
+            clear_region(fb, clip);
+            let cursor = &mut Cursor::from_top_left_of(clip);
+            paint_str(fb, clip, cursor, *st, s);
+            assert_eq!(m3hash::frame_buffer(fb, 0), *expected);
+        }
+    }
+
+    #[test]
+    /// Test that braille dots are evenly spaced across neighboring cells
+    fn test_paint_str_braille_dot_spacing() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        for st in [GlyphStyle::Small, GlyphStyle::Regular, GlyphStyle::Bold].iter() {
+            clear_region(fb, clip);
+            let cursor = &mut Cursor::from_top_left_of(clip);
+            paint_str(fb, clip, cursor, *st, "⠉⠉⠉⠉");
+            // Find the left edges of the dots along the middle of the top row
+            let pitch = (glyph_to_height_hint(*st) + 1) / 4;
+            let y = pitch / 2;
+            let mut edges = [0; 8];
+            let mut n = 0;
+            let black = |x: usize| (fb[y * WORDS_PER_LINE + (x >> 5)] >> (x & 0x1f)) & 1 == 0;
+            for x in 0..WIDTH {
+                if black(x) && (x == 0 || !black(x - 1)) {
+                    assert!(n < edges.len());
+                    edges[n] = x;
+                    n += 1;
+                }
+            }
+            assert_eq!(n, edges.len());
+            for pair in edges.windows(2) {
+                assert_eq!(pair[1] - pair[0], pitch);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "latin-regular")]
+    /// Test paint_str() with every Precursor UI icon in a line of text
+    fn test_paint_str_icons() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let s = "\u{E700}\u{E701}\u{E702}\u{E703}\u{E704} Battery\n\
+                 \u{E705}\u{E706}\u{E707}\u{E708}\u{E709} Radio\n\
+                 \u{E70A} Shift \u{E70B} Delete \u{E70C} Enter";
+        assert_eq!(coverage(s, GlyphStyle::Regular).next(), None);
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str(fb, clip, cursor, GlyphStyle::Regular, s);
+        assert_eq!(m3hash::frame_buffer(fb, 0), 0xD3243A61);
+    }
+
+    /// Custom glyph rows for a 16x20 sync icon (two arrows in a circle)
+    #[cfg(feature = "latin-regular")]
+    static SYNC_ICON: [u32; 20] = [
+        0x00000000, 0x00000000, 0x00000000, 0x0FF00000, 0x1FF80000, 0x381C0000, 0x300E0000,
+        0x00060000, 0x000E0000, 0x001E0000, 0x78000000, 0x70000000, 0x60000000, 0x700C0000,
+        0x381C0000, 0x1FF80000, 0x0FF00000, 0x00000000, 0x00000000, 0x00000000,
+    ];
+
+    #[test]
+    #[cfg(feature = "latin-regular")]
+    /// Test paint_str_with_fallback() with a custom glyph registered for a
+    /// Private Use Area char in the text flow
+    fn test_paint_str_custom_glyphs() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let s = "Tap \u{E000} to sync";
+        let mut slots = [None; 4];
+        let mut glyphs = CustomGlyphs::new(&mut slots);
+        glyphs.register('\u{E000}', 16, &SYNC_ICON).unwrap();
+        let chain = FallbackChain::EMOJI_FIRST.with_custom_glyphs(&glyphs);
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str_with_fallback(fb, clip, cursor, GlyphStyle::Regular, chain, s);
+        assert_eq!(m3hash::frame_buffer(fb, 0), 0x82C136FA);
+        // Without the registry, the PUA char gets painted as U+FFFD
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str(fb, clip, cursor, GlyphStyle::Regular, s);
+        assert_ne!(m3hash::frame_buffer(fb, 0), 0x82C136FA);
+    }
+
+    #[test]
+    /// Test paint_str_with_fallback() with a built-in font as a Font object.
+    /// Kana should look the same as when paint_str() finds them itself.
+    fn test_paint_str_builtin_font() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let s = "カタカナ ひらがな";
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str(fb, clip, cursor, GlyphStyle::Regular, s);
+        let expected = m3hash::frame_buffer(fb, 0);
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        let sources = [GlyphSource::Latin, GlyphSource::Font];
+        let chain = FallbackChain::new(&sources).with_font(&BuiltinFont::Kana);
+        paint_str_with_fallback(fb, clip, cursor, GlyphStyle::Regular, chain, s);
+        assert_eq!(m3hash::frame_buffer(fb, 0), expected);
+    }
+
+    /// Font of solid bars for the digits 0-9, with a height of 2px per unit
+    #[cfg(feature = "latin-regular")]
+    struct BarFont;
+
+    #[cfg(feature = "latin-regular")]
+    impl Font for BarFont {
+        fn lookup(&self, cluster: &str) -> Option<(usize, usize)> {
+            let digit = cluster.chars().next()?.to_digit(10)?;
+            Some((digit as usize, 1))
+        }
+
+        fn header(&self, glyph: usize) -> GlyphHeader {
+            let h = 2 * glyph + 1;
+            GlyphHeader {
+                w: 5,
+                h,
+                y_offset: self.max_height() - h,
+            }
+        }
+
+        fn nth_word(&self, _glyph: usize, _n: usize) -> u32 {
+            0xFFFF_FFFF
+        }
+
+        fn max_height(&self) -> usize {
+            19
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "latin-regular")]
+    /// Test paint_str_with_fallback() with a Font object defined outside the crate
+    fn test_paint_str_font_object() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let s = "0123456789 ok";
+        let chain = FallbackChain::EMOJI_FIRST.with_font(&BarFont);
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str_with_fallback(fb, clip, cursor, GlyphStyle::Regular, chain, s);
+        assert_eq!(m3hash::frame_buffer(fb, 0), 0xEC92977D);
+        // Without the font, digits come from the emoji glyph set
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str(fb, clip, cursor, GlyphStyle::Regular, s);
+        assert_ne!(m3hash::frame_buffer(fb, 0), 0xEC92977D);
+    }
+
+    /// Font with one solid glyph of the given width, for "="
+    struct WideFont(usize);
+
+    impl Font for WideFont {
+        fn lookup(&self, cluster: &str) -> Option<(usize, usize)> {
+            cluster.starts_with('=').then_some((0, 1))
+        }
+
+        fn header(&self, _glyph: usize) -> GlyphHeader {
+            GlyphHeader {
+                w: self.0,
+                h: 4,
+                y_offset: 8,
+            }
+        }
+
+        fn nth_word(&self, _glyph: usize, _n: usize) -> u32 {
+            0xFFFF_FFFF
+        }
+
+        fn max_height(&self) -> usize {
+            19
+        }
+    }
+
+    #[test]
+    /// Test that glyphs wider than 32px get painted, advance the cursor, and
+    /// get clipped to the clip rect
+    fn test_paint_str_wide_glyph() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let chain = FallbackChain::EMOJI_FIRST.with_font(&WideFont(100));
+        // Painted pixels get cleared, so count the clear pixels
+        let painted = |fb: &FrBuf| fb.iter().map(|word| word.count_zeros()).sum::<u32>();
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str_with_fallback(fb, clip, cursor, GlyphStyle::Regular, chain, "==");
+        assert_eq!(painted(fb), 2 * 100 * 4);
+        assert_eq!(cursor.pt.x, 2 * (100 + 3));
+        // A glyph wider than the clip rect wraps to the next line, and the
+        // part past the right edge gets clipped
+        clear_region(fb, clip);
+        let narrow = ClipRect::new(10, 10, 70, 60);
+        let cursor = &mut Cursor::from_top_left_of(narrow);
+        paint_str_with_fallback(fb, narrow, cursor, GlyphStyle::Regular, chain, "=");
+        assert_eq!(painted(fb), (70 - 11) * 4);
+    }
+
+    #[test]
+    #[cfg(feature = "latin-regular")]
+    /// Test that Font glyphs too wide to unpack into a row buffer get treated
+    /// as missing, at every scale, rather than panicking or getting cut off
+    fn test_paint_str_too_wide_glyph() {
+        let clip = ClipRect::full_screen();
+        let sources = [GlyphSource::Font, GlyphSource::Latin];
+        let paint = |chain: FallbackChain, scale: Scale| {
+            let options = PaintOptions::new(chain).with_scale(scale);
+            let fb = &mut new_fr_buf();
+            clear_region(fb, clip);
+            let cursor = &mut Cursor::from_top_left_of(clip);
+            paint_str_with_options(fb, clip, cursor, GlyphStyle::Regular, options, None, "=");
+            m3hash::frame_buffer(fb, 0)
+        };
+        let latin = FallbackChain::new(&sources[1..]);
+        let (widest, too_wide) = (WideFont(255), WideFont(256));
+        for scale in [Scale::X1, Scale::X2] {
+            let latin_hash = paint(latin, scale);
+            let chain = FallbackChain::new(&sources);
+            assert_ne!(paint(chain.with_font(&widest), scale), latin_hash);
+            assert_eq!(paint(chain.with_font(&too_wide), scale), latin_hash);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "emoji")]
+    /// Test paint_str_with_fallback() with the emoji font loaded from a font
+    /// file. It should look the same as the compiled-in emoji font.
+    fn test_paint_str_font_file() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let s = "😸 🇺🇸 🇦🇦 1️⃣ © 👨‍👩‍👧 abc";
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str(fb, clip, cursor, GlyphStyle::Regular, s);
+        let expected = m3hash::frame_buffer(fb, 0);
+        let buf = &mut [0; 120_000];
+        let len = serialize(BuiltinFont::Emoji, buf).unwrap();
+        let emoji = FontFile::from_words(&buf[..len]).unwrap();
+        let sources = [
+            GlyphSource::Font,
+            GlyphSource::Latin,
+            GlyphSource::BoxDrawing,
+            GlyphSource::Braille,
+            GlyphSource::Icons,
+            #[cfg(feature = "hanzi")]
+            GlyphSource::Hanzi,
+            GlyphSource::Kana,
+            GlyphSource::Hangul,
+        ];
+        let chain = FallbackChain::new(&sources).with_font(&emoji);
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str_with_fallback(fb, clip, cursor, GlyphStyle::Regular, chain, s);
+        assert_eq!(m3hash::frame_buffer(fb, 0), expected);
+    }
+
+    #[test]
+    #[cfg(all(feature = "emoji", feature = "latin-regular"))]
+    /// Test paint_str_with_fallback() with text-first and emoji-first chains.
+    /// Text-first should draw "©" and digits from the latin font.
+    fn test_paint_str_with_fallback_text_first() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let s = "© 2020 😸";
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str(fb, clip, cursor, GlyphStyle::Regular, s);
+        let emoji_first = m3hash::frame_buffer(fb, 0);
+
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        let chain = FallbackChain::EMOJI_FIRST;
+        paint_str_with_fallback(fb, clip, cursor, GlyphStyle::Regular, chain, s);
+        assert_eq!(m3hash::frame_buffer(fb, 0), emoji_first);
+
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        let chain = FallbackChain::TEXT_FIRST;
+        paint_str_with_fallback(fb, clip, cursor, GlyphStyle::Regular, chain, s);
+        assert_eq!(m3hash::frame_buffer(fb, 0), 0xD2F654F8);
+        assert_ne!(m3hash::frame_buffer(fb, 0), emoji_first);
+    }
+
+    #[test]
+    #[cfg(feature = "hanzi")]
+    /// Test paint_str_with_fallback() with a caller supplied chain. Leaving
+    /// out emoji should make the cat fall back to U+FFFD.
+    fn test_paint_str_with_fallback_custom_chain() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        let chain = FallbackChain::new(&[GlyphSource::Latin, GlyphSource::Hanzi]);
+        paint_str_with_fallback(fb, clip, cursor, GlyphStyle::Regular, chain, "a😸");
+        let hash = m3hash::frame_buffer(fb, 0);
+
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str(fb, clip, cursor, GlyphStyle::Regular, "a\u{FFFD}");
+        assert_eq!(m3hash::frame_buffer(fb, 0), hash);
+    }
+
+    #[test]
+    #[cfg(all(feature = "latin-small", feature = "latin-regular"))]
+    /// Test paint_str_with_fallback() painting missing chars as hex code boxes.
+    /// Unlike U+FFFD, boxes for different codepoints should look different.
+    fn test_paint_str_with_fallback_hex_codes() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let chain = FallbackChain::EMOJI_FIRST.with_hex_codes();
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str_with_fallback(fb, clip, cursor, GlyphStyle::Small, chain, "a\u{378}b\n");
+        paint_str_with_fallback(fb, clip, cursor, GlyphStyle::Regular, chain, "\u{10FFFD}");
+        assert_eq!(m3hash::frame_buffer(fb, 0), 0x8CF4D96D);
+
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str_with_fallback(fb, clip, cursor, GlyphStyle::Small, chain, "\u{378}");
+        let hash = m3hash::frame_buffer(fb, 0);
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str_with_fallback(fb, clip, cursor, GlyphStyle::Small, chain, "\u{379}");
+        assert_ne!(m3hash::frame_buffer(fb, 0), hash);
+    }
+
+    #[test]
+    #[cfg(feature = "emoji")]
+    /// Test paint_str() with text (U+FE0E) and emoji (U+FE0F) presentation
+    /// selectors. Each pair should paint identically, and selectors should
+    /// never show up as U+FFFD.
+    fn test_paint_str_presentation_selectors() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let text = FallbackChain::TEXT_FIRST;
+        let emoji = FallbackChain::EMOJI_FIRST;
+        let pairs = [
+            ((emoji, "©\u{FE0E}™\u{FE0E}"), (text, "©™")),
+            ((text, "©\u{FE0F}™\u{FE0F}"), (emoji, "©™")),
+            ((emoji, "🅰\u{FE0E}🅰\u{FE0F}"), (emoji, "🅰🅰")),
+            ((emoji, "a\u{FE0F}b\u{FE0E}"), (emoji, "ab")),
+            ((emoji, "\u{FE0E}\u{FE0F}"), (emoji, "")),
+        ];
+        for ((chain_a, a), (chain_b, b)) in pairs.iter() {
+            clear_region(fb, clip);
+            let cursor = &mut Cursor::from_top_left_of(clip);
+            paint_str_with_fallback(fb, clip, cursor, GlyphStyle::Regular, *chain_a, a);
+            let hash = m3hash::frame_buffer(fb, 0);
+
+            clear_region(fb, clip);
+            let cursor = &mut Cursor::from_top_left_of(clip);
+            paint_str_with_fallback(fb, clip, cursor, GlyphStyle::Regular, *chain_b, b);
+            assert_eq!(m3hash::frame_buffer(fb, 0), hash);
+        }
+        // The text presentation of "©" should not be the emoji glyph
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str(fb, clip, cursor, GlyphStyle::Regular, "©\u{FE0E}");
+        let hash = m3hash::frame_buffer(fb, 0);
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str(fb, clip, cursor, GlyphStyle::Regular, "©");
+        assert_ne!(m3hash::frame_buffer(fb, 0), hash);
+    }
+
+    #[test]
+    #[cfg(feature = "latin-regular")]
     /// Test paint_str() for full string at once vs. concatenating chars.
     /// The point of this is, you can call paint_str() repeatedly reusing the
     /// same cursor, and it will keep track of concatenation and word-wrap.
@@ -146,4 +851,201 @@ mod tests {
         }
         assert_eq!(m3hash::frame_buffer(fb, 0), 0xE5240DD1); // Same hash
     }
+
+    #[test]
+    #[cfg(all(feature = "emoji", feature = "hanzi", feature = "latin-regular"))]
+    /// Test that paint_str_with_cache() paints the same as paint_str(), with
+    /// an empty cache, a full cache, and a cache too small for the string
+    fn test_paint_str_with_cache() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let st = GlyphStyle::Regular;
+        let s = "Café cafe\u{301} 1\u{FE0F}\u{20E3} 🇺🇸 カ\u{3099} \u{1100}\u{1161} 鹅 ©";
+        clear_region(fb, clip);
+        paint_str(fb, clip, &mut Cursor::from_top_left_of(clip), st, s);
+        let hash = m3hash::frame_buffer(fb, 0);
+        for size in [64, 3].iter() {
+            let slots = &mut [None; 64];
+            let cache = &mut GlyphCache::new(&mut slots[..*size]);
+            let mut stats = (0, 0);
+            for _ in 0..2 {
+                clear_region(fb, clip);
+                let cursor = &mut Cursor::from_top_left_of(clip);
+                paint_str_with_cache(fb, clip, cursor, st, cache, s);
+                assert_eq!(m3hash::frame_buffer(fb, 0), hash);
+                assert!(cache.hits() + cache.misses() > stats.0 + stats.1);
+                if *size == 64 && stats != (0, 0) {
+                    // No chars of s share a slot, so every cacheable cluster
+                    // was found the second time
+                    assert_eq!(cache.misses(), stats.1);
+                }
+                stats = (cache.hits(), cache.misses());
+            }
+            assert!(cache.len() <= *size);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "emoji", feature = "latin-regular"))]
+    /// Test that one cache used with two chains paints the same as no cache:
+    /// the text-first chain gets the latin "©" after the emoji-first chain
+    /// cached its emoji, and custom glyphs aren't painted by chains without
+    /// the registry, or after they're unregistered
+    fn test_paint_str_with_cache_two_chains() {
+        let clip = ClipRect::full_screen();
+        let st = GlyphStyle::Regular;
+        let paint = |options: PaintOptions, cache: Option<&mut GlyphCache>, s: &str| {
+            let fb = &mut new_fr_buf();
+            clear_region(fb, clip);
+            let cursor = &mut Cursor::from_top_left_of(clip);
+            paint_str_with_options(fb, clip, cursor, st, options, cache, s);
+            m3hash::frame_buffer(fb, 0)
+        };
+        let slots = &mut [None; 16];
+        let cache = &mut GlyphCache::new(slots);
+        let emoji_first = PaintOptions::default();
+        let text_first = PaintOptions::new(FallbackChain::TEXT_FIRST);
+        let emoji_hash = paint(emoji_first, Some(cache), "\u{A9}");
+        let text_hash = paint(text_first, None, "\u{A9}");
+        assert_ne!(emoji_hash, text_hash);
+        assert_eq!(paint(text_first, Some(cache), "\u{A9}"), text_hash);
+        assert_eq!(paint(emoji_first, Some(cache), "\u{A9}"), emoji_hash);
+        let mut custom_slots = [None; 4];
+        let mut glyphs = CustomGlyphs::new(&mut custom_slots);
+        glyphs.register('\u{E000}', 16, &SYNC_ICON).unwrap();
+        let chain = FallbackChain::EMOJI_FIRST.with_custom_glyphs(&glyphs);
+        let custom_hash = paint(PaintOptions::new(chain), Some(cache), "\u{E000}");
+        let missing_hash = paint(emoji_first, None, "\u{E000}");
+        assert_ne!(custom_hash, missing_hash);
+        assert_eq!(paint(emoji_first, Some(cache), "\u{E000}"), missing_hash);
+        glyphs.unregister('\u{E000}');
+        let chain = FallbackChain::EMOJI_FIRST.with_custom_glyphs(&glyphs);
+        let unregistered_hash = paint(PaintOptions::new(chain), Some(cache), "\u{E000}");
+        assert_eq!(unregistered_hash, missing_hash);
+    }
+
+    #[test]
+    #[cfg(all(feature = "numeric", feature = "latin-bold"))]
+    /// Test that Numeric style paints digits that all advance the cursor by
+    /// the same amount, so numbers of the same length line up
+    fn test_paint_str_numeric() {
+        let fb = &mut new_fr_buf();
+        let clip = ClipRect::full_screen();
+        let st = GlyphStyle::Numeric;
+        let width = |s: &str, fb: &mut FrBuf| {
+            let cursor = &mut Cursor::from_top_left_of(clip);
+            paint_str(fb, clip, cursor, st, s);
+            cursor.pt.x
+        };
+        let w0 = width("0", fb);
+        for d in "123456789".chars() {
+            assert_eq!(width(d.encode_utf8(&mut [0; 4]), fb), w0);
+        }
+        assert_eq!(width("12:34", fb), width("10:01", fb));
+        assert!(width(":", fb) < w0);
+        clear_region(fb, clip);
+        let cursor = &mut Cursor::from_top_left_of(clip);
+        paint_str(fb, clip, cursor, st, "12:34 pm\n-5.0% +2\u{2212}1");
+        assert_eq!(cursor.line_height, 64);
+        assert_eq!(m3hash::frame_buffer(fb, 0), 0x4F6524CE);
+    }
+
+    #[test]
+    #[cfg(all(feature = "emoji", feature = "hanzi", feature = "latin-regular"))]
+    /// Test that paint_str_with_options() at each scale paints the same pixels
+    /// as at 1x with the clip rect scaled down, with each pixel scaled up to a
+    /// block. That covers padding, line height, word wrap, and clipping, for
+    /// the default chain and for a chain with custom glyphs and hex code boxes,
+    /// with and without synthetic styles.
+    fn test_paint_str_scaled() {
+        let s = "Hi e\u{301}x \u{1F63A} \u{1F1E6}\u{1F1E6} \u{4E16}\u{754C} \u{250C}\u{2500}\u{2510} \u{28FF} \
+            \u{E700} \u{E000} \u{378} \u{A9} wrap these words\nonto lines until they run off the bottom";
+        let px =
+            |fb: &FrBuf, x: usize, y: usize| fb[y * WORDS_PER_LINE + (x >> 5)] >> (x & 0x1f) & 1;
+        let mut custom_slots = [None; 4];
+        let mut glyphs = CustomGlyphs::new(&mut custom_slots);
+        glyphs.register('\u{E000}', 16, &SYNC_ICON).unwrap();
+        let custom_chain = FallbackChain::TEXT_FIRST
+            .with_hex_codes()
+            .with_custom_glyphs(&glyphs);
+        let bold_oblique = Synthetic::BOLD_OBLIQUE;
+        let custom = PaintOptions::new(custom_chain).with_synthetic(bold_oblique);
+        for &options in [PaintOptions::default(), custom].iter() {
+            let fb1 = &mut new_fr_buf();
+            let clip1 = ClipRect::new(2, 3, 81, 130);
+            clear_region(fb1, clip1);
+            let c1 = &mut Cursor::from_top_left_of(clip1);
+            paint_str_with_options(fb1, clip1, c1, GlyphStyle::Regular, options, None, s);
+            assert!(c1.pt.y > clip1.max.y);
+            let slots = &mut [None; 16];
+            let cache = &mut GlyphCache::new(slots);
+            for scale in [Scale::X1, Scale::X2, Scale::X3, Scale::X4] {
+                let n = scale.factor();
+                let fb = &mut new_fr_buf();
+                let clip = ClipRect::new(2 * n, 3 * n, 81 * n, 130 * n);
+                clear_region(fb, clip);
+                let c = &mut Cursor::from_top_left_of(clip);
+                let scaled = options.with_scale(scale);
+                paint_str_with_options(fb, clip, c, GlyphStyle::Regular, scaled, Some(cache), s);
+                assert_eq!(c.pt, Pt::new(c1.pt.x * n, c1.pt.y * n));
+                assert_eq!(c.line_height, c1.line_height * n);
+                for y in clip.min.y..clip.max.y {
+                    for x in clip.min.x..clip.max.x {
+                        let expected = px(fb1, x / n, y / n);
+                        assert_eq!(px(fb, x, y), expected, "{}x at ({}, {})", n, x, y);
+                    }
+                }
+            }
+            assert!(cache.hits() > 0);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "emoji", feature = "hanzi", feature = "latin-regular"))]
+    /// Test that synthetic bold and oblique work on glyph sets that have no bold
+    /// glyphs of their own, and leave box drawing cells alone so they join up
+    fn test_paint_str_synthetic() {
+        let clip = ClipRect::full_screen();
+        let st = GlyphStyle::Regular;
+        let painted = |fb: &FrBuf, x: usize, y: usize| {
+            fb[y * WORDS_PER_LINE + (x >> 5)] >> (x & 0x1f) & 1 == 0
+        };
+        let paint = |s: &str, synthetic: Synthetic| {
+            let mut fb = new_fr_buf();
+            clear_region(&mut fb, clip);
+            let cursor = &mut Cursor::from_top_left_of(clip);
+            let options = PaintOptions::default().with_synthetic(synthetic);
+            paint_str_with_options(&mut fb, clip, cursor, st, options, None, s);
+            (fb, cursor.pt.x)
+        };
+        // Bold is the glyph ORed with itself shifted 1px right, 1px wider
+        let (fb, x) = paint("\u{4E16}", Synthetic::NONE);
+        let (bold_fb, bold_x) = paint("\u{4E16}", Synthetic::BOLD);
+        assert_eq!(bold_x, x + 1);
+        for y in 0..LINES {
+            for x in 1..WIDTH {
+                let expected = painted(&fb, x, y) || painted(&fb, x - 1, y);
+                assert_eq!(painted(&bold_fb, x, y), expected, "({}, {})", x, y);
+            }
+        }
+        // Oblique moves pixels right without adding or removing any
+        let count = |fb: &FrBuf| fb.iter().map(|word| word.count_zeros()).sum::<u32>();
+        let (oblique_fb, oblique_x) = paint("\u{4E16}", Synthetic::OBLIQUE);
+        assert!(oblique_x > x);
+        assert_eq!(count(&oblique_fb), count(&fb));
+        assert_ne!(oblique_fb, fb);
+        // Box drawing cells stay the same so that they tile
+        let boxes = "\u{250C}\u{2500}\u{2510}";
+        let plain_boxes = paint(boxes, Synthetic::NONE);
+        assert_eq!(paint(boxes, Synthetic::BOLD_OBLIQUE), plain_boxes);
+        let s = "Bold \u{4E16}\u{754C} \u{1F63A} x\u{301}\u{323}";
+        for (synthetic, expected) in [
+            (Synthetic::BOLD, 0xAA12E9D5),
+            (Synthetic::OBLIQUE, 0x4C15B1B2),
+            (Synthetic::BOLD_OBLIQUE, 0x8D8B3568),
+        ] {
+            let (fb, _) = paint(s, synthetic);
+            assert_eq!(m3hash::frame_buffer(&fb, 0), expected, "{:?}", synthetic);
+        }
+    }
 }
@@ -17,10 +17,9 @@ pub fn grapheme_cluster(gc: &str, seed: u32, limit: u32) -> (u32, usize) {
     let mut h = seed;
     let mut k;
     // Hash each character as its own u32 block
-    let mut n = 0;
     let mut bytes_hashed = gc.len();
-    for (i, c) in gc.char_indices() {
-        if n >= limit {
+    for (n, (i, c)) in gc.char_indices().enumerate() {
+        if n as u32 >= limit {
             bytes_hashed = i;
             break;
         }
@@ -32,7 +31,6 @@ pub fn grapheme_cluster(gc: &str, seed: u32, limit: u32) -> (u32, usize) {
         h = h.rotate_left(13);
         h = h.wrapping_mul(5);
         h = h.wrapping_add(0xe6546b64);
-        n += 1;
     }
     h ^= bytes_hashed as u32;
     // Finalize with avalanche
@@ -0,0 +1,70 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+use crate::fallback::FallbackChain;
+use crate::scale::Scale;
+use crate::synthetic::Synthetic;
+
+/// Options for paint_str_with_options(), so glyph lookup and rendering choices
+/// can be combined freely: any fallback chain (with custom glyphs, a Font
+/// object, or hex code boxes) can be painted at any scale with synthetic
+/// styles.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PaintOptions<'a> {
+    /// Order to look up glyphs for each grapheme cluster
+    pub chain: FallbackChain<'a>,
+    /// Size of the block of screen pixels painted for each glyph pixel
+    pub scale: Scale,
+    /// Synthetic bold and oblique to apply to every glyph
+    pub synthetic: Synthetic,
+}
+
+impl<'a> PaintOptions<'a> {
+    /// Make options that look up glyphs in chain and paint them as they are
+    /// at 1x
+    pub const fn new(chain: FallbackChain<'a>) -> Self {
+        PaintOptions {
+            chain,
+            scale: Scale::X1,
+            synthetic: Synthetic::NONE,
+        }
+    }
+
+    /// Copy of the options that paints glyphs at scale
+    pub const fn with_scale(self, scale: Scale) -> Self {
+        PaintOptions { scale, ..self }
+    }
+
+    /// Copy of the options that applies synthetic styles to every glyph
+    pub const fn with_synthetic(self, synthetic: Synthetic) -> Self {
+        PaintOptions { synthetic, ..self }
+    }
+}
+
+/// Default options paint the same way as paint_str()
+impl Default for PaintOptions<'static> {
+    fn default() -> Self {
+        PaintOptions::new(FallbackChain::EMOJI_FIRST)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paint_options_builders() {
+        let options = PaintOptions::new(FallbackChain::TEXT_FIRST.with_hex_codes());
+        assert_eq!(options.scale, Scale::X1);
+        assert_eq!(options.synthetic, Synthetic::NONE);
+        let styled = options
+            .with_scale(Scale::X3)
+            .with_synthetic(Synthetic::BOLD);
+        assert_eq!(styled.chain, options.chain);
+        assert_eq!(styled.scale, Scale::X3);
+        assert_eq!(styled.synthetic, Synthetic::BOLD);
+        assert_eq!(PaintOptions::default().chain, FallbackChain::EMOJI_FIRST);
+    }
+}
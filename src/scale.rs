@@ -0,0 +1,37 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+/// Integer scale factors for painting glyphs with pixel replication. At a
+/// scale of N, each glyph pixel becomes an NxN block, and glyph padding and
+/// line height grow by the same factor.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Scale {
+    #[default]
+    X1 = 1,
+    X2 = 2,
+    X3 = 3,
+    X4 = 4,
+}
+
+impl Scale {
+    /// Number of screen pixels across each glyph pixel
+    pub fn factor(self) -> usize {
+        self as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale_factor() {
+        assert_eq!(Scale::X1.factor(), 1);
+        assert_eq!(Scale::X2.factor(), 2);
+        assert_eq!(Scale::X3.factor(), 3);
+        assert_eq!(Scale::X4.factor(), 4);
+        assert_eq!(Scale::default(), Scale::X1);
+    }
+}
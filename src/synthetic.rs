@@ -0,0 +1,54 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+#![forbid(unsafe_code)]
+
+/// Synthetic styles that get applied to glyphs as they are painted. They work
+/// the same for every glyph set, so hanzi, kana, and emoji can match bold or
+/// slanted latin text. They are independent of GlyphStyle, which picks the
+/// latin font.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Synthetic {
+    /// Thicken strokes by ORing each glyph with itself shifted 1px right, and
+    /// widen its advance by 1px
+    pub bold: bool,
+    /// Slant glyphs by shifting their rows right 1px for every 4 rows above
+    /// the bottom of the line, and widen their advance to fit
+    pub oblique: bool,
+}
+
+impl Synthetic {
+    /// Paint glyphs as they are
+    pub const NONE: Synthetic = Synthetic {
+        bold: false,
+        oblique: false,
+    };
+
+    /// Synthetic bold
+    pub const BOLD: Synthetic = Synthetic {
+        bold: true,
+        oblique: false,
+    };
+
+    /// Synthetic oblique
+    pub const OBLIQUE: Synthetic = Synthetic {
+        bold: false,
+        oblique: true,
+    };
+
+    /// Synthetic bold and oblique together
+    pub const BOLD_OBLIQUE: Synthetic = Synthetic {
+        bold: true,
+        oblique: true,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_synthetic_default() {
+        assert_eq!(Synthetic::default(), Synthetic::NONE);
+    }
+}
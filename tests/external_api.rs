@@ -0,0 +1,30 @@
+// Copyright (c) 2020 Sam Blenny
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+//! Check that public names, including the types of public fields, can be
+//! named from outside the crate
+#![forbid(unsafe_code)]
+
+use blitstr::{demo, new_fr_buf, FallbackChain, GlyphSource, MissingGlyph};
+
+#[test]
+fn test_custom_chain_with_missing_glyph() {
+    let sources = [GlyphSource::Latin];
+    let chain = FallbackChain {
+        missing: MissingGlyph::HexCode,
+        ..FallbackChain::new(&sources)
+    };
+    assert_eq!(chain, FallbackChain::new(&sources).with_hex_codes());
+    assert_eq!(
+        FallbackChain::EMOJI_FIRST.missing,
+        MissingGlyph::Replacement
+    );
+}
+
+#[test]
+fn test_demo() {
+    let fb = &mut new_fr_buf();
+    demo::short_greeting(fb);
+    assert!(fb.iter().any(|&word| word != 0xffff0000));
+    assert!(demo::GOOSE_POEM.ends_with('\n'));
+}